@@ -14,6 +14,8 @@ mod matcher;
 pub mod options;
 mod query;
 mod registry;
+#[cfg(feature = "serde")]
+pub mod restricted_regex;
 mod rule;
 mod services;
 mod signals;
@@ -248,6 +250,40 @@ struct LineSuppression {
     /// Set to `true` when a signal matching this suppression was emitted and
     /// suppressed
     did_suppress_signal: bool,
+    /// Set to `true` if the comment is attached to a node on the same line,
+    /// in which case it only suppresses the signals that start inside `text_range`
+    is_attached: bool,
+}
+
+/// Returns the last suppression comment that isn't attached to a node
+fn last_line_suppression(suppressions: &mut [LineSuppression]) -> Option<&mut LineSuppression> {
+    suppressions
+        .iter_mut()
+        .rev()
+        .find(|suppression| !suppression.is_attached)
+}
+
+impl LineSuppression {
+    /// Returns `true` if this comment suppresses the signals of `rule` for the given `instances`
+    fn suppresses(&self, rule: &RuleKey, instances: &[Box<str>]) -> bool {
+        if self.suppress_all {
+            return true;
+        }
+
+        if self.suppressed_rules.iter().any(|filter| *filter == *rule) {
+            return true;
+        }
+
+        if instances.is_empty() {
+            return false;
+        }
+
+        instances.iter().all(|value| {
+            self.suppressed_instances
+                .iter()
+                .any(|(filter, v)| *filter == *rule && v == value.as_ref())
+        })
+    }
 }
 
 impl<'a, 'phase, L, Matcher, Break, Diag> PhaseRunner<'a, 'phase, L, Matcher, Break, Diag>
@@ -378,17 +414,29 @@ where
             }
 
             // Search for an active suppression comment covering the range of
-            // this signal: first try to load the last line suppression and see
-            // if it matches the current line index, otherwise perform a binary
-            // search over all the previously seen suppressions to find one
-            // with a matching range
-            let suppression = self.line_suppressions.last_mut().filter(|suppression| {
-                suppression.line_index == *self.line_index
-                    && suppression.text_range.start() <= start
+            // this signal: first look for a comment attached to a node where
+            // the signal starts, then try to load the last line suppression
+            // and see if it matches the current line index, otherwise perform
+            // a binary search over all the previously seen suppressions to find
+            // one with a matching range
+            let attached = self.line_suppressions.iter().rposition(|suppression| {
+                suppression.is_attached
+                    && suppression.text_range.contains(start)
+                    && suppression.suppresses(&entry.rule, &entry.instances)
             });
 
-            let suppression = match suppression {
-                Some(suppression) => Some(suppression),
+            let last = self
+                .line_suppressions
+                .iter()
+                .rposition(|suppression| !suppression.is_attached)
+                .filter(|index| {
+                    let suppression = &self.line_suppressions[*index];
+                    suppression.line_index == *self.line_index
+                        && suppression.text_range.start() <= start
+                });
+
+            let suppression = match attached.or(last) {
+                Some(index) => Some(&mut self.line_suppressions[index]),
                 None => {
                     let index = self.line_suppressions.binary_search_by(|suppression| {
                         if suppression.text_range.end() < entry.text_range.start() {
@@ -400,34 +448,15 @@ where
                         }
                     });
 
-                    index.ok().map(|index| &mut self.line_suppressions[index])
+                    index
+                        .ok()
+                        .map(|index| &mut self.line_suppressions[index])
+                        .filter(|suppression| !suppression.is_attached)
                 }
             };
 
-            let suppression = suppression.filter(|suppression| {
-                if suppression.suppress_all {
-                    return true;
-                }
-
-                if suppression
-                    .suppressed_rules
-                    .iter()
-                    .any(|filter| *filter == entry.rule)
-                {
-                    return true;
-                }
-
-                if entry.instances.is_empty() {
-                    return false;
-                }
-
-                entry.instances.iter().all(|value| {
-                    suppression
-                        .suppressed_instances
-                        .iter()
-                        .any(|(filter, v)| *filter == entry.rule && v == value.as_ref())
-                })
-            });
+            let suppression = suppression
+                .filter(|suppression| suppression.suppresses(&entry.rule, &entry.instances));
 
             // If the signal is being suppressed mark the line suppression as
             // hit, otherwise emit the signal
@@ -568,14 +597,50 @@ where
             return ControlFlow::Continue(());
         }
 
+        // A comment followed by a token on the same line is attached to this
+        // token, and only applies to the node starting with it
+        let attached_token = if is_leading {
+            token
+                .leading_trivia()
+                .pieces()
+                .skip(index + 1)
+                .all(|piece| !piece.is_newline())
+                .then(|| token.clone())
+        } else {
+            token.next_token().filter(|next_token| {
+                !next_token
+                    .leading_trivia()
+                    .pieces()
+                    .any(|piece| piece.is_newline())
+            })
+        };
+        let attached_range = attached_token.and_then(|attached_token| {
+            self.suppression_action
+                .find_attached_suppression_range(&attached_token)
+        });
+        if let Some(attached_range) = attached_range {
+            self.line_suppressions.push(LineSuppression {
+                line_index: *self.line_index,
+                comment_span: range,
+                text_range: range.cover(attached_range),
+                suppress_all,
+                suppressed_rules,
+                suppressed_instances,
+                did_suppress_signal: false,
+                is_attached: true,
+            });
+            return ControlFlow::Continue(());
+        }
+
         // Suppression comments apply to the next line
         let line_index = *self.line_index + 1;
 
         // If the last suppression was on the same or previous line, extend its
         // range and set of suppressed rules with the content for the new suppression
         if let Some(last_suppression) = self.line_suppressions.last_mut() {
-            if last_suppression.line_index == line_index
-                || last_suppression.line_index + 1 == line_index
+            if !last_suppression.is_attached
+                && (last_suppression.line_index == line_index
+                    || last_suppression.line_index + 1 == line_index)
             {
                 last_suppression.line_index = line_index;
                 last_suppression.text_range = last_suppression.text_range.cover(range);
@@ -601,6 +666,7 @@ where
             suppressed_rules,
             suppressed_instances,
             did_suppress_signal: false,
+            is_attached: false,
         };
 
         self.line_suppressions.push(entry);
@@ -614,7 +680,7 @@ where
     fn bump_line_index(&mut self, text: &str, range: TextRange) {
         let mut did_match = false;
         for (index, _) in text.match_indices('\n') {
-            if let Some(last_suppression) = self.line_suppressions.last_mut() {
+            if let Some(last_suppression) = last_line_suppression(self.line_suppressions) {
                 if last_suppression.line_index == *self.line_index {
                     let index = TextSize::try_from(index).expect(
                         "integer overflow while converting a suppression line to `TextSize`",
@@ -629,7 +695,7 @@ where
         }
 
         if !did_match {
-            if let Some(last_suppression) = self.line_suppressions.last_mut() {
+            if let Some(last_suppression) = last_line_suppression(self.line_suppressions) {
                 if last_suppression.line_index == *self.line_index {
                     last_suppression.text_range = last_suppression.text_range.cover(range);
                 }
@@ -98,7 +98,6 @@ impl biome_deserialize::Deserializable for RestrictedRegex {
     }
 }
 
-#[cfg(feature = "schemars")]
 impl schemars::JsonSchema for RestrictedRegex {
     fn schema_name() -> String {
         "Regex".to_string()
@@ -105,6 +105,8 @@ pub enum RuleSource {
     EslintImportAccess(&'static str),
     /// Rules from [Eslint Plugin Jest](https://github.com/jest-community/eslint-plugin-jest)
     EslintJest(&'static str),
+    /// Rules from [Eslint Plugin JSDoc](https://github.com/gajus/eslint-plugin-jsdoc)
+    EslintJsDoc(&'static str),
     /// Rules from [Eslint Plugin JSX A11y](https://github.com/jsx-eslint/eslint-plugin-jsx-a11y)
     EslintJsxA11y(&'static str),
     /// Rules from [Eslint Plugin React](https://github.com/jsx-eslint/eslint-plugin-react)
@@ -137,6 +139,8 @@ pub enum RuleSource {
     Stylelint(&'static str),
     /// Rules from [Eslint Plugin No Secrets](https://github.com/nickdeis/eslint-plugin-no-secrets)
     EslintNoSecrets(&'static str),
+    /// Rules from [Eslint Plugin No Unsanitized](https://github.com/mozilla/eslint-plugin-no-unsanitized)
+    EslintNoUnsanitized(&'static str),
     /// Rules from [deno lint](https://github.com/denoland/deno_lint)
     DenoLint(&'static str),
 }
@@ -157,6 +161,7 @@ impl std::fmt::Display for RuleSource {
             Self::EslintImport(_) => write!(f, "eslint-plugin-import"),
             Self::EslintImportAccess(_) => write!(f, "eslint-plugin-import-access"),
             Self::EslintJest(_) => write!(f, "eslint-plugin-jest"),
+            Self::EslintJsDoc(_) => write!(f, "eslint-plugin-jsdoc"),
             Self::EslintJsxA11y(_) => write!(f, "eslint-plugin-jsx-a11y"),
             Self::EslintReact(_) => write!(f, "eslint-plugin-react"),
             Self::EslintReactHooks(_) => write!(f, "eslint-plugin-react-hooks"),
@@ -173,6 +178,7 @@ impl std::fmt::Display for RuleSource {
             Self::EslintNext(_) => write!(f, "@next/eslint-plugin-next"),
             Self::Stylelint(_) => write!(f, "Stylelint"),
             Self::EslintNoSecrets(_) => write!(f, "eslint-plugin-no-secrets"),
+            Self::EslintNoUnsanitized(_) => write!(f, "eslint-plugin-no-unsanitized"),
             Self::DenoLint(_) => write!(f, "deno-lint"),
         }
     }
@@ -210,6 +216,7 @@ impl RuleSource {
             | Self::EslintImport(rule_name)
             | Self::EslintImportAccess(rule_name)
             | Self::EslintJest(rule_name)
+            | Self::EslintJsDoc(rule_name)
             | Self::EslintJsxA11y(rule_name)
             | Self::EslintReact(rule_name)
             | Self::EslintReactHooks(rule_name)
@@ -225,6 +232,7 @@ impl RuleSource {
             | Self::EslintN(rule_name)
             | Self::EslintNext(rule_name)
             | Self::EslintNoSecrets(rule_name)
+            | Self::EslintNoUnsanitized(rule_name)
             | Self::Stylelint(rule_name)
             | Self::DenoLint(rule_name) => rule_name,
         }
@@ -238,6 +246,7 @@ impl RuleSource {
             Self::EslintImport(rule_name) => format!("import/{rule_name}"),
             Self::EslintImportAccess(rule_name) => format!("import-access/{rule_name}"),
             Self::EslintJest(rule_name) => format!("jest/{rule_name}"),
+            Self::EslintJsDoc(rule_name) => format!("jsdoc/{rule_name}"),
             Self::EslintJsxA11y(rule_name) => format!("jsx-a11y/{rule_name}"),
             Self::EslintReact(rule_name) => format!("react/{rule_name}"),
             Self::EslintReactHooks(rule_name) => format!("react-hooks/{rule_name}"),
@@ -254,6 +263,7 @@ impl RuleSource {
             Self::EslintNext(rule_name) => format!("@next/{rule_name}"),
             Self::Stylelint(rule_name) => format!("stylelint/{rule_name}"),
             Self::EslintNoSecrets(rule_name) => format!("no-secrets/{rule_name}"),
+            Self::EslintNoUnsanitized(rule_name) => format!("no-unsanitized/{rule_name}"),
             Self::DenoLint(rule_name) => format!("deno-lint/{rule_name}"),
         }
     }
@@ -267,6 +277,7 @@ impl RuleSource {
             Self::EslintImport(rule_name) => format!("https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/{rule_name}.md"),
             Self::EslintImportAccess(_) => "https://github.com/uhyo/eslint-plugin-import-access".to_string(),
             Self::EslintJest(rule_name) => format!("https://github.com/jest-community/eslint-plugin-jest/blob/main/docs/rules/{rule_name}.md"),
+            Self::EslintJsDoc(rule_name) => format!("https://github.com/gajus/eslint-plugin-jsdoc/blob/main/docs/rules/{rule_name}.md"),
             Self::EslintJsxA11y(rule_name) => format!("https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/{rule_name}.md"),
             Self::EslintReact(rule_name) => format!("https://github.com/jsx-eslint/eslint-plugin-react/blob/master/docs/rules/{rule_name}.md"),
             Self::EslintReactHooks(_) =>  "https://github.com/facebook/react/blob/main/packages/eslint-plugin-react-hooks/README.md".to_string(),
@@ -283,6 +294,7 @@ impl RuleSource {
             Self::EslintNext(rule_name) => format!("https://nextjs.org/docs/messages/{rule_name}"),
             Self::Stylelint(rule_name) => format!("https://github.com/stylelint/stylelint/blob/main/lib/rules/{rule_name}/README.md"),
             Self::EslintNoSecrets(_) => "https://github.com/nickdeis/eslint-plugin-no-secrets/blob/master/README.md".to_string(),
+            Self::EslintNoUnsanitized(rule_name) => format!("https://github.com/mozilla/eslint-plugin-no-unsanitized/blob/master/docs/rules/{rule_name}.md"),
             Self::DenoLint(rule_name) => format!("https://lint.deno.land/rules/{rule_name}"),
         }
     }
@@ -73,6 +73,17 @@ pub trait SuppressionAction {
         original_token: SyntaxToken<Self::Language>,
     ) -> Option<ApplySuppression<Self::Language>>;
 
+    /// Returns the range of the node that a suppression comment suppresses when it's attached to `token`,
+    /// that is, when the comment precedes `token` on the same line.
+    ///
+    /// By default, attached comments aren't supported and suppress the next line, like the other comments.
+    fn find_attached_suppression_range(
+        &self,
+        _token: &SyntaxToken<Self::Language>,
+    ) -> Option<TextRange> {
+        None
+    }
+
     fn apply_suppression(
         &self,
         mutation: &mut BatchMutation<Self::Language>,
@@ -662,17 +662,24 @@ pub(crate) fn validate_configuration_diagnostics(
     Ok(())
 }
 
+/// Searches the first of `file_names` in the working directory and its parents.
+///
+/// The path of a manifest found in the working directory is made relative to it,
+/// like the paths of the files that are traversed, so the path aliases of a `tsconfig.json`
+/// can be compared with them.
 fn resolve_manifest(
     fs: &DynRef<'_, dyn FileSystem>,
+    file_names: &[&str],
 ) -> Result<Option<(BiomePath, String)>, WorkspaceError> {
-    let result = fs.auto_search(
-        &fs.working_directory().unwrap_or_default(),
-        &["package.json"],
-        false,
-    )?;
+    let working_directory = fs.working_directory().unwrap_or_default();
+    let result = fs.auto_search(&working_directory, file_names, false)?;
 
     if let Some(result) = result {
-        return Ok(Some((BiomePath::new(result.file_path), result.content)));
+        let file_path = match result.file_path.strip_prefix(&working_directory) {
+            Ok(file_path) => file_path.to_path_buf(),
+            Err(_) => result.file_path,
+        };
+        return Ok(Some((BiomePath::new(file_path), result.content)));
     }
 
     Ok(None)
@@ -871,10 +878,12 @@ pub(crate) trait CommandRunner: Sized {
             set_as_current_workspace: true,
         })?;
 
-        let manifest_data = resolve_manifest(fs)?;
+        for file_names in [&["package.json"][..], &["tsconfig.json", "jsconfig.json"]] {
+            let manifest_data = resolve_manifest(fs, file_names)?;
 
-        if let Some(manifest_data) = manifest_data {
-            workspace.set_manifest_for_project(manifest_data.into())?;
+            if let Some(manifest_data) = manifest_data {
+                workspace.set_manifest_for_project(manifest_data.into())?;
+            }
         }
         workspace.update_settings(UpdateSettingsParams {
             workspace_directory: fs.working_directory(),
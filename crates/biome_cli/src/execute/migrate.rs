@@ -131,6 +131,7 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                     path: biome_path.clone(),
                     content: new_content,
                     version: 1,
+                    changed_range: None,
                 })?;
                 let printed = workspace.format_file(FormatFileParams { path: biome_path })?;
                 if write {
@@ -208,6 +209,7 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                     path: biome_path.clone(),
                     content: new_content,
                     version: 1,
+                    changed_range: None,
                 })?;
                 let printed = workspace.format_file(FormatFileParams { path: biome_path })?;
                 if write {
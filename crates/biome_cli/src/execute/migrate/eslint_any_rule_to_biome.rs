@@ -147,6 +147,14 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/max-params" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_max_params.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/naming-convention" => {
             if !options.include_inspired {
                 results.has_inspired_rules = true;
@@ -163,6 +171,18 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_array_literals.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/no-deprecated" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_deprecated_apis.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/no-dupe-class-members" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group
@@ -203,6 +223,18 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_static_only_class.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/no-floating-promises" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_floating_promises.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/no-inferrable-types" => {
             let group = rules.style.get_or_insert_with(Default::default);
             let rule = group.no_inferrable_types.get_or_insert(Default::default());
@@ -227,6 +259,18 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/no-misused-promises" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_misused_promises.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/no-namespace" => {
             let group = rules.style.get_or_insert_with(Default::default);
             let rule = group.no_namespace.get_or_insert(Default::default());
@@ -281,6 +325,20 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/no-unnecessary-condition" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unnecessary_conditions
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/no-unnecessary-type-constraint" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group
@@ -295,6 +353,20 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/no-unsafe-type-assertion" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unsafe_type_assertions
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/no-unused-vars" => {
             let group = rules.correctness.get_or_insert_with(Default::default);
             let rule = group.no_unused_variables.get_or_insert(Default::default());
@@ -386,11 +458,39 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_optional_chain.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/prefer-readonly" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_readonly_class_properties
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/require-await" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group.use_await.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/switch-exhaustiveness-check" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_exhaustive_switch_cases
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "barrel-files/avoid-barrel-files" => {
             if !options.include_inspired {
                 results.has_inspired_rules = true;
@@ -473,6 +573,48 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_getter_return.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "graphql/fields-on-correct-type" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_valid_graphql_operations
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "graphql/known-argument-names" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_valid_graphql_operations
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "graphql/no-undefined-variables" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_valid_graphql_operations
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "guard-for-in" => {
             if !options.include_nursery {
                 return false;
@@ -511,6 +653,18 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_common_js.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "import/no-cycle" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_circular_imports.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "import/no-default-export" => {
             let group = rules.style.get_or_insert_with(Default::default);
             let rule = group.no_default_export.get_or_insert(Default::default());
@@ -528,6 +682,18 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_nodejs_modules.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "import/no-unused-modules" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_unused_exports.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "jest/max-nested-describe" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group
@@ -535,6 +701,16 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "jest/no-conditional-expect" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_conditional_expect
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "jest/no-disabled-tests" => {
             if !options.include_inspired {
                 results.has_inspired_rules = true;
@@ -578,6 +754,20 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_focused_tests.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "jest/no-identical-title" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_duplicate_test_titles
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "jest/no-standalone-expect" => {
             if !options.include_inspired {
                 results.has_inspired_rules = true;
@@ -589,6 +779,76 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "jest/prefer-lowercase-title" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_consistent_test_naming
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "jsdoc/check-param-names" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_js_doc_consistency
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "jsdoc/require-param" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_js_doc_consistency
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "jsdoc/require-returns" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_js_doc_consistency
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "jsdoc/require-returns-check" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_js_doc_consistency
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "jsx-a11y/alt-text" => {
             let group = rules.a11y.get_or_insert_with(Default::default);
             let rule = group.use_alt_text.get_or_insert(Default::default());
@@ -797,6 +1057,56 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_positive_tabindex.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "max-lines-per-function" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_max_function_size
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "max-params" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_max_params.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "max-statements" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_max_function_size
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "n/no-path-concat" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_platform_agnostic_paths
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "n/no-process-env" => {
             if !options.include_nursery {
                 return false;
@@ -805,6 +1115,20 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_process_env.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "n/no-sync" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_sync_fs_in_async_contexts
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "no-array-constructor" => {
             let group = rules.correctness.get_or_insert_with(Default::default);
             let rule = group.use_array_literals.get_or_insert(Default::default());
@@ -817,6 +1141,14 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "no-await-in-loop" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_await_in_loop.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "no-case-declarations" => {
             let group = rules.correctness.get_or_insert_with(Default::default);
             let rule = group
@@ -1051,6 +1383,14 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_precision_loss.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "no-magic-numbers" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_magic_numbers.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "no-misleading-character-class" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group
@@ -1153,6 +1493,18 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "no-restricted-syntax" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_restricted_syntax.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "no-secrets/no-secrets" => {
             if !options.include_inspired {
                 results.has_inspired_rules = true;
@@ -1262,6 +1614,34 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "no-unsanitized/method" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unsanitized_dom_insertion
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "no-unsanitized/property" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unsanitized_dom_insertion
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "no-unused-labels" => {
             let group = rules.correctness.get_or_insert_with(Default::default);
             let rule = group.no_unused_labels.get_or_insert(Default::default());
@@ -1330,6 +1710,20 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_void.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "no-warning-comments" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_todo_without_ticket
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "no-with" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group.no_with.get_or_insert(Default::default());
@@ -1361,6 +1755,16 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_const.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "prefer-destructuring" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_prefer_destructuring
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "prefer-exponentiation-operator" => {
             let group = rules.style.get_or_insert_with(Default::default);
             let rule = group
@@ -1380,6 +1784,16 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "prefer-object-spread" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_prefer_object_spread
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "prefer-regex-literals" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group.use_regex_literals.get_or_insert(Default::default());
@@ -1395,6 +1809,18 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_template.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "preserve-caught-error" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_error_cause.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "radix" => {
             if !options.include_nursery {
                 return false;
@@ -1483,6 +1909,20 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "react/jsx-no-literals" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_hardcoded_user_facing_strings
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "react/jsx-no-target-blank" => {
             let group = rules.a11y.get_or_insert_with(Default::default);
             let rule = group.no_blank_target.get_or_insert(Default::default());
@@ -1517,6 +1957,20 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "react/no-object-type-as-default-prop" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unstable_default_props
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "react/void-dom-elements-no-children" => {
             let group = rules.correctness.get_or_insert_with(Default::default);
             let rule = group
@@ -1548,11 +2002,51 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "sonarjs/prefer-single-boolean-return" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_simplified_boolean_return
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "sonarjs/prefer-while" => {
             let group = rules.style.get_or_insert_with(Default::default);
             let rule = group.use_while.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "sonarjs/slow-regex" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_super_linear_regex
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "sonarjs/sql-queries" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_sql_injection.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "unicorn/error-message" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group.use_error_message.get_or_insert(Default::default());
@@ -1639,6 +2133,20 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_useless_undefined.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "unicorn/numeric-separators-style" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_numeric_separators
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "unicorn/prefer-array-flat-map" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group.use_flat_map.get_or_insert(Default::default());
@@ -1703,6 +2211,18 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_trim_start_end.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "unicorn/prefer-structured-clone" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_structured_clone.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "unicorn/require-number-to-fixed-digits-argument" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group
@@ -3,12 +3,12 @@ use std::{cmp::Ordering, str::FromStr};
 /// Configuration related to [TypeScript Eslint](https://typescript-eslint.io/).
 ///
 /// Also, the module includes implementation to convert rule options to Biome's rule options.
+use biome_analyze::restricted_regex::RestrictedRegex;
 use biome_deserialize::Deserializable;
 use biome_deserialize_macros::Deserializable;
 use biome_js_analyze::{
     lint::nursery::use_consistent_member_accessibility,
     lint::style::{use_consistent_array_type, use_naming_convention},
-    utils::restricted_regex::RestrictedRegex,
 };
 
 use super::eslint_eslint;
@@ -140,6 +140,7 @@ pub(crate) fn run<'a>(
                         content: output.clone(),
                         path: biome_path.clone(),
                         version,
+                        changed_range: None,
                     })?;
                     new_content = Cow::Owned(output);
                 }
@@ -162,6 +163,7 @@ pub(crate) fn run<'a>(
                         content: output.clone(),
                         path: biome_path.clone(),
                         version,
+                        changed_range: None,
                     })?;
                     new_content = Cow::Owned(output);
                 }
@@ -10,12 +10,16 @@ use crate::reporter::TraversalSummary;
 use crate::{CliDiagnostic, CliSession};
 use biome_diagnostics::DiagnosticTags;
 use biome_diagnostics::{category, DiagnosticExt, Error, Resource, Severity};
-use biome_fs::{BiomePath, FileSystem, PathInterner};
+use biome_fs::{BiomePath, FileSystem, OpenOptions, PathInterner};
 use biome_fs::{TraversalContext, TraversalScope};
 use biome_service::dome::Dome;
-use biome_service::workspace::{DropPatternParams, IsPathIgnoredParams};
+use biome_service::workspace::{
+    DropPatternParams, FileGuard, IsPathIgnoredParams, IsPathIndexedParams, OpenFileParams,
+    SetProjectIndexedParams,
+};
 use biome_service::{extension_error, workspace::SupportsFeatureParams, Workspace, WorkspaceError};
 use crossbeam::channel::{unbounded, Receiver, Sender};
+use rayon::prelude::*;
 use rustc_hash::FxHashSet;
 use std::collections::BTreeSet;
 use std::sync::atomic::AtomicU32;
@@ -183,6 +187,9 @@ fn traverse_inputs(
     }));
 
     let paths = ctx.evaluated_paths();
+    if ctx.execution.is_lint() || ctx.execution.is_check() || ctx.execution.is_ci() {
+        index_files(ctx, &paths);
+    }
     let dome = Dome::new(paths);
     let mut iter = dome.iter();
     fs.traversal(Box::new(|scope: &dyn TraversalScope| {
@@ -202,6 +209,39 @@ fn traverse_inputs(
     (start.elapsed(), ctx.evaluated_paths())
 }
 
+/// Opens and closes every file that is going to be analyzed and that the workspace indexes,
+/// so the workspace indexes all of them before the rules that look across files run.
+/// The files of the project are all analyzed, so the workspace can trust its index afterwards.
+///
+/// Errors are ignored here: they're reported when the file is processed.
+fn index_files(ctx: &TraversalOptions, paths: &BTreeSet<BiomePath>) {
+    let fs = ctx.fs;
+    let workspace = ctx.workspace;
+    paths.par_iter().for_each(|path| {
+        let is_indexed = workspace.is_path_indexed(IsPathIndexedParams { path: path.clone() });
+        if !is_indexed.unwrap_or_default() {
+            return;
+        }
+        let Ok(mut file) = fs.open_with_options(path, OpenOptions::default().read(true)) else {
+            return;
+        };
+        let mut content = String::new();
+        if file.read_to_string(&mut content).is_err() {
+            return;
+        }
+        let _ = FileGuard::open(
+            workspace,
+            OpenFileParams {
+                document_file_source: None,
+                path: path.clone(),
+                version: 0,
+                content,
+            },
+        );
+    });
+    let _ = workspace.set_project_indexed(SetProjectIndexedParams { indexed: true });
+}
+
 // struct DiagnosticsReporter<'ctx> {}
 
 struct DiagnosticsPrinter<'ctx> {
@@ -101,6 +101,49 @@ fn should_format_write_files_by_when_opt_in() {
     ));
 }
 
+#[test]
+fn should_keep_scss_syntax_as_written() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let plain_file = Path::new("plain.scss");
+    fs.insert(plain_file.into(), r#"html {}"#.as_bytes());
+
+    let scss_file = Path::new("variables.scss");
+    fs.insert(
+        scss_file.into(),
+        r#"$color: red;
+a { color: $color; }
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                "--css-formatter-enabled=true",
+                plain_file.as_os_str().to_str().unwrap(),
+                scss_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_keep_scss_syntax_as_written",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn should_not_lint_files_by_default() {
     let mut fs = MemoryFileSystem::default();
@@ -181,3 +224,64 @@ fn should_lint_files_by_when_enabled() {
         result,
     ));
 }
+
+#[test]
+fn should_find_custom_properties_read_by_other_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "css": { "linter": { "enabled": true } },
+  "linter": { "rules": { "nursery": { "noUnusedCustomProperties": "error" } } }
+}
+"#
+        .as_bytes(),
+    );
+
+    let theme_file = Path::new("theme.css");
+    fs.insert(
+        theme_file.into(),
+        r#":root {
+  --brand-color: rebeccapurple;
+  --never-read: 1px;
+}
+"#
+        .as_bytes(),
+    );
+
+    let button_file = Path::new("button.css");
+    fs.insert(
+        button_file.into(),
+        r#".button {
+  color: var(--brand-color);
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                button_file.as_os_str().to_str().unwrap(),
+                theme_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_find_custom_properties_read_by_other_files",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -4327,3 +4327,130 @@ fn should_error_if_unchanged_files_only_with_changed_flag() {
         result,
     ));
 }
+
+#[test]
+fn should_find_exports_imported_by_other_modules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "recommended": false,
+      "nursery": {
+        "noUnusedExports": {
+          "level": "error",
+          "options": { "entryPoints": ["main.js"] }
+        }
+      }
+    }
+  }
+}
+"#
+        .as_bytes(),
+    );
+
+    let utils_file = Path::new("src/utils.js");
+    fs.insert(
+        utils_file.into(),
+        r#"export function formatDate(date) {}
+export function neverImported() {}
+"#
+        .as_bytes(),
+    );
+
+    let main_file = Path::new("main.js");
+    fs.insert(
+        main_file.into(),
+        r#"import { formatDate } from "./src/utils.js";
+export function main() {}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                main_file.as_os_str().to_str().unwrap(),
+                utils_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_find_exports_imported_by_other_modules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_use_the_path_aliases_of_the_tsconfig() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "recommended": false,
+      "nursery": {
+        "useImportAliases": "error"
+      }
+    }
+  }
+}
+"#
+        .as_bytes(),
+    );
+
+    fs.insert(
+        Path::new("tsconfig.json").into(),
+        r#"{
+  // Comments are allowed
+  "compilerOptions": {
+    "paths": {
+      "@/*": ["./src/*"],
+    },
+  },
+}
+"#
+        .as_bytes(),
+    );
+
+    let page_file = Path::new("src/features/settings/page.js");
+    fs.insert(
+        page_file.into(),
+        r#"import { formatDate } from "../../shared/format.js";
+import { Layout } from "../layout.js";
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", page_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_use_the_path_aliases_of_the_tsconfig",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -109,11 +109,19 @@ pub struct Source {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_sorted_keys:
         Option<RuleAssistConfiguration<biome_json_analyze::options::UseSortedKeys>>,
+    #[doc = "Sort the declarations of a block."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_properties:
+        Option<RuleAssistConfiguration<biome_css_analyze::options::UseSortedProperties>>,
 }
 impl Source {
     const GROUP_NAME: &'static str = "source";
-    pub(crate) const GROUP_RULES: &'static [&'static str] =
-        &["organizeImports", "useSortedAttributes", "useSortedKeys"];
+    pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "organizeImports",
+        "useSortedAttributes",
+        "useSortedKeys",
+        "useSortedProperties",
+    ];
     pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut index_set = FxHashSet::default();
         if let Some(rule) = self.organize_imports.as_ref() {
@@ -131,6 +139,11 @@ impl Source {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
+        if let Some(rule) = self.use_sorted_properties.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
         index_set
     }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
@@ -154,6 +167,10 @@ impl Source {
                 .use_sorted_keys
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedProperties" => self
+                .use_sorted_properties
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             _ => None,
         }
     }
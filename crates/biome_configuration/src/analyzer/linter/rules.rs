@@ -3271,9 +3271,46 @@ pub struct Nursery {
     #[doc = r" It enables ALL rules for this group."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub all: Option<bool>,
+    #[doc = "Enforce a maximum depth for the nested rules."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_nesting_depth: Option<RuleConfiguration<biome_css_analyze::options::MaxNestingDepth>>,
+    #[doc = "Enforce that z-index values come from a scale."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_arbitrary_z_index:
+        Option<RuleConfiguration<biome_css_analyze::options::NoArbitraryZIndex>>,
+    #[doc = "Disallow await inside of loops."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_await_in_loop: Option<RuleConfiguration<biome_js_analyze::options::NoAwaitInLoop>>,
+    #[doc = "Disallow the imports that form a cycle between modules."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_circular_imports:
+        Option<RuleConfiguration<biome_js_analyze::options::NoCircularImports>>,
+    #[doc = "Disallow commented-out code."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_commented_out_code:
+        Option<RuleConfiguration<biome_js_analyze::options::NoCommentedOutCode>>,
     #[doc = "Disallow use of CommonJs module system in favor of ESM style imports."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_common_js: Option<RuleConfiguration<biome_js_analyze::options::NoCommonJs>>,
+    #[doc = "Disallow calling expect conditionally."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_conditional_expect:
+        Option<RuleConfiguration<biome_js_analyze::options::NoConditionalExpect>>,
+    #[doc = "Disallow references to undeclared custom properties that look like typos."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_custom_property_typos:
+        Option<RuleConfiguration<biome_css_analyze::options::NoCustomPropertyTypos>>,
+    #[doc = "Disallow the relative imports that go through too many parent directories."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_deep_relative_imports:
+        Option<RuleConfiguration<biome_js_analyze::options::NoDeepRelativeImports>>,
+    #[doc = "Disallow the use of declarations marked as deprecated with the JSDoc tag @deprecated."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_deprecated_apis: Option<RuleConfiguration<biome_js_analyze::options::NoDeprecatedApis>>,
+    #[doc = "Disallow deprecated and nonstandard properties and values."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_deprecated_properties:
+        Option<RuleFixConfiguration<biome_css_analyze::options::NoDeprecatedProperties>>,
     #[doc = "Disallow a lower specificity selector from coming after a higher specificity selector."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_descending_specificity:
@@ -3297,6 +3334,14 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_properties:
         Option<RuleConfiguration<biome_css_analyze::options::NoDuplicateProperties>>,
+    #[doc = "Disallow style rules whose selector is already used in another stylesheet."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_selectors_across_files:
+        Option<RuleConfiguration<biome_css_analyze::options::NoDuplicateSelectorsAcrossFiles>>,
+    #[doc = "Disallow tests with the same title in the same `describe` block."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_test_titles:
+        Option<RuleConfiguration<biome_js_analyze::options::NoDuplicateTestTitles>>,
     #[doc = "No duplicated fields in GraphQL operations."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicated_fields:
@@ -3312,10 +3357,22 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_exported_imports:
         Option<RuleConfiguration<biome_js_analyze::options::NoExportedImports>>,
+    #[doc = "Require the promises created in a statement to be awaited or handled."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_floating_promises:
+        Option<RuleFixConfiguration<biome_js_analyze::options::NoFloatingPromises>>,
     #[doc = "Disallow the use of __dirname and __filename in the global scope."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_global_dirname_filename:
         Option<RuleFixConfiguration<biome_js_analyze::options::NoGlobalDirnameFilename>>,
+    #[doc = "Disallow hardcoded colors in favor of the custom properties of a design system."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_hardcoded_colors:
+        Option<RuleConfiguration<biome_css_analyze::options::NoHardcodedColors>>,
+    #[doc = "Disallow the strings displayed to the user that aren't passed to a translation function."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_hardcoded_user_facing_strings:
+        Option<RuleConfiguration<biome_js_analyze::options::NoHardcodedUserFacingStrings>>,
     #[doc = "Prevent usage of \\<head> element in a Next.js project."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_head_element: Option<RuleConfiguration<biome_js_analyze::options::NoHeadElement>>,
@@ -3323,6 +3380,13 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_head_import_in_document:
         Option<RuleConfiguration<biome_js_analyze::options::NoHeadImportInDocument>>,
+    #[doc = "Disallow selectors whose specificity is higher than a maximum."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_high_specificity_selectors:
+        Option<RuleConfiguration<biome_css_analyze::options::NoHighSpecificitySelectors>>,
+    #[doc = "Disallow ID selectors."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_id_selectors: Option<RuleConfiguration<biome_css_analyze::options::NoIdSelectors>>,
     #[doc = "Prevent usage of \\<img> element in a Next.js project."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_img_element: Option<RuleConfiguration<biome_js_analyze::options::NoImgElement>>,
@@ -3330,10 +3394,29 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_irregular_whitespace:
         Option<RuleConfiguration<biome_css_analyze::options::NoIrregularWhitespace>>,
+    #[doc = "Require the event listeners added by an effect or by a subscription to be removed by its cleanup function."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_leaked_event_listeners:
+        Option<RuleConfiguration<biome_js_analyze::options::NoLeakedEventListeners>>,
+    #[doc = "Disallow text and background colors with an insufficient contrast."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_low_contrast_color_pairs:
+        Option<RuleConfiguration<biome_css_analyze::options::NoLowContrastColorPairs>>,
+    #[doc = "Disallow magic numbers."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_magic_numbers: Option<RuleConfiguration<biome_js_analyze::options::NoMagicNumbers>>,
     #[doc = "Disallow missing var function for css variables."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_missing_var_function:
         Option<RuleConfiguration<biome_css_analyze::options::NoMissingVarFunction>>,
+    #[doc = "Disallow promises where they aren't expected."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_misused_promises:
+        Option<RuleConfiguration<biome_js_analyze::options::NoMisusedPromises>>,
+    #[doc = "Disallow the statements with side effects at the top level of a module."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_module_scope_side_effects:
+        Option<RuleConfiguration<biome_js_analyze::options::NoModuleScopeSideEffects>>,
     #[doc = "Disallow nested ternary expressions."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_nested_ternary: Option<RuleConfiguration<biome_js_analyze::options::NoNestedTernary>>,
@@ -3350,6 +3433,10 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_restricted_imports:
         Option<RuleConfiguration<biome_js_analyze::options::NoRestrictedImports>>,
+    #[doc = "Disallow the code matched by the configured patterns."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_restricted_syntax:
+        Option<RuleConfiguration<biome_js_analyze::options::NoRestrictedSyntax>>,
     #[doc = "Disallow user defined types."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_restricted_types:
@@ -3357,6 +3444,9 @@ pub struct Nursery {
     #[doc = "Disallow usage of sensitive data such as API keys and tokens."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_secrets: Option<RuleConfiguration<biome_js_analyze::options::NoSecrets>>,
+    #[doc = "Disallow building the queries passed to a database with template literals that interpolate values."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_sql_injection: Option<RuleConfiguration<biome_js_analyze::options::NoSqlInjection>>,
     #[doc = "Enforce that static, visible elements (such as \\<div>) that have click handlers use the valid role attribute."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_static_element_interactions:
@@ -3364,13 +3454,36 @@ pub struct Nursery {
     #[doc = "Enforce the use of String.slice() over String.substr() and String.substring()."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_substr: Option<RuleFixConfiguration<biome_js_analyze::options::NoSubstr>>,
+    #[doc = "Disallow the regular expressions that can take super-linear time to match some strings."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_super_linear_regex:
+        Option<RuleConfiguration<biome_js_analyze::options::NoSuperLinearRegex>>,
+    #[doc = "Disallow the synchronous file system and child process functions in async functions and request handlers."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_sync_fs_in_async_contexts:
+        Option<RuleConfiguration<biome_js_analyze::options::NoSyncFsInAsyncContexts>>,
     #[doc = "Disallow template literal placeholder syntax in regular strings."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_template_curly_in_string:
         Option<RuleConfiguration<biome_js_analyze::options::NoTemplateCurlyInString>>,
+    #[doc = "Require the TODO and FIXME comments to reference a ticket."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_todo_without_ticket:
+        Option<RuleConfiguration<biome_js_analyze::options::NoTodoWithoutTicket>>,
+    #[doc = "Disallow transitions of all the properties."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_transition_all: Option<RuleConfiguration<biome_css_analyze::options::NoTransitionAll>>,
+    #[doc = "Disallow animation names that don't match any @keyframes."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_animation_name:
+        Option<RuleConfiguration<biome_css_analyze::options::NoUnknownAnimationName>>,
     #[doc = "Disallow unknown at-rules."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_at_rule: Option<RuleConfiguration<biome_css_analyze::options::NoUnknownAtRule>>,
+    #[doc = "Disallow unknown size feature names in container queries."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_container_feature_name:
+        Option<RuleConfiguration<biome_css_analyze::options::NoUnknownContainerFeatureName>>,
     #[doc = "Disallow unknown pseudo-class selectors."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_pseudo_class:
@@ -3383,6 +3496,33 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_type_selector:
         Option<RuleConfiguration<biome_css_analyze::options::NoUnknownTypeSelector>>,
+    #[doc = "Disallow conditions that are always truthy or always falsy according to the types."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unnecessary_conditions:
+        Option<RuleFixConfiguration<biome_js_analyze::options::NoUnnecessaryConditions>>,
+    #[doc = "Disallow type assertions that bypass the type checking."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unsafe_type_assertions:
+        Option<RuleConfiguration<biome_js_analyze::options::NoUnsafeTypeAssertions>>,
+    #[doc = "Disallow inserting HTML that isn't sanitized into the document."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unsanitized_dom_insertion:
+        Option<RuleConfiguration<biome_js_analyze::options::NoUnsanitizedDomInsertion>>,
+    #[doc = "Disallow the object, array, and function literals as default values of the props of a component, when the props are dependencies of a hook."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unstable_default_props:
+        Option<RuleConfiguration<biome_js_analyze::options::NoUnstableDefaultProps>>,
+    #[doc = "Disallow custom properties that are never read."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_custom_properties:
+        Option<RuleConfiguration<biome_css_analyze::options::NoUnusedCustomProperties>>,
+    #[doc = "Disallow exports that no module of the project imports."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_exports: Option<RuleConfiguration<biome_js_analyze::options::NoUnusedExports>>,
+    #[doc = "Disallow @keyframes that are never used by an animation."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_keyframes:
+        Option<RuleConfiguration<biome_css_analyze::options::NoUnusedKeyframes>>,
     #[doc = "Disallow unnecessary escape sequence in regular expression literals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_useless_escape_in_regex:
@@ -3398,10 +3538,17 @@ pub struct Nursery {
     #[doc = "Disallow use of @value rule in css modules."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_value_at_rule: Option<RuleConfiguration<biome_css_analyze::options::NoValueAtRule>>,
+    #[doc = "Disallow vendor prefixes that aren't needed by the target browsers."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_vendor_prefixes:
+        Option<RuleFixConfiguration<biome_css_analyze::options::NoVendorPrefixes>>,
     #[doc = "Disallow the use of overload signatures that are not next to each other."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_adjacent_overload_signatures:
         Option<RuleConfiguration<biome_js_analyze::options::UseAdjacentOverloadSignatures>>,
+    #[doc = "Enforce the units allowed for each property."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_allowed_units: Option<RuleConfiguration<biome_css_analyze::options::UseAllowedUnits>>,
     #[doc = "Enforce that ARIA properties are valid for the roles that are supported by the element."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_aria_props_supported_by_role:
@@ -3409,6 +3556,13 @@ pub struct Nursery {
     #[doc = "Use at() instead of integer index access."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_at_index: Option<RuleFixConfiguration<biome_js_analyze::options::UseAtIndex>>,
+    #[doc = "Disallow CSS features that aren't supported by the target browsers."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_browser_compat: Option<RuleConfiguration<biome_css_analyze::options::UseBrowserCompat>>,
+    #[doc = "Enforce a naming convention for the class selectors."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_class_naming_convention:
+        Option<RuleConfiguration<biome_css_analyze::options::UseClassNamingConvention>>,
     #[doc = "Enforce using single if instead of nested if clauses."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_collapsed_if: Option<RuleFixConfiguration<biome_js_analyze::options::UseCollapsedIf>>,
@@ -3416,6 +3570,10 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_component_export_only_modules:
         Option<RuleConfiguration<biome_js_analyze::options::UseComponentExportOnlyModules>>,
+    #[doc = "Enforce a consistent format for the colors."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_consistent_color_format:
+        Option<RuleFixConfiguration<biome_css_analyze::options::UseConsistentColorFormat>>,
     #[doc = "This rule enforces consistent use of curly braces inside JSX attributes and JSX children."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_consistent_curly_braces:
@@ -3424,16 +3582,35 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_consistent_member_accessibility:
         Option<RuleConfiguration<biome_js_analyze::options::UseConsistentMemberAccessibility>>,
+    #[doc = "Enforce a consistent convention for the titles of the tests."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_consistent_test_naming:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseConsistentTestNaming>>,
     #[doc = "Require specifying the reason argument when using @deprecated directive"]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_deprecated_reason:
         Option<RuleConfiguration<biome_graphql_analyze::options::UseDeprecatedReason>>,
+    #[doc = "Require the caught error to be preserved as the cause of the error thrown in a catch clause."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_error_cause: Option<RuleFixConfiguration<biome_js_analyze::options::UseErrorCause>>,
+    #[doc = "Require the switch statements over a union of literal types or over an enum to handle every case."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_exhaustive_switch_cases:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseExhaustiveSwitchCases>>,
+    #[doc = "Prefer `using` declarations to disposing of resources in a `finally` clause."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_explicit_resource_management:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseExplicitResourceManagement>>,
     #[doc = "Require explicit return types on functions and class methods."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_explicit_type: Option<RuleConfiguration<biome_js_analyze::options::UseExplicitType>>,
     #[doc = "Require that all exports are declared after all non-export statements."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_exports_last: Option<RuleConfiguration<biome_js_analyze::options::UseExportsLast>>,
+    #[doc = "Require font-family declarations to end with a generic font family."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_font_family_fallbacks:
+        Option<RuleConfiguration<biome_css_analyze::options::UseFontFamilyFallbacks>>,
     #[doc = "Enforces the use of a recommended display strategy with Google Fonts."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_google_font_display:
@@ -3445,10 +3622,29 @@ pub struct Nursery {
     #[doc = "Require for-in loops to include an if statement."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_guard_for_in: Option<RuleConfiguration<biome_js_analyze::options::UseGuardForIn>>,
+    #[doc = "Enforce the use of the path aliases of the project instead of deep relative imports."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_import_aliases:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseImportAliases>>,
     #[doc = "Disallows package private imports."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_import_restrictions:
         Option<RuleConfiguration<biome_js_analyze::options::UseImportRestrictions>>,
+    #[doc = "Enforce that the JSDoc comments of the functions match their signatures."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_js_doc_consistency:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseJsDocConsistency>>,
+    #[doc = "Enforce the use of logical properties and values instead of physical ones."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_logical_properties:
+        Option<RuleFixConfiguration<biome_css_analyze::options::UseLogicalProperties>>,
+    #[doc = "Enforce a maximum size of the functions."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_max_function_size:
+        Option<RuleConfiguration<biome_js_analyze::options::UseMaxFunctionSize>>,
+    #[doc = "Enforce a maximum number of parameters in the function signatures."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_max_params: Option<RuleConfiguration<biome_js_analyze::options::UseMaxParams>>,
     #[doc = "Enforce specifying the name of GraphQL operations."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_named_operation:
@@ -3457,10 +3653,37 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_naming_convention:
         Option<RuleConfiguration<biome_graphql_analyze::options::UseNamingConvention>>,
+    #[doc = "Enforce the use of numeric separators in long numeric literals."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_numeric_separators:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseNumericSeparators>>,
     #[doc = "Enforce the consistent use of the radix argument when using parseInt()."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_parse_int_radix:
         Option<RuleFixConfiguration<biome_js_analyze::options::UseParseIntRadix>>,
+    #[doc = "Disallow building file paths with string concatenation and hardcoded separators."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_platform_agnostic_paths:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UsePlatformAgnosticPaths>>,
+    #[doc = "Require destructuring from arrays and objects."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_prefer_destructuring:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UsePreferDestructuring>>,
+    #[doc = "Prefer object spread over `Object.assign()` when constructing a new object."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_prefer_object_spread:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UsePreferObjectSpread>>,
+    #[doc = "Enforce marking private properties as readonly if they're never modified outside the constructor."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_readonly_class_properties:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseReadonlyClassProperties>>,
+    #[doc = "Enforce the use of shorthand properties instead of groups of longhand properties."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_shorthand: Option<RuleFixConfiguration<biome_css_analyze::options::UseShorthand>>,
+    #[doc = "Disallow returning boolean literals that depend on a condition, instead of the condition itself."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_simplified_boolean_return:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseSimplifiedBooleanReturn>>,
     #[doc = "Enforce the sorting of CSS utility classes."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_sorted_classes:
@@ -3468,6 +3691,10 @@ pub struct Nursery {
     #[doc = "Enforce the use of the directive \"use strict\" in script files."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_strict_mode: Option<RuleFixConfiguration<biome_js_analyze::options::UseStrictMode>>,
+    #[doc = "Prefer `structuredClone()` to deep clone a value with `JSON.parse()` and `JSON.stringify()`."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_structured_clone:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseStructuredClone>>,
     #[doc = "Enforce the use of String.trimStart() and String.trimEnd() over String.trimLeft() and String.trimRight()."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_trim_start_end:
@@ -3476,6 +3703,13 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_valid_autocomplete:
         Option<RuleConfiguration<biome_js_analyze::options::UseValidAutocomplete>>,
+    #[doc = "Enforce that the GraphQL operations of the `gql` and `graphql` tagged templates are valid against the schema of the project."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_valid_graphql_operations:
+        Option<RuleConfiguration<biome_js_analyze::options::UseValidGraphqlOperations>>,
+    #[doc = "Require a fallback value in the var() functions that reference custom properties declared outside the project."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_var_fallbacks: Option<RuleConfiguration<biome_css_analyze::options::UseVarFallbacks>>,
 }
 impl DeserializableValidator for Nursery {
     fn validate(
@@ -3494,62 +3728,124 @@ impl DeserializableValidator for Nursery {
 impl Nursery {
     const GROUP_NAME: &'static str = "nursery";
     pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "maxNestingDepth",
+        "noArbitraryZIndex",
+        "noAwaitInLoop",
+        "noCircularImports",
+        "noCommentedOutCode",
         "noCommonJs",
+        "noConditionalExpect",
+        "noCustomPropertyTypos",
+        "noDeepRelativeImports",
+        "noDeprecatedApis",
+        "noDeprecatedProperties",
         "noDescendingSpecificity",
         "noDocumentCookie",
         "noDocumentImportInPage",
         "noDuplicateCustomProperties",
         "noDuplicateElseIf",
         "noDuplicateProperties",
+        "noDuplicateSelectorsAcrossFiles",
+        "noDuplicateTestTitles",
         "noDuplicatedFields",
         "noDynamicNamespaceImportAccess",
         "noEnum",
         "noExportedImports",
+        "noFloatingPromises",
         "noGlobalDirnameFilename",
+        "noHardcodedColors",
+        "noHardcodedUserFacingStrings",
         "noHeadElement",
         "noHeadImportInDocument",
+        "noHighSpecificitySelectors",
+        "noIdSelectors",
         "noImgElement",
         "noIrregularWhitespace",
+        "noLeakedEventListeners",
+        "noLowContrastColorPairs",
+        "noMagicNumbers",
         "noMissingVarFunction",
+        "noMisusedPromises",
+        "noModuleScopeSideEffects",
         "noNestedTernary",
         "noOctalEscape",
         "noProcessEnv",
         "noProcessGlobal",
         "noRestrictedImports",
+        "noRestrictedSyntax",
         "noRestrictedTypes",
         "noSecrets",
+        "noSqlInjection",
         "noStaticElementInteractions",
         "noSubstr",
+        "noSuperLinearRegex",
+        "noSyncFsInAsyncContexts",
         "noTemplateCurlyInString",
+        "noTodoWithoutTicket",
+        "noTransitionAll",
+        "noUnknownAnimationName",
         "noUnknownAtRule",
+        "noUnknownContainerFeatureName",
         "noUnknownPseudoClass",
         "noUnknownPseudoElement",
         "noUnknownTypeSelector",
+        "noUnnecessaryConditions",
+        "noUnsafeTypeAssertions",
+        "noUnsanitizedDomInsertion",
+        "noUnstableDefaultProps",
+        "noUnusedCustomProperties",
+        "noUnusedExports",
+        "noUnusedKeyframes",
         "noUselessEscapeInRegex",
         "noUselessStringRaw",
         "noUselessUndefined",
         "noValueAtRule",
+        "noVendorPrefixes",
         "useAdjacentOverloadSignatures",
+        "useAllowedUnits",
         "useAriaPropsSupportedByRole",
         "useAtIndex",
+        "useBrowserCompat",
+        "useClassNamingConvention",
         "useCollapsedIf",
         "useComponentExportOnlyModules",
+        "useConsistentColorFormat",
         "useConsistentCurlyBraces",
         "useConsistentMemberAccessibility",
+        "useConsistentTestNaming",
         "useDeprecatedReason",
+        "useErrorCause",
+        "useExhaustiveSwitchCases",
+        "useExplicitResourceManagement",
         "useExplicitType",
         "useExportsLast",
+        "useFontFamilyFallbacks",
         "useGoogleFontDisplay",
         "useGoogleFontPreconnect",
         "useGuardForIn",
+        "useImportAliases",
         "useImportRestrictions",
+        "useJsDocConsistency",
+        "useLogicalProperties",
+        "useMaxFunctionSize",
+        "useMaxParams",
         "useNamedOperation",
         "useNamingConvention",
+        "useNumericSeparators",
         "useParseIntRadix",
+        "usePlatformAgnosticPaths",
+        "usePreferDestructuring",
+        "usePreferObjectSpread",
+        "useReadonlyClassProperties",
+        "useShorthand",
+        "useSimplifiedBooleanReturn",
         "useSortedClasses",
         "useStrictMode",
+        "useStructuredClone",
         "useTrimStartEnd",
         "useValidAutocomplete",
+        "useValidGraphqlOperations",
+        "useVarFallbacks",
     ];
     const RECOMMENDED_RULES: &'static [&'static str] = &[
         "noDescendingSpecificity",
@@ -3571,23 +3867,23 @@ impl Nursery {
         "useStrictMode",
     ];
     const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[112]),
     ];
     const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
@@ -3646,6 +3942,68 @@ impl Nursery {
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[64]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[67]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[69]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[70]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[72]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[73]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[76]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[77]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[78]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[79]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[81]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[82]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[84]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[87]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[88]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[89]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[91]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[92]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[93]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[94]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[98]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[99]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[100]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[102]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[105]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[106]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[107]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[108]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[109]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[110]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[111]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[112]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[113]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[114]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[115]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[116]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[117]),
     ];
     #[doc = r" Retrieves the recommended rules"]
     pub(crate) fn is_recommended_true(&self) -> bool {
@@ -3662,568 +4020,1188 @@ impl Nursery {
     }
     pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut index_set = FxHashSet::default();
-        if let Some(rule) = self.no_common_js.as_ref() {
+        if let Some(rule) = self.max_nesting_depth.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.no_descending_specificity.as_ref() {
+        if let Some(rule) = self.no_arbitrary_z_index.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.no_document_cookie.as_ref() {
+        if let Some(rule) = self.no_await_in_loop.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
-        if let Some(rule) = self.no_document_import_in_page.as_ref() {
+        if let Some(rule) = self.no_circular_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
-        if let Some(rule) = self.no_duplicate_custom_properties.as_ref() {
+        if let Some(rule) = self.no_commented_out_code.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
             }
         }
-        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
+        if let Some(rule) = self.no_common_js.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_duplicate_properties.as_ref() {
+        if let Some(rule) = self.no_conditional_expect.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_duplicated_fields.as_ref() {
+        if let Some(rule) = self.no_custom_property_typos.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.no_dynamic_namespace_import_access.as_ref() {
+        if let Some(rule) = self.no_deep_relative_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.no_enum.as_ref() {
+        if let Some(rule) = self.no_deprecated_apis.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.no_exported_imports.as_ref() {
+        if let Some(rule) = self.no_deprecated_properties.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.no_global_dirname_filename.as_ref() {
+        if let Some(rule) = self.no_descending_specificity.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.no_head_element.as_ref() {
+        if let Some(rule) = self.no_document_cookie.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_head_import_in_document.as_ref() {
+        if let Some(rule) = self.no_document_import_in_page.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.no_img_element.as_ref() {
+        if let Some(rule) = self.no_duplicate_custom_properties.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_irregular_whitespace.as_ref() {
+        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_missing_var_function.as_ref() {
+        if let Some(rule) = self.no_duplicate_properties.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
-        if let Some(rule) = self.no_nested_ternary.as_ref() {
+        if let Some(rule) = self.no_duplicate_selectors_across_files.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
             }
         }
-        if let Some(rule) = self.no_octal_escape.as_ref() {
+        if let Some(rule) = self.no_duplicate_test_titles.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
             }
         }
-        if let Some(rule) = self.no_process_env.as_ref() {
+        if let Some(rule) = self.no_duplicated_fields.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
             }
         }
-        if let Some(rule) = self.no_process_global.as_ref() {
+        if let Some(rule) = self.no_dynamic_namespace_import_access.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
             }
         }
-        if let Some(rule) = self.no_restricted_imports.as_ref() {
+        if let Some(rule) = self.no_enum.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
             }
         }
-        if let Some(rule) = self.no_restricted_types.as_ref() {
+        if let Some(rule) = self.no_exported_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
             }
         }
-        if let Some(rule) = self.no_secrets.as_ref() {
+        if let Some(rule) = self.no_floating_promises.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
             }
         }
-        if let Some(rule) = self.no_static_element_interactions.as_ref() {
+        if let Some(rule) = self.no_global_dirname_filename.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
             }
         }
-        if let Some(rule) = self.no_substr.as_ref() {
+        if let Some(rule) = self.no_hardcoded_colors.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
             }
         }
-        if let Some(rule) = self.no_template_curly_in_string.as_ref() {
+        if let Some(rule) = self.no_hardcoded_user_facing_strings.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
             }
         }
-        if let Some(rule) = self.no_unknown_at_rule.as_ref() {
+        if let Some(rule) = self.no_head_element.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
             }
         }
-        if let Some(rule) = self.no_unknown_pseudo_class.as_ref() {
+        if let Some(rule) = self.no_head_import_in_document.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
             }
         }
-        if let Some(rule) = self.no_unknown_pseudo_element.as_ref() {
+        if let Some(rule) = self.no_high_specificity_selectors.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
             }
         }
-        if let Some(rule) = self.no_unknown_type_selector.as_ref() {
+        if let Some(rule) = self.no_id_selectors.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
             }
         }
-        if let Some(rule) = self.no_useless_escape_in_regex.as_ref() {
+        if let Some(rule) = self.no_img_element.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
             }
         }
-        if let Some(rule) = self.no_useless_string_raw.as_ref() {
+        if let Some(rule) = self.no_irregular_whitespace.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
             }
         }
-        if let Some(rule) = self.no_useless_undefined.as_ref() {
+        if let Some(rule) = self.no_leaked_event_listeners.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
             }
         }
-        if let Some(rule) = self.no_value_at_rule.as_ref() {
+        if let Some(rule) = self.no_low_contrast_color_pairs.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
             }
         }
-        if let Some(rule) = self.use_adjacent_overload_signatures.as_ref() {
+        if let Some(rule) = self.no_magic_numbers.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
             }
         }
-        if let Some(rule) = self.use_aria_props_supported_by_role.as_ref() {
+        if let Some(rule) = self.no_missing_var_function.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
             }
         }
-        if let Some(rule) = self.use_at_index.as_ref() {
+        if let Some(rule) = self.no_misused_promises.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
             }
         }
-        if let Some(rule) = self.use_collapsed_if.as_ref() {
+        if let Some(rule) = self.no_module_scope_side_effects.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
             }
         }
-        if let Some(rule) = self.use_component_export_only_modules.as_ref() {
+        if let Some(rule) = self.no_nested_ternary.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
             }
         }
-        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
+        if let Some(rule) = self.no_octal_escape.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
             }
         }
-        if let Some(rule) = self.use_consistent_member_accessibility.as_ref() {
+        if let Some(rule) = self.no_process_env.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
             }
         }
-        if let Some(rule) = self.use_deprecated_reason.as_ref() {
+        if let Some(rule) = self.no_process_global.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
             }
         }
-        if let Some(rule) = self.use_explicit_type.as_ref() {
+        if let Some(rule) = self.no_restricted_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
             }
         }
-        if let Some(rule) = self.use_exports_last.as_ref() {
+        if let Some(rule) = self.no_restricted_syntax.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
             }
         }
-        if let Some(rule) = self.use_google_font_display.as_ref() {
+        if let Some(rule) = self.no_restricted_types.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
             }
         }
-        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
+        if let Some(rule) = self.no_secrets.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
             }
         }
-        if let Some(rule) = self.use_guard_for_in.as_ref() {
+        if let Some(rule) = self.no_sql_injection.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.no_static_element_interactions.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
             }
         }
-        if let Some(rule) = self.use_named_operation.as_ref() {
+        if let Some(rule) = self.no_substr.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
             }
         }
-        if let Some(rule) = self.use_naming_convention.as_ref() {
+        if let Some(rule) = self.no_super_linear_regex.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
             }
         }
-        if let Some(rule) = self.use_parse_int_radix.as_ref() {
+        if let Some(rule) = self.no_sync_fs_in_async_contexts.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
             }
         }
-        if let Some(rule) = self.use_sorted_classes.as_ref() {
+        if let Some(rule) = self.no_template_curly_in_string.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
             }
         }
-        if let Some(rule) = self.use_strict_mode.as_ref() {
+        if let Some(rule) = self.no_todo_without_ticket.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
             }
         }
-        if let Some(rule) = self.use_trim_start_end.as_ref() {
+        if let Some(rule) = self.no_transition_all.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
             }
         }
-        if let Some(rule) = self.use_valid_autocomplete.as_ref() {
+        if let Some(rule) = self.no_unknown_animation_name.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
             }
         }
-        index_set
-    }
-    pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
-        let mut index_set = FxHashSet::default();
-        if let Some(rule) = self.no_common_js.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+        if let Some(rule) = self.no_unknown_at_rule.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]));
             }
         }
-        if let Some(rule) = self.no_descending_specificity.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+        if let Some(rule) = self.no_unknown_container_feature_name.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]));
             }
         }
-        if let Some(rule) = self.no_document_cookie.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+        if let Some(rule) = self.no_unknown_pseudo_class.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]));
             }
         }
-        if let Some(rule) = self.no_document_import_in_page.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+        if let Some(rule) = self.no_unknown_pseudo_element.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]));
             }
         }
-        if let Some(rule) = self.no_duplicate_custom_properties.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+        if let Some(rule) = self.no_unknown_type_selector.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]));
             }
         }
-        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+        if let Some(rule) = self.no_unnecessary_conditions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]));
             }
         }
-        if let Some(rule) = self.no_duplicate_properties.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+        if let Some(rule) = self.no_unsafe_type_assertions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]));
             }
         }
-        if let Some(rule) = self.no_duplicated_fields.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+        if let Some(rule) = self.no_unsanitized_dom_insertion.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]));
             }
         }
-        if let Some(rule) = self.no_dynamic_namespace_import_access.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+        if let Some(rule) = self.no_unstable_default_props.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[64]));
             }
         }
-        if let Some(rule) = self.no_enum.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+        if let Some(rule) = self.no_unused_custom_properties.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]));
             }
         }
-        if let Some(rule) = self.no_exported_imports.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+        if let Some(rule) = self.no_unused_exports.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]));
             }
         }
-        if let Some(rule) = self.no_global_dirname_filename.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
+        if let Some(rule) = self.no_unused_keyframes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[67]));
             }
         }
-        if let Some(rule) = self.no_head_element.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
+        if let Some(rule) = self.no_useless_escape_in_regex.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]));
             }
         }
-        if let Some(rule) = self.no_head_import_in_document.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
+        if let Some(rule) = self.no_useless_string_raw.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[69]));
             }
         }
-        if let Some(rule) = self.no_img_element.as_ref() {
+        if let Some(rule) = self.no_useless_undefined.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[70]));
+            }
+        }
+        if let Some(rule) = self.no_value_at_rule.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]));
+            }
+        }
+        if let Some(rule) = self.no_vendor_prefixes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[72]));
+            }
+        }
+        if let Some(rule) = self.use_adjacent_overload_signatures.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[73]));
+            }
+        }
+        if let Some(rule) = self.use_allowed_units.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]));
+            }
+        }
+        if let Some(rule) = self.use_aria_props_supported_by_role.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]));
+            }
+        }
+        if let Some(rule) = self.use_at_index.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[76]));
+            }
+        }
+        if let Some(rule) = self.use_browser_compat.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[77]));
+            }
+        }
+        if let Some(rule) = self.use_class_naming_convention.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[78]));
+            }
+        }
+        if let Some(rule) = self.use_collapsed_if.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[79]));
+            }
+        }
+        if let Some(rule) = self.use_component_export_only_modules.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_color_format.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[81]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[82]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_member_accessibility.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_test_naming.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[84]));
+            }
+        }
+        if let Some(rule) = self.use_deprecated_reason.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]));
+            }
+        }
+        if let Some(rule) = self.use_error_cause.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]));
+            }
+        }
+        if let Some(rule) = self.use_exhaustive_switch_cases.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[87]));
+            }
+        }
+        if let Some(rule) = self.use_explicit_resource_management.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[88]));
+            }
+        }
+        if let Some(rule) = self.use_explicit_type.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[89]));
+            }
+        }
+        if let Some(rule) = self.use_exports_last.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]));
+            }
+        }
+        if let Some(rule) = self.use_font_family_fallbacks.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[91]));
+            }
+        }
+        if let Some(rule) = self.use_google_font_display.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[92]));
+            }
+        }
+        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[93]));
+            }
+        }
+        if let Some(rule) = self.use_guard_for_in.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[94]));
+            }
+        }
+        if let Some(rule) = self.use_import_aliases.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]));
+            }
+        }
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]));
+            }
+        }
+        if let Some(rule) = self.use_js_doc_consistency.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]));
+            }
+        }
+        if let Some(rule) = self.use_logical_properties.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[98]));
+            }
+        }
+        if let Some(rule) = self.use_max_function_size.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[99]));
+            }
+        }
+        if let Some(rule) = self.use_max_params.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[100]));
+            }
+        }
+        if let Some(rule) = self.use_named_operation.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]));
+            }
+        }
+        if let Some(rule) = self.use_naming_convention.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[102]));
+            }
+        }
+        if let Some(rule) = self.use_numeric_separators.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]));
+            }
+        }
+        if let Some(rule) = self.use_parse_int_radix.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]));
+            }
+        }
+        if let Some(rule) = self.use_platform_agnostic_paths.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[105]));
+            }
+        }
+        if let Some(rule) = self.use_prefer_destructuring.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[106]));
+            }
+        }
+        if let Some(rule) = self.use_prefer_object_spread.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[107]));
+            }
+        }
+        if let Some(rule) = self.use_readonly_class_properties.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[108]));
+            }
+        }
+        if let Some(rule) = self.use_shorthand.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[109]));
+            }
+        }
+        if let Some(rule) = self.use_simplified_boolean_return.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[110]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_classes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[111]));
+            }
+        }
+        if let Some(rule) = self.use_strict_mode.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[112]));
+            }
+        }
+        if let Some(rule) = self.use_structured_clone.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[113]));
+            }
+        }
+        if let Some(rule) = self.use_trim_start_end.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[114]));
+            }
+        }
+        if let Some(rule) = self.use_valid_autocomplete.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[115]));
+            }
+        }
+        if let Some(rule) = self.use_valid_graphql_operations.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[116]));
+            }
+        }
+        if let Some(rule) = self.use_var_fallbacks.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[117]));
+            }
+        }
+        index_set
+    }
+    pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.max_nesting_depth.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.no_arbitrary_z_index.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.no_await_in_loop.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.no_circular_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.no_commented_out_code.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.no_common_js.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.no_conditional_expect.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.no_custom_property_typos.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.no_deep_relative_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
+        if let Some(rule) = self.no_deprecated_apis.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+            }
+        }
+        if let Some(rule) = self.no_deprecated_properties.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+            }
+        }
+        if let Some(rule) = self.no_descending_specificity.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
+            }
+        }
+        if let Some(rule) = self.no_document_cookie.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
+            }
+        }
+        if let Some(rule) = self.no_document_import_in_page.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_custom_properties.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_irregular_whitespace.as_ref() {
+        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_missing_var_function.as_ref() {
+        if let Some(rule) = self.no_duplicate_properties.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_selectors_across_files.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_test_titles.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
+            }
+        }
+        if let Some(rule) = self.no_duplicated_fields.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
+            }
+        }
+        if let Some(rule) = self.no_dynamic_namespace_import_access.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
+            }
+        }
+        if let Some(rule) = self.no_enum.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
+            }
+        }
+        if let Some(rule) = self.no_exported_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
+            }
+        }
+        if let Some(rule) = self.no_floating_promises.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
+            }
+        }
+        if let Some(rule) = self.no_global_dirname_filename.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+            }
+        }
+        if let Some(rule) = self.no_hardcoded_colors.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
+            }
+        }
+        if let Some(rule) = self.no_hardcoded_user_facing_strings.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
+            }
+        }
+        if let Some(rule) = self.no_head_element.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
+            }
+        }
+        if let Some(rule) = self.no_head_import_in_document.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
+            }
+        }
+        if let Some(rule) = self.no_high_specificity_selectors.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
+            }
+        }
+        if let Some(rule) = self.no_id_selectors.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
+            }
+        }
+        if let Some(rule) = self.no_img_element.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
+            }
+        }
+        if let Some(rule) = self.no_irregular_whitespace.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
+            }
+        }
+        if let Some(rule) = self.no_leaked_event_listeners.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
+            }
+        }
+        if let Some(rule) = self.no_low_contrast_color_pairs.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
+            }
+        }
+        if let Some(rule) = self.no_magic_numbers.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
+            }
+        }
+        if let Some(rule) = self.no_missing_var_function.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
+            }
+        }
+        if let Some(rule) = self.no_misused_promises.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
+            }
+        }
+        if let Some(rule) = self.no_module_scope_side_effects.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
+            }
+        }
+        if let Some(rule) = self.no_nested_ternary.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
+            }
+        }
+        if let Some(rule) = self.no_octal_escape.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
+            }
+        }
+        if let Some(rule) = self.no_process_env.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
+            }
+        }
+        if let Some(rule) = self.no_process_global.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
+            }
+        }
+        if let Some(rule) = self.no_restricted_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
+            }
+        }
+        if let Some(rule) = self.no_restricted_syntax.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
+            }
+        }
+        if let Some(rule) = self.no_restricted_types.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
+            }
+        }
+        if let Some(rule) = self.no_secrets.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
+            }
+        }
+        if let Some(rule) = self.no_sql_injection.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
+            }
+        }
+        if let Some(rule) = self.no_static_element_interactions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
+            }
+        }
+        if let Some(rule) = self.no_substr.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
+            }
+        }
+        if let Some(rule) = self.no_super_linear_regex.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
+            }
+        }
+        if let Some(rule) = self.no_sync_fs_in_async_contexts.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
+            }
+        }
+        if let Some(rule) = self.no_template_curly_in_string.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
+            }
+        }
+        if let Some(rule) = self.no_todo_without_ticket.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
+            }
+        }
+        if let Some(rule) = self.no_transition_all.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_animation_name.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_at_rule.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_container_feature_name.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_pseudo_class.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_pseudo_element.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_type_selector.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]));
+            }
+        }
+        if let Some(rule) = self.no_unnecessary_conditions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]));
+            }
+        }
+        if let Some(rule) = self.no_unsafe_type_assertions.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]));
             }
         }
-        if let Some(rule) = self.no_nested_ternary.as_ref() {
+        if let Some(rule) = self.no_unsanitized_dom_insertion.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]));
             }
         }
-        if let Some(rule) = self.no_octal_escape.as_ref() {
+        if let Some(rule) = self.no_unstable_default_props.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[64]));
             }
         }
-        if let Some(rule) = self.no_process_env.as_ref() {
+        if let Some(rule) = self.no_unused_custom_properties.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]));
             }
         }
-        if let Some(rule) = self.no_process_global.as_ref() {
+        if let Some(rule) = self.no_unused_exports.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]));
             }
         }
-        if let Some(rule) = self.no_restricted_imports.as_ref() {
+        if let Some(rule) = self.no_unused_keyframes.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[67]));
             }
         }
-        if let Some(rule) = self.no_restricted_types.as_ref() {
+        if let Some(rule) = self.no_useless_escape_in_regex.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]));
             }
         }
-        if let Some(rule) = self.no_secrets.as_ref() {
+        if let Some(rule) = self.no_useless_string_raw.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[69]));
             }
         }
-        if let Some(rule) = self.no_static_element_interactions.as_ref() {
+        if let Some(rule) = self.no_useless_undefined.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[70]));
             }
         }
-        if let Some(rule) = self.no_substr.as_ref() {
+        if let Some(rule) = self.no_value_at_rule.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]));
             }
         }
-        if let Some(rule) = self.no_template_curly_in_string.as_ref() {
+        if let Some(rule) = self.no_vendor_prefixes.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[72]));
             }
         }
-        if let Some(rule) = self.no_unknown_at_rule.as_ref() {
+        if let Some(rule) = self.use_adjacent_overload_signatures.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[73]));
             }
         }
-        if let Some(rule) = self.no_unknown_pseudo_class.as_ref() {
+        if let Some(rule) = self.use_allowed_units.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]));
             }
         }
-        if let Some(rule) = self.no_unknown_pseudo_element.as_ref() {
+        if let Some(rule) = self.use_aria_props_supported_by_role.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]));
             }
         }
-        if let Some(rule) = self.no_unknown_type_selector.as_ref() {
+        if let Some(rule) = self.use_at_index.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[76]));
             }
         }
-        if let Some(rule) = self.no_useless_escape_in_regex.as_ref() {
+        if let Some(rule) = self.use_browser_compat.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[77]));
             }
         }
-        if let Some(rule) = self.no_useless_string_raw.as_ref() {
+        if let Some(rule) = self.use_class_naming_convention.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[78]));
             }
         }
-        if let Some(rule) = self.no_useless_undefined.as_ref() {
+        if let Some(rule) = self.use_collapsed_if.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[79]));
             }
         }
-        if let Some(rule) = self.no_value_at_rule.as_ref() {
+        if let Some(rule) = self.use_component_export_only_modules.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]));
             }
         }
-        if let Some(rule) = self.use_adjacent_overload_signatures.as_ref() {
+        if let Some(rule) = self.use_consistent_color_format.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[81]));
             }
         }
-        if let Some(rule) = self.use_aria_props_supported_by_role.as_ref() {
+        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[82]));
             }
         }
-        if let Some(rule) = self.use_at_index.as_ref() {
+        if let Some(rule) = self.use_consistent_member_accessibility.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]));
             }
         }
-        if let Some(rule) = self.use_collapsed_if.as_ref() {
+        if let Some(rule) = self.use_consistent_test_naming.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[84]));
             }
         }
-        if let Some(rule) = self.use_component_export_only_modules.as_ref() {
+        if let Some(rule) = self.use_deprecated_reason.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]));
             }
         }
-        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
+        if let Some(rule) = self.use_error_cause.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]));
             }
         }
-        if let Some(rule) = self.use_consistent_member_accessibility.as_ref() {
+        if let Some(rule) = self.use_exhaustive_switch_cases.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[87]));
             }
         }
-        if let Some(rule) = self.use_deprecated_reason.as_ref() {
+        if let Some(rule) = self.use_explicit_resource_management.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[88]));
             }
         }
         if let Some(rule) = self.use_explicit_type.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[89]));
             }
         }
         if let Some(rule) = self.use_exports_last.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]));
+            }
+        }
+        if let Some(rule) = self.use_font_family_fallbacks.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[91]));
             }
         }
         if let Some(rule) = self.use_google_font_display.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[92]));
             }
         }
         if let Some(rule) = self.use_google_font_preconnect.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[93]));
             }
         }
         if let Some(rule) = self.use_guard_for_in.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[94]));
+            }
+        }
+        if let Some(rule) = self.use_import_aliases.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]));
             }
         }
         if let Some(rule) = self.use_import_restrictions.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]));
+            }
+        }
+        if let Some(rule) = self.use_js_doc_consistency.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]));
+            }
+        }
+        if let Some(rule) = self.use_logical_properties.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[98]));
+            }
+        }
+        if let Some(rule) = self.use_max_function_size.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[99]));
+            }
+        }
+        if let Some(rule) = self.use_max_params.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[100]));
             }
         }
         if let Some(rule) = self.use_named_operation.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]));
             }
         }
         if let Some(rule) = self.use_naming_convention.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[102]));
+            }
+        }
+        if let Some(rule) = self.use_numeric_separators.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]));
             }
         }
         if let Some(rule) = self.use_parse_int_radix.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]));
+            }
+        }
+        if let Some(rule) = self.use_platform_agnostic_paths.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[105]));
+            }
+        }
+        if let Some(rule) = self.use_prefer_destructuring.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[106]));
+            }
+        }
+        if let Some(rule) = self.use_prefer_object_spread.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[107]));
+            }
+        }
+        if let Some(rule) = self.use_readonly_class_properties.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[108]));
+            }
+        }
+        if let Some(rule) = self.use_shorthand.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[109]));
+            }
+        }
+        if let Some(rule) = self.use_simplified_boolean_return.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[110]));
             }
         }
         if let Some(rule) = self.use_sorted_classes.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[111]));
             }
         }
         if let Some(rule) = self.use_strict_mode.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[112]));
+            }
+        }
+        if let Some(rule) = self.use_structured_clone.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[113]));
             }
         }
         if let Some(rule) = self.use_trim_start_end.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[114]));
             }
         }
         if let Some(rule) = self.use_valid_autocomplete.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[115]));
+            }
+        }
+        if let Some(rule) = self.use_valid_graphql_operations.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[116]));
+            }
+        }
+        if let Some(rule) = self.use_var_fallbacks.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[117]));
             }
         }
         index_set
@@ -4262,10 +5240,50 @@ impl Nursery {
         rule_name: &str,
     ) -> Option<(RulePlainConfiguration, Option<RuleOptions>)> {
         match rule_name {
+            "maxNestingDepth" => self
+                .max_nesting_depth
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noArbitraryZIndex" => self
+                .no_arbitrary_z_index
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noAwaitInLoop" => self
+                .no_await_in_loop
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noCircularImports" => self
+                .no_circular_imports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noCommentedOutCode" => self
+                .no_commented_out_code
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noCommonJs" => self
                 .no_common_js
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noConditionalExpect" => self
+                .no_conditional_expect
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noCustomPropertyTypos" => self
+                .no_custom_property_typos
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDeepRelativeImports" => self
+                .no_deep_relative_imports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDeprecatedApis" => self
+                .no_deprecated_apis
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDeprecatedProperties" => self
+                .no_deprecated_properties
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noDescendingSpecificity" => self
                 .no_descending_specificity
                 .as_ref()
@@ -4290,6 +5308,14 @@ impl Nursery {
                 .no_duplicate_properties
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateSelectorsAcrossFiles" => self
+                .no_duplicate_selectors_across_files
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateTestTitles" => self
+                .no_duplicate_test_titles
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noDuplicatedFields" => self
                 .no_duplicated_fields
                 .as_ref()
@@ -4306,10 +5332,22 @@ impl Nursery {
                 .no_exported_imports
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noFloatingPromises" => self
+                .no_floating_promises
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noGlobalDirnameFilename" => self
                 .no_global_dirname_filename
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noHardcodedColors" => self
+                .no_hardcoded_colors
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noHardcodedUserFacingStrings" => self
+                .no_hardcoded_user_facing_strings
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noHeadElement" => self
                 .no_head_element
                 .as_ref()
@@ -4318,6 +5356,14 @@ impl Nursery {
                 .no_head_import_in_document
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noHighSpecificitySelectors" => self
+                .no_high_specificity_selectors
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noIdSelectors" => self
+                .no_id_selectors
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noImgElement" => self
                 .no_img_element
                 .as_ref()
@@ -4326,10 +5372,30 @@ impl Nursery {
                 .no_irregular_whitespace
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noLeakedEventListeners" => self
+                .no_leaked_event_listeners
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noLowContrastColorPairs" => self
+                .no_low_contrast_color_pairs
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noMagicNumbers" => self
+                .no_magic_numbers
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noMissingVarFunction" => self
                 .no_missing_var_function
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noMisusedPromises" => self
+                .no_misused_promises
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noModuleScopeSideEffects" => self
+                .no_module_scope_side_effects
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noNestedTernary" => self
                 .no_nested_ternary
                 .as_ref()
@@ -4350,6 +5416,10 @@ impl Nursery {
                 .no_restricted_imports
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noRestrictedSyntax" => self
+                .no_restricted_syntax
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noRestrictedTypes" => self
                 .no_restricted_types
                 .as_ref()
@@ -4358,6 +5428,10 @@ impl Nursery {
                 .no_secrets
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noSqlInjection" => self
+                .no_sql_injection
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noStaticElementInteractions" => self
                 .no_static_element_interactions
                 .as_ref()
@@ -4366,14 +5440,38 @@ impl Nursery {
                 .no_substr
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noSuperLinearRegex" => self
+                .no_super_linear_regex
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noSyncFsInAsyncContexts" => self
+                .no_sync_fs_in_async_contexts
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noTemplateCurlyInString" => self
                 .no_template_curly_in_string
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noTodoWithoutTicket" => self
+                .no_todo_without_ticket
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noTransitionAll" => self
+                .no_transition_all
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownAnimationName" => self
+                .no_unknown_animation_name
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUnknownAtRule" => self
                 .no_unknown_at_rule
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownContainerFeatureName" => self
+                .no_unknown_container_feature_name
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUnknownPseudoClass" => self
                 .no_unknown_pseudo_class
                 .as_ref()
@@ -4386,6 +5484,34 @@ impl Nursery {
                 .no_unknown_type_selector
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUnnecessaryConditions" => self
+                .no_unnecessary_conditions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnsafeTypeAssertions" => self
+                .no_unsafe_type_assertions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnsanitizedDomInsertion" => self
+                .no_unsanitized_dom_insertion
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnstableDefaultProps" => self
+                .no_unstable_default_props
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedCustomProperties" => self
+                .no_unused_custom_properties
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedExports" => self
+                .no_unused_exports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedKeyframes" => self
+                .no_unused_keyframes
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUselessEscapeInRegex" => self
                 .no_useless_escape_in_regex
                 .as_ref()
@@ -4402,10 +5528,18 @@ impl Nursery {
                 .no_value_at_rule
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noVendorPrefixes" => self
+                .no_vendor_prefixes
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useAdjacentOverloadSignatures" => self
                 .use_adjacent_overload_signatures
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useAllowedUnits" => self
+                .use_allowed_units
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useAriaPropsSupportedByRole" => self
                 .use_aria_props_supported_by_role
                 .as_ref()
@@ -4414,6 +5548,14 @@ impl Nursery {
                 .use_at_index
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useBrowserCompat" => self
+                .use_browser_compat
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useClassNamingConvention" => self
+                .use_class_naming_convention
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useCollapsedIf" => self
                 .use_collapsed_if
                 .as_ref()
@@ -4422,6 +5564,10 @@ impl Nursery {
                 .use_component_export_only_modules
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentColorFormat" => self
+                .use_consistent_color_format
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useConsistentCurlyBraces" => self
                 .use_consistent_curly_braces
                 .as_ref()
@@ -4430,10 +5576,26 @@ impl Nursery {
                 .use_consistent_member_accessibility
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentTestNaming" => self
+                .use_consistent_test_naming
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useDeprecatedReason" => self
                 .use_deprecated_reason
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useErrorCause" => self
+                .use_error_cause
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useExhaustiveSwitchCases" => self
+                .use_exhaustive_switch_cases
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useExplicitResourceManagement" => self
+                .use_explicit_resource_management
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useExplicitType" => self
                 .use_explicit_type
                 .as_ref()
@@ -4442,6 +5604,10 @@ impl Nursery {
                 .use_exports_last
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useFontFamilyFallbacks" => self
+                .use_font_family_fallbacks
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useGoogleFontDisplay" => self
                 .use_google_font_display
                 .as_ref()
@@ -4454,10 +5620,30 @@ impl Nursery {
                 .use_guard_for_in
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useImportAliases" => self
+                .use_import_aliases
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useImportRestrictions" => self
                 .use_import_restrictions
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useJsDocConsistency" => self
+                .use_js_doc_consistency
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useLogicalProperties" => self
+                .use_logical_properties
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useMaxFunctionSize" => self
+                .use_max_function_size
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useMaxParams" => self
+                .use_max_params
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useNamedOperation" => self
                 .use_named_operation
                 .as_ref()
@@ -4466,10 +5652,38 @@ impl Nursery {
                 .use_naming_convention
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useNumericSeparators" => self
+                .use_numeric_separators
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useParseIntRadix" => self
                 .use_parse_int_radix
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "usePlatformAgnosticPaths" => self
+                .use_platform_agnostic_paths
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "usePreferDestructuring" => self
+                .use_prefer_destructuring
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "usePreferObjectSpread" => self
+                .use_prefer_object_spread
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useReadonlyClassProperties" => self
+                .use_readonly_class_properties
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useShorthand" => self
+                .use_shorthand
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSimplifiedBooleanReturn" => self
+                .use_simplified_boolean_return
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useSortedClasses" => self
                 .use_sorted_classes
                 .as_ref()
@@ -4478,6 +5692,10 @@ impl Nursery {
                 .use_strict_mode
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useStructuredClone" => self
+                .use_structured_clone
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useTrimStartEnd" => self
                 .use_trim_start_end
                 .as_ref()
@@ -4486,6 +5704,14 @@ impl Nursery {
                 .use_valid_autocomplete
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useValidGraphqlOperations" => self
+                .use_valid_graphql_operations
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useVarFallbacks" => self
+                .use_var_fallbacks
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             _ => None,
         }
     }
@@ -6198,7 +7424,7 @@ pub struct Suspicious {
         Option<RuleFixConfiguration<biome_js_analyze::options::NoUnsafeNegation>>,
     #[doc = "Ensure async functions utilize await."]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub use_await: Option<RuleConfiguration<biome_js_analyze::options::UseAwait>>,
+    pub use_await: Option<RuleFixConfiguration<biome_js_analyze::options::UseAwait>>,
     #[doc = "Enforce default clauses in switch statements to be last"]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_default_switch_clause_last:
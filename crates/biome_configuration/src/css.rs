@@ -39,6 +39,18 @@ pub struct CssParser {
     /// Enables parsing of CSS Modules specific features.
     #[partial(bpaf(hide))]
     pub css_modules: bool,
+
+    /// Enables parsing of the `@custom-media` at-rule.
+    #[partial(bpaf(hide))]
+    pub custom_media: bool,
+
+    /// Enables parsing of the Tailwind CSS directives, such as `@tailwind` and `@apply`.
+    #[partial(bpaf(hide))]
+    pub tailwind_directives: bool,
+
+    /// Keeps the block of unknown at-rules as written instead of parsing it as CSS.
+    #[partial(bpaf(hide))]
+    pub raw_unknown_at_rules: bool,
 }
 
 /// Options that changes how the CSS formatter behaves
@@ -70,6 +82,42 @@ pub struct CssFormatter {
     /// The type of quotes used in CSS code. Defaults to double.
     #[partial(bpaf(long("css-formatter-quote-style"), argument("double|single"), optional))]
     pub quote_style: QuoteStyle,
+
+    /// Whether to print the rules and at-rules that contain a single declaration on a single line, such as `a { color: red; }`. Defaults to false.
+    #[partial(bpaf(
+        long("css-formatter-collapse-single-declaration-rules"),
+        argument("true|false"),
+        optional
+    ))]
+    pub collapse_single_declaration_rules: bool,
+
+    /// The maximum number of consecutive empty lines preserved between rules, at-rules, and declarations. Defaults to 1.
+    #[partial(bpaf(long("css-formatter-max-empty-lines"), argument("NUMBER"), optional))]
+    pub max_empty_lines: Option<u8>,
+
+    /// Whether to shorten the hex colors that have a short form, such as `#ffffff` to `#fff`. The hex colors are always printed in lowercase. Defaults to false.
+    #[partial(bpaf(
+        long("css-formatter-shorten-hex-colors"),
+        argument("true|false"),
+        optional
+    ))]
+    pub shorten_hex_colors: bool,
+
+    /// Whether to print the banner comments as written, without aligning or reflowing them. The banner comments are the comment at the start of the file, and the comments that start with `/*!`. Defaults to false.
+    #[partial(bpaf(
+        long("css-formatter-preserve-banner-comments"),
+        argument("true|false"),
+        optional
+    ))]
+    pub preserve_banner_comments: bool,
+
+    /// Whether to wrap the single-line block comments that don't fit in the line width over multiple lines. Defaults to false.
+    #[partial(bpaf(
+        long("css-formatter-reflow-comments"),
+        argument("true|false"),
+        optional
+    ))]
+    pub reflow_comments: bool,
 }
 
 impl Default for CssFormatter {
@@ -81,6 +129,11 @@ impl Default for CssFormatter {
             line_ending: Default::default(),
             line_width: Default::default(),
             quote_style: QuoteStyle::Double,
+            collapse_single_declaration_rules: false,
+            max_empty_lines: Default::default(),
+            shorten_hex_colors: false,
+            preserve_banner_comments: false,
+            reflow_comments: false,
         }
     }
 }
@@ -94,6 +147,13 @@ impl PartialCssFormatter {
             line_ending: self.line_ending,
             line_width: self.line_width,
             quote_style: self.quote_style.unwrap_or_default(),
+            collapse_single_declaration_rules: self
+                .collapse_single_declaration_rules
+                .unwrap_or_default(),
+            max_empty_lines: self.max_empty_lines,
+            shorten_hex_colors: self.shorten_hex_colors.unwrap_or_default(),
+            preserve_banner_comments: self.preserve_banner_comments.unwrap_or_default(),
+            reflow_comments: self.reflow_comments.unwrap_or_default(),
         }
     }
 }
@@ -144,4 +204,9 @@ fn default_css() {
     assert_eq!(css_configuration.line_ending, None);
     assert_eq!(css_configuration.line_width, None);
     assert_eq!(css_configuration.quote_style, QuoteStyle::Double);
+    assert!(!css_configuration.collapse_single_declaration_rules);
+    assert_eq!(css_configuration.max_empty_lines, None);
+    assert!(!css_configuration.shorten_hex_colors);
+    assert!(!css_configuration.preserve_banner_comments);
+    assert!(!css_configuration.reflow_comments);
 }
@@ -0,0 +1,636 @@
+use crate::utils::{get_longhand_sub_properties, get_reset_to_initial_properties, vender_prefix};
+use crate::CssRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, Ast, Rule, RuleAction,
+    SourceActionKind,
+};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssProperty, CssDeclarationBlock, CssDeclarationOrRuleBlock,
+    CssDeclarationWithSemicolon, CssLanguage, CssSyntaxNode, CssSyntaxToken, T,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, SyntaxTriviaPiece};
+use biome_string_case::StrLikeExtension;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+declare_source_rule! {
+    /// Sort the declarations of a block.
+    ///
+    /// The declarations are sorted according to the `strategy` option.
+    /// Custom properties are placed before the other declarations,
+    /// and the declarations separated by a nested rule or at-rule are sorted independently.
+    ///
+    /// The action never changes the value of a property: two declarations that set the same property,
+    /// such as `margin` and `margin-top`, or `margin-left` and `margin-inline-start`, keep their relative order.
+    /// The comments attached to a declaration are moved with it.
+    ///
+    /// ## Examples
+    ///
+    /// ```css,expect_diff
+    /// a {
+    ///   color: red;
+    ///   /* The background of the link */
+    ///   background: blue;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `strategy`
+    ///
+    /// The order of the declarations:
+    /// - `alphabetical`: the declarations are sorted by property name, a vendor-prefixed property is placed before the standard one. This is the default.
+    /// - `concentric`: the properties are sorted from the outside of the box to its inside, following [Concentric CSS](https://github.com/brandon-rhodes/Concentric-CSS).
+    /// - `recess`: the properties are sorted following the order of [RECESS](https://github.com/twitter/recess).
+    /// - `smacss`: the properties are grouped by box, border, background, text, and other properties, following [SMACSS](https://smacss.com/book/formatting/).
+    ///
+    /// With the `concentric`, `recess`, and `smacss` strategies, the properties that aren't part of the order are placed last.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "strategy": "concentric"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseSortedProperties {
+        version: "next",
+        name: "useSortedProperties",
+        language: "css",
+    }
+}
+
+declare_node_union! {
+    pub AnyCssDeclarationBlockLike = CssDeclarationOrRuleBlock | CssDeclarationBlock
+}
+
+impl AnyCssDeclarationBlockLike {
+    fn items(&self) -> impl Iterator<Item = CssSyntaxNode> {
+        match self {
+            Self::CssDeclarationOrRuleBlock(block) => block.items().syntax().children(),
+            Self::CssDeclarationBlock(block) => block.declarations().syntax().children(),
+        }
+    }
+}
+
+impl Rule for UseSortedProperties {
+    type Query = Ast<AnyCssDeclarationBlockLike>;
+    type State = DeclarationGroup;
+    type Signals = Box<[Self::State]>;
+    type Options = Box<UseSortedPropertiesOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let strategy = ctx.options().strategy;
+        let mut groups = Vec::new();
+        let mut current_group = DeclarationGroup::default();
+        for item in ctx.query().items() {
+            match CssDeclarationWithSemicolon::cast(item).and_then(SortableDeclaration::new) {
+                Some(declaration) => current_group.declarations.push(declaration),
+                // Nested rules, at-rules, and declarations that can't be sorted end the group
+                None => groups.push(std::mem::take(&mut current_group)),
+            }
+        }
+        groups.push(current_group);
+        groups
+            .into_iter()
+            .filter_map(|mut group| {
+                group.order = sorted_order(&group.declarations, strategy);
+                (!group.is_sorted()).then_some(group)
+            })
+            .collect()
+    }
+
+    fn action(ctx: &RuleContext<Self>, group: &Self::State) -> Option<CssRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let last_position = group.order.len().saturating_sub(1);
+        for (position, (declaration, &sorted_index)) in
+            group.declarations.iter().zip(&group.order).enumerate()
+        {
+            if position == sorted_index {
+                continue;
+            }
+            let mut sorted = group.declarations[sorted_index].node.clone();
+            // The last declaration of a block may not have a semicolon
+            if position != last_position && sorted.semicolon_token().is_none() {
+                let trailing_trivia = sorted
+                    .syntax()
+                    .last_token()
+                    .map(|token| token.trailing_trivia().pieces().collect::<Vec<_>>())
+                    .unwrap_or_default();
+                sorted = sorted.trim_trailing_trivia()?.with_semicolon_token(Some(
+                    CssSyntaxToken::new_detached(T![;], ";", [], [])
+                        .with_trailing_trivia_pieces(trailing_trivia),
+                ));
+            }
+            // The comments before a declaration move with it,
+            // the blank lines and the indentation before them stay in place
+            let mut trivia = leading_trivia(&declaration.node)
+                .take_while(|piece| !piece.is_comments())
+                .collect::<Vec<_>>();
+            trivia.extend(leading_trivia(&sorted).skip_while(|piece| !piece.is_comments()));
+            let sorted = sorted.with_leading_trivia_pieces(trivia)?;
+            mutation.replace_node_discard_trivia(declaration.node.clone(), sorted);
+        }
+
+        Some(RuleAction::new(
+            rule_action_category!(),
+            Applicability::Always,
+            markup! { "Sort the declarations of the block." },
+            mutation,
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseSortedPropertiesOptions {
+    /// The order of the declarations.
+    pub strategy: SortStrategy,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SortStrategy {
+    /// Sort the properties by name.
+    #[default]
+    Alphabetical,
+    /// Sort the properties from the outside of the box to its inside.
+    Concentric,
+    /// Sort the properties following the order of RECESS.
+    Recess,
+    /// Group the properties by box, border, background, text, and other properties.
+    Smacss,
+}
+
+/// A run of declarations that aren't separated by a nested rule or an at-rule.
+#[derive(Debug, Default)]
+pub struct DeclarationGroup {
+    declarations: Vec<SortableDeclaration>,
+    /// The index of the declaration to place at each position.
+    order: Vec<usize>,
+}
+
+impl DeclarationGroup {
+    fn is_sorted(&self) -> bool {
+        self.order
+            .iter()
+            .enumerate()
+            .all(|(position, &index)| position == index)
+    }
+}
+
+#[derive(Debug)]
+struct SortableDeclaration {
+    node: CssDeclarationWithSemicolon,
+    /// The name of the property, in lowercase unless it's a custom property.
+    name: Box<str>,
+}
+
+impl SortableDeclaration {
+    fn new(node: CssDeclarationWithSemicolon) -> Option<Self> {
+        let AnyCssProperty::CssGenericProperty(property) =
+            node.declaration().ok()?.property().ok()?
+        else {
+            return None;
+        };
+        let name = match property.name().ok()? {
+            AnyCssDeclarationName::CssDashedIdentifier(name) => {
+                name.value_token().ok()?.text_trimmed().into()
+            }
+            AnyCssDeclarationName::CssIdentifier(name) => name
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                .to_ascii_lowercase_cow()
+                .into(),
+        };
+        Some(Self { node, name })
+    }
+
+    fn is_custom_property(&self) -> bool {
+        self.name.starts_with("--")
+    }
+
+    /// The name of the property without its vendor prefix.
+    fn standard_name(&self) -> &str {
+        if self.is_custom_property() {
+            return &self.name;
+        }
+        &self.name[vender_prefix(&self.name).len()..]
+    }
+
+    fn sort_key(&self, strategy: SortStrategy) -> (usize, &str, bool) {
+        if self.is_custom_property() {
+            return match strategy {
+                SortStrategy::Alphabetical => (0, &self.name, true),
+                _ => (0, "", true),
+            };
+        }
+        let name = self.standard_name();
+        let order = match strategy {
+            // Prefixed properties go before the standard one
+            SortStrategy::Alphabetical => return (1, name, name.len() == self.name.len()),
+            SortStrategy::Concentric => CONCENTRIC_ORDER,
+            SortStrategy::Recess => RECESS_ORDER,
+            SortStrategy::Smacss => SMACSS_ORDER,
+        };
+        // The most specific entry wins, e.g. `margin-top` over `margin`
+        let rank = order
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| is_same_or_sub_property(name, entry))
+            .max_by_key(|(_, entry)| entry.len())
+            .map_or(order.len(), |(index, _)| index);
+        (1 + rank, "", true)
+    }
+
+    /// Returns `true` if swapping the two declarations could change the value of a property.
+    fn conflicts_with(&self, other: &Self) -> bool {
+        if self.is_custom_property() || other.is_custom_property() {
+            return self.name == other.name;
+        }
+        let (a, b) = (self.standard_name(), other.standard_name());
+        a == b
+            || a == "all"
+            || b == "all"
+            || is_same_or_sub_property(a, b)
+            || is_same_or_sub_property(b, a)
+            || is_longhand_of(a, b)
+            || is_longhand_of(b, a)
+            || box_family(a).is_some_and(|family| {
+                box_family(b) == Some(family) && (is_logical(a) || is_logical(b))
+            })
+    }
+}
+
+fn leading_trivia(
+    declaration: &CssDeclarationWithSemicolon,
+) -> impl Iterator<Item = SyntaxTriviaPiece<CssLanguage>> {
+    declaration
+        .syntax()
+        .first_token()
+        .into_iter()
+        .flat_map(|token| token.leading_trivia().pieces())
+}
+
+/// Returns the index of the declaration to place at each position.
+///
+/// This is an insertion sort that never moves a declaration past a conflicting one.
+fn sorted_order(declarations: &[SortableDeclaration], strategy: SortStrategy) -> Vec<usize> {
+    let mut order: Vec<usize> = Vec::with_capacity(declarations.len());
+    for (index, declaration) in declarations.iter().enumerate() {
+        let key = declaration.sort_key(strategy);
+        let mut position = order.len();
+        while position > 0 {
+            let previous = &declarations[order[position - 1]];
+            if previous.sort_key(strategy) <= key || previous.conflicts_with(declaration) {
+                break;
+            }
+            position -= 1;
+        }
+        order.insert(position, index);
+    }
+    order
+}
+
+/// Returns `true` if `name` is `property`, or starts with `property` followed by a dash,
+/// e.g. `border-top-color` and `border`.
+fn is_same_or_sub_property(name: &str, property: &str) -> bool {
+    name.strip_prefix(property)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+}
+
+/// Returns `true` if the shorthand `shorthand` sets the property `longhand`.
+fn is_longhand_of(longhand: &str, shorthand: &str) -> bool {
+    get_reset_to_initial_properties(shorthand).contains(&longhand)
+        || get_longhand_sub_properties(shorthand)
+            .iter()
+            .any(|property| *property == longhand || is_longhand_of(longhand, property))
+}
+
+/// Returns the family of the properties that can set the same side of a box,
+/// e.g. `margin-left` and `margin-inline-start` in a left-to-right document.
+///
+/// A physical property and a logical property of the same family can set the same side
+/// depending on the writing mode.
+fn box_family(name: &str) -> Option<&'static str> {
+    const FAMILIES: [&str; 8] = [
+        "border",
+        "inset",
+        "margin",
+        "overflow",
+        "overscroll-behavior",
+        "padding",
+        "scroll-margin",
+        "scroll-padding",
+    ];
+    match name.trim_start_matches("min-").trim_start_matches("max-") {
+        "top" | "right" | "bottom" | "left" => Some("inset"),
+        "width" | "height" | "inline-size" | "block-size" => Some("size"),
+        name => FAMILIES
+            .into_iter()
+            .find(|family| is_same_or_sub_property(name, family)),
+    }
+}
+
+/// Returns `true` if the property depends on the writing mode, e.g. `inline-size`.
+fn is_logical(name: &str) -> bool {
+    name.split('-')
+        .any(|part| matches!(part, "block" | "inline" | "start" | "end"))
+}
+
+/// The order of [Concentric CSS](https://github.com/brandon-rhodes/Concentric-CSS/blob/master/style.css).
+///
+/// An entry also matches the properties that start with it, e.g. `margin` matches `margin-block-start`.
+const CONCENTRIC_ORDER: &[&str] = &[
+    "all",
+    "display",
+    "flex",
+    "grid",
+    "gap",
+    "order",
+    "align",
+    "justify",
+    "place",
+    "position",
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "inset",
+    "columns",
+    "column",
+    "float",
+    "clear",
+    "transform",
+    "translate",
+    "rotate",
+    "scale",
+    "transition",
+    "animation",
+    "visibility",
+    "opacity",
+    "z-index",
+    "margin",
+    "margin-top",
+    "margin-right",
+    "margin-bottom",
+    "margin-left",
+    "margin-block",
+    "margin-inline",
+    "outline",
+    "border",
+    "border-top",
+    "border-right",
+    "border-bottom",
+    "border-left",
+    "border-block",
+    "border-inline",
+    "border-width",
+    "border-style",
+    "border-color",
+    "border-radius",
+    "border-top-left-radius",
+    "border-top-right-radius",
+    "border-bottom-right-radius",
+    "border-bottom-left-radius",
+    "border-image",
+    "box-shadow",
+    "background",
+    "cursor",
+    "padding",
+    "padding-top",
+    "padding-right",
+    "padding-bottom",
+    "padding-left",
+    "padding-block",
+    "padding-inline",
+    "box-sizing",
+    "width",
+    "min-width",
+    "max-width",
+    "height",
+    "min-height",
+    "max-height",
+    "inline-size",
+    "block-size",
+    "aspect-ratio",
+    "overflow",
+    "list-style",
+    "caption-side",
+    "table-layout",
+    "border-collapse",
+    "border-spacing",
+    "empty-cells",
+    "vertical-align",
+    "text-align",
+    "text-indent",
+    "text-transform",
+    "text-decoration",
+    "text-rendering",
+    "text-shadow",
+    "text-overflow",
+    "line-height",
+    "word-spacing",
+    "letter-spacing",
+    "white-space",
+    "word-break",
+    "overflow-wrap",
+    "color",
+    "font",
+    "font-family",
+    "font-size",
+    "font-weight",
+    "font-style",
+    "font-variant",
+    "content",
+    "quotes",
+];
+
+/// The order of [RECESS](https://github.com/twitter/recess/blob/master/lib/lint/strict-property-order.js).
+///
+/// An entry also matches the properties that start with it, e.g. `margin` matches `margin-block-start`.
+const RECESS_ORDER: &[&str] = &[
+    "all",
+    "position",
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "inset",
+    "z-index",
+    "box-sizing",
+    "display",
+    "flex",
+    "flex-flow",
+    "flex-direction",
+    "flex-wrap",
+    "flex-grow",
+    "flex-shrink",
+    "flex-basis",
+    "grid",
+    "gap",
+    "align",
+    "justify",
+    "place",
+    "order",
+    "float",
+    "clear",
+    "width",
+    "min-width",
+    "max-width",
+    "height",
+    "min-height",
+    "max-height",
+    "inline-size",
+    "block-size",
+    "aspect-ratio",
+    "margin",
+    "padding",
+    "overflow",
+    "clip",
+    "columns",
+    "column",
+    "table-layout",
+    "empty-cells",
+    "caption-side",
+    "border-spacing",
+    "border-collapse",
+    "list-style",
+    "font",
+    "font-family",
+    "font-size",
+    "font-style",
+    "font-weight",
+    "font-variant",
+    "line-height",
+    "text-align",
+    "text-decoration",
+    "text-indent",
+    "text-overflow",
+    "text-shadow",
+    "text-transform",
+    "white-space",
+    "word-break",
+    "word-spacing",
+    "word-wrap",
+    "overflow-wrap",
+    "letter-spacing",
+    "vertical-align",
+    "color",
+    "background",
+    "border",
+    "border-top",
+    "border-right",
+    "border-bottom",
+    "border-left",
+    "border-block",
+    "border-inline",
+    "border-width",
+    "border-style",
+    "border-color",
+    "border-radius",
+    "border-image",
+    "outline",
+    "box-shadow",
+    "opacity",
+    "visibility",
+    "cursor",
+    "content",
+    "quotes",
+    "transform",
+    "translate",
+    "rotate",
+    "scale",
+    "transition",
+    "animation",
+];
+
+/// The groups of [SMACSS](https://smacss.com/book/formatting/): box, border, background, text, and other properties.
+///
+/// An entry also matches the properties that start with it, e.g. `margin` matches `margin-block-start`.
+const SMACSS_ORDER: &[&str] = &[
+    // Box
+    "all",
+    "display",
+    "position",
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "inset",
+    "z-index",
+    "box-sizing",
+    "flex",
+    "grid",
+    "gap",
+    "align",
+    "justify",
+    "place",
+    "order",
+    "float",
+    "clear",
+    "width",
+    "min-width",
+    "max-width",
+    "height",
+    "min-height",
+    "max-height",
+    "inline-size",
+    "block-size",
+    "aspect-ratio",
+    "margin",
+    "padding",
+    "overflow",
+    "columns",
+    "column",
+    "table-layout",
+    "vertical-align",
+    // Border
+    "border",
+    "border-top",
+    "border-right",
+    "border-bottom",
+    "border-left",
+    "border-block",
+    "border-inline",
+    "border-width",
+    "border-style",
+    "border-color",
+    "border-radius",
+    "border-image",
+    "outline",
+    "box-shadow",
+    // Background
+    "background",
+    // Text
+    "color",
+    "font",
+    "line-height",
+    "text",
+    "letter-spacing",
+    "word-spacing",
+    "white-space",
+    "word-break",
+    "word-wrap",
+    "overflow-wrap",
+    "list-style",
+    "content",
+    "quotes",
+    // Other
+    "opacity",
+    "visibility",
+    "cursor",
+    "transform",
+    "translate",
+    "rotate",
+    "scale",
+    "transition",
+    "animation",
+];
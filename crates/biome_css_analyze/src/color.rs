@@ -0,0 +1,629 @@
+//! Parsing, conversion, and serialization of the colors written in stylesheets.
+//!
+//! Colors are converted through the sRGB color space. The conversions between sRGB and OKLCH
+//! follow the [reference implementation of OKLab](https://bottosson.github.io/posts/oklab/).
+
+use crate::keywords::NAMED_COLORS;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssFunction, AnyCssGenericComponentValue, AnyCssValue, CssColor,
+    CssFunction, CssGenericProperty, CssIdentifier, CssRegularDimension, CssSyntaxKind, T,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, Direction};
+use biome_string_case::StrLikeExtension;
+use std::fmt::{self, Display};
+
+/// The difference tolerated between a channel and the bounds of the sRGB gamut,
+/// which absorbs the rounding errors of the conversions.
+const GAMUT_TOLERANCE: f64 = 0.5 / 255.0;
+
+/// A color in the sRGB color space.
+///
+/// The channels are between 0 and 1 for the colors of the sRGB gamut,
+/// and may be outside of this range for the colors of wider gamuts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Color {
+    red: f64,
+    green: f64,
+    blue: f64,
+    alpha: f64,
+}
+
+/// The syntax of a color in a given format.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ColorSyntax {
+    /// The digits of a hexadecimal color, without the leading `#`.
+    Hex(String),
+    Function(ColorFunction),
+}
+
+/// A color function such as `rgb(255 0 0 / 0.5)`, written with the space-separated syntax.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ColorFunction {
+    pub(crate) name: &'static str,
+    pub(crate) channels: [ColorChannel; 3],
+    pub(crate) alpha: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ColorChannel {
+    Number(String),
+    /// A percentage, without the `%` sign.
+    Percentage(String),
+}
+
+impl Color {
+    /// Returns the color of a named color such as `rebeccapurple`.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase_cow();
+        let index = NAMED_COLORS
+            .binary_search_by(|(color, _)| (*color).cmp(&name))
+            .ok()?;
+        let [red, green, blue] = NAMED_COLORS[index].1;
+        Some(Self::from_rgb8(red, green, blue, 255))
+    }
+
+    /// Returns the color of the digits of a hexadecimal color, e.g. `ff0000` in `#ff0000`.
+    pub(crate) fn from_hex(digits: &str) -> Option<Self> {
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |index: usize, size: usize| {
+            let value = u8::from_str_radix(&digits[index * size..(index + 1) * size], 16).ok()?;
+            Some(if size == 1 { value * 17 } else { value })
+        };
+        let size = match digits.len() {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            _ => return None,
+        };
+        let alpha = if digits.len() % 4 == 0 {
+            channel(3, size)?
+        } else {
+            255
+        };
+        Some(Self::from_rgb8(
+            channel(0, size)?,
+            channel(1, size)?,
+            channel(2, size)?,
+            alpha,
+        ))
+    }
+
+    /// Returns the color of a `rgb()`, `rgba()`, `hsl()`, `hsla()`, or `oklch()` function.
+    ///
+    /// Returns `None` for other functions, and for functions whose arguments aren't plain numbers,
+    /// such as `var()` references, the `none` keyword, or relative colors.
+    pub(crate) fn from_function(function: &CssFunction) -> Option<Self> {
+        let name = function.name().ok()?.value_token().ok()?;
+        let name = name.text_trimmed().to_ascii_lowercase_cow();
+        let ColorArguments { channels, alpha } = ColorArguments::parse(function)?;
+        let [first, second, third] = channels;
+        let mut color = match name.as_ref() {
+            "rgb" | "rgba" => Self {
+                red: first.rgb_channel()?,
+                green: second.rgb_channel()?,
+                blue: third.rgb_channel()?,
+                alpha: 1.0,
+            },
+            "hsl" | "hsla" => Self::from_hsl(
+                first.angle()?,
+                second.percentage_or_number()?,
+                third.percentage_or_number()?,
+            ),
+            "oklch" => {
+                Self::from_oklch(first.fraction(1.0)?, second.fraction(0.4)?, third.angle()?)
+            }
+            _ => return None,
+        };
+        if let Some(alpha) = alpha {
+            color.alpha = alpha.fraction(1.0)?.clamp(0.0, 1.0);
+        }
+        Some(color)
+    }
+
+    fn from_rgb8(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self {
+            red: f64::from(red) / 255.0,
+            green: f64::from(green) / 255.0,
+            blue: f64::from(blue) / 255.0,
+            alpha: f64::from(alpha) / 255.0,
+        }
+    }
+
+    /// `hue` is in degrees, `saturation` and `lightness` are between 0 and 1.
+    fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+        let amplitude = saturation * lightness.min(1.0 - lightness);
+        let channel = |offset: f64| {
+            let k = (offset + hue / 30.0).rem_euclid(12.0);
+            lightness - amplitude * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        Self {
+            red: channel(0.0),
+            green: channel(8.0),
+            blue: channel(4.0),
+            alpha: 1.0,
+        }
+    }
+
+    /// `hue` is in degrees, `lightness` is between 0 and 1.
+    fn from_oklch(lightness: f64, chroma: f64, hue: f64) -> Self {
+        let chroma = chroma.max(0.0);
+        let (sin, cos) = hue.to_radians().sin_cos();
+        let (a, b) = (chroma * cos, chroma * sin);
+        let l = (lightness + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+        let m = (lightness - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+        let s = (lightness - 0.089_484_177_5 * a - 1.291_485_548_0 * b).powi(3);
+        Self {
+            red: to_gamma(4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s),
+            green: to_gamma(-1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s),
+            blue: to_gamma(-0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s),
+            alpha: 1.0,
+        }
+    }
+
+    /// Returns `true` if the color can be written in the sRGB formats, e.g. with a hexadecimal color.
+    pub(crate) fn is_in_srgb_gamut(&self) -> bool {
+        [self.red, self.green, self.blue]
+            .into_iter()
+            .all(|channel| (-GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE).contains(&channel))
+    }
+
+    /// Returns the channels clamped to the sRGB gamut.
+    fn srgb_channels(&self) -> [f64; 3] {
+        [self.red, self.green, self.blue].map(|channel| channel.clamp(0.0, 1.0))
+    }
+
+    /// Returns the color written as a lowercase hexadecimal color with 6 digits,
+    /// or 8 digits for translucent colors.
+    pub(crate) fn to_hex(self) -> Option<ColorSyntax> {
+        if !self.is_in_srgb_gamut() {
+            return None;
+        }
+        let mut digits = String::with_capacity(8);
+        for channel in self.srgb_channels() {
+            digits.push_str(&format!("{:02x}", (channel * 255.0).round() as u8));
+        }
+        let alpha = (self.alpha * 255.0).round() as u8;
+        if alpha != 255 {
+            digits.push_str(&format!("{alpha:02x}"));
+        }
+        Some(ColorSyntax::Hex(digits))
+    }
+
+    pub(crate) fn to_rgb(self) -> Option<ColorSyntax> {
+        if !self.is_in_srgb_gamut() {
+            return None;
+        }
+        Some(ColorSyntax::Function(ColorFunction {
+            name: "rgb",
+            channels: self
+                .srgb_channels()
+                .map(|channel| ColorChannel::Number(format_number(channel * 255.0, 2))),
+            alpha: self.alpha_text(),
+        }))
+    }
+
+    pub(crate) fn to_hsl(self) -> Option<ColorSyntax> {
+        if !self.is_in_srgb_gamut() {
+            return None;
+        }
+        let [red, green, blue] = self.srgb_channels();
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let delta = max - min;
+        let lightness = (max + min) / 2.0;
+        let (hue, saturation) = if delta == 0.0 {
+            (0.0, 0.0)
+        } else {
+            let hue = if max == red {
+                ((green - blue) / delta).rem_euclid(6.0)
+            } else if max == green {
+                (blue - red) / delta + 2.0
+            } else {
+                (red - green) / delta + 4.0
+            };
+            (hue * 60.0, delta / (1.0 - (2.0 * lightness - 1.0).abs()))
+        };
+        Some(ColorSyntax::Function(ColorFunction {
+            name: "hsl",
+            channels: [
+                ColorChannel::Number(format_number(hue, 2)),
+                ColorChannel::Percentage(format_number(saturation * 100.0, 2)),
+                ColorChannel::Percentage(format_number(lightness * 100.0, 2)),
+            ],
+            alpha: self.alpha_text(),
+        }))
+    }
+
+    pub(crate) fn to_oklch(self) -> ColorSyntax {
+        let [red, green, blue] = [self.red, self.green, self.blue].map(to_linear);
+        let l = (0.412_221_470_8 * red + 0.536_332_536_3 * green + 0.051_445_992_9 * blue).cbrt();
+        let m = (0.211_903_498_2 * red + 0.680_699_545_1 * green + 0.107_396_956_6 * blue).cbrt();
+        let s = (0.088_302_461_9 * red + 0.281_718_837_6 * green + 0.629_978_700_5 * blue).cbrt();
+        let lightness = 0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s;
+        let a = 1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s;
+        let b = 0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s;
+        let chroma = a.hypot(b);
+        // The hue of achromatic colors is powerless
+        let hue = if chroma < 1e-4 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        };
+        ColorSyntax::Function(ColorFunction {
+            name: "oklch",
+            channels: [
+                ColorChannel::Percentage(format_number(lightness * 100.0, 2)),
+                ColorChannel::Number(format_number(if chroma < 1e-4 { 0.0 } else { chroma }, 4)),
+                ColorChannel::Number(format_number(hue, 2)),
+            ],
+            alpha: self.alpha_text(),
+        })
+    }
+
+    /// Returns `true` if the color is fully opaque.
+    pub(crate) fn is_opaque(&self) -> bool {
+        self.alpha >= 1.0
+    }
+
+    /// Returns the color seen when the color is painted over `backdrop`.
+    pub(crate) fn over(self, backdrop: Self) -> Self {
+        let blend =
+            |channel: f64, backdrop: f64| channel * self.alpha + backdrop * (1.0 - self.alpha);
+        let [red, green, blue] = self.srgb_channels();
+        let [backdrop_red, backdrop_green, backdrop_blue] = backdrop.srgb_channels();
+        Self {
+            red: blend(red, backdrop_red),
+            green: blend(green, backdrop_green),
+            blue: blend(blue, backdrop_blue),
+            alpha: 1.0,
+        }
+    }
+
+    /// Returns the [relative luminance](https://www.w3.org/TR/WCAG22/#dfn-relative-luminance) of the color,
+    /// between 0 for black and 1 for white.
+    pub(crate) fn relative_luminance(&self) -> f64 {
+        let [red, green, blue] = self.srgb_channels().map(to_linear);
+        0.2126 * red + 0.7152 * green + 0.0722 * blue
+    }
+
+    /// Returns the [contrast ratio](https://www.w3.org/TR/WCAG22/#dfn-contrast-ratio) between two opaque colors,
+    /// between 1 and 21.
+    pub(crate) fn contrast_ratio(&self, other: &Self) -> f64 {
+        let luminance = self.relative_luminance();
+        let other_luminance = other.relative_luminance();
+        (luminance.max(other_luminance) + 0.05) / (luminance.min(other_luminance) + 0.05)
+    }
+
+    fn alpha_text(&self) -> Option<String> {
+        (self.alpha < 1.0).then(|| format_number(self.alpha, 3))
+    }
+}
+
+declare_node_union! {
+    /// A value that can be a color: a hexadecimal color, a color function, or a named color.
+    pub AnyColorValue = CssColor | CssFunction | CssIdentifier
+}
+
+/// Returns `true` if `node` is an argument of a color function, e.g. in a relative color.
+pub(crate) fn is_in_color_function(node: &AnyColorValue) -> bool {
+    node.syntax()
+        .ancestors()
+        .skip(1)
+        .filter_map(CssFunction::cast)
+        .any(|function| is_color_function(&function))
+}
+
+/// Returns `true` if `function` creates a color from its channels, such as `rgb()` or `oklch()`.
+pub(crate) fn is_color_function(function: &CssFunction) -> bool {
+    function
+        .name()
+        .and_then(|name| name.value_token())
+        .is_ok_and(|name| {
+            matches!(
+                name.text_trimmed().to_ascii_lowercase_cow().as_ref(),
+                "rgb"
+                    | "rgba"
+                    | "hsl"
+                    | "hsla"
+                    | "hwb"
+                    | "lab"
+                    | "lch"
+                    | "oklab"
+                    | "oklch"
+                    | "color"
+            )
+        })
+}
+
+/// Returns the color of `identifier` if it's a named color in the value of `property`, e.g. `red` in `color: red`.
+pub(crate) fn named_color(
+    identifier: &CssIdentifier,
+    property: &CssGenericProperty,
+) -> Option<Color> {
+    let parent = identifier.syntax().parent()?;
+    if !matches!(
+        parent.kind(),
+        CssSyntaxKind::CSS_GENERIC_COMPONENT_VALUE_LIST | CssSyntaxKind::CSS_COMPONENT_VALUE_LIST
+    ) || !accepts_named_colors(property)
+    {
+        return None;
+    }
+    Color::from_name(identifier.value_token().ok()?.text_trimmed())
+}
+
+/// Returns the color of the value of `property` if it's a single literal color, e.g. `#fff` in `color: #fff`.
+pub(crate) fn literal_color(property: &CssGenericProperty) -> Option<Color> {
+    let mut values = property.value().iter();
+    let value = values.next()?;
+    if values.next().is_some() {
+        return None;
+    }
+    match value {
+        AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssColor(color)) => {
+            Color::from_hex(color.value_token().ok()?.text_trimmed())
+        }
+        AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::AnyCssFunction(
+            AnyCssFunction::CssFunction(function),
+        )) => Color::from_function(&function),
+        AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssIdentifier(identifier)) => {
+            named_color(&identifier, property)
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if the value of `property` can contain a named color.
+///
+/// Identifiers such as `red` can also be animation names or grid areas,
+/// so they are only considered to be colors in the properties that accept colors.
+fn accepts_named_colors(property: &CssGenericProperty) -> bool {
+    let Ok(AnyCssDeclarationName::CssIdentifier(name)) = property.name() else {
+        return false;
+    };
+    let Ok(name) = name.value_token() else {
+        return false;
+    };
+    let name = name.text_trimmed().to_ascii_lowercase_cow();
+    name.ends_with("color")
+        || name.starts_with("background")
+        || name.starts_with("border")
+        || name.starts_with("outline")
+        || name.starts_with("text-decoration")
+        || name.starts_with("column-rule")
+        || matches!(
+            name.as_ref(),
+            "box-shadow" | "text-shadow" | "fill" | "stroke" | "text-emphasis"
+        )
+}
+
+impl Display for ColorSyntax {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hex(digits) => write!(f, "#{digits}"),
+            Self::Function(function) => {
+                write!(f, "{}(", function.name)?;
+                for (index, channel) in function.channels.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(" ")?;
+                    }
+                    match channel {
+                        ColorChannel::Number(number) => f.write_str(number)?,
+                        ColorChannel::Percentage(number) => write!(f, "{number}%")?,
+                    }
+                }
+                if let Some(alpha) = &function.alpha {
+                    write!(f, " / {alpha}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// The numeric arguments of a color function.
+struct ColorArguments {
+    channels: [ColorArgument; 3],
+    alpha: Option<ColorArgument>,
+}
+
+#[derive(Clone, Copy)]
+enum ColorArgument {
+    Number(f64),
+    Percentage(f64),
+    /// An angle in degrees.
+    Angle(f64),
+}
+
+impl ColorArguments {
+    /// Parses the arguments of both the legacy syntax, e.g. `rgba(255, 0, 0, 0.5)`,
+    /// and the modern syntax, e.g. `rgb(255 0 0 / 0.5)`.
+    fn parse(function: &CssFunction) -> Option<Self> {
+        let mut arguments = Vec::with_capacity(4);
+        let mut slash_position = None;
+        let mut comma_count = 0;
+        for token in function
+            .items()
+            .syntax()
+            .descendants_tokens(Direction::Next)
+        {
+            let parent = token.parent()?;
+            match token.kind() {
+                CssSyntaxKind::CSS_NUMBER_LITERAL => {
+                    let value = token.text_trimmed().parse::<f64>().ok()?;
+                    arguments.push(match parent.kind() {
+                        CssSyntaxKind::CSS_NUMBER => ColorArgument::Number(value),
+                        CssSyntaxKind::CSS_PERCENTAGE => ColorArgument::Percentage(value),
+                        CssSyntaxKind::CSS_REGULAR_DIMENSION => {
+                            let unit = CssRegularDimension::cast(parent)?.unit_token().ok()?;
+                            let degrees =
+                                match unit.text_trimmed().to_ascii_lowercase_cow().as_ref() {
+                                    "deg" => value,
+                                    "grad" => value * 0.9,
+                                    "rad" => value.to_degrees(),
+                                    "turn" => value * 360.0,
+                                    _ => return None,
+                                };
+                            ColorArgument::Angle(degrees)
+                        }
+                        _ => return None,
+                    });
+                }
+                CssSyntaxKind::PERCENT if parent.kind() == CssSyntaxKind::CSS_PERCENTAGE => {}
+                CssSyntaxKind::IDENT if parent.kind() == CssSyntaxKind::CSS_REGULAR_DIMENSION => {}
+                T![/] if slash_position.is_none() => slash_position = Some(arguments.len()),
+                T![,] => comma_count += 1,
+                _ => return None,
+            }
+        }
+        let alpha = match (arguments.len(), slash_position, comma_count) {
+            (3, None, 0 | 2) => None,
+            (4, Some(3), 0) | (4, None, 3) => arguments.pop(),
+            _ => return None,
+        };
+        Some(Self {
+            channels: arguments.try_into().ok()?,
+            alpha,
+        })
+    }
+}
+
+impl ColorArgument {
+    /// Returns a channel of `rgb()` between 0 and 1.
+    fn rgb_channel(self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(value / 255.0),
+            Self::Percentage(value) => Some(value / 100.0),
+            Self::Angle(_) => None,
+        }
+    }
+
+    /// Returns a percentage between 0 and 1. Numbers are read as percentages.
+    fn percentage_or_number(self) -> Option<f64> {
+        match self {
+            Self::Number(value) | Self::Percentage(value) => Some(value / 100.0),
+            Self::Angle(_) => None,
+        }
+    }
+
+    /// Returns a number, where percentages are a fraction of `reference`.
+    fn fraction(self, reference: f64) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(value),
+            Self::Percentage(value) => Some(value / 100.0 * reference),
+            Self::Angle(_) => None,
+        }
+    }
+
+    /// Returns an angle in degrees. Numbers are read as degrees.
+    fn angle(self) -> Option<f64> {
+        match self {
+            Self::Number(value) | Self::Angle(value) => Some(value),
+            Self::Percentage(_) => None,
+        }
+    }
+}
+
+fn to_linear(channel: f64) -> f64 {
+    if channel.abs() <= 0.040_45 {
+        channel / 12.92
+    } else {
+        channel.signum() * ((channel.abs() + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn to_gamma(channel: f64) -> f64 {
+    if channel.abs() <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        channel.signum() * (1.055 * channel.abs().powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+/// Formats `value` with at most `decimals` decimals, without trailing zeros.
+fn format_number(value: f64, decimals: usize) -> String {
+    let text = format!("{value:.decimals$}");
+    let text = if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &text
+    };
+    match text {
+        "-0" => "0".to_string(),
+        text => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_hex_colors() {
+        let color = Color::from_hex("F00").unwrap();
+        assert_eq!(color.to_hex().unwrap().to_string(), "#ff0000");
+        assert_eq!(color.to_rgb().unwrap().to_string(), "rgb(255 0 0)");
+        assert_eq!(color.to_hsl().unwrap().to_string(), "hsl(0 100% 50%)");
+        assert_eq!(color.to_oklch().to_string(), "oklch(62.8% 0.2577 29.23)");
+    }
+
+    #[test]
+    fn converts_translucent_colors() {
+        let color = Color::from_hex("33669980").unwrap();
+        assert_eq!(color.to_hex().unwrap().to_string(), "#33669980");
+        assert_eq!(
+            color.to_hsl().unwrap().to_string(),
+            "hsl(210 50% 40% / 0.502)"
+        );
+    }
+
+    #[test]
+    fn converts_named_colors() {
+        let color = Color::from_name("RebeccaPurple").unwrap();
+        assert_eq!(color.to_hex().unwrap().to_string(), "#663399");
+        assert!(Color::from_name("transparent").is_none());
+    }
+
+    #[test]
+    fn computes_contrast_ratios() {
+        let black = Color::from_name("black").unwrap();
+        let white = Color::from_name("white").unwrap();
+        assert_eq!(black.contrast_ratio(&white), 21.0);
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+        let gray = Color::from_hex("767676").unwrap();
+        assert_eq!(format_number(gray.contrast_ratio(&white), 2), "4.54");
+    }
+
+    #[test]
+    fn blends_translucent_colors() {
+        let color = Color::from_hex("00000080").unwrap();
+        let white = Color::from_name("white").unwrap();
+        assert!(!color.is_opaque());
+        let blended = color.over(white);
+        assert!(blended.is_opaque());
+        assert_eq!(blended.to_hex().unwrap().to_string(), "#7f7f7f");
+    }
+
+    #[test]
+    fn round_trips_through_oklch() {
+        for digits in ["000000", "ffffff", "663399", "12ab7f", "fe01c3"] {
+            let ColorSyntax::Function(function) = Color::from_hex(digits).unwrap().to_oklch()
+            else {
+                unreachable!()
+            };
+            let [ColorChannel::Percentage(lightness), ColorChannel::Number(chroma), ColorChannel::Number(hue)] =
+                function.channels
+            else {
+                unreachable!()
+            };
+            let color = Color::from_oklch(
+                lightness.parse::<f64>().unwrap() / 100.0,
+                chroma.parse().unwrap(),
+                hue.parse().unwrap(),
+            );
+            assert_eq!(color.to_hex().unwrap(), ColorSyntax::Hex(digits.into()));
+        }
+    }
+}
@@ -1,22 +1,28 @@
+mod assists;
+mod color;
 mod keywords;
 mod lint;
 pub mod options;
+pub mod project;
 mod registry;
 mod services;
 mod suppression_action;
 mod utils;
 
+use crate::project::CssProjectIndex;
 pub use crate::registry::visit_registry;
 use crate::suppression_action::CssSuppressionAction;
 use biome_analyze::{
     AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleRegistry, SuppressionKind,
+    MetadataRegistry, RuleAction, RuleRegistry, SuppressionKind,
 };
 use biome_css_syntax::CssLanguage;
 use biome_diagnostics::{category, Error};
 use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
 use std::ops::Deref;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
+
+pub(crate) type CssRuleAction = RuleAction<CssLanguage>;
 
 pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
     let mut metadata = MetadataRegistry::default();
@@ -31,13 +37,14 @@ pub fn analyze<'a, F, B>(
     root: &LanguageRoot<CssLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    project: Arc<CssProjectIndex>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<CssLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(root, filter, |_| {}, options, project, emit_signal)
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -51,6 +58,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    project: Arc<CssProjectIndex>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -99,7 +107,7 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
@@ -118,6 +126,7 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    services.insert_service(project);
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -186,6 +195,7 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            Default::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -2,31 +2,77 @@
 
 use biome_analyze::declare_lint_group;
 
+pub mod max_nesting_depth;
+pub mod no_arbitrary_z_index;
+pub mod no_custom_property_typos;
+pub mod no_deprecated_properties;
 pub mod no_descending_specificity;
 pub mod no_duplicate_custom_properties;
 pub mod no_duplicate_properties;
+pub mod no_duplicate_selectors_across_files;
+pub mod no_hardcoded_colors;
+pub mod no_high_specificity_selectors;
+pub mod no_id_selectors;
 pub mod no_irregular_whitespace;
+pub mod no_low_contrast_color_pairs;
 pub mod no_missing_var_function;
+pub mod no_transition_all;
+pub mod no_unknown_animation_name;
 pub mod no_unknown_at_rule;
+pub mod no_unknown_container_feature_name;
 pub mod no_unknown_pseudo_class;
 pub mod no_unknown_pseudo_element;
 pub mod no_unknown_type_selector;
+pub mod no_unused_custom_properties;
+pub mod no_unused_keyframes;
 pub mod no_value_at_rule;
+pub mod no_vendor_prefixes;
+pub mod use_allowed_units;
+pub mod use_browser_compat;
+pub mod use_class_naming_convention;
+pub mod use_consistent_color_format;
+pub mod use_font_family_fallbacks;
+pub mod use_logical_properties;
+pub mod use_shorthand;
+pub mod use_var_fallbacks;
 
 declare_lint_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: max_nesting_depth :: MaxNestingDepth ,
+            self :: no_arbitrary_z_index :: NoArbitraryZIndex ,
+            self :: no_custom_property_typos :: NoCustomPropertyTypos ,
+            self :: no_deprecated_properties :: NoDeprecatedProperties ,
             self :: no_descending_specificity :: NoDescendingSpecificity ,
             self :: no_duplicate_custom_properties :: NoDuplicateCustomProperties ,
             self :: no_duplicate_properties :: NoDuplicateProperties ,
+            self :: no_duplicate_selectors_across_files :: NoDuplicateSelectorsAcrossFiles ,
+            self :: no_hardcoded_colors :: NoHardcodedColors ,
+            self :: no_high_specificity_selectors :: NoHighSpecificitySelectors ,
+            self :: no_id_selectors :: NoIdSelectors ,
             self :: no_irregular_whitespace :: NoIrregularWhitespace ,
+            self :: no_low_contrast_color_pairs :: NoLowContrastColorPairs ,
             self :: no_missing_var_function :: NoMissingVarFunction ,
+            self :: no_transition_all :: NoTransitionAll ,
+            self :: no_unknown_animation_name :: NoUnknownAnimationName ,
             self :: no_unknown_at_rule :: NoUnknownAtRule ,
+            self :: no_unknown_container_feature_name :: NoUnknownContainerFeatureName ,
             self :: no_unknown_pseudo_class :: NoUnknownPseudoClass ,
             self :: no_unknown_pseudo_element :: NoUnknownPseudoElement ,
             self :: no_unknown_type_selector :: NoUnknownTypeSelector ,
+            self :: no_unused_custom_properties :: NoUnusedCustomProperties ,
+            self :: no_unused_keyframes :: NoUnusedKeyframes ,
             self :: no_value_at_rule :: NoValueAtRule ,
+            self :: no_vendor_prefixes :: NoVendorPrefixes ,
+            self :: use_allowed_units :: UseAllowedUnits ,
+            self :: use_browser_compat :: UseBrowserCompat ,
+            self :: use_class_naming_convention :: UseClassNamingConvention ,
+            self :: use_consistent_color_format :: UseConsistentColorFormat ,
+            self :: use_font_family_fallbacks :: UseFontFamilyFallbacks ,
+            self :: use_logical_properties :: UseLogicalProperties ,
+            self :: use_shorthand :: UseShorthand ,
+            self :: use_var_fallbacks :: UseVarFallbacks ,
         ]
      }
 }
@@ -0,0 +1,203 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_css_syntax::{CssAtRule, CssNestedQualifiedRule, CssQualifiedRule, CssSyntaxNode, T};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{declare_node_union, AstNode, Direction, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce a maximum depth for the nested rules.
+    ///
+    /// Native CSS nesting makes it easy to write rules that mirror the structure of the HTML.
+    /// Deeply nested rules are hard to read, and they produce long selectors with a high specificity
+    /// that are tied to the structure of the document.
+    ///
+    /// The depth of a style rule is the number of style rules that contain it,
+    /// and the depth of an at-rule is the number of at-rules that contain it.
+    /// The two depths are counted separately, so a style rule nested in `@media` isn't deeper than the same rule at the top level,
+    /// and each depth has its own maximum.
+    ///
+    /// Only the outermost rule that exceeds the maximum is reported: the rules that it contains are reported with it.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .card {
+    ///   .header {
+    ///     .title {
+    ///       .icon {
+    ///         .path {
+    ///           fill: currentcolor;
+    ///         }
+    ///       }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .card {
+    ///   .title {
+    ///     @media (width >= 600px) {
+    ///       font-size: 2rem;
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxDepth`
+    ///
+    /// The maximum depth of the style rules. The default is `3`.
+    /// Set it to `0` to disallow nested style rules.
+    ///
+    /// ### `maxAtRuleDepth`
+    ///
+    /// The maximum depth of the at-rules. The default is `3`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "maxDepth": 1,
+    ///         "maxAtRuleDepth": 0
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// @layer components {
+    ///   @media (width >= 600px) {
+    ///     .card {
+    ///       padding: 1rem;
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub MaxNestingDepth {
+        version: "next",
+        name: "maxNestingDepth",
+        language: "css",
+        recommended: false,
+        sources: &[RuleSource::Stylelint("max-nesting-depth")],
+        source_kind: RuleSourceKind::Inspired,
+    }
+}
+
+/// Options for the rule `maxNestingDepth`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct MaxNestingDepthOptions {
+    /// The maximum number of style rules that can contain a style rule.
+    pub max_depth: u8,
+    /// The maximum number of at-rules that can contain an at-rule.
+    pub max_at_rule_depth: u8,
+}
+
+impl Default for MaxNestingDepthOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 3,
+            max_at_rule_depth: 3,
+        }
+    }
+}
+
+declare_node_union! {
+    pub AnyNestableRule = CssQualifiedRule | CssNestedQualifiedRule | CssAtRule
+}
+
+impl AnyNestableRule {
+    fn is_at_rule(&self) -> bool {
+        matches!(self, Self::CssAtRule(_))
+    }
+
+    /// Returns `true` if `node` is a rule of the same kind as `self`.
+    fn is_same_kind(&self, node: &CssSyntaxNode) -> bool {
+        if self.is_at_rule() {
+            CssAtRule::can_cast(node.kind())
+        } else {
+            CssQualifiedRule::can_cast(node.kind()) || CssNestedQualifiedRule::can_cast(node.kind())
+        }
+    }
+
+    /// Returns the range of the selector or of the prelude of the at-rule, before the opening brace of the block.
+    fn prelude_range(&self) -> Option<TextRange> {
+        let open_brace = self
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .find(|token| token.kind() == T!['{'])?;
+        let last_prelude_token = open_brace.prev_token()?;
+        Some(TextRange::new(
+            self.syntax().text_trimmed_range().start(),
+            last_prelude_token.text_trimmed_range().end(),
+        ))
+    }
+}
+
+impl Rule for MaxNestingDepth {
+    type Query = Ast<AnyNestableRule>;
+    type State = usize;
+    type Signals = Option<Self::State>;
+    type Options = Box<MaxNestingDepthOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let options = ctx.options();
+        let max_depth = if node.is_at_rule() {
+            options.max_at_rule_depth
+        } else {
+            options.max_depth
+        };
+        let depth = node
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .filter(|ancestor| node.is_same_kind(ancestor))
+            .count();
+        // Only report the outermost rule that is too deep, not all the rules that it contains
+        (depth == usize::from(max_depth) + 1).then_some(depth)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, depth: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let options = ctx.options();
+        let (depth_description, max_depth) = if node.is_at_rule() {
+            (
+                "The depth of an at-rule is the number of at-rules that contain it.",
+                options.max_at_rule_depth,
+            )
+        } else {
+            (
+                "The depth of a style rule is the number of style rules that contain it.",
+                options.max_depth,
+            )
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.prelude_range()?,
+                markup! {
+                    "The nesting depth of this rule is "{depth}", which exceeds the maximum of "{max_depth}"."
+                },
+            )
+            .note(markup! {
+                {depth_description}
+            })
+            .note(markup! {
+                "Deeply nested rules are hard to read and produce selectors tied to the structure of the document."
+            })
+            .note(markup! {
+                "Move the rule to a shallower level, or use a dedicated class instead of nesting."
+            }),
+        )
+    }
+}
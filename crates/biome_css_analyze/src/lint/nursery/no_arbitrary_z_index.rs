@@ -0,0 +1,224 @@
+use crate::project::{is_custom_property_reference, is_in_condition};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{AnyCssDeclarationName, CssDashedIdentifier, CssGenericProperty, CssNumber};
+use biome_deserialize_macros::Deserializable;
+use biome_glob::Glob;
+use biome_rowan::AstNode;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce that `z-index` values come from a scale.
+    ///
+    /// Arbitrary `z-index` values such as `9999` are hard to reason about:
+    /// every new layer tries to outbid the existing ones, and the stacking order of the page is scattered across the stylesheets.
+    /// Defining the layers of the page in one place, as a list of allowed values or as custom properties, keeps the stacking order predictable.
+    ///
+    /// The rule reports the numbers in the `z-index` declarations that aren't in the allowed values,
+    /// including the numbers in `calc()` and in the fallbacks of `var()`.
+    /// `0`, `auto`, and the CSS-wide keywords are always allowed.
+    ///
+    /// The custom properties read with `var()` are allowed, unless the option `allowedCustomProperties` restricts them.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .modal {
+    ///   z-index: 9999;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .tooltip {
+    ///   z-index: calc(var(--z-modal) + 1);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// :root {
+    ///   --z-modal: 100;
+    /// }
+    ///
+    /// .modal {
+    ///   z-index: var(--z-modal);
+    /// }
+    ///
+    /// .card {
+    ///   z-index: 0;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `allowedValues`
+    ///
+    /// The integers of the `z-index` scale.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "allowedValues": [1, 10, 100]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,use_options
+    /// .dropdown {
+    ///   z-index: 10;
+    /// }
+    /// ```
+    ///
+    /// ### `allowedCustomProperties`
+    ///
+    /// A list of glob patterns that match the custom properties of the `z-index` scale, including the leading `--`.
+    /// When the list is empty, which is the default, all the custom properties are allowed.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "allowedCustomProperties": ["--z-*"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// .dropdown {
+    ///   z-index: var(--dropdown-layer);
+    /// }
+    /// ```
+    ///
+    pub NoArbitraryZIndex {
+        version: "next",
+        name: "noArbitraryZIndex",
+        language: "css",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noArbitraryZIndex`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoArbitraryZIndexOptions {
+    /// The integers of the `z-index` scale.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub allowed_values: Box<[i32]>,
+    /// The custom properties of the `z-index` scale. All the custom properties are allowed when it's empty.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub allowed_custom_properties: Box<[Glob]>,
+}
+
+impl NoArbitraryZIndexOptions {
+    fn allows_number(&self, number: &CssNumber) -> bool {
+        let Ok(value) = number.value_token() else {
+            return true;
+        };
+        let Ok(value) = value.text_trimmed().parse::<i32>() else {
+            // Not an integer: the value is invalid, which isn't the concern of this rule
+            return true;
+        };
+        value == 0 || self.allowed_values.contains(&value)
+    }
+
+    fn allows_custom_property(&self, name: &str) -> bool {
+        self.allowed_custom_properties.is_empty()
+            || self
+                .allowed_custom_properties
+                .iter()
+                .any(|pattern| pattern.is_match(name))
+    }
+}
+
+pub enum ArbitraryZIndex {
+    Number(CssNumber),
+    CustomProperty(CssDashedIdentifier),
+}
+
+impl Rule for NoArbitraryZIndex {
+    type Query = Ast<CssGenericProperty>;
+    type State = ArbitraryZIndex;
+    type Signals = Box<[Self::State]>;
+    type Options = Box<NoArbitraryZIndexOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let options = ctx.options();
+        let is_z_index = matches!(
+            node.name(),
+            Ok(AnyCssDeclarationName::CssIdentifier(name))
+                if name.value_token().is_ok_and(|name| name.text_trimmed().eq_ignore_ascii_case("z-index"))
+        );
+        if !is_z_index || is_in_condition(node.syntax()) {
+            return Box::default();
+        }
+        node.value()
+            .syntax()
+            .descendants()
+            .filter_map(|descendant| {
+                if let Some(number) = CssNumber::cast_ref(&descendant) {
+                    return (!options.allows_number(&number))
+                        .then_some(ArbitraryZIndex::Number(number));
+                }
+                let identifier = CssDashedIdentifier::cast(descendant)?;
+                let name = identifier.value_token().ok()?;
+                (is_custom_property_reference(&identifier)
+                    && !options.allows_custom_property(name.text_trimmed()))
+                .then_some(ArbitraryZIndex::CustomProperty(identifier))
+            })
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let options = ctx.options();
+        let diagnostic = match state {
+            ArbitraryZIndex::Number(number) => {
+                let value = number.value_token().ok()?;
+                let text = value.text_trimmed();
+                let diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    number.range(),
+                    markup! {
+                        "The z-index "<Emphasis>{text}</Emphasis>" isn't part of the z-index scale."
+                    },
+                )
+                .note(markup! {
+                    "Arbitrary z-index values make the stacking order of the page hard to predict."
+                });
+                if options.allowed_values.is_empty() {
+                    diagnostic.note(markup! {
+                        "Use a custom property that defines a layer of the scale, such as "<Emphasis>"var(--z-modal)"</Emphasis>"."
+                    })
+                } else {
+                    let allowed_values = options
+                        .allowed_values
+                        .iter()
+                        .map(i32::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    diagnostic.note(markup! {
+                        "Use one of the values of the scale, "<Emphasis>{allowed_values}</Emphasis>", or a custom property."
+                    })
+                }
+            }
+            ArbitraryZIndex::CustomProperty(identifier) => {
+                let name = identifier.value_token().ok()?;
+                let name = name.text_trimmed();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    identifier.range(),
+                    markup! {
+                        "The custom property "<Emphasis>{name}</Emphasis>" isn't part of the z-index scale."
+                    },
+                )
+                .note(markup! {
+                    "Use one of the custom properties allowed by the option "<Emphasis>"allowedCustomProperties"</Emphasis>"."
+                })
+            }
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::project::{is_custom_property_reference, CssFileSummary};
+use crate::services::project::Project;
+use crate::utils::find_similar_name;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{CssDashedIdentifier, CssRoot};
+use biome_rowan::AstNode;
+use std::collections::BTreeSet;
+
+declare_lint_rule! {
+    /// Disallow references to undeclared custom properties that look like typos.
+    ///
+    /// A `var()` function that references a custom property that isn't declared falls back to its fallback value,
+    /// or makes the declaration invalid at computed-value time. When the name is close to the name of a declared custom property,
+    /// the reference is most likely a typo.
+    ///
+    /// The rule looks for the declarations in the current stylesheet and in all the other stylesheets handled by Biome,
+    /// including the custom properties registered with `@property`.
+    /// A reference is only reported when no stylesheet declares it and a custom property with a similar name exists,
+    /// so the custom properties set by scripts or by inline styles aren't reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// :root {
+    ///   --color-primary: rebeccapurple;
+    /// }
+    ///
+    /// a {
+    ///   color: var(--colr-primary);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// :root {
+    ///   --color-primary: rebeccapurple;
+    /// }
+    ///
+    /// a {
+    ///   color: var(--color-primary);
+    ///   background: var(--scroll-progress);
+    /// }
+    /// ```
+    ///
+    pub NoCustomPropertyTypos {
+        version: "next",
+        name: "noCustomPropertyTypos",
+        language: "css",
+        recommended: false,
+    }
+}
+
+pub struct CustomPropertyTypo {
+    name: CssDashedIdentifier,
+    suggestion: Box<str>,
+}
+
+impl Rule for NoCustomPropertyTypos {
+    type Query = Project<CssRoot>;
+    type State = CustomPropertyTypo;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let summary = CssFileSummary::from_root(root);
+        let mut declared: BTreeSet<Box<str>> = summary
+            .declared_custom_properties()
+            .map(Box::from)
+            .collect();
+        ctx.project().for_each_other_file(ctx.file_path(), |file| {
+            declared.extend(file.declared_custom_properties().map(Box::from));
+        });
+        root.syntax()
+            .descendants()
+            .filter_map(CssDashedIdentifier::cast)
+            .filter(is_custom_property_reference)
+            .filter_map(|name| {
+                let token = name.value_token().ok()?;
+                let text = token.text_trimmed();
+                if declared.contains(text) {
+                    return None;
+                }
+                let suggestion = find_similar_name(text, declared.iter().map(AsRef::as_ref))?;
+                Some(CustomPropertyTypo {
+                    name,
+                    suggestion: suggestion.into(),
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.name.value_token().ok()?;
+        let name = name.text_trimmed();
+        let suggestion = state.suggestion.as_ref();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.name.range(),
+                markup! {
+                    "The custom property "<Emphasis>{name}</Emphasis>" isn't declared in any stylesheet of the project."
+                },
+            )
+            .note(markup! {
+                "The custom property "<Emphasis>{suggestion}</Emphasis>" has a similar name: this is probably a typo."
+            }),
+        )
+    }
+}
@@ -0,0 +1,249 @@
+use crate::project::is_in_condition;
+use crate::CssRuleAction;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssGenericComponentValue, AnyCssProperty, AnyCssValue,
+    CssDeclarationWithSemicolon, CssGenericProperty, CssSyntaxToken, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+use biome_string_case::StrLikeExtension;
+
+declare_lint_rule! {
+    /// Disallow deprecated and nonstandard properties and values.
+    ///
+    /// Some properties and values were removed from the specifications, or replaced by a standard equivalent.
+    /// Browsers keep supporting most of them for compatibility, but they can be removed at any time,
+    /// and their standard equivalents are better supported by the tools.
+    ///
+    /// The rule reports, among others:
+    ///
+    /// - `clip`, replaced by `clip-path`;
+    /// - `grid-gap`, `grid-row-gap`, and `grid-column-gap`, replaced by `gap`, `row-gap`, and `column-gap`;
+    /// - `word-wrap`, replaced by `overflow-wrap`;
+    /// - `page-break-before`, `page-break-after`, and `page-break-inside`, replaced by `break-before`, `break-after`, and `break-inside`;
+    /// - the properties of the old flexible box layout, such as `-webkit-box-orient`, replaced by the flexbox properties;
+    /// - `word-break: break-word`, replaced by `overflow-wrap: anywhere`;
+    /// - `overflow: overlay`, replaced by `overflow: auto`.
+    ///
+    /// `-webkit-box-orient` is still required to clamp text with `-webkit-line-clamp`: it isn't reported next to a `-webkit-line-clamp` declaration.
+    ///
+    /// The fix replaces the properties and values that are aliases of their standard equivalent.
+    /// The other ones, such as `clip`, have a different syntax or behavior and must be migrated manually.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .grid {
+    ///   grid-gap: 1rem;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .visually-hidden {
+    ///   clip: rect(0 0 0 0);
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .text {
+    ///   word-break: break-word;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .grid {
+    ///   gap: 1rem;
+    ///   overflow-wrap: anywhere;
+    /// }
+    ///
+    /// .excerpt {
+    ///   display: -webkit-box;
+    ///   -webkit-box-orient: vertical;
+    ///   -webkit-line-clamp: 3;
+    /// }
+    /// ```
+    ///
+    pub NoDeprecatedProperties {
+        version: "next",
+        name: "noDeprecatedProperties",
+        language: "css",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// The deprecated properties, their replacement, and whether the replacement is an alias that can be used as is.
+const DEPRECATED_PROPERTIES: [(&str, &str, bool); 17] = [
+    ("-webkit-box-align", "align-items", false),
+    ("-webkit-box-direction", "flex-direction", false),
+    ("-webkit-box-flex", "flex", false),
+    ("-webkit-box-flex-group", "flex", false),
+    ("-webkit-box-lines", "flex-wrap", false),
+    ("-webkit-box-ordinal-group", "order", false),
+    ("-webkit-box-orient", "flex-direction", false),
+    ("-webkit-box-pack", "justify-content", false),
+    ("clip", "clip-path", false),
+    ("color-adjust", "print-color-adjust", true),
+    ("grid-column-gap", "column-gap", true),
+    ("grid-gap", "gap", true),
+    ("grid-row-gap", "row-gap", true),
+    ("page-break-after", "break-after", false),
+    ("page-break-before", "break-before", false),
+    ("page-break-inside", "break-inside", false),
+    ("word-wrap", "overflow-wrap", true),
+];
+
+/// The deprecated values of a property, their replacement, and whether the replacement is an alias that can be used as is.
+const DEPRECATED_VALUES: [(&str, &str, &str, bool); 5] = [
+    ("overflow", "overlay", "auto", true),
+    ("overflow-x", "overlay", "auto", true),
+    ("overflow-y", "overlay", "auto", true),
+    ("text-justify", "distribute", "inter-character", true),
+    ("word-break", "break-word", "overflow-wrap: anywhere", false),
+];
+
+pub struct DeprecatedUsage {
+    token: CssSyntaxToken,
+    replacement: &'static str,
+    /// Whether the replacement can replace the token as is.
+    is_alias: bool,
+    /// The property of the value, or `None` if the deprecated usage is the property itself.
+    property: Option<&'static str>,
+}
+
+impl Rule for NoDeprecatedProperties {
+    type Query = Ast<CssGenericProperty>;
+    type State = DeprecatedUsage;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let name = property_name(node)?;
+        if is_in_condition(node.syntax()) {
+            return None;
+        }
+        let lowercase_name = name.text_trimmed().to_ascii_lowercase_cow();
+        if let Some(&(deprecated, replacement, is_alias)) = DEPRECATED_PROPERTIES
+            .iter()
+            .find(|(deprecated, ..)| *deprecated == lowercase_name)
+        {
+            if deprecated == "-webkit-box-orient" && is_next_to_line_clamp(node) {
+                return None;
+            }
+            return Some(DeprecatedUsage {
+                token: name,
+                replacement,
+                is_alias,
+                property: None,
+            });
+        }
+        node.value().into_iter().find_map(|value| {
+            let AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssIdentifier(value)) = value
+            else {
+                return None;
+            };
+            let token = value.value_token().ok()?;
+            let lowercase_value = token.text_trimmed().to_ascii_lowercase_cow();
+            let &(property, _, replacement, is_alias) =
+                DEPRECATED_VALUES
+                    .iter()
+                    .find(|(property, deprecated, ..)| {
+                        *property == lowercase_name && *deprecated == lowercase_value
+                    })?;
+            Some(DeprecatedUsage {
+                token,
+                replacement,
+                is_alias,
+                property: Some(property),
+            })
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let deprecated = state.token.text_trimmed();
+        let replacement = state.replacement;
+        let message = match state.property {
+            Some(property) => markup! {
+                "The value "<Emphasis>{deprecated}</Emphasis>" of "<Emphasis>{property}</Emphasis>" is deprecated."
+            }
+            .to_owned(),
+            None => markup! {
+                "The property "<Emphasis>{deprecated}</Emphasis>" is deprecated."
+            }
+            .to_owned(),
+        };
+        let diagnostic =
+            RuleDiagnostic::new(rule_category!(), state.token.text_trimmed_range(), message)
+                .note(markup! {
+                    "Deprecated properties and values are only supported for compatibility, and browsers can remove them at any time."
+                });
+        let diagnostic = if state.is_alias {
+            diagnostic.note(markup! {
+                "Use "<Emphasis>{replacement}</Emphasis>" instead, which has the same behavior."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "Use "<Emphasis>{replacement}</Emphasis>" instead. Its syntax or behavior is different: check the value when migrating."
+            })
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        if !state.is_alias {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(
+            state.token.clone(),
+            CssSyntaxToken::new_detached(T![ident], state.replacement, [], []),
+        );
+        let replacement = state.replacement;
+        Some(CssRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>{replacement}</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+fn property_name(property: &CssGenericProperty) -> Option<CssSyntaxToken> {
+    let AnyCssDeclarationName::CssIdentifier(name) = property.name().ok()? else {
+        return None;
+    };
+    name.value_token().ok()
+}
+
+/// Returns `true` if `-webkit-line-clamp` is declared in the same block as `property`.
+fn is_next_to_line_clamp(property: &CssGenericProperty) -> bool {
+    let Some(block) = property
+        .syntax()
+        .ancestors()
+        .find_map(CssDeclarationWithSemicolon::cast)
+        .and_then(|declaration| declaration.syntax().parent())
+    else {
+        return false;
+    };
+    block
+        .children()
+        .filter_map(CssDeclarationWithSemicolon::cast)
+        .filter_map(|sibling| {
+            let AnyCssProperty::CssGenericProperty(sibling) =
+                sibling.declaration().ok()?.property().ok()?
+            else {
+                return None;
+            };
+            property_name(&sibling)
+        })
+        .any(|name| {
+            name.text_trimmed()
+                .eq_ignore_ascii_case("-webkit-line-clamp")
+        })
+}
@@ -0,0 +1,154 @@
+use crate::project::scoped_selectors;
+use crate::services::project::Project;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{CssRoot, CssSyntaxNode};
+use biome_deserialize_macros::Deserializable;
+use biome_glob::{CandidatePath, Glob};
+use biome_rowan::AstNode;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+declare_lint_rule! {
+    /// Disallow style rules whose selector is already used in another stylesheet.
+    ///
+    /// When two stylesheets define rules with the same selector, the declarations of the stylesheet loaded last win.
+    /// The result depends on the loading order of the stylesheets, and authors often end up increasing
+    /// the specificity of their selectors to win over the other stylesheet.
+    ///
+    /// Two selectors are considered identical when they have the same text, ignoring comments and insignificant whitespace,
+    /// and when they are nested in the same rules and at-rules.
+    /// For example, `.button` at the top level and `.button` in `@media print` are different selectors.
+    ///
+    /// The rule looks for the selectors of all the other stylesheets handled by Biome.
+    /// CSS modules, whose files end with `.module.css`, are ignored because their class names are scoped to the module.
+    ///
+    /// ## Examples
+    ///
+    /// Given a stylesheet `button.css` that contains the following rule:
+    ///
+    /// ```css,ignore
+    /// .button {
+    ///   padding: 0.5rem;
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// Another stylesheet that defines the same selector is reported:
+    ///
+    /// ```css,ignore
+    /// .button {
+    ///   padding: 1rem;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .card .button {
+    ///   padding: 1rem;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `ignore`
+    ///
+    /// A list of glob patterns that match the stylesheets to ignore,
+    /// such as the stylesheets of utility classes that are meant to be combined with other rules.
+    /// The selectors of the ignored stylesheets are neither reported nor compared with the selectors of other stylesheets.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignore": ["**/utilities.css", "**/vendor/**"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoDuplicateSelectorsAcrossFiles {
+        version: "next",
+        name: "noDuplicateSelectorsAcrossFiles",
+        language: "css",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noDuplicateSelectorsAcrossFiles`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoDuplicateSelectorsAcrossFilesOptions {
+    /// The stylesheets to ignore.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub ignore: Box<[Glob]>,
+}
+
+impl NoDuplicateSelectorsAcrossFilesOptions {
+    fn is_ignored(&self, path: &Path) -> bool {
+        is_css_module(path) || CandidatePath::new(&path).matches_with_exceptions(&*self.ignore)
+    }
+}
+
+fn is_css_module(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(".module.css"))
+}
+
+impl Rule for NoDuplicateSelectorsAcrossFiles {
+    type Query = Project<CssRoot>;
+    type State = (CssSyntaxNode, PathBuf);
+    type Signals = Box<[Self::State]>;
+    type Options = Box<NoDuplicateSelectorsAcrossFilesOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        if options.is_ignored(ctx.file_path()) {
+            return Box::default();
+        }
+        ctx.query()
+            .syntax()
+            .descendants()
+            .flat_map(|node| scoped_selectors(&node))
+            .filter_map(|(selector, scoped)| {
+                let other_file = ctx
+                    .project()
+                    .find_other_file(ctx.file_path(), |path, file| {
+                        !options.is_ignored(path) && file.has_selector(&scoped)
+                    })?;
+                Some((selector, other_file))
+            })
+            .collect()
+    }
+
+    fn diagnostic(
+        ctx: &RuleContext<Self>,
+        (selector, other_file): &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        // Show the other file relative to the directory of the current file when it's nested in it
+        let other_file = ctx
+            .file_path()
+            .parent()
+            .and_then(|directory| other_file.strip_prefix(directory).ok())
+            .unwrap_or(other_file)
+            .display()
+            .to_string();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                selector.text_trimmed_range(),
+                markup! {
+                    "The selector "<Emphasis>{selector.text_trimmed().to_string()}</Emphasis>" is also used in "<Emphasis>{other_file}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The declarations of the rules override each other depending on the loading order of the stylesheets."
+            })
+            .note(markup! {
+                "Merge the rules in a single stylesheet, or use a more specific selector."
+            }),
+        )
+    }
+}
@@ -0,0 +1,206 @@
+use crate::color::{is_color_function, is_in_color_function, named_color, AnyColorValue};
+use crate::project::is_in_condition;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{AnyCssDeclarationName, CssFunction, CssGenericProperty};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::AstNode;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow hardcoded colors in favor of the custom properties of a design system.
+    ///
+    /// Design systems define their colors once, as custom properties such as `--color-primary`,
+    /// and the stylesheets refer to them with `var()`.
+    /// A hardcoded color doesn't follow the changes of the palette and doesn't adapt to the themes, such as a dark mode.
+    ///
+    /// The rule reports the hexadecimal colors, the named colors, and the color functions such as `rgb()` or `oklch()`
+    /// written in the values of the declarations.
+    /// The declarations of custom properties are allowed, because they are where the tokens are defined,
+    /// and so are the fallbacks of `var()` and the color functions whose channels use `var()`.
+    /// The keywords `transparent` and `currentcolor` aren't colors of the palette, so they are always allowed.
+    ///
+    /// Named colors are only checked in the properties that accept colors, such as `color` or `border`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .button {
+    ///   color: #1e90ff;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .button {
+    ///   border: 1px solid rgb(0 0 0 / 0.2);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// :root {
+    ///   --color-primary: #1e90ff;
+    /// }
+    ///
+    /// .button {
+    ///   color: var(--color-primary);
+    ///   background-color: transparent;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `allowedProperties`
+    ///
+    /// The properties whose values can contain hardcoded colors.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "allowedProperties": ["box-shadow"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,use_options
+    /// .card {
+    ///   box-shadow: 0 1px 2px rgb(0 0 0 / 0.1);
+    /// }
+    /// ```
+    ///
+    /// ### `allowedValues`
+    ///
+    /// The colors that can be hardcoded, such as `white` or `#000`.
+    /// The colors are compared case-insensitively with the colors of the stylesheets, without converting them:
+    /// allowing `#fff` doesn't allow `#ffffff` or `white`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "allowedValues": ["white", "#fff"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,use_options
+    /// .card {
+    ///   background-color: white;
+    ///   color: #FFF;
+    /// }
+    /// ```
+    ///
+    pub NoHardcodedColors {
+        version: "next",
+        name: "noHardcodedColors",
+        language: "css",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noHardcodedColors`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoHardcodedColorsOptions {
+    /// The properties whose values can contain hardcoded colors.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub allowed_properties: Box<[Box<str>]>,
+    /// The colors that can be hardcoded.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub allowed_values: Box<[Box<str>]>,
+}
+
+impl Rule for NoHardcodedColors {
+    type Query = Ast<AnyColorValue>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = Box<NoHardcodedColorsOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let options = ctx.options();
+        let property = node
+            .syntax()
+            .ancestors()
+            .find_map(CssGenericProperty::cast)?;
+        let AnyCssDeclarationName::CssIdentifier(name) = property.name().ok()? else {
+            // The declarations of custom properties define the design tokens
+            return None;
+        };
+        let name = name.value_token().ok()?;
+        if is_in_condition(property.syntax())
+            || is_in_color_function(node)
+            || is_in_var_fallback(node)
+            || options
+                .allowed_properties
+                .iter()
+                .any(|allowed| name.text_trimmed().eq_ignore_ascii_case(allowed))
+        {
+            return None;
+        }
+        let is_color = match node {
+            AnyColorValue::CssColor(_) => true,
+            AnyColorValue::CssFunction(function) => {
+                is_color_function(function) && !uses_var(function)
+            }
+            AnyColorValue::CssIdentifier(identifier) => {
+                named_color(identifier, &property).is_some()
+            }
+        };
+        let text = node.syntax().text_trimmed().to_string();
+        let is_allowed = options
+            .allowed_values
+            .iter()
+            .any(|allowed| text.eq_ignore_ascii_case(allowed));
+        (is_color && !is_allowed).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let color = node.syntax().text_trimmed().to_string();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The color "<Emphasis>{color}</Emphasis>" is hardcoded."
+                },
+            )
+            .note(markup! {
+                "Hardcoded colors don't follow the changes of the design system and don't adapt to its themes."
+            })
+            .note(markup! {
+                "Use a custom property of the design system instead, such as "<Emphasis>"var(--color-primary)"</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `node` is in an argument of `var()`, such as the fallback of `var(--color-primary, #1e90ff)`.
+fn is_in_var_fallback(node: &AnyColorValue) -> bool {
+    node.syntax()
+        .ancestors()
+        .skip(1)
+        .filter_map(CssFunction::cast)
+        .any(|function| is_var_function(&function))
+}
+
+/// Returns `true` if the arguments of `function` contain a `var()`, e.g. `rgb(var(--red) 0 0)`.
+fn uses_var(function: &CssFunction) -> bool {
+    function
+        .syntax()
+        .descendants()
+        .skip(1)
+        .filter_map(CssFunction::cast)
+        .any(|function| is_var_function(&function))
+}
+
+fn is_var_function(function: &CssFunction) -> bool {
+    function
+        .name()
+        .and_then(|name| name.value_token())
+        .is_ok_and(|name| name.text_trimmed().eq_ignore_ascii_case("var"))
+}
@@ -0,0 +1,230 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_css_semantic::model::Specificity;
+use biome_css_syntax::CssRoot;
+use biome_deserialize::{Deserializable, DeserializableValue, DeserializationDiagnostic};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::TextRange;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_lint_rule! {
+    /// Disallow selectors whose specificity is higher than a maximum.
+    ///
+    /// A selector with a high specificity can only be overridden by a selector with an even higher specificity,
+    /// or with `!important`. This leads to an escalation of the specificity across the stylesheets.
+    ///
+    /// The specificity of a selector is made of three numbers: the number of ID selectors,
+    /// the number of class selectors, attribute selectors, and pseudo-classes,
+    /// and the number of type selectors and pseudo-elements.
+    /// Two specificities are compared number by number, from left to right.
+    ///
+    /// The specificity of a nested selector includes the highest specificity of the selectors of its parent rule.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// #header .navigation a {
+    ///   color: inherit;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .card .header .title .icon .path {
+    ///   fill: currentcolor;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .navigation-link {
+    ///   color: inherit;
+    /// }
+    ///
+    /// :where(#header) .navigation a {
+    ///   color: inherit;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxSpecificity`
+    ///
+    /// The highest specificity allowed, written as `<ids>,<classes>,<types>`. The default is `0,4,0`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "maxSpecificity": "0,2,0"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// .card .header .title {
+    ///   font-weight: bold;
+    /// }
+    /// ```
+    ///
+    pub NoHighSpecificitySelectors {
+        version: "next",
+        name: "noHighSpecificitySelectors",
+        language: "css",
+        recommended: false,
+        sources: &[RuleSource::Stylelint("selector-max-specificity")],
+        source_kind: RuleSourceKind::Inspired,
+    }
+}
+
+/// Options for the rule `noHighSpecificitySelectors`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoHighSpecificitySelectorsOptions {
+    /// The highest specificity allowed, written as `<ids>,<classes>,<types>`.
+    pub max_specificity: MaxSpecificity,
+}
+
+/// A specificity written as `<ids>,<classes>,<types>`, such as `0,4,0`.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct MaxSpecificity(Specificity);
+
+impl Default for MaxSpecificity {
+    fn default() -> Self {
+        Self(Specificity(0, 4, 0))
+    }
+}
+
+impl std::fmt::Display for MaxSpecificity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Specificity(ids, classes, types) = self.0;
+        write!(f, "{ids},{classes},{types}")
+    }
+}
+
+impl FromStr for MaxSpecificity {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        const ERROR: &str =
+            "A specificity is expected to be written as `<ids>,<classes>,<types>`, such as `0,4,0`.";
+        let mut components = value
+            .split(',')
+            .map(|component| component.trim().parse::<u32>().map_err(|_| ERROR));
+        let (Some(ids), Some(classes), Some(types), None) = (
+            components.next(),
+            components.next(),
+            components.next(),
+            components.next(),
+        ) else {
+            return Err(ERROR);
+        };
+        Ok(Self(Specificity(ids?, classes?, types?)))
+    }
+}
+
+impl TryFrom<String> for MaxSpecificity {
+    type Error = &'static str;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<MaxSpecificity> for String {
+    fn from(value: MaxSpecificity) -> Self {
+        value.to_string()
+    }
+}
+
+impl Deserializable for MaxSpecificity {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        let text: String = Deserializable::deserialize(value, name, diagnostics)?;
+        match text.parse() {
+            Ok(specificity) => Some(specificity),
+            Err(error) => {
+                diagnostics.push(DeserializationDiagnostic::new(error).with_range(value.range()));
+                None
+            }
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for MaxSpecificity {
+    fn schema_name() -> String {
+        "MaxSpecificity".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+pub struct HighSpecificitySelector {
+    range: TextRange,
+    specificity: Specificity,
+}
+
+impl Rule for NoHighSpecificitySelectors {
+    type Query = Semantic<CssRoot>;
+    type State = HighSpecificitySelector;
+    type Signals = Box<[Self::State]>;
+    type Options = Box<NoHighSpecificitySelectorsOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        let max_specificity = &ctx.options().max_specificity.0;
+        let mut signals = Vec::new();
+        let mut rules: Vec<_> = model.rules().iter().rev().collect();
+        while let Some(rule) = rules.pop() {
+            signals.extend(
+                rule.selectors
+                    .iter()
+                    .filter(|selector| &selector.specificity > max_specificity)
+                    .map(|selector| HighSpecificitySelector {
+                        range: selector.range,
+                        specificity: selector.specificity.clone(),
+                    }),
+            );
+            rules.extend(
+                rule.child_ids
+                    .iter()
+                    .rev()
+                    .filter_map(|child_id| model.get_rule_by_id(*child_id)),
+            );
+        }
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let specificity = state.specificity.to_string();
+        let max_specificity = ctx.options().max_specificity.0.to_string();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The specificity "<Emphasis>{specificity}</Emphasis>" of this selector is higher than the maximum of "<Emphasis>{max_specificity}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Selectors with a high specificity can only be overridden by selectors with an even higher specificity."
+            })
+            .note(markup! {
+                "Use fewer IDs and classes in the selector, or wrap a part of it in "<Emphasis>":where()"</Emphasis>", which doesn't add any specificity."
+            }),
+        )
+    }
+}
@@ -0,0 +1,166 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_css_syntax::{CssIdSelector, CssPseudoClassFunctionSelectorList};
+use biome_deserialize_macros::Deserializable;
+use biome_glob::Glob;
+use biome_rowan::AstNode;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow ID selectors.
+    ///
+    /// An ID selector has a higher specificity than any number of class selectors,
+    /// so the rules that use it can only be overridden by other ID selectors or by `!important`.
+    /// IDs are also unique in a document, which prevents reusing the styles of the rule.
+    ///
+    /// Use a class selector instead, or an attribute selector such as `[id="header"]`
+    /// when the element can only be selected by its ID: it has the specificity of a class selector.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// #header {
+    ///   position: sticky;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .card:not(#featured) {
+    ///   opacity: 0.8;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .header {
+    ///   position: sticky;
+    /// }
+    ///
+    /// [id="header"] {
+    ///   position: sticky;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `allowInNot`
+    ///
+    /// Allow the ID selectors in the arguments of `:not()`, which exclude an element without styling it.
+    /// The default is `false`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "allowInNot": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,use_options
+    /// .card:not(#featured) {
+    ///   opacity: 0.8;
+    /// }
+    /// ```
+    ///
+    /// ### `allowedIds`
+    ///
+    /// A list of glob patterns that match the allowed IDs, without the leading `#`.
+    /// For instance, the IDs generated by a framework can be allowed with `__next` or `app-*`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "allowedIds": ["app-*"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,use_options
+    /// #app-root {
+    ///   min-height: 100vh;
+    /// }
+    /// ```
+    ///
+    pub NoIdSelectors {
+        version: "next",
+        name: "noIdSelectors",
+        language: "css",
+        recommended: false,
+        sources: &[RuleSource::Stylelint("selector-max-id")],
+        source_kind: RuleSourceKind::Inspired,
+    }
+}
+
+/// Options for the rule `noIdSelectors`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoIdSelectorsOptions {
+    /// Allow the ID selectors in the arguments of `:not()`.
+    pub allow_in_not: bool,
+    /// The IDs that can be selected.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub allowed_ids: Box<[Glob]>,
+}
+
+impl Rule for NoIdSelectors {
+    type Query = Ast<CssIdSelector>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = Box<NoIdSelectorsOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let options = ctx.options();
+        let name = node.name().ok()?.value_token().ok()?;
+        if options
+            .allowed_ids
+            .iter()
+            .any(|pattern| pattern.is_match(name.text_trimmed()))
+        {
+            return None;
+        }
+        if options.allow_in_not && is_in_not(node) {
+            return None;
+        }
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let name = node.name().ok()?.value_token().ok()?;
+        let name = name.text_trimmed();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "Avoid selecting elements by their ID."
+                },
+            )
+            .note(markup! {
+                "ID selectors have a high specificity that is hard to override, and they can't be reused for other elements."
+            })
+            .note(markup! {
+                "Use a class selector such as "<Emphasis>"."{name}</Emphasis>", or the attribute selector "<Emphasis>"[id=\""{name}"\"]"</Emphasis>" that has the specificity of a class selector."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `node` is in the arguments of `:not()`.
+fn is_in_not(node: &CssIdSelector) -> bool {
+    node.syntax()
+        .ancestors()
+        .filter_map(CssPseudoClassFunctionSelectorList::cast)
+        .any(|function| {
+            function
+                .name()
+                .is_ok_and(|name| name.text_trimmed().eq_ignore_ascii_case("not"))
+        })
+}
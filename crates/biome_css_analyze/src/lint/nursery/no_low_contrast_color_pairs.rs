@@ -0,0 +1,228 @@
+use crate::color::{literal_color, Color};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssProperty, CssDeclarationBlock, CssDeclarationOrRuleBlock,
+    CssDeclarationWithSemicolon, CssGenericProperty,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, TextRange};
+use biome_string_case::StrLikeExtension;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow text and background colors with an insufficient contrast.
+    ///
+    /// Text whose color is too close to the color of its background is hard to read,
+    /// in particular for people with low vision or color deficiencies.
+    /// The [Web Content Accessibility Guidelines](https://www.w3.org/TR/WCAG22/#contrast-minimum) (WCAG)
+    /// define the contrast ratio between two colors, from 1:1 for identical colors to 21:1 for black and white,
+    /// and require a contrast ratio of at least 4.5:1 for the level AA, and 7:1 for the level AAA.
+    ///
+    /// The rule reports the declaration blocks where both `color` and `background-color`, or a `background` that is a single color,
+    /// are literal colors whose contrast ratio is under the threshold of the configured level.
+    /// A translucent text color is blended with the background color. Translucent background colors are ignored,
+    /// because their color depends on the elements behind them.
+    ///
+    /// The colors set in other rules, and the size of the text, aren't taken into account:
+    /// large text only requires a contrast ratio of 3:1 for the level AA.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .badge {
+    ///   color: #999;
+    ///   background-color: white;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .banner {
+    ///   background: rgb(0 0 128);
+    ///   color: rgb(0 0 255 / 0.8);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .badge {
+    ///   color: #595959;
+    ///   background-color: white;
+    /// }
+    ///
+    /// .link {
+    ///   color: var(--link-color);
+    ///   background-color: white;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `level`
+    ///
+    /// The WCAG conformance level: `"AA"`, which requires a contrast ratio of 4.5:1 and is the default,
+    /// or `"AAA"`, which requires a contrast ratio of 7:1.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "level": "AAA"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// .badge {
+    ///   color: #666;
+    ///   background-color: white;
+    /// }
+    /// ```
+    ///
+    pub NoLowContrastColorPairs {
+        version: "next",
+        name: "noLowContrastColorPairs",
+        language: "css",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noLowContrastColorPairs`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoLowContrastColorPairsOptions {
+    /// The WCAG conformance level whose minimum contrast ratio is required.
+    pub level: ContrastLevel,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ContrastLevel {
+    /// A contrast ratio of at least 4.5:1.
+    #[default]
+    #[serde(rename = "AA")]
+    Aa,
+    /// A contrast ratio of at least 7:1.
+    #[serde(rename = "AAA")]
+    Aaa,
+}
+
+impl ContrastLevel {
+    fn minimum_ratio(self) -> f64 {
+        match self {
+            Self::Aa => 4.5,
+            Self::Aaa => 7.0,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Aa => "AA",
+            Self::Aaa => "AAA",
+        }
+    }
+}
+
+declare_node_union! {
+    pub AnyDeclarationBlock = CssDeclarationOrRuleBlock | CssDeclarationBlock
+}
+
+pub struct LowContrastPair {
+    foreground: TextRange,
+    background: TextRange,
+    ratio: f64,
+}
+
+impl Rule for NoLowContrastColorPairs {
+    type Query = Ast<AnyDeclarationBlock>;
+    type State = LowContrastPair;
+    type Signals = Option<Self::State>;
+    type Options = Box<NoLowContrastColorPairsOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let declarations: Vec<_> = match ctx.query() {
+            AnyDeclarationBlock::CssDeclarationOrRuleBlock(block) => block
+                .items()
+                .iter()
+                .filter_map(|item| CssDeclarationWithSemicolon::cast(item.into_syntax()))
+                .collect(),
+            AnyDeclarationBlock::CssDeclarationBlock(block) => block
+                .declarations()
+                .iter()
+                .filter_map(|item| CssDeclarationWithSemicolon::cast(item.into_syntax()))
+                .collect(),
+        };
+        // The last declarations win: an unknown value, such as `var()`, resets the color
+        let mut foreground: Option<(Color, TextRange)> = None;
+        let mut background: Option<(Color, TextRange)> = None;
+        for declaration in declarations {
+            let Ok(declaration) = declaration.declaration() else {
+                continue;
+            };
+            let Ok(AnyCssProperty::CssGenericProperty(property)) = declaration.property() else {
+                continue;
+            };
+            let Some(name) = property_name(&property) else {
+                continue;
+            };
+            let color = literal_color(&property).map(|color| (color, property.range()));
+            match name.as_ref() {
+                "color" => foreground = color,
+                "background-color" | "background" => background = color,
+                _ => {}
+            }
+        }
+        let (foreground, foreground_range) = foreground?;
+        let (background, background_range) = background?;
+        if !background.is_opaque() {
+            return None;
+        }
+        let foreground = if foreground.is_opaque() {
+            foreground
+        } else {
+            foreground.over(background)
+        };
+        let ratio = foreground.contrast_ratio(&background);
+        (ratio < ctx.options().level.minimum_ratio()).then_some(LowContrastPair {
+            foreground: foreground_range,
+            background: background_range,
+            ratio,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let level = ctx.options().level;
+        let name = level.name();
+        let minimum = level.minimum_ratio().to_string();
+        // Round down, so that a ratio of 4.499 isn't displayed as 4.5
+        let ratio = format!("{:.2}", (state.ratio * 100.0).floor() / 100.0);
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.foreground,
+                markup! {
+                    "The contrast ratio between this text color and the background color is "<Emphasis>{ratio}":1"</Emphasis>"."
+                },
+            )
+            .detail(state.background, markup! { "The background color is set here." })
+            .note(markup! {
+                "The level "{name}" of the Web Content Accessibility Guidelines requires a contrast ratio of at least "<Emphasis>{minimum}":1"</Emphasis>", so that the text is readable by people with low vision."
+            })
+            .note(markup! {
+                "Use a darker or lighter color for the text or the background."
+            }),
+        )
+    }
+}
+
+/// Returns the lowercase name of `property`, or `None` for custom properties.
+fn property_name(property: &CssGenericProperty) -> Option<String> {
+    let AnyCssDeclarationName::CssIdentifier(name) = property.name().ok()? else {
+        return None;
+    };
+    let name = name.value_token().ok()?;
+    Some(name.text_trimmed().to_ascii_lowercase_cow().to_string())
+}
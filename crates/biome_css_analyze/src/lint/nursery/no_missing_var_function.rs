@@ -51,7 +51,10 @@ declare_lint_rule! {
     /// ```
     ///
     /// ```css,expect_diagnostic
-    /// @property --bar {}
+    /// @property --bar {
+    ///   syntax: "*";
+    ///   inherits: false;
+    /// }
     ///
     /// a {
     ///   color: --bar;
@@ -100,7 +103,10 @@ declare_lint_rule! {
     /// ```
     ///
     /// ```css
-    /// @property --global-value {}
+    /// @property --global-value {
+    ///   syntax: "*";
+    ///   inherits: false;
+    /// }
     /// a {
     ///   color: var(--global-value);
     /// }
@@ -238,6 +244,18 @@ fn get_property_name(node: &CssDashedIdentifier) -> Option<String> {
             return match prop {
                 AnyCssProperty::CssBogusProperty(_) => None,
                 AnyCssProperty::CssComposesProperty(prop) => Some(prop.name().ok()?.text()),
+                AnyCssProperty::CssCounterStyleAdditiveSymbolsProperty(prop) => {
+                    Some(prop.name().ok()?.text())
+                }
+                AnyCssProperty::CssCounterStyleRangeProperty(prop) => {
+                    Some(prop.name().ok()?.text())
+                }
+                AnyCssProperty::CssCounterStyleSymbolsProperty(prop) => {
+                    Some(prop.name().ok()?.text())
+                }
+                AnyCssProperty::CssCounterStyleSystemProperty(prop) => {
+                    Some(prop.name().ok()?.text())
+                }
                 AnyCssProperty::CssGenericProperty(prop) => Some(prop.name().ok()?.text()),
             };
         }
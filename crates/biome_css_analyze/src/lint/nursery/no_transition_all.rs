@@ -0,0 +1,263 @@
+use crate::project::{is_in_condition, selector_text};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssGenericComponentValue, AnyCssProperty, AnyCssValue,
+    CssDeclarationWithSemicolon, CssGenericProperty, CssNestedQualifiedRule, CssQualifiedRule,
+    CssSyntaxNode,
+};
+use biome_rowan::{AstNode, AstNodeList, SyntaxKind, SyntaxNodeCast, TextRange};
+use biome_string_case::StrLikeExtension;
+
+declare_lint_rule! {
+    /// Disallow transitions of all the properties.
+    ///
+    /// `transition: all` animates every property that changes, including the ones that shouldn't be animated,
+    /// such as a color that changes when the theme switches or a size that changes when the viewport is resized.
+    /// It also makes the browser track all the properties of the element, and any new property added later to the rule
+    /// is animated by accident.
+    ///
+    /// The rule reports the `all` keyword in the `transition` and `transition-property` properties.
+    /// List the transitioned properties explicitly instead.
+    /// A `transition` shorthand without a property, such as `transition: 0.3s`, also transitions all the properties,
+    /// but it isn't reported.
+    ///
+    /// When the stylesheet contains `:hover` or `:focus` rules for the same selector,
+    /// the diagnostic suggests the properties that these rules change.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .button {
+    ///   transition: all 0.2s ease-out;
+    /// }
+    ///
+    /// .button:hover {
+    ///   background-color: navy;
+    ///   transform: scale(1.05);
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .link {
+    ///   transition-property: all;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .button {
+    ///   transition: background-color 0.2s ease-out, transform 0.2s ease-out;
+    /// }
+    /// ```
+    ///
+    pub NoTransitionAll {
+        version: "next",
+        name: "noTransitionAll",
+        language: "css",
+        recommended: false,
+    }
+}
+
+/// The pseudo-classes of the interactive states whose changes are usually transitioned.
+const STATE_PSEUDO_CLASSES: [&str; 4] = [":hover", ":focus", ":focus-visible", ":focus-within"];
+
+/// The prefixes of the properties that can't be transitioned, or whose transition isn't meaningful.
+const NON_TRANSITIONABLE_PROPERTIES: [&str; 7] = [
+    "animation",
+    "content",
+    "cursor",
+    "pointer-events",
+    "transition",
+    "user-select",
+    "will-change",
+];
+
+pub struct TransitionAll {
+    range: TextRange,
+    /// The properties changed by the `:hover` and `:focus` rules of the same selector.
+    suggestions: Box<[Box<str>]>,
+}
+
+impl Rule for NoTransitionAll {
+    type Query = Ast<CssGenericProperty>;
+    type State = TransitionAll;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let Ok(AnyCssDeclarationName::CssIdentifier(name)) = node.name() else {
+            return Box::default();
+        };
+        let is_transition = name.value_token().is_ok_and(|name| {
+            matches!(
+                name.text_trimmed().to_ascii_lowercase_cow().as_ref(),
+                "transition" | "transition-property"
+            )
+        });
+        if !is_transition || is_in_condition(node.syntax()) {
+            return Box::default();
+        }
+        let ranges: Vec<_> = node
+            .value()
+            .iter()
+            .filter_map(|component| match component {
+                AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssIdentifier(
+                    identifier,
+                )) => identifier
+                    .value_token()
+                    .is_ok_and(|token| token.text_trimmed().eq_ignore_ascii_case("all"))
+                    .then(|| identifier.range()),
+                _ => None,
+            })
+            .collect();
+        if ranges.is_empty() {
+            return Box::default();
+        }
+        let suggestions: Box<[Box<str>]> = changed_properties(node).into();
+        ranges
+            .into_iter()
+            .map(|range| TransitionAll {
+                range,
+                suggestions: suggestions.clone(),
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.range,
+            markup! {
+                "Avoid transitioning "<Emphasis>"all"</Emphasis>" the properties."
+            },
+        )
+        .note(markup! {
+            "Every property that changes is animated, including the ones that shouldn't be, and the properties added to the rule later."
+        });
+        let diagnostic = if state.suggestions.is_empty() {
+            diagnostic.note(markup! {
+                "List the transitioned properties explicitly instead."
+            })
+        } else {
+            let suggestions = state.suggestions.join(", ");
+            diagnostic.note(markup! {
+                "List the properties changed by the "<Emphasis>":hover"</Emphasis>" and "<Emphasis>":focus"</Emphasis>" rules of this selector instead: "<Emphasis>{suggestions}</Emphasis>"."
+            })
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the properties changed by the rules that style the interactive states of the rule of `property`:
+/// the nested `&:hover` rules, and the rules whose selector is the selector of the rule followed by `:hover`.
+fn changed_properties(property: &CssGenericProperty) -> Vec<Box<str>> {
+    let Some(rule) = property.syntax().ancestors().find(is_style_rule) else {
+        return Vec::new();
+    };
+    let selectors = style_rule_selectors(&rule);
+    let state_selectors: Vec<String> = selectors
+        .iter()
+        .chain(std::iter::once(&"&".to_string()))
+        .flat_map(|selector| {
+            STATE_PSEUDO_CLASSES
+                .iter()
+                .map(move |state| format!("{selector}{state}"))
+        })
+        .collect();
+    let Some(root) = rule.ancestors().last() else {
+        return Vec::new();
+    };
+    let mut properties: Vec<Box<str>> = Vec::new();
+    for state_rule in root.descendants().filter(|node| {
+        is_style_rule(node)
+            && style_rule_selectors(node)
+                .iter()
+                .any(|selector| state_selectors.contains(selector))
+    }) {
+        // The `&:hover` rules only style the interactive states of the rule they're nested in
+        if CssNestedQualifiedRule::can_cast(state_rule.kind())
+            && state_rule.ancestors().skip(1).find(is_style_rule).as_ref() != Some(&rule)
+            && style_rule_selectors(&state_rule)
+                .iter()
+                .all(|selector| selector.starts_with('&'))
+        {
+            continue;
+        }
+        for name in declared_properties(&state_rule) {
+            let is_transitionable = !name.starts_with("--")
+                && !NON_TRANSITIONABLE_PROPERTIES
+                    .iter()
+                    .any(|prefix| name.starts_with(prefix));
+            if is_transitionable && !properties.contains(&name) {
+                properties.push(name);
+            }
+        }
+    }
+    properties
+}
+
+fn is_style_rule(node: &CssSyntaxNode) -> bool {
+    CssQualifiedRule::can_cast(node.kind()) || CssNestedQualifiedRule::can_cast(node.kind())
+}
+
+fn style_rule_selectors(rule: &CssSyntaxNode) -> Vec<String> {
+    let prelude = if let Some(rule) = CssQualifiedRule::cast_ref(rule) {
+        rule.prelude().into_syntax()
+    } else if let Some(rule) = CssNestedQualifiedRule::cast_ref(rule) {
+        rule.prelude().into_syntax()
+    } else {
+        return Vec::new();
+    };
+    prelude
+        .children()
+        .filter(|selector| !selector.kind().is_bogus())
+        .map(|selector| selector_text(&selector))
+        .collect()
+}
+
+/// Returns the lowercase names of the properties declared directly in the block of `rule`.
+fn declared_properties(rule: &CssSyntaxNode) -> Vec<Box<str>> {
+    let block = rule
+        .clone()
+        .cast::<CssQualifiedRule>()
+        .and_then(|rule| rule.block().ok())
+        .or_else(|| {
+            rule.clone()
+                .cast::<CssNestedQualifiedRule>()
+                .and_then(|rule| rule.block().ok())
+        });
+    let Some(block) = block else {
+        return Vec::new();
+    };
+    block
+        .syntax()
+        .descendants()
+        .filter_map(CssDeclarationWithSemicolon::cast)
+        .filter(|declaration| {
+            declaration
+                .syntax()
+                .ancestors()
+                .skip(1)
+                .find(is_style_rule)
+                .as_ref()
+                == Some(rule)
+        })
+        .filter_map(|declaration| {
+            let AnyCssProperty::CssGenericProperty(property) =
+                declaration.declaration().ok()?.property().ok()?
+            else {
+                return None;
+            };
+            let AnyCssDeclarationName::CssIdentifier(name) = property.name().ok()? else {
+                return None;
+            };
+            let name = name.value_token().ok()?;
+            Some(name.text_trimmed().to_ascii_lowercase_cow().into())
+        })
+        .collect()
+}
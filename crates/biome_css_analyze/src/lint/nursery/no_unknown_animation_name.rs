@@ -0,0 +1,95 @@
+use crate::project::{animation_names, CssFileSummary};
+use crate::services::project::Project;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{AnyCssValue, CssGenericProperty, CssRoot};
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Disallow animation names that don't match any `@keyframes`.
+    ///
+    /// The names referenced by the `animation` and `animation-name` properties must be defined by a `@keyframes` at-rule,
+    /// otherwise the animation doesn't run.
+    /// The rule looks for the `@keyframes` in the current stylesheet and in all the other stylesheets handled by Biome,
+    /// so keyframes defined in a shared stylesheet can be used in a component stylesheet.
+    ///
+    /// Note that keyframes defined by stylesheets that aren't handled by Biome, such as the ones of a third-party library,
+    /// aren't taken into account.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .modal {
+    ///   animation: fade-in 200ms ease-out;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// @keyframes fade-in {
+    ///   from { opacity: 0; }
+    ///   to { opacity: 1; }
+    /// }
+    ///
+    /// .modal {
+    ///   animation: fade-in 200ms ease-out;
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// .spinner {
+    ///   animation-name: none;
+    /// }
+    /// ```
+    ///
+    pub NoUnknownAnimationName {
+        version: "next",
+        name: "noUnknownAnimationName",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnknownAnimationName {
+    type Query = Project<CssRoot>;
+    type State = (AnyCssValue, Box<str>);
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let summary = CssFileSummary::from_root(root);
+        root.syntax()
+            .descendants()
+            .filter_map(CssGenericProperty::cast)
+            .flat_map(|property| animation_names(&property))
+            .filter(|(_, name)| {
+                !summary.defines_keyframes(name)
+                    && !ctx
+                        .project()
+                        .any_other_file(ctx.file_path(), |file| file.defines_keyframes(name))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, (value, name): &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                value.range(),
+                markup! {
+                    "The animation name "<Emphasis>{name}</Emphasis>" doesn't match any "<Emphasis>"@keyframes"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The animation doesn't run when its keyframes aren't defined."
+            })
+            .note(markup! {
+                "Define the keyframes, or fix the name of the animation."
+            }),
+        )
+    }
+}
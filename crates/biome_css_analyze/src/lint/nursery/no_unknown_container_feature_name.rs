@@ -0,0 +1,105 @@
+use crate::keywords::CONTAINER_SIZE_FEATURE_NAMES;
+use crate::utils::{find_similar_name, is_container_size_feature_name, is_media_feature_name};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{AnyCssQueryFeature, CssContainerSizeFeatureInParens, CssSyntaxToken};
+use biome_string_case::StrLikeExtension;
+
+declare_lint_rule! {
+    /// Disallow unknown size feature names in container queries.
+    ///
+    /// The size queries of `@container` can only test the size features of the container:
+    /// `width`, `height`, `inline-size`, `block-size`, `aspect-ratio`, and `orientation`,
+    /// as well as the `min-` and `max-` prefixed forms of the range features.
+    /// A container query that tests an unknown feature, such as a misspelled feature or a media feature, never matches.
+    ///
+    /// When the name is close to the name of a size feature, the diagnostic suggests it.
+    /// The feature names of media queries are checked by [noUnknownMediaFeatureName](https://biomejs.dev/linter/rules/no-unknown-media-feature-name/).
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// @container (inlin-size > 400px) {}
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @container card (min-widht: 400px) {}
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @container (hover: hover) {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// @container (inline-size > 400px) {}
+    /// ```
+    ///
+    /// ```css
+    /// @container card (min-width: 400px) and (orientation: landscape) {}
+    /// ```
+    ///
+    pub NoUnknownContainerFeatureName {
+        version: "next",
+        name: "noUnknownContainerFeatureName",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnknownContainerFeatureName {
+    type Query = Ast<CssContainerSizeFeatureInParens>;
+    type State = CssSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let name = feature_name(ctx.query().feature().ok()?)?;
+        (!is_container_size_feature_name(name.text_trimmed())).then_some(name)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        let text = name.text_trimmed();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            name.text_trimmed_range(),
+            markup! {
+                "The container size feature "<Emphasis>{text}</Emphasis>" is unknown."
+            },
+        )
+        .note(markup! {
+            "A container query that tests an unknown feature never matches."
+        });
+        let lowercase_text = text.to_ascii_lowercase_cow();
+        let diagnostic = if let Some(suggestion) =
+            find_similar_name(&lowercase_text, CONTAINER_SIZE_FEATURE_NAMES)
+        {
+            diagnostic.note(markup! {
+                "Did you mean "<Emphasis>{suggestion}</Emphasis>"?"
+            })
+        } else if is_media_feature_name(&lowercase_text) {
+            diagnostic.note(markup! {
+                <Emphasis>{text}</Emphasis>" is a media feature: test it in an "<Emphasis>"@media"</Emphasis>" query instead."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "The size features are "<Emphasis>"width"</Emphasis>", "<Emphasis>"height"</Emphasis>", "<Emphasis>"inline-size"</Emphasis>", "<Emphasis>"block-size"</Emphasis>", "<Emphasis>"aspect-ratio"</Emphasis>", and "<Emphasis>"orientation"</Emphasis>"."
+            })
+        };
+        Some(diagnostic)
+    }
+}
+
+fn feature_name(feature: AnyCssQueryFeature) -> Option<CssSyntaxToken> {
+    let name = match feature {
+        AnyCssQueryFeature::CssQueryFeaturePlain(feature) => feature.name(),
+        AnyCssQueryFeature::CssQueryFeatureRange(feature) => feature.left(),
+        AnyCssQueryFeature::CssQueryFeatureReverseRange(feature) => feature.right(),
+        AnyCssQueryFeature::CssQueryFeatureRangeInterval(feature) => feature.name(),
+        AnyCssQueryFeature::CssQueryFeatureBoolean(feature) => feature.name(),
+    };
+    name.ok()?.value_token().ok()
+}
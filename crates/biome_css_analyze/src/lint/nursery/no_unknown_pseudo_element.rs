@@ -71,7 +71,13 @@ impl Rule for NoUnknownPseudoElement {
         let pseudo_element_name = match &pseudo_element {
             AnyCssPseudoElement::CssBogusPseudoElement(element) => element.text(),
             AnyCssPseudoElement::CssPseudoElementFunctionIdentifier(ident) => {
-                ident.name().ok()?.text().to_string()
+                ident.name_token().ok()?.text().to_string()
+            }
+            AnyCssPseudoElement::CssPseudoElementFunctionCustomIdentifierList(ident) => {
+                ident.name_token().ok()?.text().to_string()
+            }
+            AnyCssPseudoElement::CssPseudoElementFunctionCompoundSelector(selector) => {
+                selector.name_token().ok()?.text().to_string()
             }
             AnyCssPseudoElement::CssPseudoElementFunctionSelector(selector) => selector.text(),
             AnyCssPseudoElement::CssPseudoElementIdentifier(ident) => {
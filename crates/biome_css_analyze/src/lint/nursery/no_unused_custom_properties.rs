@@ -0,0 +1,112 @@
+use crate::project::{declared_custom_property, CssFileSummary};
+use crate::services::project::Project;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{CssDashedIdentifier, CssGenericProperty, CssRoot};
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Disallow custom properties that are never read.
+    ///
+    /// A custom property is read when it's referenced by a `var()` function,
+    /// or when it's tested by a container style query.
+    /// The rule looks for the references in the current stylesheet and in all the other stylesheets handled by Biome,
+    /// so a custom property declared in a theme file and read in a component stylesheet isn't reported.
+    ///
+    /// Note that stylesheets that aren't handled by Biome, as well as scripts that read custom properties
+    /// with `getPropertyValue()`, aren't taken into account.
+    ///
+    /// The rule only reports custom properties when Biome analyzes the whole project, e.g. with the `lint` or the `check` command.
+    /// It doesn't report anything in editors, or when a single file is read from the standard input,
+    /// because the other stylesheets of the project aren't known.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// :root {
+    ///   --brand-color: rebeccapurple;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// :root {
+    ///   --brand-color: rebeccapurple;
+    /// }
+    ///
+    /// a {
+    ///   color: var(--brand-color);
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// .card {
+    ///   --variant: compact;
+    /// }
+    ///
+    /// @container style(--variant: compact) {
+    ///   .title {
+    ///     font-size: small;
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnusedCustomProperties {
+        version: "next",
+        name: "noUnusedCustomProperties",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnusedCustomProperties {
+    type Query = Project<CssRoot>;
+    type State = CssDashedIdentifier;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        // The usage could be in a stylesheet that isn't indexed
+        if !ctx.project().is_complete() {
+            return Box::default();
+        }
+        let root = ctx.query();
+        let summary = CssFileSummary::from_root(root);
+        root.syntax()
+            .descendants()
+            .filter_map(CssGenericProperty::cast)
+            .filter_map(|property| declared_custom_property(&property))
+            .filter(|name| {
+                let Ok(token) = name.value_token() else {
+                    return false;
+                };
+                let name = token.text_trimmed();
+                !summary.uses_custom_property(name)
+                    && !ctx
+                        .project()
+                        .any_other_file(ctx.file_path(), |file| file.uses_custom_property(name))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.range(),
+                markup! {
+                    "The custom property "<Emphasis>{name.text()}</Emphasis>" is never read."
+                },
+            )
+            .note(markup! {
+                "No stylesheet of the project references it with "<Emphasis>"var()"</Emphasis>"."
+            })
+            .note(markup! {
+                "Remove the declaration, or reference the custom property where it's needed."
+            }),
+        )
+    }
+}
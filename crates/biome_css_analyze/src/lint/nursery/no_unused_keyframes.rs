@@ -0,0 +1,104 @@
+use crate::project::{keyframes_name, keyframes_name_text, CssFileSummary};
+use crate::services::project::Project;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{AnyCssKeyframesIdentifier, CssKeyframesAtRule, CssRoot};
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Disallow `@keyframes` that are never used by an animation.
+    ///
+    /// A `@keyframes` at-rule is used when its name is referenced by the `animation` or the `animation-name` property.
+    /// The rule looks for the references in the current stylesheet and in all the other stylesheets handled by Biome,
+    /// so keyframes defined in a shared stylesheet and used in a component stylesheet aren't reported.
+    ///
+    /// Note that stylesheets that aren't handled by Biome, as well as scripts that set animations,
+    /// aren't taken into account.
+    ///
+    /// The rule only reports keyframes when Biome analyzes the whole project, e.g. with the `lint` or the `check` command.
+    /// It doesn't report anything in editors, or when a single file is read from the standard input,
+    /// because the other stylesheets of the project aren't known.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// @keyframes fade-in {
+    ///   from { opacity: 0; }
+    ///   to { opacity: 1; }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// @keyframes fade-in {
+    ///   from { opacity: 0; }
+    ///   to { opacity: 1; }
+    /// }
+    ///
+    /// .modal {
+    ///   animation: fade-in 200ms ease-out;
+    /// }
+    /// ```
+    ///
+    pub NoUnusedKeyframes {
+        version: "next",
+        name: "noUnusedKeyframes",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnusedKeyframes {
+    type Query = Project<CssRoot>;
+    type State = (AnyCssKeyframesIdentifier, Box<str>);
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        // The usage could be in a stylesheet that isn't indexed
+        if !ctx.project().is_complete() {
+            return Box::default();
+        }
+        let root = ctx.query();
+        let summary = CssFileSummary::from_root(root);
+        root.syntax()
+            .descendants()
+            .filter_map(CssKeyframesAtRule::cast)
+            .filter_map(|at_rule| {
+                let identifier = keyframes_name(&at_rule)?;
+                let name = keyframes_name_text(&identifier)?;
+                Some((identifier, name))
+            })
+            .filter(|(_, name)| {
+                !summary.uses_animation_name(name)
+                    && !ctx
+                        .project()
+                        .any_other_file(ctx.file_path(), |file| file.uses_animation_name(name))
+            })
+            .collect()
+    }
+
+    fn diagnostic(
+        _: &RuleContext<Self>,
+        (identifier, name): &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                identifier.range(),
+                markup! {
+                    "The keyframes "<Emphasis>{name}</Emphasis>" are never used."
+                },
+            )
+            .note(markup! {
+                "No stylesheet of the project references them with "<Emphasis>"animation"</Emphasis>" or "<Emphasis>"animation-name"</Emphasis>"."
+            })
+            .note(markup! {
+                "Remove the keyframes, or use them in an animation."
+            }),
+        )
+    }
+}
@@ -0,0 +1,519 @@
+use crate::project::is_in_condition;
+use crate::utils::vender_prefix;
+use crate::CssRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_css_compat::{find_feature, BrowserTargets, FeatureKind};
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssProperty, CssAtRule, CssDeclarationWithSemicolon,
+    CssGenericProperty, CssIdentifier, CssKeyframesAtRule, CssSyntaxKind, CssSyntaxNode,
+    CssSyntaxToken, T,
+};
+use biome_deserialize::{DeserializableValidator, DeserializationDiagnostic};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, TextRange};
+use biome_string_case::StrLikeExtension;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow vendor prefixes that aren't needed by the target browsers.
+    ///
+    /// Vendor prefixes such as `-webkit-` or `-moz-` were required while browsers implemented experimental features.
+    /// Once the target browsers support the standard form of a feature, the prefixed form is dead code
+    /// that makes the stylesheets longer and harder to maintain.
+    ///
+    /// The rule reports:
+    ///
+    /// - the prefixed properties whose standard property has the same syntax, such as `-webkit-transform`;
+    /// - the prefixed values, such as `position: -webkit-sticky` or `width: -moz-min-content`;
+    /// - the prefixed `@keyframes` at-rules.
+    ///
+    /// Prefixed features without an equivalent standard form, such as `-webkit-line-clamp` or `-webkit-box`, are never reported.
+    ///
+    /// By default, a prefix is reported when the standard form is part of [Baseline](https://web.dev/baseline),
+    /// that is, when it's supported by the current version of all the major browsers.
+    ///
+    /// The fix removes the prefixed declaration or at-rule when the standard form is already declared next to it,
+    /// and replaces the prefixed name with the standard one otherwise.
+    /// The fix is unsafe because the browsers that aren't targeted lose the support of the feature.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .card {
+    ///   -webkit-transform: rotate(45deg);
+    ///   transform: rotate(45deg);
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .header {
+    ///   position: -webkit-sticky;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @-webkit-keyframes spin {
+    ///   to {
+    ///     rotate: 1turn;
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .card {
+    ///   transform: rotate(45deg);
+    ///   -webkit-line-clamp: 3;
+    ///   -webkit-user-select: none;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `browserslist`
+    ///
+    /// The target browsers, written as a [browserslist](https://github.com/browserslist/browserslist) query
+    /// made of `<browser> >= <version>` queries, separated by a comma or by `or`.
+    /// The supported browsers are the same as the ones of [useBrowserCompat](https://biomejs.dev/linter/rules/use-browser-compat/).
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "browserslist": "chrome >= 120, firefox >= 115, safari >= 17"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// .overlay {
+    ///   -webkit-mask-image: url(mask.svg);
+    /// }
+    /// ```
+    ///
+    pub NoVendorPrefixes {
+        version: "next",
+        name: "noVendorPrefixes",
+        language: "css",
+        recommended: false,
+        sources: &[
+            RuleSource::Stylelint("property-no-vendor-prefix"),
+            RuleSource::Stylelint("value-no-vendor-prefix"),
+            RuleSource::Stylelint("at-rule-no-vendor-prefix"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// The standard properties whose prefixed forms have the same syntax.
+const PREFIXABLE_PROPERTIES: [&str; 73] = [
+    "align-content",
+    "align-items",
+    "align-self",
+    "animation",
+    "animation-delay",
+    "animation-direction",
+    "animation-duration",
+    "animation-fill-mode",
+    "animation-iteration-count",
+    "animation-name",
+    "animation-play-state",
+    "animation-timing-function",
+    "appearance",
+    "backdrop-filter",
+    "backface-visibility",
+    "background-origin",
+    "background-size",
+    "border-bottom-left-radius",
+    "border-bottom-right-radius",
+    "border-image",
+    "border-radius",
+    "border-top-left-radius",
+    "border-top-right-radius",
+    "box-decoration-break",
+    "box-shadow",
+    "box-sizing",
+    "clip-path",
+    "column-count",
+    "column-fill",
+    "column-gap",
+    "column-rule",
+    "column-rule-color",
+    "column-rule-style",
+    "column-rule-width",
+    "column-span",
+    "column-width",
+    "columns",
+    "filter",
+    "flex",
+    "flex-basis",
+    "flex-direction",
+    "flex-flow",
+    "flex-grow",
+    "flex-shrink",
+    "flex-wrap",
+    "font-feature-settings",
+    "hyphens",
+    "justify-content",
+    "mask",
+    "mask-clip",
+    "mask-image",
+    "mask-origin",
+    "mask-position",
+    "mask-repeat",
+    "mask-size",
+    "order",
+    "perspective",
+    "perspective-origin",
+    "tab-size",
+    "text-emphasis",
+    "text-emphasis-color",
+    "text-emphasis-position",
+    "text-emphasis-style",
+    "text-overflow",
+    "text-size-adjust",
+    "transform",
+    "transform-origin",
+    "transform-style",
+    "transition",
+    "transition-delay",
+    "transition-duration",
+    "transition-property",
+    "transition-timing-function",
+];
+
+/// The prefixed values and their standard forms, which are supported by all the major browsers.
+const PREFIXED_VALUES: [(&str, &str); 19] = [
+    ("-moz-calc", "calc"),
+    ("-moz-grab", "grab"),
+    ("-moz-grabbing", "grabbing"),
+    ("-moz-max-content", "max-content"),
+    ("-moz-min-content", "min-content"),
+    ("-moz-zoom-in", "zoom-in"),
+    ("-moz-zoom-out", "zoom-out"),
+    ("-ms-flexbox", "flex"),
+    ("-ms-inline-flexbox", "inline-flex"),
+    ("-webkit-calc", "calc"),
+    ("-webkit-flex", "flex"),
+    ("-webkit-grab", "grab"),
+    ("-webkit-grabbing", "grabbing"),
+    ("-webkit-inline-flex", "inline-flex"),
+    ("-webkit-max-content", "max-content"),
+    ("-webkit-min-content", "min-content"),
+    ("-webkit-sticky", "sticky"),
+    ("-webkit-zoom-in", "zoom-in"),
+    ("-webkit-zoom-out", "zoom-out"),
+];
+
+/// The properties whose values are property names, such as `transition: -webkit-transform 1s`.
+const PROPERTIES_WITH_PROPERTY_VALUES: [&str; 3] =
+    ["transition", "transition-property", "will-change"];
+
+/// Options for the rule `noVendorPrefixes`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[deserializable(with_validator)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoVendorPrefixesOptions {
+    /// A browserslist query of the target browsers, e.g. `"chrome >= 110, safari >= 16.4"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browserslist: Option<Box<str>>,
+}
+
+impl DeserializableValidator for NoVendorPrefixesOptions {
+    fn validate(
+        &mut self,
+        _name: &str,
+        range: TextRange,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> bool {
+        if let Some(Err(error)) = self.browserslist.as_deref().map(BrowserTargets::parse) {
+            diagnostics.push(
+                DeserializationDiagnostic::new(markup!({ error.to_string() })).with_range(range),
+            );
+            return false;
+        }
+        true
+    }
+}
+
+impl NoVendorPrefixesOptions {
+    /// Returns `true` if the target browsers support the standard `property`.
+    fn supports_property(&self, property: &str) -> bool {
+        let Some(feature) = find_feature(FeatureKind::Property, property) else {
+            // The properties that aren't in the compatibility data are supported everywhere
+            return true;
+        };
+        match self.browserslist.as_deref() {
+            Some(browserslist) => BrowserTargets::parse(browserslist)
+                .is_ok_and(|targets| targets.unsupported(&feature.support).is_empty()),
+            None => feature.baseline.is_some(),
+        }
+    }
+
+    /// Returns the standard form of a prefixed property name, if the target browsers support it.
+    fn standard_property(&self, name: &str) -> Option<&'static str> {
+        let prefix = vender_prefix(name);
+        if prefix.is_empty() {
+            return None;
+        }
+        let standard = PREFIXABLE_PROPERTIES
+            .iter()
+            .find(|property| **property == &name[prefix.len()..])?;
+        self.supports_property(standard).then_some(*standard)
+    }
+}
+
+declare_node_union! {
+    pub AnyVendorPrefixable = CssGenericProperty | CssKeyframesAtRule
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PrefixedKind {
+    Property,
+    Value,
+    AtRule,
+}
+
+pub struct PrefixedUsage {
+    token: CssSyntaxToken,
+    standard: &'static str,
+    kind: PrefixedKind,
+    /// The prefixed declaration or at-rule, when its standard form is already declared next to it.
+    redundant: Option<CssSyntaxNode>,
+}
+
+impl Rule for NoVendorPrefixes {
+    type Query = Ast<AnyVendorPrefixable>;
+    type State = PrefixedUsage;
+    type Signals = Box<[Self::State]>;
+    type Options = Box<NoVendorPrefixesOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        match ctx.query() {
+            AnyVendorPrefixable::CssGenericProperty(property) => {
+                prefixed_property_usages(property, options)
+            }
+            AnyVendorPrefixable::CssKeyframesAtRule(at_rule) => {
+                prefixed_keyframes(at_rule).into_iter().collect()
+            }
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let prefixed = state.token.text_trimmed();
+        let standard = state.standard;
+        let (message, note) = match state.kind {
+            PrefixedKind::Property => (
+                markup! {
+                    "The vendor-prefixed property "<Emphasis>{prefixed}</Emphasis>" isn't needed."
+                }
+                .to_owned(),
+                markup! {
+                    "The target browsers support the standard property "<Emphasis>{standard}</Emphasis>"."
+                }
+                .to_owned(),
+            ),
+            PrefixedKind::Value => (
+                markup! {
+                    "The vendor-prefixed value "<Emphasis>{prefixed}</Emphasis>" isn't needed."
+                }
+                .to_owned(),
+                markup! {
+                    "The target browsers support the standard value "<Emphasis>{standard}</Emphasis>"."
+                }
+                .to_owned(),
+            ),
+            PrefixedKind::AtRule => (
+                markup! {
+                    "The vendor-prefixed at-rule "<Emphasis>"@"{prefixed}</Emphasis>" isn't needed."
+                }
+                .to_owned(),
+                markup! {
+                    "The target browsers support the standard at-rule "<Emphasis>"@"{standard}</Emphasis>"."
+                }
+                .to_owned(),
+            ),
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), state.token.text_trimmed_range(), message)
+                .note(note),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let standard = state.standard;
+        let message = if let Some(redundant) = &state.redundant {
+            mutation.remove_element(redundant.clone().into());
+            match state.kind {
+                PrefixedKind::AtRule => markup! { "Remove the prefixed at-rule." }.to_owned(),
+                PrefixedKind::Property | PrefixedKind::Value => {
+                    markup! { "Remove the prefixed declaration." }.to_owned()
+                }
+            }
+        } else {
+            let kind = if state.kind == PrefixedKind::AtRule {
+                CssSyntaxKind::KEYFRAMES_KW
+            } else {
+                T![ident]
+            };
+            mutation.replace_token_transfer_trivia(
+                state.token.clone(),
+                CssSyntaxToken::new_detached(kind, standard, [], []),
+            );
+            markup! { "Use "<Emphasis>{standard}</Emphasis>" instead." }.to_owned()
+        };
+        Some(CssRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            message,
+            mutation,
+        ))
+    }
+}
+
+fn prefixed_property_usages(
+    property: &CssGenericProperty,
+    options: &NoVendorPrefixesOptions,
+) -> Box<[PrefixedUsage]> {
+    let Some(name) = property_name(property) else {
+        return Box::default();
+    };
+    if is_in_condition(property.syntax()) {
+        return Box::default();
+    }
+    let lowercase_name = name.text_trimmed().to_ascii_lowercase_cow().to_string();
+    let declaration = property
+        .syntax()
+        .ancestors()
+        .find_map(CssDeclarationWithSemicolon::cast);
+    let mut usages = Vec::new();
+    if let Some(standard) = options.standard_property(&lowercase_name) {
+        let redundant = declaration.as_ref().and_then(|declaration| {
+            has_sibling_declaration(declaration, |sibling, _| sibling == standard)
+                .then(|| declaration.syntax().clone())
+        });
+        usages.push(PrefixedUsage {
+            token: name,
+            standard,
+            kind: PrefixedKind::Property,
+            redundant,
+        });
+    }
+    // The standard property of the declaration, to find the standard form of its prefixed values
+    let standard_name = lowercase_name.trim_start_matches(vender_prefix(&lowercase_name));
+    let accepts_properties = PROPERTIES_WITH_PROPERTY_VALUES.contains(&standard_name);
+    let value = property.value();
+    let value_text = value.syntax().text_trimmed().to_string();
+    usages.extend(
+        value
+            .syntax()
+            .descendants()
+            .filter_map(CssIdentifier::cast)
+            .filter_map(|identifier| {
+                let token = identifier.value_token().ok()?;
+                let text = token.text_trimmed().to_ascii_lowercase_cow();
+                let standard = PREFIXED_VALUES
+                    .iter()
+                    .find_map(|(prefixed, standard)| (*prefixed == text).then_some(*standard))
+                    .or_else(|| {
+                        accepts_properties
+                            .then(|| options.standard_property(&text))
+                            .flatten()
+                    })?;
+                let standard_value = value_text.replace(token.text_trimmed(), standard);
+                let redundant = declaration.as_ref().and_then(|declaration| {
+                    has_sibling_declaration(declaration, |sibling, sibling_value| {
+                        sibling == standard_name
+                            && sibling_value.eq_ignore_ascii_case(&standard_value)
+                    })
+                    .then(|| declaration.syntax().clone())
+                });
+                Some(PrefixedUsage {
+                    token,
+                    standard,
+                    kind: PrefixedKind::Value,
+                    redundant,
+                })
+            }),
+    );
+    usages.into_boxed_slice()
+}
+
+fn prefixed_keyframes(at_rule: &CssKeyframesAtRule) -> Option<PrefixedUsage> {
+    let token = at_rule.keyframes_token().ok()?;
+    if vender_prefix(&token.text_trimmed().to_ascii_lowercase_cow()).is_empty() {
+        return None;
+    }
+    let at_rule = at_rule.syntax().parent().and_then(CssAtRule::cast)?;
+    let name = keyframes_name(&at_rule)?;
+    let has_standard_keyframes = at_rule
+        .syntax()
+        .parent()?
+        .children()
+        .filter_map(|sibling| keyframes_at_rule_token_and_name(&sibling))
+        .any(|(keyword, sibling_name)| {
+            keyword.text_trimmed().eq_ignore_ascii_case("keyframes") && sibling_name == name
+        });
+    Some(PrefixedUsage {
+        token,
+        standard: "keyframes",
+        kind: PrefixedKind::AtRule,
+        redundant: has_standard_keyframes.then(|| at_rule.syntax().clone()),
+    })
+}
+
+fn keyframes_name(at_rule: &CssAtRule) -> Option<String> {
+    keyframes_at_rule_token_and_name(at_rule.syntax()).map(|(_, name)| name)
+}
+
+/// Returns the `keyframes` keyword and the name of a `@keyframes` at-rule.
+fn keyframes_at_rule_token_and_name(node: &CssSyntaxNode) -> Option<(CssSyntaxToken, String)> {
+    let at_rule = CssAtRule::cast_ref(node)?;
+    let keyframes = CssKeyframesAtRule::cast(at_rule.rule().ok()?.into_syntax())?;
+    Some((
+        keyframes.keyframes_token().ok()?,
+        keyframes.name().ok()?.syntax().text_trimmed().to_string(),
+    ))
+}
+
+fn property_name(property: &CssGenericProperty) -> Option<CssSyntaxToken> {
+    let AnyCssDeclarationName::CssIdentifier(name) = property.name().ok()? else {
+        return None;
+    };
+    name.value_token().ok()
+}
+
+/// Returns `true` if a declaration next to `declaration` satisfies `predicate`,
+/// which receives the lowercase property name and the value of the declaration.
+fn has_sibling_declaration(
+    declaration: &CssDeclarationWithSemicolon,
+    predicate: impl Fn(&str, &str) -> bool,
+) -> bool {
+    let Some(parent) = declaration.syntax().parent() else {
+        return false;
+    };
+    parent
+        .children()
+        .filter(|sibling| sibling != declaration.syntax())
+        .filter_map(CssDeclarationWithSemicolon::cast)
+        .filter_map(|sibling| {
+            let AnyCssProperty::CssGenericProperty(property) =
+                sibling.declaration().ok()?.property().ok()?
+            else {
+                return None;
+            };
+            let name = property_name(&property)?;
+            Some((name, property.value().syntax().text_trimmed().to_string()))
+        })
+        .any(|(name, value)| predicate(&name.text_trimmed().to_ascii_lowercase_cow(), &value))
+}
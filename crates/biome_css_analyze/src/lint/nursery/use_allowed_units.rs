@@ -0,0 +1,174 @@
+use crate::project::is_in_condition;
+use crate::utils::vender_prefix;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssDimension, CssGenericProperty, CssSyntaxToken,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_glob::Glob;
+use biome_rowan::AstNode;
+use biome_string_case::StrLikeExtension;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce the units allowed for each property.
+    ///
+    /// Some units don't fit every property.
+    /// For instance, a design system may require font sizes in `rem` to respect the font size chosen by the user,
+    /// while it keeps `px` for the width of borders.
+    ///
+    /// The rule is configured with a map from property patterns to the units they accept.
+    /// A pattern is a property name, or a glob pattern such as `border-*` that matches several properties.
+    /// When several patterns match a property, the first one applies.
+    /// The patterns are matched against the property names without their vendor prefix.
+    /// Properties that don't match any pattern accept any unit.
+    ///
+    /// The rule checks the dimensions and the percentages of the value, including the ones inside functions such as `calc()`.
+    /// Percentages are checked as the unit `%`.
+    ///
+    /// The rule doesn't report anything until it's configured.
+    ///
+    /// ## Examples
+    ///
+    /// With the following configuration:
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "properties": {
+    ///             "font-size": ["rem", "em"],
+    ///             "border-*": ["px"]
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// p {
+    ///   font-size: 14px;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// .card {
+    ///   border-top-width: 0.1rem;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css,use_options
+    /// .card {
+    ///   font-size: 0.875rem;
+    ///   border-top-width: 1px;
+    ///   width: 20em;
+    /// }
+    /// ```
+    ///
+    pub UseAllowedUnits {
+        version: "next",
+        name: "useAllowedUnits",
+        language: "css",
+        recommended: false,
+        sources: &[RuleSource::Stylelint("declaration-property-unit-allowed-list")],
+    }
+}
+
+/// Options for the rule `useAllowedUnits`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseAllowedUnitsOptions {
+    /// A map from property patterns to the units they accept.
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub properties: IndexMap<Glob, Box<[Box<str>]>>,
+}
+
+pub struct DisallowedUnit {
+    /// The token of the unit.
+    unit: CssSyntaxToken,
+    /// The pattern that matches the property.
+    pattern: Glob,
+}
+
+impl Rule for UseAllowedUnits {
+    type Query = Ast<CssGenericProperty>;
+    type State = DisallowedUnit;
+    type Signals = Box<[Self::State]>;
+    type Options = Box<UseAllowedUnitsOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let options = ctx.options();
+        if options.properties.is_empty() || is_in_condition(node.syntax()) {
+            return Box::default();
+        }
+        let Ok(AnyCssDeclarationName::CssIdentifier(name)) = node.name() else {
+            return Box::default();
+        };
+        let Ok(name) = name.value_token() else {
+            return Box::default();
+        };
+        let name = name.text_trimmed().to_ascii_lowercase_cow();
+        let name = name.trim_start_matches(vender_prefix(&name));
+        let Some((pattern, allowed_units)) = options
+            .properties
+            .iter()
+            .find(|(pattern, _)| pattern.is_match(name))
+        else {
+            return Box::default();
+        };
+        node.value()
+            .syntax()
+            .descendants()
+            .filter_map(AnyCssDimension::cast)
+            .filter_map(|dimension| {
+                let unit = match dimension {
+                    AnyCssDimension::CssPercentage(percentage) => percentage.percent_token(),
+                    AnyCssDimension::CssRegularDimension(dimension) => dimension.unit_token(),
+                    AnyCssDimension::CssUnknownDimension(dimension) => dimension.unit_token(),
+                }
+                .ok()?;
+                let is_allowed = allowed_units
+                    .iter()
+                    .any(|allowed| unit.text_trimmed().eq_ignore_ascii_case(allowed));
+                (!is_allowed).then(|| DisallowedUnit {
+                    unit,
+                    pattern: pattern.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let property = node.name().ok()?.syntax().text_trimmed().to_string();
+        let unit = state.unit.text_trimmed();
+        let pattern = state.pattern.to_string();
+        let allowed_units = &ctx.options().properties[&state.pattern];
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.unit.text_trimmed_range(),
+            markup! {
+                "The unit "<Emphasis>{unit}</Emphasis>" isn't allowed for "<Emphasis>{property}</Emphasis>"."
+            },
+        );
+        let diagnostic = if allowed_units.is_empty() {
+            diagnostic.note(markup! {
+                "The pattern "<Emphasis>{pattern}</Emphasis>" of the rule options doesn't allow any unit."
+            })
+        } else {
+            let allowed_units = allowed_units.join(", ");
+            diagnostic.note(markup! {
+                "The pattern "<Emphasis>{pattern}</Emphasis>" of the rule options only allows the units: "<Emphasis>{allowed_units}</Emphasis>"."
+            })
+        };
+        Some(diagnostic)
+    }
+}
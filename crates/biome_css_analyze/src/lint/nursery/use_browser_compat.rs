@@ -0,0 +1,334 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_compat::{find_feature, Browser, BrowserTargets, Feature, FeatureKind, Version};
+use biome_css_syntax::{
+    AnyCssAtRule, AnyCssDeclarationName, AnyCssFunction, AnyCssPseudoClass, AnyCssPseudoElement,
+    CssAtRule, CssGenericProperty, CssPseudoClassSelector, CssPseudoElementSelector,
+    CssRegularDimension, CssSupportsAtRule,
+};
+use biome_deserialize::{DeserializableValidator, DeserializationDiagnostic};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{declare_node_union, AstNode, TextRange};
+use biome_string_case::StrLikeExtension;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow CSS features that aren't supported by the target browsers.
+    ///
+    /// The rule checks properties, at-rules, pseudo-classes, pseudo-elements, value functions, and units
+    /// against a bundled subset of [MDN's browser compatibility data](https://github.com/mdn/browser-compat-data).
+    /// Features that aren't in the data, such as long-established or vendor-prefixed features, are never reported.
+    ///
+    /// By default, the rule reports the features that aren't part of [Baseline](https://web.dev/baseline) yet,
+    /// that is, the features that aren't supported by the current version of all the major browsers.
+    ///
+    /// Features used inside an `@supports` rule are ignored, because they are expected to be feature-detected.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// textarea {
+    ///   field-sizing: content;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .tooltip {
+    ///   top: anchor(--trigger bottom);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .card:has(img) {
+    ///   accent-color: rebeccapurple;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `baseline`
+    ///
+    /// Only allow the features that became Baseline during or before the given year.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "baseline": 2022
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// .card:has(img) {}
+    /// ```
+    ///
+    /// ### `browserslist`
+    ///
+    /// Only allow the features supported by the given browsers.
+    /// The value is a [browserslist](https://github.com/browserslist/browserslist) query
+    /// made of `<browser> >= <version>` queries, separated by a comma or by `or`.
+    /// The supported browsers are `chrome`, `edge`, `firefox`, and `safari`.
+    /// `and_chr`, `and_ff`, and `ios_saf` are respectively checked as `chrome`, `firefox`, and `safari`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "browserslist": "chrome >= 110, firefox >= 115, safari >= 16.4"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// .card:has(img) {}
+    /// ```
+    ///
+    /// `baseline` and `browserslist` can't be used together.
+    ///
+    pub UseBrowserCompat {
+        version: "next",
+        name: "useBrowserCompat",
+        language: "css",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyCompatFeature =
+        CssGenericProperty
+        | CssAtRule
+        | CssPseudoClassSelector
+        | CssPseudoElementSelector
+        | AnyCssFunction
+        | CssRegularDimension
+}
+
+impl AnyCompatFeature {
+    /// Returns the kind, the name, and the range of the name of the feature.
+    fn name(&self) -> Option<(FeatureKind, String, TextRange)> {
+        match self {
+            Self::CssGenericProperty(property) => {
+                let AnyCssDeclarationName::CssIdentifier(name) = property.name().ok()? else {
+                    return None;
+                };
+                let token = name.value_token().ok()?;
+                Some((
+                    FeatureKind::Property,
+                    token.text_trimmed().to_string(),
+                    token.text_trimmed_range(),
+                ))
+            }
+            Self::CssAtRule(at_rule) => {
+                let rule = at_rule.rule().ok()?;
+                if matches!(rule, AnyCssAtRule::CssBogusAtRule(_)) {
+                    return None;
+                }
+                let token = rule.syntax().first_token()?;
+                Some((
+                    FeatureKind::AtRule,
+                    token.text_trimmed().to_string(),
+                    TextRange::new(
+                        at_rule.at_token().ok()?.text_trimmed_range().start(),
+                        token.text_trimmed_range().end(),
+                    ),
+                ))
+            }
+            Self::CssPseudoClassSelector(selector) => {
+                let class = selector.class().ok()?;
+                if matches!(class, AnyCssPseudoClass::CssBogusPseudoClass(_)) {
+                    return None;
+                }
+                let token = class.syntax().first_token()?;
+                Some((
+                    FeatureKind::PseudoClass,
+                    token.text_trimmed().to_string(),
+                    token.text_trimmed_range(),
+                ))
+            }
+            Self::CssPseudoElementSelector(selector) => {
+                let element = selector.element().ok()?;
+                if matches!(element, AnyCssPseudoElement::CssBogusPseudoElement(_)) {
+                    return None;
+                }
+                let token = element.syntax().first_token()?;
+                Some((
+                    FeatureKind::PseudoElement,
+                    token.text_trimmed().to_string(),
+                    token.text_trimmed_range(),
+                ))
+            }
+            Self::AnyCssFunction(function) => {
+                let token = function.syntax().first_token()?;
+                Some((
+                    FeatureKind::Function,
+                    token.text_trimmed().to_string(),
+                    token.text_trimmed_range(),
+                ))
+            }
+            Self::CssRegularDimension(dimension) => {
+                let token = dimension.unit_token().ok()?;
+                Some((
+                    FeatureKind::Unit,
+                    token.text_trimmed().to_string(),
+                    token.text_trimmed_range(),
+                ))
+            }
+        }
+    }
+}
+
+/// Options for the rule `useBrowserCompat`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[deserializable(with_validator)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseBrowserCompatOptions {
+    /// Only allow the features that became Baseline during or before this year.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<u16>,
+
+    /// A browserslist query of the target browsers, e.g. `"chrome >= 110, safari >= 16.4"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browserslist: Option<Box<str>>,
+}
+
+impl DeserializableValidator for UseBrowserCompatOptions {
+    fn validate(
+        &mut self,
+        _name: &str,
+        range: TextRange,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> bool {
+        if self.baseline.is_some() && self.browserslist.is_some() {
+            diagnostics.push(
+                DeserializationDiagnostic::new(markup!(
+                    <Emphasis>"'baseline'"</Emphasis>" and "<Emphasis>"'browserslist'"</Emphasis>" can't be used together."
+                ))
+                .with_range(range),
+            );
+            return false;
+        }
+        if let Some(Err(error)) = self.browserslist.as_deref().map(BrowserTargets::parse) {
+            diagnostics.push(
+                DeserializationDiagnostic::new(markup!({ error.to_string() })).with_range(range),
+            );
+            return false;
+        }
+        true
+    }
+}
+
+pub enum Incompatibility {
+    /// The targeted browsers that don't support the feature, with their targeted version.
+    Browsers(Vec<(Browser, Version)>),
+    /// The feature didn't become Baseline during or before the configured year.
+    Baseline(Option<u16>),
+}
+
+pub struct UseBrowserCompatState {
+    kind: FeatureKind,
+    name: String,
+    range: TextRange,
+    feature: &'static Feature,
+    incompatibility: Incompatibility,
+}
+
+impl Rule for UseBrowserCompat {
+    type Query = Ast<AnyCompatFeature>;
+    type State = UseBrowserCompatState;
+    type Signals = Option<Self::State>;
+    type Options = Box<UseBrowserCompatOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let options = ctx.options();
+        if node
+            .syntax()
+            .ancestors()
+            .any(|ancestor| CssSupportsAtRule::can_cast(ancestor.kind()))
+        {
+            return None;
+        }
+        let (kind, name, range) = node.name()?;
+        let feature = find_feature(kind, &name.to_ascii_lowercase_cow())?;
+
+        let incompatibility = if let Some(browserslist) = options.browserslist.as_deref() {
+            let unsupported = BrowserTargets::parse(browserslist)
+                .ok()?
+                .unsupported(&feature.support);
+            if unsupported.is_empty() {
+                return None;
+            }
+            Incompatibility::Browsers(unsupported)
+        } else {
+            match (feature.baseline, options.baseline) {
+                (Some(feature_year), Some(year)) if feature_year <= year => return None,
+                (Some(_), None) => return None,
+                _ => Incompatibility::Baseline(options.baseline),
+            }
+        };
+
+        Some(UseBrowserCompatState {
+            kind,
+            name,
+            range,
+            feature,
+            incompatibility,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UseBrowserCompatState {
+            kind,
+            name,
+            range,
+            feature,
+            incompatibility,
+        } = state;
+        let (kind, name) = match kind {
+            FeatureKind::Property => ("property", name.clone()),
+            FeatureKind::AtRule => ("at-rule", format!("@{name}")),
+            FeatureKind::PseudoClass => ("pseudo-class", format!(":{name}")),
+            FeatureKind::PseudoElement => ("pseudo-element", format!("::{name}")),
+            FeatureKind::Function => ("function", format!("{name}()")),
+            FeatureKind::Unit => ("unit", name.clone()),
+        };
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            range,
+            markup! {
+                "The "{kind}" "<Emphasis>{name}</Emphasis>" isn't supported by all the target browsers."
+            },
+        );
+        let diagnostic = match incompatibility {
+            Incompatibility::Browsers(unsupported) => {
+                unsupported
+                    .iter()
+                    .fold(diagnostic, |diagnostic, (browser, version)| {
+                        match feature.support.first_version(*browser) {
+                            Some(first) => diagnostic.note(markup! {
+                                {browser.to_string()}" "{version.to_string()}" is targeted, but the "{kind}" is only supported since "{browser.to_string()}" "{first.to_string()}"."
+                            }),
+                            None => diagnostic.note(markup! {
+                                {browser.to_string()}" doesn't support the "{kind}"."
+                            }),
+                        }
+                    })
+            }
+            Incompatibility::Baseline(year) => match (feature.baseline, year) {
+                (Some(feature_year), Some(year)) => diagnostic.note(markup! {
+                    "The "{kind}" became Baseline in "{feature_year}", after the targeted year "{year}"."
+                }),
+                _ => diagnostic.note(markup! {
+                    "The "{kind}" isn't Baseline yet: some major browsers don't support it."
+                }),
+            },
+        };
+        Some(diagnostic.note(markup! {
+            "Provide a fallback inside an "<Emphasis>"@supports"</Emphasis>" rule, or target more recent browsers."
+        }))
+    }
+}
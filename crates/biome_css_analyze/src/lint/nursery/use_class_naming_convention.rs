@@ -0,0 +1,190 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, restricted_regex::RestrictedRegex, Ast, Rule,
+    RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_css_syntax::{CssClassSelector, CssSyntaxToken};
+use biome_deserialize_macros::Deserializable;
+use biome_string_case::Case;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+declare_lint_rule! {
+    /// Enforce a naming convention for the class selectors.
+    ///
+    /// A consistent naming convention makes the class names predictable across the stylesheets and the markup.
+    ///
+    /// The rule supports the following conventions:
+    ///
+    /// - `kebab-case`: lowercase words separated by hyphens, such as `card-title`. This is the default.
+    /// - `bem`: the [BEM](https://getbem.com/naming/) convention, `block__element--modifier`,
+    ///   where the block, the element, and the modifier are in kebab-case, such as `card__title--large`.
+    ///
+    /// A custom regular expression can also be used instead of a convention.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .cardTitle {
+    ///   font-weight: bold;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .card_title {
+    ///   font-weight: bold;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .card-title {
+    ///   font-weight: bold;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `convention`
+    ///
+    /// The naming convention of the class names: `kebab-case` or `bem`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "convention": "bem"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,use_options
+    /// .card__title--large {
+    ///   font-size: 2rem;
+    /// }
+    /// ```
+    ///
+    /// ### `match`
+    ///
+    /// A regular expression that the class names must match. It replaces the convention.
+    /// The regular expression is anchored: it must match the whole class name.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "match": "(?:js|u)-[a-z]+"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// .button {
+    ///   display: none;
+    /// }
+    /// ```
+    ///
+    pub UseClassNamingConvention {
+        version: "next",
+        name: "useClassNamingConvention",
+        language: "css",
+        recommended: false,
+        sources: &[RuleSource::Stylelint("selector-class-pattern")],
+        source_kind: RuleSourceKind::Inspired,
+    }
+}
+
+/// Options for the rule `useClassNamingConvention`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseClassNamingConventionOptions {
+    /// The naming convention of the class names.
+    pub convention: ClassNamingConvention,
+    /// A regular expression that the class names must match. It replaces the convention.
+    #[serde(rename = "match", skip_serializing_if = "Option::is_none")]
+    pub matching: Option<RestrictedRegex>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ClassNamingConvention {
+    /// Lowercase words separated by hyphens, such as `card-title`.
+    #[default]
+    #[serde(rename = "kebab-case")]
+    Kebab,
+    /// `block__element--modifier`, with each part in kebab-case.
+    #[serde(rename = "bem")]
+    Bem,
+}
+
+static KEBAB_CASE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z][a-z0-9]*(?:-[a-z0-9]+)*$").unwrap());
+
+static BEM: LazyLock<Regex> = LazyLock::new(|| {
+    let part = "[a-z][a-z0-9]*(?:-[a-z0-9]+)*";
+    Regex::new(&format!("^{part}(?:__{part})?(?:--{part})?$")).unwrap()
+});
+
+impl ClassNamingConvention {
+    fn is_match(self, name: &str) -> bool {
+        match self {
+            Self::Kebab => KEBAB_CASE.is_match(name),
+            Self::Bem => BEM.is_match(name),
+        }
+    }
+}
+
+impl Rule for UseClassNamingConvention {
+    type Query = Ast<CssClassSelector>;
+    type State = CssSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = Box<UseClassNamingConventionOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let options = ctx.options();
+        let name = node.name().ok()?.value_token().ok()?;
+        let is_valid = match &options.matching {
+            Some(regex) => regex.is_match(name.text_trimmed()),
+            None => options.convention.is_match(name.text_trimmed()),
+        };
+        (!is_valid).then_some(name)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        let options = ctx.options();
+        let text = name.text_trimmed();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            name.text_trimmed_range(),
+            markup! {
+                "The class name "<Emphasis>{text}</Emphasis>" doesn't follow the naming convention."
+            },
+        );
+        let diagnostic = match (&options.matching, options.convention) {
+            (Some(regex), _) => {
+                let regex = regex.as_str();
+                diagnostic.note(markup! {
+                    "The class names are expected to match the regular expression "<Emphasis>{regex}</Emphasis>"."
+                })
+            }
+            (None, ClassNamingConvention::Kebab) => {
+                let suggestion = Case::Kebab.convert(text);
+                diagnostic
+                    .note(markup! {
+                        "The class names are expected to be in kebab-case: lowercase words separated by hyphens."
+                    })
+                    .note(markup! {
+                        "Rename it to "<Emphasis>{suggestion}</Emphasis>"."
+                    })
+            }
+            (None, ClassNamingConvention::Bem) => diagnostic.note(markup! {
+                "The class names are expected to follow the BEM convention "<Emphasis>"block__element--modifier"</Emphasis>", with each part in kebab-case."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
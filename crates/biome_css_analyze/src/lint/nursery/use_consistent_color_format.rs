@@ -0,0 +1,318 @@
+use crate::color::{
+    is_in_color_function, named_color, AnyColorValue, Color, ColorChannel, ColorFunction,
+    ColorSyntax,
+};
+use crate::project::is_in_condition;
+use crate::CssRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_css_factory::make;
+use biome_css_syntax::{
+    AnyCssDimension, AnyCssExpression, AnyCssFunction, AnyCssValue, CssFunction,
+    CssGenericProperty, CssSyntaxKind, CssSyntaxToken, T,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{AstNode, BatchMutationExt, TriviaPieceKind};
+use biome_string_case::StrLikeExtension;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce a consistent format for the colors.
+    ///
+    /// The same color can be written in many ways: `red`, `#f00`, `#FF0000`, `rgb(255 0 0)`, or `hsl(0 100% 50%)`.
+    /// Writing all the colors of a project in the same format makes them easier to compare and to search for.
+    ///
+    /// The rule reports the hexadecimal colors, the named colors, and the `rgb()`, `rgba()`, `hsl()`, `hsla()`, and `oklch()` functions
+    /// that aren't written in the configured format, and its fix converts them to this format.
+    /// `rgba()` and `hsla()` are considered to be in the same format as `rgb()` and `hsl()`.
+    ///
+    /// Colors whose channels aren't plain numbers, such as colors that use `var()` or relative colors, are ignored.
+    /// Colors that are outside of the sRGB gamut are ignored when the configured format can't represent them.
+    /// Named colors are only checked in the properties that accept colors, such as `color` or `border`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   color: #F00;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   color: rgb(255 0 0);
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   color: red;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a {
+    ///   color: #ff0000;
+    ///   background-color: #00000080;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `format`
+    ///
+    /// The format of the colors:
+    ///
+    /// - `hex`: lowercase hexadecimal colors with 6 digits, or 8 digits for translucent colors. This is the default.
+    /// - `rgb`: `rgb()` functions.
+    /// - `hsl`: `hsl()` functions.
+    /// - `oklch`: `oklch()` functions.
+    ///
+    /// The fix writes the functions with the space-separated syntax, such as `rgb(255 0 0 / 0.5)`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "format": "oklch"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// a {
+    ///   color: #ff0000;
+    /// }
+    /// ```
+    ///
+    pub UseConsistentColorFormat {
+        version: "next",
+        name: "useConsistentColorFormat",
+        language: "css",
+        recommended: false,
+        sources: &[RuleSource::Stylelint("color-hex-length"), RuleSource::Stylelint("color-named")],
+        source_kind: RuleSourceKind::Inspired,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// Options for the rule `useConsistentColorFormat`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseConsistentColorFormatOptions {
+    /// The format of the colors.
+    pub format: ColorFormat,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ColorFormat {
+    /// Lowercase hexadecimal colors with 6 or 8 digits.
+    #[default]
+    Hex,
+    /// `rgb()` functions.
+    Rgb,
+    /// `hsl()` functions.
+    Hsl,
+    /// `oklch()` functions.
+    Oklch,
+}
+
+impl ColorFormat {
+    /// Returns the format of a color function, e.g. `rgb` for `rgba()`.
+    fn of_function(name: &str) -> Option<Self> {
+        match name {
+            "rgb" | "rgba" => Some(Self::Rgb),
+            "hsl" | "hsla" => Some(Self::Hsl),
+            "oklch" => Some(Self::Oklch),
+            _ => None,
+        }
+    }
+
+    fn convert(self, color: Color) -> Option<ColorSyntax> {
+        match self {
+            Self::Hex => color.to_hex(),
+            Self::Rgb => color.to_rgb(),
+            Self::Hsl => color.to_hsl(),
+            Self::Oklch => Some(color.to_oklch()),
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Hex => "lowercase hexadecimal colors with 6 or 8 digits",
+            Self::Rgb => "rgb() functions",
+            Self::Hsl => "hsl() functions",
+            Self::Oklch => "oklch() functions",
+        }
+    }
+}
+
+impl Rule for UseConsistentColorFormat {
+    type Query = Ast<AnyColorValue>;
+    type State = ColorSyntax;
+    type Signals = Option<Self::State>;
+    type Options = Box<UseConsistentColorFormatOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let format = ctx.options().format;
+        let property = node
+            .syntax()
+            .ancestors()
+            .find_map(CssGenericProperty::cast)?;
+        if is_in_condition(property.syntax()) || is_in_color_function(node) {
+            return None;
+        }
+        let color = match node {
+            AnyColorValue::CssColor(color) => {
+                let digits = color.value_token().ok()?;
+                let digits = digits.text_trimmed();
+                let parsed = Color::from_hex(digits)?;
+                let is_valid = format == ColorFormat::Hex
+                    && matches!(digits.len(), 6 | 8)
+                    && !digits.bytes().any(|byte| byte.is_ascii_uppercase());
+                if is_valid {
+                    return None;
+                }
+                parsed
+            }
+            AnyColorValue::CssFunction(function) => {
+                let name = function.name().ok()?.value_token().ok()?;
+                let function_format =
+                    ColorFormat::of_function(&name.text_trimmed().to_ascii_lowercase_cow())?;
+                if function_format == format {
+                    return None;
+                }
+                Color::from_function(function)?
+            }
+            AnyColorValue::CssIdentifier(identifier) => named_color(identifier, &property)?,
+        };
+        format.convert(color)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let format = ctx.options().format.description();
+        let converted = state.to_string();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "This color isn't written with the format of the project."
+                },
+            )
+            .note(markup! {
+                "The colors are expected to be "{format}"."
+            })
+            .note(markup! {
+                "Write it as "<Emphasis>{converted}</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let node = ctx.query();
+        let previous = match node {
+            AnyColorValue::CssColor(color) => AnyCssValue::CssColor(color.clone()),
+            AnyColorValue::CssFunction(function) => {
+                AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(function.clone()))
+            }
+            AnyColorValue::CssIdentifier(identifier) => {
+                AnyCssValue::CssIdentifier(identifier.clone())
+            }
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(previous, color_value(state));
+        let converted = state.to_string();
+        Some(CssRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>{converted}</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Builds the syntax of a color value.
+fn color_value(color: &ColorSyntax) -> AnyCssValue {
+    match color {
+        ColorSyntax::Hex(digits) => AnyCssValue::CssColor(make::css_color(
+            token(T![#], "#"),
+            token(CssSyntaxKind::CSS_COLOR_LITERAL, digits),
+        )),
+        ColorSyntax::Function(function) => {
+            AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(color_function(function)))
+        }
+    }
+}
+
+/// Builds a color function with the space-separated syntax, e.g. `rgb(255 0 0 / 0.5)`.
+fn color_function(function: &ColorFunction) -> CssFunction {
+    let last_index = function.channels.len() - 1;
+    let channels = function
+        .channels
+        .iter()
+        .enumerate()
+        .map(|(index, channel)| {
+            let last_token = if index < last_index || function.alpha.is_some() {
+                spaced_token
+            } else {
+                token
+            };
+            match channel {
+                ColorChannel::Number(number) => AnyCssValue::CssNumber(make::css_number(
+                    last_token(CssSyntaxKind::CSS_NUMBER_LITERAL, number),
+                )),
+                ColorChannel::Percentage(number) => AnyCssValue::AnyCssDimension(
+                    AnyCssDimension::CssPercentage(make::css_percentage(
+                        token(CssSyntaxKind::CSS_NUMBER_LITERAL, number),
+                        last_token(T![%], "%"),
+                    )),
+                ),
+            }
+        })
+        .collect::<Vec<_>>();
+    let channels = AnyCssExpression::CssListOfComponentValuesExpression(
+        make::css_list_of_component_values_expression(make::css_component_value_list(channels)),
+    );
+    let expression = match &function.alpha {
+        Some(alpha) => AnyCssExpression::CssBinaryExpression(make::css_binary_expression(
+            channels,
+            spaced_token(T![/], "/"),
+            AnyCssExpression::CssListOfComponentValuesExpression(
+                make::css_list_of_component_values_expression(make::css_component_value_list([
+                    AnyCssValue::CssNumber(make::css_number(token(
+                        CssSyntaxKind::CSS_NUMBER_LITERAL,
+                        alpha,
+                    ))),
+                ])),
+            ),
+        )),
+        None => channels,
+    };
+    make::css_function(
+        make::css_identifier(token(T![ident], function.name)),
+        token(T!['('], "("),
+        make::css_parameter_list([make::css_parameter(expression)], []),
+        token(T![')'], ")"),
+    )
+}
+
+fn token(kind: CssSyntaxKind, text: &str) -> CssSyntaxToken {
+    CssSyntaxToken::new_detached(kind, text, [], [])
+}
+
+/// Creates a token followed by a space.
+fn spaced_token(kind: CssSyntaxKind, text: &str) -> CssSyntaxToken {
+    token(kind, text).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+}
@@ -0,0 +1,218 @@
+use crate::keywords::{BASIC_KEYWORDS, FONT_FAMILY_KEYWORDS};
+use crate::project::is_in_condition;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssGenericComponentValue, AnyCssValue, CssFontFaceAtRule,
+    CssGenericProperty, T,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{AstNode, AstNodeList, TextRange};
+use biome_string_case::StrLikeExtension;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Require `font-family` declarations to end with a generic font family.
+    ///
+    /// When none of the fonts of a `font-family` list is available, the browser falls back to its default font,
+    /// which can look very different from the intended font.
+    /// Ending the list with a generic font family, such as `sans-serif`, `serif`, or `monospace`,
+    /// lets the browser pick a font of the same kind.
+    ///
+    /// A generic font family always matches a font, so the fonts that come after it are never used:
+    /// the rule also reports the generic font families that aren't at the end of the list.
+    ///
+    /// The declarations in `@font-face` and the CSS-wide keywords, such as `inherit`, are ignored.
+    /// A quoted generic font family, such as `"sans-serif"`, is the name of a font, not a generic font family.
+    /// This rule only checks the `font-family` property; see [useGenericFontNames](https://biomejs.dev/linter/rules/use-generic-font-names/)
+    /// for a less strict rule that also checks the `font` shorthand property.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .title {
+    ///   font-family: Inter, "Helvetica Neue", Arial;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .code {
+    ///   font-family: monospace, "Fira Code";
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .title {
+    ///   font-family: Inter, "Helvetica Neue", Arial, sans-serif;
+    /// }
+    ///
+    /// .inherited {
+    ///   font-family: inherit;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `ignoreCustomProperties`
+    ///
+    /// Ignore the declarations whose value uses a custom property, such as `font-family: var(--font-body)`,
+    /// because the custom property can contain a whole font list. The default is `false`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignoreCustomProperties": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,use_options
+    /// .body {
+    ///   font-family: var(--font-body);
+    /// }
+    /// ```
+    ///
+    pub UseFontFamilyFallbacks {
+        version: "next",
+        name: "useFontFamilyFallbacks",
+        language: "css",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useFontFamilyFallbacks`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseFontFamilyFallbacksOptions {
+    /// Ignore the declarations whose value uses a custom property.
+    pub ignore_custom_properties: bool,
+}
+
+pub enum MissingFallback {
+    /// The list doesn't contain any generic font family.
+    Missing(TextRange),
+    /// The list contains a generic font family that isn't at the end.
+    NotLast(TextRange),
+}
+
+impl Rule for UseFontFamilyFallbacks {
+    type Query = Ast<CssGenericProperty>;
+    type State = MissingFallback;
+    type Signals = Option<Self::State>;
+    type Options = Box<UseFontFamilyFallbacksOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let AnyCssDeclarationName::CssIdentifier(name) = node.name().ok()? else {
+            return None;
+        };
+        if !name
+            .value_token()
+            .ok()?
+            .text_trimmed()
+            .eq_ignore_ascii_case("font-family")
+            || is_in_condition(node.syntax())
+            || node
+                .syntax()
+                .ancestors()
+                .any(|ancestor| CssFontFaceAtRule::can_cast(ancestor.kind()))
+        {
+            return None;
+        }
+        let value = node.value();
+        let text = value.syntax().text_trimmed().to_string();
+        if BASIC_KEYWORDS.contains(&text.to_ascii_lowercase_cow().as_ref()) {
+            return None;
+        }
+        if ctx.options().ignore_custom_properties && text.to_ascii_lowercase_cow().contains("var(")
+        {
+            return None;
+        }
+        let families = font_families(value.iter());
+        let last = families.last()?;
+        if is_generic_family(last) {
+            return None;
+        }
+        let range = |family: &[AnyCssGenericComponentValue]| {
+            Some(TextRange::new(
+                family.first()?.range().start(),
+                family.last()?.range().end(),
+            ))
+        };
+        match families.iter().find(|family| is_generic_family(family)) {
+            Some(generic) => range(generic).map(MissingFallback::NotLast),
+            None => range(last).map(MissingFallback::Missing),
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            MissingFallback::Missing(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This font list doesn't end with a generic font family."
+                },
+            )
+            .note(markup! {
+                "When none of the fonts is available, the browser uses its default font, which can look very different."
+            })
+            .note(markup! {
+                "Add a generic font family at the end of the list, such as "<Emphasis>"sans-serif"</Emphasis>", "<Emphasis>"serif"</Emphasis>", or "<Emphasis>"monospace"</Emphasis>"."
+            }),
+            MissingFallback::NotLast(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The generic font family isn't at the end of the font list."
+                },
+            )
+            .note(markup! {
+                "A generic font family always matches a font, so the fonts after it are never used."
+            })
+            .note(markup! {
+                "Move the generic font family to the end of the list."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Splits the components of a `font-family` value into the font families separated by commas.
+fn font_families(
+    components: impl Iterator<Item = AnyCssGenericComponentValue>,
+) -> Vec<Vec<AnyCssGenericComponentValue>> {
+    let mut families = vec![Vec::new()];
+    for component in components {
+        let is_comma = matches!(
+            &component,
+            AnyCssGenericComponentValue::CssGenericDelimiter(delimiter)
+                if delimiter.value().is_ok_and(|token| token.kind() == T![,])
+        );
+        if is_comma {
+            families.push(Vec::new());
+        } else if let Some(family) = families.last_mut() {
+            family.push(component);
+        }
+    }
+    families.retain(|family| !family.is_empty());
+    families
+}
+
+/// Returns `true` if `family` is an unquoted generic font family.
+fn is_generic_family(family: &[AnyCssGenericComponentValue]) -> bool {
+    match family {
+        [AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssIdentifier(identifier))] => {
+            identifier.value_token().is_ok_and(|token| {
+                FONT_FAMILY_KEYWORDS
+                    .contains(&token.text_trimmed().to_ascii_lowercase_cow().as_ref())
+            })
+        }
+        _ => false,
+    }
+}
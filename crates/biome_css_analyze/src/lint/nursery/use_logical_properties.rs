@@ -0,0 +1,294 @@
+use crate::project::is_in_condition;
+use crate::CssRuleAction;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssGenericComponentValue, AnyCssProperty, AnyCssValue,
+    CssDeclarationWithSemicolon, CssGenericProperty, CssSyntaxToken, T,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{AstNode, BatchMutationExt};
+use biome_string_case::StrLikeExtension;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce the use of logical properties and values instead of physical ones.
+    ///
+    /// Physical properties and values, such as `margin-left` or `text-align: left`, always refer to the same side of the box.
+    /// Their logical equivalents, such as `margin-inline-start` or `text-align: start`, follow the writing mode and the direction of the text,
+    /// so the same stylesheet works for left-to-right, right-to-left, and vertical languages.
+    ///
+    /// The rule reports the physical sides of the `margin`, `padding`, `border`, `scroll-margin`, and `scroll-padding` properties,
+    /// the `top`, `right`, `bottom`, and `left` properties, the corner radii of `border-radius`,
+    /// and the `left` and `right` values of `text-align`, `text-align-last`, `float`, and `clear`.
+    ///
+    /// The fix replaces them with the logical equivalents that are identical in a left-to-right, horizontal writing mode.
+    /// A physical property isn't fixed when its logical equivalent is already declared in the same block,
+    /// because the block would declare the logical property twice.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .card {
+    ///   margin-left: 1rem;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .card {
+    ///   text-align: left;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .card {
+    ///   margin-inline-start: 1rem;
+    ///   padding-block: 0.5rem;
+    ///   text-align: start;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `properties`
+    ///
+    /// Only report the given physical properties.
+    /// Use `text-align`, `text-align-last`, `float`, or `clear` to report their physical values.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "properties": ["margin-left", "margin-right", "text-align"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,use_options
+    /// .card {
+    ///   padding-left: 1rem;
+    /// }
+    /// ```
+    ///
+    pub UseLogicalProperties {
+        version: "next",
+        name: "useLogicalProperties",
+        language: "css",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// The physical properties and their logical equivalents in a left-to-right, horizontal writing mode.
+const LOGICAL_PROPERTIES: [(&str, &str); 40] = [
+    ("border-bottom", "border-block-end"),
+    ("border-bottom-color", "border-block-end-color"),
+    ("border-bottom-left-radius", "border-end-start-radius"),
+    ("border-bottom-right-radius", "border-end-end-radius"),
+    ("border-bottom-style", "border-block-end-style"),
+    ("border-bottom-width", "border-block-end-width"),
+    ("border-left", "border-inline-start"),
+    ("border-left-color", "border-inline-start-color"),
+    ("border-left-style", "border-inline-start-style"),
+    ("border-left-width", "border-inline-start-width"),
+    ("border-right", "border-inline-end"),
+    ("border-right-color", "border-inline-end-color"),
+    ("border-right-style", "border-inline-end-style"),
+    ("border-right-width", "border-inline-end-width"),
+    ("border-top", "border-block-start"),
+    ("border-top-color", "border-block-start-color"),
+    ("border-top-left-radius", "border-start-start-radius"),
+    ("border-top-right-radius", "border-start-end-radius"),
+    ("border-top-style", "border-block-start-style"),
+    ("border-top-width", "border-block-start-width"),
+    ("bottom", "inset-block-end"),
+    ("left", "inset-inline-start"),
+    ("margin-bottom", "margin-block-end"),
+    ("margin-left", "margin-inline-start"),
+    ("margin-right", "margin-inline-end"),
+    ("margin-top", "margin-block-start"),
+    ("padding-bottom", "padding-block-end"),
+    ("padding-left", "padding-inline-start"),
+    ("padding-right", "padding-inline-end"),
+    ("padding-top", "padding-block-start"),
+    ("right", "inset-inline-end"),
+    ("scroll-margin-bottom", "scroll-margin-block-end"),
+    ("scroll-margin-left", "scroll-margin-inline-start"),
+    ("scroll-margin-right", "scroll-margin-inline-end"),
+    ("scroll-margin-top", "scroll-margin-block-start"),
+    ("scroll-padding-bottom", "scroll-padding-block-end"),
+    ("scroll-padding-left", "scroll-padding-inline-start"),
+    ("scroll-padding-right", "scroll-padding-inline-end"),
+    ("scroll-padding-top", "scroll-padding-block-start"),
+    ("top", "inset-block-start"),
+];
+
+/// The properties that accept a physical value.
+const PROPERTIES_WITH_PHYSICAL_VALUES: [&str; 4] =
+    ["clear", "float", "text-align", "text-align-last"];
+
+/// Returns the logical equivalent of a physical value of `property`.
+fn logical_value(property: &str, value: &str) -> Option<&'static str> {
+    match (property, value) {
+        ("text-align" | "text-align-last", "left") => Some("start"),
+        ("text-align" | "text-align-last", "right") => Some("end"),
+        ("float" | "clear", "left") => Some("inline-start"),
+        ("float" | "clear", "right") => Some("inline-end"),
+        _ => None,
+    }
+}
+
+/// Options for the rule `useLogicalProperties`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseLogicalPropertiesOptions {
+    /// The physical properties to report. All the physical properties are reported when it's empty.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub properties: Box<[Box<str>]>,
+}
+
+/// A physical property name or value, and its logical equivalent.
+pub struct PhysicalUsage {
+    token: CssSyntaxToken,
+    logical: &'static str,
+    /// The property of the value, or `None` if the physical usage is the property itself.
+    property: Option<&'static str>,
+}
+
+impl Rule for UseLogicalProperties {
+    type Query = Ast<CssGenericProperty>;
+    type State = PhysicalUsage;
+    type Signals = Box<[Self::State]>;
+    type Options = Box<UseLogicalPropertiesOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let Ok(AnyCssDeclarationName::CssIdentifier(name)) = node.name() else {
+            return Box::default();
+        };
+        let Ok(name) = name.value_token() else {
+            return Box::default();
+        };
+        let lowercase_name = name.text_trimmed().to_ascii_lowercase_cow();
+        let properties = &ctx.options().properties;
+        let is_reported = |physical: &str| {
+            properties.is_empty() || properties.iter().any(|property| &**property == physical)
+        };
+        if is_in_condition(node.syntax()) {
+            return Box::default();
+        }
+        if let Some(&(physical, logical)) = LOGICAL_PROPERTIES
+            .iter()
+            .find(|(physical, _)| *physical == lowercase_name)
+        {
+            if !is_reported(physical) {
+                return Box::default();
+            }
+            return Box::new([PhysicalUsage {
+                token: name,
+                logical,
+                property: None,
+            }]);
+        }
+        let Some(&physical) = PROPERTIES_WITH_PHYSICAL_VALUES
+            .iter()
+            .find(|physical| **physical == lowercase_name)
+        else {
+            return Box::default();
+        };
+        if !is_reported(physical) {
+            return Box::default();
+        }
+        node.value()
+            .into_iter()
+            .filter_map(|value| {
+                let AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssIdentifier(value)) =
+                    value
+                else {
+                    return None;
+                };
+                let token = value.value_token().ok()?;
+                let logical =
+                    logical_value(physical, &token.text_trimmed().to_ascii_lowercase_cow())?;
+                Some(PhysicalUsage {
+                    token,
+                    logical,
+                    property: Some(physical),
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let physical = state.token.text_trimmed();
+        let logical = state.logical;
+        let message = match state.property {
+            Some(property) => markup! {
+                "The value "<Emphasis>{physical}</Emphasis>" of "<Emphasis>{property}</Emphasis>" refers to a physical side."
+            }
+            .to_owned(),
+            None => markup! {
+                "The property "<Emphasis>{physical}</Emphasis>" refers to a physical side."
+            }
+            .to_owned(),
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), state.token.text_trimmed_range(), message)
+                .note(markup! {
+                    "Logical properties and values follow the writing mode and the direction of the text, so the layout adapts to right-to-left and vertical languages."
+                })
+                .note(markup! {
+                    "Use "<Emphasis>{logical}</Emphasis>" instead."
+                }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        if state.property.is_none() && is_declared_in_block(ctx.query(), state.logical) {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(
+            state.token.clone(),
+            CssSyntaxToken::new_detached(T![ident], state.logical, [], []),
+        );
+        let logical = state.logical;
+        Some(CssRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>{logical}</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if the property `name` is declared in the same block as `property`.
+fn is_declared_in_block(property: &CssGenericProperty, name: &str) -> bool {
+    let Some(block) = property
+        .syntax()
+        .ancestors()
+        .find_map(CssDeclarationWithSemicolon::cast)
+        .and_then(|declaration| declaration.syntax().parent())
+    else {
+        return false;
+    };
+    block
+        .children()
+        .filter_map(CssDeclarationWithSemicolon::cast)
+        .filter_map(|sibling| {
+            let AnyCssProperty::CssGenericProperty(sibling) =
+                sibling.declaration().ok()?.property().ok()?
+            else {
+                return None;
+            };
+            let AnyCssDeclarationName::CssIdentifier(sibling_name) = sibling.name().ok()? else {
+                return None;
+            };
+            sibling_name.value_token().ok()
+        })
+        .any(|sibling_name| sibling_name.text_trimmed().eq_ignore_ascii_case(name))
+}
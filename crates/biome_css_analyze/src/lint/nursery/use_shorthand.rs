@@ -0,0 +1,492 @@
+use crate::keywords::BASIC_KEYWORDS;
+use crate::CssRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_css_factory::make;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssGenericComponentValue, AnyCssProperty, CssDeclarationBlock,
+    CssDeclarationOrRuleBlock, CssDeclarationWithSemicolon, CssGenericComponentValueList,
+    CssGenericProperty, CssLanguage, CssSyntaxToken, T,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeList, BatchMutationExt, SyntaxTriviaPiece, TextRange,
+    TriviaPieceKind,
+};
+use biome_string_case::StrLikeExtension;
+
+declare_lint_rule! {
+    /// Enforce the use of shorthand properties instead of groups of longhand properties.
+    ///
+    /// When a declaration block sets all the longhand properties of a shorthand property,
+    /// the longhand properties can be merged into a single declaration, which is shorter and easier to read.
+    ///
+    /// The rule reports the groups of longhand properties of the following shorthand properties:
+    /// `margin`, `padding`, `inset`, `border-width`, `border-style`, `border-color`, `border-radius`,
+    /// `overflow`, `gap`, and `font`.
+    /// For the `font` shorthand, the longhand properties are `font-style`, `font-variant`, `font-weight`,
+    /// `font-size`, `line-height`, and `font-family`.
+    ///
+    /// A group isn't reported when one of its declarations is `!important` or uses a CSS-wide keyword such as `inherit`,
+    /// or when a related declaration, such as the shorthand property itself, comes before the last longhand property:
+    /// merging the group would change which declaration wins.
+    ///
+    /// The fix replaces the last longhand declaration with the shorthand declaration, and removes the other ones.
+    /// The values are written in the order expected by the shorthand property, using the shortest form.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .card {
+    ///   margin-top: 0;
+    ///   margin-right: 1rem;
+    ///   margin-bottom: 0;
+    ///   margin-left: 1rem;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .title {
+    ///   font-style: italic;
+    ///   font-variant: normal;
+    ///   font-weight: bold;
+    ///   font-size: 2rem;
+    ///   line-height: 1.2;
+    ///   font-family: Georgia, serif;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .card {
+    ///   margin: 0 1rem;
+    /// }
+    ///
+    /// .list {
+    ///   margin-top: 0;
+    ///   margin-bottom: 1rem;
+    /// }
+    /// ```
+    ///
+    pub UseShorthand {
+        version: "next",
+        name: "useShorthand",
+        language: "css",
+        recommended: false,
+        sources: &[RuleSource::Stylelint("declaration-block-no-redundant-longhand-properties")],
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// The ways the values of the longhand properties are combined in a shorthand property.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ShorthandSyntax {
+    /// The four sides of a box, such as `margin: 0 1rem`.
+    Box,
+    /// Two values, or one when they are equal, such as `overflow: hidden auto`.
+    Pair,
+    /// `font: <style> <variant> <weight> <size>/<line-height> <family>`.
+    Font,
+}
+
+struct Shorthand {
+    name: &'static str,
+    /// The longhand properties, in the order of their values in the shorthand property.
+    longhands: &'static [&'static str],
+    syntax: ShorthandSyntax,
+    /// The prefixes of the properties that set some of the longhand properties.
+    related: &'static [&'static str],
+}
+
+const SHORTHANDS: [Shorthand; 10] = [
+    Shorthand {
+        name: "margin",
+        longhands: &["margin-top", "margin-right", "margin-bottom", "margin-left"],
+        syntax: ShorthandSyntax::Box,
+        related: &["margin"],
+    },
+    Shorthand {
+        name: "padding",
+        longhands: &[
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ],
+        syntax: ShorthandSyntax::Box,
+        related: &["padding"],
+    },
+    Shorthand {
+        name: "inset",
+        longhands: &["top", "right", "bottom", "left"],
+        syntax: ShorthandSyntax::Box,
+        related: &["inset"],
+    },
+    Shorthand {
+        name: "border-width",
+        longhands: &[
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+        syntax: ShorthandSyntax::Box,
+        related: &["border"],
+    },
+    Shorthand {
+        name: "border-style",
+        longhands: &[
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+        ],
+        syntax: ShorthandSyntax::Box,
+        related: &["border"],
+    },
+    Shorthand {
+        name: "border-color",
+        longhands: &[
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+        syntax: ShorthandSyntax::Box,
+        related: &["border"],
+    },
+    Shorthand {
+        name: "border-radius",
+        longhands: &[
+            "border-top-left-radius",
+            "border-top-right-radius",
+            "border-bottom-right-radius",
+            "border-bottom-left-radius",
+        ],
+        syntax: ShorthandSyntax::Box,
+        related: &["border-radius"],
+    },
+    Shorthand {
+        name: "overflow",
+        longhands: &["overflow-x", "overflow-y"],
+        syntax: ShorthandSyntax::Pair,
+        related: &["overflow"],
+    },
+    Shorthand {
+        name: "gap",
+        longhands: &["row-gap", "column-gap"],
+        syntax: ShorthandSyntax::Pair,
+        related: &["gap", "grid-gap", "grid-row-gap", "grid-column-gap"],
+    },
+    Shorthand {
+        name: "font",
+        longhands: &[
+            "font-style",
+            "font-variant",
+            "font-weight",
+            "font-size",
+            "line-height",
+            "font-family",
+        ],
+        syntax: ShorthandSyntax::Font,
+        related: &["font", "line-height"],
+    },
+];
+
+declare_node_union! {
+    pub AnyDeclarationBlock = CssDeclarationOrRuleBlock | CssDeclarationBlock
+}
+
+pub struct MergeableLonghands {
+    shorthand: &'static Shorthand,
+    /// The longhand declarations, in the order of their values in the shorthand property.
+    declarations: Box<[CssGenericProperty]>,
+    range: TextRange,
+}
+
+impl Rule for UseShorthand {
+    type Query = Ast<AnyDeclarationBlock>;
+    type State = MergeableLonghands;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let declarations: Vec<_> = match ctx.query() {
+            AnyDeclarationBlock::CssDeclarationOrRuleBlock(block) => block
+                .items()
+                .iter()
+                .filter_map(|item| CssDeclarationWithSemicolon::cast(item.into_syntax()))
+                .collect(),
+            AnyDeclarationBlock::CssDeclarationBlock(block) => block
+                .declarations()
+                .iter()
+                .filter_map(|item| CssDeclarationWithSemicolon::cast(item.into_syntax()))
+                .collect(),
+        };
+        let declarations: Vec<_> = declarations
+            .iter()
+            .filter_map(|declaration| {
+                let declaration = declaration.declaration().ok()?;
+                let AnyCssProperty::CssGenericProperty(property) = declaration.property().ok()?
+                else {
+                    return None;
+                };
+                let name = property_name(&property)?;
+                Some((name, property, declaration.important().is_some()))
+            })
+            .collect();
+        SHORTHANDS
+            .iter()
+            .filter_map(|shorthand| mergeable_longhands(shorthand, &declarations))
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let shorthand = state.shorthand.name;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "These longhand properties can be merged into the shorthand property "<Emphasis>{shorthand}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "A shorthand property is shorter and easier to read than the group of its longhand properties."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let last = state
+            .declarations
+            .iter()
+            .max_by_key(|property| property.range().start())?;
+        for property in state
+            .declarations
+            .iter()
+            .filter(|property| *property != last)
+        {
+            let declaration = property
+                .syntax()
+                .ancestors()
+                .find_map(CssDeclarationWithSemicolon::cast)?;
+            mutation.remove_element(declaration.into_syntax().into());
+        }
+        let name = last.name().ok()?;
+        let name_token = name.syntax().first_token()?;
+        let shorthand = state.shorthand.name;
+        let value = shorthand_value(state.shorthand.syntax, &state.declarations)?;
+        mutation.replace_node(
+            last.clone(),
+            make::css_generic_property(
+                AnyCssDeclarationName::CssIdentifier(make::css_identifier(
+                    CssSyntaxToken::new_detached(T![ident], shorthand, [], [])
+                        .with_leading_trivia_pieces(name_token.leading_trivia().pieces())
+                        .with_trailing_trivia_pieces(name_token.trailing_trivia().pieces()),
+                )),
+                last.colon_token().ok()?,
+                make::css_generic_component_value_list(value),
+            ),
+        );
+        Some(CssRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Use the shorthand property "<Emphasis>{shorthand}</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+fn mergeable_longhands(
+    shorthand: &'static Shorthand,
+    declarations: &[(String, CssGenericProperty, bool)],
+) -> Option<MergeableLonghands> {
+    let mut longhands = Vec::with_capacity(shorthand.longhands.len());
+    for longhand in shorthand.longhands {
+        let mut matches = declarations.iter().filter(|(name, _, _)| name == longhand);
+        let (_, property, is_important) = matches.next()?;
+        // Duplicated longhand properties are reported by `noDuplicateProperties`
+        if *is_important || matches.next().is_some() || !is_mergeable_value(longhand, property) {
+            return None;
+        }
+        longhands.push(property.clone());
+    }
+    let start = longhands
+        .iter()
+        .map(|property| property.range().start())
+        .min()?;
+    let end = longhands
+        .iter()
+        .map(|property| property.range().end())
+        .max()?;
+    let has_related_declaration = declarations.iter().any(|(name, property, _)| {
+        property.range().start() < end
+            && !shorthand.longhands.contains(&name.as_str())
+            && shorthand
+                .related
+                .iter()
+                .any(|related| name.starts_with(related))
+    });
+    if has_related_declaration {
+        return None;
+    }
+    // Extend the range to the semicolon of the last declaration
+    let range = longhands
+        .iter()
+        .find(|property| property.range().end() == end)
+        .and_then(|property| {
+            property
+                .syntax()
+                .ancestors()
+                .find_map(CssDeclarationWithSemicolon::cast)
+        })
+        .map_or(TextRange::new(start, end), |declaration| {
+            TextRange::new(start, declaration.range().end())
+        });
+    Some(MergeableLonghands {
+        shorthand,
+        declarations: longhands.into_boxed_slice(),
+        range,
+    })
+}
+
+/// Returns `true` if the value of the longhand `property` can be written in its shorthand property.
+fn is_mergeable_value(longhand: &str, property: &CssGenericProperty) -> bool {
+    let value = property.value();
+    let text = value.syntax().text_trimmed().to_string();
+    if BASIC_KEYWORDS.contains(&text.to_ascii_lowercase_cow().as_ref()) {
+        return false;
+    }
+    match longhand {
+        "font-family" => !value.is_empty(),
+        // Only the CSS 2.1 values of `font-variant` are allowed in the `font` shorthand property
+        "font-variant" => matches!(
+            text.to_ascii_lowercase_cow().as_ref(),
+            "normal" | "small-caps"
+        ),
+        // The longhand properties with two values, such as `border-top-left-radius: 1rem 2rem`,
+        // would require the slash syntax of the shorthand property.
+        _ => value.len() == 1,
+    }
+}
+
+fn property_name(property: &CssGenericProperty) -> Option<String> {
+    let AnyCssDeclarationName::CssIdentifier(name) = property.name().ok()? else {
+        return None;
+    };
+    Some(
+        name.value_token()
+            .ok()?
+            .text_trimmed()
+            .to_ascii_lowercase_cow()
+            .to_string(),
+    )
+}
+
+/// Builds the value of the shorthand property from the values of its longhand properties.
+fn shorthand_value(
+    syntax: ShorthandSyntax,
+    longhands: &[CssGenericProperty],
+) -> Option<Vec<AnyCssGenericComponentValue>> {
+    let values: Vec<_> = longhands.iter().map(CssGenericProperty::value).collect();
+    let parts = match syntax {
+        ShorthandSyntax::Box => {
+            let [top, right, bottom, left] = values.as_slice() else {
+                return None;
+            };
+            let count = if !is_same_value(left, right) {
+                4
+            } else if !is_same_value(top, bottom) {
+                3
+            } else if !is_same_value(top, right) {
+                2
+            } else {
+                1
+            };
+            values[..count].iter().map(trimmed_components).collect()
+        }
+        ShorthandSyntax::Pair => {
+            let [first, second] = values.as_slice() else {
+                return None;
+            };
+            let count = if is_same_value(first, second) { 1 } else { 2 };
+            values[..count].iter().map(trimmed_components).collect()
+        }
+        ShorthandSyntax::Font => {
+            let [style, variant, weight, size, line_height, family] = values.as_slice() else {
+                return None;
+            };
+            let mut parts: Vec<_> = [style, variant, weight]
+                .into_iter()
+                .filter(|value| {
+                    !value
+                        .syntax()
+                        .text_trimmed()
+                        .to_string()
+                        .eq_ignore_ascii_case("normal")
+                })
+                .map(trimmed_components)
+                .collect();
+            let mut size_and_line_height = trimmed_components(size);
+            size_and_line_height.push(AnyCssGenericComponentValue::CssGenericDelimiter(
+                make::css_generic_delimiter(CssSyntaxToken::new_detached(T![/], "/", [], [])),
+            ));
+            size_and_line_height.extend(trimmed_components(line_height));
+            parts.push(size_and_line_height);
+            parts.push(trimmed_components(family));
+            parts
+        }
+    };
+    // Separate the parts with a space
+    let last_index = parts.len().checked_sub(1)?;
+    let mut components = Vec::new();
+    for (index, mut part) in parts.into_iter().enumerate() {
+        if index < last_index {
+            let last = part.pop()?;
+            part.push(last.append_trivia_pieces(space())?);
+        }
+        components.extend(part);
+    }
+    Some(components)
+}
+
+/// Returns a single space, as trivia.
+fn space() -> impl ExactSizeIterator<Item = SyntaxTriviaPiece<CssLanguage>> {
+    CssSyntaxToken::new_detached(T![ident], "", [], [])
+        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+        .trailing_trivia()
+        .pieces()
+}
+
+/// Returns the components of `value`, without the trivia around them.
+fn trimmed_components(value: &CssGenericComponentValueList) -> Vec<AnyCssGenericComponentValue> {
+    let mut components: Vec<_> = value.iter().collect();
+    if let Some(first) = components.first_mut() {
+        if let Some(trimmed) = first.clone().trim_leading_trivia() {
+            *first = trimmed;
+        }
+    }
+    if let Some(last) = components.last_mut() {
+        if let Some(trimmed) = last.clone().trim_trailing_trivia() {
+            *last = trimmed;
+        }
+    }
+    components
+}
+
+fn is_same_value(
+    first: &CssGenericComponentValueList,
+    second: &CssGenericComponentValueList,
+) -> bool {
+    first
+        .syntax()
+        .text_trimmed()
+        .to_string()
+        .eq_ignore_ascii_case(&second.syntax().text_trimmed().to_string())
+}
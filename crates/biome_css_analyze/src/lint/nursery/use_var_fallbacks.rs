@@ -0,0 +1,160 @@
+use crate::project::CssFileSummary;
+use crate::services::project::Project;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{CssDashedIdentifier, CssFunction, CssRoot};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+declare_lint_rule! {
+    /// Require a fallback value in the `var()` functions that reference custom properties declared outside the project.
+    ///
+    /// A `var()` function that references a custom property that isn't set uses its fallback value.
+    /// Without a fallback value, the declaration is invalid at computed-value time,
+    /// and the property is reset to its inherited or initial value.
+    ///
+    /// The stylesheets of a component library are often used in environments that the library doesn't control,
+    /// and that don't necessarily set the custom properties that the components read.
+    /// The rule reports the `var()` functions without a fallback value
+    /// when the custom property isn't declared in the current stylesheet or in any other stylesheet of the project.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .button {
+    ///   color: var(--button-color);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .button {
+    ///   color: var(--button-color, white);
+    ///   background: var(--button-background);
+    /// }
+    ///
+    /// :root {
+    ///   --button-background: navy;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `prefixes`
+    ///
+    /// Only require a fallback value for the custom properties whose name starts with one of the given prefixes.
+    /// All the custom properties are checked when it's empty, which is the default.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "prefixes": ["--ui-"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```css,use_options
+    /// .button {
+    ///   color: var(--theme-color);
+    /// }
+    /// ```
+    ///
+    pub UseVarFallbacks {
+        version: "next",
+        name: "useVarFallbacks",
+        language: "css",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useVarFallbacks`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseVarFallbacksOptions {
+    /// The prefixes of the custom properties that require a fallback value.
+    /// All the custom properties require a fallback value when it's empty.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub prefixes: Box<[Box<str>]>,
+}
+
+pub struct MissingVarFallback {
+    range: TextRange,
+    name: Box<str>,
+}
+
+impl Rule for UseVarFallbacks {
+    type Query = Project<CssRoot>;
+    type State = MissingVarFallback;
+    type Signals = Box<[Self::State]>;
+    type Options = Box<UseVarFallbacksOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let prefixes = &ctx.options().prefixes;
+        let summary = CssFileSummary::from_root(root);
+        let mut declared: BTreeSet<Box<str>> = summary
+            .declared_custom_properties()
+            .map(Box::from)
+            .collect();
+        ctx.project().for_each_other_file(ctx.file_path(), |file| {
+            declared.extend(file.declared_custom_properties().map(Box::from));
+        });
+        root.syntax()
+            .descendants()
+            .filter_map(CssFunction::cast)
+            .filter_map(|function| {
+                let is_var = function
+                    .name()
+                    .and_then(|name| name.value_token())
+                    .is_ok_and(|name| name.text_trimmed().eq_ignore_ascii_case("var"));
+                let parameters = function.items();
+                // `var(--name,)` has an empty fallback value
+                if !is_var || parameters.len() > 1 || parameters.trailing_separator().is_some() {
+                    return None;
+                }
+                let name = parameters
+                    .first()?
+                    .ok()?
+                    .syntax()
+                    .descendants()
+                    .find_map(CssDashedIdentifier::cast)?
+                    .value_token()
+                    .ok()?;
+                let name = name.text_trimmed();
+                let is_checked = prefixes.is_empty()
+                    || prefixes
+                        .iter()
+                        .any(|prefix| name.starts_with(prefix.as_ref()));
+                (is_checked && !declared.contains(name)).then(|| MissingVarFallback {
+                    range: function.range(),
+                    name: name.into(),
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.name.as_ref();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The custom property "<Emphasis>{name}</Emphasis>" isn't declared in the project, and this "<Emphasis>"var()"</Emphasis>" function has no fallback value."
+                },
+            )
+            .note(markup! {
+                "When "<Emphasis>{name}</Emphasis>" isn't set, the declaration is invalid and the property is reset to its inherited or initial value."
+            })
+            .note(markup! {
+                "Add a fallback value as the second argument of "<Emphasis>"var()"</Emphasis>"."
+            }),
+        )
+    }
+}
@@ -1,16 +1,29 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
+use crate::assists;
 use crate::lint;
 
+pub type MaxNestingDepth =
+    <lint::nursery::max_nesting_depth::MaxNestingDepth as biome_analyze::Rule>::Options;
+pub type NoArbitraryZIndex =
+    <lint::nursery::no_arbitrary_z_index::NoArbitraryZIndex as biome_analyze::Rule>::Options;
+pub type NoCustomPropertyTypos = < lint :: nursery :: no_custom_property_typos :: NoCustomPropertyTypos as biome_analyze :: Rule > :: Options ;
+pub type NoDeprecatedProperties = < lint :: nursery :: no_deprecated_properties :: NoDeprecatedProperties as biome_analyze :: Rule > :: Options ;
 pub type NoDescendingSpecificity = < lint :: nursery :: no_descending_specificity :: NoDescendingSpecificity as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateAtImportRules = < lint :: suspicious :: no_duplicate_at_import_rules :: NoDuplicateAtImportRules as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateCustomProperties = < lint :: nursery :: no_duplicate_custom_properties :: NoDuplicateCustomProperties as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateFontNames = < lint :: suspicious :: no_duplicate_font_names :: NoDuplicateFontNames as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateProperties =
     <lint::nursery::no_duplicate_properties::NoDuplicateProperties as biome_analyze::Rule>::Options;
+pub type NoDuplicateSelectorsAcrossFiles = < lint :: nursery :: no_duplicate_selectors_across_files :: NoDuplicateSelectorsAcrossFiles as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateSelectorsKeyframeBlock = < lint :: suspicious :: no_duplicate_selectors_keyframe_block :: NoDuplicateSelectorsKeyframeBlock as biome_analyze :: Rule > :: Options ;
 pub type NoEmptyBlock =
     <lint::suspicious::no_empty_block::NoEmptyBlock as biome_analyze::Rule>::Options;
+pub type NoHardcodedColors =
+    <lint::nursery::no_hardcoded_colors::NoHardcodedColors as biome_analyze::Rule>::Options;
+pub type NoHighSpecificitySelectors = < lint :: nursery :: no_high_specificity_selectors :: NoHighSpecificitySelectors as biome_analyze :: Rule > :: Options ;
+pub type NoIdSelectors =
+    <lint::nursery::no_id_selectors::NoIdSelectors as biome_analyze::Rule>::Options;
 pub type NoImportantInKeyframe = < lint :: suspicious :: no_important_in_keyframe :: NoImportantInKeyframe as biome_analyze :: Rule > :: Options ;
 pub type NoInvalidDirectionInLinearGradient = < lint :: correctness :: no_invalid_direction_in_linear_gradient :: NoInvalidDirectionInLinearGradient as biome_analyze :: Rule > :: Options ;
 pub type NoInvalidGridAreas =
@@ -18,11 +31,16 @@ pub type NoInvalidGridAreas =
 pub type NoInvalidPositionAtImportRule = < lint :: correctness :: no_invalid_position_at_import_rule :: NoInvalidPositionAtImportRule as biome_analyze :: Rule > :: Options ;
 pub type NoIrregularWhitespace =
     <lint::nursery::no_irregular_whitespace::NoIrregularWhitespace as biome_analyze::Rule>::Options;
+pub type NoLowContrastColorPairs = < lint :: nursery :: no_low_contrast_color_pairs :: NoLowContrastColorPairs as biome_analyze :: Rule > :: Options ;
 pub type NoMissingVarFunction =
     <lint::nursery::no_missing_var_function::NoMissingVarFunction as biome_analyze::Rule>::Options;
 pub type NoShorthandPropertyOverrides = < lint :: suspicious :: no_shorthand_property_overrides :: NoShorthandPropertyOverrides as biome_analyze :: Rule > :: Options ;
+pub type NoTransitionAll =
+    <lint::nursery::no_transition_all::NoTransitionAll as biome_analyze::Rule>::Options;
+pub type NoUnknownAnimationName = < lint :: nursery :: no_unknown_animation_name :: NoUnknownAnimationName as biome_analyze :: Rule > :: Options ;
 pub type NoUnknownAtRule =
     <lint::nursery::no_unknown_at_rule::NoUnknownAtRule as biome_analyze::Rule>::Options;
+pub type NoUnknownContainerFeatureName = < lint :: nursery :: no_unknown_container_feature_name :: NoUnknownContainerFeatureName as biome_analyze :: Rule > :: Options ;
 pub type NoUnknownFunction =
     <lint::correctness::no_unknown_function::NoUnknownFunction as biome_analyze::Rule>::Options;
 pub type NoUnknownMediaFeatureName = < lint :: correctness :: no_unknown_media_feature_name :: NoUnknownMediaFeatureName as biome_analyze :: Rule > :: Options ;
@@ -35,7 +53,27 @@ pub type NoUnknownTypeSelector = < lint :: nursery :: no_unknown_type_selector :
 pub type NoUnknownUnit =
     <lint::correctness::no_unknown_unit::NoUnknownUnit as biome_analyze::Rule>::Options;
 pub type NoUnmatchableAnbSelector = < lint :: correctness :: no_unmatchable_anb_selector :: NoUnmatchableAnbSelector as biome_analyze :: Rule > :: Options ;
+pub type NoUnusedCustomProperties = < lint :: nursery :: no_unused_custom_properties :: NoUnusedCustomProperties as biome_analyze :: Rule > :: Options ;
+pub type NoUnusedKeyframes =
+    <lint::nursery::no_unused_keyframes::NoUnusedKeyframes as biome_analyze::Rule>::Options;
 pub type NoValueAtRule =
     <lint::nursery::no_value_at_rule::NoValueAtRule as biome_analyze::Rule>::Options;
+pub type NoVendorPrefixes =
+    <lint::nursery::no_vendor_prefixes::NoVendorPrefixes as biome_analyze::Rule>::Options;
+pub type UseAllowedUnits =
+    <lint::nursery::use_allowed_units::UseAllowedUnits as biome_analyze::Rule>::Options;
+pub type UseBrowserCompat =
+    <lint::nursery::use_browser_compat::UseBrowserCompat as biome_analyze::Rule>::Options;
+pub type UseClassNamingConvention = < lint :: nursery :: use_class_naming_convention :: UseClassNamingConvention as biome_analyze :: Rule > :: Options ;
+pub type UseConsistentColorFormat = < lint :: nursery :: use_consistent_color_format :: UseConsistentColorFormat as biome_analyze :: Rule > :: Options ;
+pub type UseFontFamilyFallbacks = < lint :: nursery :: use_font_family_fallbacks :: UseFontFamilyFallbacks as biome_analyze :: Rule > :: Options ;
 pub type UseGenericFontNames =
     <lint::a11y::use_generic_font_names::UseGenericFontNames as biome_analyze::Rule>::Options;
+pub type UseLogicalProperties =
+    <lint::nursery::use_logical_properties::UseLogicalProperties as biome_analyze::Rule>::Options;
+pub type UseShorthand =
+    <lint::nursery::use_shorthand::UseShorthand as biome_analyze::Rule>::Options;
+pub type UseSortedProperties =
+    <assists::source::use_sorted_properties::UseSortedProperties as biome_analyze::Rule>::Options;
+pub type UseVarFallbacks =
+    <lint::nursery::use_var_fallbacks::UseVarFallbacks as biome_analyze::Rule>::Options;
@@ -0,0 +1,375 @@
+//! Project-level information shared by the rules that need to look beyond the file being analyzed.
+//!
+//! The workspace computes a [CssFileSummary] for every stylesheet it parses and stores it in a
+//! [CssProjectIndex]. The index is then provided to the analyzer of every stylesheet.
+
+use crate::utils::{is_animation_keyword, vender_prefix};
+use biome_analyze::RuleFilter;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssGenericComponentValue, AnyCssKeyframesIdentifier,
+    AnyCssKeyframesName, AnyCssKeyframesScope, AnyCssValue, CssAtRule,
+    CssContainerStyleQueryInParens, CssDashedIdentifier, CssFunction, CssGenericProperty,
+    CssKeyframesAtRule, CssNestedQualifiedRule, CssPropertyAtRule, CssQualifiedRule, CssRoot,
+    CssSupportsFeatureDeclaration, CssSyntaxKind, CssSyntaxNode, T,
+};
+use biome_rowan::{AstNode, Direction, SyntaxKind};
+use biome_string_case::StrLikeExtension;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+/// The rules that query the [CssProjectIndex].
+///
+/// Stylesheets are parsed as soon as they're opened only when one of these rules is enabled.
+pub const PROJECT_RULES: &[RuleFilter<'static>] = &[
+    RuleFilter::Rule("nursery", "noCustomPropertyTypos"),
+    RuleFilter::Rule("nursery", "noDuplicateSelectorsAcrossFiles"),
+    RuleFilter::Rule("nursery", "noUnknownAnimationName"),
+    RuleFilter::Rule("nursery", "noUnusedCustomProperties"),
+    RuleFilter::Rule("nursery", "noUnusedKeyframes"),
+    RuleFilter::Rule("nursery", "useVarFallbacks"),
+];
+
+/// The symbols of a stylesheet that other stylesheets can refer to.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CssFileSummary {
+    /// The custom properties read by the stylesheet, e.g. `--color` in `var(--color)`.
+    used_custom_properties: FxHashSet<Box<str>>,
+    /// The custom properties declared by the stylesheet, including the ones registered with `@property`.
+    declared_custom_properties: FxHashSet<Box<str>>,
+    /// The names of the `@keyframes` defined by the stylesheet.
+    keyframes: FxHashSet<Box<str>>,
+    /// The animation names referenced by the `animation` and `animation-name` properties.
+    used_animation_names: FxHashSet<Box<str>>,
+    /// The selectors of the style rules of the stylesheet.
+    selectors: FxHashSet<ScopedSelector>,
+}
+
+impl CssFileSummary {
+    pub fn from_root(root: &CssRoot) -> Self {
+        let mut summary = Self::default();
+        for node in root.syntax().descendants() {
+            if let Some(identifier) = CssDashedIdentifier::cast_ref(&node) {
+                if is_custom_property_reference(&identifier) {
+                    if let Ok(token) = identifier.value_token() {
+                        summary
+                            .used_custom_properties
+                            .insert(token.text_trimmed().into());
+                    }
+                }
+            } else if let Some(at_rule) = CssKeyframesAtRule::cast_ref(&node) {
+                if let Some(name) = keyframes_name(&at_rule)
+                    .as_ref()
+                    .and_then(keyframes_name_text)
+                {
+                    summary.keyframes.insert(name);
+                }
+            } else if let Some(property) = CssGenericProperty::cast_ref(&node) {
+                if let Some(token) =
+                    declared_custom_property(&property).and_then(|name| name.value_token().ok())
+                {
+                    summary
+                        .declared_custom_properties
+                        .insert(token.text_trimmed().into());
+                }
+                summary
+                    .used_animation_names
+                    .extend(animation_names(&property).into_iter().map(|(_, name)| name));
+            } else if let Some(at_rule) = CssPropertyAtRule::cast_ref(&node) {
+                if let Ok(token) = at_rule.name().and_then(|name| name.value_token()) {
+                    summary
+                        .declared_custom_properties
+                        .insert(token.text_trimmed().into());
+                }
+            } else {
+                summary.selectors.extend(
+                    scoped_selectors(&node)
+                        .into_iter()
+                        .map(|(_, selector)| selector),
+                );
+            }
+        }
+        summary
+    }
+
+    pub(crate) fn uses_custom_property(&self, name: &str) -> bool {
+        self.used_custom_properties.contains(name)
+    }
+
+    pub(crate) fn declared_custom_properties(&self) -> impl Iterator<Item = &str> {
+        self.declared_custom_properties.iter().map(AsRef::as_ref)
+    }
+
+    pub(crate) fn defines_keyframes(&self, name: &str) -> bool {
+        self.keyframes.contains(name)
+    }
+
+    pub(crate) fn uses_animation_name(&self, name: &str) -> bool {
+        self.used_animation_names.contains(name)
+    }
+
+    pub(crate) fn has_selector(&self, selector: &ScopedSelector) -> bool {
+        self.selectors.contains(selector)
+    }
+}
+
+/// The summaries of all the stylesheets known by the workspace.
+#[derive(Debug, Default)]
+pub struct CssProjectIndex {
+    files: RwLock<FxHashMap<PathBuf, CssFileSummary>>,
+    /// Whether all the stylesheets of the project are indexed.
+    ///
+    /// That's the case when the CLI analyzes the project, but not in an editor,
+    /// which only knows the stylesheets that are open.
+    complete: AtomicBool,
+}
+
+impl CssProjectIndex {
+    /// Stores the summary of the stylesheet at `path`, replacing the previous one.
+    pub fn insert(&self, path: PathBuf, summary: CssFileSummary) {
+        self.files.write().unwrap().insert(path, summary);
+    }
+
+    /// Forgets the stylesheet at `path`.
+    pub fn remove(&self, path: &Path) {
+        self.files.write().unwrap().remove(path);
+    }
+
+    /// Sets whether all the stylesheets of the project are indexed.
+    pub fn set_complete(&self, complete: bool) {
+        self.complete.store(complete, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if all the stylesheets of the project are indexed.
+    ///
+    /// Rules that report what no stylesheet uses can only trust the index when it's complete:
+    /// otherwise, the usage could be in a stylesheet that isn't indexed.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.complete.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the summary of a stylesheet other than `path` satisfies `predicate`.
+    ///
+    /// The stylesheet at `path` is skipped because its summary could be outdated:
+    /// rules should inspect its syntax tree instead.
+    pub(crate) fn any_other_file(
+        &self,
+        path: &Path,
+        predicate: impl Fn(&CssFileSummary) -> bool,
+    ) -> bool {
+        self.files
+            .read()
+            .unwrap()
+            .iter()
+            .any(|(file_path, summary)| file_path != path && predicate(summary))
+    }
+
+    /// Calls `f` with the summary of every stylesheet other than `path`.
+    pub(crate) fn for_each_other_file(&self, path: &Path, mut f: impl FnMut(&CssFileSummary)) {
+        for (file_path, summary) in self.files.read().unwrap().iter() {
+            if file_path != path {
+                f(summary);
+            }
+        }
+    }
+
+    /// Returns the path of a stylesheet other than `path` whose summary satisfies `predicate`.
+    ///
+    /// When several stylesheets satisfy `predicate`, the first one in lexicographic order is returned.
+    pub(crate) fn find_other_file(
+        &self,
+        path: &Path,
+        predicate: impl Fn(&Path, &CssFileSummary) -> bool,
+    ) -> Option<PathBuf> {
+        self.files
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(file_path, summary)| *file_path != path && predicate(file_path, summary))
+            .map(|(file_path, _)| file_path)
+            .min()
+            .cloned()
+    }
+}
+
+/// A selector of a style rule, alongside the preludes of the rules and at-rules that contain it.
+///
+/// Two style rules with the same scoped selector target the same elements in the same conditions.
+/// The texts ignore comments and insignificant whitespace.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct ScopedSelector {
+    context: Box<str>,
+    selector: Box<str>,
+}
+
+/// Returns the selectors of `rule` if it's a style rule or a nested style rule.
+pub(crate) fn scoped_selectors(rule: &CssSyntaxNode) -> Vec<(CssSyntaxNode, ScopedSelector)> {
+    let prelude = if let Some(rule) = CssQualifiedRule::cast_ref(rule) {
+        rule.prelude().into_syntax()
+    } else if let Some(rule) = CssNestedQualifiedRule::cast_ref(rule) {
+        rule.prelude().into_syntax()
+    } else {
+        return Vec::new();
+    };
+    let mut preludes = rule
+        .ancestors()
+        .skip(1)
+        .filter(|ancestor| {
+            CssQualifiedRule::can_cast(ancestor.kind())
+                || CssNestedQualifiedRule::can_cast(ancestor.kind())
+                || CssAtRule::can_cast(ancestor.kind())
+        })
+        .map(|ancestor| prelude_text(&ancestor))
+        .collect::<Vec<_>>();
+    preludes.reverse();
+    let context: Box<str> = preludes.join(" { ").into();
+    prelude
+        .children()
+        .filter(|selector| !selector.kind().is_bogus())
+        .map(|selector| {
+            let scoped = ScopedSelector {
+                context: context.clone(),
+                selector: selector_text(&selector).into(),
+            };
+            (selector, scoped)
+        })
+        .collect()
+}
+
+/// Returns the text of a selector without its comments and insignificant whitespace, e.g. `.card > .title`.
+pub(crate) fn selector_text(selector: &CssSyntaxNode) -> String {
+    selector
+        .descendants_tokens(Direction::Next)
+        .map(|token| token.text_trimmed().to_string())
+        .collect()
+}
+
+/// Returns the text of a rule before its block, where whitespace and comments are collapsed into a single space.
+fn prelude_text(rule: &CssSyntaxNode) -> String {
+    let mut text = String::new();
+    let mut has_trivia = false;
+    for token in rule
+        .descendants_tokens(Direction::Next)
+        .take_while(|token| token.kind() != T!['{'])
+    {
+        // The descendant combinator is a whitespace token
+        if token.kind() == CssSyntaxKind::CSS_SPACE_LITERAL {
+            has_trivia = true;
+            continue;
+        }
+        if !text.is_empty() && (has_trivia || token.leading_trivia().pieces().next().is_some()) {
+            text.push(' ');
+        }
+        text.push_str(token.text_trimmed());
+        has_trivia = token.trailing_trivia().pieces().next().is_some();
+    }
+    text
+}
+
+/// Returns `true` if `identifier` reads the value of a custom property,
+/// either through `var()` or in a container style query.
+pub(crate) fn is_custom_property_reference(identifier: &CssDashedIdentifier) -> bool {
+    identifier.syntax().ancestors().any(|ancestor| {
+        CssContainerStyleQueryInParens::can_cast(ancestor.kind())
+            || CssFunction::cast(ancestor).is_some_and(|function| {
+                function
+                    .name()
+                    .and_then(|name| name.value_token())
+                    .is_ok_and(|name| name.text_trimmed().eq_ignore_ascii_case("var"))
+            })
+    })
+}
+
+/// Returns the name of the custom property declared by `property`, if any.
+///
+/// Declarations in `@supports` conditions and in container style queries don't declare anything.
+pub(crate) fn declared_custom_property(
+    property: &CssGenericProperty,
+) -> Option<CssDashedIdentifier> {
+    let AnyCssDeclarationName::CssDashedIdentifier(name) = property.name().ok()? else {
+        return None;
+    };
+    (!is_in_condition(property.syntax())).then_some(name)
+}
+
+/// Returns the identifier that names a `@keyframes` at-rule, e.g. `fade` in `@keyframes :global(fade)`.
+pub(crate) fn keyframes_name(at_rule: &CssKeyframesAtRule) -> Option<AnyCssKeyframesIdentifier> {
+    match at_rule.name().ok()? {
+        AnyCssKeyframesName::AnyCssKeyframesIdentifier(identifier) => Some(identifier),
+        AnyCssKeyframesName::CssKeyframesScopedName(scoped) => match scoped.scope().ok()? {
+            AnyCssKeyframesScope::CssKeyframesScopeFunction(function) => function.name().ok(),
+            AnyCssKeyframesScope::CssKeyframesScopePrefix(prefix) => prefix.name().ok(),
+        },
+        AnyCssKeyframesName::CssBogusKeyframesName(_) => None,
+    }
+}
+
+/// Returns the name defined by a `@keyframes` identifier, without the quotes of a string.
+pub(crate) fn keyframes_name_text(identifier: &AnyCssKeyframesIdentifier) -> Option<Box<str>> {
+    match identifier {
+        AnyCssKeyframesIdentifier::CssCustomIdentifier(identifier) => {
+            Some(identifier.value_token().ok()?.text_trimmed().into())
+        }
+        AnyCssKeyframesIdentifier::CssString(string) => {
+            Some(unquote(string.value_token().ok()?.text_trimmed()).into())
+        }
+    }
+}
+
+/// Returns the animation names referenced by an `animation` or an `animation-name` declaration,
+/// alongside the values that reference them.
+///
+/// Keywords such as `infinite` or `none` aren't animation names, and neither are the
+/// identifiers nested in functions, e.g. `jump-end` in `steps(4, jump-end)`.
+pub(crate) fn animation_names(property: &CssGenericProperty) -> Vec<(AnyCssValue, Box<str>)> {
+    let Ok(AnyCssDeclarationName::CssIdentifier(name)) = property.name() else {
+        return Vec::new();
+    };
+    let Ok(name) = name.value_token() else {
+        return Vec::new();
+    };
+    let name = name.text_trimmed().to_ascii_lowercase_cow();
+    let name = name.trim_start_matches(vender_prefix(&name));
+    if !matches!(name, "animation" | "animation-name") || is_in_condition(property.syntax()) {
+        return Vec::new();
+    }
+    property
+        .value()
+        .into_iter()
+        .filter_map(|value| match value {
+            AnyCssGenericComponentValue::AnyCssValue(value) => {
+                let name: Box<str> = match &value {
+                    AnyCssValue::CssIdentifier(identifier) => {
+                        let token = identifier.value_token().ok()?;
+                        let text = token.text_trimmed();
+                        if is_animation_keyword(text) {
+                            return None;
+                        }
+                        text.into()
+                    }
+                    AnyCssValue::CssString(string) => {
+                        unquote(string.value_token().ok()?.text_trimmed()).into()
+                    }
+                    _ => return None,
+                };
+                Some((value, name))
+            }
+            AnyCssGenericComponentValue::CssGenericDelimiter(_) => None,
+        })
+        .collect()
+}
+
+/// Returns `true` if `node` is in the condition of `@supports` or of a container style query,
+/// where declarations don't apply anything.
+pub(crate) fn is_in_condition(node: &CssSyntaxNode) -> bool {
+    node.ancestors().any(|ancestor| {
+        CssContainerStyleQueryInParens::can_cast(ancestor.kind())
+            || CssSupportsFeatureDeclaration::can_cast(ancestor.kind())
+    })
+}
+
+fn unquote(text: &str) -> &str {
+    text.get(1..text.len().saturating_sub(1))
+        .unwrap_or_default()
+}
@@ -0,0 +1,67 @@
+use crate::project::CssProjectIndex;
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleKey, ServiceBag, SyntaxVisitor,
+};
+use biome_css_syntax::{CssLanguage, CssRoot, CssSyntaxNode};
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+/// The [ProjectServices] give access to the [CssProjectIndex] of the workspace.
+#[derive(Debug, Clone)]
+pub struct ProjectServices {
+    project: Arc<CssProjectIndex>,
+}
+
+impl ProjectServices {
+    pub fn project(&self) -> &CssProjectIndex {
+        &self.project
+    }
+}
+
+impl FromServices for ProjectServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> Result<Self, MissingServicesDiagnostic> {
+        let project: &Arc<CssProjectIndex> = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["CssProjectIndex"])
+        })?;
+        Ok(Self {
+            project: project.clone(),
+        })
+    }
+}
+
+impl Phase for ProjectServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the project index** to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct Project<N>(pub N);
+
+impl<N> Queryable for Project<N>
+where
+    N: AstNode<Language = CssLanguage> + 'static,
+{
+    type Input = CssSyntaxNode;
+    type Output = N;
+
+    type Language = CssLanguage;
+    type Services = ProjectServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<CssLanguage>, _: &CssRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
@@ -1,6 +1,6 @@
 use biome_analyze::{ApplySuppression, SuppressionAction};
-use biome_css_syntax::{CssLanguage, CssSyntaxToken};
-use biome_rowan::{BatchMutation, TriviaPieceKind};
+use biome_css_syntax::{CssLanguage, CssSyntaxKind, CssSyntaxToken};
+use biome_rowan::{BatchMutation, TextRange, TriviaPieceKind};
 
 pub(crate) struct CssSuppressionAction;
 
@@ -36,6 +36,26 @@ impl SuppressionAction for CssSuppressionAction {
         Some(apply_suppression)
     }
 
+    /// Suppression comments can be attached to a declaration, e.g. `a { /* biome-ignore ... */ color: red; }`,
+    /// or to a feature of a `@media` or `@container` query, e.g. `@media /* biome-ignore ... */ (widht: 0) {}`.
+    fn find_attached_suppression_range(&self, token: &CssSyntaxToken) -> Option<TextRange> {
+        token
+            .ancestors()
+            .take_while(|node| node.first_token().as_ref() == Some(token))
+            .find(|node| {
+                matches!(
+                    node.kind(),
+                    CssSyntaxKind::CSS_DECLARATION
+                        | CssSyntaxKind::CSS_MEDIA_FEATURE_IN_PARENS
+                        | CssSyntaxKind::CSS_MEDIA_CONDITION_IN_PARENS
+                        | CssSyntaxKind::CSS_CONTAINER_SIZE_FEATURE_IN_PARENS
+                        | CssSyntaxKind::CSS_CONTAINER_QUERY_IN_PARENS
+                        | CssSyntaxKind::CSS_CONTAINER_STYLE_QUERY_IN_PARENS
+                )
+            })
+            .map(|node| node.text_trimmed_range())
+    }
+
     fn apply_suppression(
         &self,
         mutation: &mut BatchMutation<Self::Language>,
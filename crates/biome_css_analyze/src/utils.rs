@@ -1,10 +1,10 @@
 use crate::keywords::{
-    AT_RULE_PAGE_PSEUDO_CLASSES, A_NPLUS_BNOTATION_PSEUDO_CLASSES,
-    A_NPLUS_BOF_SNOTATION_PSEUDO_CLASSES, BASIC_KEYWORDS, FONT_FAMILY_KEYWORDS, FONT_SIZE_KEYWORDS,
-    FONT_STRETCH_KEYWORDS, FONT_STYLE_KEYWORDS, FONT_VARIANTS_KEYWORDS,
-    FONT_WEIGHT_ABSOLUTE_KEYWORDS, FONT_WEIGHT_NUMERIC_KEYWORDS, FUNCTION_KEYWORDS, HTML_TAGS,
-    KNOWN_CHROME_PROPERTIES, KNOWN_EDGE_PROPERTIES, KNOWN_EXPLORER_PROPERTIES,
-    KNOWN_FIREFOX_PROPERTIES, KNOWN_PROPERTIES, KNOWN_SAFARI_PROPERTIES,
+    ANIMATION_KEYWORDS, AT_RULE_PAGE_PSEUDO_CLASSES, A_NPLUS_BNOTATION_PSEUDO_CLASSES,
+    A_NPLUS_BOF_SNOTATION_PSEUDO_CLASSES, BASIC_KEYWORDS, CONTAINER_SIZE_FEATURE_NAMES,
+    FONT_FAMILY_KEYWORDS, FONT_SIZE_KEYWORDS, FONT_STRETCH_KEYWORDS, FONT_STYLE_KEYWORDS,
+    FONT_VARIANTS_KEYWORDS, FONT_WEIGHT_ABSOLUTE_KEYWORDS, FONT_WEIGHT_NUMERIC_KEYWORDS,
+    FUNCTION_KEYWORDS, HTML_TAGS, KNOWN_CHROME_PROPERTIES, KNOWN_EDGE_PROPERTIES,
+    KNOWN_EXPLORER_PROPERTIES, KNOWN_FIREFOX_PROPERTIES, KNOWN_PROPERTIES, KNOWN_SAFARI_PROPERTIES,
     KNOWN_SAMSUNG_INTERNET_PROPERTIES, KNOWN_US_BROWSER_PROPERTIES,
     LEVEL_ONE_AND_TWO_PSEUDO_ELEMENTS, LINE_HEIGHT_KEYWORDS, LINGUISTIC_PSEUDO_CLASSES,
     LOGICAL_COMBINATIONS_PSEUDO_CLASSES, LONGHAND_SUB_PROPERTIES_OF_SHORTHAND_PROPERTIES,
@@ -111,6 +111,13 @@ pub fn find_font_family(value: CssGenericComponentValueList) -> Vec<AnyCssValue>
     font_families
 }
 
+/// Check if the value is a keyword of the `animation` shorthand, or a CSS-wide keyword.
+pub fn is_animation_keyword(value: &str) -> bool {
+    let value = value.to_ascii_lowercase_cow();
+    ANIMATION_KEYWORDS.binary_search(&value.as_ref()).is_ok()
+        || BASIC_KEYWORDS.binary_search(&value.as_ref()).is_ok()
+}
+
 /// Check if the value is a known CSS value function.
 pub fn is_function_keyword(value: &str) -> bool {
     FUNCTION_KEYWORDS
@@ -214,6 +221,13 @@ pub fn is_media_feature_name(prop: &str) -> bool {
     false
 }
 
+/// Check if the input string is the name of a size feature of container queries.
+pub fn is_container_size_feature_name(prop: &str) -> bool {
+    CONTAINER_SIZE_FEATURE_NAMES
+        .binary_search(&prop.to_ascii_lowercase_cow().as_ref())
+        .is_ok()
+}
+
 pub fn get_longhand_sub_properties(shorthand_property: &str) -> &'static [&'static str] {
     if let Ok(index) = SHORTHAND_PROPERTIES.binary_search(&shorthand_property) {
         return LONGHAND_SUB_PROPERTIES_OF_SHORTHAND_PROPERTIES[index];
@@ -242,3 +256,47 @@ pub fn is_known_type_selector(prop: &str) -> bool {
         || MATH_ML_TAGS.binary_search(&input.as_ref()).is_ok()
         || is_custom_element(prop)
 }
+
+/// Returns the name of `candidates` that is the closest to `name`, if it's close enough to be a typo of it.
+///
+/// Names are compared with their edit distance. The allowed distance grows with the length of the name,
+/// so that short names don't match unrelated names. Ties are resolved in favor of the first candidate.
+pub fn find_similar_name<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = (name.trim_start_matches('-').chars().count() / 4).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns the minimal number of insertions, deletions, substitutions, and transpositions of adjacent characters
+/// that turn `first` into `second`.
+pub fn edit_distance(first: &str, second: &str) -> usize {
+    let first: Vec<char> = first.chars().collect();
+    let second: Vec<char> = second.chars().collect();
+    // The rows of the distances between the prefixes of `first` and the prefixes of `second`
+    let mut before_previous_row = vec![0; second.len() + 1];
+    let mut previous_row: Vec<usize> = (0..=second.len()).collect();
+    let mut current_row = vec![0; second.len() + 1];
+    for i in 0..first.len() {
+        current_row[0] = i + 1;
+        for j in 0..second.len() {
+            let substitution_cost = usize::from(first[i] != second[j]);
+            let mut distance = (previous_row[j] + substitution_cost)
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+            if i > 0 && j > 0 && first[i] == second[j - 1] && first[i - 1] == second[j] {
+                distance = distance.min(before_previous_row[j - 1] + 1);
+            }
+            current_row[j + 1] = distance;
+        }
+        std::mem::swap(&mut before_previous_row, &mut previous_row);
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+    previous_row[second.len()]
+}
@@ -1,4 +1,5 @@
 use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
+use biome_css_analyze::project::{CssFileSummary, CssProjectIndex};
 use biome_css_parser::{parse_css, CssParserOptions};
 use biome_css_syntax::{CssFileSource, CssLanguage};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
@@ -9,7 +10,9 @@ use biome_test_utils::{
     has_bogus_nodes_or_empty_slots, parse_test_path, register_leak_checker, scripts_from_json,
     write_analyzer_snapshot, CheckActionType,
 };
+use std::collections::BTreeMap;
 use std::ops::Deref;
+use std::sync::Arc;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{css,json,jsonc}", crate::run_test, "module"}
@@ -116,8 +119,9 @@ pub(crate) fn analyze_and_snap(
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
+    let project = create_project_index(input_file, parser_options);
 
-    let (_, errors) = biome_css_analyze::analyze(&root, filter, &options, |event| {
+    let (_, errors) = biome_css_analyze::analyze(&root, filter, &options, project, |event| {
         if let Some(mut diag) = event.diagnostic() {
             for action in event.actions() {
                 if check_action_type.is_suppression() {
@@ -172,6 +176,35 @@ pub(crate) fn analyze_and_snap(
     diagnostics.len()
 }
 
+/// Builds the project index of a test file.
+///
+/// A test file can simulate other stylesheets of the project using a special file with
+/// the same name as the test but with extension ".project.json". This file maps file names to
+/// their source code. A file mapped to `null` simulates a stylesheet that isn't indexed,
+/// in which case the index isn't complete.
+fn create_project_index(input_file: &Path, options: CssParserOptions) -> Arc<CssProjectIndex> {
+    let project = CssProjectIndex::default();
+    let mut complete = true;
+    let project_file = input_file.with_extension("project.json");
+    if let Ok(json) = read_to_string(&project_file) {
+        let files: BTreeMap<String, Option<String>> = serde_json::from_str(&json)
+            .unwrap_or_else(|err| panic!("failed to parse {project_file:?}: {err:?}"));
+        for (file_name, source) in files {
+            let Some(source) = source else {
+                complete = false;
+                continue;
+            };
+            let parsed = parse_css(&source, options);
+            project.insert(
+                input_file.with_file_name(file_name),
+                CssFileSummary::from_root(&parsed.tree()),
+            );
+        }
+    }
+    project.set_complete(complete);
+    Arc::new(project)
+}
+
 fn check_code_action(
     path: &Path,
     source: &str,
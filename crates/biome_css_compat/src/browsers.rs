@@ -0,0 +1,230 @@
+use crate::Support;
+use biome_string_case::StrLikeExtension;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A browser tracked by the compatibility data.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Browser {
+    Chrome,
+    Edge,
+    Firefox,
+    Safari,
+}
+
+impl Browser {
+    /// Returns the browser matching a [browserslist](https://github.com/browserslist/browserslist#browsers) name.
+    ///
+    /// Mobile variants are mapped to their desktop counterpart because they share the same engine.
+    pub fn from_browserslist_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "chrome" | "and_chr" => Self::Chrome,
+            "edge" => Self::Edge,
+            "firefox" | "ff" | "and_ff" => Self::Firefox,
+            "safari" | "ios_saf" => Self::Safari,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for Browser {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Chrome => "Chrome",
+            Self::Edge => "Edge",
+            Self::Firefox => "Firefox",
+            Self::Safari => "Safari",
+        })
+    }
+}
+
+/// A browser version such as `110` or `15.4`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Version {
+    major: u16,
+    minor: u16,
+}
+
+impl Version {
+    pub const fn new(major: u16, minor: u16) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl FromStr for Version {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (major, minor) = s.split_once('.').unwrap_or((s, "0"));
+        Ok(Self {
+            major: major.parse().map_err(|_| ())?,
+            minor: minor.parse().map_err(|_| ())?,
+        })
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.minor == 0 {
+            write!(f, "{}", self.major)
+        } else {
+            write!(f, "{}.{}", self.major, self.minor)
+        }
+    }
+}
+
+/// The oldest version of every targeted browser.
+///
+/// Browsers that aren't targeted are ignored when checking the support of a feature.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BrowserTargets(Vec<(Browser, Version)>);
+
+impl BrowserTargets {
+    /// Parses a [browserslist](https://github.com/browserslist/browserslist) query.
+    ///
+    /// Only explicit versions are supported, e.g. `chrome >= 110, safari 16.4`.
+    /// Queries are separated by a comma or by `or`.
+    /// When a browser is targeted several times, its oldest version is kept.
+    ///
+    /// ```
+    /// use biome_css_compat::BrowserTargets;
+    ///
+    /// assert!("chrome >= 110, firefox >= 115 or safari 16.4".parse::<BrowserTargets>().is_ok());
+    /// assert!("last 2 versions".parse::<BrowserTargets>().is_err());
+    /// ```
+    pub fn parse(query: &str) -> Result<Self, BrowserslistError> {
+        let mut targets: Vec<(Browser, Version)> = Vec::new();
+        for query in query
+            .split(',')
+            .flat_map(|query| query.split(" or "))
+            .map(str::trim)
+        {
+            if query.is_empty() {
+                return Err(BrowserslistError::EmptyQuery);
+            }
+            let (browser, version) = parse_single_query(query)
+                .ok_or_else(|| BrowserslistError::UnsupportedQuery(query.into()))?;
+            match targets.iter_mut().find(|(target, _)| *target == browser) {
+                Some((_, oldest)) => *oldest = (*oldest).min(version),
+                None => targets.push((browser, version)),
+            }
+        }
+        targets.sort_unstable();
+        Ok(Self(targets))
+    }
+
+    /// Returns the targeted browsers that don't support a feature,
+    /// alongside the targeted version of each browser.
+    pub fn unsupported(&self, support: &Support) -> Vec<(Browser, Version)> {
+        self.0
+            .iter()
+            .filter(|(browser, version)| {
+                support
+                    .first_version(*browser)
+                    .map_or(true, |first| first > *version)
+            })
+            .copied()
+            .collect()
+    }
+}
+
+impl FromStr for BrowserTargets {
+    type Err = BrowserslistError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Parses `<browser> >= <version>` and `<browser> <version>`.
+fn parse_single_query(query: &str) -> Option<(Browser, Version)> {
+    let mut parts = query.split_whitespace();
+    let browser = Browser::from_browserslist_name(&parts.next()?.to_ascii_lowercase_cow())?;
+    let version = match parts.next()? {
+        ">=" => parts.next()?,
+        version => version,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((browser, version.parse().ok()?))
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BrowserslistError {
+    /// The query contains an empty part, e.g. `chrome >= 110,`
+    EmptyQuery,
+    /// The query contains a part that isn't supported, e.g. `defaults`
+    UnsupportedQuery(Box<str>),
+}
+
+impl Display for BrowserslistError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyQuery => f.write_str("The browserslist query contains an empty query."),
+            Self::UnsupportedQuery(query) => write!(
+                f,
+                "The browserslist query `{query}` isn't supported, use `<browser> >= <version>` instead."
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_browserslist() {
+        let targets = BrowserTargets::parse("Safari >= 15.4, chrome 110 or chrome >= 100").unwrap();
+        assert_eq!(
+            targets,
+            BrowserTargets(vec![
+                (Browser::Chrome, Version::new(100, 0)),
+                (Browser::Safari, Version::new(15, 4)),
+            ])
+        );
+        assert_eq!(
+            BrowserTargets::parse("ios_saf >= 16, and_ff >= 120").unwrap(),
+            BrowserTargets(vec![
+                (Browser::Firefox, Version::new(120, 0)),
+                (Browser::Safari, Version::new(16, 0)),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_invalid_browserslist() {
+        assert_eq!(
+            BrowserTargets::parse("chrome >= 110,"),
+            Err(BrowserslistError::EmptyQuery)
+        );
+        assert_eq!(
+            BrowserTargets::parse("defaults"),
+            Err(BrowserslistError::UnsupportedQuery("defaults".into()))
+        );
+        assert_eq!(
+            BrowserTargets::parse("chrome > 110"),
+            Err(BrowserslistError::UnsupportedQuery("chrome > 110".into()))
+        );
+        assert_eq!(
+            BrowserTargets::parse("opera >= 90"),
+            Err(BrowserslistError::UnsupportedQuery("opera >= 90".into()))
+        );
+    }
+
+    #[test]
+    fn unsupported_browsers() {
+        let targets = BrowserTargets::parse("chrome >= 100, safari >= 15.4").unwrap();
+        let support = Support {
+            chrome: Some(Version::new(105, 0)),
+            edge: Some(Version::new(105, 0)),
+            firefox: None,
+            safari: Some(Version::new(15, 4)),
+        };
+        assert_eq!(
+            targets.unsupported(&support),
+            vec![(Browser::Chrome, Version::new(100, 0))]
+        );
+    }
+}
@@ -0,0 +1,318 @@
+//! Compatibility tables, sorted by name.
+//!
+//! Every row lists the first version of Chrome, Edge, Firefox, and Safari that supports the feature
+//! without a vendor prefix, followed by the year the feature became Baseline.
+
+use crate::{Feature, Support, Version};
+
+const fn v(major: u16) -> Option<Version> {
+    Some(Version::new(major, 0))
+}
+
+const fn vm(major: u16, minor: u16) -> Option<Version> {
+    Some(Version::new(major, minor))
+}
+
+const fn feature(
+    name: &'static str,
+    chrome: Option<Version>,
+    edge: Option<Version>,
+    firefox: Option<Version>,
+    safari: Option<Version>,
+    baseline: Option<u16>,
+) -> Feature {
+    Feature {
+        name,
+        support: Support {
+            chrome,
+            edge,
+            firefox,
+            safari,
+        },
+        baseline,
+    }
+}
+
+pub(crate) const PROPERTIES: &[Feature] = &[
+    feature("accent-color", v(93), v(93), v(92), vm(15, 4), Some(2022)),
+    feature("anchor-name", v(125), v(125), None, None, None),
+    feature("animation-range", v(115), v(115), None, None, None),
+    feature("animation-timeline", v(115), v(115), None, None, None),
+    feature("appearance", v(84), v(84), v(80), vm(15, 4), Some(2022)),
+    feature("aspect-ratio", v(88), v(88), v(89), v(15), Some(2021)),
+    feature("backdrop-filter", v(76), v(79), v(103), v(18), Some(2024)),
+    feature(
+        "backface-visibility",
+        v(36),
+        v(12),
+        v(16),
+        vm(15, 4),
+        Some(2022),
+    ),
+    feature("box-decoration-break", v(130), v(130), v(32), None, None),
+    feature(
+        "contain-intrinsic-size",
+        v(83),
+        v(83),
+        v(107),
+        v(17),
+        Some(2023),
+    ),
+    feature("container", v(105), v(105), v(110), v(16), Some(2023)),
+    feature("container-name", v(105), v(105), v(110), v(16), Some(2023)),
+    feature("container-type", v(105), v(105), v(110), v(16), Some(2023)),
+    feature(
+        "content-visibility",
+        v(85),
+        v(85),
+        v(125),
+        v(18),
+        Some(2024),
+    ),
+    feature("field-sizing", v(123), v(123), None, None, None),
+    feature(
+        "font-palette",
+        v(101),
+        v(101),
+        v(107),
+        vm(15, 4),
+        Some(2022),
+    ),
+    feature(
+        "hyphenate-character",
+        v(106),
+        v(106),
+        v(98),
+        v(17),
+        Some(2023),
+    ),
+    feature("hyphens", v(88), v(88), v(43), v(17), Some(2023)),
+    feature("initial-letter", v(110), v(110), None, None, None),
+    feature("inset", v(87), v(87), v(66), vm(14, 1), Some(2021)),
+    feature("interpolate-size", v(129), v(129), None, None, None),
+    feature("mask", v(120), v(120), v(53), vm(15, 4), Some(2023)),
+    feature("mask-clip", v(120), v(120), v(53), vm(15, 4), Some(2023)),
+    feature(
+        "mask-composite",
+        v(120),
+        v(120),
+        v(53),
+        vm(15, 4),
+        Some(2023),
+    ),
+    feature("mask-image", v(120), v(120), v(53), vm(15, 4), Some(2023)),
+    feature("mask-origin", v(120), v(120), v(53), vm(15, 4), Some(2023)),
+    feature(
+        "mask-position",
+        v(120),
+        v(120),
+        v(53),
+        vm(15, 4),
+        Some(2023),
+    ),
+    feature("mask-repeat", v(120), v(120), v(53), vm(15, 4), Some(2023)),
+    feature("mask-size", v(120), v(120), v(53), vm(15, 4), Some(2023)),
+    feature("math-depth", v(109), v(109), v(117), None, None),
+    feature("offset-path", v(55), v(79), v(72), vm(15, 4), Some(2022)),
+    feature("overflow-clip-margin", v(90), v(90), v(102), None, None),
+    feature(
+        "overscroll-behavior",
+        v(63),
+        v(18),
+        v(59),
+        v(16),
+        Some(2022),
+    ),
+    feature("position-anchor", v(125), v(125), None, None, None),
+    feature("position-area", v(129), v(129), None, None, None),
+    feature("position-try", v(125), v(125), None, None, None),
+    feature("position-try-fallbacks", v(128), v(128), None, None, None),
+    feature("rotate", v(104), v(104), v(72), vm(14, 1), Some(2022)),
+    feature("scale", v(104), v(104), v(72), vm(14, 1), Some(2022)),
+    feature("scrollbar-color", v(121), v(121), v(64), None, None),
+    feature(
+        "scrollbar-gutter",
+        v(94),
+        v(94),
+        v(97),
+        vm(18, 2),
+        Some(2024),
+    ),
+    feature("scrollbar-width", v(121), v(121), v(64), None, None),
+    feature("tab-size", v(21), v(79), v(91), v(7), Some(2021)),
+    feature("text-box-trim", v(133), v(133), None, vm(18, 2), None),
+    feature("text-emphasis", v(99), v(99), v(46), v(7), Some(2022)),
+    feature("text-emphasis-color", v(99), v(99), v(46), v(7), Some(2022)),
+    feature(
+        "text-emphasis-position",
+        v(99),
+        v(99),
+        v(46),
+        v(7),
+        Some(2022),
+    ),
+    feature("text-emphasis-style", v(99), v(99), v(46), v(7), Some(2022)),
+    feature("text-size-adjust", v(54), v(79), None, None, None),
+    feature("text-wrap", v(114), v(114), v(121), vm(17, 4), Some(2024)),
+    feature(
+        "text-wrap-mode",
+        v(130),
+        v(130),
+        v(124),
+        vm(17, 4),
+        Some(2024),
+    ),
+    feature(
+        "text-wrap-style",
+        v(130),
+        v(130),
+        v(124),
+        vm(17, 5),
+        Some(2024),
+    ),
+    feature("translate", v(104), v(104), v(72), vm(14, 1), Some(2022)),
+    feature("user-select", v(54), v(79), v(69), None, None),
+    feature("view-timeline", v(115), v(115), None, None, None),
+    feature("view-transition-name", v(111), v(111), None, v(18), None),
+    feature(
+        "white-space-collapse",
+        v(114),
+        v(114),
+        v(124),
+        vm(17, 4),
+        Some(2024),
+    ),
+    feature("zoom", v(1), v(12), v(126), vm(3, 1), Some(2024)),
+];
+
+pub(crate) const AT_RULES: &[Feature] = &[
+    feature("container", v(105), v(105), v(110), v(16), Some(2023)),
+    feature("counter-style", v(91), v(91), v(33), v(17), Some(2023)),
+    feature("custom-media", None, None, None, None, None),
+    feature(
+        "font-palette-values",
+        v(101),
+        v(101),
+        v(107),
+        vm(15, 4),
+        Some(2022),
+    ),
+    feature("layer", v(99), v(99), v(97), vm(15, 4), Some(2022)),
+    feature("position-try", v(125), v(125), None, None, None),
+    feature("property", v(85), v(85), v(128), vm(16, 4), Some(2024)),
+    feature("scope", v(118), v(118), None, vm(17, 4), None),
+    feature(
+        "starting-style",
+        v(117),
+        v(117),
+        v(129),
+        vm(17, 5),
+        Some(2024),
+    ),
+    feature("view-transition", v(126), v(126), None, vm(18, 2), None),
+];
+
+pub(crate) const PSEUDO_CLASSES: &[Feature] = &[
+    feature("autofill", v(110), v(110), v(86), v(15), Some(2023)),
+    feature("dir", v(120), v(120), v(49), vm(16, 4), Some(2023)),
+    feature("focus-visible", v(86), v(86), v(85), vm(15, 4), Some(2022)),
+    feature("focus-within", v(60), v(79), v(52), vm(10, 1), Some(2020)),
+    feature("has", v(105), v(105), v(121), vm(15, 4), Some(2023)),
+    feature("host-context", v(54), v(79), None, None, None),
+    feature("is", v(88), v(88), v(78), v(14), Some(2021)),
+    feature("modal", v(105), v(105), v(103), vm(15, 6), Some(2022)),
+    feature("popover-open", v(114), v(114), v(125), v(17), Some(2024)),
+    feature("state", v(125), v(125), v(126), vm(17, 4), Some(2024)),
+    feature("user-invalid", v(119), v(119), v(88), vm(16, 5), Some(2023)),
+    feature("user-valid", v(119), v(119), v(88), vm(16, 5), Some(2023)),
+    feature("where", v(88), v(88), v(78), v(14), Some(2021)),
+];
+
+pub(crate) const PSEUDO_ELEMENTS: &[Feature] = &[
+    feature("backdrop", v(37), v(79), v(47), vm(15, 4), Some(2022)),
+    feature("details-content", v(131), v(131), None, None, None),
+    feature(
+        "file-selector-button",
+        v(89),
+        v(89),
+        v(82),
+        vm(14, 1),
+        Some(2021),
+    ),
+    feature("grammar-error", v(121), v(121), None, None, None),
+    feature("highlight", v(105), v(105), None, vm(17, 2), None),
+    feature("marker", v(86), v(86), v(68), vm(11, 1), Some(2020)),
+    feature("part", v(73), v(79), v(72), vm(13, 1), Some(2020)),
+    feature("slotted", v(50), v(79), v(63), v(10), Some(2020)),
+    feature("spelling-error", v(121), v(121), None, None, None),
+    feature("target-text", v(89), v(89), v(131), vm(18, 2), Some(2024)),
+    feature("view-transition", v(111), v(111), None, v(18), None),
+    feature("view-transition-group", v(111), v(111), None, v(18), None),
+    feature(
+        "view-transition-image-pair",
+        v(111),
+        v(111),
+        None,
+        v(18),
+        None,
+    ),
+    feature("view-transition-new", v(111), v(111), None, v(18), None),
+    feature("view-transition-old", v(111), v(111), None, v(18), None),
+];
+
+pub(crate) const FUNCTIONS: &[Feature] = &[
+    feature("abs", None, None, v(118), vm(15, 4), None),
+    feature("acos", v(111), v(111), v(108), vm(15, 4), Some(2023)),
+    feature("anchor", v(125), v(125), None, None, None),
+    feature("anchor-size", v(125), v(125), None, None, None),
+    feature("asin", v(111), v(111), v(108), vm(15, 4), Some(2023)),
+    feature("atan", v(111), v(111), v(108), vm(15, 4), Some(2023)),
+    feature("atan2", v(111), v(111), v(108), vm(15, 4), Some(2023)),
+    feature("clamp", v(79), v(79), v(75), vm(13, 1), Some(2020)),
+    feature("color-mix", v(111), v(111), v(113), vm(16, 2), Some(2023)),
+    feature("cos", v(111), v(111), v(108), vm(15, 4), Some(2023)),
+    feature("env", v(69), v(79), v(65), vm(11, 1), Some(2020)),
+    feature("exp", v(120), v(120), v(118), vm(15, 4), Some(2023)),
+    feature("hwb", v(101), v(101), v(96), v(15), Some(2022)),
+    feature("hypot", v(120), v(120), v(118), vm(15, 4), Some(2023)),
+    feature("image-set", v(113), v(113), v(88), v(17), Some(2023)),
+    feature("lab", v(111), v(111), v(113), v(15), Some(2023)),
+    feature("lch", v(111), v(111), v(113), v(15), Some(2023)),
+    feature("light-dark", v(123), v(123), v(120), vm(17, 5), Some(2024)),
+    feature("log", v(120), v(120), v(118), vm(15, 4), Some(2023)),
+    feature("max", v(79), v(79), v(75), vm(11, 1), Some(2020)),
+    feature("min", v(79), v(79), v(75), vm(11, 1), Some(2020)),
+    feature("mod", v(125), v(125), v(118), vm(15, 4), Some(2024)),
+    feature("oklab", v(111), v(111), v(113), vm(15, 4), Some(2023)),
+    feature("oklch", v(111), v(111), v(113), vm(15, 4), Some(2023)),
+    feature("pow", v(120), v(120), v(118), vm(15, 4), Some(2023)),
+    feature("rem", v(125), v(125), v(118), vm(15, 4), Some(2024)),
+    feature("round", v(125), v(125), v(118), vm(15, 4), Some(2024)),
+    feature("scroll", v(115), v(115), None, None, None),
+    feature("sign", None, None, v(118), vm(15, 4), None),
+    feature("sin", v(111), v(111), v(108), vm(15, 4), Some(2023)),
+    feature("sqrt", v(120), v(120), v(118), vm(15, 4), Some(2023)),
+    feature("tan", v(111), v(111), v(108), vm(15, 4), Some(2023)),
+    feature("view", v(115), v(115), None, None, None),
+];
+
+pub(crate) const UNITS: &[Feature] = &[
+    feature("cqb", v(105), v(105), v(110), v(16), Some(2023)),
+    feature("cqh", v(105), v(105), v(110), v(16), Some(2023)),
+    feature("cqi", v(105), v(105), v(110), v(16), Some(2023)),
+    feature("cqmax", v(105), v(105), v(110), v(16), Some(2023)),
+    feature("cqmin", v(105), v(105), v(110), v(16), Some(2023)),
+    feature("cqw", v(105), v(105), v(110), v(16), Some(2023)),
+    feature("dvb", v(108), v(108), v(101), vm(15, 4), Some(2022)),
+    feature("dvh", v(108), v(108), v(101), vm(15, 4), Some(2022)),
+    feature("dvi", v(108), v(108), v(101), vm(15, 4), Some(2022)),
+    feature("dvmax", v(108), v(108), v(101), vm(15, 4), Some(2022)),
+    feature("dvmin", v(108), v(108), v(101), vm(15, 4), Some(2022)),
+    feature("dvw", v(108), v(108), v(101), vm(15, 4), Some(2022)),
+    feature("lh", v(109), v(109), v(120), vm(16, 4), Some(2023)),
+    feature("lvh", v(108), v(108), v(101), vm(15, 4), Some(2022)),
+    feature("lvw", v(108), v(108), v(101), vm(15, 4), Some(2022)),
+    feature("rlh", v(111), v(111), v(120), vm(16, 4), Some(2023)),
+    feature("svh", v(108), v(108), v(101), vm(15, 4), Some(2022)),
+    feature("svw", v(108), v(108), v(101), vm(15, 4), Some(2022)),
+];
@@ -0,0 +1,126 @@
+//! Browser compatibility data for CSS features.
+//!
+//! The data is a curated subset of [MDN's browser-compat-data](https://github.com/mdn/browser-compat-data)
+//! and of the [Baseline](https://web.dev/baseline) status of each feature.
+//! It only lists features that are recent enough to matter when targeting evergreen browsers:
+//! a feature that isn't listed is considered supported everywhere.
+
+mod browsers;
+mod data;
+
+pub use browsers::{Browser, BrowserTargets, BrowserslistError, Version};
+
+/// The kind of CSS feature described by a [Feature].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeatureKind {
+    /// A property, e.g. `accent-color`
+    Property,
+    /// An at-rule, e.g. `@container`
+    AtRule,
+    /// A pseudo-class, e.g. `:has()`
+    PseudoClass,
+    /// A pseudo-element, e.g. `::backdrop`
+    PseudoElement,
+    /// A value function, e.g. `color-mix()`
+    Function,
+    /// A dimension unit, e.g. `dvh`
+    Unit,
+}
+
+/// The first version of each browser that supports a feature.
+///
+/// `None` means that the browser doesn't support the feature at all.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Support {
+    pub chrome: Option<Version>,
+    pub edge: Option<Version>,
+    pub firefox: Option<Version>,
+    pub safari: Option<Version>,
+}
+
+impl Support {
+    /// Returns the first version of `browser` that supports the feature.
+    pub const fn first_version(&self, browser: Browser) -> Option<Version> {
+        match browser {
+            Browser::Chrome => self.chrome,
+            Browser::Edge => self.edge,
+            Browser::Firefox => self.firefox,
+            Browser::Safari => self.safari,
+        }
+    }
+}
+
+/// Compatibility data of a CSS feature.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Feature {
+    /// The lowercase name of the feature, without any `@`, `:` or `(`.
+    pub name: &'static str,
+    pub support: Support,
+    /// The year the feature became Baseline "newly available",
+    /// or `None` if it isn't part of Baseline yet.
+    pub baseline: Option<u16>,
+}
+
+/// Returns the compatibility data of the feature of the given `kind` named `name`.
+///
+/// `name` must be lowercase. Vendor-prefixed names are never found.
+///
+/// ```
+/// use biome_css_compat::{find_feature, FeatureKind};
+///
+/// assert_eq!(find_feature(FeatureKind::Property, "accent-color").unwrap().baseline, Some(2022));
+/// assert!(find_feature(FeatureKind::Property, "color").is_none());
+/// ```
+pub fn find_feature(kind: FeatureKind, name: &str) -> Option<&'static Feature> {
+    let table = match kind {
+        FeatureKind::Property => data::PROPERTIES,
+        FeatureKind::AtRule => data::AT_RULES,
+        FeatureKind::PseudoClass => data::PSEUDO_CLASSES,
+        FeatureKind::PseudoElement => data::PSEUDO_ELEMENTS,
+        FeatureKind::Function => data::FUNCTIONS,
+        FeatureKind::Unit => data::UNITS,
+    };
+    table
+        .binary_search_by(|feature| feature.name.cmp(name))
+        .ok()
+        .map(|index| &table[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_are_sorted() {
+        for table in [
+            data::PROPERTIES,
+            data::AT_RULES,
+            data::PSEUDO_CLASSES,
+            data::PSEUDO_ELEMENTS,
+            data::FUNCTIONS,
+            data::UNITS,
+        ] {
+            assert!(
+                table.windows(2).all(|pair| pair[0].name < pair[1].name),
+                "{:?} is not sorted",
+                table.iter().map(|feature| feature.name).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn find_known_features() {
+        let has = find_feature(FeatureKind::PseudoClass, "has").unwrap();
+        assert_eq!(
+            has.support.first_version(Browser::Firefox),
+            Some(Version::new(121, 0))
+        );
+        assert_eq!(has.baseline, Some(2023));
+
+        let field_sizing = find_feature(FeatureKind::Property, "field-sizing").unwrap();
+        assert_eq!(field_sizing.support.first_version(Browser::Safari), None);
+        assert_eq!(field_sizing.baseline, None);
+
+        assert!(find_feature(FeatureKind::AtRule, "media").is_none());
+    }
+}
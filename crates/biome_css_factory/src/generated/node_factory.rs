@@ -7,6 +7,115 @@ use biome_css_syntax::{
     *,
 };
 use biome_rowan::AstNode;
+pub fn css_anchor_fallback(comma_token: SyntaxToken, value: AnyCssValue) -> CssAnchorFallback {
+    CssAnchorFallback::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_ANCHOR_FALLBACK,
+        [
+            Some(SyntaxElement::Token(comma_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn css_anchor_function(
+    name_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    side: AnyCssAnchorSide,
+    r_paren_token: SyntaxToken,
+) -> CssAnchorFunctionBuilder {
+    CssAnchorFunctionBuilder {
+        name_token,
+        l_paren_token,
+        side,
+        r_paren_token,
+        anchor_name: None,
+        fallback: None,
+    }
+}
+pub struct CssAnchorFunctionBuilder {
+    name_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    side: AnyCssAnchorSide,
+    r_paren_token: SyntaxToken,
+    anchor_name: Option<CssDashedIdentifier>,
+    fallback: Option<CssAnchorFallback>,
+}
+impl CssAnchorFunctionBuilder {
+    pub fn with_anchor_name(mut self, anchor_name: CssDashedIdentifier) -> Self {
+        self.anchor_name = Some(anchor_name);
+        self
+    }
+    pub fn with_fallback(mut self, fallback: CssAnchorFallback) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+    pub fn build(self) -> CssAnchorFunction {
+        CssAnchorFunction::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_ANCHOR_FUNCTION,
+            [
+                Some(SyntaxElement::Token(self.name_token)),
+                Some(SyntaxElement::Token(self.l_paren_token)),
+                self.anchor_name
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.side.into_syntax())),
+                self.fallback
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_paren_token)),
+            ],
+        ))
+    }
+}
+pub fn css_anchor_size_function(
+    name_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    r_paren_token: SyntaxToken,
+) -> CssAnchorSizeFunctionBuilder {
+    CssAnchorSizeFunctionBuilder {
+        name_token,
+        l_paren_token,
+        r_paren_token,
+        anchor_name: None,
+        size: None,
+        fallback: None,
+    }
+}
+pub struct CssAnchorSizeFunctionBuilder {
+    name_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    r_paren_token: SyntaxToken,
+    anchor_name: Option<CssDashedIdentifier>,
+    size: Option<CssIdentifier>,
+    fallback: Option<CssAnchorFallback>,
+}
+impl CssAnchorSizeFunctionBuilder {
+    pub fn with_anchor_name(mut self, anchor_name: CssDashedIdentifier) -> Self {
+        self.anchor_name = Some(anchor_name);
+        self
+    }
+    pub fn with_size(mut self, size: CssIdentifier) -> Self {
+        self.size = Some(size);
+        self
+    }
+    pub fn with_fallback(mut self, fallback: CssAnchorFallback) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+    pub fn build(self) -> CssAnchorSizeFunction {
+        CssAnchorSizeFunction::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_ANCHOR_SIZE_FUNCTION,
+            [
+                Some(SyntaxElement::Token(self.name_token)),
+                Some(SyntaxElement::Token(self.l_paren_token)),
+                self.anchor_name
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.size
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.fallback
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_paren_token)),
+            ],
+        ))
+    }
+}
 pub fn css_at_rule(at_token: SyntaxToken, rule: AnyCssAtRule) -> CssAtRule {
     CssAtRule::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_AT_RULE,
@@ -175,6 +284,99 @@ pub fn css_color(hash_token: SyntaxToken, value_token: SyntaxToken) -> CssColor
         ],
     ))
 }
+pub fn css_color_hue_interpolation_method(
+    method: CssIdentifier,
+    hue_token: SyntaxToken,
+) -> CssColorHueInterpolationMethod {
+    CssColorHueInterpolationMethod::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COLOR_HUE_INTERPOLATION_METHOD,
+        [
+            Some(SyntaxElement::Node(method.into_syntax())),
+            Some(SyntaxElement::Token(hue_token)),
+        ],
+    ))
+}
+pub fn css_color_interpolation_method(
+    in_token: SyntaxToken,
+    color_space: CssIdentifier,
+) -> CssColorInterpolationMethodBuilder {
+    CssColorInterpolationMethodBuilder {
+        in_token,
+        color_space,
+        hue_interpolation_method: None,
+    }
+}
+pub struct CssColorInterpolationMethodBuilder {
+    in_token: SyntaxToken,
+    color_space: CssIdentifier,
+    hue_interpolation_method: Option<CssColorHueInterpolationMethod>,
+}
+impl CssColorInterpolationMethodBuilder {
+    pub fn with_hue_interpolation_method(
+        mut self,
+        hue_interpolation_method: CssColorHueInterpolationMethod,
+    ) -> Self {
+        self.hue_interpolation_method = Some(hue_interpolation_method);
+        self
+    }
+    pub fn build(self) -> CssColorInterpolationMethod {
+        CssColorInterpolationMethod::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_COLOR_INTERPOLATION_METHOD,
+            [
+                Some(SyntaxElement::Token(self.in_token)),
+                Some(SyntaxElement::Node(self.color_space.into_syntax())),
+                self.hue_interpolation_method
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn css_color_mix_color(color: AnyCssValue) -> CssColorMixColorBuilder {
+    CssColorMixColorBuilder {
+        color,
+        percentage: None,
+    }
+}
+pub struct CssColorMixColorBuilder {
+    color: AnyCssValue,
+    percentage: Option<CssPercentage>,
+}
+impl CssColorMixColorBuilder {
+    pub fn with_percentage(mut self, percentage: CssPercentage) -> Self {
+        self.percentage = Some(percentage);
+        self
+    }
+    pub fn build(self) -> CssColorMixColor {
+        CssColorMixColor::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_COLOR_MIX_COLOR,
+            [
+                Some(SyntaxElement::Node(self.color.into_syntax())),
+                self.percentage
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn css_color_mix_function(
+    name_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    interpolation_method: CssColorInterpolationMethod,
+    comma_token: SyntaxToken,
+    colors: CssColorMixColorList,
+    r_paren_token: SyntaxToken,
+) -> CssColorMixFunction {
+    CssColorMixFunction::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COLOR_MIX_FUNCTION,
+        [
+            Some(SyntaxElement::Token(name_token)),
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(interpolation_method.into_syntax())),
+            Some(SyntaxElement::Token(comma_token)),
+            Some(SyntaxElement::Node(colors.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
 pub fn css_color_profile_at_rule(
     color_profile_token: SyntaxToken,
     name: CssCustomIdentifier,
@@ -379,6 +581,76 @@ pub fn css_container_query_in_parens(
         ],
     ))
 }
+pub fn css_container_scroll_state_and_query(
+    left: CssContainerScrollStateInParens,
+    and_token: SyntaxToken,
+    right: AnyCssContainerScrollStateAndCombinableQuery,
+) -> CssContainerScrollStateAndQuery {
+    CssContainerScrollStateAndQuery::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_CONTAINER_SCROLL_STATE_AND_QUERY,
+        [
+            Some(SyntaxElement::Node(left.into_syntax())),
+            Some(SyntaxElement::Token(and_token)),
+            Some(SyntaxElement::Node(right.into_syntax())),
+        ],
+    ))
+}
+pub fn css_container_scroll_state_in_parens(
+    l_paren_token: SyntaxToken,
+    query: AnyCssContainerScrollStateQuery,
+    r_paren_token: SyntaxToken,
+) -> CssContainerScrollStateInParens {
+    CssContainerScrollStateInParens::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_CONTAINER_SCROLL_STATE_IN_PARENS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(query.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn css_container_scroll_state_not_query(
+    not_token: SyntaxToken,
+    query: CssContainerScrollStateInParens,
+) -> CssContainerScrollStateNotQuery {
+    CssContainerScrollStateNotQuery::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_CONTAINER_SCROLL_STATE_NOT_QUERY,
+        [
+            Some(SyntaxElement::Token(not_token)),
+            Some(SyntaxElement::Node(query.into_syntax())),
+        ],
+    ))
+}
+pub fn css_container_scroll_state_or_query(
+    left: CssContainerScrollStateInParens,
+    or_token: SyntaxToken,
+    right: AnyCssContainerScrollStateOrCombinableQuery,
+) -> CssContainerScrollStateOrQuery {
+    CssContainerScrollStateOrQuery::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_CONTAINER_SCROLL_STATE_OR_QUERY,
+        [
+            Some(SyntaxElement::Node(left.into_syntax())),
+            Some(SyntaxElement::Token(or_token)),
+            Some(SyntaxElement::Node(right.into_syntax())),
+        ],
+    ))
+}
+pub fn css_container_scroll_state_query_in_parens(
+    scroll_state_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    query: AnyCssContainerScrollStateQuery,
+    r_paren_token: SyntaxToken,
+) -> CssContainerScrollStateQueryInParens {
+    CssContainerScrollStateQueryInParens::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_CONTAINER_SCROLL_STATE_QUERY_IN_PARENS,
+        [
+            Some(SyntaxElement::Token(scroll_state_token)),
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(query.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
 pub fn css_container_size_feature_in_parens(
     l_paren_token: SyntaxToken,
     feature: AnyCssQueryFeature,
@@ -463,6 +735,64 @@ pub fn css_container_style_query_in_parens(
         ],
     ))
 }
+pub fn css_container_style_range(
+    left: AnyCssValue,
+    comparison: CssQueryFeatureRangeComparison,
+    right: AnyCssValue,
+) -> CssContainerStyleRange {
+    CssContainerStyleRange::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_CONTAINER_STYLE_RANGE,
+        [
+            Some(SyntaxElement::Node(left.into_syntax())),
+            Some(SyntaxElement::Node(comparison.into_syntax())),
+            Some(SyntaxElement::Node(right.into_syntax())),
+        ],
+    ))
+}
+pub fn css_container_style_range_interval(
+    left: AnyCssValue,
+    left_comparison: CssQueryFeatureRangeComparison,
+    middle: AnyCssValue,
+    right_comparison: CssQueryFeatureRangeComparison,
+    right: AnyCssValue,
+) -> CssContainerStyleRangeInterval {
+    CssContainerStyleRangeInterval::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_CONTAINER_STYLE_RANGE_INTERVAL,
+        [
+            Some(SyntaxElement::Node(left.into_syntax())),
+            Some(SyntaxElement::Node(left_comparison.into_syntax())),
+            Some(SyntaxElement::Node(middle.into_syntax())),
+            Some(SyntaxElement::Node(right_comparison.into_syntax())),
+            Some(SyntaxElement::Node(right.into_syntax())),
+        ],
+    ))
+}
+pub fn css_counter_style_additive_symbol(
+    weight: CssNumber,
+    symbol: AnyCssCounterStyleSymbol,
+) -> CssCounterStyleAdditiveSymbol {
+    CssCounterStyleAdditiveSymbol::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COUNTER_STYLE_ADDITIVE_SYMBOL,
+        [
+            Some(SyntaxElement::Node(weight.into_syntax())),
+            Some(SyntaxElement::Node(symbol.into_syntax())),
+        ],
+    ))
+}
+pub fn css_counter_style_additive_symbols_property(
+    name: CssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssCounterStyleAdditiveSymbolList,
+) -> CssCounterStyleAdditiveSymbolsProperty {
+    CssCounterStyleAdditiveSymbolsProperty::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COUNTER_STYLE_ADDITIVE_SYMBOLS_PROPERTY,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
 pub fn css_counter_style_at_rule(
     counter_style_token: SyntaxToken,
     name: CssCustomIdentifier,
@@ -477,12 +807,130 @@ pub fn css_counter_style_at_rule(
         ],
     ))
 }
+pub fn css_counter_style_extends_system(
+    extends_token: SyntaxToken,
+    name: CssCustomIdentifier,
+) -> CssCounterStyleExtendsSystem {
+    CssCounterStyleExtendsSystem::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COUNTER_STYLE_EXTENDS_SYSTEM,
+        [
+            Some(SyntaxElement::Token(extends_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn css_counter_style_fixed_system(
+    fixed_token: SyntaxToken,
+) -> CssCounterStyleFixedSystemBuilder {
+    CssCounterStyleFixedSystemBuilder {
+        fixed_token,
+        first_symbol_value: None,
+    }
+}
+pub struct CssCounterStyleFixedSystemBuilder {
+    fixed_token: SyntaxToken,
+    first_symbol_value: Option<CssNumber>,
+}
+impl CssCounterStyleFixedSystemBuilder {
+    pub fn with_first_symbol_value(mut self, first_symbol_value: CssNumber) -> Self {
+        self.first_symbol_value = Some(first_symbol_value);
+        self
+    }
+    pub fn build(self) -> CssCounterStyleFixedSystem {
+        CssCounterStyleFixedSystem::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_COUNTER_STYLE_FIXED_SYSTEM,
+            [
+                Some(SyntaxElement::Token(self.fixed_token)),
+                self.first_symbol_value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn css_counter_style_range(
+    lower: AnyCssCounterStyleRangeBound,
+    upper: AnyCssCounterStyleRangeBound,
+) -> CssCounterStyleRange {
+    CssCounterStyleRange::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COUNTER_STYLE_RANGE,
+        [
+            Some(SyntaxElement::Node(lower.into_syntax())),
+            Some(SyntaxElement::Node(upper.into_syntax())),
+        ],
+    ))
+}
+pub fn css_counter_style_range_property(
+    name: CssIdentifier,
+    colon_token: SyntaxToken,
+    value: AnyCssCounterStyleRange,
+) -> CssCounterStyleRangeProperty {
+    CssCounterStyleRangeProperty::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COUNTER_STYLE_RANGE_PROPERTY,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn css_counter_style_range_value(
+    ranges: CssCounterStyleRangeList,
+) -> CssCounterStyleRangeValue {
+    CssCounterStyleRangeValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COUNTER_STYLE_RANGE_VALUE,
+        [Some(SyntaxElement::Node(ranges.into_syntax()))],
+    ))
+}
+pub fn css_counter_style_symbols_property(
+    name: CssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssCounterStyleSymbolList,
+) -> CssCounterStyleSymbolsProperty {
+    CssCounterStyleSymbolsProperty::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COUNTER_STYLE_SYMBOLS_PROPERTY,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn css_counter_style_system_property(
+    name: CssIdentifier,
+    colon_token: SyntaxToken,
+    value: AnyCssCounterStyleSystem,
+) -> CssCounterStyleSystemProperty {
+    CssCounterStyleSystemProperty::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COUNTER_STYLE_SYSTEM_PROPERTY,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
 pub fn css_custom_identifier(value_token: SyntaxToken) -> CssCustomIdentifier {
     CssCustomIdentifier::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_CUSTOM_IDENTIFIER,
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
+pub fn css_custom_media_at_rule(
+    custom_media_token: SyntaxToken,
+    name: CssDashedIdentifier,
+    queries: CssMediaQueryList,
+    semicolon_token: SyntaxToken,
+) -> CssCustomMediaAtRule {
+    CssCustomMediaAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_CUSTOM_MEDIA_AT_RULE,
+        [
+            Some(SyntaxElement::Token(custom_media_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Node(queries.into_syntax())),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
+    ))
+}
 pub fn css_dashed_identifier(value_token: SyntaxToken) -> CssDashedIdentifier {
     CssDashedIdentifier::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_DASHED_IDENTIFIER,
@@ -1365,10 +1813,24 @@ pub fn css_percentage(value_token: SyntaxToken, percent_token: SyntaxToken) -> C
         ],
     ))
 }
+pub fn css_position_try_at_rule(
+    position_try_token: SyntaxToken,
+    name: CssDashedIdentifier,
+    block: AnyCssDeclarationBlock,
+) -> CssPositionTryAtRule {
+    CssPositionTryAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_POSITION_TRY_AT_RULE,
+        [
+            Some(SyntaxElement::Token(position_try_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
 pub fn css_property_at_rule(
     property_token: SyntaxToken,
     name: CssDashedIdentifier,
-    block: AnyCssDeclarationBlock,
+    block: AnyCssPropertyAtRuleBlock,
 ) -> CssPropertyAtRule {
     CssPropertyAtRule::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_PROPERTY_AT_RULE,
@@ -1379,6 +1841,20 @@ pub fn css_property_at_rule(
         ],
     ))
 }
+pub fn css_property_at_rule_block(
+    l_curly_token: SyntaxToken,
+    declarations: CssDeclarationList,
+    r_curly_token: SyntaxToken,
+) -> CssPropertyAtRuleBlock {
+    CssPropertyAtRuleBlock::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_PROPERTY_AT_RULE_BLOCK,
+        [
+            Some(SyntaxElement::Token(l_curly_token)),
+            Some(SyntaxElement::Node(declarations.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
 pub fn css_pseudo_class_function_compound_selector(
     name_token: SyntaxToken,
     l_paren_token: SyntaxToken,
@@ -1637,6 +2113,38 @@ pub fn css_pseudo_class_selector(
         ],
     ))
 }
+pub fn css_pseudo_element_function_compound_selector(
+    name_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    selector: AnyCssCompoundSelector,
+    r_paren_token: SyntaxToken,
+) -> CssPseudoElementFunctionCompoundSelector {
+    CssPseudoElementFunctionCompoundSelector::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_PSEUDO_ELEMENT_FUNCTION_COMPOUND_SELECTOR,
+        [
+            Some(SyntaxElement::Token(name_token)),
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(selector.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn css_pseudo_element_function_custom_identifier_list(
+    name_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    names: CssCustomIdentifierList,
+    r_paren_token: SyntaxToken,
+) -> CssPseudoElementFunctionCustomIdentifierList {
+    CssPseudoElementFunctionCustomIdentifierList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_PSEUDO_ELEMENT_FUNCTION_CUSTOM_IDENTIFIER_LIST,
+        [
+            Some(SyntaxElement::Token(name_token)),
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(names.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
 pub fn css_pseudo_element_function_identifier(
     name_token: SyntaxToken,
     l_paren_token: SyntaxToken,
@@ -1799,6 +2307,66 @@ pub fn css_regular_dimension(
         ],
     ))
 }
+pub fn css_relative_color_alpha(
+    slash_token: SyntaxToken,
+    value: AnyCssValue,
+) -> CssRelativeColorAlpha {
+    CssRelativeColorAlpha::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_RELATIVE_COLOR_ALPHA,
+        [
+            Some(SyntaxElement::Token(slash_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn css_relative_color_function(
+    name: CssIdentifier,
+    l_paren_token: SyntaxToken,
+    from_token: SyntaxToken,
+    origin: AnyCssValue,
+    channels: CssComponentValueList,
+    r_paren_token: SyntaxToken,
+) -> CssRelativeColorFunctionBuilder {
+    CssRelativeColorFunctionBuilder {
+        name,
+        l_paren_token,
+        from_token,
+        origin,
+        channels,
+        r_paren_token,
+        alpha: None,
+    }
+}
+pub struct CssRelativeColorFunctionBuilder {
+    name: CssIdentifier,
+    l_paren_token: SyntaxToken,
+    from_token: SyntaxToken,
+    origin: AnyCssValue,
+    channels: CssComponentValueList,
+    r_paren_token: SyntaxToken,
+    alpha: Option<CssRelativeColorAlpha>,
+}
+impl CssRelativeColorFunctionBuilder {
+    pub fn with_alpha(mut self, alpha: CssRelativeColorAlpha) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+    pub fn build(self) -> CssRelativeColorFunction {
+        CssRelativeColorFunction::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_RELATIVE_COLOR_FUNCTION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.l_paren_token)),
+                Some(SyntaxElement::Token(self.from_token)),
+                Some(SyntaxElement::Node(self.origin.into_syntax())),
+                Some(SyntaxElement::Node(self.channels.into_syntax())),
+                self.alpha
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_paren_token)),
+            ],
+        ))
+    }
+}
 pub fn css_relative_selector(selector: AnyCssSelector) -> CssRelativeSelectorBuilder {
     CssRelativeSelectorBuilder {
         selector,
@@ -2016,6 +2584,38 @@ pub fn css_supports_feature_declaration(
         ],
     ))
 }
+pub fn css_supports_feature_font_format(
+    font_format_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    value: CssIdentifier,
+    r_paren_token: SyntaxToken,
+) -> CssSupportsFeatureFontFormat {
+    CssSupportsFeatureFontFormat::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_SUPPORTS_FEATURE_FONT_FORMAT,
+        [
+            Some(SyntaxElement::Token(font_format_token)),
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn css_supports_feature_font_tech(
+    font_tech_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    value: CssIdentifier,
+    r_paren_token: SyntaxToken,
+) -> CssSupportsFeatureFontTech {
+    CssSupportsFeatureFontTech::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_SUPPORTS_FEATURE_FONT_TECH,
+        [
+            Some(SyntaxElement::Token(font_tech_token)),
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
 pub fn css_supports_feature_selector(
     selector_token: SyntaxToken,
     l_paren_token: SyntaxToken,
@@ -2058,6 +2658,108 @@ pub fn css_supports_or_condition(
         ],
     ))
 }
+pub fn css_tailwind_apply_at_rule(
+    apply_token: SyntaxToken,
+    utilities: CssTailwindUtilityList,
+) -> CssTailwindApplyAtRuleBuilder {
+    CssTailwindApplyAtRuleBuilder {
+        apply_token,
+        utilities,
+        important: None,
+        semicolon_token: None,
+    }
+}
+pub struct CssTailwindApplyAtRuleBuilder {
+    apply_token: SyntaxToken,
+    utilities: CssTailwindUtilityList,
+    important: Option<CssDeclarationImportant>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl CssTailwindApplyAtRuleBuilder {
+    pub fn with_important(mut self, important: CssDeclarationImportant) -> Self {
+        self.important = Some(important);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> CssTailwindApplyAtRule {
+        CssTailwindApplyAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_TAILWIND_APPLY_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.apply_token)),
+                Some(SyntaxElement::Node(self.utilities.into_syntax())),
+                self.important
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn css_tailwind_at_rule(
+    tailwind_token: SyntaxToken,
+    layer: CssIdentifier,
+    semicolon_token: SyntaxToken,
+) -> CssTailwindAtRule {
+    CssTailwindAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_TAILWIND_AT_RULE,
+        [
+            Some(SyntaxElement::Token(tailwind_token)),
+            Some(SyntaxElement::Node(layer.into_syntax())),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
+    ))
+}
+pub fn css_tailwind_config_at_rule(
+    config_token: SyntaxToken,
+    path: CssString,
+    semicolon_token: SyntaxToken,
+) -> CssTailwindConfigAtRule {
+    CssTailwindConfigAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_TAILWIND_CONFIG_AT_RULE,
+        [
+            Some(SyntaxElement::Token(config_token)),
+            Some(SyntaxElement::Node(path.into_syntax())),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
+    ))
+}
+pub fn css_tailwind_theme_at_rule(
+    theme_token: SyntaxToken,
+    modifiers: CssTailwindThemeModifierList,
+    block: AnyCssDeclarationOrAtRuleBlock,
+) -> CssTailwindThemeAtRule {
+    CssTailwindThemeAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_TAILWIND_THEME_AT_RULE,
+        [
+            Some(SyntaxElement::Token(theme_token)),
+            Some(SyntaxElement::Node(modifiers.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn css_tailwind_utility(value_token: SyntaxToken) -> CssTailwindUtility {
+    CssTailwindUtility::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_TAILWIND_UTILITY,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn css_tailwind_variants_at_rule(
+    variants_token: SyntaxToken,
+    variants: CssTailwindVariantList,
+    block: AnyCssConditionalBlock,
+) -> CssTailwindVariantsAtRule {
+    CssTailwindVariantsAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_TAILWIND_VARIANTS_AT_RULE,
+        [
+            Some(SyntaxElement::Token(variants_token)),
+            Some(SyntaxElement::Node(variants.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
 pub fn css_type_selector(ident: CssIdentifier) -> CssTypeSelectorBuilder {
     CssTypeSelectorBuilder {
         ident,
@@ -2151,10 +2853,24 @@ impl CssUniversalSelectorBuilder {
         ))
     }
 }
+pub fn css_unknown_at_rule_raw_block(
+    l_curly_token: SyntaxToken,
+    items: CssUnknownAtRuleComponentList,
+    r_curly_token: SyntaxToken,
+) -> CssUnknownAtRuleRawBlock {
+    CssUnknownAtRuleRawBlock::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_UNKNOWN_AT_RULE_RAW_BLOCK,
+        [
+            Some(SyntaxElement::Token(l_curly_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
 pub fn css_unknown_block_at_rule(
     name: CssIdentifier,
     components: CssUnknownAtRuleComponentList,
-    block: AnyCssDeclarationOrRuleBlock,
+    block: AnyCssUnknownAtRuleBlock,
 ) -> CssUnknownBlockAtRule {
     CssUnknownBlockAtRule::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_UNKNOWN_BLOCK_AT_RULE,
@@ -2307,6 +3023,18 @@ pub fn css_value_at_rule_named_import_specifier(
         ],
     ))
 }
+pub fn css_view_transition_at_rule(
+    view_transition_token: SyntaxToken,
+    block: AnyCssDeclarationBlock,
+) -> CssViewTransitionAtRule {
+    CssViewTransitionAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_VIEW_TRANSITION_AT_RULE,
+        [
+            Some(SyntaxElement::Token(view_transition_token)),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
 pub fn css_bracketed_value_list<I>(items: I) -> CssBracketedValueList
 where
     I: IntoIterator<Item = AnyCssCustomIdentifier>,
@@ -2319,6 +3047,27 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn css_color_mix_color_list<I, S>(items: I, separators: S) -> CssColorMixColorList
+where
+    I: IntoIterator<Item = CssColorMixColor>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    CssColorMixColorList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COLOR_MIX_COLOR_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
 pub fn css_component_value_list<I>(items: I) -> CssComponentValueList
 where
     I: IntoIterator<Item = AnyCssValue>,
@@ -2364,6 +3113,63 @@ where
         }),
     ))
 }
+pub fn css_counter_style_additive_symbol_list<I, S>(
+    items: I,
+    separators: S,
+) -> CssCounterStyleAdditiveSymbolList
+where
+    I: IntoIterator<Item = CssCounterStyleAdditiveSymbol>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    CssCounterStyleAdditiveSymbolList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COUNTER_STYLE_ADDITIVE_SYMBOL_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn css_counter_style_range_list<I, S>(items: I, separators: S) -> CssCounterStyleRangeList
+where
+    I: IntoIterator<Item = CssCounterStyleRange>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    CssCounterStyleRangeList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COUNTER_STYLE_RANGE_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn css_counter_style_symbol_list<I>(items: I) -> CssCounterStyleSymbolList
+where
+    I: IntoIterator<Item = AnyCssCounterStyleSymbol>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssCounterStyleSymbolList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COUNTER_STYLE_SYMBOL_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn css_custom_identifier_list<I>(items: I) -> CssCustomIdentifierList
 where
     I: IntoIterator<Item = AnyCssCustomIdentifier>,
@@ -2739,6 +3545,51 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn css_tailwind_theme_modifier_list<I>(items: I) -> CssTailwindThemeModifierList
+where
+    I: IntoIterator<Item = CssIdentifier>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssTailwindThemeModifierList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_TAILWIND_THEME_MODIFIER_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn css_tailwind_utility_list<I>(items: I) -> CssTailwindUtilityList
+where
+    I: IntoIterator<Item = CssTailwindUtility>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssTailwindUtilityList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_TAILWIND_UTILITY_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn css_tailwind_variant_list<I, S>(items: I, separators: S) -> CssTailwindVariantList
+where
+    I: IntoIterator<Item = CssIdentifier>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    CssTailwindVariantList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_TAILWIND_VARIANT_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
 pub fn css_url_modifier_list<I>(items: I) -> CssUrlModifierList
 where
     I: IntoIterator<Item = AnyCssUrlModifier>,
@@ -2823,6 +3674,16 @@ where
         slots,
     ))
 }
+pub fn css_bogus_container_query<I>(slots: I) -> CssBogusContainerQuery
+where
+    I: IntoIterator<Item = Option<SyntaxElement>>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssBogusContainerQuery::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_BOGUS_CONTAINER_QUERY,
+        slots,
+    ))
+}
 pub fn css_bogus_custom_identifier<I>(slots: I) -> CssBogusCustomIdentifier
 where
     I: IntoIterator<Item = Option<SyntaxElement>>,
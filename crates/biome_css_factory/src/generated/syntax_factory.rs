@@ -17,6 +17,7 @@ impl SyntaxFactory for CssSyntaxFactory {
             CSS_BOGUS
             | CSS_BOGUS_AT_RULE
             | CSS_BOGUS_BLOCK
+            | CSS_BOGUS_CONTAINER_QUERY
             | CSS_BOGUS_CUSTOM_IDENTIFIER
             | CSS_BOGUS_DECLARATION_ITEM
             | CSS_BOGUS_DOCUMENT_MATCHER
@@ -42,6 +43,140 @@ impl SyntaxFactory for CssSyntaxFactory {
             | CSS_VALUE_AT_RULE_GENERIC_VALUE => {
                 RawSyntaxNode::new(kind, children.into_iter().map(Some))
             }
+            CSS_ANCHOR_FALLBACK => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [,] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssValue::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_ANCHOR_FALLBACK.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_ANCHOR_FALLBACK, children)
+            }
+            CSS_ANCHOR_FUNCTION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![anchor] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssDashedIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssAnchorSide::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssAnchorFallback::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_ANCHOR_FUNCTION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_ANCHOR_FUNCTION, children)
+            }
+            CSS_ANCHOR_SIZE_FUNCTION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![anchor_size] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssDashedIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssAnchorFallback::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_ANCHOR_SIZE_FUNCTION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_ANCHOR_SIZE_FUNCTION, children)
+            }
             CSS_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
@@ -340,6 +475,145 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.into_node(CSS_COLOR, children)
             }
+            CSS_COLOR_HUE_INTERPOLATION_METHOD => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if CssIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![hue] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_COLOR_HUE_INTERPOLATION_METHOD.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_COLOR_HUE_INTERPOLATION_METHOD, children)
+            }
+            CSS_COLOR_INTERPOLATION_METHOD => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![in] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssColorHueInterpolationMethod::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_COLOR_INTERPOLATION_METHOD.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_COLOR_INTERPOLATION_METHOD, children)
+            }
+            CSS_COLOR_MIX_COLOR => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if AnyCssValue::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssPercentage::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_COLOR_MIX_COLOR.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_COLOR_MIX_COLOR, children)
+            }
+            CSS_COLOR_MIX_FUNCTION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![color_mix] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssColorInterpolationMethod::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [,] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssColorMixColorList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_COLOR_MIX_FUNCTION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_COLOR_MIX_FUNCTION, children)
+            }
             CSS_COLOR_PROFILE_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
@@ -692,26 +966,26 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.into_node(CSS_CONTAINER_QUERY_IN_PARENS, children)
             }
-            CSS_CONTAINER_SIZE_FEATURE_IN_PARENS => {
+            CSS_CONTAINER_SCROLL_STATE_AND_QUERY => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if CssContainerScrollStateInParens::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssQueryFeature::can_cast(element.kind()) {
+                    if element.kind() == T![and] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if AnyCssContainerScrollStateAndCombinableQuery::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -719,32 +993,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_CONTAINER_SIZE_FEATURE_IN_PARENS.to_bogus(),
+                        CSS_CONTAINER_SCROLL_STATE_AND_QUERY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_CONTAINER_SIZE_FEATURE_IN_PARENS, children)
+                slots.into_node(CSS_CONTAINER_SCROLL_STATE_AND_QUERY, children)
             }
-            CSS_CONTAINER_STYLE_AND_QUERY => {
+            CSS_CONTAINER_SCROLL_STATE_IN_PARENS => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssContainerStyleInParens::can_cast(element.kind()) {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![and] {
+                    if AnyCssContainerScrollStateQuery::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssContainerStyleAndCombinableQuery::can_cast(element.kind()) {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -752,32 +1026,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_CONTAINER_STYLE_AND_QUERY.to_bogus(),
+                        CSS_CONTAINER_SCROLL_STATE_IN_PARENS.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_CONTAINER_STYLE_AND_QUERY, children)
+                slots.into_node(CSS_CONTAINER_SCROLL_STATE_IN_PARENS, children)
             }
-            CSS_CONTAINER_STYLE_IN_PARENS => {
+            CSS_CONTAINER_SCROLL_STATE_NOT_QUERY => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if AnyCssContainerStyleInParens::can_cast(element.kind()) {
+                    if element.kind() == T![not] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if CssContainerScrollStateInParens::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -785,25 +1052,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_CONTAINER_STYLE_IN_PARENS.to_bogus(),
+                        CSS_CONTAINER_SCROLL_STATE_NOT_QUERY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_CONTAINER_STYLE_IN_PARENS, children)
+                slots.into_node(CSS_CONTAINER_SCROLL_STATE_NOT_QUERY, children)
             }
-            CSS_CONTAINER_STYLE_NOT_QUERY => {
+            CSS_CONTAINER_SCROLL_STATE_OR_QUERY => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![not] {
+                    if CssContainerScrollStateInParens::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssContainerStyleInParens::can_cast(element.kind()) {
+                    if element.kind() == T![or] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssContainerScrollStateOrCombinableQuery::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -811,32 +1085,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_CONTAINER_STYLE_NOT_QUERY.to_bogus(),
+                        CSS_CONTAINER_SCROLL_STATE_OR_QUERY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_CONTAINER_STYLE_NOT_QUERY, children)
+                slots.into_node(CSS_CONTAINER_SCROLL_STATE_OR_QUERY, children)
             }
-            CSS_CONTAINER_STYLE_OR_QUERY => {
+            CSS_CONTAINER_SCROLL_STATE_QUERY_IN_PARENS => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssContainerStyleInParens::can_cast(element.kind()) {
+                    if element.kind() == T![scroll_state] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![or] {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssContainerStyleOrCombinableQuery::can_cast(element.kind()) {
+                    if AnyCssContainerScrollStateQuery::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -844,23 +1125,16 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_CONTAINER_STYLE_OR_QUERY.to_bogus(),
+                        CSS_CONTAINER_SCROLL_STATE_QUERY_IN_PARENS.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_CONTAINER_STYLE_OR_QUERY, children)
+                slots.into_node(CSS_CONTAINER_SCROLL_STATE_QUERY_IN_PARENS, children)
             }
-            CSS_CONTAINER_STYLE_QUERY_IN_PARENS => {
+            CSS_CONTAINER_SIZE_FEATURE_IN_PARENS => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
-                if let Some(element) = &current_element {
-                    if element.kind() == T![style] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
                 if let Some(element) = &current_element {
                     if element.kind() == T!['('] {
                         slots.mark_present();
@@ -869,7 +1143,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssContainerStyleQuery::can_cast(element.kind()) {
+                    if AnyCssQueryFeature::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -884,32 +1158,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_CONTAINER_STYLE_QUERY_IN_PARENS.to_bogus(),
+                        CSS_CONTAINER_SIZE_FEATURE_IN_PARENS.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_CONTAINER_STYLE_QUERY_IN_PARENS, children)
+                slots.into_node(CSS_CONTAINER_SIZE_FEATURE_IN_PARENS, children)
             }
-            CSS_COUNTER_STYLE_AT_RULE => {
+            CSS_CONTAINER_STYLE_AND_QUERY => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![counter_style] {
+                    if CssContainerStyleInParens::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssCustomIdentifier::can_cast(element.kind()) {
+                    if element.kind() == T![and] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
+                    if AnyCssContainerStyleAndCombinableQuery::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -917,37 +1191,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_COUNTER_STYLE_AT_RULE.to_bogus(),
+                        CSS_CONTAINER_STYLE_AND_QUERY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_COUNTER_STYLE_AT_RULE, children)
+                slots.into_node(CSS_CONTAINER_STYLE_AND_QUERY, children)
             }
-            CSS_CUSTOM_IDENTIFIER => {
+            CSS_CONTAINER_STYLE_IN_PARENS => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == IDENT {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_CUSTOM_IDENTIFIER.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
+                if let Some(element) = &current_element {
+                    if AnyCssContainerStyleInParens::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
                 }
-                slots.into_node(CSS_CUSTOM_IDENTIFIER, children)
-            }
-            CSS_DASHED_IDENTIFIER => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
+                slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == IDENT {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -955,25 +1224,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_DASHED_IDENTIFIER.to_bogus(),
+                        CSS_CONTAINER_STYLE_IN_PARENS.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_DASHED_IDENTIFIER, children)
+                slots.into_node(CSS_CONTAINER_STYLE_IN_PARENS, children)
             }
-            CSS_DECLARATION => {
+            CSS_CONTAINER_STYLE_NOT_QUERY => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssProperty::can_cast(element.kind()) {
+                    if element.kind() == T![not] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssDeclarationImportant::can_cast(element.kind()) {
+                    if CssContainerStyleInParens::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -981,32 +1250,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_DECLARATION.to_bogus(),
+                        CSS_CONTAINER_STYLE_NOT_QUERY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_DECLARATION, children)
+                slots.into_node(CSS_CONTAINER_STYLE_NOT_QUERY, children)
             }
-            CSS_DECLARATION_BLOCK => {
+            CSS_CONTAINER_STYLE_OR_QUERY => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['{'] {
+                    if CssContainerStyleInParens::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssDeclarationList::can_cast(element.kind()) {
+                    if element.kind() == T![or] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['}'] {
+                    if AnyCssContainerStyleOrCombinableQuery::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1014,25 +1283,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_DECLARATION_BLOCK.to_bogus(),
+                        CSS_CONTAINER_STYLE_OR_QUERY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_DECLARATION_BLOCK, children)
+                slots.into_node(CSS_CONTAINER_STYLE_OR_QUERY, children)
             }
-            CSS_DECLARATION_IMPORTANT => {
+            CSS_CONTAINER_STYLE_QUERY_IN_PARENS => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![!] {
+                    if element.kind() == T![style] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![important] {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssContainerStyleQuery::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1040,32 +1323,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_DECLARATION_IMPORTANT.to_bogus(),
+                        CSS_CONTAINER_STYLE_QUERY_IN_PARENS.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_DECLARATION_IMPORTANT, children)
+                slots.into_node(CSS_CONTAINER_STYLE_QUERY_IN_PARENS, children)
             }
-            CSS_DECLARATION_OR_AT_RULE_BLOCK => {
+            CSS_CONTAINER_STYLE_RANGE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['{'] {
+                    if AnyCssValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssDeclarationOrAtRuleList::can_cast(element.kind()) {
+                    if CssQueryFeatureRangeComparison::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['}'] {
+                    if AnyCssValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1073,32 +1356,46 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_DECLARATION_OR_AT_RULE_BLOCK.to_bogus(),
+                        CSS_CONTAINER_STYLE_RANGE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_DECLARATION_OR_AT_RULE_BLOCK, children)
+                slots.into_node(CSS_CONTAINER_STYLE_RANGE, children)
             }
-            CSS_DECLARATION_OR_RULE_BLOCK => {
+            CSS_CONTAINER_STYLE_RANGE_INTERVAL => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<5usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['{'] {
+                    if AnyCssValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssDeclarationOrRuleList::can_cast(element.kind()) {
+                    if CssQueryFeatureRangeComparison::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['}'] {
+                    if AnyCssValue::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssQueryFeatureRangeComparison::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1106,25 +1403,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_DECLARATION_OR_RULE_BLOCK.to_bogus(),
+                        CSS_CONTAINER_STYLE_RANGE_INTERVAL.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_DECLARATION_OR_RULE_BLOCK, children)
+                slots.into_node(CSS_CONTAINER_STYLE_RANGE_INTERVAL, children)
             }
-            CSS_DECLARATION_WITH_SEMICOLON => {
+            CSS_COUNTER_STYLE_ADDITIVE_SYMBOL => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssDeclaration::can_cast(element.kind()) {
+                    if CssNumber::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [;] {
+                    if AnyCssCounterStyleSymbol::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1132,32 +1429,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_DECLARATION_WITH_SEMICOLON.to_bogus(),
+                        CSS_COUNTER_STYLE_ADDITIVE_SYMBOL.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_DECLARATION_WITH_SEMICOLON, children)
+                slots.into_node(CSS_COUNTER_STYLE_ADDITIVE_SYMBOL, children)
             }
-            CSS_DOCUMENT_AT_RULE => {
+            CSS_COUNTER_STYLE_ADDITIVE_SYMBOLS_PROPERTY => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![document] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssDocumentMatcherList::can_cast(element.kind()) {
+                    if element.kind() == T ! [:] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssRuleBlock::can_cast(element.kind()) {
+                    if CssCounterStyleAdditiveSymbolList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1165,42 +1462,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_DOCUMENT_AT_RULE.to_bogus(),
+                        CSS_COUNTER_STYLE_ADDITIVE_SYMBOLS_PROPERTY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_DOCUMENT_AT_RULE, children)
+                slots.into_node(CSS_COUNTER_STYLE_ADDITIVE_SYMBOLS_PROPERTY, children)
             }
-            CSS_DOCUMENT_CUSTOM_MATCHER => {
+            CSS_COUNTER_STYLE_AT_RULE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(
-                        element.kind(),
-                        T![url_prefix] | T![domain] | T![media_document] | T![regexp]
-                    ) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if element.kind() == T![counter_style] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssUrlValue::can_cast(element.kind()) {
+                    if CssCustomIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1208,18 +1495,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_DOCUMENT_CUSTOM_MATCHER.to_bogus(),
+                        CSS_COUNTER_STYLE_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_DOCUMENT_CUSTOM_MATCHER, children)
+                slots.into_node(CSS_COUNTER_STYLE_AT_RULE, children)
             }
-            CSS_EMPTY_DECLARATION => {
+            CSS_COUNTER_STYLE_EXTENDS_SYSTEM => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [;] {
+                    if element.kind() == T![extends] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssCustomIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1227,25 +1521,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_EMPTY_DECLARATION.to_bogus(),
+                        CSS_COUNTER_STYLE_EXTENDS_SYSTEM.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_EMPTY_DECLARATION, children)
+                slots.into_node(CSS_COUNTER_STYLE_EXTENDS_SYSTEM, children)
             }
-            CSS_FONT_FACE_AT_RULE => {
+            CSS_COUNTER_STYLE_FIXED_SYSTEM => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![font_face] {
+                    if element.kind() == T![fixed] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
+                    if CssNumber::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1253,18 +1547,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_FONT_FACE_AT_RULE.to_bogus(),
+                        CSS_COUNTER_STYLE_FIXED_SYSTEM.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_FONT_FACE_AT_RULE, children)
+                slots.into_node(CSS_COUNTER_STYLE_FIXED_SYSTEM, children)
             }
-            CSS_FONT_FAMILY_NAME => {
+            CSS_COUNTER_STYLE_RANGE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssCustomIdentifierList::can_cast(element.kind()) {
+                    if AnyCssCounterStyleRangeBound::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssCounterStyleRangeBound::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1272,32 +1573,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_FONT_FAMILY_NAME.to_bogus(),
+                        CSS_COUNTER_STYLE_RANGE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_FONT_FAMILY_NAME, children)
+                slots.into_node(CSS_COUNTER_STYLE_RANGE, children)
             }
-            CSS_FONT_FEATURE_VALUES_AT_RULE => {
+            CSS_COUNTER_STYLE_RANGE_PROPERTY => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![font_feature_values] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssFontFamilyNameList::can_cast(element.kind()) {
+                    if element.kind() == T ! [:] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssFontFeatureValuesBlock::can_cast(element.kind()) {
+                    if AnyCssCounterStyleRange::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1305,32 +1606,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_FONT_FEATURE_VALUES_AT_RULE.to_bogus(),
+                        CSS_COUNTER_STYLE_RANGE_PROPERTY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_FONT_FEATURE_VALUES_AT_RULE, children)
+                slots.into_node(CSS_COUNTER_STYLE_RANGE_PROPERTY, children)
             }
-            CSS_FONT_FEATURE_VALUES_BLOCK => {
+            CSS_COUNTER_STYLE_RANGE_VALUE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['{'] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if CssFontFeatureValuesItemList::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T!['}'] {
+                    if CssCounterStyleRangeList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1338,41 +1625,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_FONT_FEATURE_VALUES_BLOCK.to_bogus(),
+                        CSS_COUNTER_STYLE_RANGE_VALUE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_FONT_FEATURE_VALUES_BLOCK, children)
+                slots.into_node(CSS_COUNTER_STYLE_RANGE_VALUE, children)
             }
-            CSS_FONT_FEATURE_VALUES_ITEM => {
+            CSS_COUNTER_STYLE_SYMBOLS_PROPERTY => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [@] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if matches!(
-                        element.kind(),
-                        T![stylistic]
-                            | T![historical_forms]
-                            | T![styleset]
-                            | T![character_variant]
-                            | T![swash]
-                            | T![ornaments]
-                            | T![annotation]
-                    ) {
+                    if element.kind() == T ! [:] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
+                    if CssCounterStyleSymbolList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1380,32 +1658,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_FONT_FEATURE_VALUES_ITEM.to_bogus(),
+                        CSS_COUNTER_STYLE_SYMBOLS_PROPERTY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_FONT_FEATURE_VALUES_ITEM, children)
+                slots.into_node(CSS_COUNTER_STYLE_SYMBOLS_PROPERTY, children)
             }
-            CSS_FONT_PALETTE_VALUES_AT_RULE => {
+            CSS_COUNTER_STYLE_SYSTEM_PROPERTY => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![font_palette_values] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssDashedIdentifier::can_cast(element.kind()) {
+                    if element.kind() == T ! [:] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
+                    if AnyCssCounterStyleSystem::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1413,39 +1691,58 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_FONT_PALETTE_VALUES_AT_RULE.to_bogus(),
+                        CSS_COUNTER_STYLE_SYSTEM_PROPERTY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_FONT_PALETTE_VALUES_AT_RULE, children)
+                slots.into_node(CSS_COUNTER_STYLE_SYSTEM_PROPERTY, children)
             }
-            CSS_FUNCTION => {
+            CSS_CUSTOM_IDENTIFIER => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == IDENT {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_CUSTOM_IDENTIFIER.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_CUSTOM_IDENTIFIER, children)
+            }
+            CSS_CUSTOM_MEDIA_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if element.kind() == T![custom_media] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if CssDashedIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssParameterList::can_cast(element.kind()) {
+                    if CssMediaQueryList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if element.kind() == T ! [;] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1453,18 +1750,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_FUNCTION.to_bogus(),
+                        CSS_CUSTOM_MEDIA_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_FUNCTION, children)
+                slots.into_node(CSS_CUSTOM_MEDIA_AT_RULE, children)
             }
-            CSS_GENERIC_DELIMITER => {
+            CSS_DASHED_IDENTIFIER => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [,] | T ! [/]) {
+                    if element.kind() == IDENT {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1472,32 +1769,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_GENERIC_DELIMITER.to_bogus(),
+                        CSS_DASHED_IDENTIFIER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_GENERIC_DELIMITER, children)
+                slots.into_node(CSS_DASHED_IDENTIFIER, children)
             }
-            CSS_GENERIC_PROPERTY => {
+            CSS_DECLARATION => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssDeclarationName::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T ! [:] {
+                    if AnyCssProperty::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssGenericComponentValueList::can_cast(element.kind()) {
+                    if CssDeclarationImportant::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1505,44 +1795,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_GENERIC_PROPERTY.to_bogus(),
+                        CSS_DECLARATION.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_GENERIC_PROPERTY, children)
+                slots.into_node(CSS_DECLARATION, children)
             }
-            CSS_ID_SELECTOR => {
+            CSS_DECLARATION_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [#] {
+                    if element.kind() == T!['{'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssCustomIdentifier::can_cast(element.kind()) {
+                    if CssDeclarationList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_ID_SELECTOR.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_ID_SELECTOR, children)
-            }
-            CSS_IDENTIFIER => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == IDENT {
+                    if element.kind() == T!['}'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1550,18 +1828,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_IDENTIFIER.to_bogus(),
+                        CSS_DECLARATION_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_IDENTIFIER, children)
+                slots.into_node(CSS_DECLARATION_BLOCK, children)
             }
-            CSS_IMPORT_ANONYMOUS_LAYER => {
+            CSS_DECLARATION_IMPORTANT => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![layer] {
+                    if element.kind() == T![!] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![important] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1569,53 +1854,65 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_IMPORT_ANONYMOUS_LAYER.to_bogus(),
+                        CSS_DECLARATION_IMPORTANT.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_IMPORT_ANONYMOUS_LAYER, children)
+                slots.into_node(CSS_DECLARATION_IMPORTANT, children)
             }
-            CSS_IMPORT_AT_RULE => {
+            CSS_DECLARATION_OR_AT_RULE_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![import] {
+                    if element.kind() == T!['{'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssImportUrl::can_cast(element.kind()) {
+                    if CssDeclarationOrAtRuleList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssImportLayer::can_cast(element.kind()) {
+                    if element.kind() == T!['}'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_DECLARATION_OR_AT_RULE_BLOCK.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_DECLARATION_OR_AT_RULE_BLOCK, children)
+            }
+            CSS_DECLARATION_OR_RULE_BLOCK => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssImportSupports::can_cast(element.kind()) {
+                    if element.kind() == T!['{'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssMediaQueryList::can_cast(element.kind()) {
+                    if CssDeclarationOrRuleList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [;] {
+                    if element.kind() == T!['}'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1623,39 +1920,58 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_IMPORT_AT_RULE.to_bogus(),
+                        CSS_DECLARATION_OR_RULE_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_IMPORT_AT_RULE, children)
+                slots.into_node(CSS_DECLARATION_OR_RULE_BLOCK, children)
             }
-            CSS_IMPORT_NAMED_LAYER => {
+            CSS_DECLARATION_WITH_SEMICOLON => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![layer] {
+                    if CssDeclaration::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if element.kind() == T ! [;] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_DECLARATION_WITH_SEMICOLON.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_DECLARATION_WITH_SEMICOLON, children)
+            }
+            CSS_DOCUMENT_AT_RULE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![document] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssLayerNameList::can_cast(element.kind()) {
+                    if CssDocumentMatcherList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if AnyCssRuleBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1663,18 +1979,21 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_IMPORT_NAMED_LAYER.to_bogus(),
+                        CSS_DOCUMENT_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_IMPORT_NAMED_LAYER, children)
+                slots.into_node(CSS_DOCUMENT_AT_RULE, children)
             }
-            CSS_IMPORT_SUPPORTS => {
+            CSS_DOCUMENT_CUSTOM_MATCHER => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![supports] {
+                    if matches!(
+                        element.kind(),
+                        T![url_prefix] | T![domain] | T![media_document] | T![regexp]
+                    ) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1688,7 +2007,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssImportSupportsCondition::can_cast(element.kind()) {
+                    if AnyCssUrlValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1703,32 +2022,44 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_IMPORT_SUPPORTS.to_bogus(),
+                        CSS_DOCUMENT_CUSTOM_MATCHER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_IMPORT_SUPPORTS, children)
+                slots.into_node(CSS_DOCUMENT_CUSTOM_MATCHER, children)
             }
-            CSS_KEYFRAMES_AT_RULE => {
+            CSS_EMPTY_DECLARATION => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![keyframes] {
+                    if element.kind() == T ! [;] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_EMPTY_DECLARATION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_EMPTY_DECLARATION, children)
+            }
+            CSS_FONT_FACE_AT_RULE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssKeyframesName::can_cast(element.kind()) {
+                    if element.kind() == T![font_face] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssKeyframesBlock::can_cast(element.kind()) {
+                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1736,32 +2067,1003 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_KEYFRAMES_AT_RULE.to_bogus(),
+                        CSS_FONT_FACE_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_KEYFRAMES_AT_RULE, children)
+                slots.into_node(CSS_FONT_FACE_AT_RULE, children)
             }
-            CSS_KEYFRAMES_BLOCK => {
+            CSS_FONT_FAMILY_NAME => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['{'] {
+                    if CssCustomIdentifierList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if CssKeyframesItemList::can_cast(element.kind()) {
-                        slots.mark_present();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_FONT_FAMILY_NAME.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_FONT_FAMILY_NAME, children)
+            }
+            CSS_FONT_FEATURE_VALUES_AT_RULE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![font_feature_values] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssFontFamilyNameList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssFontFeatureValuesBlock::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_FONT_FEATURE_VALUES_AT_RULE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_FONT_FEATURE_VALUES_AT_RULE, children)
+            }
+            CSS_FONT_FEATURE_VALUES_BLOCK => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['{'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssFontFeatureValuesItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['}'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_FONT_FEATURE_VALUES_BLOCK.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_FONT_FEATURE_VALUES_BLOCK, children)
+            }
+            CSS_FONT_FEATURE_VALUES_ITEM => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [@] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if matches!(
+                        element.kind(),
+                        T![stylistic]
+                            | T![historical_forms]
+                            | T![styleset]
+                            | T![character_variant]
+                            | T![swash]
+                            | T![ornaments]
+                            | T![annotation]
+                    ) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_FONT_FEATURE_VALUES_ITEM.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_FONT_FEATURE_VALUES_ITEM, children)
+            }
+            CSS_FONT_PALETTE_VALUES_AT_RULE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![font_palette_values] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssDashedIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_FONT_PALETTE_VALUES_AT_RULE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_FONT_PALETTE_VALUES_AT_RULE, children)
+            }
+            CSS_FUNCTION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if CssIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssParameterList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_FUNCTION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_FUNCTION, children)
+            }
+            CSS_GENERIC_DELIMITER => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if matches!(element.kind(), T ! [,] | T ! [/]) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_GENERIC_DELIMITER.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_GENERIC_DELIMITER, children)
+            }
+            CSS_GENERIC_PROPERTY => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if AnyCssDeclarationName::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [:] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssGenericComponentValueList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_GENERIC_PROPERTY.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_GENERIC_PROPERTY, children)
+            }
+            CSS_ID_SELECTOR => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [#] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssCustomIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_ID_SELECTOR.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_ID_SELECTOR, children)
+            }
+            CSS_IDENTIFIER => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == IDENT {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_IDENTIFIER.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_IDENTIFIER, children)
+            }
+            CSS_IMPORT_ANONYMOUS_LAYER => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![layer] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_IMPORT_ANONYMOUS_LAYER.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_IMPORT_ANONYMOUS_LAYER, children)
+            }
+            CSS_IMPORT_AT_RULE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![import] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssImportUrl::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssImportLayer::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssImportSupports::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssMediaQueryList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [;] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_IMPORT_AT_RULE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_IMPORT_AT_RULE, children)
+            }
+            CSS_IMPORT_NAMED_LAYER => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![layer] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssLayerNameList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_IMPORT_NAMED_LAYER.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_IMPORT_NAMED_LAYER, children)
+            }
+            CSS_IMPORT_SUPPORTS => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![supports] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssImportSupportsCondition::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_IMPORT_SUPPORTS.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_IMPORT_SUPPORTS, children)
+            }
+            CSS_KEYFRAMES_AT_RULE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![keyframes] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssKeyframesName::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssKeyframesBlock::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_KEYFRAMES_AT_RULE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_KEYFRAMES_AT_RULE, children)
+            }
+            CSS_KEYFRAMES_BLOCK => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['{'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssKeyframesItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['}'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_KEYFRAMES_BLOCK.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_KEYFRAMES_BLOCK, children)
+            }
+            CSS_KEYFRAMES_IDENT_SELECTOR => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if matches!(element.kind(), T![from] | T![to]) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_KEYFRAMES_IDENT_SELECTOR.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_KEYFRAMES_IDENT_SELECTOR, children)
+            }
+            CSS_KEYFRAMES_ITEM => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if CssKeyframesSelectorList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_KEYFRAMES_ITEM.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_KEYFRAMES_ITEM, children)
+            }
+            CSS_KEYFRAMES_PERCENTAGE_SELECTOR => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if CssPercentage::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_KEYFRAMES_PERCENTAGE_SELECTOR.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_KEYFRAMES_PERCENTAGE_SELECTOR, children)
+            }
+            CSS_KEYFRAMES_SCOPE_FUNCTION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if matches!(element.kind(), T![global] | T![local]) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssKeyframesIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_KEYFRAMES_SCOPE_FUNCTION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_KEYFRAMES_SCOPE_FUNCTION, children)
+            }
+            CSS_KEYFRAMES_SCOPE_PREFIX => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if matches!(element.kind(), T![global] | T![local]) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssKeyframesIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_KEYFRAMES_SCOPE_PREFIX.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_KEYFRAMES_SCOPE_PREFIX, children)
+            }
+            CSS_KEYFRAMES_SCOPED_NAME => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [:] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssKeyframesScope::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_KEYFRAMES_SCOPED_NAME.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_KEYFRAMES_SCOPED_NAME, children)
+            }
+            CSS_LAYER_AT_RULE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![layer] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssLayer::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_LAYER_AT_RULE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_LAYER_AT_RULE, children)
+            }
+            CSS_LAYER_DECLARATION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if CssLayerReferenceList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssConditionalBlock::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_LAYER_DECLARATION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_LAYER_DECLARATION, children)
+            }
+            CSS_LAYER_REFERENCE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if CssLayerReferenceList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [;] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_LAYER_REFERENCE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_LAYER_REFERENCE, children)
+            }
+            CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if CssComponentValueList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION, children)
+            }
+            CSS_MARGIN_AT_RULE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [@] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if matches!(
+                        element.kind(),
+                        T![top_left_corner]
+                            | T![top_left]
+                            | T![top_center]
+                            | T![top_right]
+                            | T![top_right_corner]
+                            | T![bottom_left_corner]
+                            | T![bottom_left]
+                            | T![bottom_center]
+                            | T![bottom_right]
+                            | T![bottom_right_corner]
+                            | T![left_top]
+                            | T![left_middle]
+                            | T![left_bottom]
+                            | T![right_top]
+                            | T![right_middle]
+                            | T![right_bottom]
+                    ) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssDeclarationOrAtRuleBlock::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_MARGIN_AT_RULE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_MARGIN_AT_RULE, children)
+            }
+            CSS_MEDIA_AND_CONDITION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if AnyCssMediaInParens::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![and] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssMediaAndCombinableCondition::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_MEDIA_AND_CONDITION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_MEDIA_AND_CONDITION, children)
+            }
+            CSS_MEDIA_AND_TYPE_QUERY => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if CssMediaTypeQuery::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![and] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssMediaTypeCondition::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_MEDIA_AND_TYPE_QUERY.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_MEDIA_AND_TYPE_QUERY, children)
+            }
+            CSS_MEDIA_AT_RULE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![media] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssMediaQueryList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssConditionalBlock::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_MEDIA_AT_RULE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_MEDIA_AT_RULE, children)
+            }
+            CSS_MEDIA_CONDITION_IN_PARENS => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssMediaCondition::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_MEDIA_CONDITION_IN_PARENS.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_MEDIA_CONDITION_IN_PARENS, children)
+            }
+            CSS_MEDIA_CONDITION_QUERY => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if AnyCssMediaCondition::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_MEDIA_CONDITION_QUERY.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_MEDIA_CONDITION_QUERY, children)
+            }
+            CSS_MEDIA_FEATURE_IN_PARENS => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['}'] {
+                    if AnyCssQueryFeature::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1769,18 +3071,77 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_KEYFRAMES_BLOCK.to_bogus(),
+                        CSS_MEDIA_FEATURE_IN_PARENS.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_KEYFRAMES_BLOCK, children)
+                slots.into_node(CSS_MEDIA_FEATURE_IN_PARENS, children)
             }
-            CSS_KEYFRAMES_IDENT_SELECTOR => {
+            CSS_MEDIA_NOT_CONDITION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![not] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssMediaInParens::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_MEDIA_NOT_CONDITION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_MEDIA_NOT_CONDITION, children)
+            }
+            CSS_MEDIA_OR_CONDITION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if AnyCssMediaInParens::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![or] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssMediaOrCombinableCondition::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_MEDIA_OR_CONDITION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_MEDIA_OR_CONDITION, children)
+            }
+            CSS_MEDIA_TYPE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T![from] | T![to]) {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1788,25 +3149,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_KEYFRAMES_IDENT_SELECTOR.to_bogus(),
+                        CSS_MEDIA_TYPE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_KEYFRAMES_IDENT_SELECTOR, children)
+                slots.into_node(CSS_MEDIA_TYPE, children)
             }
-            CSS_KEYFRAMES_ITEM => {
+            CSS_MEDIA_TYPE_QUERY => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssKeyframesSelectorList::can_cast(element.kind()) {
+                    if matches!(element.kind(), T![only] | T![not]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
+                    if CssMediaType::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1814,18 +3175,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_KEYFRAMES_ITEM.to_bogus(),
+                        CSS_MEDIA_TYPE_QUERY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_KEYFRAMES_ITEM, children)
+                slots.into_node(CSS_MEDIA_TYPE_QUERY, children)
             }
-            CSS_KEYFRAMES_PERCENTAGE_SELECTOR => {
+            CSS_METAVARIABLE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssPercentage::can_cast(element.kind()) {
+                    if element.kind() == GRIT_METAVARIABLE {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1833,39 +3194,84 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_KEYFRAMES_PERCENTAGE_SELECTOR.to_bogus(),
+                        CSS_METAVARIABLE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_KEYFRAMES_PERCENTAGE_SELECTOR, children)
+                slots.into_node(CSS_METAVARIABLE, children)
             }
-            CSS_KEYFRAMES_SCOPE_FUNCTION => {
+            CSS_NAMED_NAMESPACE_PREFIX => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if CssIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_NAMED_NAMESPACE_PREFIX.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_NAMED_NAMESPACE_PREFIX, children)
+            }
+            CSS_NAMESPACE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if AnyCssNamespacePrefix::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [|] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_NAMESPACE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_NAMESPACE, children)
+            }
+            CSS_NAMESPACE_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T![global] | T![local]) {
+                    if element.kind() == T![namespace] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssKeyframesIdentifier::can_cast(element.kind()) {
+                    if AnyCssNamespaceUrl::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if element.kind() == T ! [;] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1873,25 +3279,89 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_KEYFRAMES_SCOPE_FUNCTION.to_bogus(),
+                        CSS_NAMESPACE_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_KEYFRAMES_SCOPE_FUNCTION, children)
+                slots.into_node(CSS_NAMESPACE_AT_RULE, children)
             }
-            CSS_KEYFRAMES_SCOPE_PREFIX => {
+            CSS_NESTED_QUALIFIED_RULE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if CssRelativeSelectorList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssDeclarationOrRuleBlock::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_NESTED_QUALIFIED_RULE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_NESTED_QUALIFIED_RULE, children)
+            }
+            CSS_NESTED_SELECTOR => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [&] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_NESTED_SELECTOR.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_NESTED_SELECTOR, children)
+            }
+            CSS_NTH_OFFSET => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if matches!(element.kind(), T ! [+] | T ! [-]) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssNumber::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_NTH_OFFSET.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_NTH_OFFSET, children)
+            }
+            CSS_NUMBER => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T![global] | T![local]) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if AnyCssKeyframesIdentifier::can_cast(element.kind()) {
+                    if element.kind() == CSS_NUMBER_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1899,25 +3369,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_KEYFRAMES_SCOPE_PREFIX.to_bogus(),
+                        CSS_NUMBER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_KEYFRAMES_SCOPE_PREFIX, children)
+                slots.into_node(CSS_NUMBER, children)
             }
-            CSS_KEYFRAMES_SCOPED_NAME => {
+            CSS_PAGE_AT_RULE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [:] {
+                    if element.kind() == T![page] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssKeyframesScope::can_cast(element.kind()) {
+                    if CssPageSelectorList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssPageAtRuleBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1925,25 +3402,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_KEYFRAMES_SCOPED_NAME.to_bogus(),
+                        CSS_PAGE_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_KEYFRAMES_SCOPED_NAME, children)
+                slots.into_node(CSS_PAGE_AT_RULE, children)
             }
-            CSS_LAYER_AT_RULE => {
+            CSS_PAGE_AT_RULE_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![layer] {
+                    if element.kind() == T!['{'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssLayer::can_cast(element.kind()) {
+                    if CssPageAtRuleItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['}'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1951,25 +3435,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_LAYER_AT_RULE.to_bogus(),
+                        CSS_PAGE_AT_RULE_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_LAYER_AT_RULE, children)
+                slots.into_node(CSS_PAGE_AT_RULE_BLOCK, children)
             }
-            CSS_LAYER_DECLARATION => {
+            CSS_PAGE_SELECTOR => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssLayerReferenceList::can_cast(element.kind()) {
+                    if CssCustomIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssConditionalBlock::can_cast(element.kind()) {
+                    if CssPageSelectorPseudoList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1977,25 +3461,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_LAYER_DECLARATION.to_bogus(),
+                        CSS_PAGE_SELECTOR.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_LAYER_DECLARATION, children)
+                slots.into_node(CSS_PAGE_SELECTOR, children)
             }
-            CSS_LAYER_REFERENCE => {
+            CSS_PAGE_SELECTOR_PSEUDO => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssLayerReferenceList::can_cast(element.kind()) {
+                    if element.kind() == T ! [:] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [;] {
+                    if matches!(element.kind(), T![left] | T![right] | T![first] | T![blank]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2003,18 +3487,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_LAYER_REFERENCE.to_bogus(),
+                        CSS_PAGE_SELECTOR_PSEUDO.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_LAYER_REFERENCE, children)
+                slots.into_node(CSS_PAGE_SELECTOR_PSEUDO, children)
             }
-            CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION => {
+            CSS_PARAMETER => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssComponentValueList::can_cast(element.kind()) {
+                    if AnyCssExpression::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2022,50 +3506,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION.to_bogus(),
+                        CSS_PARAMETER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION, children)
+                slots.into_node(CSS_PARAMETER, children)
             }
-            CSS_MARGIN_AT_RULE => {
+            CSS_PARENTHESIZED_EXPRESSION => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [@] {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if matches!(
-                        element.kind(),
-                        T![top_left_corner]
-                            | T![top_left]
-                            | T![top_center]
-                            | T![top_right]
-                            | T![top_right_corner]
-                            | T![bottom_left_corner]
-                            | T![bottom_left]
-                            | T![bottom_center]
-                            | T![bottom_right]
-                            | T![bottom_right_corner]
-                            | T![left_top]
-                            | T![left_middle]
-                            | T![left_bottom]
-                            | T![right_top]
-                            | T![right_middle]
-                            | T![right_bottom]
-                    ) {
+                    if AnyCssExpression::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssDeclarationOrAtRuleBlock::can_cast(element.kind()) {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2073,32 +3539,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_MARGIN_AT_RULE.to_bogus(),
+                        CSS_PARENTHESIZED_EXPRESSION.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_MARGIN_AT_RULE, children)
+                slots.into_node(CSS_PARENTHESIZED_EXPRESSION, children)
             }
-            CSS_MEDIA_AND_CONDITION => {
+            CSS_PERCENTAGE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssMediaInParens::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T![and] {
+                    if element.kind() == CSS_NUMBER_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssMediaAndCombinableCondition::can_cast(element.kind()) {
+                    if element.kind() == T ! [%] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2106,32 +3565,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_MEDIA_AND_CONDITION.to_bogus(),
+                        CSS_PERCENTAGE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_MEDIA_AND_CONDITION, children)
+                slots.into_node(CSS_PERCENTAGE, children)
             }
-            CSS_MEDIA_AND_TYPE_QUERY => {
+            CSS_POSITION_TRY_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssMediaTypeQuery::can_cast(element.kind()) {
+                    if element.kind() == T![position_try] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![and] {
+                    if CssDashedIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssMediaTypeCondition::can_cast(element.kind()) {
+                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2139,32 +3598,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_MEDIA_AND_TYPE_QUERY.to_bogus(),
+                        CSS_POSITION_TRY_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_MEDIA_AND_TYPE_QUERY, children)
+                slots.into_node(CSS_POSITION_TRY_AT_RULE, children)
             }
-            CSS_MEDIA_AT_RULE => {
+            CSS_PROPERTY_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![media] {
+                    if element.kind() == T![property] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssMediaQueryList::can_cast(element.kind()) {
+                    if CssDashedIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssConditionalBlock::can_cast(element.kind()) {
+                    if AnyCssPropertyAtRuleBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2172,32 +3631,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_MEDIA_AT_RULE.to_bogus(),
+                        CSS_PROPERTY_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_MEDIA_AT_RULE, children)
+                slots.into_node(CSS_PROPERTY_AT_RULE, children)
             }
-            CSS_MEDIA_CONDITION_IN_PARENS => {
+            CSS_PROPERTY_AT_RULE_BLOCK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if element.kind() == T!['{'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssMediaCondition::can_cast(element.kind()) {
+                    if CssDeclarationList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if element.kind() == T!['}'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2205,35 +3664,23 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_MEDIA_CONDITION_IN_PARENS.to_bogus(),
+                        CSS_PROPERTY_AT_RULE_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_MEDIA_CONDITION_IN_PARENS, children)
+                slots.into_node(CSS_PROPERTY_AT_RULE_BLOCK, children)
             }
-            CSS_MEDIA_CONDITION_QUERY => {
+            CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssMediaCondition::can_cast(element.kind()) {
+                    if matches!(element.kind(), T![host] | T![host_context]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_MEDIA_CONDITION_QUERY.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_MEDIA_CONDITION_QUERY, children)
-            }
-            CSS_MEDIA_FEATURE_IN_PARENS => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if element.kind() == T!['('] {
                         slots.mark_present();
@@ -2242,7 +3689,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssQueryFeature::can_cast(element.kind()) {
+                    if AnyCssCompoundSelector::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2257,58 +3704,42 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_MEDIA_FEATURE_IN_PARENS.to_bogus(),
+                        CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_MEDIA_FEATURE_IN_PARENS, children)
+                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR, children)
             }
-            CSS_MEDIA_NOT_CONDITION => {
+            CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![not] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if AnyCssMediaInParens::can_cast(element.kind()) {
+                    if matches!(
+                        element.kind(),
+                        T![any] | T![past] | T![current] | T![future]
+                    ) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_MEDIA_NOT_CONDITION.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_MEDIA_NOT_CONDITION, children)
-            }
-            CSS_MEDIA_OR_CONDITION => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssMediaInParens::can_cast(element.kind()) {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![or] {
+                    if CssCompoundSelectorList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssMediaOrCombinableCondition::can_cast(element.kind()) {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2316,63 +3747,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_MEDIA_OR_CONDITION.to_bogus(),
+                        CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_MEDIA_OR_CONDITION, children)
+                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST, children)
             }
-            CSS_MEDIA_TYPE => {
+            CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if element.kind() == T![dir] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_MEDIA_TYPE.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_MEDIA_TYPE, children)
-            }
-            CSS_MEDIA_TYPE_QUERY => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T![only] | T![not]) {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssMediaType::can_cast(element.kind()) {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
-                slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_MEDIA_TYPE_QUERY.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_MEDIA_TYPE_QUERY, children)
-            }
-            CSS_METAVARIABLE => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
+                slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == GRIT_METAVARIABLE {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2380,44 +3787,47 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_METAVARIABLE.to_bogus(),
+                        CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_METAVARIABLE, children)
+                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER, children)
             }
-            CSS_NAMED_NAMESPACE_PREFIX => {
+            CSS_PSEUDO_CLASS_FUNCTION_NTH => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if matches!(
+                        element.kind(),
+                        T![nth_child]
+                            | T![nth_last_child]
+                            | T![nth_of_type]
+                            | T![nth_last_of_type]
+                            | T![nth_col]
+                            | T![nth_last_col]
+                    ) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_NAMED_NAMESPACE_PREFIX.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
                 }
-                slots.into_node(CSS_NAMED_NAMESPACE_PREFIX, children)
-            }
-            CSS_NAMESPACE => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
+                slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssNamespacePrefix::can_cast(element.kind()) {
+                    if AnyCssPseudoClassNthSelector::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [|] {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2425,39 +3835,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_NAMESPACE.to_bogus(),
+                        CSS_PSEUDO_CLASS_FUNCTION_NTH.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_NAMESPACE, children)
+                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_NTH, children)
             }
-            CSS_NAMESPACE_AT_RULE => {
+            CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![namespace] {
+                    if element.kind() == T![has] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssNamespaceUrl::can_cast(element.kind()) {
+                    if CssRelativeSelectorList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [;] {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2465,44 +3875,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_NAMESPACE_AT_RULE.to_bogus(),
+                        CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_NAMESPACE_AT_RULE, children)
+                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST, children)
             }
-            CSS_NESTED_QUALIFIED_RULE => {
+            CSS_PSEUDO_CLASS_FUNCTION_SELECTOR => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssRelativeSelectorList::can_cast(element.kind()) {
+                    if matches!(element.kind(), T![global] | T![local]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssDeclarationOrRuleBlock::can_cast(element.kind()) {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_NESTED_QUALIFIED_RULE.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
+                if let Some(element) = &current_element {
+                    if AnyCssSelector::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
                 }
-                slots.into_node(CSS_NESTED_QUALIFIED_RULE, children)
-            }
-            CSS_NESTED_SELECTOR => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
+                slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [&] {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2510,44 +3915,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_NESTED_SELECTOR.to_bogus(),
+                        CSS_PSEUDO_CLASS_FUNCTION_SELECTOR.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_NESTED_SELECTOR, children)
+                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_SELECTOR, children)
             }
-            CSS_NTH_OFFSET => {
+            CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [+] | T ! [-]) {
+                    if matches!(element.kind(), T![matches] | T![not] | T![is] | T![where]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssNumber::can_cast(element.kind()) {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_NTH_OFFSET.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
+                if let Some(element) = &current_element {
+                    if CssSelectorList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
                 }
-                slots.into_node(CSS_NTH_OFFSET, children)
-            }
-            CSS_NUMBER => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
+                slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == CSS_NUMBER_LITERAL {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2555,32 +3955,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_NUMBER.to_bogus(),
+                        CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_NUMBER, children)
+                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST, children)
             }
-            CSS_PAGE_AT_RULE => {
+            CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![page] {
+                    if element.kind() == T![lang] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssPageSelectorList::can_cast(element.kind()) {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssPageAtRuleBlock::can_cast(element.kind()) {
+                    if CssPseudoValueList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2588,32 +3995,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PAGE_AT_RULE.to_bogus(),
+                        CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PAGE_AT_RULE, children)
+                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST, children)
             }
-            CSS_PAGE_AT_RULE_BLOCK => {
+            CSS_PSEUDO_CLASS_IDENTIFIER => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['{'] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if CssPageAtRuleItemList::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T!['}'] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2621,51 +4014,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PAGE_AT_RULE_BLOCK.to_bogus(),
+                        CSS_PSEUDO_CLASS_IDENTIFIER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PAGE_AT_RULE_BLOCK, children)
+                slots.into_node(CSS_PSEUDO_CLASS_IDENTIFIER, children)
             }
-            CSS_PAGE_SELECTOR => {
+            CSS_PSEUDO_CLASS_NTH => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssCustomIdentifier::can_cast(element.kind()) {
+                    if matches!(element.kind(), T ! [+] | T ! [-]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssPageSelectorPseudoList::can_cast(element.kind()) {
+                    if CssNumber::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_PAGE_SELECTOR.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_PAGE_SELECTOR, children)
-            }
-            CSS_PAGE_SELECTOR_PSEUDO => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [:] {
+                    if element.kind() == T![n] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T![left] | T![right] | T![first] | T![blank]) {
+                    if CssNthOffset::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2673,18 +4054,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PAGE_SELECTOR_PSEUDO.to_bogus(),
+                        CSS_PSEUDO_CLASS_NTH.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PAGE_SELECTOR_PSEUDO, children)
+                slots.into_node(CSS_PSEUDO_CLASS_NTH, children)
             }
-            CSS_PARAMETER => {
+            CSS_PSEUDO_CLASS_NTH_IDENTIFIER => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssExpression::can_cast(element.kind()) {
+                    if matches!(element.kind(), T![even] | T![odd]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2692,32 +4073,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PARAMETER.to_bogus(),
+                        CSS_PSEUDO_CLASS_NTH_IDENTIFIER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PARAMETER, children)
+                slots.into_node(CSS_PSEUDO_CLASS_NTH_IDENTIFIER, children)
             }
-            CSS_PARENTHESIZED_EXPRESSION => {
+            CSS_PSEUDO_CLASS_NTH_NUMBER => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if AnyCssExpression::can_cast(element.kind()) {
+                    if matches!(element.kind(), T ! [+] | T ! [-]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if CssNumber::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2725,25 +4099,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PARENTHESIZED_EXPRESSION.to_bogus(),
+                        CSS_PSEUDO_CLASS_NTH_NUMBER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PARENTHESIZED_EXPRESSION, children)
+                slots.into_node(CSS_PSEUDO_CLASS_NTH_NUMBER, children)
             }
-            CSS_PERCENTAGE => {
+            CSS_PSEUDO_CLASS_NTH_SELECTOR => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == CSS_NUMBER_LITERAL {
+                    if AnyCssPseudoClassNth::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [%] {
+                    if CssPseudoClassOfNthSelector::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2751,32 +4125,51 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PERCENTAGE.to_bogus(),
+                        CSS_PSEUDO_CLASS_NTH_SELECTOR.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PERCENTAGE, children)
+                slots.into_node(CSS_PSEUDO_CLASS_NTH_SELECTOR, children)
             }
-            CSS_PROPERTY_AT_RULE => {
+            CSS_PSEUDO_CLASS_OF_NTH_SELECTOR => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![property] {
+                    if element.kind() == T![of] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssSelectorList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_PSEUDO_CLASS_OF_NTH_SELECTOR.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_PSEUDO_CLASS_OF_NTH_SELECTOR, children)
+            }
+            CSS_PSEUDO_CLASS_SELECTOR => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssDashedIdentifier::can_cast(element.kind()) {
+                    if element.kind() == T ! [:] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
+                    if AnyCssPseudoClass::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2784,18 +4177,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PROPERTY_AT_RULE.to_bogus(),
+                        CSS_PSEUDO_CLASS_SELECTOR.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PROPERTY_AT_RULE, children)
+                slots.into_node(CSS_PSEUDO_CLASS_SELECTOR, children)
             }
-            CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR => {
+            CSS_PSEUDO_ELEMENT_FUNCTION_COMPOUND_SELECTOR => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T![host] | T![host_context]) {
+                    if element.kind() == T![slotted] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2824,21 +4217,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR.to_bogus(),
+                        CSS_PSEUDO_ELEMENT_FUNCTION_COMPOUND_SELECTOR.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR, children)
+                slots.into_node(CSS_PSEUDO_ELEMENT_FUNCTION_COMPOUND_SELECTOR, children)
             }
-            CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST => {
+            CSS_PSEUDO_ELEMENT_FUNCTION_CUSTOM_IDENTIFIER_LIST => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(
-                        element.kind(),
-                        T![any] | T![past] | T![current] | T![future]
-                    ) {
+                    if element.kind() == T![part] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2852,7 +4242,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssCompoundSelectorList::can_cast(element.kind()) {
+                    if CssCustomIdentifierList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2867,18 +4257,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST.to_bogus(),
+                        CSS_PSEUDO_ELEMENT_FUNCTION_CUSTOM_IDENTIFIER_LIST.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST, children)
+                slots.into_node(CSS_PSEUDO_ELEMENT_FUNCTION_CUSTOM_IDENTIFIER_LIST, children)
             }
-            CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER => {
+            CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![dir] {
+                    if element.kind() == T![highlight] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2907,26 +4297,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER.to_bogus(),
+                        CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER, children)
+                slots.into_node(CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER, children)
             }
-            CSS_PSEUDO_CLASS_FUNCTION_NTH => {
+            CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(
-                        element.kind(),
-                        T![nth_child]
-                            | T![nth_last_child]
-                            | T![nth_of_type]
-                            | T![nth_last_of_type]
-                            | T![nth_col]
-                            | T![nth_last_col]
-                    ) {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2940,7 +4322,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssPseudoClassNthSelector::can_cast(element.kind()) {
+                    if AnyCssSelector::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2955,39 +4337,44 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_FUNCTION_NTH.to_bogus(),
+                        CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_NTH, children)
+                slots.into_node(CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR, children)
             }
-            CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST => {
+            CSS_PSEUDO_ELEMENT_IDENTIFIER => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![has] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_PSEUDO_ELEMENT_IDENTIFIER.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
                 }
-                slots.next_slot();
+                slots.into_node(CSS_PSEUDO_ELEMENT_IDENTIFIER, children)
+            }
+            CSS_PSEUDO_ELEMENT_SELECTOR => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssRelativeSelectorList::can_cast(element.kind()) {
+                    if element.kind() == T ! [::] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if AnyCssPseudoElement::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -2995,39 +4382,44 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST.to_bogus(),
+                        CSS_PSEUDO_ELEMENT_SELECTOR.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST, children)
+                slots.into_node(CSS_PSEUDO_ELEMENT_SELECTOR, children)
             }
-            CSS_PSEUDO_CLASS_FUNCTION_SELECTOR => {
+            CSS_QUALIFIED_RULE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T![global] | T![local]) {
+                    if CssSelectorList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if AnyCssDeclarationOrRuleBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if AnyCssSelector::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_QUALIFIED_RULE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
                 }
-                slots.next_slot();
+                slots.into_node(CSS_QUALIFIED_RULE, children)
+            }
+            CSS_QUERY_FEATURE_BOOLEAN => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3035,39 +4427,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_FUNCTION_SELECTOR.to_bogus(),
+                        CSS_QUERY_FEATURE_BOOLEAN.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_SELECTOR, children)
+                slots.into_node(CSS_QUERY_FEATURE_BOOLEAN, children)
             }
-            CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST => {
+            CSS_QUERY_FEATURE_PLAIN => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T![matches] | T![not] | T![is] | T![where]) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssSelectorList::can_cast(element.kind()) {
+                    if element.kind() == T ! [:] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if AnyCssQueryFeatureValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3075,39 +4460,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST.to_bogus(),
+                        CSS_QUERY_FEATURE_PLAIN.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST, children)
+                slots.into_node(CSS_QUERY_FEATURE_PLAIN, children)
             }
-            CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST => {
+            CSS_QUERY_FEATURE_RANGE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![lang] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssPseudoValueList::can_cast(element.kind()) {
+                    if CssQueryFeatureRangeComparison::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if AnyCssQueryFeatureValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3115,18 +4493,21 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST.to_bogus(),
+                        CSS_QUERY_FEATURE_RANGE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST, children)
+                slots.into_node(CSS_QUERY_FEATURE_RANGE, children)
             }
-            CSS_PSEUDO_CLASS_IDENTIFIER => {
+            CSS_QUERY_FEATURE_RANGE_COMPARISON => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if matches!(
+                        element.kind(),
+                        T ! [>] | T ! [<] | T ! [=] | T ! [>=] | T ! [<=]
+                    ) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3134,58 +4515,46 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_IDENTIFIER.to_bogus(),
+                        CSS_QUERY_FEATURE_RANGE_COMPARISON.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_IDENTIFIER, children)
+                slots.into_node(CSS_QUERY_FEATURE_RANGE_COMPARISON, children)
             }
-            CSS_PSEUDO_CLASS_NTH => {
+            CSS_QUERY_FEATURE_RANGE_INTERVAL => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<5usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [+] | T ! [-]) {
+                    if AnyCssQueryFeatureValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssNumber::can_cast(element.kind()) {
+                    if CssQueryFeatureRangeComparison::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![n] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssNthOffset::can_cast(element.kind()) {
+                    if CssQueryFeatureRangeComparison::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_NTH.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_PSEUDO_CLASS_NTH, children)
-            }
-            CSS_PSEUDO_CLASS_NTH_IDENTIFIER => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T![even] | T![odd]) {
+                    if AnyCssQueryFeatureValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3193,51 +4562,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_NTH_IDENTIFIER.to_bogus(),
+                        CSS_QUERY_FEATURE_RANGE_INTERVAL.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_NTH_IDENTIFIER, children)
+                slots.into_node(CSS_QUERY_FEATURE_RANGE_INTERVAL, children)
             }
-            CSS_PSEUDO_CLASS_NTH_NUMBER => {
+            CSS_QUERY_FEATURE_REVERSE_RANGE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [+] | T ! [-]) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if CssNumber::can_cast(element.kind()) {
+                    if AnyCssQueryFeatureValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_NTH_NUMBER.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_PSEUDO_CLASS_NTH_NUMBER, children)
-            }
-            CSS_PSEUDO_CLASS_NTH_SELECTOR => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssPseudoClassNth::can_cast(element.kind()) {
+                    if CssQueryFeatureRangeComparison::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssPseudoClassOfNthSelector::can_cast(element.kind()) {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3245,25 +4595,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_NTH_SELECTOR.to_bogus(),
+                        CSS_QUERY_FEATURE_REVERSE_RANGE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_NTH_SELECTOR, children)
+                slots.into_node(CSS_QUERY_FEATURE_REVERSE_RANGE, children)
             }
-            CSS_PSEUDO_CLASS_OF_NTH_SELECTOR => {
+            CSS_RATIO => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![of] {
+                    if CssNumber::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssSelectorList::can_cast(element.kind()) {
+                    if element.kind() == T ! [/] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssNumber::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3271,25 +4628,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_OF_NTH_SELECTOR.to_bogus(),
+                        CSS_RATIO.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_OF_NTH_SELECTOR, children)
+                slots.into_node(CSS_RATIO, children)
             }
-            CSS_PSEUDO_CLASS_SELECTOR => {
+            CSS_REGULAR_DIMENSION => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [:] {
+                    if element.kind() == CSS_NUMBER_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssPseudoClass::can_cast(element.kind()) {
+                    if element.kind() == IDENT {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3297,30 +4654,42 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_SELECTOR.to_bogus(),
+                        CSS_REGULAR_DIMENSION.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_SELECTOR, children)
+                slots.into_node(CSS_REGULAR_DIMENSION, children)
             }
-            CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER => {
+            CSS_RELATIVE_COLOR_ALPHA => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T![highlight] | T![part]) {
+                    if element.kind() == T ! [/] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if AnyCssValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_RELATIVE_COLOR_ALPHA.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_RELATIVE_COLOR_ALPHA, children)
+            }
+            CSS_RELATIVE_COLOR_FUNCTION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<7usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
@@ -3329,66 +4698,42 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER, children)
-            }
-            CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if element.kind() == T![from] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if AnyCssValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssSelector::can_cast(element.kind()) {
+                    if CssComponentValueList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if CssRelativeColorAlpha::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR, children)
-            }
-            CSS_PSEUDO_ELEMENT_IDENTIFIER => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3396,25 +4741,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_ELEMENT_IDENTIFIER.to_bogus(),
+                        CSS_RELATIVE_COLOR_FUNCTION.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_ELEMENT_IDENTIFIER, children)
+                slots.into_node(CSS_RELATIVE_COLOR_FUNCTION, children)
             }
-            CSS_PSEUDO_ELEMENT_SELECTOR => {
+            CSS_RELATIVE_SELECTOR => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [::] {
+                    if matches!(element.kind(), T ! [>] | T ! [+] | T ! [~] | T ! [||]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssPseudoElement::can_cast(element.kind()) {
+                    if AnyCssSelector::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3422,77 +4767,62 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_ELEMENT_SELECTOR.to_bogus(),
+                        CSS_RELATIVE_SELECTOR.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_ELEMENT_SELECTOR, children)
+                slots.into_node(CSS_RELATIVE_SELECTOR, children)
             }
-            CSS_QUALIFIED_RULE => {
+            CSS_ROOT => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssSelectorList::can_cast(element.kind()) {
+                    if element.kind() == T![UNICODE_BOM] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssDeclarationOrRuleBlock::can_cast(element.kind()) {
+                    if CssRuleList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_QUALIFIED_RULE.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_QUALIFIED_RULE, children)
-            }
-            CSS_QUERY_FEATURE_BOOLEAN => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if element.kind() == T![EOF] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_QUERY_FEATURE_BOOLEAN.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
+                    return RawSyntaxNode::new(CSS_ROOT.to_bogus(), children.into_iter().map(Some));
                 }
-                slots.into_node(CSS_QUERY_FEATURE_BOOLEAN, children)
+                slots.into_node(CSS_ROOT, children)
             }
-            CSS_QUERY_FEATURE_PLAIN => {
+            CSS_RULE_BLOCK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if element.kind() == T!['{'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [:] {
+                    if CssRuleList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssQueryFeatureValue::can_cast(element.kind()) {
+                    if element.kind() == T!['}'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3500,32 +4830,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_QUERY_FEATURE_PLAIN.to_bogus(),
+                        CSS_RULE_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_QUERY_FEATURE_PLAIN, children)
+                slots.into_node(CSS_RULE_BLOCK, children)
             }
-            CSS_QUERY_FEATURE_RANGE => {
+            CSS_SCOPE_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if element.kind() == T![scope] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssQueryFeatureRangeComparison::can_cast(element.kind()) {
+                    if AnyCssScopeRange::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssQueryFeatureValue::can_cast(element.kind()) {
+                    if AnyCssConditionalBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3533,68 +4863,58 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_QUERY_FEATURE_RANGE.to_bogus(),
+                        CSS_SCOPE_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_QUERY_FEATURE_RANGE, children)
+                slots.into_node(CSS_SCOPE_AT_RULE, children)
             }
-            CSS_QUERY_FEATURE_RANGE_COMPARISON => {
+            CSS_SCOPE_EDGE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(
-                        element.kind(),
-                        T ! [>] | T ! [<] | T ! [=] | T ! [>=] | T ! [<=]
-                    ) {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_QUERY_FEATURE_RANGE_COMPARISON.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_QUERY_FEATURE_RANGE_COMPARISON, children)
-            }
-            CSS_QUERY_FEATURE_RANGE_INTERVAL => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<5usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssQueryFeatureValue::can_cast(element.kind()) {
+                    if CssSelectorList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssQueryFeatureRangeComparison::can_cast(element.kind()) {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_SCOPE_EDGE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
                 }
-                slots.next_slot();
+                slots.into_node(CSS_SCOPE_EDGE, children)
+            }
+            CSS_SCOPE_RANGE_END => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssQueryFeatureRangeComparison::can_cast(element.kind()) {
+                    if element.kind() == T![to] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssQueryFeatureValue::can_cast(element.kind()) {
+                    if CssScopeEdge::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3602,32 +4922,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_QUERY_FEATURE_RANGE_INTERVAL.to_bogus(),
+                        CSS_SCOPE_RANGE_END.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_QUERY_FEATURE_RANGE_INTERVAL, children)
+                slots.into_node(CSS_SCOPE_RANGE_END, children)
             }
-            CSS_QUERY_FEATURE_REVERSE_RANGE => {
+            CSS_SCOPE_RANGE_INTERVAL => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssQueryFeatureValue::can_cast(element.kind()) {
+                    if CssScopeEdge::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssQueryFeatureRangeComparison::can_cast(element.kind()) {
+                    if element.kind() == T![to] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if CssScopeEdge::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3635,32 +4955,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_QUERY_FEATURE_REVERSE_RANGE.to_bogus(),
+                        CSS_SCOPE_RANGE_INTERVAL.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_QUERY_FEATURE_REVERSE_RANGE, children)
+                slots.into_node(CSS_SCOPE_RANGE_INTERVAL, children)
             }
-            CSS_RATIO => {
+            CSS_SCOPE_RANGE_START => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssNumber::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T ! [/] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if CssNumber::can_cast(element.kind()) {
+                    if CssScopeEdge::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3668,25 +4974,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_RATIO.to_bogus(),
+                        CSS_SCOPE_RANGE_START.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_RATIO, children)
+                slots.into_node(CSS_SCOPE_RANGE_START, children)
             }
-            CSS_REGULAR_DIMENSION => {
+            CSS_STARTING_STYLE_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == CSS_NUMBER_LITERAL {
+                    if element.kind() == T![starting_style] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == IDENT {
+                    if AnyCssStartingStyleBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3694,25 +5000,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_REGULAR_DIMENSION.to_bogus(),
+                        CSS_STARTING_STYLE_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_REGULAR_DIMENSION, children)
+                slots.into_node(CSS_STARTING_STYLE_AT_RULE, children)
             }
-            CSS_RELATIVE_SELECTOR => {
+            CSS_STRING => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [>] | T ! [+] | T ! [~] | T ! [||]) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if AnyCssSelector::can_cast(element.kind()) {
+                    if element.kind() == CSS_STRING_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3720,62 +5019,65 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_RELATIVE_SELECTOR.to_bogus(),
+                        CSS_STRING.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_RELATIVE_SELECTOR, children)
+                slots.into_node(CSS_STRING, children)
             }
-            CSS_ROOT => {
+            CSS_SUPPORTS_AND_CONDITION => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![UNICODE_BOM] {
+                    if AnyCssSupportsInParens::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssRuleList::can_cast(element.kind()) {
+                    if element.kind() == T![and] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![EOF] {
+                    if AnyCssSupportsAndCombinableCondition::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if current_element.is_some() {
-                    return RawSyntaxNode::new(CSS_ROOT.to_bogus(), children.into_iter().map(Some));
+                    return RawSyntaxNode::new(
+                        CSS_SUPPORTS_AND_CONDITION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
                 }
-                slots.into_node(CSS_ROOT, children)
+                slots.into_node(CSS_SUPPORTS_AND_CONDITION, children)
             }
-            CSS_RULE_BLOCK => {
+            CSS_SUPPORTS_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['{'] {
+                    if element.kind() == T![supports] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssRuleList::can_cast(element.kind()) {
+                    if AnyCssSupportsCondition::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['}'] {
+                    if AnyCssConditionalBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3783,32 +5085,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_RULE_BLOCK.to_bogus(),
+                        CSS_SUPPORTS_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_RULE_BLOCK, children)
+                slots.into_node(CSS_SUPPORTS_AT_RULE, children)
             }
-            CSS_SCOPE_AT_RULE => {
+            CSS_SUPPORTS_CONDITION_IN_PARENS => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![scope] {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssScopeRange::can_cast(element.kind()) {
+                    if AnyCssSupportsCondition::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssConditionalBlock::can_cast(element.kind()) {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3816,13 +5118,13 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_SCOPE_AT_RULE.to_bogus(),
+                        CSS_SUPPORTS_CONDITION_IN_PARENS.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_SCOPE_AT_RULE, children)
+                slots.into_node(CSS_SUPPORTS_CONDITION_IN_PARENS, children)
             }
-            CSS_SCOPE_EDGE => {
+            CSS_SUPPORTS_FEATURE_DECLARATION => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
@@ -3834,7 +5136,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssSelectorList::can_cast(element.kind()) {
+                    if CssDeclaration::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3849,25 +5151,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_SCOPE_EDGE.to_bogus(),
+                        CSS_SUPPORTS_FEATURE_DECLARATION.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_SCOPE_EDGE, children)
+                slots.into_node(CSS_SUPPORTS_FEATURE_DECLARATION, children)
             }
-            CSS_SCOPE_RANGE_END => {
+            CSS_SUPPORTS_FEATURE_FONT_FORMAT => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![to] {
+                    if element.kind() == T![font_format] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssScopeEdge::can_cast(element.kind()) {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3875,32 +5191,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_SCOPE_RANGE_END.to_bogus(),
+                        CSS_SUPPORTS_FEATURE_FONT_FORMAT.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_SCOPE_RANGE_END, children)
+                slots.into_node(CSS_SUPPORTS_FEATURE_FONT_FORMAT, children)
             }
-            CSS_SCOPE_RANGE_INTERVAL => {
+            CSS_SUPPORTS_FEATURE_FONT_TECH => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssScopeEdge::can_cast(element.kind()) {
+                    if element.kind() == T![font_tech] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![to] {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssScopeEdge::can_cast(element.kind()) {
+                    if CssIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3908,44 +5231,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_SCOPE_RANGE_INTERVAL.to_bogus(),
+                        CSS_SUPPORTS_FEATURE_FONT_TECH.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_SCOPE_RANGE_INTERVAL, children)
+                slots.into_node(CSS_SUPPORTS_FEATURE_FONT_TECH, children)
             }
-            CSS_SCOPE_RANGE_START => {
+            CSS_SUPPORTS_FEATURE_SELECTOR => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssScopeEdge::can_cast(element.kind()) {
+                    if element.kind() == T![selector] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_SCOPE_RANGE_START.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
                 }
-                slots.into_node(CSS_SCOPE_RANGE_START, children)
-            }
-            CSS_STARTING_STYLE_AT_RULE => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
+                slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![starting_style] {
+                    if AnyCssSelector::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssStartingStyleBlock::can_cast(element.kind()) {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3953,18 +5271,25 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_STARTING_STYLE_AT_RULE.to_bogus(),
+                        CSS_SUPPORTS_FEATURE_SELECTOR.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_STARTING_STYLE_AT_RULE, children)
+                slots.into_node(CSS_SUPPORTS_FEATURE_SELECTOR, children)
             }
-            CSS_STRING => {
+            CSS_SUPPORTS_NOT_CONDITION => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == CSS_STRING_LITERAL {
+                    if element.kind() == T![not] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssSupportsInParens::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -3972,13 +5297,13 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_STRING.to_bogus(),
+                        CSS_SUPPORTS_NOT_CONDITION.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_STRING, children)
+                slots.into_node(CSS_SUPPORTS_NOT_CONDITION, children)
             }
-            CSS_SUPPORTS_AND_CONDITION => {
+            CSS_SUPPORTS_OR_CONDITION => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
@@ -3990,14 +5315,14 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![and] {
+                    if element.kind() == T![or] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssSupportsAndCombinableCondition::can_cast(element.kind()) {
+                    if AnyCssSupportsOrCombinableCondition::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -4005,32 +5330,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_SUPPORTS_AND_CONDITION.to_bogus(),
+                        CSS_SUPPORTS_OR_CONDITION.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_SUPPORTS_AND_CONDITION, children)
+                slots.into_node(CSS_SUPPORTS_OR_CONDITION, children)
             }
-            CSS_SUPPORTS_AT_RULE => {
+            CSS_TAILWIND_APPLY_AT_RULE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![supports] {
+                    if element.kind() == T![apply] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssSupportsCondition::can_cast(element.kind()) {
+                    if CssTailwindUtilityList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssConditionalBlock::can_cast(element.kind()) {
+                    if CssDeclarationImportant::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [;] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -4038,32 +5370,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_SUPPORTS_AT_RULE.to_bogus(),
+                        CSS_TAILWIND_APPLY_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_SUPPORTS_AT_RULE, children)
+                slots.into_node(CSS_TAILWIND_APPLY_AT_RULE, children)
             }
-            CSS_SUPPORTS_CONDITION_IN_PARENS => {
+            CSS_TAILWIND_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if element.kind() == T![tailwind] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssSupportsCondition::can_cast(element.kind()) {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if element.kind() == T ! [;] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -4071,32 +5403,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_SUPPORTS_CONDITION_IN_PARENS.to_bogus(),
+                        CSS_TAILWIND_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_SUPPORTS_CONDITION_IN_PARENS, children)
+                slots.into_node(CSS_TAILWIND_AT_RULE, children)
             }
-            CSS_SUPPORTS_FEATURE_DECLARATION => {
+            CSS_TAILWIND_CONFIG_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if element.kind() == T![config] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssDeclaration::can_cast(element.kind()) {
+                    if CssString::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if element.kind() == T ! [;] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -4104,39 +5436,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_SUPPORTS_FEATURE_DECLARATION.to_bogus(),
+                        CSS_TAILWIND_CONFIG_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_SUPPORTS_FEATURE_DECLARATION, children)
+                slots.into_node(CSS_TAILWIND_CONFIG_AT_RULE, children)
             }
-            CSS_SUPPORTS_FEATURE_SELECTOR => {
+            CSS_TAILWIND_THEME_AT_RULE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![selector] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if element.kind() == T![theme] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssSelector::can_cast(element.kind()) {
+                    if CssTailwindThemeModifierList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if AnyCssDeclarationOrAtRuleBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -4144,25 +5469,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_SUPPORTS_FEATURE_SELECTOR.to_bogus(),
+                        CSS_TAILWIND_THEME_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_SUPPORTS_FEATURE_SELECTOR, children)
+                slots.into_node(CSS_TAILWIND_THEME_AT_RULE, children)
             }
-            CSS_SUPPORTS_NOT_CONDITION => {
+            CSS_TAILWIND_UTILITY => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![not] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if AnyCssSupportsInParens::can_cast(element.kind()) {
+                    if element.kind() == CSS_TAILWIND_UTILITY_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -4170,32 +5488,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_SUPPORTS_NOT_CONDITION.to_bogus(),
+                        CSS_TAILWIND_UTILITY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_SUPPORTS_NOT_CONDITION, children)
+                slots.into_node(CSS_TAILWIND_UTILITY, children)
             }
-            CSS_SUPPORTS_OR_CONDITION => {
+            CSS_TAILWIND_VARIANTS_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssSupportsInParens::can_cast(element.kind()) {
+                    if element.kind() == T![variants] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![or] {
+                    if CssTailwindVariantList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssSupportsOrCombinableCondition::can_cast(element.kind()) {
+                    if AnyCssConditionalBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -4203,11 +5521,11 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_SUPPORTS_OR_CONDITION.to_bogus(),
+                        CSS_TAILWIND_VARIANTS_AT_RULE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_SUPPORTS_OR_CONDITION, children)
+                slots.into_node(CSS_TAILWIND_VARIANTS_AT_RULE, children)
             }
             CSS_TYPE_SELECTOR => {
                 let mut elements = (&children).into_iter();
@@ -4377,6 +5695,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.into_node(CSS_UNIVERSAL_SELECTOR, children)
             }
+            CSS_UNKNOWN_AT_RULE_RAW_BLOCK => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['{'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssUnknownAtRuleComponentList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['}'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_UNKNOWN_AT_RULE_RAW_BLOCK.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_UNKNOWN_AT_RULE_RAW_BLOCK, children)
+            }
             CSS_UNKNOWN_BLOCK_AT_RULE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
@@ -4396,7 +5747,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssDeclarationOrRuleBlock::can_cast(element.kind()) {
+                    if AnyCssUnknownAtRuleBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -4705,9 +6056,42 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.into_node(CSS_VALUE_AT_RULE_NAMED_IMPORT_SPECIFIER, children)
             }
+            CSS_VIEW_TRANSITION_AT_RULE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![view_transition] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssDeclarationBlock::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_VIEW_TRANSITION_AT_RULE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_VIEW_TRANSITION_AT_RULE, children)
+            }
             CSS_BRACKETED_VALUE_LIST => {
                 Self::make_node_list_syntax(kind, children, AnyCssCustomIdentifier::can_cast)
             }
+            CSS_COLOR_MIX_COLOR_LIST => Self::make_separated_list_syntax(
+                kind,
+                children,
+                CssColorMixColor::can_cast,
+                T ! [,],
+                false,
+            ),
             CSS_COMPONENT_VALUE_LIST => {
                 Self::make_node_list_syntax(kind, children, AnyCssValue::can_cast)
             }
@@ -4721,6 +6105,23 @@ impl SyntaxFactory for CssSyntaxFactory {
                 T ! [,],
                 false,
             ),
+            CSS_COUNTER_STYLE_ADDITIVE_SYMBOL_LIST => Self::make_separated_list_syntax(
+                kind,
+                children,
+                CssCounterStyleAdditiveSymbol::can_cast,
+                T ! [,],
+                false,
+            ),
+            CSS_COUNTER_STYLE_RANGE_LIST => Self::make_separated_list_syntax(
+                kind,
+                children,
+                CssCounterStyleRange::can_cast,
+                T ! [,],
+                false,
+            ),
+            CSS_COUNTER_STYLE_SYMBOL_LIST => {
+                Self::make_node_list_syntax(kind, children, AnyCssCounterStyleSymbol::can_cast)
+            }
             CSS_CUSTOM_IDENTIFIER_LIST => {
                 Self::make_node_list_syntax(kind, children, AnyCssCustomIdentifier::can_cast)
             }
@@ -4832,6 +6233,19 @@ impl SyntaxFactory for CssSyntaxFactory {
             CSS_SUB_SELECTOR_LIST => {
                 Self::make_node_list_syntax(kind, children, AnyCssSubSelector::can_cast)
             }
+            CSS_TAILWIND_THEME_MODIFIER_LIST => {
+                Self::make_node_list_syntax(kind, children, CssIdentifier::can_cast)
+            }
+            CSS_TAILWIND_UTILITY_LIST => {
+                Self::make_node_list_syntax(kind, children, CssTailwindUtility::can_cast)
+            }
+            CSS_TAILWIND_VARIANT_LIST => Self::make_separated_list_syntax(
+                kind,
+                children,
+                CssIdentifier::can_cast,
+                T ! [,],
+                false,
+            ),
             CSS_URL_MODIFIER_LIST => {
                 Self::make_node_list_syntax(kind, children, AnyCssUrlModifier::can_cast)
             }
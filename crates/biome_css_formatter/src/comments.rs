@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use biome_css_syntax::{
     AnyCssDeclarationName, CssComplexSelector, CssFunction, CssIdentifier, CssLanguage,
-    CssSyntaxKind, TextLen,
+    CssSyntaxKind, TextLen, TextSize,
 };
 use biome_diagnostics::category;
 use biome_formatter::comments::{
@@ -9,7 +9,7 @@ use biome_formatter::comments::{
     DecoratedComment, SourceComment,
 };
 use biome_formatter::formatter::Formatter;
-use biome_formatter::{write, FormatResult, FormatRule};
+use biome_formatter::{format_args, write, Format, FormatResult, FormatRule};
 use biome_rowan::SyntaxTriviaPieceComments;
 use biome_suppression::parse_suppression_comment;
 
@@ -26,6 +26,18 @@ impl FormatRule<SourceComment<CssLanguage>> for FormatCssLeadingComment {
         comment: &SourceComment<CssLanguage>,
         f: &mut Formatter<Self::Context>,
     ) -> FormatResult<()> {
+        let options = f.options();
+        if options.preserve_banner_comments() && is_banner_comment(comment.piece()) {
+            return write!(f, [comment.piece().as_piece()]);
+        }
+
+        // The trailing comments stay on the line of the code they follow
+        let is_own_line =
+            comment.lines_before() > 0 || comment.piece().text_range().start() == TextSize::from(0);
+        if options.reflow_comments() && comment.kind() == CommentKind::InlineBlock && is_own_line {
+            return write!(f, [FormatReflowedComment::new(comment.piece())]);
+        }
+
         if is_doc_comment(comment.piece()) {
             let mut source_offset = comment.piece().text_range().start();
 
@@ -62,6 +74,86 @@ impl FormatRule<SourceComment<CssLanguage>> for FormatCssLeadingComment {
     }
 }
 
+/// Returns `true` if `comment` is a banner comment: the comment at the start of the file,
+/// or a comment that starts with `/*!`, which minifiers keep in the output.
+fn is_banner_comment(comment: &SyntaxTriviaPieceComments<CssLanguage>) -> bool {
+    comment.text().starts_with("/*!") || comment.text_range().start() == TextSize::from(0)
+}
+
+/// Formats a single-line block comment on its own line, and wraps its words over multiple lines
+/// when it doesn't fit in the line width:
+///
+/// ```css
+/// /* A long comment that doesn't fit
+///    in the line width */
+/// ```
+struct FormatReflowedComment<'a> {
+    comment: &'a SyntaxTriviaPieceComments<CssLanguage>,
+}
+
+impl<'a> FormatReflowedComment<'a> {
+    fn new(comment: &'a SyntaxTriviaPieceComments<CssLanguage>) -> Self {
+        Self { comment }
+    }
+}
+
+impl Format<CssFormatContext> for FormatReflowedComment<'_> {
+    fn fmt(&self, f: &mut Formatter<CssFormatContext>) -> FormatResult<()> {
+        let Some(content) = self
+            .comment
+            .text()
+            .strip_prefix("/*")
+            .and_then(|text| text.strip_suffix("*/"))
+        else {
+            return write!(f, [self.comment.as_piece()]);
+        };
+
+        // The words of the comment, with their position in the source
+        let mut words = Vec::new();
+        let mut source_offset = self.comment.text_range().start() + TextSize::from(2);
+        for word in content.split(|char: char| char.is_ascii_whitespace()) {
+            if !word.is_empty() {
+                words.push((word, source_offset));
+            }
+            source_offset += word.text_len() + TextSize::from(1);
+        }
+
+        if words.len() < 2 {
+            return write!(f, [self.comment.as_piece()]);
+        }
+
+        let reflowed = format_with(|f| {
+            // Align the next lines with the first word, after `/* `
+            let words = format_with(|f| {
+                let mut fill = f.fill();
+                let last_index = words.len() - 1;
+                for (index, (word, source_offset)) in words.iter().enumerate() {
+                    let word = dynamic_text(word, *source_offset);
+                    if index == last_index {
+                        fill.entry(
+                            &soft_line_break_or_space(),
+                            &format_args![word, space(), text("*/")],
+                        );
+                    } else {
+                        fill.entry(&soft_line_break_or_space(), &word);
+                    }
+                }
+                fill.finish()
+            });
+            write!(f, [text("/*"), space(), align(3, &words)])
+        });
+
+        // The comment is printed as written when it fits
+        write!(
+            f,
+            [group(&format_args![
+                if_group_fits_on_line(&self.comment.as_piece()),
+                if_group_breaks(&reflowed)
+            ])]
+        )
+    }
+}
+
 #[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
 pub struct CssCommentStyle;
 
@@ -55,16 +55,27 @@ impl CstFormatContext for CssFormatContext {
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CssFormatOptions {
     indent_style: IndentStyle,
     indent_width: IndentWidth,
     line_ending: LineEnding,
     line_width: LineWidth,
     quote_style: QuoteStyle,
+    collapse_single_declaration_rules: bool,
+    max_empty_lines: u8,
+    shorten_hex_colors: bool,
+    preserve_banner_comments: bool,
+    reflow_comments: bool,
     _file_source: CssFileSource,
 }
 
+impl Default for CssFormatOptions {
+    fn default() -> Self {
+        Self::new(CssFileSource::default())
+    }
+}
+
 impl CssFormatOptions {
     pub fn new(file_source: CssFileSource) -> Self {
         Self {
@@ -74,6 +85,11 @@ impl CssFormatOptions {
             line_ending: LineEnding::default(),
             line_width: LineWidth::default(),
             quote_style: QuoteStyle::default(),
+            collapse_single_declaration_rules: false,
+            max_empty_lines: 1,
+            shorten_hex_colors: false,
+            preserve_banner_comments: false,
+            reflow_comments: false,
         }
     }
 
@@ -102,6 +118,34 @@ impl CssFormatOptions {
         self
     }
 
+    pub fn with_collapse_single_declaration_rules(
+        mut self,
+        collapse_single_declaration_rules: bool,
+    ) -> Self {
+        self.collapse_single_declaration_rules = collapse_single_declaration_rules;
+        self
+    }
+
+    pub fn with_max_empty_lines(mut self, max_empty_lines: u8) -> Self {
+        self.max_empty_lines = max_empty_lines;
+        self
+    }
+
+    pub fn with_shorten_hex_colors(mut self, shorten_hex_colors: bool) -> Self {
+        self.shorten_hex_colors = shorten_hex_colors;
+        self
+    }
+
+    pub fn with_preserve_banner_comments(mut self, preserve_banner_comments: bool) -> Self {
+        self.preserve_banner_comments = preserve_banner_comments;
+        self
+    }
+
+    pub fn with_reflow_comments(mut self, reflow_comments: bool) -> Self {
+        self.reflow_comments = reflow_comments;
+        self
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -122,9 +166,52 @@ impl CssFormatOptions {
         self.quote_style = quote_style;
     }
 
+    pub fn set_collapse_single_declaration_rules(
+        &mut self,
+        collapse_single_declaration_rules: bool,
+    ) {
+        self.collapse_single_declaration_rules = collapse_single_declaration_rules;
+    }
+
+    pub fn set_max_empty_lines(&mut self, max_empty_lines: u8) {
+        self.max_empty_lines = max_empty_lines;
+    }
+
+    pub fn set_shorten_hex_colors(&mut self, shorten_hex_colors: bool) {
+        self.shorten_hex_colors = shorten_hex_colors;
+    }
+
+    pub fn set_preserve_banner_comments(&mut self, preserve_banner_comments: bool) {
+        self.preserve_banner_comments = preserve_banner_comments;
+    }
+
+    pub fn set_reflow_comments(&mut self, reflow_comments: bool) {
+        self.reflow_comments = reflow_comments;
+    }
+
     pub fn quote_style(&self) -> QuoteStyle {
         self.quote_style
     }
+
+    pub fn collapse_single_declaration_rules(&self) -> bool {
+        self.collapse_single_declaration_rules
+    }
+
+    pub fn max_empty_lines(&self) -> u8 {
+        self.max_empty_lines
+    }
+
+    pub fn shorten_hex_colors(&self) -> bool {
+        self.shorten_hex_colors
+    }
+
+    pub fn preserve_banner_comments(&self) -> bool {
+        self.preserve_banner_comments
+    }
+
+    pub fn reflow_comments(&self) -> bool {
+        self.reflow_comments
+    }
 }
 
 impl FormatOptions for CssFormatOptions {
@@ -163,6 +250,19 @@ impl fmt::Display for CssFormatOptions {
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.value())?;
-        writeln!(f, "Quote style: {}", self.quote_style)
+        writeln!(f, "Quote style: {}", self.quote_style)?;
+        writeln!(
+            f,
+            "Collapse single declaration rules: {}",
+            self.collapse_single_declaration_rules
+        )?;
+        writeln!(f, "Max empty lines: {}", self.max_empty_lines)?;
+        writeln!(f, "Shorten hex colors: {}", self.shorten_hex_colors)?;
+        writeln!(
+            f,
+            "Preserve banner comments: {}",
+            self.preserve_banner_comments
+        )?;
+        writeln!(f, "Reflow comments: {}", self.reflow_comments)
     }
 }
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyCssAnchorSide;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssAnchorSide;
+impl FormatRule<AnyCssAnchorSide> for FormatAnyCssAnchorSide {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssAnchorSide, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssAnchorSide::CssIdentifier(node) => node.format().fmt(f),
+            AnyCssAnchorSide::CssPercentage(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -13,6 +13,7 @@ impl FormatRule<AnyCssAtRule> for FormatAnyCssAtRule {
             AnyCssAtRule::CssColorProfileAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssContainerAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssCounterStyleAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::CssCustomMediaAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssDocumentAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssFontFaceAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssFontFeatureValuesAtRule(node) => node.format().fmt(f),
@@ -23,13 +24,20 @@ impl FormatRule<AnyCssAtRule> for FormatAnyCssAtRule {
             AnyCssAtRule::CssMediaAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssNamespaceAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssPageAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::CssPositionTryAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssPropertyAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssScopeAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssStartingStyleAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssSupportsAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::CssTailwindApplyAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::CssTailwindAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::CssTailwindConfigAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::CssTailwindThemeAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::CssTailwindVariantsAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssUnknownBlockAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssUnknownValueAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssValueAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::CssViewTransitionAtRule(node) => node.format().fmt(f),
         }
     }
 }
@@ -8,7 +8,11 @@ impl FormatRule<AnyCssContainerQueryInParens> for FormatAnyCssContainerQueryInPa
     type Context = CssFormatContext;
     fn fmt(&self, node: &AnyCssContainerQueryInParens, f: &mut CssFormatter) -> FormatResult<()> {
         match node {
+            AnyCssContainerQueryInParens::CssBogusContainerQuery(node) => node.format().fmt(f),
             AnyCssContainerQueryInParens::CssContainerQueryInParens(node) => node.format().fmt(f),
+            AnyCssContainerQueryInParens::CssContainerScrollStateQueryInParens(node) => {
+                node.format().fmt(f)
+            }
             AnyCssContainerQueryInParens::CssContainerSizeFeatureInParens(node) => {
                 node.format().fmt(f)
             }
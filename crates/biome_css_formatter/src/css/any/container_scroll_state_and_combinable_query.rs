@@ -0,0 +1,25 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyCssContainerScrollStateAndCombinableQuery;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssContainerScrollStateAndCombinableQuery;
+impl FormatRule<AnyCssContainerScrollStateAndCombinableQuery>
+    for FormatAnyCssContainerScrollStateAndCombinableQuery
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &AnyCssContainerScrollStateAndCombinableQuery,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        match node {
+            AnyCssContainerScrollStateAndCombinableQuery::CssContainerScrollStateAndQuery(node) => {
+                node.format().fmt(f)
+            }
+            AnyCssContainerScrollStateAndCombinableQuery::CssContainerScrollStateInParens(node) => {
+                node.format().fmt(f)
+            }
+        }
+    }
+}
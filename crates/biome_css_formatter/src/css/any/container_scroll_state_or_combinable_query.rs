@@ -0,0 +1,25 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyCssContainerScrollStateOrCombinableQuery;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssContainerScrollStateOrCombinableQuery;
+impl FormatRule<AnyCssContainerScrollStateOrCombinableQuery>
+    for FormatAnyCssContainerScrollStateOrCombinableQuery
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &AnyCssContainerScrollStateOrCombinableQuery,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        match node {
+            AnyCssContainerScrollStateOrCombinableQuery::CssContainerScrollStateInParens(node) => {
+                node.format().fmt(f)
+            }
+            AnyCssContainerScrollStateOrCombinableQuery::CssContainerScrollStateOrQuery(node) => {
+                node.format().fmt(f)
+            }
+        }
+    }
+}
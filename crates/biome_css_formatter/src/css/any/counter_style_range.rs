@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyCssCounterStyleRange;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssCounterStyleRange;
+impl FormatRule<AnyCssCounterStyleRange> for FormatAnyCssCounterStyleRange {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssCounterStyleRange, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssCounterStyleRange::CssCounterStyleRangeValue(node) => node.format().fmt(f),
+            AnyCssCounterStyleRange::CssIdentifier(node) => node.format().fmt(f),
+        }
+    }
+}
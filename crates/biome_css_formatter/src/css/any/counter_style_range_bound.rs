@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyCssCounterStyleRangeBound;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssCounterStyleRangeBound;
+impl FormatRule<AnyCssCounterStyleRangeBound> for FormatAnyCssCounterStyleRangeBound {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssCounterStyleRangeBound, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssCounterStyleRangeBound::CssIdentifier(node) => node.format().fmt(f),
+            AnyCssCounterStyleRangeBound::CssNumber(node) => node.format().fmt(f),
+        }
+    }
+}
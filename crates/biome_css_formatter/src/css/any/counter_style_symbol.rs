@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyCssCounterStyleSymbol;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssCounterStyleSymbol;
+impl FormatRule<AnyCssCounterStyleSymbol> for FormatAnyCssCounterStyleSymbol {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssCounterStyleSymbol, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssCounterStyleSymbol::AnyCssFunction(node) => node.format().fmt(f),
+            AnyCssCounterStyleSymbol::CssCustomIdentifier(node) => node.format().fmt(f),
+            AnyCssCounterStyleSymbol::CssString(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyCssCounterStyleSystem;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssCounterStyleSystem;
+impl FormatRule<AnyCssCounterStyleSystem> for FormatAnyCssCounterStyleSystem {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssCounterStyleSystem, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssCounterStyleSystem::CssCounterStyleExtendsSystem(node) => node.format().fmt(f),
+            AnyCssCounterStyleSystem::CssCounterStyleFixedSystem(node) => node.format().fmt(f),
+            AnyCssCounterStyleSystem::CssIdentifier(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -8,7 +8,11 @@ impl FormatRule<AnyCssFunction> for FormatAnyCssFunction {
     type Context = CssFormatContext;
     fn fmt(&self, node: &AnyCssFunction, f: &mut CssFormatter) -> FormatResult<()> {
         match node {
+            AnyCssFunction::CssAnchorFunction(node) => node.format().fmt(f),
+            AnyCssFunction::CssAnchorSizeFunction(node) => node.format().fmt(f),
+            AnyCssFunction::CssColorMixFunction(node) => node.format().fmt(f),
             AnyCssFunction::CssFunction(node) => node.format().fmt(f),
+            AnyCssFunction::CssRelativeColorFunction(node) => node.format().fmt(f),
             AnyCssFunction::CssUrlFunction(node) => node.format().fmt(f),
         }
     }
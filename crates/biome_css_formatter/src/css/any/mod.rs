@@ -1,5 +1,6 @@
 //! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
 
+pub(crate) mod anchor_side;
 pub(crate) mod at_rule;
 pub(crate) mod attribute_matcher_value;
 pub(crate) mod composes_import_source;
@@ -9,10 +10,17 @@ pub(crate) mod container_and_combinable_query;
 pub(crate) mod container_or_combinable_query;
 pub(crate) mod container_query;
 pub(crate) mod container_query_in_parens;
+pub(crate) mod container_scroll_state_and_combinable_query;
+pub(crate) mod container_scroll_state_or_combinable_query;
+pub(crate) mod container_scroll_state_query;
 pub(crate) mod container_style_and_combinable_query;
 pub(crate) mod container_style_in_parens;
 pub(crate) mod container_style_or_combinable_query;
 pub(crate) mod container_style_query;
+pub(crate) mod counter_style_range;
+pub(crate) mod counter_style_range_bound;
+pub(crate) mod counter_style_symbol;
+pub(crate) mod counter_style_system;
 pub(crate) mod custom_identifier;
 pub(crate) mod declaration_block;
 pub(crate) mod declaration_name;
@@ -52,6 +60,7 @@ pub(crate) mod page_at_rule_item;
 pub(crate) mod page_selector;
 pub(crate) mod page_selector_pseudo;
 pub(crate) mod property;
+pub(crate) mod property_at_rule_block;
 pub(crate) mod pseudo_class;
 pub(crate) mod pseudo_class_nth;
 pub(crate) mod pseudo_class_nth_selector;
@@ -72,6 +81,7 @@ pub(crate) mod supports_condition;
 pub(crate) mod supports_in_parens;
 pub(crate) mod supports_or_combinable_condition;
 pub(crate) mod unicode_value;
+pub(crate) mod unknown_at_rule_block;
 pub(crate) mod url_modifier;
 pub(crate) mod url_value;
 pub(crate) mod value;
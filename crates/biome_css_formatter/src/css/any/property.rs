@@ -10,6 +10,10 @@ impl FormatRule<AnyCssProperty> for FormatAnyCssProperty {
         match node {
             AnyCssProperty::CssBogusProperty(node) => node.format().fmt(f),
             AnyCssProperty::CssComposesProperty(node) => node.format().fmt(f),
+            AnyCssProperty::CssCounterStyleAdditiveSymbolsProperty(node) => node.format().fmt(f),
+            AnyCssProperty::CssCounterStyleRangeProperty(node) => node.format().fmt(f),
+            AnyCssProperty::CssCounterStyleSymbolsProperty(node) => node.format().fmt(f),
+            AnyCssProperty::CssCounterStyleSystemProperty(node) => node.format().fmt(f),
             AnyCssProperty::CssGenericProperty(node) => node.format().fmt(f),
         }
     }
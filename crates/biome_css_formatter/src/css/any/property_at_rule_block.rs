@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyCssPropertyAtRuleBlock;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssPropertyAtRuleBlock;
+impl FormatRule<AnyCssPropertyAtRuleBlock> for FormatAnyCssPropertyAtRuleBlock {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssPropertyAtRuleBlock, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssPropertyAtRuleBlock::CssBogusBlock(node) => node.format().fmt(f),
+            AnyCssPropertyAtRuleBlock::CssPropertyAtRuleBlock(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -9,6 +9,12 @@ impl FormatRule<AnyCssPseudoElement> for FormatAnyCssPseudoElement {
     fn fmt(&self, node: &AnyCssPseudoElement, f: &mut CssFormatter) -> FormatResult<()> {
         match node {
             AnyCssPseudoElement::CssBogusPseudoElement(node) => node.format().fmt(f),
+            AnyCssPseudoElement::CssPseudoElementFunctionCompoundSelector(node) => {
+                node.format().fmt(f)
+            }
+            AnyCssPseudoElement::CssPseudoElementFunctionCustomIdentifierList(node) => {
+                node.format().fmt(f)
+            }
             AnyCssPseudoElement::CssPseudoElementFunctionIdentifier(node) => node.format().fmt(f),
             AnyCssPseudoElement::CssPseudoElementFunctionSelector(node) => node.format().fmt(f),
             AnyCssPseudoElement::CssPseudoElementIdentifier(node) => node.format().fmt(f),
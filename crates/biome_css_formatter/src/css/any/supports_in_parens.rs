@@ -12,6 +12,8 @@ impl FormatRule<AnyCssSupportsInParens> for FormatAnyCssSupportsInParens {
             AnyCssSupportsInParens::CssFunction(node) => node.format().fmt(f),
             AnyCssSupportsInParens::CssSupportsConditionInParens(node) => node.format().fmt(f),
             AnyCssSupportsInParens::CssSupportsFeatureDeclaration(node) => node.format().fmt(f),
+            AnyCssSupportsInParens::CssSupportsFeatureFontFormat(node) => node.format().fmt(f),
+            AnyCssSupportsInParens::CssSupportsFeatureFontTech(node) => node.format().fmt(f),
             AnyCssSupportsInParens::CssSupportsFeatureSelector(node) => node.format().fmt(f),
         }
     }
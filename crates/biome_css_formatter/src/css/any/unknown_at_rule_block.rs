@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyCssUnknownAtRuleBlock;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssUnknownAtRuleBlock;
+impl FormatRule<AnyCssUnknownAtRuleBlock> for FormatAnyCssUnknownAtRuleBlock {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssUnknownAtRuleBlock, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssUnknownAtRuleBlock::CssBogusBlock(node) => node.format().fmt(f),
+            AnyCssUnknownAtRuleBlock::CssDeclarationOrRuleBlock(node) => node.format().fmt(f),
+            AnyCssUnknownAtRuleBlock::CssUnknownAtRuleRawBlock(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssAnchorFallback, CssAnchorFallbackFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAnchorFallback;
+impl FormatNodeRule<CssAnchorFallback> for FormatCssAnchorFallback {
+    fn fmt_fields(&self, node: &CssAnchorFallback, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAnchorFallbackFields { comma_token, value } = node.as_fields();
+
+        write!(f, [comma_token.format(), space(), value.format()])
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssAnchorFunction, CssAnchorFunctionFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAnchorFunction;
+impl FormatNodeRule<CssAnchorFunction> for FormatCssAnchorFunction {
+    fn fmt_fields(&self, node: &CssAnchorFunction, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAnchorFunctionFields {
+            name_token,
+            l_paren_token,
+            anchor_name,
+            side,
+            fallback,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(f, [name_token.format(), l_paren_token.format()])?;
+
+        if let Some(anchor_name) = anchor_name {
+            write!(f, [anchor_name.format(), space()])?;
+        }
+
+        write!(
+            f,
+            [side.format(), fallback.format(), r_paren_token.format()]
+        )
+    }
+}
@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssAnchorSizeFunction, CssAnchorSizeFunctionFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAnchorSizeFunction;
+impl FormatNodeRule<CssAnchorSizeFunction> for FormatCssAnchorSizeFunction {
+    fn fmt_fields(&self, node: &CssAnchorSizeFunction, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAnchorSizeFunctionFields {
+            name_token,
+            l_paren_token,
+            anchor_name,
+            size,
+            fallback,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                name_token.format(),
+                l_paren_token.format(),
+                anchor_name.format()
+            ]
+        )?;
+
+        if let Some(size) = size {
+            if anchor_name.is_some() {
+                write!(f, [space()])?;
+            }
+            write!(f, [size.format()])?;
+        }
+
+        write!(f, [fallback.format(), r_paren_token.format()])
+    }
+}
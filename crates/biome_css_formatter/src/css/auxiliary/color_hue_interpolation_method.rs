@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssColorHueInterpolationMethod, CssColorHueInterpolationMethodFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssColorHueInterpolationMethod;
+impl FormatNodeRule<CssColorHueInterpolationMethod> for FormatCssColorHueInterpolationMethod {
+    fn fmt_fields(
+        &self,
+        node: &CssColorHueInterpolationMethod,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssColorHueInterpolationMethodFields { method, hue_token } = node.as_fields();
+
+        write!(f, [method.format(), space(), hue_token.format()])
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssColorInterpolationMethod, CssColorInterpolationMethodFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssColorInterpolationMethod;
+impl FormatNodeRule<CssColorInterpolationMethod> for FormatCssColorInterpolationMethod {
+    fn fmt_fields(
+        &self,
+        node: &CssColorInterpolationMethod,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssColorInterpolationMethodFields {
+            in_token,
+            color_space,
+            hue_interpolation_method,
+        } = node.as_fields();
+
+        write!(f, [in_token.format(), space(), color_space.format()])?;
+
+        if let Some(hue_interpolation_method) = hue_interpolation_method {
+            write!(f, [space(), hue_interpolation_method.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssColorMixColor, CssColorMixColorFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssColorMixColor;
+impl FormatNodeRule<CssColorMixColor> for FormatCssColorMixColor {
+    fn fmt_fields(&self, node: &CssColorMixColor, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssColorMixColorFields { color, percentage } = node.as_fields();
+
+        write!(f, [color.format()])?;
+
+        if let Some(percentage) = percentage {
+            write!(f, [space(), percentage.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssColorMixFunction, CssColorMixFunctionFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssColorMixFunction;
+impl FormatNodeRule<CssColorMixFunction> for FormatCssColorMixFunction {
+    fn fmt_fields(&self, node: &CssColorMixFunction, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssColorMixFunctionFields {
+            name_token,
+            l_paren_token,
+            interpolation_method,
+            comma_token,
+            colors,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                name_token.format(),
+                group(&format_args![
+                    l_paren_token.format(),
+                    soft_block_indent(&format_args![
+                        interpolation_method.format(),
+                        comma_token.format(),
+                        soft_line_break_or_space(),
+                        colors.format()
+                    ]),
+                    r_paren_token.format()
+                ])
+            ]
+        )
+    }
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use crate::utils::query_condition::FormatQueryConditionChain;
+use biome_css_syntax::{CssContainerScrollStateAndQuery, CssContainerScrollStateAndQueryFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssContainerScrollStateAndQuery;
+impl FormatNodeRule<CssContainerScrollStateAndQuery> for FormatCssContainerScrollStateAndQuery {
+    fn fmt_fields(
+        &self,
+        node: &CssContainerScrollStateAndQuery,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssContainerScrollStateAndQueryFields {
+            left,
+            and_token,
+            right,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [FormatQueryConditionChain::new(
+                node.syntax(),
+                left.format(),
+                and_token.format(),
+                right.format()
+            )]
+        )
+    }
+}
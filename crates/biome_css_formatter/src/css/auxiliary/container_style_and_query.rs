@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::query_condition::FormatQueryConditionChain;
 use biome_css_syntax::{CssContainerStyleAndQuery, CssContainerStyleAndQueryFields};
 use biome_formatter::write;
 
@@ -18,13 +19,12 @@ impl FormatNodeRule<CssContainerStyleAndQuery> for FormatCssContainerStyleAndQue
 
         write!(
             f,
-            [
+            [FormatQueryConditionChain::new(
+                node.syntax(),
                 left.format(),
-                space(),
                 and_token.format(),
-                space(),
                 right.format()
-            ]
+            )]
         )
     }
 }
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::query_condition::FormatQueryConditionChain;
 use biome_css_syntax::{CssContainerStyleOrQuery, CssContainerStyleOrQueryFields};
 use biome_formatter::write;
 
@@ -18,13 +19,12 @@ impl FormatNodeRule<CssContainerStyleOrQuery> for FormatCssContainerStyleOrQuery
 
         write!(
             f,
-            [
+            [FormatQueryConditionChain::new(
+                node.syntax(),
                 left.format(),
-                space(),
                 or_token.format(),
-                space(),
                 right.format()
-            ]
+            )]
         )
     }
 }
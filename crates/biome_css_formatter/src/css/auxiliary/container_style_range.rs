@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssContainerStyleRange, CssContainerStyleRangeFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssContainerStyleRange;
+impl FormatNodeRule<CssContainerStyleRange> for FormatCssContainerStyleRange {
+    fn fmt_fields(&self, node: &CssContainerStyleRange, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssContainerStyleRangeFields {
+            left,
+            comparison,
+            right,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                left.format(),
+                space(),
+                comparison.format(),
+                space(),
+                right.format()
+            ]
+        )
+    }
+}
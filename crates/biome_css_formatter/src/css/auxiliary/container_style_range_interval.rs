@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssContainerStyleRangeInterval, CssContainerStyleRangeIntervalFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssContainerStyleRangeInterval;
+impl FormatNodeRule<CssContainerStyleRangeInterval> for FormatCssContainerStyleRangeInterval {
+    fn fmt_fields(
+        &self,
+        node: &CssContainerStyleRangeInterval,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssContainerStyleRangeIntervalFields {
+            left,
+            left_comparison,
+            middle,
+            right_comparison,
+            right,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                left.format(),
+                space(),
+                left_comparison.format(),
+                space(),
+                middle.format(),
+                space(),
+                right_comparison.format(),
+                space(),
+                right.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssCounterStyleAdditiveSymbol, CssCounterStyleAdditiveSymbolFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCounterStyleAdditiveSymbol;
+impl FormatNodeRule<CssCounterStyleAdditiveSymbol> for FormatCssCounterStyleAdditiveSymbol {
+    fn fmt_fields(
+        &self,
+        node: &CssCounterStyleAdditiveSymbol,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssCounterStyleAdditiveSymbolFields { weight, symbol } = node.as_fields();
+
+        write!(f, [weight.format(), space(), symbol.format()])
+    }
+}
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssCounterStyleExtendsSystem, CssCounterStyleExtendsSystemFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCounterStyleExtendsSystem;
+impl FormatNodeRule<CssCounterStyleExtendsSystem> for FormatCssCounterStyleExtendsSystem {
+    fn fmt_fields(
+        &self,
+        node: &CssCounterStyleExtendsSystem,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssCounterStyleExtendsSystemFields {
+            extends_token,
+            name,
+        } = node.as_fields();
+
+        write!(f, [extends_token.format(), space(), name.format()])
+    }
+}
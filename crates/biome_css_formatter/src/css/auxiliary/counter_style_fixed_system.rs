@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssCounterStyleFixedSystem, CssCounterStyleFixedSystemFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCounterStyleFixedSystem;
+impl FormatNodeRule<CssCounterStyleFixedSystem> for FormatCssCounterStyleFixedSystem {
+    fn fmt_fields(
+        &self,
+        node: &CssCounterStyleFixedSystem,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssCounterStyleFixedSystemFields {
+            fixed_token,
+            first_symbol_value,
+        } = node.as_fields();
+
+        write!(f, [fixed_token.format()])?;
+
+        if let Some(first_symbol_value) = first_symbol_value {
+            write!(f, [space(), first_symbol_value.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssCounterStyleRange, CssCounterStyleRangeFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCounterStyleRange;
+impl FormatNodeRule<CssCounterStyleRange> for FormatCssCounterStyleRange {
+    fn fmt_fields(&self, node: &CssCounterStyleRange, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssCounterStyleRangeFields { lower, upper } = node.as_fields();
+
+        write!(f, [lower.format(), space(), upper.format()])
+    }
+}
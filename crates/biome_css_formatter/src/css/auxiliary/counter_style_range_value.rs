@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssCounterStyleRangeValue, CssCounterStyleRangeValueFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCounterStyleRangeValue;
+impl FormatNodeRule<CssCounterStyleRangeValue> for FormatCssCounterStyleRangeValue {
+    fn fmt_fields(
+        &self,
+        node: &CssCounterStyleRangeValue,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssCounterStyleRangeValueFields { ranges } = node.as_fields();
+
+        write!(f, [ranges.format()])
+    }
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::query_condition::FormatQueryConditionChain;
 use biome_css_syntax::{CssMediaAndTypeQuery, CssMediaAndTypeQueryFields};
 use biome_formatter::write;
 
@@ -14,13 +15,12 @@ impl FormatNodeRule<CssMediaAndTypeQuery> for FormatCssMediaAndTypeQuery {
 
         write!(
             f,
-            [
+            [FormatQueryConditionChain::new(
+                node.syntax(),
                 left.format(),
-                space(),
                 and_token.format(),
-                space(),
                 right.format()
-            ]
+            )]
         )
     }
 }
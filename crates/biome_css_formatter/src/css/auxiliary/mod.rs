@@ -1,22 +1,41 @@
 //! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
 
+pub(crate) mod anchor_fallback;
+pub(crate) mod anchor_function;
+pub(crate) mod anchor_size_function;
 pub(crate) mod attribute_matcher;
 pub(crate) mod attribute_matcher_value;
 pub(crate) mod attribute_name;
 pub(crate) mod binary_expression;
 pub(crate) mod bracketed_value;
+pub(crate) mod color_hue_interpolation_method;
+pub(crate) mod color_interpolation_method;
+pub(crate) mod color_mix_color;
+pub(crate) mod color_mix_function;
 pub(crate) mod composes_import_specifier;
 pub(crate) mod composes_property_value;
 pub(crate) mod container_and_query;
 pub(crate) mod container_not_query;
 pub(crate) mod container_or_query;
 pub(crate) mod container_query_in_parens;
+pub(crate) mod container_scroll_state_and_query;
+pub(crate) mod container_scroll_state_in_parens;
+pub(crate) mod container_scroll_state_not_query;
+pub(crate) mod container_scroll_state_or_query;
+pub(crate) mod container_scroll_state_query_in_parens;
 pub(crate) mod container_size_feature_in_parens;
 pub(crate) mod container_style_and_query;
 pub(crate) mod container_style_in_parens;
 pub(crate) mod container_style_not_query;
 pub(crate) mod container_style_or_query;
 pub(crate) mod container_style_query_in_parens;
+pub(crate) mod container_style_range;
+pub(crate) mod container_style_range_interval;
+pub(crate) mod counter_style_additive_symbol;
+pub(crate) mod counter_style_extends_system;
+pub(crate) mod counter_style_fixed_system;
+pub(crate) mod counter_style_range;
+pub(crate) mod counter_style_range_value;
 pub(crate) mod declaration;
 pub(crate) mod declaration_block;
 pub(crate) mod declaration_important;
@@ -58,6 +77,7 @@ pub(crate) mod nth_offset;
 pub(crate) mod page_at_rule_block;
 pub(crate) mod parameter;
 pub(crate) mod parenthesized_expression;
+pub(crate) mod property_at_rule_block;
 pub(crate) mod qualified_rule;
 pub(crate) mod query_feature_boolean;
 pub(crate) mod query_feature_plain;
@@ -65,6 +85,8 @@ pub(crate) mod query_feature_range;
 pub(crate) mod query_feature_range_comparison;
 pub(crate) mod query_feature_range_interval;
 pub(crate) mod query_feature_reverse_range;
+pub(crate) mod relative_color_alpha;
+pub(crate) mod relative_color_function;
 pub(crate) mod root;
 pub(crate) mod rule_block;
 pub(crate) mod scope_edge;
@@ -74,13 +96,17 @@ pub(crate) mod scope_range_start;
 pub(crate) mod supports_and_condition;
 pub(crate) mod supports_condition_in_parens;
 pub(crate) mod supports_feature_declaration;
+pub(crate) mod supports_feature_font_format;
+pub(crate) mod supports_feature_font_tech;
 pub(crate) mod supports_not_condition;
 pub(crate) mod supports_or_condition;
+pub(crate) mod tailwind_utility;
 pub(crate) mod unicode_codepoint;
 pub(crate) mod unicode_range;
 pub(crate) mod unicode_range_interval;
 pub(crate) mod unicode_range_wildcard;
 pub(crate) mod universal_namespace_prefix;
+pub(crate) mod unknown_at_rule_raw_block;
 pub(crate) mod url_function;
 pub(crate) mod value_at_rule_declaration_clause;
 pub(crate) mod value_at_rule_import_clause;
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use crate::utils::block_like::FormatCssBlockLike;
+use biome_css_syntax::stmt_ext::CssBlockLike;
+use biome_css_syntax::CssPropertyAtRuleBlock;
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssPropertyAtRuleBlock;
+impl FormatNodeRule<CssPropertyAtRuleBlock> for FormatCssPropertyAtRuleBlock {
+    fn fmt_fields(&self, node: &CssPropertyAtRuleBlock, f: &mut CssFormatter) -> FormatResult<()> {
+        write!(
+            f,
+            [FormatCssBlockLike::new(&CssBlockLike::from(node.clone()))]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &CssPropertyAtRuleBlock,
+        _: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        // Formatted inside of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssRelativeColorAlpha, CssRelativeColorAlphaFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssRelativeColorAlpha;
+impl FormatNodeRule<CssRelativeColorAlpha> for FormatCssRelativeColorAlpha {
+    fn fmt_fields(&self, node: &CssRelativeColorAlpha, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssRelativeColorAlphaFields { slash_token, value } = node.as_fields();
+
+        write!(f, [space(), slash_token.format(), space(), value.format()])
+    }
+}
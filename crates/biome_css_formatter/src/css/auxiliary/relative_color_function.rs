@@ -0,0 +1,42 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssRelativeColorFunction, CssRelativeColorFunctionFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssRelativeColorFunction;
+impl FormatNodeRule<CssRelativeColorFunction> for FormatCssRelativeColorFunction {
+    fn fmt_fields(
+        &self,
+        node: &CssRelativeColorFunction,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssRelativeColorFunctionFields {
+            name,
+            l_paren_token,
+            from_token,
+            origin,
+            channels,
+            alpha,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                name.format(),
+                group(&format_args![
+                    l_paren_token.format(),
+                    soft_block_indent(&format_args![
+                        from_token.format(),
+                        space(),
+                        origin.format(),
+                        space(),
+                        channels.format(),
+                        alpha.format()
+                    ]),
+                    r_paren_token.format()
+                ])
+            ]
+        )
+    }
+}
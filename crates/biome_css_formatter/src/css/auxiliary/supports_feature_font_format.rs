@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssSupportsFeatureFontFormat, CssSupportsFeatureFontFormatFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssSupportsFeatureFontFormat;
+impl FormatNodeRule<CssSupportsFeatureFontFormat> for FormatCssSupportsFeatureFontFormat {
+    fn fmt_fields(
+        &self,
+        node: &CssSupportsFeatureFontFormat,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssSupportsFeatureFontFormatFields {
+            font_format_token,
+            l_paren_token,
+            value,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                font_format_token.format(),
+                l_paren_token.format(),
+                value.format(),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssSupportsFeatureFontTech, CssSupportsFeatureFontTechFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssSupportsFeatureFontTech;
+impl FormatNodeRule<CssSupportsFeatureFontTech> for FormatCssSupportsFeatureFontTech {
+    fn fmt_fields(
+        &self,
+        node: &CssSupportsFeatureFontTech,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssSupportsFeatureFontTechFields {
+            font_tech_token,
+            l_paren_token,
+            value,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                font_tech_token.format(),
+                l_paren_token.format(),
+                value.format(),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssTailwindUtility, CssTailwindUtilityFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssTailwindUtility;
+impl FormatNodeRule<CssTailwindUtility> for FormatCssTailwindUtility {
+    fn fmt_fields(&self, node: &CssTailwindUtility, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssTailwindUtilityFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
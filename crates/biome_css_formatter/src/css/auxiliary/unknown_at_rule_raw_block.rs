@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use biome_css_syntax::CssUnknownAtRuleRawBlock;
+use biome_rowan::AstNode;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssUnknownAtRuleRawBlock;
+impl FormatNodeRule<CssUnknownAtRuleRawBlock> for FormatCssUnknownAtRuleRawBlock {
+    fn fmt_fields(
+        &self,
+        node: &CssUnknownAtRuleRawBlock,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        // The content of the block isn't known to be CSS, so it's kept as written.
+        format_verbatim_node(node.syntax()).fmt(f)
+    }
+}
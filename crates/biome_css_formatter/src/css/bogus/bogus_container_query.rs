@@ -0,0 +1,5 @@
+use crate::FormatBogusNodeRule;
+use biome_css_syntax::CssBogusContainerQuery;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssBogusContainerQuery;
+impl FormatBogusNodeRule<CssBogusContainerQuery> for FormatCssBogusContainerQuery {}
@@ -4,6 +4,7 @@
 pub(crate) mod bogus;
 pub(crate) mod bogus_at_rule;
 pub(crate) mod bogus_block;
+pub(crate) mod bogus_container_query;
 pub(crate) mod bogus_custom_identifier;
 pub(crate) mod bogus_declaration_item;
 pub(crate) mod bogus_document_matcher;
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use biome_css_syntax::CssCounterStyleAdditiveSymbolList;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCounterStyleAdditiveSymbolList;
+impl FormatRule<CssCounterStyleAdditiveSymbolList> for FormatCssCounterStyleAdditiveSymbolList {
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &CssCounterStyleAdditiveSymbolList,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let separator = soft_line_break_or_space();
+        let mut joiner = f.join_with(&separator);
+
+        for formatted in node.format_separated(",") {
+            joiner.entry(&formatted);
+        }
+
+        joiner.finish()
+    }
+}
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_css_syntax::CssCounterStyleRangeList;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCounterStyleRangeList;
+impl FormatRule<CssCounterStyleRangeList> for FormatCssCounterStyleRangeList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssCounterStyleRangeList, f: &mut CssFormatter) -> FormatResult<()> {
+        let separator = soft_line_break_or_space();
+        let mut joiner = f.join_with(&separator);
+
+        for formatted in node.format_separated(",") {
+            joiner.entry(&formatted);
+        }
+
+        joiner.finish()
+    }
+}
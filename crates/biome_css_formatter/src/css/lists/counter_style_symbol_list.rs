@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use biome_css_syntax::CssCounterStyleSymbolList;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCounterStyleSymbolList;
+impl FormatRule<CssCounterStyleSymbolList> for FormatCssCounterStyleSymbolList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssCounterStyleSymbolList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.join_with(&space())
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
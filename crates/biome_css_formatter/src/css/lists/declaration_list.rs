@@ -8,7 +8,10 @@ impl FormatRule<CssDeclarationList> for FormatCssDeclarationList {
     fn fmt(&self, node: &CssDeclarationList, f: &mut CssFormatter) -> FormatResult<()> {
         // This is one of the few cases where we _do_ want to respect empty
         // lines from the input, so we can use `join_nodes_with_hardline`.
-        let mut join = f.join_nodes_with_hardline();
+        let max_empty_lines = f.options().max_empty_lines();
+        let mut join = f
+            .join_nodes_with_hardline()
+            .with_max_empty_lines(max_empty_lines);
 
         for declaration in node {
             join.entry(
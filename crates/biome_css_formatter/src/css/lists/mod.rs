@@ -1,9 +1,13 @@
 //! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
 
 pub(crate) mod bracketed_value_list;
+pub(crate) mod color_mix_color_list;
 pub(crate) mod component_value_list;
 pub(crate) mod composes_class_list;
 pub(crate) mod compound_selector_list;
+pub(crate) mod counter_style_additive_symbol_list;
+pub(crate) mod counter_style_range_list;
+pub(crate) mod counter_style_symbol_list;
 pub(crate) mod custom_identifier_list;
 pub(crate) mod declaration_list;
 pub(crate) mod declaration_or_at_rule_list;
@@ -27,6 +31,9 @@ pub(crate) mod relative_selector_list;
 pub(crate) mod rule_list;
 pub(crate) mod selector_list;
 pub(crate) mod sub_selector_list;
+pub(crate) mod tailwind_theme_modifier_list;
+pub(crate) mod tailwind_utility_list;
+pub(crate) mod tailwind_variant_list;
 pub(crate) mod url_modifier_list;
 pub(crate) mod value_at_rule_import_specifier_list;
 pub(crate) mod value_at_rule_property_list;
@@ -7,7 +7,10 @@ impl FormatRule<CssPageAtRuleItemList> for FormatCssPageAtRuleItemList {
     fn fmt(&self, node: &CssPageAtRuleItemList, f: &mut CssFormatter) -> FormatResult<()> {
         // This is one of the few cases where we _do_ want to respect empty
         // lines from the input, so we can use `join_nodes_with_hardline`.
-        let mut joiner = f.join_nodes_with_hardline();
+        let max_empty_lines = f.options().max_empty_lines();
+        let mut joiner = f
+            .join_nodes_with_hardline()
+            .with_max_empty_lines(max_empty_lines);
 
         for item in node.iter() {
             joiner.entry(item.syntax(), &item.format());
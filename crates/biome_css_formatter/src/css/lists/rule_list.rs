@@ -7,7 +7,10 @@ impl FormatRule<CssRuleList> for FormatCssRuleList {
     fn fmt(&self, node: &CssRuleList, f: &mut CssFormatter) -> FormatResult<()> {
         // This is one of the few cases where we _do_ want to respect empty
         // lines from the input, so we can use `join_nodes_with_hardline`.
-        let mut join = f.join_nodes_with_hardline();
+        let max_empty_lines = f.options().max_empty_lines();
+        let mut join = f
+            .join_nodes_with_hardline()
+            .with_max_empty_lines(max_empty_lines);
 
         for rule in node {
             join.entry(rule.syntax(), &format_or_verbatim(rule.format()));
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::CssTailwindThemeModifierList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssTailwindThemeModifierList;
+impl FormatRule<CssTailwindThemeModifierList> for FormatCssTailwindThemeModifierList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssTailwindThemeModifierList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.join_with(&space())
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::CssTailwindUtilityList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssTailwindUtilityList;
+impl FormatRule<CssTailwindUtilityList> for FormatCssTailwindUtilityList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssTailwindUtilityList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.fill()
+            .entries(&soft_line_break_or_space(), node.iter().formatted())
+            .finish()
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::CssTailwindVariantList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssTailwindVariantList;
+impl FormatRule<CssTailwindVariantList> for FormatCssTailwindVariantList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssTailwindVariantList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.join_with(&space())
+            .entries(node.format_separated(","))
+            .finish()
+    }
+}
@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use biome_css_syntax::{
+    CssCounterStyleAdditiveSymbolsProperty, CssCounterStyleAdditiveSymbolsPropertyFields,
+};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCounterStyleAdditiveSymbolsProperty;
+impl FormatNodeRule<CssCounterStyleAdditiveSymbolsProperty>
+    for FormatCssCounterStyleAdditiveSymbolsProperty
+{
+    fn fmt_fields(
+        &self,
+        node: &CssCounterStyleAdditiveSymbolsProperty,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssCounterStyleAdditiveSymbolsPropertyFields {
+            name,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        // When the symbols don't fit on one line, every symbol is printed on
+        // its own line, indented below the descriptor name.
+        write!(
+            f,
+            [
+                name.format(),
+                colon_token.format(),
+                group(&indent(&format_args![
+                    soft_line_break_or_space(),
+                    value.format()
+                ]))
+            ]
+        )
+    }
+}
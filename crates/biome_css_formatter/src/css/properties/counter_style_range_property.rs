@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssCounterStyleRangeProperty, CssCounterStyleRangePropertyFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCounterStyleRangeProperty;
+impl FormatNodeRule<CssCounterStyleRangeProperty> for FormatCssCounterStyleRangeProperty {
+    fn fmt_fields(
+        &self,
+        node: &CssCounterStyleRangeProperty,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssCounterStyleRangePropertyFields {
+            name,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        // Same as `additive-symbols`, long range lists are printed one range per line.
+        write!(
+            f,
+            [
+                name.format(),
+                colon_token.format(),
+                group(&indent(&format_args![
+                    soft_line_break_or_space(),
+                    value.format()
+                ]))
+            ]
+        )
+    }
+}
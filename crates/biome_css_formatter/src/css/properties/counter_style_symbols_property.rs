@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssCounterStyleSymbolsProperty, CssCounterStyleSymbolsPropertyFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCounterStyleSymbolsProperty;
+impl FormatNodeRule<CssCounterStyleSymbolsProperty> for FormatCssCounterStyleSymbolsProperty {
+    fn fmt_fields(
+        &self,
+        node: &CssCounterStyleSymbolsProperty,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssCounterStyleSymbolsPropertyFields {
+            name,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [name.format(), colon_token.format(), space(), value.format()]
+        )
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssCounterStyleSystemProperty, CssCounterStyleSystemPropertyFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCounterStyleSystemProperty;
+impl FormatNodeRule<CssCounterStyleSystemProperty> for FormatCssCounterStyleSystemProperty {
+    fn fmt_fields(
+        &self,
+        node: &CssCounterStyleSystemProperty,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssCounterStyleSystemPropertyFields {
+            name,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [name.format(), colon_token.format(), space(), value.format()]
+        )
+    }
+}
@@ -1,5 +1,9 @@
 //! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
 
 pub(crate) mod composes_property;
+pub(crate) mod counter_style_additive_symbols_property;
+pub(crate) mod counter_style_range_property;
+pub(crate) mod counter_style_symbols_property;
+pub(crate) mod counter_style_system_property;
 pub(crate) mod generic_property;
 pub(crate) mod value_at_rule_generic_property;
@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use biome_css_syntax::{
+    CssPseudoElementFunctionCustomIdentifierList,
+    CssPseudoElementFunctionCustomIdentifierListFields,
+};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssPseudoElementFunctionCustomIdentifierList;
+impl FormatNodeRule<CssPseudoElementFunctionCustomIdentifierList>
+    for FormatCssPseudoElementFunctionCustomIdentifierList
+{
+    fn fmt_fields(
+        &self,
+        node: &CssPseudoElementFunctionCustomIdentifierList,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssPseudoElementFunctionCustomIdentifierListFields {
+            name_token,
+            l_paren_token,
+            names,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                name_token.format(),
+                group(&format_args![
+                    l_paren_token.format(),
+                    soft_block_indent(&names.format()),
+                    r_paren_token.format()
+                ])
+            ]
+        )
+    }
+}
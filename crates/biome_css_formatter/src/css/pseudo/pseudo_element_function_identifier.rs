@@ -15,7 +15,7 @@ impl FormatNodeRule<CssPseudoElementFunctionIdentifier>
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
         let CssPseudoElementFunctionIdentifierFields {
-            name,
+            name_token,
             l_paren_token,
             ident,
             r_paren_token,
@@ -24,7 +24,7 @@ impl FormatNodeRule<CssPseudoElementFunctionIdentifier>
         write!(
             f,
             [
-                name.format(),
+                name_token.format(),
                 group(&format_args![
                     l_paren_token.format(),
                     soft_block_indent(&ident.format()),
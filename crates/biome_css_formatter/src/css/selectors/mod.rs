@@ -14,6 +14,7 @@ pub(crate) mod pseudo_class_function_selector;
 pub(crate) mod pseudo_class_nth_selector;
 pub(crate) mod pseudo_class_of_nth_selector;
 pub(crate) mod pseudo_class_selector;
+pub(crate) mod pseudo_element_function_compound_selector;
 pub(crate) mod pseudo_element_function_selector;
 pub(crate) mod pseudo_element_selector;
 pub(crate) mod relative_selector;
@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use biome_css_syntax::{
+    CssPseudoElementFunctionCompoundSelector, CssPseudoElementFunctionCompoundSelectorFields,
+};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssPseudoElementFunctionCompoundSelector;
+impl FormatNodeRule<CssPseudoElementFunctionCompoundSelector>
+    for FormatCssPseudoElementFunctionCompoundSelector
+{
+    fn fmt_fields(
+        &self,
+        node: &CssPseudoElementFunctionCompoundSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssPseudoElementFunctionCompoundSelectorFields {
+            name_token,
+            l_paren_token,
+            selector,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                name_token.format(),
+                group(&format_args![
+                    l_paren_token.format(),
+                    soft_block_indent(&selector.format()),
+                    r_paren_token.format()
+                ])
+            ]
+        )
+    }
+}
@@ -19,6 +19,11 @@ impl FormatNodeRule<CssContainerAtRule> for FormatCssContainerAtRule {
             write!(f, [name.format(), space()])?;
         }
 
-        write!(f, [query.format(), space(), block.format()])
+        // The query is indented when it breaks over multiple lines,
+        // like the queries of `@media`.
+        write!(
+            f,
+            [group(&indent(&query.format())), space(), block.format()]
+        )
     }
 }
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssCustomMediaAtRule, CssCustomMediaAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssCustomMediaAtRule;
+impl FormatNodeRule<CssCustomMediaAtRule> for FormatCssCustomMediaAtRule {
+    fn fmt_fields(&self, node: &CssCustomMediaAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssCustomMediaAtRuleFields {
+            custom_media_token,
+            name,
+            queries,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                custom_media_token.format(),
+                space(),
+                name.format(),
+                space(),
+                group(&indent(&queries.format())),
+                semicolon_token.format()
+            ]
+        )
+    }
+}
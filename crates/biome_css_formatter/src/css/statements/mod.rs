@@ -5,6 +5,7 @@ pub(crate) mod charset_at_rule;
 pub(crate) mod color_profile_at_rule;
 pub(crate) mod container_at_rule;
 pub(crate) mod counter_style_at_rule;
+pub(crate) mod custom_media_at_rule;
 pub(crate) mod document_at_rule;
 pub(crate) mod font_face_at_rule;
 pub(crate) mod font_feature_values_at_rule;
@@ -16,10 +17,17 @@ pub(crate) mod margin_at_rule;
 pub(crate) mod media_at_rule;
 pub(crate) mod namespace_at_rule;
 pub(crate) mod page_at_rule;
+pub(crate) mod position_try_at_rule;
 pub(crate) mod property_at_rule;
 pub(crate) mod scope_at_rule;
 pub(crate) mod starting_style_at_rule;
 pub(crate) mod supports_at_rule;
+pub(crate) mod tailwind_apply_at_rule;
+pub(crate) mod tailwind_at_rule;
+pub(crate) mod tailwind_config_at_rule;
+pub(crate) mod tailwind_theme_at_rule;
+pub(crate) mod tailwind_variants_at_rule;
 pub(crate) mod unknown_block_at_rule;
 pub(crate) mod unknown_value_at_rule;
 pub(crate) mod value_at_rule;
+pub(crate) mod view_transition_at_rule;
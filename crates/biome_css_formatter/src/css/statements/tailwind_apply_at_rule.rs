@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssTailwindApplyAtRule, CssTailwindApplyAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssTailwindApplyAtRule;
+impl FormatNodeRule<CssTailwindApplyAtRule> for FormatCssTailwindApplyAtRule {
+    fn fmt_fields(&self, node: &CssTailwindApplyAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssTailwindApplyAtRuleFields {
+            apply_token,
+            utilities,
+            important,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                apply_token.format(),
+                space(),
+                group(&indent(&utilities.format()))
+            ]
+        )?;
+
+        if important.is_some() {
+            write!(f, [space(), important.format()])?;
+        }
+
+        if semicolon_token.is_some() {
+            // if semicolon is present, use the token's format to keep the comments
+            write!(f, [semicolon_token.format()])
+        } else {
+            write!(f, [text(";")])
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssTailwindConfigAtRule, CssTailwindConfigAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssTailwindConfigAtRule;
+impl FormatNodeRule<CssTailwindConfigAtRule> for FormatCssTailwindConfigAtRule {
+    fn fmt_fields(&self, node: &CssTailwindConfigAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssTailwindConfigAtRuleFields {
+            config_token,
+            path,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                config_token.format(),
+                space(),
+                path.format(),
+                semicolon_token.format()
+            ]
+        )
+    }
+}
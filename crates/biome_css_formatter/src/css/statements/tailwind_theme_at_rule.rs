@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssTailwindThemeAtRule, CssTailwindThemeAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssTailwindThemeAtRule;
+impl FormatNodeRule<CssTailwindThemeAtRule> for FormatCssTailwindThemeAtRule {
+    fn fmt_fields(&self, node: &CssTailwindThemeAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssTailwindThemeAtRuleFields {
+            theme_token,
+            modifiers,
+            block,
+        } = node.as_fields();
+
+        write!(f, [theme_token.format(), space()])?;
+
+        if !modifiers.is_empty() {
+            write!(f, [modifiers.format(), space()])?;
+        }
+
+        write!(f, [block.format()])
+    }
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssTailwindVariantsAtRule, CssTailwindVariantsAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssTailwindVariantsAtRule;
+impl FormatNodeRule<CssTailwindVariantsAtRule> for FormatCssTailwindVariantsAtRule {
+    fn fmt_fields(
+        &self,
+        node: &CssTailwindVariantsAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssTailwindVariantsAtRuleFields {
+            variants_token,
+            variants,
+            block,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                variants_token.format(),
+                space(),
+                variants.format(),
+                space(),
+                block.format()
+            ]
+        )
+    }
+}
@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use crate::utils::string_utils::FormatTokenAsLowercase;
 use biome_css_syntax::{CssColor, CssColorFields};
-use biome_formatter::write;
+use biome_formatter::{trivia::format_replaced, write};
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatCssColor;
@@ -11,13 +11,47 @@ impl FormatNodeRule<CssColor> for FormatCssColor {
             hash_token,
             value_token,
         } = node.as_fields();
+        let value_token = value_token?;
+
+        if f.options().shorten_hex_colors() {
+            if let Some(short_value) = shorten_hex_color(value_token.text_trimmed()) {
+                return write!(
+                    f,
+                    [
+                        hash_token.format(),
+                        format_replaced(
+                            &value_token,
+                            &dynamic_text(&short_value, value_token.text_trimmed_range().start())
+                        )
+                    ]
+                );
+            }
+        }
 
         write!(
             f,
             [
                 hash_token.format(),
-                FormatTokenAsLowercase::from(value_token?)
+                FormatTokenAsLowercase::from(value_token)
             ]
         )
     }
 }
+
+/// Returns the lowercase short form of the 6 or 8 digit hex color `value`,
+/// e.g. `f0a` for `FF00AA`, or `None` if it doesn't have one.
+fn shorten_hex_color(value: &str) -> Option<String> {
+    if !matches!(value.len(), 6 | 8) || !value.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    let bytes = value.as_bytes();
+    bytes
+        .chunks(2)
+        .all(|pair| pair[0].eq_ignore_ascii_case(&pair[1]))
+        .then(|| {
+            bytes
+                .chunks(2)
+                .map(|pair| char::from(pair[0].to_ascii_lowercase()))
+                .collect()
+        })
+}
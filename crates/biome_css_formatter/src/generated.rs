@@ -4,6 +4,126 @@ use crate::{
     AsFormat, CssFormatContext, CssFormatter, FormatBogusNodeRule, FormatNodeRule, IntoFormat,
 };
 use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<biome_css_syntax::CssAnchorFallback>
+    for crate::css::auxiliary::anchor_fallback::FormatCssAnchorFallback
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssAnchorFallback,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssAnchorFallback>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssAnchorFallback {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssAnchorFallback,
+        crate::css::auxiliary::anchor_fallback::FormatCssAnchorFallback,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::anchor_fallback::FormatCssAnchorFallback::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssAnchorFallback {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssAnchorFallback,
+        crate::css::auxiliary::anchor_fallback::FormatCssAnchorFallback,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::anchor_fallback::FormatCssAnchorFallback::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssAnchorFunction>
+    for crate::css::auxiliary::anchor_function::FormatCssAnchorFunction
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssAnchorFunction,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssAnchorFunction>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssAnchorFunction {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssAnchorFunction,
+        crate::css::auxiliary::anchor_function::FormatCssAnchorFunction,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::anchor_function::FormatCssAnchorFunction::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssAnchorFunction {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssAnchorFunction,
+        crate::css::auxiliary::anchor_function::FormatCssAnchorFunction,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::anchor_function::FormatCssAnchorFunction::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssAnchorSizeFunction>
+    for crate::css::auxiliary::anchor_size_function::FormatCssAnchorSizeFunction
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssAnchorSizeFunction,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssAnchorSizeFunction>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssAnchorSizeFunction {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssAnchorSizeFunction,
+        crate::css::auxiliary::anchor_size_function::FormatCssAnchorSizeFunction,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::anchor_size_function::FormatCssAnchorSizeFunction::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssAnchorSizeFunction {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssAnchorSizeFunction,
+        crate::css::auxiliary::anchor_size_function::FormatCssAnchorSizeFunction,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::anchor_size_function::FormatCssAnchorSizeFunction::default(),
+        )
+    }
+}
 impl FormatRule<biome_css_syntax::CssAtRule> for crate::css::statements::at_rule::FormatCssAtRule {
     type Context = CssFormatContext;
     #[inline(always)]
@@ -383,6 +503,154 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssColor {
         FormatOwnedWithRule::new(self, crate::css::value::color::FormatCssColor::default())
     }
 }
+impl FormatRule<biome_css_syntax::CssColorHueInterpolationMethod>
+    for crate::css::auxiliary::color_hue_interpolation_method::FormatCssColorHueInterpolationMethod
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssColorHueInterpolationMethod,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssColorHueInterpolationMethod>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssColorHueInterpolationMethod {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssColorHueInterpolationMethod,
+        crate::css::auxiliary::color_hue_interpolation_method::FormatCssColorHueInterpolationMethod,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: color_hue_interpolation_method :: FormatCssColorHueInterpolationMethod :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssColorHueInterpolationMethod {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssColorHueInterpolationMethod,
+        crate::css::auxiliary::color_hue_interpolation_method::FormatCssColorHueInterpolationMethod,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: color_hue_interpolation_method :: FormatCssColorHueInterpolationMethod :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssColorInterpolationMethod>
+    for crate::css::auxiliary::color_interpolation_method::FormatCssColorInterpolationMethod
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssColorInterpolationMethod,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssColorInterpolationMethod>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssColorInterpolationMethod {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssColorInterpolationMethod,
+        crate::css::auxiliary::color_interpolation_method::FormatCssColorInterpolationMethod,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: color_interpolation_method :: FormatCssColorInterpolationMethod :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssColorInterpolationMethod {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssColorInterpolationMethod,
+        crate::css::auxiliary::color_interpolation_method::FormatCssColorInterpolationMethod,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: color_interpolation_method :: FormatCssColorInterpolationMethod :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssColorMixColor>
+    for crate::css::auxiliary::color_mix_color::FormatCssColorMixColor
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssColorMixColor,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssColorMixColor>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssColorMixColor {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssColorMixColor,
+        crate::css::auxiliary::color_mix_color::FormatCssColorMixColor,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::color_mix_color::FormatCssColorMixColor::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssColorMixColor {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssColorMixColor,
+        crate::css::auxiliary::color_mix_color::FormatCssColorMixColor,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::color_mix_color::FormatCssColorMixColor::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssColorMixFunction>
+    for crate::css::auxiliary::color_mix_function::FormatCssColorMixFunction
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssColorMixFunction,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssColorMixFunction>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssColorMixFunction {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssColorMixFunction,
+        crate::css::auxiliary::color_mix_function::FormatCssColorMixFunction,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::color_mix_function::FormatCssColorMixFunction::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssColorMixFunction {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssColorMixFunction,
+        crate::css::auxiliary::color_mix_function::FormatCssColorMixFunction,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::color_mix_function::FormatCssColorMixFunction::default(),
+        )
+    }
+}
 impl FormatRule<biome_css_syntax::CssColorProfileAtRule>
     for crate::css::statements::color_profile_at_rule::FormatCssColorProfileAtRule
 {
@@ -813,6 +1081,93 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerQueryInParen
         FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_query_in_parens :: FormatCssContainerQueryInParens :: default ())
     }
 }
+impl FormatRule < biome_css_syntax :: CssContainerScrollStateAndQuery > for crate :: css :: auxiliary :: container_scroll_state_and_query :: FormatCssContainerScrollStateAndQuery { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssContainerScrollStateAndQuery , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssContainerScrollStateAndQuery > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerScrollStateAndQuery {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssContainerScrollStateAndQuery , crate :: css :: auxiliary :: container_scroll_state_and_query :: FormatCssContainerScrollStateAndQuery > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_scroll_state_and_query :: FormatCssContainerScrollStateAndQuery :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerScrollStateAndQuery {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssContainerScrollStateAndQuery , crate :: css :: auxiliary :: container_scroll_state_and_query :: FormatCssContainerScrollStateAndQuery > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_scroll_state_and_query :: FormatCssContainerScrollStateAndQuery :: default ())
+    }
+}
+impl FormatRule < biome_css_syntax :: CssContainerScrollStateInParens > for crate :: css :: auxiliary :: container_scroll_state_in_parens :: FormatCssContainerScrollStateInParens { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssContainerScrollStateInParens , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssContainerScrollStateInParens > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerScrollStateInParens {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssContainerScrollStateInParens , crate :: css :: auxiliary :: container_scroll_state_in_parens :: FormatCssContainerScrollStateInParens > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_scroll_state_in_parens :: FormatCssContainerScrollStateInParens :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerScrollStateInParens {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssContainerScrollStateInParens , crate :: css :: auxiliary :: container_scroll_state_in_parens :: FormatCssContainerScrollStateInParens > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_scroll_state_in_parens :: FormatCssContainerScrollStateInParens :: default ())
+    }
+}
+impl FormatRule < biome_css_syntax :: CssContainerScrollStateNotQuery > for crate :: css :: auxiliary :: container_scroll_state_not_query :: FormatCssContainerScrollStateNotQuery { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssContainerScrollStateNotQuery , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssContainerScrollStateNotQuery > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerScrollStateNotQuery {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssContainerScrollStateNotQuery , crate :: css :: auxiliary :: container_scroll_state_not_query :: FormatCssContainerScrollStateNotQuery > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_scroll_state_not_query :: FormatCssContainerScrollStateNotQuery :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerScrollStateNotQuery {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssContainerScrollStateNotQuery , crate :: css :: auxiliary :: container_scroll_state_not_query :: FormatCssContainerScrollStateNotQuery > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_scroll_state_not_query :: FormatCssContainerScrollStateNotQuery :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssContainerScrollStateOrQuery>
+    for crate::css::auxiliary::container_scroll_state_or_query::FormatCssContainerScrollStateOrQuery
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssContainerScrollStateOrQuery,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssContainerScrollStateOrQuery>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerScrollStateOrQuery {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssContainerScrollStateOrQuery , crate :: css :: auxiliary :: container_scroll_state_or_query :: FormatCssContainerScrollStateOrQuery > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_scroll_state_or_query :: FormatCssContainerScrollStateOrQuery :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerScrollStateOrQuery {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssContainerScrollStateOrQuery , crate :: css :: auxiliary :: container_scroll_state_or_query :: FormatCssContainerScrollStateOrQuery > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_scroll_state_or_query :: FormatCssContainerScrollStateOrQuery :: default ())
+    }
+}
+impl FormatRule < biome_css_syntax :: CssContainerScrollStateQueryInParens > for crate :: css :: auxiliary :: container_scroll_state_query_in_parens :: FormatCssContainerScrollStateQueryInParens { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssContainerScrollStateQueryInParens , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssContainerScrollStateQueryInParens > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerScrollStateQueryInParens {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssContainerScrollStateQueryInParens , crate :: css :: auxiliary :: container_scroll_state_query_in_parens :: FormatCssContainerScrollStateQueryInParens > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_scroll_state_query_in_parens :: FormatCssContainerScrollStateQueryInParens :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerScrollStateQueryInParens {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssContainerScrollStateQueryInParens , crate :: css :: auxiliary :: container_scroll_state_query_in_parens :: FormatCssContainerScrollStateQueryInParens > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_scroll_state_query_in_parens :: FormatCssContainerScrollStateQueryInParens :: default ())
+    }
+}
 impl FormatRule < biome_css_syntax :: CssContainerSizeFeatureInParens > for crate :: css :: auxiliary :: container_size_feature_in_parens :: FormatCssContainerSizeFeatureInParens { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssContainerSizeFeatureInParens , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssContainerSizeFeatureInParens > :: fmt (self , node , f) } }
 impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerSizeFeatureInParens {
     type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssContainerSizeFeatureInParens , crate :: css :: auxiliary :: container_size_feature_in_parens :: FormatCssContainerSizeFeatureInParens > ;
@@ -991,4037 +1346,4935 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleQueryIn
         FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_style_query_in_parens :: FormatCssContainerStyleQueryInParens :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssCounterStyleAtRule>
-    for crate::css::statements::counter_style_at_rule::FormatCssCounterStyleAtRule
+impl FormatRule<biome_css_syntax::CssContainerStyleRange>
+    for crate::css::auxiliary::container_style_range::FormatCssContainerStyleRange
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssCounterStyleAtRule,
+        node: &biome_css_syntax::CssContainerStyleRange,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssCounterStyleAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssContainerStyleRange>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleRange {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssCounterStyleAtRule,
-        crate::css::statements::counter_style_at_rule::FormatCssCounterStyleAtRule,
+        biome_css_syntax::CssContainerStyleRange,
+        crate::css::auxiliary::container_style_range::FormatCssContainerStyleRange,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::counter_style_at_rule::FormatCssCounterStyleAtRule::default(),
+            crate::css::auxiliary::container_style_range::FormatCssContainerStyleRange::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleRange {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssCounterStyleAtRule,
-        crate::css::statements::counter_style_at_rule::FormatCssCounterStyleAtRule,
+        biome_css_syntax::CssContainerStyleRange,
+        crate::css::auxiliary::container_style_range::FormatCssContainerStyleRange,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::statements::counter_style_at_rule::FormatCssCounterStyleAtRule::default(),
+            crate::css::auxiliary::container_style_range::FormatCssContainerStyleRange::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssCustomIdentifier>
-    for crate::css::value::custom_identifier::FormatCssCustomIdentifier
+impl FormatRule<biome_css_syntax::CssContainerStyleRangeInterval>
+    for crate::css::auxiliary::container_style_range_interval::FormatCssContainerStyleRangeInterval
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssCustomIdentifier,
+        node: &biome_css_syntax::CssContainerStyleRangeInterval,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssCustomIdentifier>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssContainerStyleRangeInterval>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssCustomIdentifier {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleRangeInterval {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssCustomIdentifier,
-        crate::css::value::custom_identifier::FormatCssCustomIdentifier,
+        biome_css_syntax::CssContainerStyleRangeInterval,
+        crate::css::auxiliary::container_style_range_interval::FormatCssContainerStyleRangeInterval,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::value::custom_identifier::FormatCssCustomIdentifier::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: container_style_range_interval :: FormatCssContainerStyleRangeInterval :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCustomIdentifier {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssContainerStyleRangeInterval {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssCustomIdentifier,
-        crate::css::value::custom_identifier::FormatCssCustomIdentifier,
+        biome_css_syntax::CssContainerStyleRangeInterval,
+        crate::css::auxiliary::container_style_range_interval::FormatCssContainerStyleRangeInterval,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::value::custom_identifier::FormatCssCustomIdentifier::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: container_style_range_interval :: FormatCssContainerStyleRangeInterval :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssDashedIdentifier>
-    for crate::css::value::dashed_identifier::FormatCssDashedIdentifier
+impl FormatRule<biome_css_syntax::CssCounterStyleAdditiveSymbol>
+    for crate::css::auxiliary::counter_style_additive_symbol::FormatCssCounterStyleAdditiveSymbol
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssDashedIdentifier,
+        node: &biome_css_syntax::CssCounterStyleAdditiveSymbol,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssDashedIdentifier>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssCounterStyleAdditiveSymbol>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDashedIdentifier {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleAdditiveSymbol {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDashedIdentifier,
-        crate::css::value::dashed_identifier::FormatCssDashedIdentifier,
+        biome_css_syntax::CssCounterStyleAdditiveSymbol,
+        crate::css::auxiliary::counter_style_additive_symbol::FormatCssCounterStyleAdditiveSymbol,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::value::dashed_identifier::FormatCssDashedIdentifier::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: counter_style_additive_symbol :: FormatCssCounterStyleAdditiveSymbol :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDashedIdentifier {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleAdditiveSymbol {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDashedIdentifier,
-        crate::css::value::dashed_identifier::FormatCssDashedIdentifier,
+        biome_css_syntax::CssCounterStyleAdditiveSymbol,
+        crate::css::auxiliary::counter_style_additive_symbol::FormatCssCounterStyleAdditiveSymbol,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::value::dashed_identifier::FormatCssDashedIdentifier::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: counter_style_additive_symbol :: FormatCssCounterStyleAdditiveSymbol :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssDeclaration>
-    for crate::css::auxiliary::declaration::FormatCssDeclaration
+impl FormatRule < biome_css_syntax :: CssCounterStyleAdditiveSymbolsProperty > for crate :: css :: properties :: counter_style_additive_symbols_property :: FormatCssCounterStyleAdditiveSymbolsProperty { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssCounterStyleAdditiveSymbolsProperty , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssCounterStyleAdditiveSymbolsProperty > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleAdditiveSymbolsProperty {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssCounterStyleAdditiveSymbolsProperty , crate :: css :: properties :: counter_style_additive_symbols_property :: FormatCssCounterStyleAdditiveSymbolsProperty > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: properties :: counter_style_additive_symbols_property :: FormatCssCounterStyleAdditiveSymbolsProperty :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleAdditiveSymbolsProperty {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssCounterStyleAdditiveSymbolsProperty , crate :: css :: properties :: counter_style_additive_symbols_property :: FormatCssCounterStyleAdditiveSymbolsProperty > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: properties :: counter_style_additive_symbols_property :: FormatCssCounterStyleAdditiveSymbolsProperty :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssCounterStyleAtRule>
+    for crate::css::statements::counter_style_at_rule::FormatCssCounterStyleAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssDeclaration,
+        node: &biome_css_syntax::CssCounterStyleAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssDeclaration>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssCounterStyleAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclaration {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDeclaration,
-        crate::css::auxiliary::declaration::FormatCssDeclaration,
+        biome_css_syntax::CssCounterStyleAtRule,
+        crate::css::statements::counter_style_at_rule::FormatCssCounterStyleAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::declaration::FormatCssDeclaration::default(),
+            crate::css::statements::counter_style_at_rule::FormatCssCounterStyleAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclaration {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDeclaration,
-        crate::css::auxiliary::declaration::FormatCssDeclaration,
+        biome_css_syntax::CssCounterStyleAtRule,
+        crate::css::statements::counter_style_at_rule::FormatCssCounterStyleAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::declaration::FormatCssDeclaration::default(),
+            crate::css::statements::counter_style_at_rule::FormatCssCounterStyleAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssDeclarationBlock>
-    for crate::css::auxiliary::declaration_block::FormatCssDeclarationBlock
+impl FormatRule<biome_css_syntax::CssCounterStyleExtendsSystem>
+    for crate::css::auxiliary::counter_style_extends_system::FormatCssCounterStyleExtendsSystem
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssDeclarationBlock,
+        node: &biome_css_syntax::CssCounterStyleExtendsSystem,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssDeclarationBlock>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssCounterStyleExtendsSystem>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationBlock {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleExtendsSystem {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDeclarationBlock,
-        crate::css::auxiliary::declaration_block::FormatCssDeclarationBlock,
+        biome_css_syntax::CssCounterStyleExtendsSystem,
+        crate::css::auxiliary::counter_style_extends_system::FormatCssCounterStyleExtendsSystem,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::declaration_block::FormatCssDeclarationBlock::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: counter_style_extends_system :: FormatCssCounterStyleExtendsSystem :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationBlock {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleExtendsSystem {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDeclarationBlock,
-        crate::css::auxiliary::declaration_block::FormatCssDeclarationBlock,
+        biome_css_syntax::CssCounterStyleExtendsSystem,
+        crate::css::auxiliary::counter_style_extends_system::FormatCssCounterStyleExtendsSystem,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::declaration_block::FormatCssDeclarationBlock::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: counter_style_extends_system :: FormatCssCounterStyleExtendsSystem :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssDeclarationImportant>
-    for crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant
+impl FormatRule<biome_css_syntax::CssCounterStyleFixedSystem>
+    for crate::css::auxiliary::counter_style_fixed_system::FormatCssCounterStyleFixedSystem
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssDeclarationImportant,
+        node: &biome_css_syntax::CssCounterStyleFixedSystem,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssDeclarationImportant>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssCounterStyleFixedSystem>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationImportant {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleFixedSystem {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDeclarationImportant,
-        crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant,
+        biome_css_syntax::CssCounterStyleFixedSystem,
+        crate::css::auxiliary::counter_style_fixed_system::FormatCssCounterStyleFixedSystem,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: counter_style_fixed_system :: FormatCssCounterStyleFixedSystem :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationImportant {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleFixedSystem {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDeclarationImportant,
-        crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant,
+        biome_css_syntax::CssCounterStyleFixedSystem,
+        crate::css::auxiliary::counter_style_fixed_system::FormatCssCounterStyleFixedSystem,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: counter_style_fixed_system :: FormatCssCounterStyleFixedSystem :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssDeclarationOrAtRuleBlock>
-    for crate::css::auxiliary::declaration_or_at_rule_block::FormatCssDeclarationOrAtRuleBlock
+impl FormatRule<biome_css_syntax::CssCounterStyleRange>
+    for crate::css::auxiliary::counter_style_range::FormatCssCounterStyleRange
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssDeclarationOrAtRuleBlock,
+        node: &biome_css_syntax::CssCounterStyleRange,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssDeclarationOrAtRuleBlock>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssCounterStyleRange>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrAtRuleBlock {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleRange {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDeclarationOrAtRuleBlock,
-        crate::css::auxiliary::declaration_or_at_rule_block::FormatCssDeclarationOrAtRuleBlock,
+        biome_css_syntax::CssCounterStyleRange,
+        crate::css::auxiliary::counter_style_range::FormatCssCounterStyleRange,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: declaration_or_at_rule_block :: FormatCssDeclarationOrAtRuleBlock :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::counter_style_range::FormatCssCounterStyleRange::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrAtRuleBlock {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleRange {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDeclarationOrAtRuleBlock,
-        crate::css::auxiliary::declaration_or_at_rule_block::FormatCssDeclarationOrAtRuleBlock,
+        biome_css_syntax::CssCounterStyleRange,
+        crate::css::auxiliary::counter_style_range::FormatCssCounterStyleRange,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: declaration_or_at_rule_block :: FormatCssDeclarationOrAtRuleBlock :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::counter_style_range::FormatCssCounterStyleRange::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssDeclarationOrRuleBlock>
-    for crate::css::auxiliary::declaration_or_rule_block::FormatCssDeclarationOrRuleBlock
+impl FormatRule<biome_css_syntax::CssCounterStyleRangeProperty>
+    for crate::css::properties::counter_style_range_property::FormatCssCounterStyleRangeProperty
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssDeclarationOrRuleBlock,
+        node: &biome_css_syntax::CssCounterStyleRangeProperty,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssDeclarationOrRuleBlock>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssCounterStyleRangeProperty>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrRuleBlock {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleRangeProperty {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDeclarationOrRuleBlock,
-        crate::css::auxiliary::declaration_or_rule_block::FormatCssDeclarationOrRuleBlock,
+        biome_css_syntax::CssCounterStyleRangeProperty,
+        crate::css::properties::counter_style_range_property::FormatCssCounterStyleRangeProperty,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: declaration_or_rule_block :: FormatCssDeclarationOrRuleBlock :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: properties :: counter_style_range_property :: FormatCssCounterStyleRangeProperty :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrRuleBlock {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleRangeProperty {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDeclarationOrRuleBlock,
-        crate::css::auxiliary::declaration_or_rule_block::FormatCssDeclarationOrRuleBlock,
+        biome_css_syntax::CssCounterStyleRangeProperty,
+        crate::css::properties::counter_style_range_property::FormatCssCounterStyleRangeProperty,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: declaration_or_rule_block :: FormatCssDeclarationOrRuleBlock :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: properties :: counter_style_range_property :: FormatCssCounterStyleRangeProperty :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssDeclarationWithSemicolon>
-    for crate::css::auxiliary::declaration_with_semicolon::FormatCssDeclarationWithSemicolon
+impl FormatRule<biome_css_syntax::CssCounterStyleRangeValue>
+    for crate::css::auxiliary::counter_style_range_value::FormatCssCounterStyleRangeValue
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssDeclarationWithSemicolon,
+        node: &biome_css_syntax::CssCounterStyleRangeValue,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssDeclarationWithSemicolon>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssCounterStyleRangeValue>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationWithSemicolon {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleRangeValue {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDeclarationWithSemicolon,
-        crate::css::auxiliary::declaration_with_semicolon::FormatCssDeclarationWithSemicolon,
+        biome_css_syntax::CssCounterStyleRangeValue,
+        crate::css::auxiliary::counter_style_range_value::FormatCssCounterStyleRangeValue,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: declaration_with_semicolon :: FormatCssDeclarationWithSemicolon :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: counter_style_range_value :: FormatCssCounterStyleRangeValue :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationWithSemicolon {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleRangeValue {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDeclarationWithSemicolon,
-        crate::css::auxiliary::declaration_with_semicolon::FormatCssDeclarationWithSemicolon,
+        biome_css_syntax::CssCounterStyleRangeValue,
+        crate::css::auxiliary::counter_style_range_value::FormatCssCounterStyleRangeValue,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: declaration_with_semicolon :: FormatCssDeclarationWithSemicolon :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: counter_style_range_value :: FormatCssCounterStyleRangeValue :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssDocumentAtRule>
-    for crate::css::statements::document_at_rule::FormatCssDocumentAtRule
+impl FormatRule<biome_css_syntax::CssCounterStyleSymbolsProperty>
+    for crate::css::properties::counter_style_symbols_property::FormatCssCounterStyleSymbolsProperty
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssDocumentAtRule,
+        node: &biome_css_syntax::CssCounterStyleSymbolsProperty,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssDocumentAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssCounterStyleSymbolsProperty>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDocumentAtRule {
-    type Format<'a> = FormatRefWithRule<
-        'a,
-        biome_css_syntax::CssDocumentAtRule,
-        crate::css::statements::document_at_rule::FormatCssDocumentAtRule,
-    >;
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleSymbolsProperty {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssCounterStyleSymbolsProperty , crate :: css :: properties :: counter_style_symbols_property :: FormatCssCounterStyleSymbolsProperty > ;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::statements::document_at_rule::FormatCssDocumentAtRule::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: properties :: counter_style_symbols_property :: FormatCssCounterStyleSymbolsProperty :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDocumentAtRule {
-    type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDocumentAtRule,
-        crate::css::statements::document_at_rule::FormatCssDocumentAtRule,
-    >;
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleSymbolsProperty {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssCounterStyleSymbolsProperty , crate :: css :: properties :: counter_style_symbols_property :: FormatCssCounterStyleSymbolsProperty > ;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::statements::document_at_rule::FormatCssDocumentAtRule::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: properties :: counter_style_symbols_property :: FormatCssCounterStyleSymbolsProperty :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssDocumentCustomMatcher>
-    for crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher
+impl FormatRule<biome_css_syntax::CssCounterStyleSystemProperty>
+    for crate::css::properties::counter_style_system_property::FormatCssCounterStyleSystemProperty
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssDocumentCustomMatcher,
+        node: &biome_css_syntax::CssCounterStyleSystemProperty,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssDocumentCustomMatcher>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssCounterStyleSystemProperty>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDocumentCustomMatcher {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleSystemProperty {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDocumentCustomMatcher,
-        crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher,
+        biome_css_syntax::CssCounterStyleSystemProperty,
+        crate::css::properties::counter_style_system_property::FormatCssCounterStyleSystemProperty,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher::default(
-            ),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: properties :: counter_style_system_property :: FormatCssCounterStyleSystemProperty :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDocumentCustomMatcher {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleSystemProperty {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDocumentCustomMatcher,
-        crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher,
+        biome_css_syntax::CssCounterStyleSystemProperty,
+        crate::css::properties::counter_style_system_property::FormatCssCounterStyleSystemProperty,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher::default(
-            ),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: properties :: counter_style_system_property :: FormatCssCounterStyleSystemProperty :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssEmptyDeclaration>
-    for crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration
+impl FormatRule<biome_css_syntax::CssCustomIdentifier>
+    for crate::css::value::custom_identifier::FormatCssCustomIdentifier
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssEmptyDeclaration,
+        node: &biome_css_syntax::CssCustomIdentifier,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssEmptyDeclaration>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssCustomIdentifier>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssEmptyDeclaration {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCustomIdentifier {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssEmptyDeclaration,
-        crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration,
+        biome_css_syntax::CssCustomIdentifier,
+        crate::css::value::custom_identifier::FormatCssCustomIdentifier,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration::default(),
+            crate::css::value::custom_identifier::FormatCssCustomIdentifier::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssEmptyDeclaration {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCustomIdentifier {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssEmptyDeclaration,
-        crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration,
+        biome_css_syntax::CssCustomIdentifier,
+        crate::css::value::custom_identifier::FormatCssCustomIdentifier,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration::default(),
+            crate::css::value::custom_identifier::FormatCssCustomIdentifier::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssFontFaceAtRule>
-    for crate::css::statements::font_face_at_rule::FormatCssFontFaceAtRule
+impl FormatRule<biome_css_syntax::CssCustomMediaAtRule>
+    for crate::css::statements::custom_media_at_rule::FormatCssCustomMediaAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssFontFaceAtRule,
+        node: &biome_css_syntax::CssCustomMediaAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssFontFaceAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssCustomMediaAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFaceAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCustomMediaAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssFontFaceAtRule,
-        crate::css::statements::font_face_at_rule::FormatCssFontFaceAtRule,
+        biome_css_syntax::CssCustomMediaAtRule,
+        crate::css::statements::custom_media_at_rule::FormatCssCustomMediaAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::font_face_at_rule::FormatCssFontFaceAtRule::default(),
+            crate::css::statements::custom_media_at_rule::FormatCssCustomMediaAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFaceAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCustomMediaAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssFontFaceAtRule,
-        crate::css::statements::font_face_at_rule::FormatCssFontFaceAtRule,
+        biome_css_syntax::CssCustomMediaAtRule,
+        crate::css::statements::custom_media_at_rule::FormatCssCustomMediaAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::statements::font_face_at_rule::FormatCssFontFaceAtRule::default(),
+            crate::css::statements::custom_media_at_rule::FormatCssCustomMediaAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssFontFamilyName>
-    for crate::css::auxiliary::font_family_name::FormatCssFontFamilyName
+impl FormatRule<biome_css_syntax::CssDashedIdentifier>
+    for crate::css::value::dashed_identifier::FormatCssDashedIdentifier
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssFontFamilyName,
+        node: &biome_css_syntax::CssDashedIdentifier,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssFontFamilyName>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssDashedIdentifier>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFamilyName {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDashedIdentifier {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssFontFamilyName,
-        crate::css::auxiliary::font_family_name::FormatCssFontFamilyName,
+        biome_css_syntax::CssDashedIdentifier,
+        crate::css::value::dashed_identifier::FormatCssDashedIdentifier,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::font_family_name::FormatCssFontFamilyName::default(),
+            crate::css::value::dashed_identifier::FormatCssDashedIdentifier::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFamilyName {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDashedIdentifier {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssFontFamilyName,
-        crate::css::auxiliary::font_family_name::FormatCssFontFamilyName,
+        biome_css_syntax::CssDashedIdentifier,
+        crate::css::value::dashed_identifier::FormatCssDashedIdentifier,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::font_family_name::FormatCssFontFamilyName::default(),
+            crate::css::value::dashed_identifier::FormatCssDashedIdentifier::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssFontFeatureValuesAtRule>
-    for crate::css::statements::font_feature_values_at_rule::FormatCssFontFeatureValuesAtRule
+impl FormatRule<biome_css_syntax::CssDeclaration>
+    for crate::css::auxiliary::declaration::FormatCssDeclaration
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssFontFeatureValuesAtRule,
+        node: &biome_css_syntax::CssDeclaration,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssFontFeatureValuesAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssDeclaration>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclaration {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssFontFeatureValuesAtRule,
-        crate::css::statements::font_feature_values_at_rule::FormatCssFontFeatureValuesAtRule,
+        biome_css_syntax::CssDeclaration,
+        crate::css::auxiliary::declaration::FormatCssDeclaration,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: statements :: font_feature_values_at_rule :: FormatCssFontFeatureValuesAtRule :: default ())
-    }
-}
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesAtRule {
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::declaration::FormatCssDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclaration {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssFontFeatureValuesAtRule,
-        crate::css::statements::font_feature_values_at_rule::FormatCssFontFeatureValuesAtRule,
+        biome_css_syntax::CssDeclaration,
+        crate::css::auxiliary::declaration::FormatCssDeclaration,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: statements :: font_feature_values_at_rule :: FormatCssFontFeatureValuesAtRule :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::declaration::FormatCssDeclaration::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssFontFeatureValuesBlock>
-    for crate::css::auxiliary::font_feature_values_block::FormatCssFontFeatureValuesBlock
+impl FormatRule<biome_css_syntax::CssDeclarationBlock>
+    for crate::css::auxiliary::declaration_block::FormatCssDeclarationBlock
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssFontFeatureValuesBlock,
+        node: &biome_css_syntax::CssDeclarationBlock,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssFontFeatureValuesBlock>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssDeclarationBlock>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesBlock {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationBlock {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssFontFeatureValuesBlock,
-        crate::css::auxiliary::font_feature_values_block::FormatCssFontFeatureValuesBlock,
+        biome_css_syntax::CssDeclarationBlock,
+        crate::css::auxiliary::declaration_block::FormatCssDeclarationBlock,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_block :: FormatCssFontFeatureValuesBlock :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::declaration_block::FormatCssDeclarationBlock::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesBlock {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationBlock {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssFontFeatureValuesBlock,
-        crate::css::auxiliary::font_feature_values_block::FormatCssFontFeatureValuesBlock,
+        biome_css_syntax::CssDeclarationBlock,
+        crate::css::auxiliary::declaration_block::FormatCssDeclarationBlock,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_block :: FormatCssFontFeatureValuesBlock :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::declaration_block::FormatCssDeclarationBlock::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssFontFeatureValuesItem>
-    for crate::css::auxiliary::font_feature_values_item::FormatCssFontFeatureValuesItem
+impl FormatRule<biome_css_syntax::CssDeclarationImportant>
+    for crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssFontFeatureValuesItem,
+        node: &biome_css_syntax::CssDeclarationImportant,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssFontFeatureValuesItem>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssDeclarationImportant>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesItem {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationImportant {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssFontFeatureValuesItem,
-        crate::css::auxiliary::font_feature_values_item::FormatCssFontFeatureValuesItem,
+        biome_css_syntax::CssDeclarationImportant,
+        crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_item :: FormatCssFontFeatureValuesItem :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesItem {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationImportant {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssFontFeatureValuesItem,
-        crate::css::auxiliary::font_feature_values_item::FormatCssFontFeatureValuesItem,
+        biome_css_syntax::CssDeclarationImportant,
+        crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_item :: FormatCssFontFeatureValuesItem :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssFontPaletteValuesAtRule>
-    for crate::css::statements::font_palette_values_at_rule::FormatCssFontPaletteValuesAtRule
+impl FormatRule<biome_css_syntax::CssDeclarationOrAtRuleBlock>
+    for crate::css::auxiliary::declaration_or_at_rule_block::FormatCssDeclarationOrAtRuleBlock
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssFontPaletteValuesAtRule,
+        node: &biome_css_syntax::CssDeclarationOrAtRuleBlock,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssFontPaletteValuesAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssDeclarationOrAtRuleBlock>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontPaletteValuesAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrAtRuleBlock {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssFontPaletteValuesAtRule,
-        crate::css::statements::font_palette_values_at_rule::FormatCssFontPaletteValuesAtRule,
+        biome_css_syntax::CssDeclarationOrAtRuleBlock,
+        crate::css::auxiliary::declaration_or_at_rule_block::FormatCssDeclarationOrAtRuleBlock,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: statements :: font_palette_values_at_rule :: FormatCssFontPaletteValuesAtRule :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: declaration_or_at_rule_block :: FormatCssDeclarationOrAtRuleBlock :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontPaletteValuesAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrAtRuleBlock {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssFontPaletteValuesAtRule,
-        crate::css::statements::font_palette_values_at_rule::FormatCssFontPaletteValuesAtRule,
+        biome_css_syntax::CssDeclarationOrAtRuleBlock,
+        crate::css::auxiliary::declaration_or_at_rule_block::FormatCssDeclarationOrAtRuleBlock,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: statements :: font_palette_values_at_rule :: FormatCssFontPaletteValuesAtRule :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: declaration_or_at_rule_block :: FormatCssDeclarationOrAtRuleBlock :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssFunction>
-    for crate::css::auxiliary::function::FormatCssFunction
+impl FormatRule<biome_css_syntax::CssDeclarationOrRuleBlock>
+    for crate::css::auxiliary::declaration_or_rule_block::FormatCssDeclarationOrRuleBlock
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssFunction, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssFunction>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssDeclarationOrRuleBlock,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssDeclarationOrRuleBlock>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssFunction {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrRuleBlock {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssFunction,
-        crate::css::auxiliary::function::FormatCssFunction,
+        biome_css_syntax::CssDeclarationOrRuleBlock,
+        crate::css::auxiliary::declaration_or_rule_block::FormatCssDeclarationOrRuleBlock,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::function::FormatCssFunction::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: declaration_or_rule_block :: FormatCssDeclarationOrRuleBlock :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFunction {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrRuleBlock {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssFunction,
-        crate::css::auxiliary::function::FormatCssFunction,
+        biome_css_syntax::CssDeclarationOrRuleBlock,
+        crate::css::auxiliary::declaration_or_rule_block::FormatCssDeclarationOrRuleBlock,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::function::FormatCssFunction::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: declaration_or_rule_block :: FormatCssDeclarationOrRuleBlock :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssGenericDelimiter>
-    for crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter
+impl FormatRule<biome_css_syntax::CssDeclarationWithSemicolon>
+    for crate::css::auxiliary::declaration_with_semicolon::FormatCssDeclarationWithSemicolon
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssGenericDelimiter,
+        node: &biome_css_syntax::CssDeclarationWithSemicolon,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssGenericDelimiter>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssDeclarationWithSemicolon>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssGenericDelimiter {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationWithSemicolon {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssGenericDelimiter,
-        crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter,
+        biome_css_syntax::CssDeclarationWithSemicolon,
+        crate::css::auxiliary::declaration_with_semicolon::FormatCssDeclarationWithSemicolon,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: declaration_with_semicolon :: FormatCssDeclarationWithSemicolon :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssGenericDelimiter {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationWithSemicolon {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssGenericDelimiter,
-        crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter,
+        biome_css_syntax::CssDeclarationWithSemicolon,
+        crate::css::auxiliary::declaration_with_semicolon::FormatCssDeclarationWithSemicolon,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: declaration_with_semicolon :: FormatCssDeclarationWithSemicolon :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssGenericProperty>
-    for crate::css::properties::generic_property::FormatCssGenericProperty
+impl FormatRule<biome_css_syntax::CssDocumentAtRule>
+    for crate::css::statements::document_at_rule::FormatCssDocumentAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssGenericProperty,
+        node: &biome_css_syntax::CssDocumentAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssGenericProperty>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssDocumentAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssGenericProperty {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDocumentAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssGenericProperty,
-        crate::css::properties::generic_property::FormatCssGenericProperty,
+        biome_css_syntax::CssDocumentAtRule,
+        crate::css::statements::document_at_rule::FormatCssDocumentAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::properties::generic_property::FormatCssGenericProperty::default(),
+            crate::css::statements::document_at_rule::FormatCssDocumentAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssGenericProperty {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDocumentAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssGenericProperty,
-        crate::css::properties::generic_property::FormatCssGenericProperty,
+        biome_css_syntax::CssDocumentAtRule,
+        crate::css::statements::document_at_rule::FormatCssDocumentAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::properties::generic_property::FormatCssGenericProperty::default(),
+            crate::css::statements::document_at_rule::FormatCssDocumentAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssIdSelector>
-    for crate::css::selectors::id_selector::FormatCssIdSelector
+impl FormatRule<biome_css_syntax::CssDocumentCustomMatcher>
+    for crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssIdSelector,
+        node: &biome_css_syntax::CssDocumentCustomMatcher,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssIdSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssDocumentCustomMatcher>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssIdSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDocumentCustomMatcher {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssIdSelector,
-        crate::css::selectors::id_selector::FormatCssIdSelector,
+        biome_css_syntax::CssDocumentCustomMatcher,
+        crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::selectors::id_selector::FormatCssIdSelector::default(),
+            crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher::default(
+            ),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssIdSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDocumentCustomMatcher {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssIdSelector,
-        crate::css::selectors::id_selector::FormatCssIdSelector,
+        biome_css_syntax::CssDocumentCustomMatcher,
+        crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::selectors::id_selector::FormatCssIdSelector::default(),
+            crate::css::auxiliary::document_custom_matcher::FormatCssDocumentCustomMatcher::default(
+            ),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssIdentifier>
-    for crate::css::value::identifier::FormatCssIdentifier
+impl FormatRule<biome_css_syntax::CssEmptyDeclaration>
+    for crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssIdentifier,
+        node: &biome_css_syntax::CssEmptyDeclaration,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssIdentifier>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssEmptyDeclaration>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssIdentifier {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssEmptyDeclaration {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssIdentifier,
-        crate::css::value::identifier::FormatCssIdentifier,
+        biome_css_syntax::CssEmptyDeclaration,
+        crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::value::identifier::FormatCssIdentifier::default(),
+            crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssIdentifier {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssEmptyDeclaration {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssIdentifier,
-        crate::css::value::identifier::FormatCssIdentifier,
+        biome_css_syntax::CssEmptyDeclaration,
+        crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::value::identifier::FormatCssIdentifier::default(),
+            crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssImportAnonymousLayer>
-    for crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer
+impl FormatRule<biome_css_syntax::CssFontFaceAtRule>
+    for crate::css::statements::font_face_at_rule::FormatCssFontFaceAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssImportAnonymousLayer,
+        node: &biome_css_syntax::CssFontFaceAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssImportAnonymousLayer>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssFontFaceAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportAnonymousLayer {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFaceAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssImportAnonymousLayer,
-        crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer,
+        biome_css_syntax::CssFontFaceAtRule,
+        crate::css::statements::font_face_at_rule::FormatCssFontFaceAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer::default(),
+            crate::css::statements::font_face_at_rule::FormatCssFontFaceAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportAnonymousLayer {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFaceAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssImportAnonymousLayer,
-        crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer,
+        biome_css_syntax::CssFontFaceAtRule,
+        crate::css::statements::font_face_at_rule::FormatCssFontFaceAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer::default(),
+            crate::css::statements::font_face_at_rule::FormatCssFontFaceAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssImportAtRule>
-    for crate::css::statements::import_at_rule::FormatCssImportAtRule
+impl FormatRule<biome_css_syntax::CssFontFamilyName>
+    for crate::css::auxiliary::font_family_name::FormatCssFontFamilyName
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssImportAtRule,
+        node: &biome_css_syntax::CssFontFamilyName,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssImportAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssFontFamilyName>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFamilyName {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssImportAtRule,
-        crate::css::statements::import_at_rule::FormatCssImportAtRule,
+        biome_css_syntax::CssFontFamilyName,
+        crate::css::auxiliary::font_family_name::FormatCssFontFamilyName,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::import_at_rule::FormatCssImportAtRule::default(),
+            crate::css::auxiliary::font_family_name::FormatCssFontFamilyName::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFamilyName {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssImportAtRule,
-        crate::css::statements::import_at_rule::FormatCssImportAtRule,
+        biome_css_syntax::CssFontFamilyName,
+        crate::css::auxiliary::font_family_name::FormatCssFontFamilyName,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::statements::import_at_rule::FormatCssImportAtRule::default(),
+            crate::css::auxiliary::font_family_name::FormatCssFontFamilyName::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssImportNamedLayer>
-    for crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer
+impl FormatRule<biome_css_syntax::CssFontFeatureValuesAtRule>
+    for crate::css::statements::font_feature_values_at_rule::FormatCssFontFeatureValuesAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssImportNamedLayer,
+        node: &biome_css_syntax::CssFontFeatureValuesAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssImportNamedLayer>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssFontFeatureValuesAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportNamedLayer {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssImportNamedLayer,
-        crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer,
+        biome_css_syntax::CssFontFeatureValuesAtRule,
+        crate::css::statements::font_feature_values_at_rule::FormatCssFontFeatureValuesAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: statements :: font_feature_values_at_rule :: FormatCssFontFeatureValuesAtRule :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportNamedLayer {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssImportNamedLayer,
-        crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer,
+        biome_css_syntax::CssFontFeatureValuesAtRule,
+        crate::css::statements::font_feature_values_at_rule::FormatCssFontFeatureValuesAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: statements :: font_feature_values_at_rule :: FormatCssFontFeatureValuesAtRule :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssImportSupports>
-    for crate::css::auxiliary::import_supports::FormatCssImportSupports
+impl FormatRule<biome_css_syntax::CssFontFeatureValuesBlock>
+    for crate::css::auxiliary::font_feature_values_block::FormatCssFontFeatureValuesBlock
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssImportSupports,
+        node: &biome_css_syntax::CssFontFeatureValuesBlock,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssImportSupports>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssFontFeatureValuesBlock>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportSupports {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesBlock {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssImportSupports,
-        crate::css::auxiliary::import_supports::FormatCssImportSupports,
+        biome_css_syntax::CssFontFeatureValuesBlock,
+        crate::css::auxiliary::font_feature_values_block::FormatCssFontFeatureValuesBlock,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::import_supports::FormatCssImportSupports::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_block :: FormatCssFontFeatureValuesBlock :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportSupports {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesBlock {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssImportSupports,
-        crate::css::auxiliary::import_supports::FormatCssImportSupports,
+        biome_css_syntax::CssFontFeatureValuesBlock,
+        crate::css::auxiliary::font_feature_values_block::FormatCssFontFeatureValuesBlock,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::import_supports::FormatCssImportSupports::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_block :: FormatCssFontFeatureValuesBlock :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssKeyframesAtRule>
-    for crate::css::statements::keyframes_at_rule::FormatCssKeyframesAtRule
+impl FormatRule<biome_css_syntax::CssFontFeatureValuesItem>
+    for crate::css::auxiliary::font_feature_values_item::FormatCssFontFeatureValuesItem
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssKeyframesAtRule,
+        node: &biome_css_syntax::CssFontFeatureValuesItem,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssKeyframesAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssFontFeatureValuesItem>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesItem {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesAtRule,
-        crate::css::statements::keyframes_at_rule::FormatCssKeyframesAtRule,
+        biome_css_syntax::CssFontFeatureValuesItem,
+        crate::css::auxiliary::font_feature_values_item::FormatCssFontFeatureValuesItem,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::statements::keyframes_at_rule::FormatCssKeyframesAtRule::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_item :: FormatCssFontFeatureValuesItem :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontFeatureValuesItem {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesAtRule,
-        crate::css::statements::keyframes_at_rule::FormatCssKeyframesAtRule,
+        biome_css_syntax::CssFontFeatureValuesItem,
+        crate::css::auxiliary::font_feature_values_item::FormatCssFontFeatureValuesItem,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::statements::keyframes_at_rule::FormatCssKeyframesAtRule::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: font_feature_values_item :: FormatCssFontFeatureValuesItem :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssKeyframesBlock>
-    for crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock
+impl FormatRule<biome_css_syntax::CssFontPaletteValuesAtRule>
+    for crate::css::statements::font_palette_values_at_rule::FormatCssFontPaletteValuesAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssKeyframesBlock,
+        node: &biome_css_syntax::CssFontPaletteValuesAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssKeyframesBlock>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssFontPaletteValuesAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesBlock {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssFontPaletteValuesAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesBlock,
-        crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock,
+        biome_css_syntax::CssFontPaletteValuesAtRule,
+        crate::css::statements::font_palette_values_at_rule::FormatCssFontPaletteValuesAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: statements :: font_palette_values_at_rule :: FormatCssFontPaletteValuesAtRule :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesBlock {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFontPaletteValuesAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesBlock,
-        crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock,
+        biome_css_syntax::CssFontPaletteValuesAtRule,
+        crate::css::statements::font_palette_values_at_rule::FormatCssFontPaletteValuesAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: statements :: font_palette_values_at_rule :: FormatCssFontPaletteValuesAtRule :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssKeyframesIdentSelector>
-    for crate::css::selectors::keyframes_ident_selector::FormatCssKeyframesIdentSelector
+impl FormatRule<biome_css_syntax::CssFunction>
+    for crate::css::auxiliary::function::FormatCssFunction
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(
-        &self,
-        node: &biome_css_syntax::CssKeyframesIdentSelector,
-        f: &mut CssFormatter,
-    ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssKeyframesIdentSelector>::fmt(self, node, f)
+    fn fmt(&self, node: &biome_css_syntax::CssFunction, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssFunction>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesIdentSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssFunction {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesIdentSelector,
-        crate::css::selectors::keyframes_ident_selector::FormatCssKeyframesIdentSelector,
+        biome_css_syntax::CssFunction,
+        crate::css::auxiliary::function::FormatCssFunction,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: selectors :: keyframes_ident_selector :: FormatCssKeyframesIdentSelector :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::function::FormatCssFunction::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesIdentSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssFunction {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesIdentSelector,
-        crate::css::selectors::keyframes_ident_selector::FormatCssKeyframesIdentSelector,
+        biome_css_syntax::CssFunction,
+        crate::css::auxiliary::function::FormatCssFunction,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: keyframes_ident_selector :: FormatCssKeyframesIdentSelector :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::function::FormatCssFunction::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssKeyframesItem>
-    for crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem
+impl FormatRule<biome_css_syntax::CssGenericDelimiter>
+    for crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssKeyframesItem,
+        node: &biome_css_syntax::CssGenericDelimiter,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssKeyframesItem>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssGenericDelimiter>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesItem {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssGenericDelimiter {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesItem,
-        crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem,
+        biome_css_syntax::CssGenericDelimiter,
+        crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem::default(),
+            crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesItem {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssGenericDelimiter {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesItem,
-        crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem,
+        biome_css_syntax::CssGenericDelimiter,
+        crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem::default(),
+            crate::css::auxiliary::generic_delimiter::FormatCssGenericDelimiter::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssKeyframesPercentageSelector>
-    for crate::css::selectors::keyframes_percentage_selector::FormatCssKeyframesPercentageSelector
+impl FormatRule<biome_css_syntax::CssGenericProperty>
+    for crate::css::properties::generic_property::FormatCssGenericProperty
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssKeyframesPercentageSelector,
+        node: &biome_css_syntax::CssGenericProperty,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssKeyframesPercentageSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssGenericProperty>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesPercentageSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssGenericProperty {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesPercentageSelector,
-        crate::css::selectors::keyframes_percentage_selector::FormatCssKeyframesPercentageSelector,
+        biome_css_syntax::CssGenericProperty,
+        crate::css::properties::generic_property::FormatCssGenericProperty,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: selectors :: keyframes_percentage_selector :: FormatCssKeyframesPercentageSelector :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::properties::generic_property::FormatCssGenericProperty::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesPercentageSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssGenericProperty {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesPercentageSelector,
-        crate::css::selectors::keyframes_percentage_selector::FormatCssKeyframesPercentageSelector,
+        biome_css_syntax::CssGenericProperty,
+        crate::css::properties::generic_property::FormatCssGenericProperty,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: keyframes_percentage_selector :: FormatCssKeyframesPercentageSelector :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::properties::generic_property::FormatCssGenericProperty::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssKeyframesScopeFunction>
-    for crate::css::auxiliary::keyframes_scope_function::FormatCssKeyframesScopeFunction
+impl FormatRule<biome_css_syntax::CssIdSelector>
+    for crate::css::selectors::id_selector::FormatCssIdSelector
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssKeyframesScopeFunction,
+        node: &biome_css_syntax::CssIdSelector,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssKeyframesScopeFunction>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssIdSelector>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesScopeFunction {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssIdSelector {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesScopeFunction,
-        crate::css::auxiliary::keyframes_scope_function::FormatCssKeyframesScopeFunction,
+        biome_css_syntax::CssIdSelector,
+        crate::css::selectors::id_selector::FormatCssIdSelector,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: keyframes_scope_function :: FormatCssKeyframesScopeFunction :: default ())
-    }
-}
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesScopeFunction {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::id_selector::FormatCssIdSelector::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssIdSelector {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesScopeFunction,
-        crate::css::auxiliary::keyframes_scope_function::FormatCssKeyframesScopeFunction,
+        biome_css_syntax::CssIdSelector,
+        crate::css::selectors::id_selector::FormatCssIdSelector,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: keyframes_scope_function :: FormatCssKeyframesScopeFunction :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::id_selector::FormatCssIdSelector::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssKeyframesScopePrefix>
-    for crate::css::auxiliary::keyframes_scope_prefix::FormatCssKeyframesScopePrefix
+impl FormatRule<biome_css_syntax::CssIdentifier>
+    for crate::css::value::identifier::FormatCssIdentifier
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssKeyframesScopePrefix,
+        node: &biome_css_syntax::CssIdentifier,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssKeyframesScopePrefix>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssIdentifier>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesScopePrefix {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssIdentifier {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesScopePrefix,
-        crate::css::auxiliary::keyframes_scope_prefix::FormatCssKeyframesScopePrefix,
+        biome_css_syntax::CssIdentifier,
+        crate::css::value::identifier::FormatCssIdentifier,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::keyframes_scope_prefix::FormatCssKeyframesScopePrefix::default(),
+            crate::css::value::identifier::FormatCssIdentifier::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesScopePrefix {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssIdentifier {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesScopePrefix,
-        crate::css::auxiliary::keyframes_scope_prefix::FormatCssKeyframesScopePrefix,
+        biome_css_syntax::CssIdentifier,
+        crate::css::value::identifier::FormatCssIdentifier,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::keyframes_scope_prefix::FormatCssKeyframesScopePrefix::default(),
+            crate::css::value::identifier::FormatCssIdentifier::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssKeyframesScopedName>
-    for crate::css::auxiliary::keyframes_scoped_name::FormatCssKeyframesScopedName
+impl FormatRule<biome_css_syntax::CssImportAnonymousLayer>
+    for crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssKeyframesScopedName,
+        node: &biome_css_syntax::CssImportAnonymousLayer,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssKeyframesScopedName>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssImportAnonymousLayer>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesScopedName {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportAnonymousLayer {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssKeyframesScopedName,
-        crate::css::auxiliary::keyframes_scoped_name::FormatCssKeyframesScopedName,
+        biome_css_syntax::CssImportAnonymousLayer,
+        crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::keyframes_scoped_name::FormatCssKeyframesScopedName::default(),
+            crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesScopedName {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportAnonymousLayer {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssKeyframesScopedName,
-        crate::css::auxiliary::keyframes_scoped_name::FormatCssKeyframesScopedName,
+        biome_css_syntax::CssImportAnonymousLayer,
+        crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::keyframes_scoped_name::FormatCssKeyframesScopedName::default(),
+            crate::css::auxiliary::import_anonymous_layer::FormatCssImportAnonymousLayer::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssLayerAtRule>
-    for crate::css::statements::layer_at_rule::FormatCssLayerAtRule
+impl FormatRule<biome_css_syntax::CssImportAtRule>
+    for crate::css::statements::import_at_rule::FormatCssImportAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssLayerAtRule,
+        node: &biome_css_syntax::CssImportAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssLayerAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssImportAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssLayerAtRule,
-        crate::css::statements::layer_at_rule::FormatCssLayerAtRule,
+        biome_css_syntax::CssImportAtRule,
+        crate::css::statements::import_at_rule::FormatCssImportAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::layer_at_rule::FormatCssLayerAtRule::default(),
+            crate::css::statements::import_at_rule::FormatCssImportAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssLayerAtRule,
-        crate::css::statements::layer_at_rule::FormatCssLayerAtRule,
+        biome_css_syntax::CssImportAtRule,
+        crate::css::statements::import_at_rule::FormatCssImportAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::statements::layer_at_rule::FormatCssLayerAtRule::default(),
+            crate::css::statements::import_at_rule::FormatCssImportAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssLayerDeclaration>
-    for crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration
+impl FormatRule<biome_css_syntax::CssImportNamedLayer>
+    for crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssLayerDeclaration,
+        node: &biome_css_syntax::CssImportNamedLayer,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssLayerDeclaration>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssImportNamedLayer>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerDeclaration {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportNamedLayer {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssLayerDeclaration,
-        crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration,
+        biome_css_syntax::CssImportNamedLayer,
+        crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration::default(),
+            crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerDeclaration {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportNamedLayer {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssLayerDeclaration,
-        crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration,
+        biome_css_syntax::CssImportNamedLayer,
+        crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration::default(),
+            crate::css::auxiliary::import_named_layer::FormatCssImportNamedLayer::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssLayerReference>
-    for crate::css::auxiliary::layer_reference::FormatCssLayerReference
+impl FormatRule<biome_css_syntax::CssImportSupports>
+    for crate::css::auxiliary::import_supports::FormatCssImportSupports
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssLayerReference,
+        node: &biome_css_syntax::CssImportSupports,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssLayerReference>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssImportSupports>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerReference {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssImportSupports {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssLayerReference,
-        crate::css::auxiliary::layer_reference::FormatCssLayerReference,
+        biome_css_syntax::CssImportSupports,
+        crate::css::auxiliary::import_supports::FormatCssImportSupports,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::layer_reference::FormatCssLayerReference::default(),
+            crate::css::auxiliary::import_supports::FormatCssImportSupports::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerReference {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssImportSupports {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssLayerReference,
-        crate::css::auxiliary::layer_reference::FormatCssLayerReference,
+        biome_css_syntax::CssImportSupports,
+        crate::css::auxiliary::import_supports::FormatCssImportSupports,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::layer_reference::FormatCssLayerReference::default(),
+            crate::css::auxiliary::import_supports::FormatCssImportSupports::default(),
         )
     }
 }
-impl FormatRule < biome_css_syntax :: CssListOfComponentValuesExpression > for crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssListOfComponentValuesExpression , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssListOfComponentValuesExpression > :: fmt (self , node , f) } }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssListOfComponentValuesExpression {
-    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssListOfComponentValuesExpression , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression > ;
-    fn format(&self) -> Self::Format<'_> {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression :: default ())
-    }
-}
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssListOfComponentValuesExpression {
-    type Format = FormatOwnedWithRule < biome_css_syntax :: CssListOfComponentValuesExpression , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression > ;
-    fn into_format(self) -> Self::Format {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression :: default ())
-    }
-}
-impl FormatRule<biome_css_syntax::CssMarginAtRule>
-    for crate::css::statements::margin_at_rule::FormatCssMarginAtRule
+impl FormatRule<biome_css_syntax::CssKeyframesAtRule>
+    for crate::css::statements::keyframes_at_rule::FormatCssKeyframesAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMarginAtRule,
+        node: &biome_css_syntax::CssKeyframesAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMarginAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssKeyframesAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMarginAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMarginAtRule,
-        crate::css::statements::margin_at_rule::FormatCssMarginAtRule,
+        biome_css_syntax::CssKeyframesAtRule,
+        crate::css::statements::keyframes_at_rule::FormatCssKeyframesAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::margin_at_rule::FormatCssMarginAtRule::default(),
+            crate::css::statements::keyframes_at_rule::FormatCssKeyframesAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMarginAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMarginAtRule,
-        crate::css::statements::margin_at_rule::FormatCssMarginAtRule,
+        biome_css_syntax::CssKeyframesAtRule,
+        crate::css::statements::keyframes_at_rule::FormatCssKeyframesAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::statements::margin_at_rule::FormatCssMarginAtRule::default(),
+            crate::css::statements::keyframes_at_rule::FormatCssKeyframesAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaAndCondition>
-    for crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition
+impl FormatRule<biome_css_syntax::CssKeyframesBlock>
+    for crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaAndCondition,
+        node: &biome_css_syntax::CssKeyframesBlock,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaAndCondition>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssKeyframesBlock>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaAndCondition {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesBlock {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaAndCondition,
-        crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition,
+        biome_css_syntax::CssKeyframesBlock,
+        crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition::default(),
+            crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaAndCondition {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesBlock {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaAndCondition,
-        crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition,
+        biome_css_syntax::CssKeyframesBlock,
+        crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition::default(),
+            crate::css::auxiliary::keyframes_block::FormatCssKeyframesBlock::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaAndTypeQuery>
-    for crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery
+impl FormatRule<biome_css_syntax::CssKeyframesIdentSelector>
+    for crate::css::selectors::keyframes_ident_selector::FormatCssKeyframesIdentSelector
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaAndTypeQuery,
+        node: &biome_css_syntax::CssKeyframesIdentSelector,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaAndTypeQuery>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssKeyframesIdentSelector>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaAndTypeQuery {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesIdentSelector {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaAndTypeQuery,
-        crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery,
+        biome_css_syntax::CssKeyframesIdentSelector,
+        crate::css::selectors::keyframes_ident_selector::FormatCssKeyframesIdentSelector,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: keyframes_ident_selector :: FormatCssKeyframesIdentSelector :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaAndTypeQuery {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesIdentSelector {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaAndTypeQuery,
-        crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery,
+        biome_css_syntax::CssKeyframesIdentSelector,
+        crate::css::selectors::keyframes_ident_selector::FormatCssKeyframesIdentSelector,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: keyframes_ident_selector :: FormatCssKeyframesIdentSelector :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaAtRule>
-    for crate::css::statements::media_at_rule::FormatCssMediaAtRule
+impl FormatRule<biome_css_syntax::CssKeyframesItem>
+    for crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaAtRule,
+        node: &biome_css_syntax::CssKeyframesItem,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssKeyframesItem>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesItem {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaAtRule,
-        crate::css::statements::media_at_rule::FormatCssMediaAtRule,
+        biome_css_syntax::CssKeyframesItem,
+        crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::media_at_rule::FormatCssMediaAtRule::default(),
+            crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesItem {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaAtRule,
-        crate::css::statements::media_at_rule::FormatCssMediaAtRule,
+        biome_css_syntax::CssKeyframesItem,
+        crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::statements::media_at_rule::FormatCssMediaAtRule::default(),
+            crate::css::auxiliary::keyframes_item::FormatCssKeyframesItem::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaConditionInParens>
-    for crate::css::auxiliary::media_condition_in_parens::FormatCssMediaConditionInParens
-{
+impl FormatRule<biome_css_syntax::CssKeyframesPercentageSelector>
+    for crate::css::selectors::keyframes_percentage_selector::FormatCssKeyframesPercentageSelector
+{
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaConditionInParens,
+        node: &biome_css_syntax::CssKeyframesPercentageSelector,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaConditionInParens>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssKeyframesPercentageSelector>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionInParens {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesPercentageSelector {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaConditionInParens,
-        crate::css::auxiliary::media_condition_in_parens::FormatCssMediaConditionInParens,
+        biome_css_syntax::CssKeyframesPercentageSelector,
+        crate::css::selectors::keyframes_percentage_selector::FormatCssKeyframesPercentageSelector,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: media_condition_in_parens :: FormatCssMediaConditionInParens :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: keyframes_percentage_selector :: FormatCssKeyframesPercentageSelector :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionInParens {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesPercentageSelector {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaConditionInParens,
-        crate::css::auxiliary::media_condition_in_parens::FormatCssMediaConditionInParens,
+        biome_css_syntax::CssKeyframesPercentageSelector,
+        crate::css::selectors::keyframes_percentage_selector::FormatCssKeyframesPercentageSelector,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: media_condition_in_parens :: FormatCssMediaConditionInParens :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: keyframes_percentage_selector :: FormatCssKeyframesPercentageSelector :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaConditionQuery>
-    for crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery
+impl FormatRule<biome_css_syntax::CssKeyframesScopeFunction>
+    for crate::css::auxiliary::keyframes_scope_function::FormatCssKeyframesScopeFunction
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaConditionQuery,
+        node: &biome_css_syntax::CssKeyframesScopeFunction,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaConditionQuery>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssKeyframesScopeFunction>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionQuery {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesScopeFunction {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaConditionQuery,
-        crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery,
+        biome_css_syntax::CssKeyframesScopeFunction,
+        crate::css::auxiliary::keyframes_scope_function::FormatCssKeyframesScopeFunction,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: keyframes_scope_function :: FormatCssKeyframesScopeFunction :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionQuery {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesScopeFunction {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaConditionQuery,
-        crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery,
+        biome_css_syntax::CssKeyframesScopeFunction,
+        crate::css::auxiliary::keyframes_scope_function::FormatCssKeyframesScopeFunction,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: keyframes_scope_function :: FormatCssKeyframesScopeFunction :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaFeatureInParens>
-    for crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens
+impl FormatRule<biome_css_syntax::CssKeyframesScopePrefix>
+    for crate::css::auxiliary::keyframes_scope_prefix::FormatCssKeyframesScopePrefix
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaFeatureInParens,
+        node: &biome_css_syntax::CssKeyframesScopePrefix,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaFeatureInParens>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssKeyframesScopePrefix>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaFeatureInParens {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesScopePrefix {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaFeatureInParens,
-        crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens,
+        biome_css_syntax::CssKeyframesScopePrefix,
+        crate::css::auxiliary::keyframes_scope_prefix::FormatCssKeyframesScopePrefix,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens::default(
-            ),
+            crate::css::auxiliary::keyframes_scope_prefix::FormatCssKeyframesScopePrefix::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaFeatureInParens {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesScopePrefix {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaFeatureInParens,
-        crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens,
+        biome_css_syntax::CssKeyframesScopePrefix,
+        crate::css::auxiliary::keyframes_scope_prefix::FormatCssKeyframesScopePrefix,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens::default(
-            ),
+            crate::css::auxiliary::keyframes_scope_prefix::FormatCssKeyframesScopePrefix::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaNotCondition>
-    for crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition
+impl FormatRule<biome_css_syntax::CssKeyframesScopedName>
+    for crate::css::auxiliary::keyframes_scoped_name::FormatCssKeyframesScopedName
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaNotCondition,
+        node: &biome_css_syntax::CssKeyframesScopedName,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaNotCondition>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssKeyframesScopedName>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaNotCondition {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssKeyframesScopedName {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaNotCondition,
-        crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition,
+        biome_css_syntax::CssKeyframesScopedName,
+        crate::css::auxiliary::keyframes_scoped_name::FormatCssKeyframesScopedName,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition::default(),
+            crate::css::auxiliary::keyframes_scoped_name::FormatCssKeyframesScopedName::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaNotCondition {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssKeyframesScopedName {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaNotCondition,
-        crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition,
+        biome_css_syntax::CssKeyframesScopedName,
+        crate::css::auxiliary::keyframes_scoped_name::FormatCssKeyframesScopedName,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition::default(),
+            crate::css::auxiliary::keyframes_scoped_name::FormatCssKeyframesScopedName::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaOrCondition>
-    for crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition
+impl FormatRule<biome_css_syntax::CssLayerAtRule>
+    for crate::css::statements::layer_at_rule::FormatCssLayerAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaOrCondition,
+        node: &biome_css_syntax::CssLayerAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaOrCondition>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssLayerAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaOrCondition {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaOrCondition,
-        crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition,
+        biome_css_syntax::CssLayerAtRule,
+        crate::css::statements::layer_at_rule::FormatCssLayerAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition::default(),
+            crate::css::statements::layer_at_rule::FormatCssLayerAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaOrCondition {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaOrCondition,
-        crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition,
+        biome_css_syntax::CssLayerAtRule,
+        crate::css::statements::layer_at_rule::FormatCssLayerAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition::default(),
+            crate::css::statements::layer_at_rule::FormatCssLayerAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaType>
-    for crate::css::auxiliary::media_type::FormatCssMediaType
+impl FormatRule<biome_css_syntax::CssLayerDeclaration>
+    for crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssMediaType, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaType>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssLayerDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssLayerDeclaration>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaType {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerDeclaration {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaType,
-        crate::css::auxiliary::media_type::FormatCssMediaType,
+        biome_css_syntax::CssLayerDeclaration,
+        crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::media_type::FormatCssMediaType::default(),
+            crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaType {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerDeclaration {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaType,
-        crate::css::auxiliary::media_type::FormatCssMediaType,
+        biome_css_syntax::CssLayerDeclaration,
+        crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::media_type::FormatCssMediaType::default(),
+            crate::css::auxiliary::layer_declaration::FormatCssLayerDeclaration::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssMediaTypeQuery>
-    for crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery
+impl FormatRule<biome_css_syntax::CssLayerReference>
+    for crate::css::auxiliary::layer_reference::FormatCssLayerReference
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMediaTypeQuery,
+        node: &biome_css_syntax::CssLayerReference,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMediaTypeQuery>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssLayerReference>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaTypeQuery {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssLayerReference {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMediaTypeQuery,
-        crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery,
+        biome_css_syntax::CssLayerReference,
+        crate::css::auxiliary::layer_reference::FormatCssLayerReference,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery::default(),
+            crate::css::auxiliary::layer_reference::FormatCssLayerReference::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaTypeQuery {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssLayerReference {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMediaTypeQuery,
-        crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery,
+        biome_css_syntax::CssLayerReference,
+        crate::css::auxiliary::layer_reference::FormatCssLayerReference,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery::default(),
+            crate::css::auxiliary::layer_reference::FormatCssLayerReference::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssMetavariable>
-    for crate::css::auxiliary::metavariable::FormatCssMetavariable
+impl FormatRule < biome_css_syntax :: CssListOfComponentValuesExpression > for crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssListOfComponentValuesExpression , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssListOfComponentValuesExpression > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssListOfComponentValuesExpression {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssListOfComponentValuesExpression , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssListOfComponentValuesExpression {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssListOfComponentValuesExpression , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: list_of_component_values_expression :: FormatCssListOfComponentValuesExpression :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssMarginAtRule>
+    for crate::css::statements::margin_at_rule::FormatCssMarginAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssMetavariable,
+        node: &biome_css_syntax::CssMarginAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssMetavariable>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMarginAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssMetavariable {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMarginAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssMetavariable,
-        crate::css::auxiliary::metavariable::FormatCssMetavariable,
+        biome_css_syntax::CssMarginAtRule,
+        crate::css::statements::margin_at_rule::FormatCssMarginAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::metavariable::FormatCssMetavariable::default(),
+            crate::css::statements::margin_at_rule::FormatCssMarginAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMetavariable {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMarginAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssMetavariable,
-        crate::css::auxiliary::metavariable::FormatCssMetavariable,
+        biome_css_syntax::CssMarginAtRule,
+        crate::css::statements::margin_at_rule::FormatCssMarginAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::metavariable::FormatCssMetavariable::default(),
+            crate::css::statements::margin_at_rule::FormatCssMarginAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssNamedNamespacePrefix>
-    for crate::css::auxiliary::named_namespace_prefix::FormatCssNamedNamespacePrefix
+impl FormatRule<biome_css_syntax::CssMediaAndCondition>
+    for crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssNamedNamespacePrefix,
+        node: &biome_css_syntax::CssMediaAndCondition,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssNamedNamespacePrefix>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaAndCondition>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssNamedNamespacePrefix {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaAndCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssNamedNamespacePrefix,
-        crate::css::auxiliary::named_namespace_prefix::FormatCssNamedNamespacePrefix,
+        biome_css_syntax::CssMediaAndCondition,
+        crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::named_namespace_prefix::FormatCssNamedNamespacePrefix::default(),
+            crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNamedNamespacePrefix {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaAndCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssNamedNamespacePrefix,
-        crate::css::auxiliary::named_namespace_prefix::FormatCssNamedNamespacePrefix,
+        biome_css_syntax::CssMediaAndCondition,
+        crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::named_namespace_prefix::FormatCssNamedNamespacePrefix::default(),
+            crate::css::auxiliary::media_and_condition::FormatCssMediaAndCondition::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssNamespace>
-    for crate::css::auxiliary::namespace::FormatCssNamespace
+impl FormatRule<biome_css_syntax::CssMediaAndTypeQuery>
+    for crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssNamespace, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssNamespace>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssMediaAndTypeQuery,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssMediaAndTypeQuery>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssNamespace {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaAndTypeQuery {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssNamespace,
-        crate::css::auxiliary::namespace::FormatCssNamespace,
+        biome_css_syntax::CssMediaAndTypeQuery,
+        crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::namespace::FormatCssNamespace::default(),
+            crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNamespace {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaAndTypeQuery {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssNamespace,
-        crate::css::auxiliary::namespace::FormatCssNamespace,
+        biome_css_syntax::CssMediaAndTypeQuery,
+        crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::namespace::FormatCssNamespace::default(),
+            crate::css::auxiliary::media_and_type_query::FormatCssMediaAndTypeQuery::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssNamespaceAtRule>
-    for crate::css::statements::namespace_at_rule::FormatCssNamespaceAtRule
+impl FormatRule<biome_css_syntax::CssMediaAtRule>
+    for crate::css::statements::media_at_rule::FormatCssMediaAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssNamespaceAtRule,
+        node: &biome_css_syntax::CssMediaAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssNamespaceAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssNamespaceAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssNamespaceAtRule,
-        crate::css::statements::namespace_at_rule::FormatCssNamespaceAtRule,
+        biome_css_syntax::CssMediaAtRule,
+        crate::css::statements::media_at_rule::FormatCssMediaAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::namespace_at_rule::FormatCssNamespaceAtRule::default(),
+            crate::css::statements::media_at_rule::FormatCssMediaAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNamespaceAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssNamespaceAtRule,
-        crate::css::statements::namespace_at_rule::FormatCssNamespaceAtRule,
+        biome_css_syntax::CssMediaAtRule,
+        crate::css::statements::media_at_rule::FormatCssMediaAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::statements::namespace_at_rule::FormatCssNamespaceAtRule::default(),
+            crate::css::statements::media_at_rule::FormatCssMediaAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssNestedQualifiedRule>
-    for crate::css::auxiliary::nested_qualified_rule::FormatCssNestedQualifiedRule
+impl FormatRule<biome_css_syntax::CssMediaConditionInParens>
+    for crate::css::auxiliary::media_condition_in_parens::FormatCssMediaConditionInParens
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssNestedQualifiedRule,
+        node: &biome_css_syntax::CssMediaConditionInParens,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssNestedQualifiedRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaConditionInParens>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssNestedQualifiedRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionInParens {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssNestedQualifiedRule,
-        crate::css::auxiliary::nested_qualified_rule::FormatCssNestedQualifiedRule,
+        biome_css_syntax::CssMediaConditionInParens,
+        crate::css::auxiliary::media_condition_in_parens::FormatCssMediaConditionInParens,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::nested_qualified_rule::FormatCssNestedQualifiedRule::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: media_condition_in_parens :: FormatCssMediaConditionInParens :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNestedQualifiedRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionInParens {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssNestedQualifiedRule,
-        crate::css::auxiliary::nested_qualified_rule::FormatCssNestedQualifiedRule,
+        biome_css_syntax::CssMediaConditionInParens,
+        crate::css::auxiliary::media_condition_in_parens::FormatCssMediaConditionInParens,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::nested_qualified_rule::FormatCssNestedQualifiedRule::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: media_condition_in_parens :: FormatCssMediaConditionInParens :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssNestedSelector>
-    for crate::css::selectors::nested_selector::FormatCssNestedSelector
+impl FormatRule<biome_css_syntax::CssMediaConditionQuery>
+    for crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssNestedSelector,
+        node: &biome_css_syntax::CssMediaConditionQuery,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssNestedSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaConditionQuery>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssNestedSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionQuery {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssNestedSelector,
-        crate::css::selectors::nested_selector::FormatCssNestedSelector,
+        biome_css_syntax::CssMediaConditionQuery,
+        crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::selectors::nested_selector::FormatCssNestedSelector::default(),
+            crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNestedSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaConditionQuery {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssNestedSelector,
-        crate::css::selectors::nested_selector::FormatCssNestedSelector,
+        biome_css_syntax::CssMediaConditionQuery,
+        crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::selectors::nested_selector::FormatCssNestedSelector::default(),
+            crate::css::auxiliary::media_condition_query::FormatCssMediaConditionQuery::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssNthOffset>
-    for crate::css::auxiliary::nth_offset::FormatCssNthOffset
+impl FormatRule<biome_css_syntax::CssMediaFeatureInParens>
+    for crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssNthOffset, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssNthOffset>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssMediaFeatureInParens,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssMediaFeatureInParens>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssNthOffset {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaFeatureInParens {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssNthOffset,
-        crate::css::auxiliary::nth_offset::FormatCssNthOffset,
+        biome_css_syntax::CssMediaFeatureInParens,
+        crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::nth_offset::FormatCssNthOffset::default(),
+            crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens::default(
+            ),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNthOffset {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaFeatureInParens {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssNthOffset,
-        crate::css::auxiliary::nth_offset::FormatCssNthOffset,
+        biome_css_syntax::CssMediaFeatureInParens,
+        crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::nth_offset::FormatCssNthOffset::default(),
+            crate::css::auxiliary::media_feature_in_parens::FormatCssMediaFeatureInParens::default(
+            ),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssNumber> for crate::css::value::number::FormatCssNumber {
+impl FormatRule<biome_css_syntax::CssMediaNotCondition>
+    for crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition
+{
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssNumber, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssNumber>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssMediaNotCondition,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssMediaNotCondition>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssNumber {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaNotCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssNumber,
-        crate::css::value::number::FormatCssNumber,
+        biome_css_syntax::CssMediaNotCondition,
+        crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(self, crate::css::value::number::FormatCssNumber::default())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNumber {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaNotCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssNumber,
-        crate::css::value::number::FormatCssNumber,
+        biome_css_syntax::CssMediaNotCondition,
+        crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(self, crate::css::value::number::FormatCssNumber::default())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::media_not_condition::FormatCssMediaNotCondition::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssPageAtRule>
-    for crate::css::statements::page_at_rule::FormatCssPageAtRule
+impl FormatRule<biome_css_syntax::CssMediaOrCondition>
+    for crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPageAtRule,
+        node: &biome_css_syntax::CssMediaOrCondition,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPageAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaOrCondition>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaOrCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPageAtRule,
-        crate::css::statements::page_at_rule::FormatCssPageAtRule,
+        biome_css_syntax::CssMediaOrCondition,
+        crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::page_at_rule::FormatCssPageAtRule::default(),
+            crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaOrCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPageAtRule,
-        crate::css::statements::page_at_rule::FormatCssPageAtRule,
+        biome_css_syntax::CssMediaOrCondition,
+        crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::statements::page_at_rule::FormatCssPageAtRule::default(),
+            crate::css::auxiliary::media_or_condition::FormatCssMediaOrCondition::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssPageAtRuleBlock>
-    for crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock
+impl FormatRule<biome_css_syntax::CssMediaType>
+    for crate::css::auxiliary::media_type::FormatCssMediaType
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(
-        &self,
-        node: &biome_css_syntax::CssPageAtRuleBlock,
-        f: &mut CssFormatter,
-    ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPageAtRuleBlock>::fmt(self, node, f)
+    fn fmt(&self, node: &biome_css_syntax::CssMediaType, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssMediaType>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageAtRuleBlock {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaType {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPageAtRuleBlock,
-        crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock,
+        biome_css_syntax::CssMediaType,
+        crate::css::auxiliary::media_type::FormatCssMediaType,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock::default(),
+            crate::css::auxiliary::media_type::FormatCssMediaType::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageAtRuleBlock {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaType {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPageAtRuleBlock,
-        crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock,
+        biome_css_syntax::CssMediaType,
+        crate::css::auxiliary::media_type::FormatCssMediaType,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock::default(),
+            crate::css::auxiliary::media_type::FormatCssMediaType::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssPageSelector>
-    for crate::css::selectors::page_selector::FormatCssPageSelector
+impl FormatRule<biome_css_syntax::CssMediaTypeQuery>
+    for crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPageSelector,
+        node: &biome_css_syntax::CssMediaTypeQuery,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPageSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMediaTypeQuery>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMediaTypeQuery {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPageSelector,
-        crate::css::selectors::page_selector::FormatCssPageSelector,
+        biome_css_syntax::CssMediaTypeQuery,
+        crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::selectors::page_selector::FormatCssPageSelector::default(),
+            crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMediaTypeQuery {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPageSelector,
-        crate::css::selectors::page_selector::FormatCssPageSelector,
+        biome_css_syntax::CssMediaTypeQuery,
+        crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::selectors::page_selector::FormatCssPageSelector::default(),
+            crate::css::auxiliary::media_type_query::FormatCssMediaTypeQuery::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssPageSelectorPseudo>
-    for crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo
+impl FormatRule<biome_css_syntax::CssMetavariable>
+    for crate::css::auxiliary::metavariable::FormatCssMetavariable
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPageSelectorPseudo,
+        node: &biome_css_syntax::CssMetavariable,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPageSelectorPseudo>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssMetavariable>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorPseudo {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssMetavariable {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPageSelectorPseudo,
-        crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo,
+        biome_css_syntax::CssMetavariable,
+        crate::css::auxiliary::metavariable::FormatCssMetavariable,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo::default(),
+            crate::css::auxiliary::metavariable::FormatCssMetavariable::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorPseudo {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssMetavariable {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPageSelectorPseudo,
-        crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo,
+        biome_css_syntax::CssMetavariable,
+        crate::css::auxiliary::metavariable::FormatCssMetavariable,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo::default(),
+            crate::css::auxiliary::metavariable::FormatCssMetavariable::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssParameter>
-    for crate::css::auxiliary::parameter::FormatCssParameter
+impl FormatRule<biome_css_syntax::CssNamedNamespacePrefix>
+    for crate::css::auxiliary::named_namespace_prefix::FormatCssNamedNamespacePrefix
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssParameter, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssParameter>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssNamedNamespacePrefix,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssNamedNamespacePrefix>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssParameter {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssNamedNamespacePrefix {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssParameter,
-        crate::css::auxiliary::parameter::FormatCssParameter,
+        biome_css_syntax::CssNamedNamespacePrefix,
+        crate::css::auxiliary::named_namespace_prefix::FormatCssNamedNamespacePrefix,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::parameter::FormatCssParameter::default(),
+            crate::css::auxiliary::named_namespace_prefix::FormatCssNamedNamespacePrefix::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssParameter {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNamedNamespacePrefix {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssParameter,
-        crate::css::auxiliary::parameter::FormatCssParameter,
+        biome_css_syntax::CssNamedNamespacePrefix,
+        crate::css::auxiliary::named_namespace_prefix::FormatCssNamedNamespacePrefix,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::parameter::FormatCssParameter::default(),
+            crate::css::auxiliary::named_namespace_prefix::FormatCssNamedNamespacePrefix::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssParenthesizedExpression>
-    for crate::css::auxiliary::parenthesized_expression::FormatCssParenthesizedExpression
+impl FormatRule<biome_css_syntax::CssNamespace>
+    for crate::css::auxiliary::namespace::FormatCssNamespace
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(
-        &self,
-        node: &biome_css_syntax::CssParenthesizedExpression,
-        f: &mut CssFormatter,
-    ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssParenthesizedExpression>::fmt(self, node, f)
+    fn fmt(&self, node: &biome_css_syntax::CssNamespace, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssNamespace>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssParenthesizedExpression {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssNamespace {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssParenthesizedExpression,
-        crate::css::auxiliary::parenthesized_expression::FormatCssParenthesizedExpression,
+        biome_css_syntax::CssNamespace,
+        crate::css::auxiliary::namespace::FormatCssNamespace,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: parenthesized_expression :: FormatCssParenthesizedExpression :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::namespace::FormatCssNamespace::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssParenthesizedExpression {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNamespace {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssParenthesizedExpression,
-        crate::css::auxiliary::parenthesized_expression::FormatCssParenthesizedExpression,
+        biome_css_syntax::CssNamespace,
+        crate::css::auxiliary::namespace::FormatCssNamespace,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: parenthesized_expression :: FormatCssParenthesizedExpression :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::namespace::FormatCssNamespace::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssPercentage>
-    for crate::css::value::percentage::FormatCssPercentage
+impl FormatRule<biome_css_syntax::CssNamespaceAtRule>
+    for crate::css::statements::namespace_at_rule::FormatCssNamespaceAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPercentage,
+        node: &biome_css_syntax::CssNamespaceAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPercentage>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssNamespaceAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPercentage {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssNamespaceAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPercentage,
-        crate::css::value::percentage::FormatCssPercentage,
+        biome_css_syntax::CssNamespaceAtRule,
+        crate::css::statements::namespace_at_rule::FormatCssNamespaceAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::value::percentage::FormatCssPercentage::default(),
+            crate::css::statements::namespace_at_rule::FormatCssNamespaceAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPercentage {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNamespaceAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPercentage,
-        crate::css::value::percentage::FormatCssPercentage,
+        biome_css_syntax::CssNamespaceAtRule,
+        crate::css::statements::namespace_at_rule::FormatCssNamespaceAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::value::percentage::FormatCssPercentage::default(),
+            crate::css::statements::namespace_at_rule::FormatCssNamespaceAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssPropertyAtRule>
-    for crate::css::statements::property_at_rule::FormatCssPropertyAtRule
+impl FormatRule<biome_css_syntax::CssNestedQualifiedRule>
+    for crate::css::auxiliary::nested_qualified_rule::FormatCssNestedQualifiedRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPropertyAtRule,
+        node: &biome_css_syntax::CssNestedQualifiedRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPropertyAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssNestedQualifiedRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPropertyAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssNestedQualifiedRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPropertyAtRule,
-        crate::css::statements::property_at_rule::FormatCssPropertyAtRule,
+        biome_css_syntax::CssNestedQualifiedRule,
+        crate::css::auxiliary::nested_qualified_rule::FormatCssNestedQualifiedRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::property_at_rule::FormatCssPropertyAtRule::default(),
+            crate::css::auxiliary::nested_qualified_rule::FormatCssNestedQualifiedRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPropertyAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNestedQualifiedRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPropertyAtRule,
-        crate::css::statements::property_at_rule::FormatCssPropertyAtRule,
+        biome_css_syntax::CssNestedQualifiedRule,
+        crate::css::auxiliary::nested_qualified_rule::FormatCssNestedQualifiedRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::statements::property_at_rule::FormatCssPropertyAtRule::default(),
+            crate::css::auxiliary::nested_qualified_rule::FormatCssNestedQualifiedRule::default(),
         )
     }
 }
-impl FormatRule < biome_css_syntax :: CssPseudoClassFunctionCompoundSelector > for crate :: css :: selectors :: pseudo_class_function_compound_selector :: FormatCssPseudoClassFunctionCompoundSelector { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoClassFunctionCompoundSelector , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoClassFunctionCompoundSelector > :: fmt (self , node , f) } }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionCompoundSelector {
-    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoClassFunctionCompoundSelector , crate :: css :: selectors :: pseudo_class_function_compound_selector :: FormatCssPseudoClassFunctionCompoundSelector > ;
-    fn format(&self) -> Self::Format<'_> {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_class_function_compound_selector :: FormatCssPseudoClassFunctionCompoundSelector :: default ())
-    }
-}
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionCompoundSelector {
-    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoClassFunctionCompoundSelector , crate :: css :: selectors :: pseudo_class_function_compound_selector :: FormatCssPseudoClassFunctionCompoundSelector > ;
-    fn into_format(self) -> Self::Format {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_class_function_compound_selector :: FormatCssPseudoClassFunctionCompoundSelector :: default ())
-    }
-}
-impl FormatRule < biome_css_syntax :: CssPseudoClassFunctionCompoundSelectorList > for crate :: css :: pseudo :: pseudo_class_function_compound_selector_list :: FormatCssPseudoClassFunctionCompoundSelectorList { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoClassFunctionCompoundSelectorList , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoClassFunctionCompoundSelectorList > :: fmt (self , node , f) } }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionCompoundSelectorList {
-    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoClassFunctionCompoundSelectorList , crate :: css :: pseudo :: pseudo_class_function_compound_selector_list :: FormatCssPseudoClassFunctionCompoundSelectorList > ;
-    fn format(&self) -> Self::Format<'_> {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_compound_selector_list :: FormatCssPseudoClassFunctionCompoundSelectorList :: default ())
-    }
-}
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionCompoundSelectorList {
-    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoClassFunctionCompoundSelectorList , crate :: css :: pseudo :: pseudo_class_function_compound_selector_list :: FormatCssPseudoClassFunctionCompoundSelectorList > ;
-    fn into_format(self) -> Self::Format {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_compound_selector_list :: FormatCssPseudoClassFunctionCompoundSelectorList :: default ())
-    }
-}
-impl FormatRule<biome_css_syntax::CssPseudoClassFunctionIdentifier>
-    for crate::css::pseudo::pseudo_class_function_identifier::FormatCssPseudoClassFunctionIdentifier
+impl FormatRule<biome_css_syntax::CssNestedSelector>
+    for crate::css::selectors::nested_selector::FormatCssNestedSelector
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPseudoClassFunctionIdentifier,
+        node: &biome_css_syntax::CssNestedSelector,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoClassFunctionIdentifier>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssNestedSelector>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionIdentifier {
-    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoClassFunctionIdentifier , crate :: css :: pseudo :: pseudo_class_function_identifier :: FormatCssPseudoClassFunctionIdentifier > ;
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssNestedSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssNestedSelector,
+        crate::css::selectors::nested_selector::FormatCssNestedSelector,
+    >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_identifier :: FormatCssPseudoClassFunctionIdentifier :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::nested_selector::FormatCssNestedSelector::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionIdentifier {
-    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoClassFunctionIdentifier , crate :: css :: pseudo :: pseudo_class_function_identifier :: FormatCssPseudoClassFunctionIdentifier > ;
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNestedSelector {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssNestedSelector,
+        crate::css::selectors::nested_selector::FormatCssNestedSelector,
+    >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_identifier :: FormatCssPseudoClassFunctionIdentifier :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::nested_selector::FormatCssNestedSelector::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssPseudoClassFunctionNth>
-    for crate::css::pseudo::pseudo_class_function_nth::FormatCssPseudoClassFunctionNth
+impl FormatRule<biome_css_syntax::CssNthOffset>
+    for crate::css::auxiliary::nth_offset::FormatCssNthOffset
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(
-        &self,
-        node: &biome_css_syntax::CssPseudoClassFunctionNth,
-        f: &mut CssFormatter,
-    ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoClassFunctionNth>::fmt(self, node, f)
+    fn fmt(&self, node: &biome_css_syntax::CssNthOffset, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssNthOffset>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionNth {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssNthOffset {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPseudoClassFunctionNth,
-        crate::css::pseudo::pseudo_class_function_nth::FormatCssPseudoClassFunctionNth,
+        biome_css_syntax::CssNthOffset,
+        crate::css::auxiliary::nth_offset::FormatCssNthOffset,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::pseudo::pseudo_class_function_nth::FormatCssPseudoClassFunctionNth::default(
-            ),
+            crate::css::auxiliary::nth_offset::FormatCssNthOffset::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionNth {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNthOffset {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoClassFunctionNth,
-        crate::css::pseudo::pseudo_class_function_nth::FormatCssPseudoClassFunctionNth,
+        biome_css_syntax::CssNthOffset,
+        crate::css::auxiliary::nth_offset::FormatCssNthOffset,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::pseudo::pseudo_class_function_nth::FormatCssPseudoClassFunctionNth::default(
-            ),
+            crate::css::auxiliary::nth_offset::FormatCssNthOffset::default(),
         )
     }
 }
-impl FormatRule < biome_css_syntax :: CssPseudoClassFunctionRelativeSelectorList > for crate :: css :: pseudo :: pseudo_class_function_relative_selector_list :: FormatCssPseudoClassFunctionRelativeSelectorList { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoClassFunctionRelativeSelectorList , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoClassFunctionRelativeSelectorList > :: fmt (self , node , f) } }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionRelativeSelectorList {
-    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoClassFunctionRelativeSelectorList , crate :: css :: pseudo :: pseudo_class_function_relative_selector_list :: FormatCssPseudoClassFunctionRelativeSelectorList > ;
+impl FormatRule<biome_css_syntax::CssNumber> for crate::css::value::number::FormatCssNumber {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::CssNumber, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssNumber>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssNumber {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssNumber,
+        crate::css::value::number::FormatCssNumber,
+    >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_relative_selector_list :: FormatCssPseudoClassFunctionRelativeSelectorList :: default ())
+        FormatRefWithRule::new(self, crate::css::value::number::FormatCssNumber::default())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionRelativeSelectorList {
-    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoClassFunctionRelativeSelectorList , crate :: css :: pseudo :: pseudo_class_function_relative_selector_list :: FormatCssPseudoClassFunctionRelativeSelectorList > ;
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssNumber {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssNumber,
+        crate::css::value::number::FormatCssNumber,
+    >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_relative_selector_list :: FormatCssPseudoClassFunctionRelativeSelectorList :: default ())
+        FormatOwnedWithRule::new(self, crate::css::value::number::FormatCssNumber::default())
     }
 }
-impl FormatRule<biome_css_syntax::CssPseudoClassFunctionSelector>
-    for crate::css::selectors::pseudo_class_function_selector::FormatCssPseudoClassFunctionSelector
+impl FormatRule<biome_css_syntax::CssPageAtRule>
+    for crate::css::statements::page_at_rule::FormatCssPageAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPseudoClassFunctionSelector,
+        node: &biome_css_syntax::CssPageAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoClassFunctionSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssPageAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPseudoClassFunctionSelector,
-        crate::css::selectors::pseudo_class_function_selector::FormatCssPseudoClassFunctionSelector,
+        biome_css_syntax::CssPageAtRule,
+        crate::css::statements::page_at_rule::FormatCssPageAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_class_function_selector :: FormatCssPseudoClassFunctionSelector :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::page_at_rule::FormatCssPageAtRule::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoClassFunctionSelector,
-        crate::css::selectors::pseudo_class_function_selector::FormatCssPseudoClassFunctionSelector,
+        biome_css_syntax::CssPageAtRule,
+        crate::css::statements::page_at_rule::FormatCssPageAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_class_function_selector :: FormatCssPseudoClassFunctionSelector :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::page_at_rule::FormatCssPageAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssPageAtRuleBlock>
+    for crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPageAtRuleBlock,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPageAtRuleBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageAtRuleBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPageAtRuleBlock,
+        crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageAtRuleBlock {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPageAtRuleBlock,
+        crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::page_at_rule_block::FormatCssPageAtRuleBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssPageSelector>
+    for crate::css::selectors::page_selector::FormatCssPageSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPageSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPageSelector>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPageSelector,
+        crate::css::selectors::page_selector::FormatCssPageSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::page_selector::FormatCssPageSelector::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageSelector {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPageSelector,
+        crate::css::selectors::page_selector::FormatCssPageSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::page_selector::FormatCssPageSelector::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssPageSelectorPseudo>
+    for crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPageSelectorPseudo,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPageSelectorPseudo>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorPseudo {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPageSelectorPseudo,
+        crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPageSelectorPseudo {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPageSelectorPseudo,
+        crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::pseudo::page_selector_pseudo::FormatCssPageSelectorPseudo::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssParameter>
+    for crate::css::auxiliary::parameter::FormatCssParameter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::CssParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssParameter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssParameter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssParameter,
+        crate::css::auxiliary::parameter::FormatCssParameter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::parameter::FormatCssParameter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssParameter {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssParameter,
+        crate::css::auxiliary::parameter::FormatCssParameter,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::parameter::FormatCssParameter::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssParenthesizedExpression>
+    for crate::css::auxiliary::parenthesized_expression::FormatCssParenthesizedExpression
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssParenthesizedExpression,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssParenthesizedExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssParenthesizedExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssParenthesizedExpression,
+        crate::css::auxiliary::parenthesized_expression::FormatCssParenthesizedExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: parenthesized_expression :: FormatCssParenthesizedExpression :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssParenthesizedExpression {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssParenthesizedExpression,
+        crate::css::auxiliary::parenthesized_expression::FormatCssParenthesizedExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: parenthesized_expression :: FormatCssParenthesizedExpression :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssPercentage>
+    for crate::css::value::percentage::FormatCssPercentage
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPercentage,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPercentage>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPercentage {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPercentage,
+        crate::css::value::percentage::FormatCssPercentage,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::percentage::FormatCssPercentage::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPercentage {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPercentage,
+        crate::css::value::percentage::FormatCssPercentage,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::percentage::FormatCssPercentage::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssPositionTryAtRule>
+    for crate::css::statements::position_try_at_rule::FormatCssPositionTryAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPositionTryAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPositionTryAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPositionTryAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPositionTryAtRule,
+        crate::css::statements::position_try_at_rule::FormatCssPositionTryAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::position_try_at_rule::FormatCssPositionTryAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPositionTryAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPositionTryAtRule,
+        crate::css::statements::position_try_at_rule::FormatCssPositionTryAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::position_try_at_rule::FormatCssPositionTryAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssPropertyAtRule>
+    for crate::css::statements::property_at_rule::FormatCssPropertyAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPropertyAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPropertyAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPropertyAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPropertyAtRule,
+        crate::css::statements::property_at_rule::FormatCssPropertyAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::property_at_rule::FormatCssPropertyAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPropertyAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPropertyAtRule,
+        crate::css::statements::property_at_rule::FormatCssPropertyAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::property_at_rule::FormatCssPropertyAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssPropertyAtRuleBlock>
+    for crate::css::auxiliary::property_at_rule_block::FormatCssPropertyAtRuleBlock
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPropertyAtRuleBlock,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPropertyAtRuleBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPropertyAtRuleBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPropertyAtRuleBlock,
+        crate::css::auxiliary::property_at_rule_block::FormatCssPropertyAtRuleBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::property_at_rule_block::FormatCssPropertyAtRuleBlock::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPropertyAtRuleBlock {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPropertyAtRuleBlock,
+        crate::css::auxiliary::property_at_rule_block::FormatCssPropertyAtRuleBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::property_at_rule_block::FormatCssPropertyAtRuleBlock::default(),
+        )
+    }
+}
+impl FormatRule < biome_css_syntax :: CssPseudoClassFunctionCompoundSelector > for crate :: css :: selectors :: pseudo_class_function_compound_selector :: FormatCssPseudoClassFunctionCompoundSelector { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoClassFunctionCompoundSelector , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoClassFunctionCompoundSelector > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionCompoundSelector {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoClassFunctionCompoundSelector , crate :: css :: selectors :: pseudo_class_function_compound_selector :: FormatCssPseudoClassFunctionCompoundSelector > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_class_function_compound_selector :: FormatCssPseudoClassFunctionCompoundSelector :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionCompoundSelector {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoClassFunctionCompoundSelector , crate :: css :: selectors :: pseudo_class_function_compound_selector :: FormatCssPseudoClassFunctionCompoundSelector > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_class_function_compound_selector :: FormatCssPseudoClassFunctionCompoundSelector :: default ())
+    }
+}
+impl FormatRule < biome_css_syntax :: CssPseudoClassFunctionCompoundSelectorList > for crate :: css :: pseudo :: pseudo_class_function_compound_selector_list :: FormatCssPseudoClassFunctionCompoundSelectorList { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoClassFunctionCompoundSelectorList , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoClassFunctionCompoundSelectorList > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionCompoundSelectorList {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoClassFunctionCompoundSelectorList , crate :: css :: pseudo :: pseudo_class_function_compound_selector_list :: FormatCssPseudoClassFunctionCompoundSelectorList > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_compound_selector_list :: FormatCssPseudoClassFunctionCompoundSelectorList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionCompoundSelectorList {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoClassFunctionCompoundSelectorList , crate :: css :: pseudo :: pseudo_class_function_compound_selector_list :: FormatCssPseudoClassFunctionCompoundSelectorList > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_compound_selector_list :: FormatCssPseudoClassFunctionCompoundSelectorList :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssPseudoClassFunctionIdentifier>
+    for crate::css::pseudo::pseudo_class_function_identifier::FormatCssPseudoClassFunctionIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPseudoClassFunctionIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPseudoClassFunctionIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionIdentifier {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoClassFunctionIdentifier , crate :: css :: pseudo :: pseudo_class_function_identifier :: FormatCssPseudoClassFunctionIdentifier > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_identifier :: FormatCssPseudoClassFunctionIdentifier :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionIdentifier {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoClassFunctionIdentifier , crate :: css :: pseudo :: pseudo_class_function_identifier :: FormatCssPseudoClassFunctionIdentifier > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_identifier :: FormatCssPseudoClassFunctionIdentifier :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssPseudoClassFunctionNth>
+    for crate::css::pseudo::pseudo_class_function_nth::FormatCssPseudoClassFunctionNth
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPseudoClassFunctionNth,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPseudoClassFunctionNth>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionNth {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPseudoClassFunctionNth,
+        crate::css::pseudo::pseudo_class_function_nth::FormatCssPseudoClassFunctionNth,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::pseudo::pseudo_class_function_nth::FormatCssPseudoClassFunctionNth::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionNth {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPseudoClassFunctionNth,
+        crate::css::pseudo::pseudo_class_function_nth::FormatCssPseudoClassFunctionNth,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::pseudo::pseudo_class_function_nth::FormatCssPseudoClassFunctionNth::default(
+            ),
+        )
+    }
+}
+impl FormatRule < biome_css_syntax :: CssPseudoClassFunctionRelativeSelectorList > for crate :: css :: pseudo :: pseudo_class_function_relative_selector_list :: FormatCssPseudoClassFunctionRelativeSelectorList { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoClassFunctionRelativeSelectorList , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoClassFunctionRelativeSelectorList > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionRelativeSelectorList {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoClassFunctionRelativeSelectorList , crate :: css :: pseudo :: pseudo_class_function_relative_selector_list :: FormatCssPseudoClassFunctionRelativeSelectorList > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_relative_selector_list :: FormatCssPseudoClassFunctionRelativeSelectorList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionRelativeSelectorList {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoClassFunctionRelativeSelectorList , crate :: css :: pseudo :: pseudo_class_function_relative_selector_list :: FormatCssPseudoClassFunctionRelativeSelectorList > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_relative_selector_list :: FormatCssPseudoClassFunctionRelativeSelectorList :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssPseudoClassFunctionSelector>
+    for crate::css::selectors::pseudo_class_function_selector::FormatCssPseudoClassFunctionSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPseudoClassFunctionSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPseudoClassFunctionSelector>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPseudoClassFunctionSelector,
+        crate::css::selectors::pseudo_class_function_selector::FormatCssPseudoClassFunctionSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_class_function_selector :: FormatCssPseudoClassFunctionSelector :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionSelector {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPseudoClassFunctionSelector,
+        crate::css::selectors::pseudo_class_function_selector::FormatCssPseudoClassFunctionSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_class_function_selector :: FormatCssPseudoClassFunctionSelector :: default ())
+    }
+}
+impl FormatRule < biome_css_syntax :: CssPseudoClassFunctionSelectorList > for crate :: css :: pseudo :: pseudo_class_function_selector_list :: FormatCssPseudoClassFunctionSelectorList { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoClassFunctionSelectorList , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoClassFunctionSelectorList > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionSelectorList {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoClassFunctionSelectorList , crate :: css :: pseudo :: pseudo_class_function_selector_list :: FormatCssPseudoClassFunctionSelectorList > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_selector_list :: FormatCssPseudoClassFunctionSelectorList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionSelectorList {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoClassFunctionSelectorList , crate :: css :: pseudo :: pseudo_class_function_selector_list :: FormatCssPseudoClassFunctionSelectorList > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_selector_list :: FormatCssPseudoClassFunctionSelectorList :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssPseudoClassFunctionValueList>
+    for crate::css::pseudo::pseudo_class_function_value_list::FormatCssPseudoClassFunctionValueList
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPseudoClassFunctionValueList,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPseudoClassFunctionValueList>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionValueList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPseudoClassFunctionValueList,
+        crate::css::pseudo::pseudo_class_function_value_list::FormatCssPseudoClassFunctionValueList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_value_list :: FormatCssPseudoClassFunctionValueList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionValueList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPseudoClassFunctionValueList,
+        crate::css::pseudo::pseudo_class_function_value_list::FormatCssPseudoClassFunctionValueList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_value_list :: FormatCssPseudoClassFunctionValueList :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssPseudoClassIdentifier>
+    for crate::css::pseudo::pseudo_class_identifier::FormatCssPseudoClassIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPseudoClassIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPseudoClassIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPseudoClassIdentifier,
+        crate::css::pseudo::pseudo_class_identifier::FormatCssPseudoClassIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::pseudo::pseudo_class_identifier::FormatCssPseudoClassIdentifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPseudoClassIdentifier,
+        crate::css::pseudo::pseudo_class_identifier::FormatCssPseudoClassIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::pseudo::pseudo_class_identifier::FormatCssPseudoClassIdentifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssPseudoClassNth>
+    for crate::css::pseudo::pseudo_class_nth::FormatCssPseudoClassNth
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPseudoClassNth,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPseudoClassNth>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNth {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPseudoClassNth,
+        crate::css::pseudo::pseudo_class_nth::FormatCssPseudoClassNth,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::pseudo::pseudo_class_nth::FormatCssPseudoClassNth::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNth {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPseudoClassNth,
+        crate::css::pseudo::pseudo_class_nth::FormatCssPseudoClassNth,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::pseudo::pseudo_class_nth::FormatCssPseudoClassNth::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssPseudoClassNthIdentifier>
+    for crate::css::pseudo::pseudo_class_nth_identifier::FormatCssPseudoClassNthIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPseudoClassNthIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPseudoClassNthIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNthIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPseudoClassNthIdentifier,
+        crate::css::pseudo::pseudo_class_nth_identifier::FormatCssPseudoClassNthIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_nth_identifier :: FormatCssPseudoClassNthIdentifier :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNthIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPseudoClassNthIdentifier,
+        crate::css::pseudo::pseudo_class_nth_identifier::FormatCssPseudoClassNthIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_nth_identifier :: FormatCssPseudoClassNthIdentifier :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssPseudoClassNthNumber>
+    for crate::css::pseudo::pseudo_class_nth_number::FormatCssPseudoClassNthNumber
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPseudoClassNthNumber,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPseudoClassNthNumber>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNthNumber {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPseudoClassNthNumber,
+        crate::css::pseudo::pseudo_class_nth_number::FormatCssPseudoClassNthNumber,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::pseudo::pseudo_class_nth_number::FormatCssPseudoClassNthNumber::default(),
+        )
     }
 }
-impl FormatRule < biome_css_syntax :: CssPseudoClassFunctionSelectorList > for crate :: css :: pseudo :: pseudo_class_function_selector_list :: FormatCssPseudoClassFunctionSelectorList { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoClassFunctionSelectorList , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoClassFunctionSelectorList > :: fmt (self , node , f) } }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionSelectorList {
-    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoClassFunctionSelectorList , crate :: css :: pseudo :: pseudo_class_function_selector_list :: FormatCssPseudoClassFunctionSelectorList > ;
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNthNumber {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPseudoClassNthNumber,
+        crate::css::pseudo::pseudo_class_nth_number::FormatCssPseudoClassNthNumber,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::pseudo::pseudo_class_nth_number::FormatCssPseudoClassNthNumber::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssPseudoClassNthSelector>
+    for crate::css::selectors::pseudo_class_nth_selector::FormatCssPseudoClassNthSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPseudoClassNthSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPseudoClassNthSelector>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNthSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPseudoClassNthSelector,
+        crate::css::selectors::pseudo_class_nth_selector::FormatCssPseudoClassNthSelector,
+    >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_selector_list :: FormatCssPseudoClassFunctionSelectorList :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_class_nth_selector :: FormatCssPseudoClassNthSelector :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionSelectorList {
-    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoClassFunctionSelectorList , crate :: css :: pseudo :: pseudo_class_function_selector_list :: FormatCssPseudoClassFunctionSelectorList > ;
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNthSelector {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPseudoClassNthSelector,
+        crate::css::selectors::pseudo_class_nth_selector::FormatCssPseudoClassNthSelector,
+    >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_selector_list :: FormatCssPseudoClassFunctionSelectorList :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_class_nth_selector :: FormatCssPseudoClassNthSelector :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssPseudoClassFunctionValueList>
-    for crate::css::pseudo::pseudo_class_function_value_list::FormatCssPseudoClassFunctionValueList
+impl FormatRule<biome_css_syntax::CssPseudoClassOfNthSelector>
+    for crate::css::selectors::pseudo_class_of_nth_selector::FormatCssPseudoClassOfNthSelector
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPseudoClassFunctionValueList,
+        node: &biome_css_syntax::CssPseudoClassOfNthSelector,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoClassFunctionValueList>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssPseudoClassOfNthSelector>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionValueList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassOfNthSelector {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPseudoClassFunctionValueList,
-        crate::css::pseudo::pseudo_class_function_value_list::FormatCssPseudoClassFunctionValueList,
+        biome_css_syntax::CssPseudoClassOfNthSelector,
+        crate::css::selectors::pseudo_class_of_nth_selector::FormatCssPseudoClassOfNthSelector,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_value_list :: FormatCssPseudoClassFunctionValueList :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_class_of_nth_selector :: FormatCssPseudoClassOfNthSelector :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassFunctionValueList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassOfNthSelector {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoClassFunctionValueList,
-        crate::css::pseudo::pseudo_class_function_value_list::FormatCssPseudoClassFunctionValueList,
+        biome_css_syntax::CssPseudoClassOfNthSelector,
+        crate::css::selectors::pseudo_class_of_nth_selector::FormatCssPseudoClassOfNthSelector,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_function_value_list :: FormatCssPseudoClassFunctionValueList :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_class_of_nth_selector :: FormatCssPseudoClassOfNthSelector :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssPseudoClassIdentifier>
-    for crate::css::pseudo::pseudo_class_identifier::FormatCssPseudoClassIdentifier
+impl FormatRule<biome_css_syntax::CssPseudoClassSelector>
+    for crate::css::selectors::pseudo_class_selector::FormatCssPseudoClassSelector
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPseudoClassIdentifier,
+        node: &biome_css_syntax::CssPseudoClassSelector,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoClassIdentifier>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssPseudoClassSelector>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassIdentifier {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassSelector {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPseudoClassIdentifier,
-        crate::css::pseudo::pseudo_class_identifier::FormatCssPseudoClassIdentifier,
+        biome_css_syntax::CssPseudoClassSelector,
+        crate::css::selectors::pseudo_class_selector::FormatCssPseudoClassSelector,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::pseudo::pseudo_class_identifier::FormatCssPseudoClassIdentifier::default(),
+            crate::css::selectors::pseudo_class_selector::FormatCssPseudoClassSelector::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassIdentifier {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassSelector {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoClassIdentifier,
-        crate::css::pseudo::pseudo_class_identifier::FormatCssPseudoClassIdentifier,
+        biome_css_syntax::CssPseudoClassSelector,
+        crate::css::selectors::pseudo_class_selector::FormatCssPseudoClassSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::pseudo_class_selector::FormatCssPseudoClassSelector::default(),
+        )
+    }
+}
+impl FormatRule < biome_css_syntax :: CssPseudoElementFunctionCompoundSelector > for crate :: css :: selectors :: pseudo_element_function_compound_selector :: FormatCssPseudoElementFunctionCompoundSelector { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoElementFunctionCompoundSelector , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoElementFunctionCompoundSelector > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementFunctionCompoundSelector {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoElementFunctionCompoundSelector , crate :: css :: selectors :: pseudo_element_function_compound_selector :: FormatCssPseudoElementFunctionCompoundSelector > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_element_function_compound_selector :: FormatCssPseudoElementFunctionCompoundSelector :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementFunctionCompoundSelector {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoElementFunctionCompoundSelector , crate :: css :: selectors :: pseudo_element_function_compound_selector :: FormatCssPseudoElementFunctionCompoundSelector > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_element_function_compound_selector :: FormatCssPseudoElementFunctionCompoundSelector :: default ())
+    }
+}
+impl FormatRule < biome_css_syntax :: CssPseudoElementFunctionCustomIdentifierList > for crate :: css :: pseudo :: pseudo_element_function_custom_identifier_list :: FormatCssPseudoElementFunctionCustomIdentifierList { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoElementFunctionCustomIdentifierList , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoElementFunctionCustomIdentifierList > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementFunctionCustomIdentifierList {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoElementFunctionCustomIdentifierList , crate :: css :: pseudo :: pseudo_element_function_custom_identifier_list :: FormatCssPseudoElementFunctionCustomIdentifierList > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_element_function_custom_identifier_list :: FormatCssPseudoElementFunctionCustomIdentifierList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext>
+    for biome_css_syntax::CssPseudoElementFunctionCustomIdentifierList
+{
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoElementFunctionCustomIdentifierList , crate :: css :: pseudo :: pseudo_element_function_custom_identifier_list :: FormatCssPseudoElementFunctionCustomIdentifierList > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_element_function_custom_identifier_list :: FormatCssPseudoElementFunctionCustomIdentifierList :: default ())
+    }
+}
+impl FormatRule < biome_css_syntax :: CssPseudoElementFunctionIdentifier > for crate :: css :: pseudo :: pseudo_element_function_identifier :: FormatCssPseudoElementFunctionIdentifier { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoElementFunctionIdentifier , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoElementFunctionIdentifier > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementFunctionIdentifier {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoElementFunctionIdentifier , crate :: css :: pseudo :: pseudo_element_function_identifier :: FormatCssPseudoElementFunctionIdentifier > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_element_function_identifier :: FormatCssPseudoElementFunctionIdentifier :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementFunctionIdentifier {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoElementFunctionIdentifier , crate :: css :: pseudo :: pseudo_element_function_identifier :: FormatCssPseudoElementFunctionIdentifier > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_element_function_identifier :: FormatCssPseudoElementFunctionIdentifier :: default ())
+    }
+}
+impl FormatRule < biome_css_syntax :: CssPseudoElementFunctionSelector > for crate :: css :: selectors :: pseudo_element_function_selector :: FormatCssPseudoElementFunctionSelector { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoElementFunctionSelector , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoElementFunctionSelector > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementFunctionSelector {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoElementFunctionSelector , crate :: css :: selectors :: pseudo_element_function_selector :: FormatCssPseudoElementFunctionSelector > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_element_function_selector :: FormatCssPseudoElementFunctionSelector :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementFunctionSelector {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoElementFunctionSelector , crate :: css :: selectors :: pseudo_element_function_selector :: FormatCssPseudoElementFunctionSelector > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_element_function_selector :: FormatCssPseudoElementFunctionSelector :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssPseudoElementIdentifier>
+    for crate::css::pseudo::pseudo_element_identifier::FormatCssPseudoElementIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPseudoElementIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPseudoElementIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPseudoElementIdentifier,
+        crate::css::pseudo::pseudo_element_identifier::FormatCssPseudoElementIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_element_identifier :: FormatCssPseudoElementIdentifier :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPseudoElementIdentifier,
+        crate::css::pseudo::pseudo_element_identifier::FormatCssPseudoElementIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_element_identifier :: FormatCssPseudoElementIdentifier :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::CssPseudoElementSelector>
+    for crate::css::selectors::pseudo_element_selector::FormatCssPseudoElementSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssPseudoElementSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssPseudoElementSelector>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssPseudoElementSelector,
+        crate::css::selectors::pseudo_element_selector::FormatCssPseudoElementSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::pseudo_element_selector::FormatCssPseudoElementSelector::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementSelector {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssPseudoElementSelector,
+        crate::css::selectors::pseudo_element_selector::FormatCssPseudoElementSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::pseudo_element_selector::FormatCssPseudoElementSelector::default(
+            ),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::CssQualifiedRule>
+    for crate::css::auxiliary::qualified_rule::FormatCssQualifiedRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssQualifiedRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssQualifiedRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQualifiedRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssQualifiedRule,
+        crate::css::auxiliary::qualified_rule::FormatCssQualifiedRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::qualified_rule::FormatCssQualifiedRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQualifiedRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssQualifiedRule,
+        crate::css::auxiliary::qualified_rule::FormatCssQualifiedRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::pseudo::pseudo_class_identifier::FormatCssPseudoClassIdentifier::default(),
+            crate::css::auxiliary::qualified_rule::FormatCssQualifiedRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssPseudoClassNth>
-    for crate::css::pseudo::pseudo_class_nth::FormatCssPseudoClassNth
+impl FormatRule<biome_css_syntax::CssQueryFeatureBoolean>
+    for crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPseudoClassNth,
+        node: &biome_css_syntax::CssQueryFeatureBoolean,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoClassNth>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssQueryFeatureBoolean>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNth {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureBoolean {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPseudoClassNth,
-        crate::css::pseudo::pseudo_class_nth::FormatCssPseudoClassNth,
+        biome_css_syntax::CssQueryFeatureBoolean,
+        crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::pseudo::pseudo_class_nth::FormatCssPseudoClassNth::default(),
+            crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNth {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureBoolean {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoClassNth,
-        crate::css::pseudo::pseudo_class_nth::FormatCssPseudoClassNth,
+        biome_css_syntax::CssQueryFeatureBoolean,
+        crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::pseudo::pseudo_class_nth::FormatCssPseudoClassNth::default(),
+            crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssPseudoClassNthIdentifier>
-    for crate::css::pseudo::pseudo_class_nth_identifier::FormatCssPseudoClassNthIdentifier
+impl FormatRule<biome_css_syntax::CssQueryFeaturePlain>
+    for crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPseudoClassNthIdentifier,
+        node: &biome_css_syntax::CssQueryFeaturePlain,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoClassNthIdentifier>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssQueryFeaturePlain>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNthIdentifier {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeaturePlain {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPseudoClassNthIdentifier,
-        crate::css::pseudo::pseudo_class_nth_identifier::FormatCssPseudoClassNthIdentifier,
+        biome_css_syntax::CssQueryFeaturePlain,
+        crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_nth_identifier :: FormatCssPseudoClassNthIdentifier :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNthIdentifier {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeaturePlain {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoClassNthIdentifier,
-        crate::css::pseudo::pseudo_class_nth_identifier::FormatCssPseudoClassNthIdentifier,
+        biome_css_syntax::CssQueryFeaturePlain,
+        crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_class_nth_identifier :: FormatCssPseudoClassNthIdentifier :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssPseudoClassNthNumber>
-    for crate::css::pseudo::pseudo_class_nth_number::FormatCssPseudoClassNthNumber
+impl FormatRule<biome_css_syntax::CssQueryFeatureRange>
+    for crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPseudoClassNthNumber,
+        node: &biome_css_syntax::CssQueryFeatureRange,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoClassNthNumber>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssQueryFeatureRange>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNthNumber {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRange {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPseudoClassNthNumber,
-        crate::css::pseudo::pseudo_class_nth_number::FormatCssPseudoClassNthNumber,
+        biome_css_syntax::CssQueryFeatureRange,
+        crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::pseudo::pseudo_class_nth_number::FormatCssPseudoClassNthNumber::default(),
+            crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNthNumber {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRange {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoClassNthNumber,
-        crate::css::pseudo::pseudo_class_nth_number::FormatCssPseudoClassNthNumber,
+        biome_css_syntax::CssQueryFeatureRange,
+        crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::pseudo::pseudo_class_nth_number::FormatCssPseudoClassNthNumber::default(),
+            crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssPseudoClassNthSelector>
-    for crate::css::selectors::pseudo_class_nth_selector::FormatCssPseudoClassNthSelector
+impl FormatRule<biome_css_syntax::CssQueryFeatureRangeComparison>
+    for crate::css::auxiliary::query_feature_range_comparison::FormatCssQueryFeatureRangeComparison
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPseudoClassNthSelector,
+        node: &biome_css_syntax::CssQueryFeatureRangeComparison,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoClassNthSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssQueryFeatureRangeComparison>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNthSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeComparison {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPseudoClassNthSelector,
-        crate::css::selectors::pseudo_class_nth_selector::FormatCssPseudoClassNthSelector,
+        biome_css_syntax::CssQueryFeatureRangeComparison,
+        crate::css::auxiliary::query_feature_range_comparison::FormatCssQueryFeatureRangeComparison,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_class_nth_selector :: FormatCssPseudoClassNthSelector :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_comparison :: FormatCssQueryFeatureRangeComparison :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassNthSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeComparison {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoClassNthSelector,
-        crate::css::selectors::pseudo_class_nth_selector::FormatCssPseudoClassNthSelector,
+        biome_css_syntax::CssQueryFeatureRangeComparison,
+        crate::css::auxiliary::query_feature_range_comparison::FormatCssQueryFeatureRangeComparison,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_class_nth_selector :: FormatCssPseudoClassNthSelector :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_comparison :: FormatCssQueryFeatureRangeComparison :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssPseudoClassOfNthSelector>
-    for crate::css::selectors::pseudo_class_of_nth_selector::FormatCssPseudoClassOfNthSelector
+impl FormatRule<biome_css_syntax::CssQueryFeatureRangeInterval>
+    for crate::css::auxiliary::query_feature_range_interval::FormatCssQueryFeatureRangeInterval
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPseudoClassOfNthSelector,
+        node: &biome_css_syntax::CssQueryFeatureRangeInterval,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoClassOfNthSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssQueryFeatureRangeInterval>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassOfNthSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeInterval {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPseudoClassOfNthSelector,
-        crate::css::selectors::pseudo_class_of_nth_selector::FormatCssPseudoClassOfNthSelector,
+        biome_css_syntax::CssQueryFeatureRangeInterval,
+        crate::css::auxiliary::query_feature_range_interval::FormatCssQueryFeatureRangeInterval,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_class_of_nth_selector :: FormatCssPseudoClassOfNthSelector :: default ())
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_interval :: FormatCssQueryFeatureRangeInterval :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassOfNthSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeInterval {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoClassOfNthSelector,
-        crate::css::selectors::pseudo_class_of_nth_selector::FormatCssPseudoClassOfNthSelector,
+        biome_css_syntax::CssQueryFeatureRangeInterval,
+        crate::css::auxiliary::query_feature_range_interval::FormatCssQueryFeatureRangeInterval,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_class_of_nth_selector :: FormatCssPseudoClassOfNthSelector :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_interval :: FormatCssQueryFeatureRangeInterval :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssPseudoClassSelector>
-    for crate::css::selectors::pseudo_class_selector::FormatCssPseudoClassSelector
+impl FormatRule<biome_css_syntax::CssQueryFeatureReverseRange>
+    for crate::css::auxiliary::query_feature_reverse_range::FormatCssQueryFeatureReverseRange
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPseudoClassSelector,
+        node: &biome_css_syntax::CssQueryFeatureReverseRange,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoClassSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssQueryFeatureReverseRange>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureReverseRange {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPseudoClassSelector,
-        crate::css::selectors::pseudo_class_selector::FormatCssPseudoClassSelector,
+        biome_css_syntax::CssQueryFeatureReverseRange,
+        crate::css::auxiliary::query_feature_reverse_range::FormatCssQueryFeatureReverseRange,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::selectors::pseudo_class_selector::FormatCssPseudoClassSelector::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: query_feature_reverse_range :: FormatCssQueryFeatureReverseRange :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoClassSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureReverseRange {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoClassSelector,
-        crate::css::selectors::pseudo_class_selector::FormatCssPseudoClassSelector,
+        biome_css_syntax::CssQueryFeatureReverseRange,
+        crate::css::auxiliary::query_feature_reverse_range::FormatCssQueryFeatureReverseRange,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::selectors::pseudo_class_selector::FormatCssPseudoClassSelector::default(),
-        )
-    }
-}
-impl FormatRule < biome_css_syntax :: CssPseudoElementFunctionIdentifier > for crate :: css :: pseudo :: pseudo_element_function_identifier :: FormatCssPseudoElementFunctionIdentifier { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoElementFunctionIdentifier , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoElementFunctionIdentifier > :: fmt (self , node , f) } }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementFunctionIdentifier {
-    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoElementFunctionIdentifier , crate :: css :: pseudo :: pseudo_element_function_identifier :: FormatCssPseudoElementFunctionIdentifier > ;
-    fn format(&self) -> Self::Format<'_> {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_element_function_identifier :: FormatCssPseudoElementFunctionIdentifier :: default ())
-    }
-}
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementFunctionIdentifier {
-    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoElementFunctionIdentifier , crate :: css :: pseudo :: pseudo_element_function_identifier :: FormatCssPseudoElementFunctionIdentifier > ;
-    fn into_format(self) -> Self::Format {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_element_function_identifier :: FormatCssPseudoElementFunctionIdentifier :: default ())
-    }
-}
-impl FormatRule < biome_css_syntax :: CssPseudoElementFunctionSelector > for crate :: css :: selectors :: pseudo_element_function_selector :: FormatCssPseudoElementFunctionSelector { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & biome_css_syntax :: CssPseudoElementFunctionSelector , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < biome_css_syntax :: CssPseudoElementFunctionSelector > :: fmt (self , node , f) } }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementFunctionSelector {
-    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssPseudoElementFunctionSelector , crate :: css :: selectors :: pseudo_element_function_selector :: FormatCssPseudoElementFunctionSelector > ;
-    fn format(&self) -> Self::Format<'_> {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_element_function_selector :: FormatCssPseudoElementFunctionSelector :: default ())
-    }
-}
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementFunctionSelector {
-    type Format = FormatOwnedWithRule < biome_css_syntax :: CssPseudoElementFunctionSelector , crate :: css :: selectors :: pseudo_element_function_selector :: FormatCssPseudoElementFunctionSelector > ;
-    fn into_format(self) -> Self::Format {
-        #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_element_function_selector :: FormatCssPseudoElementFunctionSelector :: default ())
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: query_feature_reverse_range :: FormatCssQueryFeatureReverseRange :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssPseudoElementIdentifier>
-    for crate::css::pseudo::pseudo_element_identifier::FormatCssPseudoElementIdentifier
-{
+impl FormatRule<biome_css_syntax::CssRatio> for crate::css::value::ratio::FormatCssRatio {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(
-        &self,
-        node: &biome_css_syntax::CssPseudoElementIdentifier,
-        f: &mut CssFormatter,
-    ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoElementIdentifier>::fmt(self, node, f)
+    fn fmt(&self, node: &biome_css_syntax::CssRatio, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssRatio>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementIdentifier {
-    type Format<'a> = FormatRefWithRule<
-        'a,
-        biome_css_syntax::CssPseudoElementIdentifier,
-        crate::css::pseudo::pseudo_element_identifier::FormatCssPseudoElementIdentifier,
-    >;
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRatio {
+    type Format<'a> =
+        FormatRefWithRule<'a, biome_css_syntax::CssRatio, crate::css::value::ratio::FormatCssRatio>;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: pseudo :: pseudo_element_identifier :: FormatCssPseudoElementIdentifier :: default ())
+        FormatRefWithRule::new(self, crate::css::value::ratio::FormatCssRatio::default())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementIdentifier {
-    type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoElementIdentifier,
-        crate::css::pseudo::pseudo_element_identifier::FormatCssPseudoElementIdentifier,
-    >;
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRatio {
+    type Format =
+        FormatOwnedWithRule<biome_css_syntax::CssRatio, crate::css::value::ratio::FormatCssRatio>;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: pseudo :: pseudo_element_identifier :: FormatCssPseudoElementIdentifier :: default ())
+        FormatOwnedWithRule::new(self, crate::css::value::ratio::FormatCssRatio::default())
     }
 }
-impl FormatRule<biome_css_syntax::CssPseudoElementSelector>
-    for crate::css::selectors::pseudo_element_selector::FormatCssPseudoElementSelector
+impl FormatRule<biome_css_syntax::CssRegularDimension>
+    for crate::css::value::regular_dimension::FormatCssRegularDimension
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssPseudoElementSelector,
+        node: &biome_css_syntax::CssRegularDimension,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssPseudoElementSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssRegularDimension>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRegularDimension {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssPseudoElementSelector,
-        crate::css::selectors::pseudo_element_selector::FormatCssPseudoElementSelector,
+        biome_css_syntax::CssRegularDimension,
+        crate::css::value::regular_dimension::FormatCssRegularDimension,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::selectors::pseudo_element_selector::FormatCssPseudoElementSelector::default(
-            ),
+            crate::css::value::regular_dimension::FormatCssRegularDimension::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoElementSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRegularDimension {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssPseudoElementSelector,
-        crate::css::selectors::pseudo_element_selector::FormatCssPseudoElementSelector,
+        biome_css_syntax::CssRegularDimension,
+        crate::css::value::regular_dimension::FormatCssRegularDimension,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::selectors::pseudo_element_selector::FormatCssPseudoElementSelector::default(
-            ),
+            crate::css::value::regular_dimension::FormatCssRegularDimension::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssQualifiedRule>
-    for crate::css::auxiliary::qualified_rule::FormatCssQualifiedRule
+impl FormatRule<biome_css_syntax::CssRelativeColorAlpha>
+    for crate::css::auxiliary::relative_color_alpha::FormatCssRelativeColorAlpha
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssQualifiedRule,
+        node: &biome_css_syntax::CssRelativeColorAlpha,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQualifiedRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssRelativeColorAlpha>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQualifiedRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRelativeColorAlpha {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQualifiedRule,
-        crate::css::auxiliary::qualified_rule::FormatCssQualifiedRule,
+        biome_css_syntax::CssRelativeColorAlpha,
+        crate::css::auxiliary::relative_color_alpha::FormatCssRelativeColorAlpha,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::qualified_rule::FormatCssQualifiedRule::default(),
+            crate::css::auxiliary::relative_color_alpha::FormatCssRelativeColorAlpha::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQualifiedRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRelativeColorAlpha {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQualifiedRule,
-        crate::css::auxiliary::qualified_rule::FormatCssQualifiedRule,
+        biome_css_syntax::CssRelativeColorAlpha,
+        crate::css::auxiliary::relative_color_alpha::FormatCssRelativeColorAlpha,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::qualified_rule::FormatCssQualifiedRule::default(),
+            crate::css::auxiliary::relative_color_alpha::FormatCssRelativeColorAlpha::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssQueryFeatureBoolean>
-    for crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean
+impl FormatRule<biome_css_syntax::CssRelativeColorFunction>
+    for crate::css::auxiliary::relative_color_function::FormatCssRelativeColorFunction
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssQueryFeatureBoolean,
+        node: &biome_css_syntax::CssRelativeColorFunction,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQueryFeatureBoolean>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssRelativeColorFunction>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureBoolean {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRelativeColorFunction {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQueryFeatureBoolean,
-        crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean,
+        biome_css_syntax::CssRelativeColorFunction,
+        crate::css::auxiliary::relative_color_function::FormatCssRelativeColorFunction,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean::default(),
+            crate::css::auxiliary::relative_color_function::FormatCssRelativeColorFunction::default(
+            ),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureBoolean {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRelativeColorFunction {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQueryFeatureBoolean,
-        crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean,
+        biome_css_syntax::CssRelativeColorFunction,
+        crate::css::auxiliary::relative_color_function::FormatCssRelativeColorFunction,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::query_feature_boolean::FormatCssQueryFeatureBoolean::default(),
+            crate::css::auxiliary::relative_color_function::FormatCssRelativeColorFunction::default(
+            ),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssQueryFeaturePlain>
-    for crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain
+impl FormatRule<biome_css_syntax::CssRelativeSelector>
+    for crate::css::selectors::relative_selector::FormatCssRelativeSelector
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssQueryFeaturePlain,
+        node: &biome_css_syntax::CssRelativeSelector,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQueryFeaturePlain>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssRelativeSelector>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeaturePlain {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRelativeSelector {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQueryFeaturePlain,
-        crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain,
+        biome_css_syntax::CssRelativeSelector,
+        crate::css::selectors::relative_selector::FormatCssRelativeSelector,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain::default(),
+            crate::css::selectors::relative_selector::FormatCssRelativeSelector::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeaturePlain {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRelativeSelector {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQueryFeaturePlain,
-        crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain,
+        biome_css_syntax::CssRelativeSelector,
+        crate::css::selectors::relative_selector::FormatCssRelativeSelector,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::query_feature_plain::FormatCssQueryFeaturePlain::default(),
+            crate::css::selectors::relative_selector::FormatCssRelativeSelector::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssQueryFeatureRange>
-    for crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange
+impl FormatRule<biome_css_syntax::CssRoot> for crate::css::auxiliary::root::FormatCssRoot {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::CssRoot, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssRoot>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRoot {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssRoot,
+        crate::css::auxiliary::root::FormatCssRoot,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::css::auxiliary::root::FormatCssRoot::default())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRoot {
+    type Format =
+        FormatOwnedWithRule<biome_css_syntax::CssRoot, crate::css::auxiliary::root::FormatCssRoot>;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::css::auxiliary::root::FormatCssRoot::default())
+    }
+}
+impl FormatRule<biome_css_syntax::CssRuleBlock>
+    for crate::css::auxiliary::rule_block::FormatCssRuleBlock
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(
-        &self,
-        node: &biome_css_syntax::CssQueryFeatureRange,
-        f: &mut CssFormatter,
-    ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQueryFeatureRange>::fmt(self, node, f)
+    fn fmt(&self, node: &biome_css_syntax::CssRuleBlock, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssRuleBlock>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRange {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRuleBlock {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQueryFeatureRange,
-        crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange,
+        biome_css_syntax::CssRuleBlock,
+        crate::css::auxiliary::rule_block::FormatCssRuleBlock,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange::default(),
+            crate::css::auxiliary::rule_block::FormatCssRuleBlock::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRange {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRuleBlock {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQueryFeatureRange,
-        crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange,
+        biome_css_syntax::CssRuleBlock,
+        crate::css::auxiliary::rule_block::FormatCssRuleBlock,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::query_feature_range::FormatCssQueryFeatureRange::default(),
+            crate::css::auxiliary::rule_block::FormatCssRuleBlock::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssQueryFeatureRangeComparison>
-    for crate::css::auxiliary::query_feature_range_comparison::FormatCssQueryFeatureRangeComparison
+impl FormatRule<biome_css_syntax::CssScopeAtRule>
+    for crate::css::statements::scope_at_rule::FormatCssScopeAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssQueryFeatureRangeComparison,
+        node: &biome_css_syntax::CssScopeAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQueryFeatureRangeComparison>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssScopeAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeComparison {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQueryFeatureRangeComparison,
-        crate::css::auxiliary::query_feature_range_comparison::FormatCssQueryFeatureRangeComparison,
+        biome_css_syntax::CssScopeAtRule,
+        crate::css::statements::scope_at_rule::FormatCssScopeAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_comparison :: FormatCssQueryFeatureRangeComparison :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::scope_at_rule::FormatCssScopeAtRule::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeComparison {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQueryFeatureRangeComparison,
-        crate::css::auxiliary::query_feature_range_comparison::FormatCssQueryFeatureRangeComparison,
+        biome_css_syntax::CssScopeAtRule,
+        crate::css::statements::scope_at_rule::FormatCssScopeAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_comparison :: FormatCssQueryFeatureRangeComparison :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::scope_at_rule::FormatCssScopeAtRule::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssQueryFeatureRangeInterval>
-    for crate::css::auxiliary::query_feature_range_interval::FormatCssQueryFeatureRangeInterval
+impl FormatRule<biome_css_syntax::CssScopeEdge>
+    for crate::css::auxiliary::scope_edge::FormatCssScopeEdge
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(
-        &self,
-        node: &biome_css_syntax::CssQueryFeatureRangeInterval,
-        f: &mut CssFormatter,
-    ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQueryFeatureRangeInterval>::fmt(self, node, f)
+    fn fmt(&self, node: &biome_css_syntax::CssScopeEdge, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssScopeEdge>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeInterval {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeEdge {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQueryFeatureRangeInterval,
-        crate::css::auxiliary::query_feature_range_interval::FormatCssQueryFeatureRangeInterval,
+        biome_css_syntax::CssScopeEdge,
+        crate::css::auxiliary::scope_edge::FormatCssScopeEdge,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_interval :: FormatCssQueryFeatureRangeInterval :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::scope_edge::FormatCssScopeEdge::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureRangeInterval {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeEdge {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQueryFeatureRangeInterval,
-        crate::css::auxiliary::query_feature_range_interval::FormatCssQueryFeatureRangeInterval,
+        biome_css_syntax::CssScopeEdge,
+        crate::css::auxiliary::scope_edge::FormatCssScopeEdge,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: query_feature_range_interval :: FormatCssQueryFeatureRangeInterval :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::scope_edge::FormatCssScopeEdge::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssQueryFeatureReverseRange>
-    for crate::css::auxiliary::query_feature_reverse_range::FormatCssQueryFeatureReverseRange
+impl FormatRule<biome_css_syntax::CssScopeRangeEnd>
+    for crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssQueryFeatureReverseRange,
+        node: &biome_css_syntax::CssScopeRangeEnd,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssQueryFeatureReverseRange>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssScopeRangeEnd>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureReverseRange {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeEnd {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssQueryFeatureReverseRange,
-        crate::css::auxiliary::query_feature_reverse_range::FormatCssQueryFeatureReverseRange,
+        biome_css_syntax::CssScopeRangeEnd,
+        crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: query_feature_reverse_range :: FormatCssQueryFeatureReverseRange :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssQueryFeatureReverseRange {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeEnd {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssQueryFeatureReverseRange,
-        crate::css::auxiliary::query_feature_reverse_range::FormatCssQueryFeatureReverseRange,
+        biome_css_syntax::CssScopeRangeEnd,
+        crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: query_feature_reverse_range :: FormatCssQueryFeatureReverseRange :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssRatio> for crate::css::value::ratio::FormatCssRatio {
+impl FormatRule<biome_css_syntax::CssScopeRangeInterval>
+    for crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval
+{
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssRatio, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssRatio>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssScopeRangeInterval,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssScopeRangeInterval>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssRatio {
-    type Format<'a> =
-        FormatRefWithRule<'a, biome_css_syntax::CssRatio, crate::css::value::ratio::FormatCssRatio>;
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeInterval {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssScopeRangeInterval,
+        crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval,
+    >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(self, crate::css::value::ratio::FormatCssRatio::default())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRatio {
-    type Format =
-        FormatOwnedWithRule<biome_css_syntax::CssRatio, crate::css::value::ratio::FormatCssRatio>;
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeInterval {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssScopeRangeInterval,
+        crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval,
+    >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(self, crate::css::value::ratio::FormatCssRatio::default())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssRegularDimension>
-    for crate::css::value::regular_dimension::FormatCssRegularDimension
+impl FormatRule<biome_css_syntax::CssScopeRangeStart>
+    for crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssRegularDimension,
+        node: &biome_css_syntax::CssScopeRangeStart,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssRegularDimension>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssScopeRangeStart>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssRegularDimension {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeStart {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssRegularDimension,
-        crate::css::value::regular_dimension::FormatCssRegularDimension,
+        biome_css_syntax::CssScopeRangeStart,
+        crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::value::regular_dimension::FormatCssRegularDimension::default(),
+            crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRegularDimension {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeStart {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssRegularDimension,
-        crate::css::value::regular_dimension::FormatCssRegularDimension,
+        biome_css_syntax::CssScopeRangeStart,
+        crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::value::regular_dimension::FormatCssRegularDimension::default(),
+            crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssRelativeSelector>
-    for crate::css::selectors::relative_selector::FormatCssRelativeSelector
+impl FormatRule<biome_css_syntax::CssStartingStyleAtRule>
+    for crate::css::statements::starting_style_at_rule::FormatCssStartingStyleAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssRelativeSelector,
+        node: &biome_css_syntax::CssStartingStyleAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssRelativeSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssStartingStyleAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssRelativeSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssStartingStyleAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssRelativeSelector,
-        crate::css::selectors::relative_selector::FormatCssRelativeSelector,
+        biome_css_syntax::CssStartingStyleAtRule,
+        crate::css::statements::starting_style_at_rule::FormatCssStartingStyleAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::selectors::relative_selector::FormatCssRelativeSelector::default(),
+            crate::css::statements::starting_style_at_rule::FormatCssStartingStyleAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRelativeSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssStartingStyleAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssRelativeSelector,
-        crate::css::selectors::relative_selector::FormatCssRelativeSelector,
+        biome_css_syntax::CssStartingStyleAtRule,
+        crate::css::statements::starting_style_at_rule::FormatCssStartingStyleAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::selectors::relative_selector::FormatCssRelativeSelector::default(),
+            crate::css::statements::starting_style_at_rule::FormatCssStartingStyleAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssRoot> for crate::css::auxiliary::root::FormatCssRoot {
+impl FormatRule<biome_css_syntax::CssString> for crate::css::value::string::FormatCssString {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssRoot, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssRoot>::fmt(self, node, f)
+    fn fmt(&self, node: &biome_css_syntax::CssString, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssString>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssRoot {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssString {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssRoot,
-        crate::css::auxiliary::root::FormatCssRoot,
+        biome_css_syntax::CssString,
+        crate::css::value::string::FormatCssString,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(self, crate::css::auxiliary::root::FormatCssRoot::default())
+        FormatRefWithRule::new(self, crate::css::value::string::FormatCssString::default())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRoot {
-    type Format =
-        FormatOwnedWithRule<biome_css_syntax::CssRoot, crate::css::auxiliary::root::FormatCssRoot>;
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssString {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssString,
+        crate::css::value::string::FormatCssString,
+    >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(self, crate::css::auxiliary::root::FormatCssRoot::default())
+        FormatOwnedWithRule::new(self, crate::css::value::string::FormatCssString::default())
     }
 }
-impl FormatRule<biome_css_syntax::CssRuleBlock>
-    for crate::css::auxiliary::rule_block::FormatCssRuleBlock
+impl FormatRule<biome_css_syntax::CssSupportsAndCondition>
+    for crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssRuleBlock, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssRuleBlock>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssSupportsAndCondition,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssSupportsAndCondition>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssRuleBlock {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsAndCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssRuleBlock,
-        crate::css::auxiliary::rule_block::FormatCssRuleBlock,
+        biome_css_syntax::CssSupportsAndCondition,
+        crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::rule_block::FormatCssRuleBlock::default(),
+            crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRuleBlock {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsAndCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssRuleBlock,
-        crate::css::auxiliary::rule_block::FormatCssRuleBlock,
+        biome_css_syntax::CssSupportsAndCondition,
+        crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::rule_block::FormatCssRuleBlock::default(),
+            crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssScopeAtRule>
-    for crate::css::statements::scope_at_rule::FormatCssScopeAtRule
+impl FormatRule<biome_css_syntax::CssSupportsAtRule>
+    for crate::css::statements::supports_at_rule::FormatCssSupportsAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssScopeAtRule,
+        node: &biome_css_syntax::CssSupportsAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssScopeAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssSupportsAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssScopeAtRule,
-        crate::css::statements::scope_at_rule::FormatCssScopeAtRule,
+        biome_css_syntax::CssSupportsAtRule,
+        crate::css::statements::supports_at_rule::FormatCssSupportsAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::scope_at_rule::FormatCssScopeAtRule::default(),
+            crate::css::statements::supports_at_rule::FormatCssSupportsAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssScopeAtRule,
-        crate::css::statements::scope_at_rule::FormatCssScopeAtRule,
+        biome_css_syntax::CssSupportsAtRule,
+        crate::css::statements::supports_at_rule::FormatCssSupportsAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::statements::scope_at_rule::FormatCssScopeAtRule::default(),
+            crate::css::statements::supports_at_rule::FormatCssSupportsAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssScopeEdge>
-    for crate::css::auxiliary::scope_edge::FormatCssScopeEdge
+impl FormatRule<biome_css_syntax::CssSupportsConditionInParens>
+    for crate::css::auxiliary::supports_condition_in_parens::FormatCssSupportsConditionInParens
 {
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssScopeEdge, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssScopeEdge>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssSupportsConditionInParens,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssSupportsConditionInParens>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeEdge {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsConditionInParens {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssScopeEdge,
-        crate::css::auxiliary::scope_edge::FormatCssScopeEdge,
+        biome_css_syntax::CssSupportsConditionInParens,
+        crate::css::auxiliary::supports_condition_in_parens::FormatCssSupportsConditionInParens,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::scope_edge::FormatCssScopeEdge::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: supports_condition_in_parens :: FormatCssSupportsConditionInParens :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeEdge {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsConditionInParens {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssScopeEdge,
-        crate::css::auxiliary::scope_edge::FormatCssScopeEdge,
+        biome_css_syntax::CssSupportsConditionInParens,
+        crate::css::auxiliary::supports_condition_in_parens::FormatCssSupportsConditionInParens,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::scope_edge::FormatCssScopeEdge::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: supports_condition_in_parens :: FormatCssSupportsConditionInParens :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssScopeRangeEnd>
-    for crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd
+impl FormatRule<biome_css_syntax::CssSupportsFeatureDeclaration>
+    for crate::css::auxiliary::supports_feature_declaration::FormatCssSupportsFeatureDeclaration
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssScopeRangeEnd,
+        node: &biome_css_syntax::CssSupportsFeatureDeclaration,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssScopeRangeEnd>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssSupportsFeatureDeclaration>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeEnd {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureDeclaration {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssScopeRangeEnd,
-        crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd,
+        biome_css_syntax::CssSupportsFeatureDeclaration,
+        crate::css::auxiliary::supports_feature_declaration::FormatCssSupportsFeatureDeclaration,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: supports_feature_declaration :: FormatCssSupportsFeatureDeclaration :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeEnd {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureDeclaration {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssScopeRangeEnd,
-        crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd,
+        biome_css_syntax::CssSupportsFeatureDeclaration,
+        crate::css::auxiliary::supports_feature_declaration::FormatCssSupportsFeatureDeclaration,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::scope_range_end::FormatCssScopeRangeEnd::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: supports_feature_declaration :: FormatCssSupportsFeatureDeclaration :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssScopeRangeInterval>
-    for crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval
+impl FormatRule<biome_css_syntax::CssSupportsFeatureFontFormat>
+    for crate::css::auxiliary::supports_feature_font_format::FormatCssSupportsFeatureFontFormat
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssScopeRangeInterval,
+        node: &biome_css_syntax::CssSupportsFeatureFontFormat,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssScopeRangeInterval>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssSupportsFeatureFontFormat>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeInterval {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureFontFormat {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssScopeRangeInterval,
-        crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval,
+        biome_css_syntax::CssSupportsFeatureFontFormat,
+        crate::css::auxiliary::supports_feature_font_format::FormatCssSupportsFeatureFontFormat,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: supports_feature_font_format :: FormatCssSupportsFeatureFontFormat :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeInterval {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureFontFormat {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssScopeRangeInterval,
-        crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval,
+        biome_css_syntax::CssSupportsFeatureFontFormat,
+        crate::css::auxiliary::supports_feature_font_format::FormatCssSupportsFeatureFontFormat,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::scope_range_interval::FormatCssScopeRangeInterval::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: supports_feature_font_format :: FormatCssSupportsFeatureFontFormat :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssScopeRangeStart>
-    for crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart
+impl FormatRule<biome_css_syntax::CssSupportsFeatureFontTech>
+    for crate::css::auxiliary::supports_feature_font_tech::FormatCssSupportsFeatureFontTech
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssScopeRangeStart,
+        node: &biome_css_syntax::CssSupportsFeatureFontTech,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssScopeRangeStart>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssSupportsFeatureFontTech>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeStart {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureFontTech {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssScopeRangeStart,
-        crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart,
+        biome_css_syntax::CssSupportsFeatureFontTech,
+        crate::css::auxiliary::supports_feature_font_tech::FormatCssSupportsFeatureFontTech,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: supports_feature_font_tech :: FormatCssSupportsFeatureFontTech :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeStart {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureFontTech {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssScopeRangeStart,
-        crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart,
+        biome_css_syntax::CssSupportsFeatureFontTech,
+        crate::css::auxiliary::supports_feature_font_tech::FormatCssSupportsFeatureFontTech,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::scope_range_start::FormatCssScopeRangeStart::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: supports_feature_font_tech :: FormatCssSupportsFeatureFontTech :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssStartingStyleAtRule>
-    for crate::css::statements::starting_style_at_rule::FormatCssStartingStyleAtRule
+impl FormatRule<biome_css_syntax::CssSupportsFeatureSelector>
+    for crate::css::selectors::supports_feature_selector::FormatCssSupportsFeatureSelector
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssStartingStyleAtRule,
+        node: &biome_css_syntax::CssSupportsFeatureSelector,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssStartingStyleAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssSupportsFeatureSelector>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssStartingStyleAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureSelector {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssStartingStyleAtRule,
-        crate::css::statements::starting_style_at_rule::FormatCssStartingStyleAtRule,
+        biome_css_syntax::CssSupportsFeatureSelector,
+        crate::css::selectors::supports_feature_selector::FormatCssSupportsFeatureSelector,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::statements::starting_style_at_rule::FormatCssStartingStyleAtRule::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: supports_feature_selector :: FormatCssSupportsFeatureSelector :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssStartingStyleAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureSelector {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssStartingStyleAtRule,
-        crate::css::statements::starting_style_at_rule::FormatCssStartingStyleAtRule,
+        biome_css_syntax::CssSupportsFeatureSelector,
+        crate::css::selectors::supports_feature_selector::FormatCssSupportsFeatureSelector,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::statements::starting_style_at_rule::FormatCssStartingStyleAtRule::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: supports_feature_selector :: FormatCssSupportsFeatureSelector :: default ())
     }
 }
-impl FormatRule<biome_css_syntax::CssString> for crate::css::value::string::FormatCssString {
+impl FormatRule<biome_css_syntax::CssSupportsNotCondition>
+    for crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition
+{
     type Context = CssFormatContext;
     #[inline(always)]
-    fn fmt(&self, node: &biome_css_syntax::CssString, f: &mut CssFormatter) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssString>::fmt(self, node, f)
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssSupportsNotCondition,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssSupportsNotCondition>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssString {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsNotCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssString,
-        crate::css::value::string::FormatCssString,
+        biome_css_syntax::CssSupportsNotCondition,
+        crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(self, crate::css::value::string::FormatCssString::default())
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssString {
-    type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssString,
-        crate::css::value::string::FormatCssString,
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsNotCondition {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssSupportsNotCondition,
+        crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(self, crate::css::value::string::FormatCssString::default())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsAndCondition>
-    for crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition
+impl FormatRule<biome_css_syntax::CssSupportsOrCondition>
+    for crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsAndCondition,
+        node: &biome_css_syntax::CssSupportsOrCondition,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsAndCondition>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssSupportsOrCondition>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsAndCondition {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsOrCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsAndCondition,
-        crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition,
+        biome_css_syntax::CssSupportsOrCondition,
+        crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition::default(),
+            crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsAndCondition {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsOrCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsAndCondition,
-        crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition,
+        biome_css_syntax::CssSupportsOrCondition,
+        crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::supports_and_condition::FormatCssSupportsAndCondition::default(),
+            crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsAtRule>
-    for crate::css::statements::supports_at_rule::FormatCssSupportsAtRule
+impl FormatRule<biome_css_syntax::CssTailwindApplyAtRule>
+    for crate::css::statements::tailwind_apply_at_rule::FormatCssTailwindApplyAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsAtRule,
+        node: &biome_css_syntax::CssTailwindApplyAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsAtRule>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssTailwindApplyAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsAtRule {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssTailwindApplyAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsAtRule,
-        crate::css::statements::supports_at_rule::FormatCssSupportsAtRule,
+        biome_css_syntax::CssTailwindApplyAtRule,
+        crate::css::statements::tailwind_apply_at_rule::FormatCssTailwindApplyAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::statements::supports_at_rule::FormatCssSupportsAtRule::default(),
+            crate::css::statements::tailwind_apply_at_rule::FormatCssTailwindApplyAtRule::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsAtRule {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssTailwindApplyAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsAtRule,
-        crate::css::statements::supports_at_rule::FormatCssSupportsAtRule,
+        biome_css_syntax::CssTailwindApplyAtRule,
+        crate::css::statements::tailwind_apply_at_rule::FormatCssTailwindApplyAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::statements::supports_at_rule::FormatCssSupportsAtRule::default(),
+            crate::css::statements::tailwind_apply_at_rule::FormatCssTailwindApplyAtRule::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsConditionInParens>
-    for crate::css::auxiliary::supports_condition_in_parens::FormatCssSupportsConditionInParens
+impl FormatRule<biome_css_syntax::CssTailwindAtRule>
+    for crate::css::statements::tailwind_at_rule::FormatCssTailwindAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsConditionInParens,
+        node: &biome_css_syntax::CssTailwindAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsConditionInParens>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssTailwindAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsConditionInParens {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssTailwindAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsConditionInParens,
-        crate::css::auxiliary::supports_condition_in_parens::FormatCssSupportsConditionInParens,
+        biome_css_syntax::CssTailwindAtRule,
+        crate::css::statements::tailwind_at_rule::FormatCssTailwindAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: supports_condition_in_parens :: FormatCssSupportsConditionInParens :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::tailwind_at_rule::FormatCssTailwindAtRule::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsConditionInParens {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssTailwindAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsConditionInParens,
-        crate::css::auxiliary::supports_condition_in_parens::FormatCssSupportsConditionInParens,
+        biome_css_syntax::CssTailwindAtRule,
+        crate::css::statements::tailwind_at_rule::FormatCssTailwindAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: supports_condition_in_parens :: FormatCssSupportsConditionInParens :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::tailwind_at_rule::FormatCssTailwindAtRule::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsFeatureDeclaration>
-    for crate::css::auxiliary::supports_feature_declaration::FormatCssSupportsFeatureDeclaration
+impl FormatRule<biome_css_syntax::CssTailwindConfigAtRule>
+    for crate::css::statements::tailwind_config_at_rule::FormatCssTailwindConfigAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsFeatureDeclaration,
+        node: &biome_css_syntax::CssTailwindConfigAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsFeatureDeclaration>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssTailwindConfigAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureDeclaration {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssTailwindConfigAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsFeatureDeclaration,
-        crate::css::auxiliary::supports_feature_declaration::FormatCssSupportsFeatureDeclaration,
+        biome_css_syntax::CssTailwindConfigAtRule,
+        crate::css::statements::tailwind_config_at_rule::FormatCssTailwindConfigAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: supports_feature_declaration :: FormatCssSupportsFeatureDeclaration :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::tailwind_config_at_rule::FormatCssTailwindConfigAtRule::default(
+            ),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureDeclaration {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssTailwindConfigAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsFeatureDeclaration,
-        crate::css::auxiliary::supports_feature_declaration::FormatCssSupportsFeatureDeclaration,
+        biome_css_syntax::CssTailwindConfigAtRule,
+        crate::css::statements::tailwind_config_at_rule::FormatCssTailwindConfigAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: supports_feature_declaration :: FormatCssSupportsFeatureDeclaration :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::tailwind_config_at_rule::FormatCssTailwindConfigAtRule::default(
+            ),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsFeatureSelector>
-    for crate::css::selectors::supports_feature_selector::FormatCssSupportsFeatureSelector
+impl FormatRule<biome_css_syntax::CssTailwindThemeAtRule>
+    for crate::css::statements::tailwind_theme_at_rule::FormatCssTailwindThemeAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsFeatureSelector,
+        node: &biome_css_syntax::CssTailwindThemeAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsFeatureSelector>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssTailwindThemeAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureSelector {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssTailwindThemeAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsFeatureSelector,
-        crate::css::selectors::supports_feature_selector::FormatCssSupportsFeatureSelector,
+        biome_css_syntax::CssTailwindThemeAtRule,
+        crate::css::statements::tailwind_theme_at_rule::FormatCssTailwindThemeAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: selectors :: supports_feature_selector :: FormatCssSupportsFeatureSelector :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::tailwind_theme_at_rule::FormatCssTailwindThemeAtRule::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsFeatureSelector {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssTailwindThemeAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsFeatureSelector,
-        crate::css::selectors::supports_feature_selector::FormatCssSupportsFeatureSelector,
+        biome_css_syntax::CssTailwindThemeAtRule,
+        crate::css::statements::tailwind_theme_at_rule::FormatCssTailwindThemeAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: supports_feature_selector :: FormatCssSupportsFeatureSelector :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::tailwind_theme_at_rule::FormatCssTailwindThemeAtRule::default(),
+        )
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsNotCondition>
-    for crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition
+impl FormatRule<biome_css_syntax::CssTailwindUtility>
+    for crate::css::auxiliary::tailwind_utility::FormatCssTailwindUtility
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsNotCondition,
+        node: &biome_css_syntax::CssTailwindUtility,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsNotCondition>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssTailwindUtility>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsNotCondition {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssTailwindUtility {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsNotCondition,
-        crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition,
+        biome_css_syntax::CssTailwindUtility,
+        crate::css::auxiliary::tailwind_utility::FormatCssTailwindUtility,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition::default(),
+            crate::css::auxiliary::tailwind_utility::FormatCssTailwindUtility::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsNotCondition {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssTailwindUtility {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsNotCondition,
-        crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition,
+        biome_css_syntax::CssTailwindUtility,
+        crate::css::auxiliary::tailwind_utility::FormatCssTailwindUtility,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::auxiliary::supports_not_condition::FormatCssSupportsNotCondition::default(),
+            crate::css::auxiliary::tailwind_utility::FormatCssTailwindUtility::default(),
         )
     }
 }
-impl FormatRule<biome_css_syntax::CssSupportsOrCondition>
-    for crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition
+impl FormatRule<biome_css_syntax::CssTailwindVariantsAtRule>
+    for crate::css::statements::tailwind_variants_at_rule::FormatCssTailwindVariantsAtRule
 {
     type Context = CssFormatContext;
     #[inline(always)]
     fn fmt(
         &self,
-        node: &biome_css_syntax::CssSupportsOrCondition,
+        node: &biome_css_syntax::CssTailwindVariantsAtRule,
         f: &mut CssFormatter,
     ) -> FormatResult<()> {
-        FormatNodeRule::<biome_css_syntax::CssSupportsOrCondition>::fmt(self, node, f)
+        FormatNodeRule::<biome_css_syntax::CssTailwindVariantsAtRule>::fmt(self, node, f)
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSupportsOrCondition {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssTailwindVariantsAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSupportsOrCondition,
-        crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition,
+        biome_css_syntax::CssTailwindVariantsAtRule,
+        crate::css::statements::tailwind_variants_at_rule::FormatCssTailwindVariantsAtRule,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule::new(
-            self,
-            crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition::default(),
-        )
+        FormatRefWithRule :: new (self , crate :: css :: statements :: tailwind_variants_at_rule :: FormatCssTailwindVariantsAtRule :: default ())
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsOrCondition {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssTailwindVariantsAtRule {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSupportsOrCondition,
-        crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition,
+        biome_css_syntax::CssTailwindVariantsAtRule,
+        crate::css::statements::tailwind_variants_at_rule::FormatCssTailwindVariantsAtRule,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule::new(
-            self,
-            crate::css::auxiliary::supports_or_condition::FormatCssSupportsOrCondition::default(),
-        )
+        FormatOwnedWithRule :: new (self , crate :: css :: statements :: tailwind_variants_at_rule :: FormatCssTailwindVariantsAtRule :: default ())
     }
 }
 impl FormatRule<biome_css_syntax::CssTypeSelector>
@@ -5298,6 +6551,40 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUniversalSelector {
         )
     }
 }
+impl FormatRule<biome_css_syntax::CssUnknownAtRuleRawBlock>
+    for crate::css::auxiliary::unknown_at_rule_raw_block::FormatCssUnknownAtRuleRawBlock
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssUnknownAtRuleRawBlock,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssUnknownAtRuleRawBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssUnknownAtRuleRawBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssUnknownAtRuleRawBlock,
+        crate::css::auxiliary::unknown_at_rule_raw_block::FormatCssUnknownAtRuleRawBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: auxiliary :: unknown_at_rule_raw_block :: FormatCssUnknownAtRuleRawBlock :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUnknownAtRuleRawBlock {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssUnknownAtRuleRawBlock,
+        crate::css::auxiliary::unknown_at_rule_raw_block::FormatCssUnknownAtRuleRawBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: unknown_at_rule_raw_block :: FormatCssUnknownAtRuleRawBlock :: default ())
+    }
+}
 impl FormatRule<biome_css_syntax::CssUnknownBlockAtRule>
     for crate::css::statements::unknown_block_at_rule::FormatCssUnknownBlockAtRule
 {
@@ -5670,6 +6957,48 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleNamedImpor
         FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: value_at_rule_named_import_specifier :: FormatCssValueAtRuleNamedImportSpecifier :: default ())
     }
 }
+impl FormatRule<biome_css_syntax::CssViewTransitionAtRule>
+    for crate::css::statements::view_transition_at_rule::FormatCssViewTransitionAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssViewTransitionAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssViewTransitionAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssViewTransitionAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssViewTransitionAtRule,
+        crate::css::statements::view_transition_at_rule::FormatCssViewTransitionAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::view_transition_at_rule::FormatCssViewTransitionAtRule::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssViewTransitionAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssViewTransitionAtRule,
+        crate::css::statements::view_transition_at_rule::FormatCssViewTransitionAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::view_transition_at_rule::FormatCssViewTransitionAtRule::default(
+            ),
+        )
+    }
+}
 impl AsFormat<CssFormatContext> for biome_css_syntax::CssBracketedValueList {
     type Format<'a> = FormatRefWithRule<
         'a,
@@ -5697,6 +7026,33 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssBracketedValueList {
         )
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssColorMixColorList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssColorMixColorList,
+        crate::css::lists::color_mix_color_list::FormatCssColorMixColorList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::color_mix_color_list::FormatCssColorMixColorList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssColorMixColorList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssColorMixColorList,
+        crate::css::lists::color_mix_color_list::FormatCssColorMixColorList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::color_mix_color_list::FormatCssColorMixColorList::default(),
+        )
+    }
+}
 impl AsFormat<CssFormatContext> for biome_css_syntax::CssComponentValueList {
     type Format<'a> = FormatRefWithRule<
         'a,
@@ -5747,34 +7103,104 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssComposesClassList {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::composes_class_list::FormatCssComposesClassList::default(),
+            crate::css::lists::composes_class_list::FormatCssComposesClassList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelectorList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssCompoundSelectorList,
+        crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelectorList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssCompoundSelectorList,
+        crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleAdditiveSymbolList {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssCounterStyleAdditiveSymbolList , crate :: css :: lists :: counter_style_additive_symbol_list :: FormatCssCounterStyleAdditiveSymbolList > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: lists :: counter_style_additive_symbol_list :: FormatCssCounterStyleAdditiveSymbolList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleAdditiveSymbolList {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssCounterStyleAdditiveSymbolList , crate :: css :: lists :: counter_style_additive_symbol_list :: FormatCssCounterStyleAdditiveSymbolList > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: lists :: counter_style_additive_symbol_list :: FormatCssCounterStyleAdditiveSymbolList :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleRangeList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssCounterStyleRangeList,
+        crate::css::lists::counter_style_range_list::FormatCssCounterStyleRangeList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::counter_style_range_list::FormatCssCounterStyleRangeList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleRangeList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssCounterStyleRangeList,
+        crate::css::lists::counter_style_range_list::FormatCssCounterStyleRangeList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::counter_style_range_list::FormatCssCounterStyleRangeList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelectorList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleSymbolList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssCompoundSelectorList,
-        crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList,
+        biome_css_syntax::CssCounterStyleSymbolList,
+        crate::css::lists::counter_style_symbol_list::FormatCssCounterStyleSymbolList,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList::default(),
+            crate::css::lists::counter_style_symbol_list::FormatCssCounterStyleSymbolList::default(
+            ),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelectorList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCounterStyleSymbolList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssCompoundSelectorList,
-        crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList,
+        biome_css_syntax::CssCounterStyleSymbolList,
+        crate::css::lists::counter_style_symbol_list::FormatCssCounterStyleSymbolList,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList::default(),
+            crate::css::lists::counter_style_symbol_list::FormatCssCounterStyleSymbolList::default(
+            ),
         )
     }
 }
@@ -6383,6 +7809,81 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSubSelectorList {
         )
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssTailwindThemeModifierList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssTailwindThemeModifierList,
+        crate::css::lists::tailwind_theme_modifier_list::FormatCssTailwindThemeModifierList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: lists :: tailwind_theme_modifier_list :: FormatCssTailwindThemeModifierList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssTailwindThemeModifierList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssTailwindThemeModifierList,
+        crate::css::lists::tailwind_theme_modifier_list::FormatCssTailwindThemeModifierList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: lists :: tailwind_theme_modifier_list :: FormatCssTailwindThemeModifierList :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssTailwindUtilityList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssTailwindUtilityList,
+        crate::css::lists::tailwind_utility_list::FormatCssTailwindUtilityList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::tailwind_utility_list::FormatCssTailwindUtilityList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssTailwindUtilityList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssTailwindUtilityList,
+        crate::css::lists::tailwind_utility_list::FormatCssTailwindUtilityList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::tailwind_utility_list::FormatCssTailwindUtilityList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssTailwindVariantList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssTailwindVariantList,
+        crate::css::lists::tailwind_variant_list::FormatCssTailwindVariantList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::tailwind_variant_list::FormatCssTailwindVariantList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssTailwindVariantList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssTailwindVariantList,
+        crate::css::lists::tailwind_variant_list::FormatCssTailwindVariantList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::tailwind_variant_list::FormatCssTailwindVariantList::default(),
+        )
+    }
+}
 impl AsFormat<CssFormatContext> for biome_css_syntax::CssUrlModifierList {
     type Format<'a> = FormatRefWithRule<
         'a,
@@ -6548,6 +8049,46 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssBogusBlock {
         )
     }
 }
+impl FormatRule<biome_css_syntax::CssBogusContainerQuery>
+    for crate::css::bogus::bogus_container_query::FormatCssBogusContainerQuery
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssBogusContainerQuery,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_css_syntax::CssBogusContainerQuery>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssBogusContainerQuery {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssBogusContainerQuery,
+        crate::css::bogus::bogus_container_query::FormatCssBogusContainerQuery,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::bogus::bogus_container_query::FormatCssBogusContainerQuery::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssBogusContainerQuery {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssBogusContainerQuery,
+        crate::css::bogus::bogus_container_query::FormatCssBogusContainerQuery,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::bogus::bogus_container_query::FormatCssBogusContainerQuery::default(),
+        )
+    }
+}
 impl FormatRule<biome_css_syntax::CssBogusCustomIdentifier>
     for crate::css::bogus::bogus_custom_identifier::FormatCssBogusCustomIdentifier
 {
@@ -7442,6 +8983,33 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleGenericVal
         FormatOwnedWithRule :: new (self , crate :: css :: bogus :: value_at_rule_generic_value :: FormatCssValueAtRuleGenericValue :: default ())
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssAnchorSide {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyCssAnchorSide,
+        crate::css::any::anchor_side::FormatAnyCssAnchorSide,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::anchor_side::FormatAnyCssAnchorSide::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssAnchorSide {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyCssAnchorSide,
+        crate::css::any::anchor_side::FormatAnyCssAnchorSide,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::anchor_side::FormatAnyCssAnchorSide::default(),
+        )
+    }
+}
 impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssAtRule {
     type Format<'a> = FormatRefWithRule<
         'a,
@@ -7675,6 +9243,59 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssContainerQueryInPa
         )
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssContainerScrollStateAndCombinableQuery {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: AnyCssContainerScrollStateAndCombinableQuery , crate :: css :: any :: container_scroll_state_and_combinable_query :: FormatAnyCssContainerScrollStateAndCombinableQuery > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: any :: container_scroll_state_and_combinable_query :: FormatAnyCssContainerScrollStateAndCombinableQuery :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext>
+    for biome_css_syntax::AnyCssContainerScrollStateAndCombinableQuery
+{
+    type Format = FormatOwnedWithRule < biome_css_syntax :: AnyCssContainerScrollStateAndCombinableQuery , crate :: css :: any :: container_scroll_state_and_combinable_query :: FormatAnyCssContainerScrollStateAndCombinableQuery > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: any :: container_scroll_state_and_combinable_query :: FormatAnyCssContainerScrollStateAndCombinableQuery :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssContainerScrollStateOrCombinableQuery {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: AnyCssContainerScrollStateOrCombinableQuery , crate :: css :: any :: container_scroll_state_or_combinable_query :: FormatAnyCssContainerScrollStateOrCombinableQuery > ;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: any :: container_scroll_state_or_combinable_query :: FormatAnyCssContainerScrollStateOrCombinableQuery :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext>
+    for biome_css_syntax::AnyCssContainerScrollStateOrCombinableQuery
+{
+    type Format = FormatOwnedWithRule < biome_css_syntax :: AnyCssContainerScrollStateOrCombinableQuery , crate :: css :: any :: container_scroll_state_or_combinable_query :: FormatAnyCssContainerScrollStateOrCombinableQuery > ;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: any :: container_scroll_state_or_combinable_query :: FormatAnyCssContainerScrollStateOrCombinableQuery :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssContainerScrollStateQuery {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyCssContainerScrollStateQuery,
+        crate::css::any::container_scroll_state_query::FormatAnyCssContainerScrollStateQuery,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: any :: container_scroll_state_query :: FormatAnyCssContainerScrollStateQuery :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssContainerScrollStateQuery {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyCssContainerScrollStateQuery,
+        crate::css::any::container_scroll_state_query::FormatAnyCssContainerScrollStateQuery,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: any :: container_scroll_state_query :: FormatAnyCssContainerScrollStateQuery :: default ())
+    }
+}
 impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssContainerStyleAndCombinableQuery {
     type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: AnyCssContainerStyleAndCombinableQuery , crate :: css :: any :: container_style_and_combinable_query :: FormatAnyCssContainerStyleAndCombinableQuery > ;
     fn format(&self) -> Self::Format<'_> {
@@ -7759,6 +9380,116 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssContainerStyleQuer
         )
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssCounterStyleRange {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyCssCounterStyleRange,
+        crate::css::any::counter_style_range::FormatAnyCssCounterStyleRange,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::counter_style_range::FormatAnyCssCounterStyleRange::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssCounterStyleRange {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyCssCounterStyleRange,
+        crate::css::any::counter_style_range::FormatAnyCssCounterStyleRange,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::counter_style_range::FormatAnyCssCounterStyleRange::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssCounterStyleRangeBound {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyCssCounterStyleRangeBound,
+        crate::css::any::counter_style_range_bound::FormatAnyCssCounterStyleRangeBound,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::counter_style_range_bound::FormatAnyCssCounterStyleRangeBound::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssCounterStyleRangeBound {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyCssCounterStyleRangeBound,
+        crate::css::any::counter_style_range_bound::FormatAnyCssCounterStyleRangeBound,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::counter_style_range_bound::FormatAnyCssCounterStyleRangeBound::default(
+            ),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssCounterStyleSymbol {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyCssCounterStyleSymbol,
+        crate::css::any::counter_style_symbol::FormatAnyCssCounterStyleSymbol,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::counter_style_symbol::FormatAnyCssCounterStyleSymbol::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssCounterStyleSymbol {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyCssCounterStyleSymbol,
+        crate::css::any::counter_style_symbol::FormatAnyCssCounterStyleSymbol,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::counter_style_symbol::FormatAnyCssCounterStyleSymbol::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssCounterStyleSystem {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyCssCounterStyleSystem,
+        crate::css::any::counter_style_system::FormatAnyCssCounterStyleSystem,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::counter_style_system::FormatAnyCssCounterStyleSystem::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssCounterStyleSystem {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyCssCounterStyleSystem,
+        crate::css::any::counter_style_system::FormatAnyCssCounterStyleSystem,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::counter_style_system::FormatAnyCssCounterStyleSystem::default(),
+        )
+    }
+}
 impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssCustomIdentifier {
     type Format<'a> = FormatRefWithRule<
         'a,
@@ -8786,6 +10517,33 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssProperty {
         )
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssPropertyAtRuleBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyCssPropertyAtRuleBlock,
+        crate::css::any::property_at_rule_block::FormatAnyCssPropertyAtRuleBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::property_at_rule_block::FormatAnyCssPropertyAtRuleBlock::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssPropertyAtRuleBlock {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyCssPropertyAtRuleBlock,
+        crate::css::any::property_at_rule_block::FormatAnyCssPropertyAtRuleBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::property_at_rule_block::FormatAnyCssPropertyAtRuleBlock::default(),
+        )
+    }
+}
 impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssPseudoClass {
     type Format<'a> = FormatRefWithRule<
         'a,
@@ -9294,6 +11052,33 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssUnicodeValue {
         )
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssUnknownAtRuleBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyCssUnknownAtRuleBlock,
+        crate::css::any::unknown_at_rule_block::FormatAnyCssUnknownAtRuleBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::unknown_at_rule_block::FormatAnyCssUnknownAtRuleBlock::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssUnknownAtRuleBlock {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyCssUnknownAtRuleBlock,
+        crate::css::any::unknown_at_rule_block::FormatAnyCssUnknownAtRuleBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::unknown_at_rule_block::FormatAnyCssUnknownAtRuleBlock::default(),
+        )
+    }
+}
 impl AsFormat<CssFormatContext> for biome_css_syntax::AnyCssUrlModifier {
     type Format<'a> = FormatRefWithRule<
         'a,
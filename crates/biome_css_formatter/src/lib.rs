@@ -383,4 +383,19 @@ mod tests {
         let formatted = format_node(options, &parse.syntax()).unwrap();
         assert_eq!(formatted.print().unwrap().as_code(), "html {\n}\n");
     }
+
+    #[test]
+    fn raw_unknown_at_rule() {
+        let src = "@plugin   \"my-plugin\"   {\n  debug : true ;\n    opacity: [ 0 , 1 ] }\n.a { color : red }";
+        let parse = parse_css(
+            src,
+            CssParserOptions::default().allow_raw_unknown_at_rules(),
+        );
+        let options = CssFormatOptions::default();
+        let formatted = format_node(options, &parse.syntax()).unwrap();
+        assert_eq!(
+            formatted.print().unwrap().as_code(),
+            "@plugin \"my-plugin\" {\n  debug : true ;\n    opacity: [ 0 , 1 ] }\n.a {\n\tcolor: red;\n}\n"
+        );
+    }
 }
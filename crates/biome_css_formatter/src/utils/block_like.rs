@@ -3,7 +3,9 @@ use biome_formatter::{CstFormatContext, Format, FormatResult};
 
 use crate::CssFormatter;
 use biome_css_syntax::stmt_ext::CssBlockLike;
-use biome_formatter::write;
+use biome_css_syntax::CssSyntaxKind::CSS_DECLARATION_WITH_SEMICOLON;
+use biome_formatter::{format_args, write};
+use biome_rowan::Direction;
 
 #[derive(Debug, Copy, Clone)]
 pub(crate) struct FormatCssBlockLike<'a> {
@@ -38,8 +40,35 @@ impl<'a> FormatCssBlockLike<'a> {
             CssBlockLike::CssDeclarationOrRuleBlock(block) => {
                 write!(f, [block.items().format()])
             }
+            CssBlockLike::CssPropertyAtRuleBlock(block) => {
+                write!(f, [block.declarations().format()])
+            }
         }
     }
+
+    /// Returns `true` if the block only contains a single declaration, without any comment inside of it.
+    fn is_single_declaration(&self) -> bool {
+        let Some(list) = self.block.syntax().first_child() else {
+            return false;
+        };
+        let mut items = list.children();
+        let is_single_declaration = items
+            .next()
+            .is_some_and(|item| item.kind() == CSS_DECLARATION_WITH_SEMICOLON)
+            && items.next().is_none();
+        is_single_declaration
+            && !self
+                .block
+                .l_curly_token()
+                .is_ok_and(|token| token.has_trailing_comments())
+            && !list
+                .descendants_tokens(Direction::Next)
+                .any(|token| token.has_leading_comments() || token.has_trailing_comments())
+            && !self
+                .block
+                .r_curly_token()
+                .is_ok_and(|token| token.has_leading_comments())
+    }
 }
 
 impl<'a> Format<CssFormatContext> for FormatCssBlockLike<'a> {
@@ -63,6 +92,15 @@ impl<'a> Format<CssFormatContext> for FormatCssBlockLike<'a> {
             } else {
                 write!(f, [soft_line_break()])?;
             }
+        } else if f.options().collapse_single_declaration_rules() && self.is_single_declaration() {
+            // Print `a { color: red; }` on a single line when it fits
+            return write!(
+                f,
+                [group(&format_args![
+                    soft_space_or_block_indent(&format_with(|f| self.write_items(f))),
+                    r_curly_token.format()
+                ])]
+            );
         } else {
             write!(
                 f,
@@ -1,6 +1,8 @@
 use crate::comments::CssComments;
-use biome_css_syntax::{CssGenericDelimiter, CssGenericProperty, CssLanguage, CssSyntaxKind};
-use biome_formatter::{write, CstFormatContext};
+use biome_css_syntax::{
+    CssGenericDelimiter, CssGenericProperty, CssLanguage, CssSyntaxKind, CssSyntaxNode,
+};
+use biome_formatter::{format_args, write, CstFormatContext};
 use biome_formatter::{FormatOptions, FormatResult};
 use biome_string_case::StrLikeExtension;
 
@@ -39,26 +41,27 @@ where
                     // Consider the CSS example: `font: first , second;`
                     // The desired format is: `font: first, second;`
                     // A separator should not be added before the comma because the comma acts as a `CssGenericDelimiter`.
-                    let token_kind = CssGenericDelimiter::cast_ref(element.syntax())
-                        .and_then(|node| node.value().ok())
-                        .map(|token| token.kind());
-
-                    let is_comma = matches!(token_kind, Some(CssSyntaxKind::COMMA));
-
-                    if !is_comma {
-                        if matches!(
-                            layout,
-                            ValueListLayout::PreserveInline | ValueListLayout::OnePerLine
-                        ) {
-                            let has_leading_newline = element.syntax().has_leading_newline();
-
-                            if has_leading_newline {
-                                write!(f, [hard_line_break()])?;
-                            } else {
-                                write!(f, [space()])?;
+                    if !is_comma(&element) {
+                        match layout {
+                            ValueListLayout::PreserveInline | ValueListLayout::OnePerLine => {
+                                let has_leading_newline = element.syntax().has_leading_newline();
+
+                                if has_leading_newline {
+                                    write!(f, [hard_line_break()])?;
+                                } else {
+                                    write!(f, [space()])?;
+                                }
+                            }
+                            ValueListLayout::OneRowPerLine => {
+                                if is_grid_row_start(element.syntax())
+                                    || element.syntax().has_leading_newline()
+                                {
+                                    write!(f, [hard_line_break()])?;
+                                } else {
+                                    write!(f, [space()])?;
+                                }
                             }
-                        } else {
-                            write!(f, [soft_line_break_or_space()])?
+                            _ => write!(f, [soft_line_break_or_space()])?,
                         }
                     }
 
@@ -89,7 +92,7 @@ where
         ValueListLayout::SingleValue => {
             write!(f, [values])
         }
-        ValueListLayout::OnePerLine => {
+        ValueListLayout::OnePerLine | ValueListLayout::OneRowPerLine => {
             let content = format_once(|f| {
                 write!(f, [hard_line_break()])?;
                 write!(f, [values])
@@ -97,9 +100,70 @@ where
 
             write!(f, [group(&indent(&content))])
         }
+        ValueListLayout::OneGroupPerLine => {
+            let groups = format_with(|f| {
+                let mut is_after_comma = false;
+
+                for (index, (element, formatted)) in
+                    node.iter().zip(node.iter().formatted()).enumerate()
+                {
+                    if is_comma(&element) {
+                        is_after_comma = true;
+                    } else {
+                        if is_after_comma {
+                            write!(f, [soft_line_break_or_space()])?;
+                        } else if index > 0 {
+                            write!(f, [space()])?;
+                        }
+                        is_after_comma = false;
+                    }
+
+                    write!(f, [formatted])?;
+                }
+
+                Ok(())
+            });
+
+            write!(
+                f,
+                [group(&indent(&format_args![soft_line_break(), groups]))]
+            )
+        }
     }
 }
 
+/// Returns `true` if `node` is the first value of a row of a grid template: a string,
+/// or the line names before it, as in `[header-start] "header header" 40px [header-end]`.
+fn is_grid_row_start(node: &CssSyntaxNode) -> bool {
+    let is_line_names = |node: &CssSyntaxNode| node.kind() == CssSyntaxKind::CSS_BRACKETED_VALUE;
+
+    match node.kind() {
+        CssSyntaxKind::CSS_STRING => !node
+            .prev_sibling()
+            .is_some_and(|prev| is_line_names(&prev) && is_grid_row_start(&prev)),
+        // The line names after the size of a row belong to that row:
+        // `"header header" 40px [header-end] [main-start] "main sidebar"`
+        CssSyntaxKind::CSS_BRACKETED_VALUE => {
+            node.next_sibling()
+                .is_some_and(|next| next.kind() == CssSyntaxKind::CSS_STRING)
+                && node
+                    .prev_sibling()
+                    .map_or(true, |prev| is_line_names(&prev))
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `element` is a comma delimiter.
+fn is_comma<I>(element: &I) -> bool
+where
+    I: AstNode<Language = CssLanguage>,
+{
+    CssGenericDelimiter::cast_ref(element.syntax())
+        .and_then(|node| node.value().ok())
+        .is_some_and(|token| token.kind() == CssSyntaxKind::COMMA)
+}
+
 #[derive(Copy, Clone, Debug)]
 pub(crate) enum ValueListLayout {
     /// Ensures the usage of a singular, consistent value.
@@ -160,6 +224,28 @@ pub(crate) enum ValueListLayout {
     ///     sans-serif;
     /// ```
     OnePerLine,
+
+    /// Prints every row of a grid template on its own line, so that the quotes of
+    /// the rows are aligned. The other values stay on the line of the row they follow.
+    ///
+    /// ```css
+    /// grid-template-areas:
+    ///     "header header"
+    ///     "main sidebar"
+    ///     "footer footer";
+    /// ```
+    OneRowPerLine,
+
+    /// Prints every comma-separated group of values on its own line if the whole
+    /// list exceeds the line width, and the groups on a single line otherwise.
+    ///
+    /// ```css
+    /// transition:
+    ///     opacity 0.3s ease-in-out,
+    ///     transform 0.3s ease-in-out,
+    ///     visibility 0.3s linear;
+    /// ```
+    OneGroupPerLine,
 }
 
 /// Returns the layout to use when printing the provided CssComponentValueList.
@@ -175,15 +261,16 @@ where
     N: AstNodeList<Language = CssLanguage, Node = I> + AstNode<Language = CssLanguage>,
     I: AstNode<Language = CssLanguage> + IntoFormat<CssFormatContext>,
 {
-    let is_grid_property = list
+    let property_name = list
         .parent::<CssGenericProperty>()
         .and_then(|parent| parent.name().ok())
-        .and_then(|name| name.as_css_identifier().map(|name| name.text()))
-        .map_or(false, |name| {
-            let name = name.to_ascii_lowercase_cow();
+        .and_then(|name| name.as_css_identifier().map(|name| name.text()));
 
-            name.starts_with("grid-template") || name == "grid"
-        });
+    let is_grid_property = property_name.as_ref().map_or(false, |name| {
+        let name = name.to_ascii_lowercase_cow();
+
+        name.starts_with("grid-template") || name == "grid"
+    });
 
     let text_size: TextSize = list
         .iter()
@@ -199,8 +286,29 @@ where
         .iter()
         .any(|x| CssGenericDelimiter::cast_ref(x.syntax()).is_some());
 
+    let row_count = list
+        .iter()
+        .filter(|x| x.syntax().kind() == CssSyntaxKind::CSS_STRING)
+        .count();
+
+    // A list such as `box-shadow` or `transition` with several values in one of its groups:
+    // `opacity 0.3s, transform 0.3s`
+    let has_multi_value_group = {
+        let mut values_in_group = 0;
+        list.iter().any(|x| {
+            if is_comma(&x) {
+                values_in_group = 0;
+            } else {
+                values_in_group += 1;
+            }
+            values_in_group > 1
+        })
+    };
+
     // TODO: Check for comments, check for the types of elements in the list, etc.
-    if is_grid_property {
+    if is_grid_property && row_count > 1 {
+        ValueListLayout::OneRowPerLine
+    } else if is_grid_property {
         ValueListLayout::PreserveInline
     } else if list.len() == 1 {
         ValueListLayout::SingleValue
@@ -209,6 +317,8 @@ where
         && text_size >= TextSize::from(f.options().line_width().value() as u32)
     {
         ValueListLayout::OnePerLine
+    } else if property_name.is_some() && is_comma_separated && has_multi_value_group {
+        ValueListLayout::OneGroupPerLine
     } else {
         ValueListLayout::Fill
     }
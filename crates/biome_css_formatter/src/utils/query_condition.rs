@@ -0,0 +1,82 @@
+use crate::prelude::*;
+use biome_css_syntax::CssSyntaxKind::{CSS_MEDIA_AND_CONDITION, CSS_MEDIA_AND_TYPE_QUERY};
+use biome_css_syntax::CssSyntaxNode;
+use biome_formatter::write;
+
+/// Formats a link of a chain of conditions combined with the same operator,
+/// such as `(min-width: 100px) and (max-width: 200px) and (color)` in a media or container query.
+///
+/// The chains are right-recursive: `(a) and (b) and (c)` is `(a) and [(b) and (c)]`.
+/// The root of the chain wraps all the links in a single group, so that the whole chain is
+/// printed on a single line when it fits, and every operator starts a new line otherwise:
+///
+/// ```css
+/// @media (min-width: 100px)
+///     and (max-width: 200px)
+///     and (orientation: landscape) {
+/// }
+/// ```
+///
+/// The indentation is added by the parent of the query, which is already indented
+/// in the preludes of the at-rules.
+pub(crate) struct FormatQueryConditionChain<'a, Left, Operator, Right> {
+    node: &'a CssSyntaxNode,
+    left: Left,
+    operator: Operator,
+    right: Right,
+}
+
+impl<'a, Left, Operator, Right> FormatQueryConditionChain<'a, Left, Operator, Right> {
+    pub(crate) fn new(
+        node: &'a CssSyntaxNode,
+        left: Left,
+        operator: Operator,
+        right: Right,
+    ) -> Self {
+        Self {
+            node,
+            left,
+            operator,
+            right,
+        }
+    }
+
+    /// Returns `true` if the node is the right side of a link of the same chain.
+    fn is_chain_continuation(&self) -> bool {
+        self.node.parent().is_some_and(|parent| {
+            parent.kind() == self.node.kind()
+                // `screen and (a) and (b)` is a single chain
+                || (self.node.kind() == CSS_MEDIA_AND_CONDITION
+                    && parent.kind() == CSS_MEDIA_AND_TYPE_QUERY)
+        })
+    }
+}
+
+impl<'a, Left, Operator, Right> Format<CssFormatContext>
+    for FormatQueryConditionChain<'a, Left, Operator, Right>
+where
+    Left: Format<CssFormatContext>,
+    Operator: Format<CssFormatContext>,
+    Right: Format<CssFormatContext>,
+{
+    fn fmt(&self, f: &mut CssFormatter) -> FormatResult<()> {
+        let content = format_with(|f| {
+            write!(
+                f,
+                [
+                    self.left,
+                    soft_line_break_or_space(),
+                    self.operator,
+                    space(),
+                    self.right
+                ]
+            )
+        });
+
+        if self.is_chain_continuation() {
+            write!(f, [content])
+        } else {
+            write!(f, [group(&content)])
+        }
+    }
+}
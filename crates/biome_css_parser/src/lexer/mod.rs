@@ -43,6 +43,11 @@ pub enum CssLexContext {
     /// support U+0-9A-F? U+0-9A-F{1,6} U+0-9A-F{1,6}?
     /// https://drafts.csswg.org/css-fonts/#unicode-range-desc
     UnicodeRange,
+
+    /// Applied when lexing the utility classes of the Tailwind CSS `@apply` directive.
+    /// Greedily consume a utility until encountering a whitespace, ";", "{" or "}"
+    /// support hover:bg-blue-500 md:w-1/2 [&>*]:p-4 -mt-4 !font-bold
+    TailwindUtility,
 }
 
 impl LexContext for CssLexContext {
@@ -132,6 +137,7 @@ impl<'src> Lexer<'src> for CssLexer<'src> {
                 CssLexContext::UrlRawValue => self.consume_url_raw_value_token(current),
                 CssLexContext::Color => self.consume_color_token(current),
                 CssLexContext::UnicodeRange => self.consume_unicode_range_token(current),
+                CssLexContext::TailwindUtility => self.consume_tailwind_utility_token(current),
             },
             None => EOF,
         };
@@ -492,6 +498,42 @@ impl<'src> CssLexer<'src> {
         CSS_URL_VALUE_RAW_LITERAL
     }
 
+    fn consume_tailwind_utility_token(&mut self, current: u8) -> CssSyntaxKind {
+        match lookup_byte(current) {
+            WHS | SEM | BEO | BEC => self.consume_token(current),
+            SLH if matches!(self.peek_byte(), Some(b'*')) => self.consume_token(current),
+            // `!important` marks all the utilities as important,
+            // whereas `!font-bold` is a single important utility.
+            EXL if self.is_at_tailwind_important() => self.consume_token(current),
+            _ => self.consume_tailwind_utility(),
+        }
+    }
+
+    /// Checks if the lexer is at a `!important` that isn't followed by any other
+    /// character of a utility.
+    fn is_at_tailwind_important(&self) -> bool {
+        let rest = self.source[self.position + 1..].trim_start_matches([' ', '\t']);
+
+        rest.get(..9)
+            .is_some_and(|keyword| keyword.eq_ignore_ascii_case("important"))
+            && rest.as_bytes().get(9).map_or(true, |chr| {
+                matches!(lookup_byte(*chr), WHS | SEM | BEO | BEC)
+            })
+    }
+
+    fn consume_tailwind_utility(&mut self) -> CssSyntaxKind {
+        while let Some(chr) = self.current_byte() {
+            match lookup_byte(chr) {
+                WHS | SEM | BEO | BEC => break,
+                SLH if matches!(self.peek_byte(), Some(b'*')) => break,
+                BSL if self.is_valid_escape_at(1) => self.advance(2),
+                _ => self.advance(1),
+            }
+        }
+
+        CSS_TAILWIND_UTILITY_LITERAL
+    }
+
     fn consume_pseudo_nth_selector_token(&mut self, current: u8) -> CssSyntaxKind {
         match current {
             b'-' => self.consume_byte(T![-]),
@@ -801,6 +843,29 @@ impl<'src> CssLexer<'src> {
             b"ridge" => RIDGE_KW,
             b"inset" => INSET_KW,
             b"outset" => OUTSET_KW,
+            b"anchor" => ANCHOR_KW,
+            b"anchor-size" => ANCHOR_SIZE_KW,
+            b"position-try" => POSITION_TRY_KW,
+            b"view-transition" => VIEW_TRANSITION_KW,
+            b"system" => SYSTEM_KW,
+            b"symbols" => SYMBOLS_KW,
+            b"additive-symbols" => ADDITIVE_SYMBOLS_KW,
+            b"range" => RANGE_KW,
+            b"fixed" => FIXED_KW,
+            b"extends" => EXTENDS_KW,
+            b"infinite" => INFINITE_KW,
+            b"custom-media" => CUSTOM_MEDIA_KW,
+            b"font-tech" => FONT_TECH_KW,
+            b"font-format" => FONT_FORMAT_KW,
+            b"color-mix" => COLOR_MIX_KW,
+            b"hue" => HUE_KW,
+            b"slotted" => SLOTTED_KW,
+            b"tailwind" => TAILWIND_KW,
+            b"apply" => APPLY_KW,
+            b"theme" => THEME_KW,
+            b"config" => CONFIG_KW,
+            b"variants" => VARIANTS_KW,
+            b"scroll-state" => SCROLL_STATE_KW,
             // CSS-Wide keywords
             b"initial" => INITIAL_KW,
             b"inherit" => INHERIT_KW,
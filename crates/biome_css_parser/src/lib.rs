@@ -7,7 +7,7 @@ use biome_css_factory::CssSyntaxFactory;
 use biome_css_syntax::{CssLanguage, CssRoot, CssSyntaxNode};
 pub use biome_parser::prelude::*;
 use biome_parser::{tree_sink::LosslessTreeSink, AnyParse};
-use biome_rowan::{AstNode, NodeCache};
+use biome_rowan::{AstNode, NodeCache, TextRange, TextSize};
 pub use parser::CssParserOptions;
 
 mod lexer;
@@ -46,6 +46,112 @@ pub fn parse_css_with_cache(
     })
 }
 
+/// Reparses the provided string as CSS program after an edit of the previously
+/// parsed program, using the provided node cache.
+///
+/// `changed_range` is the range of the previous source that was replaced by the
+/// edit, and `source` is the whole source after the edit. Only the top-level rules
+/// touched by the edit are reparsed, the green nodes of all other rules are reused.
+///
+/// Falls back to parsing the whole source when the edit doesn't stay within the
+/// top-level rules, or when either the previous program or the reparsed rules
+/// have diagnostics. `options` must be the options of the previous parse.
+///
+/// ```
+/// # use biome_css_parser::{parse_css, reparse_css_with_cache, CssParserOptions};
+/// # use biome_rowan::{NodeCache, TextRange, TextSize};
+/// let previous = parse_css("a { color: red; }\nb { color: blue; }", CssParserOptions::default());
+///
+/// // replace `red` with `green`
+/// let range = TextRange::new(TextSize::from(11), TextSize::from(14));
+/// let source = "a { color: green; }\nb { color: blue; }";
+/// let parse = reparse_css_with_cache(&previous, source, range, &mut NodeCache::default(), CssParserOptions::default());
+///
+/// assert_eq!(parse.syntax().to_string(), source);
+/// ```
+pub fn reparse_css_with_cache(
+    previous: &CssParse,
+    source: &str,
+    changed_range: TextRange,
+    cache: &mut NodeCache,
+    options: CssParserOptions,
+) -> CssParse {
+    tracing::debug_span!("Reparsing phase").in_scope(move || {
+        reparse_rules(previous, source, changed_range, cache, options)
+            .unwrap_or_else(|| parse_css_with_cache(source, cache, options))
+    })
+}
+
+/// Reparses the top-level rules touched by the edit and splices them into
+/// the previous tree, or returns `None` if the whole source must be parsed.
+fn reparse_rules(
+    previous: &CssParse,
+    source: &str,
+    changed_range: TextRange,
+    cache: &mut NodeCache,
+    options: CssParserOptions,
+) -> Option<CssParse> {
+    // The diagnostics of the previous parse would need to be moved along with the rules.
+    if !previous.diagnostics().is_empty() {
+        return None;
+    }
+
+    let rules = previous.tree().rules().into_syntax();
+    let unchanged_len = previous
+        .syntax()
+        .text_range()
+        .len()
+        .checked_sub(changed_range.len())?;
+    let inserted_len = TextSize::of(source).checked_sub(unchanged_len)?;
+
+    // An edit touching the end of a rule also touches the leading trivia of the next one.
+    let mut affected = rules.children().filter(|rule| {
+        let range = rule.text_range();
+        range.start() <= changed_range.end() && changed_range.start() <= range.end()
+    });
+    let first = affected.next()?;
+    let last = affected.last().unwrap_or_else(|| first.clone());
+
+    let start = first.text_range().start();
+    let end = last.text_range().end();
+
+    // The edit changes the byte order mark or the trivia before the end of the file.
+    if changed_range.start() < start || changed_range.end() > end {
+        return None;
+    }
+
+    let new_end = end - changed_range.len() + inserted_len;
+    let fragment = parse_css_with_cache(
+        source.get(usize::from(start)..usize::from(new_end))?,
+        cache,
+        options,
+    );
+
+    // The reparsed rules must be complete on their own. A leftover trivia belongs
+    // to the rule following the edit, which isn't part of the reparsed source.
+    let fragment_root = fragment.tree();
+    if !fragment.diagnostics().is_empty()
+        || fragment_root.bom_token().is_some()
+        || !fragment_root.eof_token().ok()?.text().is_empty()
+    {
+        return None;
+    }
+
+    let reparsed_rules = rules.clone().splice_slots(
+        first.index()..=last.index(),
+        fragment_root
+            .rules()
+            .syntax()
+            .children()
+            .map(|rule| Some(rule.into())),
+    );
+    let root = previous
+        .syntax()
+        .replace_child(rules.into(), reparsed_rules.into())?;
+
+    Some(CssParse::new(root, Vec::new()))
+}
+
 /// A utility struct for managing the result of a parser job
 #[derive(Debug)]
 pub struct CssParse {
@@ -121,7 +227,8 @@ impl From<CssParse> for AnyParse {
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_css, CssParserOptions};
+    use crate::{parse_css, reparse_css_with_cache, CssParserOptions};
+    use biome_rowan::{NodeCache, TextRange, TextSize};
 
     #[test]
     fn parser_smoke_test() {
@@ -130,4 +237,59 @@ mod tests {
 
         let _css = parse_css(src, CssParserOptions::default());
     }
+
+    /// Replaces `range` of `source` with `replacement`, and checks that the
+    /// reparsed tree matches the tree of parsing the edited source from scratch.
+    fn assert_reparse(source: &str, range: std::ops::Range<u32>, replacement: &str) {
+        let options = CssParserOptions::default();
+        let previous = parse_css(source, options);
+
+        let mut edited = source.to_string();
+        edited.replace_range(range.start as usize..range.end as usize, replacement);
+
+        let changed_range = TextRange::new(TextSize::from(range.start), TextSize::from(range.end));
+        let reparsed = reparse_css_with_cache(
+            &previous,
+            &edited,
+            changed_range,
+            &mut NodeCache::default(),
+            options,
+        );
+        let parsed = parse_css(&edited, options);
+
+        assert_eq!(reparsed.syntax().to_string(), edited);
+        assert_eq!(
+            format!("{:#?}", reparsed.syntax()),
+            format!("{:#?}", parsed.syntax())
+        );
+        assert_eq!(reparsed.diagnostics().len(), parsed.diagnostics().len());
+    }
+
+    #[test]
+    fn reparse_within_rule() {
+        let source = "a { color: red; }\nb { color: blue; }\nc { color: green; }\n";
+
+        // change a value
+        assert_reparse(source, 29..33, "black");
+        // append a declaration
+        assert_reparse(source, 34..34, " margin: 0;");
+        // remove the middle rule
+        assert_reparse(source, 18..36, "");
+        // insert a rule at the start of a rule
+        assert_reparse(source, 18..18, "@media print { d { color: red; } }\n");
+    }
+
+    #[test]
+    fn reparse_falls_back_to_full_parse() {
+        let source = "a { color: red; }\nb { color: blue; }\n";
+
+        // unclosed block that swallows the next rule
+        assert_reparse(source, 16..17, "");
+        // unclosed comment
+        assert_reparse(source, 4..4, "/*");
+        // trivia at the end of the file
+        assert_reparse(source, 37..37, "/* end */");
+        // previous source with errors
+        assert_reparse("a { color: red; \nb { color: blue; }", 11..14, "green");
+    }
 }
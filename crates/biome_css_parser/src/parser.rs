@@ -30,6 +30,19 @@ pub struct CssParserOptions {
     /// Defaults to `false`.
     pub css_modules: bool,
 
+    /// Enables parsing of the `@custom-media` at-rule.
+    /// Defaults to `false`.
+    pub custom_media: bool,
+
+    /// Enables parsing of the Tailwind CSS directives, such as `@tailwind` and `@apply`.
+    /// Defaults to `false`.
+    pub tailwind_directives: bool,
+
+    /// Keeps the block of unknown at-rules as written instead of parsing it
+    /// as CSS, so that nonstandard at-rules don't produce any errors.
+    /// Defaults to `false`.
+    pub raw_unknown_at_rules: bool,
+
     /// Enables parsing of Grit metavariables.
     /// Defaults to `false`.
     pub grit_metavariables: bool,
@@ -48,6 +61,24 @@ impl CssParserOptions {
         self
     }
 
+    /// Enables parsing of the `@custom-media` at-rule.
+    pub fn allow_custom_media(mut self) -> Self {
+        self.custom_media = true;
+        self
+    }
+
+    /// Enables parsing of the Tailwind CSS directives.
+    pub fn allow_tailwind_directives(mut self) -> Self {
+        self.tailwind_directives = true;
+        self
+    }
+
+    /// Keeps the block of unknown at-rules as written.
+    pub fn allow_raw_unknown_at_rules(mut self) -> Self {
+        self.raw_unknown_at_rules = true;
+        self
+    }
+
     /// Enables parsing of Grit metavariables.
     pub fn allow_metavariables(mut self) -> Self {
         self.grit_metavariables = true;
@@ -59,6 +90,21 @@ impl CssParserOptions {
         !self.css_modules
     }
 
+    /// Checks if parsing of the `@custom-media` at-rule is enabled.
+    pub fn is_custom_media_enabled(&self) -> bool {
+        self.custom_media
+    }
+
+    /// Checks if parsing of the Tailwind CSS directives is enabled.
+    pub fn is_tailwind_directives_enabled(&self) -> bool {
+        self.tailwind_directives
+    }
+
+    /// Checks if the block of unknown at-rules is kept as written.
+    pub fn is_raw_unknown_at_rules_enabled(&self) -> bool {
+        self.raw_unknown_at_rules
+    }
+
     /// Checks if parsing of Grit metavariables is enabled.
     pub fn is_metavariable_enabled(&self) -> bool {
         self.grit_metavariables
@@ -20,6 +20,16 @@ pub(crate) struct CssParserState {
     /// handling top-level `@rules` or style declarations directly under the stylesheet.
     /// This distinction is critical for correctly interpreting and parsing different sections of a CSS document.
     pub(crate) is_nesting_block: bool,
+
+    /// Indicates whether the parser is currently inside the block of a `@counter-style` at-rule.
+    ///
+    /// The descriptors of a counter style, such as `system` or `symbols`, are parsed into dedicated
+    /// nodes instead of generic properties, but only when they appear in this block.
+    pub(crate) is_counter_style_block: bool,
+
+    /// The required descriptors seen so far in the block of a `@property` at-rule,
+    /// or `None` when the parser isn't inside such a block.
+    pub(crate) property_descriptors: Option<PropertyDescriptors>,
 }
 
 impl CssParserState {
@@ -27,6 +37,40 @@ impl CssParserState {
         Self {
             speculative_parsing: false,
             is_nesting_block: false,
+            is_counter_style_block: false,
+            property_descriptors: None,
+        }
+    }
+}
+
+/// Tracks which of the required descriptors of `@property` have been seen.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct PropertyDescriptors {
+    has_syntax: bool,
+    has_inherits: bool,
+}
+
+impl PropertyDescriptors {
+    pub(crate) fn record(&mut self, name: &str) {
+        if name.eq_ignore_ascii_case("syntax") {
+            self.has_syntax = true;
+        } else if name.eq_ignore_ascii_case("inherits") {
+            self.has_inherits = true;
+        }
+    }
+
+    /// Returns the names of the required descriptors that haven't been seen.
+    pub(crate) fn missing(&self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+
+        if !self.has_syntax {
+            missing.push("syntax");
+        }
+
+        if !self.has_inherits {
+            missing.push("inherits");
         }
+
+        missing
     }
 }
@@ -1,11 +1,24 @@
 use crate::lexer::CssLexContext;
 use crate::parser::CssParser;
-use crate::syntax::at_rule::feature::parse_any_query_feature;
+use crate::syntax::at_rule::feature::{
+    is_at_query_feature_range_comparison, parse_any_query_feature,
+    parse_query_feature_range_comparison,
+};
+use crate::syntax::at_rule::parse_error::{
+    expected_any_container_query, expected_any_container_query_in_parens,
+    expected_any_container_scroll_state_query, expected_any_container_style_query,
+    expected_any_query_feature, expected_container_scroll_state_in_parens,
+    expected_container_style_in_parens, unexpected_container_query_tokens,
+};
 use crate::syntax::block::parse_conditional_block;
 use crate::syntax::parse_error::expected_non_css_wide_keyword_identifier;
-use crate::syntax::{is_at_declaration, parse_custom_identifier, parse_declaration};
+use crate::syntax::{
+    is_at_any_value, is_at_declaration, parse_any_value, parse_custom_identifier,
+    parse_declaration, try_parse,
+};
 use biome_css_syntax::CssSyntaxKind::*;
-use biome_css_syntax::T;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
 use biome_parser::parsed_syntax::ParsedSyntax::Present;
 use biome_parser::prelude::ParsedSyntax::Absent;
 use biome_parser::prelude::*;
@@ -25,9 +38,10 @@ pub(crate) fn parse_container_at_rule(p: &mut CssParser) -> ParsedSyntax {
 
     p.bump(T![container]);
 
-    if parse_custom_identifier(p, CssLexContext::Regular)
-        .ok()
-        .is_none()
+    if !is_at_container_name(p)
+        || parse_custom_identifier(p, CssLexContext::Regular)
+            .ok()
+            .is_none()
     {
         // Because the name is optional, we have to indirectly check if it's
         // a CSS-wide keyword that can't be used. If it was required, we could
@@ -40,10 +54,57 @@ pub(crate) fn parse_container_at_rule(p: &mut CssParser) -> ParsedSyntax {
         }
     };
 
-    parse_any_container_query(p).ok(); // TODO handle error
+    parse_any_container_query(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS_CONTAINER_QUERY, token_set![T!['{']]),
+            expected_any_container_query,
+        )
+        .ok();
+
+    // A query whose condition isn't wrapped in parentheses is recovered up to
+    // its closing parenthesis, e.g. `@container not width > 500px) {}`.
+    let kind = if p.at(T![')']) {
+        if let Ok(bogus) = ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T!['{']]).recover(p) {
+            p.error(unexpected_container_query_tokens(p, bogus.range(p)));
+        }
+
+        CSS_BOGUS_AT_RULE
+    } else {
+        CSS_CONTAINER_AT_RULE
+    };
+
     parse_conditional_block(p);
 
-    Present(m.complete(p, CSS_CONTAINER_AT_RULE))
+    Present(m.complete(p, kind))
+}
+
+/// Checks if the current token can be the name of the container, which excludes
+/// the `not` keyword and the `style()` and `scroll-state()` functions because
+/// they start the container query instead.
+#[inline]
+fn is_at_container_name(p: &mut CssParser) -> bool {
+    let is_at_query_function =
+        p.at_ts(token_set![T![style], T![scroll_state]]) && p.nth_at(1, T!['(']);
+
+    !p.at(T![not]) && !is_at_query_function
+}
+
+/// The tokens at which the parser resumes after an invalid condition of a container query.
+const CONTAINER_QUERY_RECOVERY_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![and], T![or], T![')'], T!['{']];
+
+/// Parses a condition of a `not`, `and` or `or` container query.
+///
+/// An invalid condition is recovered into a [CSS_BOGUS_CONTAINER_QUERY] that
+/// spans up to the next `and`, `or`, `)` or `{`.
+#[inline]
+fn parse_container_query_in_parens_or_recover(p: &mut CssParser) -> RecoveryResult {
+    parse_any_container_query_in_parens(p).or_recover_with_token_set(
+        p,
+        &ParseRecoveryTokenSet::new(CSS_BOGUS_CONTAINER_QUERY, CONTAINER_QUERY_RECOVERY_SET),
+        expected_any_container_query_in_parens,
+    )
 }
 
 #[inline]
@@ -57,13 +118,13 @@ fn parse_any_container_query(p: &mut CssParser) -> ParsedSyntax {
             T![and] => {
                 let m = query_in_parens.precede(p);
                 p.bump(T![and]);
-                parse_container_and_query(p).ok(); // TODO handle error
+                parse_container_and_query(p).ok();
                 Present(m.complete(p, CSS_CONTAINER_AND_QUERY))
             }
             T![or] => {
                 let m = query_in_parens.precede(p);
                 p.bump(T![or]);
-                parse_container_or_query(p).ok(); // TODO handle error
+                parse_container_or_query(p).ok();
                 Present(m.complete(p, CSS_CONTAINER_OR_QUERY))
             }
             _ => query_in_parens,
@@ -72,28 +133,34 @@ fn parse_any_container_query(p: &mut CssParser) -> ParsedSyntax {
 }
 
 #[inline]
-fn parse_container_and_query(p: &mut CssParser) -> ParsedSyntax {
-    let query_in_parens = parse_any_container_query_in_parens(p);
+fn parse_container_and_query(p: &mut CssParser) -> RecoveryResult {
+    let query_in_parens = parse_container_query_in_parens_or_recover(p);
 
     if p.at(T![and]) {
-        let m = query_in_parens.precede(p);
+        let m = match query_in_parens {
+            Ok(query_in_parens) => query_in_parens.precede(p),
+            Err(_) => p.start(),
+        };
         p.bump(T![and]);
-        parse_container_and_query(p).ok(); // TODO handle error
-        Present(m.complete(p, CSS_CONTAINER_AND_QUERY))
+        parse_container_and_query(p).ok();
+        Ok(m.complete(p, CSS_CONTAINER_AND_QUERY))
     } else {
         query_in_parens
     }
 }
 
 #[inline]
-fn parse_container_or_query(p: &mut CssParser) -> ParsedSyntax {
-    let query_in_parens = parse_any_container_query_in_parens(p);
+fn parse_container_or_query(p: &mut CssParser) -> RecoveryResult {
+    let query_in_parens = parse_container_query_in_parens_or_recover(p);
 
     if p.at(T![or]) {
-        let m = query_in_parens.precede(p);
+        let m = match query_in_parens {
+            Ok(query_in_parens) => query_in_parens.precede(p),
+            Err(_) => p.start(),
+        };
         p.bump(T![or]);
-        parse_container_or_query(p).ok(); // TODO handle error
-        Present(m.complete(p, CSS_CONTAINER_OR_QUERY))
+        parse_container_or_query(p).ok();
+        Ok(m.complete(p, CSS_CONTAINER_OR_QUERY))
     } else {
         query_in_parens
     }
@@ -112,7 +179,7 @@ fn parse_container_not_query(p: &mut CssParser) -> ParsedSyntax {
     let m = p.start();
 
     p.bump(T![not]);
-    parse_any_container_query_in_parens(p).ok(); // TODO handle error
+    parse_container_query_in_parens_or_recover(p).ok();
 
     Present(m.complete(p, CSS_CONTAINER_NOT_QUERY))
 }
@@ -122,6 +189,8 @@ fn parse_any_container_query_in_parens(p: &mut CssParser) -> ParsedSyntax {
         parse_container_query_in_parens(p)
     } else if is_at_container_style_query_in_parens(p) {
         parse_container_style_query_in_parens(p)
+    } else if is_at_container_scroll_state_query_in_parens(p) {
+        parse_container_scroll_state_query_in_parens(p)
     } else if is_at_container_size_feature_in_parens(p) {
         parse_container_size_feature_in_parens(p)
     } else {
@@ -143,8 +212,14 @@ fn parse_container_query_in_parens(p: &mut CssParser) -> ParsedSyntax {
     let m = p.start();
 
     p.bump(T!['(']);
-    parse_any_container_query(p).ok(); // TODO handle error
-    p.bump(T![')']);
+    parse_any_container_query(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS_CONTAINER_QUERY, token_set![T![')'], T!['{']]),
+            expected_any_container_query,
+        )
+        .ok();
+    p.expect(T![')']);
 
     Present(m.complete(p, CSS_CONTAINER_QUERY_IN_PARENS))
 }
@@ -163,10 +238,32 @@ fn parse_container_size_feature_in_parens(p: &mut CssParser) -> ParsedSyntax {
     let m = p.start();
 
     p.bump(T!['(']);
-    parse_any_query_feature(p).ok(); // TODO handle error
+
+    let recovery = ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![')'], T!['{']]);
+
+    let kind = match parse_any_query_feature(p) {
+        Present(_) if p.at(T![')']) || p.at(T!['{']) => CSS_CONTAINER_SIZE_FEATURE_IN_PARENS,
+        Present(_) => {
+            // (width > 500px 600px)
+            //                ^^^^^ unexpected tokens after the feature
+            if let Ok(bogus) = recovery.recover(p) {
+                p.error(unexpected_container_query_tokens(p, bogus.range(p)));
+            }
+
+            CSS_BOGUS_CONTAINER_QUERY
+        }
+        Absent => {
+            match Absent.or_recover_with_token_set(p, &recovery, expected_any_query_feature) {
+                Ok(_) => CSS_BOGUS_CONTAINER_QUERY,
+                // The feature is missing entirely, e.g. `()`
+                Err(_) => CSS_CONTAINER_SIZE_FEATURE_IN_PARENS,
+            }
+        }
+    };
+
     p.expect(T![')']);
 
-    Present(m.complete(p, CSS_CONTAINER_SIZE_FEATURE_IN_PARENS))
+    Present(m.complete(p, kind))
 }
 
 #[inline]
@@ -184,23 +281,91 @@ fn parse_container_style_query_in_parens(p: &mut CssParser) -> ParsedSyntax {
 
     p.bump(T![style]);
     p.expect(T!['(']);
-    parse_any_container_style_query(p).ok(); // TODO handle error
+    parse_any_container_style_query_or_recover(p).ok();
     p.expect(T![')']);
 
     Present(m.complete(p, CSS_CONTAINER_STYLE_QUERY_IN_PARENS))
 }
 
+/// Parses a style query, an invalid query is recovered into a
+/// [CSS_BOGUS_CONTAINER_QUERY] that spans up to the next `)` or `{`.
+#[inline]
+fn parse_any_container_style_query_or_recover(p: &mut CssParser) -> RecoveryResult {
+    parse_any_container_style_query(p).or_recover_with_token_set(
+        p,
+        &ParseRecoveryTokenSet::new(CSS_BOGUS_CONTAINER_QUERY, token_set![T![')'], T!['{']]),
+        expected_any_container_style_query,
+    )
+}
+
 #[inline]
 fn parse_any_container_style_query(p: &mut CssParser) -> ParsedSyntax {
     if is_at_container_style_not_query(p) {
         parse_container_style_not_query(p)
     } else if is_at_declaration(p) {
         parse_declaration(p)
+    } else if is_at_container_style_range(p) {
+        parse_container_style_range(p)
     } else {
         parse_any_container_style_combinable_query(p)
     }
 }
 
+#[inline]
+fn is_at_container_style_range(p: &mut CssParser) -> bool {
+    is_at_any_value(p) && !p.at(T!['('])
+}
+
+/// Parses a range of a style query, which compares a custom property with a value.
+///
+/// A range that isn't followed by the closing parenthesis of the style query
+/// is rewound and recovered by the caller.
+///
+/// ```css
+/// @container style(--gap > 10px) {}
+/// @container style(10px < --gap <= 20px) {}
+/// ```
+///
+/// https://drafts.csswg.org/css-conditional-5/#typedef-style-range
+#[inline]
+fn parse_container_style_range(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_container_style_range(p) {
+        return Absent;
+    }
+
+    try_parse(p, |p| {
+        let m = p.start();
+
+        parse_any_value(p).ok();
+
+        if parse_query_feature_range_comparison(p).is_absent() || parse_any_value(p).is_absent() {
+            m.abandon(p);
+            return Err(());
+        }
+
+        let kind = if is_at_query_feature_range_comparison(p) {
+            parse_query_feature_range_comparison(p).ok();
+
+            if parse_any_value(p).is_absent() {
+                m.abandon(p);
+                return Err(());
+            }
+
+            CSS_CONTAINER_STYLE_RANGE_INTERVAL
+        } else {
+            CSS_CONTAINER_STYLE_RANGE
+        };
+
+        if !p.at(T![')']) {
+            m.abandon(p);
+            return Err(());
+        }
+
+        Ok(Present(m.complete(p, kind)))
+    })
+    .unwrap_or(Absent)
+}
+
 #[inline]
 fn parse_any_container_style_combinable_query(p: &mut CssParser) -> ParsedSyntax {
     let style_in_parens = parse_container_style_in_parens(p);
@@ -209,13 +374,15 @@ fn parse_any_container_style_combinable_query(p: &mut CssParser) -> ParsedSyntax
         T![and] => {
             let m = style_in_parens.precede(p);
             p.bump(T![and]);
-            parse_any_container_style_combinable_query(p).ok(); // TODO handle error
+            parse_any_container_style_combinable_query(p)
+                .or_add_diagnostic(p, expected_container_style_in_parens);
             Present(m.complete(p, CSS_CONTAINER_STYLE_AND_QUERY))
         }
         T![or] => {
             let m = style_in_parens.precede(p);
             p.bump(T![or]);
-            parse_any_container_style_combinable_query(p).ok(); // TODO handle error
+            parse_any_container_style_combinable_query(p)
+                .or_add_diagnostic(p, expected_container_style_in_parens);
             Present(m.complete(p, CSS_CONTAINER_STYLE_OR_QUERY))
         }
         _ => style_in_parens,
@@ -236,7 +403,7 @@ fn parse_container_style_not_query(p: &mut CssParser) -> ParsedSyntax {
     let m = p.start();
 
     p.bump(T![not]);
-    parse_container_style_in_parens(p).ok(); // TODO handle error
+    parse_container_style_in_parens(p).or_add_diagnostic(p, expected_container_style_in_parens);
 
     Present(m.complete(p, CSS_CONTAINER_STYLE_NOT_QUERY))
 }
@@ -249,7 +416,115 @@ fn parse_container_style_in_parens(p: &mut CssParser) -> ParsedSyntax {
 
     let m = p.start();
     p.bump(T!['(']);
-    parse_any_container_style_query(p).ok(); // TODO handle error
+    parse_any_container_style_query_or_recover(p).ok();
     p.expect(T![')']);
     Present(m.complete(p, CSS_CONTAINER_STYLE_IN_PARENS))
 }
+
+#[inline]
+fn is_at_container_scroll_state_query_in_parens(p: &mut CssParser) -> bool {
+    p.at(T![scroll_state])
+}
+
+/// Parses a `scroll-state()` query, which queries the scroll state of a
+/// scroll container, e.g. whether a sticky positioned container is stuck.
+///
+/// ```css
+/// @container scroll-state(stuck: top) {}
+/// @container scroll-state((stuck: top) or (snapped: x)) {}
+/// ```
+///
+/// https://drafts.csswg.org/css-conditional-5/#scroll-state-container
+#[inline]
+fn parse_container_scroll_state_query_in_parens(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_container_scroll_state_query_in_parens(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![scroll_state]);
+    p.expect(T!['(']);
+    parse_any_container_scroll_state_query_or_recover(p).ok();
+    p.expect(T![')']);
+
+    Present(m.complete(p, CSS_CONTAINER_SCROLL_STATE_QUERY_IN_PARENS))
+}
+
+/// Parses a scroll-state query, an invalid query is recovered into a
+/// [CSS_BOGUS_CONTAINER_QUERY] that spans up to the next `)` or `{`.
+#[inline]
+fn parse_any_container_scroll_state_query_or_recover(p: &mut CssParser) -> RecoveryResult {
+    parse_any_container_scroll_state_query(p).or_recover_with_token_set(
+        p,
+        &ParseRecoveryTokenSet::new(CSS_BOGUS_CONTAINER_QUERY, token_set![T![')'], T!['{']]),
+        expected_any_container_scroll_state_query,
+    )
+}
+
+#[inline]
+fn parse_any_container_scroll_state_query(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_container_scroll_state_not_query(p) {
+        parse_container_scroll_state_not_query(p)
+    } else if p.at(T!['(']) {
+        parse_any_container_scroll_state_combinable_query(p)
+    } else {
+        parse_any_query_feature(p)
+    }
+}
+
+#[inline]
+fn parse_any_container_scroll_state_combinable_query(p: &mut CssParser) -> ParsedSyntax {
+    let scroll_state_in_parens = parse_container_scroll_state_in_parens(p);
+
+    match p.cur() {
+        T![and] => {
+            let m = scroll_state_in_parens.precede(p);
+            p.bump(T![and]);
+            parse_any_container_scroll_state_combinable_query(p)
+                .or_add_diagnostic(p, expected_container_scroll_state_in_parens);
+            Present(m.complete(p, CSS_CONTAINER_SCROLL_STATE_AND_QUERY))
+        }
+        T![or] => {
+            let m = scroll_state_in_parens.precede(p);
+            p.bump(T![or]);
+            parse_any_container_scroll_state_combinable_query(p)
+                .or_add_diagnostic(p, expected_container_scroll_state_in_parens);
+            Present(m.complete(p, CSS_CONTAINER_SCROLL_STATE_OR_QUERY))
+        }
+        _ => scroll_state_in_parens,
+    }
+}
+
+#[inline]
+fn is_at_container_scroll_state_not_query(p: &mut CssParser) -> bool {
+    p.at(T![not]) && p.nth_at(1, T!['('])
+}
+
+#[inline]
+fn parse_container_scroll_state_not_query(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_container_scroll_state_not_query(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![not]);
+    parse_container_scroll_state_in_parens(p)
+        .or_add_diagnostic(p, expected_container_scroll_state_in_parens);
+
+    Present(m.complete(p, CSS_CONTAINER_SCROLL_STATE_NOT_QUERY))
+}
+
+#[inline]
+fn parse_container_scroll_state_in_parens(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['(']);
+    parse_any_container_scroll_state_query_or_recover(p).ok();
+    p.expect(T![')']);
+    Present(m.complete(p, CSS_CONTAINER_SCROLL_STATE_IN_PARENS))
+}
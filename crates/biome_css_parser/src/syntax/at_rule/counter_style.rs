@@ -15,6 +15,20 @@ pub(crate) fn is_at_counter_style_at_rule(p: &mut CssParser) -> bool {
     p.at(T![counter_style])
 }
 
+/// Parses the `@counter-style` at-rule.
+///
+/// The `system`, `symbols`, `additive-symbols` and `range` descriptors of the
+/// block are parsed into dedicated nodes instead of generic properties.
+///
+/// ```css
+/// @counter-style thumbs {
+///     system: cyclic;
+///     symbols: "👍";
+///     suffix: " ";
+/// }
+/// ```
+///
+/// https://drafts.csswg.org/css-counter-styles-3/#the-counter-style-rule
 #[inline]
 pub(crate) fn parse_counter_style_at_rule(p: &mut CssParser) -> ParsedSyntax {
     if !is_at_counter_style_at_rule(p) {
@@ -39,7 +53,10 @@ pub(crate) fn parse_counter_style_at_rule(p: &mut CssParser) -> ParsedSyntax {
         CSS_BOGUS_AT_RULE
     };
 
+    let old_counter_style_block =
+        std::mem::replace(&mut p.state_mut().is_counter_style_block, true);
     parse_declaration_block(p);
+    p.state_mut().is_counter_style_block = old_counter_style_block;
 
     Present(m.complete(p, kind))
 }
@@ -0,0 +1,62 @@
+use crate::parser::CssParser;
+use crate::syntax::at_rule::media::MediaQueryList;
+use crate::syntax::parse_dashed_identifier;
+use crate::syntax::parse_error::expected_dashed_identifier;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseSeparatedList;
+use biome_parser::parse_recovery::ParseRecoveryTokenSet;
+use biome_parser::parsed_syntax::ParsedSyntax::Present;
+use biome_parser::prelude::ParsedSyntax::Absent;
+use biome_parser::prelude::*;
+
+#[inline]
+pub(crate) fn is_at_custom_media_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![custom_media])
+}
+
+/// Parses the `@custom-media` at-rule, which defines an alias for a media
+/// query list.
+///
+/// The at-rule is only parsed when the `custom_media` parser option is enabled,
+/// otherwise it's treated like any other unknown at-rule.
+///
+/// ```css
+/// @custom-media --narrow-window (max-width: 30em);
+///
+/// @media (--narrow-window) {}
+/// ```
+///
+/// https://drafts.csswg.org/mediaqueries-5/#custom-mq
+#[inline]
+pub(crate) fn parse_custom_media_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_custom_media_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![custom_media]);
+
+    let kind = if parse_dashed_identifier(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, CUSTOM_MEDIA_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_dashed_identifier,
+        )
+        .is_ok()
+    {
+        CSS_CUSTOM_MEDIA_AT_RULE
+    } else {
+        CSS_BOGUS_AT_RULE
+    };
+
+    MediaQueryList::new(T![;]).parse_list(p);
+
+    p.expect(T![;]);
+
+    Present(m.complete(p, kind))
+}
+
+const CUSTOM_MEDIA_RECOVERY_SET: TokenSet<CssSyntaxKind> = token_set![T!['('], T![;]];
@@ -1,4 +1,7 @@
 use crate::parser::CssParser;
+use crate::syntax::at_rule::parse_error::{
+    expected_query_feature_range_comparison, expected_query_feature_value,
+};
 use crate::syntax::parse_error::expected_identifier;
 use crate::syntax::{is_at_any_value, is_at_identifier, parse_any_value, parse_regular_identifier};
 use biome_css_syntax::CssSyntaxKind::*;
@@ -55,7 +58,7 @@ fn parse_query_feature_range(p: &mut CssParser) -> ParsedSyntax {
 
     parse_regular_identifier(p).ok();
     parse_query_feature_range_comparison(p).ok();
-    parse_any_query_feature_value(p).ok(); // TODO handle error
+    parse_any_query_feature_value(p).or_add_diagnostic(p, expected_query_feature_value);
 
     Present(m.complete(p, CSS_QUERY_FEATURE_RANGE))
 }
@@ -74,12 +77,13 @@ fn parse_query_feature_reverse_or_interval_range(p: &mut CssParser) -> ParsedSyn
     let m = p.start();
 
     parse_any_query_feature_value(p).ok();
-    parse_query_feature_range_comparison(p).ok();
-    parse_regular_identifier(p).ok(); // TODO handle error
+    parse_query_feature_range_comparison(p)
+        .or_add_diagnostic(p, expected_query_feature_range_comparison);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
 
     if is_at_query_feature_range_comparison(p) {
         parse_query_feature_range_comparison(p).ok();
-        parse_any_query_feature_value(p).ok(); // TODO handle error
+        parse_any_query_feature_value(p).or_add_diagnostic(p, expected_query_feature_value);
 
         Present(m.complete(p, CSS_QUERY_FEATURE_RANGE_INTERVAL))
     } else {
@@ -91,12 +95,12 @@ const QUERY_FEATURE_RANGE_COMPARISON_OPERATOR_SET: TokenSet<CssSyntaxKind> =
     token_set![T![>], T![<], T![>=], T![<=], T![=]];
 
 #[inline]
-fn is_at_query_feature_range_comparison(p: &mut CssParser) -> bool {
+pub(crate) fn is_at_query_feature_range_comparison(p: &mut CssParser) -> bool {
     p.at_ts(QUERY_FEATURE_RANGE_COMPARISON_OPERATOR_SET)
 }
 
 #[inline]
-fn parse_query_feature_range_comparison(p: &mut CssParser) -> ParsedSyntax {
+pub(crate) fn parse_query_feature_range_comparison(p: &mut CssParser) -> ParsedSyntax {
     if !is_at_query_feature_range_comparison(p) {
         return Absent;
     }
@@ -123,7 +127,7 @@ fn parse_query_feature_plain(p: &mut CssParser) -> ParsedSyntax {
 
     parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
     p.bump(T![:]);
-    parse_any_query_feature_value(p).ok(); // TODO handle error
+    parse_any_query_feature_value(p).or_add_diagnostic(p, expected_query_feature_value);
 
     Present(m.complete(p, CSS_QUERY_FEATURE_PLAIN))
 }
@@ -113,7 +113,7 @@ fn parse_keyframes_scoped_name(p: &mut CssParser) -> ParsedSyntax {
 
         // Skip the entire pseudo-class function selector
         // Skip until the next opening curly brace
-        while !p.at(T!['{']) {
+        while !(p.at(EOF) || p.at(T!['{'])) {
             p.bump_any();
         }
 
@@ -1,5 +1,6 @@
 use crate::parser::CssParser;
 
+use crate::syntax::at_rule::parse_error::unexpected_layer_block_names;
 use crate::syntax::block::parse_conditional_block;
 use crate::syntax::parse_error::expected_identifier;
 use crate::syntax::parse_regular_identifier;
@@ -31,15 +32,37 @@ pub(crate) fn parse_layer_at_rule(p: &mut CssParser) -> ParsedSyntax {
     Present(m.complete(p, CSS_LAYER_AT_RULE))
 }
 
+/// Parses the statement form or the block form of the `@layer` at-rule.
+///
+/// The statement form declares the order of one or more layers, while the block
+/// form declares the rules of at most one, possibly anonymous, layer.
+///
+/// ```css
+/// @layer reset, framework.base, utilities;
+///
+/// @layer framework.base {
+///     h1 { color: maroon; }
+/// }
+/// ```
+///
+/// https://drafts.csswg.org/css-cascade-5/#layering
 #[inline]
 pub(crate) fn parse_any_layer(p: &mut CssParser) -> CompletedMarker {
     let m = p.start();
 
-    LayerReferenceList.parse_list(p);
+    let mut references = LayerReferenceList::default();
+    let list = references.parse_list(p);
 
     let kind = if p.at(T!['{']) {
+        let kind = if references.len > 1 {
+            p.error(unexpected_layer_block_names(p, list.range(p)));
+            CSS_BOGUS_LAYER
+        } else {
+            CSS_LAYER_DECLARATION
+        };
+
         parse_conditional_block(p);
-        CSS_LAYER_DECLARATION
+        kind
     } else if p.expect(T![;]) {
         CSS_LAYER_REFERENCE
     } else {
@@ -52,7 +75,11 @@ const LAYER_REFERENCE_LIST_END_SET: TokenSet<CssSyntaxKind> = token_set!(T!['{']
 const LAYER_REFERENCE_LIST_RECOVERY_SET: TokenSet<CssSyntaxKind> =
     LAYER_REFERENCE_LIST_END_SET.union(token_set!(T![,]));
 
-struct LayerReferenceList;
+#[derive(Default)]
+struct LayerReferenceList {
+    /// The number of the parsed layer names.
+    len: usize,
+}
 
 impl ParseSeparatedList for LayerReferenceList {
     type Kind = CssSyntaxKind;
@@ -60,6 +87,7 @@ impl ParseSeparatedList for LayerReferenceList {
     const LIST_KIND: Self::Kind = CSS_LAYER_REFERENCE_LIST;
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        self.len += 1;
         LayerNameList.parse_list(p).into()
     }
 
@@ -2,6 +2,7 @@ mod charset;
 mod color_profile;
 mod container;
 mod counter_style;
+mod custom_media;
 mod document;
 mod feature;
 mod font_face;
@@ -14,18 +15,22 @@ mod media;
 mod namespace;
 mod page;
 mod parse_error;
+mod position_try;
 mod property;
 mod scope;
 mod starting_style;
 mod supports;
+mod tailwind;
 mod unknown;
 mod value;
+mod view_transition;
 
 use crate::parser::CssParser;
 use crate::syntax::at_rule::charset::parse_charset_at_rule;
 use crate::syntax::at_rule::color_profile::parse_color_profile_at_rule;
 use crate::syntax::at_rule::container::parse_container_at_rule;
 use crate::syntax::at_rule::counter_style::parse_counter_style_at_rule;
+use crate::syntax::at_rule::custom_media::parse_custom_media_at_rule;
 use crate::syntax::at_rule::document::parse_document_at_rule;
 use crate::syntax::at_rule::font_face::parse_font_face_at_rule;
 use crate::syntax::at_rule::font_feature_values::parse_font_feature_values_at_rule;
@@ -36,12 +41,18 @@ use crate::syntax::at_rule::layer::parse_layer_at_rule;
 use crate::syntax::at_rule::media::parse_media_at_rule;
 use crate::syntax::at_rule::namespace::parse_namespace_at_rule;
 use crate::syntax::at_rule::page::parse_page_at_rule;
+use crate::syntax::at_rule::position_try::parse_position_try_at_rule;
 use crate::syntax::at_rule::property::parse_property_at_rule;
 use crate::syntax::at_rule::scope::parse_scope_at_rule;
 use crate::syntax::at_rule::starting_style::parse_starting_style_at_rule;
 use crate::syntax::at_rule::supports::parse_supports_at_rule;
+use crate::syntax::at_rule::tailwind::{
+    parse_tailwind_apply_at_rule, parse_tailwind_at_rule, parse_tailwind_config_at_rule,
+    parse_tailwind_theme_at_rule, parse_tailwind_variants_at_rule,
+};
 use crate::syntax::at_rule::unknown::{is_at_unknown_at_rule, parse_unknown_at_rule};
 use crate::syntax::at_rule::value::parse_value_at_rule;
+use crate::syntax::at_rule::view_transition::parse_view_transition_at_rule;
 use crate::syntax::parse_error::expected_any_at_rule;
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::T;
@@ -88,6 +99,7 @@ pub(crate) fn parse_any_at_rule(p: &mut CssParser) -> ParsedSyntax {
         T![font_feature_values] => parse_font_feature_values_at_rule(p),
         T![font_palette_values] => parse_font_palette_values_at_rule(p),
         T![media] => parse_media_at_rule(p),
+        T![custom_media] if p.options().is_custom_media_enabled() => parse_custom_media_at_rule(p),
         T![keyframes] => parse_keyframes_at_rule(p),
         T![page] => parse_page_at_rule(p),
         T![layer] => parse_layer_at_rule(p),
@@ -98,7 +110,22 @@ pub(crate) fn parse_any_at_rule(p: &mut CssParser) -> ParsedSyntax {
         T![starting_style] => parse_starting_style_at_rule(p),
         T![document] => parse_document_at_rule(p),
         T![property] => parse_property_at_rule(p),
+        T![position_try] => parse_position_try_at_rule(p),
+        T![view_transition] => parse_view_transition_at_rule(p),
         T![value] => parse_value_at_rule(p),
+        T![tailwind] if p.options().is_tailwind_directives_enabled() => parse_tailwind_at_rule(p),
+        T![apply] if p.options().is_tailwind_directives_enabled() => {
+            parse_tailwind_apply_at_rule(p)
+        }
+        T![theme] if p.options().is_tailwind_directives_enabled() => {
+            parse_tailwind_theme_at_rule(p)
+        }
+        T![config] if p.options().is_tailwind_directives_enabled() => {
+            parse_tailwind_config_at_rule(p)
+        }
+        T![variants] if p.options().is_tailwind_directives_enabled() => {
+            parse_tailwind_variants_at_rule(p)
+        }
         _ if is_at_unknown_at_rule(p) => parse_unknown_at_rule(p),
         _ => Absent,
     }
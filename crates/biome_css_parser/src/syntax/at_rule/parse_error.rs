@@ -1,6 +1,7 @@
 use crate::parser::CssParser;
 use biome_parser::diagnostic::{expect_one_of, expected_node, ToDiagnostic};
 use biome_parser::prelude::ParseDiagnostic;
+use biome_parser::Parser;
 use biome_rowan::TextRange;
 
 pub(crate) fn expected_media_query(p: &CssParser, range: TextRange) -> ParseDiagnostic {
@@ -86,3 +87,107 @@ pub(crate) fn expected_any_font_feature_value_item(
 pub(crate) fn expected_any_font_family_name(p: &CssParser, range: TextRange) -> ParseDiagnostic {
     expect_one_of(&["<family-name>", "<string>"], range).into_diagnostic(p)
 }
+
+pub(crate) fn missing_property_descriptors(
+    p: &CssParser,
+    descriptors: &[&str],
+    range: TextRange,
+) -> ParseDiagnostic {
+    let names = descriptors
+        .iter()
+        .map(|descriptor| format!("'{descriptor}'"))
+        .collect::<Vec<_>>()
+        .join(" and ");
+    let noun = if descriptors.len() > 1 {
+        "descriptors"
+    } else {
+        "descriptor"
+    };
+
+    p.err_builder(
+        format!("The @property rule is missing the required {names} {noun}."),
+        range,
+    )
+    .with_hint("A custom property is only registered when both the 'syntax' and the 'inherits' descriptors are present.")
+}
+
+pub(crate) fn expected_any_container_query(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("container query", range, p)
+}
+
+pub(crate) fn expected_any_container_query_in_parens(
+    p: &CssParser,
+    range: TextRange,
+) -> ParseDiagnostic {
+    expect_one_of(
+        &[
+            "(<container-query>)",
+            "(<size-feature>)",
+            "style(<style-query>)",
+            "scroll-state(<scroll-state-query>)",
+        ],
+        range,
+    )
+    .into_diagnostic(p)
+}
+
+pub(crate) fn expected_any_container_style_query(
+    p: &CssParser,
+    range: TextRange,
+) -> ParseDiagnostic {
+    expect_one_of(&["<style-feature>", "(<style-query>)"], range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_container_style_in_parens(
+    p: &CssParser,
+    range: TextRange,
+) -> ParseDiagnostic {
+    expected_node("(<style-query>)", range, p)
+}
+
+pub(crate) fn expected_any_container_scroll_state_query(
+    p: &CssParser,
+    range: TextRange,
+) -> ParseDiagnostic {
+    expect_one_of(&["<scroll-state-feature>", "(<scroll-state-query>)"], range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_container_scroll_state_in_parens(
+    p: &CssParser,
+    range: TextRange,
+) -> ParseDiagnostic {
+    expected_node("(<scroll-state-query>)", range, p)
+}
+
+pub(crate) fn expected_any_query_feature(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("query feature", range, p)
+}
+
+pub(crate) fn expected_query_feature_value(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("query feature value", range, p)
+}
+
+pub(crate) fn expected_query_feature_range_comparison(
+    p: &CssParser,
+    range: TextRange,
+) -> ParseDiagnostic {
+    expect_one_of(&["<", "<=", ">", ">=", "="], range).into_diagnostic(p)
+}
+
+pub(crate) fn unexpected_container_query_tokens(
+    p: &CssParser,
+    range: TextRange,
+) -> ParseDiagnostic {
+    p.err_builder("Unexpected value or character in the container query.", range)
+        .with_hint("Each condition of a container query must be wrapped in parentheses, e.g. `(width > 500px)`.")
+}
+
+pub(crate) fn unexpected_layer_block_names(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(
+        "A `@layer` block can only declare a single layer name.",
+        range,
+    )
+    .with_hint(
+        "Declare the order of the layers with a separate statement, e.g. `@layer reset, base;`.",
+    )
+}
@@ -0,0 +1,57 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_block;
+use crate::syntax::parse_dashed_identifier;
+use crate::syntax::parse_error::expected_dashed_identifier;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_recovery::ParseRecoveryTokenSet;
+use biome_parser::parsed_syntax::ParsedSyntax::Present;
+use biome_parser::prelude::ParsedSyntax::Absent;
+use biome_parser::prelude::*;
+
+#[inline]
+pub(crate) fn is_at_position_try_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![position_try])
+}
+
+/// Parses the `@position-try` at-rule, which defines a position option for
+/// anchor positioned elements.
+///
+/// ```css
+/// @position-try --custom-bottom {
+///     top: anchor(bottom);
+///     left: anchor(center);
+/// }
+/// ```
+///
+/// https://drafts.csswg.org/css-anchor-position-1/#fallback-rule
+#[inline]
+pub(crate) fn parse_position_try_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_position_try_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![position_try]);
+
+    let kind = if parse_dashed_identifier(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, POSITION_TRY_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_dashed_identifier,
+        )
+        .is_ok()
+    {
+        CSS_POSITION_TRY_AT_RULE
+    } else {
+        CSS_BOGUS_AT_RULE
+    };
+
+    parse_declaration_block(p);
+
+    Present(m.complete(p, kind))
+}
+
+const POSITION_TRY_RECOVERY_SET: TokenSet<CssSyntaxKind> = token_set![T!['{']];
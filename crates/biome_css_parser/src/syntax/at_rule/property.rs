@@ -8,6 +8,8 @@ use biome_parser::TokenSet;
 use biome_parser::{parsed_syntax::ParsedSyntax, Parser};
 
 use crate::parser::CssParser;
+use crate::state::PropertyDescriptors;
+use crate::syntax::at_rule::parse_error::missing_property_descriptors;
 use crate::syntax::block::parse_declaration_block;
 use crate::syntax::parse_dashed_identifier;
 use crate::syntax::parse_error::expected_dashed_identifier;
@@ -17,6 +19,21 @@ pub(crate) fn is_at_property_at_rule(p: &mut CssParser) -> bool {
     p.at(T![property])
 }
 
+/// Parses the `@property` at-rule.
+///
+/// A registered custom property is only valid when both the `syntax` and the
+/// `inherits` descriptors are present, so the parser emits a diagnostic when
+/// any of them is missing from the block.
+///
+/// ```css
+/// @property --my-color {
+///     syntax: "<color>";
+///     inherits: false;
+///     initial-value: #c0ffee;
+/// }
+/// ```
+///
+/// https://drafts.css-houdini.org/css-properties-values-api/#at-property-rule
 #[inline]
 pub(crate) fn parse_property_at_rule(p: &mut CssParser) -> ParsedSyntax {
     if !is_at_property_at_rule(p) {
@@ -41,7 +58,26 @@ pub(crate) fn parse_property_at_rule(p: &mut CssParser) -> ParsedSyntax {
         CSS_BOGUS_AT_RULE
     };
 
-    parse_declaration_block(p);
+    p.state_mut().property_descriptors = Some(PropertyDescriptors::default());
+    let mut block = parse_declaration_block(p);
+    let descriptors = p
+        .state_mut()
+        .property_descriptors
+        .take()
+        .unwrap_or_default();
+
+    // Only validate the descriptors of a well-formed block, otherwise the
+    // missing braces have already been reported.
+    if block.kind(p) == CSS_DECLARATION_BLOCK {
+        block.change_kind(p, CSS_PROPERTY_AT_RULE_BLOCK);
+
+        let missing = descriptors.missing();
+
+        if !missing.is_empty() {
+            let range = block.range(p);
+            p.error(missing_property_descriptors(p, &missing, range));
+        }
+    }
 
     Present(m.complete(p, kind))
 }
@@ -2,9 +2,11 @@ use crate::parser::CssParser;
 use crate::syntax::block::parse_conditional_block;
 use crate::syntax::selector::parse_selector;
 use crate::syntax::value::function::{is_at_function, parse_function};
-use crate::syntax::{is_nth_at_identifier, parse_any_value, parse_declaration};
+use crate::syntax::{
+    is_nth_at_identifier, parse_any_value, parse_declaration, parse_regular_identifier, try_parse,
+};
 use biome_css_syntax::CssSyntaxKind::*;
-use biome_css_syntax::T;
+use biome_css_syntax::{CssSyntaxKind, T};
 use biome_parser::parsed_syntax::ParsedSyntax::Present;
 use biome_parser::prelude::ParsedSyntax::Absent;
 use biome_parser::prelude::*;
@@ -104,6 +106,10 @@ fn parse_supports_not_condition(p: &mut CssParser) -> ParsedSyntax {
 fn parse_any_supports_condition_in_parens(p: &mut CssParser) -> ParsedSyntax {
     if is_at_supports_feature_selector(p) {
         parse_supports_feature_selector(p)
+    } else if is_at_supports_feature_font_tech(p) {
+        parse_supports_feature_font_tech(p).or_else(|| parse_function(p))
+    } else if is_at_supports_feature_font_format(p) {
+        parse_supports_feature_font_format(p).or_else(|| parse_function(p))
     } else if is_at_supports_feature_declaration(p) {
         parse_supports_feature_declaration(p)
     } else if is_at_function(p) {
@@ -156,6 +162,74 @@ fn parse_supports_feature_selector(p: &mut CssParser) -> ParsedSyntax {
     Present(m.complete(p, CSS_SUPPORTS_FEATURE_SELECTOR))
 }
 
+#[inline]
+fn is_at_supports_feature_font_tech(p: &mut CssParser) -> bool {
+    p.at(T![font_tech]) && p.nth_at(1, T!['('])
+}
+
+/// Parses the `font-tech()` function of a `@supports` condition.
+///
+/// ```css
+/// @supports font-tech(color-COLRv1) {}
+/// ```
+///
+/// https://drafts.csswg.org/css-conditional-5/#typedef-supports-font-tech-fn
+#[inline]
+fn parse_supports_feature_font_tech(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_supports_feature_font_tech(p) {
+        return Absent;
+    }
+
+    parse_supports_feature_font_function(p, T![font_tech], CSS_SUPPORTS_FEATURE_FONT_TECH)
+}
+
+#[inline]
+fn is_at_supports_feature_font_format(p: &mut CssParser) -> bool {
+    p.at(T![font_format]) && p.nth_at(1, T!['('])
+}
+
+/// Parses the `font-format()` function of a `@supports` condition.
+///
+/// ```css
+/// @supports font-format(woff2) {}
+/// ```
+///
+/// https://drafts.csswg.org/css-conditional-5/#typedef-supports-font-format-fn
+#[inline]
+fn parse_supports_feature_font_format(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_supports_feature_font_format(p) {
+        return Absent;
+    }
+
+    parse_supports_feature_font_function(p, T![font_format], CSS_SUPPORTS_FEATURE_FONT_FORMAT)
+}
+
+/// Parses a `font-tech()` or `font-format()` function whose argument is a single keyword.
+///
+/// Any other argument list is rewound, so the caller can parse it as a
+/// `<general-enclosed>` function, which evaluates to false but is still valid.
+#[inline]
+fn parse_supports_feature_font_function(
+    p: &mut CssParser,
+    keyword: CssSyntaxKind,
+    kind: CssSyntaxKind,
+) -> ParsedSyntax {
+    try_parse(p, |p| {
+        let m = p.start();
+
+        p.bump(keyword);
+        p.bump(T!['(']);
+
+        if parse_regular_identifier(p).is_absent() || !p.eat(T![')']) {
+            m.abandon(p);
+            return Err(());
+        }
+
+        Ok(Present(m.complete(p, kind)))
+    })
+    .unwrap_or(Absent)
+}
+
 #[inline]
 fn is_at_supports_feature_declaration(p: &mut CssParser) -> bool {
     p.at(T!['(']) && is_nth_at_identifier(p, 1) && p.nth_at(2, T![:])
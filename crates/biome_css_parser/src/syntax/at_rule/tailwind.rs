@@ -0,0 +1,212 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::block::{parse_conditional_block, parse_declaration_or_at_rule_list_block};
+use crate::syntax::parse_error::{expected_identifier, expected_string};
+use crate::syntax::{
+    is_at_identifier, parse_declaration_important, parse_regular_identifier, parse_string,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, TextRange, T};
+use biome_parser::diagnostic::ParseDiagnostic;
+use biome_parser::parse_recovery::ParseRecoveryTokenSet;
+use biome_parser::parsed_syntax::ParsedSyntax::Present;
+use biome_parser::prelude::ParsedSyntax::Absent;
+use biome_parser::prelude::*;
+
+/// Parses the `@tailwind` directive, which inserts one of the Tailwind CSS layers.
+///
+/// The Tailwind CSS directives are only parsed when the `tailwind_directives`
+/// parser option is enabled, otherwise they're treated like any other unknown at-rule.
+///
+/// ```css
+/// @tailwind base;
+/// @tailwind components;
+/// @tailwind utilities;
+/// ```
+///
+/// https://v3.tailwindcss.com/docs/functions-and-directives#tailwind
+#[inline]
+pub(crate) fn parse_tailwind_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![tailwind]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![tailwind]);
+
+    let kind = if parse_regular_identifier(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, TAILWIND_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_identifier,
+        )
+        .is_ok()
+    {
+        CSS_TAILWIND_AT_RULE
+    } else {
+        CSS_BOGUS_AT_RULE
+    };
+
+    p.expect(T![;]);
+
+    Present(m.complete(p, kind))
+}
+
+/// Parses the `@apply` directive, which inlines the styles of the given utility classes.
+///
+/// The utilities are lexed as opaque literals, so variants and arbitrary values
+/// such as `hover:bg-blue-700` or `w-[calc(100%-1rem)]` don't need to be valid CSS.
+/// Like a declaration, the `;` may be omitted at the end of a block.
+///
+/// ```css
+/// .btn {
+///     @apply font-bold py-2 px-4 rounded hover:bg-blue-700 !important;
+/// }
+/// ```
+///
+/// https://tailwindcss.com/docs/functions-and-directives#apply-directive
+#[inline]
+pub(crate) fn parse_tailwind_apply_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![apply]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump_with_context(T![apply], CssLexContext::TailwindUtility);
+
+    let utilities = p.start();
+    while p.at(CSS_TAILWIND_UTILITY_LITERAL) {
+        let utility = p.start();
+        p.bump_with_context(CSS_TAILWIND_UTILITY_LITERAL, CssLexContext::TailwindUtility);
+        utility.complete(p, CSS_TAILWIND_UTILITY);
+    }
+    let utilities = utilities.complete(p, CSS_TAILWIND_UTILITY_LIST);
+
+    if utilities.range(p).is_empty() {
+        p.error(expected_tailwind_utility(p, p.cur_range()));
+    }
+
+    parse_declaration_important(p).ok();
+
+    if !p.at(T!['}']) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, CSS_TAILWIND_APPLY_AT_RULE))
+}
+
+/// Parses the `@theme` directive, which defines the design tokens of a project.
+///
+/// ```css
+/// @theme inline {
+///     --font-display: "Satoshi", sans-serif;
+///     --color-primary: var(--brand);
+/// }
+/// ```
+///
+/// https://tailwindcss.com/docs/functions-and-directives#theme-directive
+#[inline]
+pub(crate) fn parse_tailwind_theme_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![theme]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![theme]);
+
+    let modifiers = p.start();
+    while is_at_identifier(p) {
+        parse_regular_identifier(p).ok();
+    }
+    modifiers.complete(p, CSS_TAILWIND_THEME_MODIFIER_LIST);
+
+    parse_declaration_or_at_rule_list_block(p);
+
+    Present(m.complete(p, CSS_TAILWIND_THEME_AT_RULE))
+}
+
+/// Parses the `@config` directive, which loads a legacy JavaScript configuration file.
+///
+/// ```css
+/// @config "./tailwind.config.js";
+/// ```
+///
+/// https://tailwindcss.com/docs/functions-and-directives#config-directive
+#[inline]
+pub(crate) fn parse_tailwind_config_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![config]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![config]);
+
+    let kind = if parse_string(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, TAILWIND_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_string,
+        )
+        .is_ok()
+    {
+        CSS_TAILWIND_CONFIG_AT_RULE
+    } else {
+        CSS_BOGUS_AT_RULE
+    };
+
+    p.expect(T![;]);
+
+    Present(m.complete(p, kind))
+}
+
+/// Parses the `@variants` directive, which generates the given variants of the
+/// rules of its block.
+///
+/// ```css
+/// @variants hover, focus {
+///     .btn-brand {
+///         background-color: #3182ce;
+///     }
+/// }
+/// ```
+///
+/// https://v2.tailwindcss.com/docs/functions-and-directives#variants
+#[inline]
+pub(crate) fn parse_tailwind_variants_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![variants]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![variants]);
+
+    let variants = p.start();
+    loop {
+        if parse_regular_identifier(p)
+            .or_add_diagnostic(p, expected_identifier)
+            .is_none()
+            || !p.eat(T![,])
+        {
+            break;
+        }
+    }
+    variants.complete(p, CSS_TAILWIND_VARIANT_LIST);
+
+    parse_conditional_block(p);
+
+    Present(m.complete(p, CSS_TAILWIND_VARIANTS_AT_RULE))
+}
+
+const TAILWIND_RECOVERY_SET: TokenSet<CssSyntaxKind> = token_set![T![;]];
+
+fn expected_tailwind_utility(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("Expected at least one utility class here.", range)
+        .with_hint("The `@apply` directive requires a list of utility classes, e.g. `@apply font-bold py-2;`.")
+}
@@ -2,10 +2,11 @@ use crate::parser::CssParser;
 use crate::syntax::block::parse_declaration_or_rule_list_block;
 use crate::syntax::{is_at_identifier, parse_regular_identifier};
 use biome_css_syntax::CssSyntaxKind::*;
-use biome_css_syntax::T;
+use biome_css_syntax::{CssSyntaxKind, T};
 use biome_parser::parsed_syntax::ParsedSyntax::Present;
 use biome_parser::prelude::ParsedSyntax::Absent;
 use biome_parser::prelude::*;
+use biome_parser::CompletedMarker;
 
 /// Checks if the parser is currently at an unknown CSS at-rule.
 ///
@@ -22,6 +23,9 @@ pub(crate) fn is_at_unknown_at_rule(p: &mut CssParser) -> bool {
 /// allowing the parser to continue processing the stylesheet by treating the unsupported rule
 /// as part of an unformed tree. This enables graceful handling of CSS constructs that are not
 /// supported by the parser, ensuring that the stylesheet can still be processed without errors.
+///
+/// When the `raw_unknown_at_rules` parser option is enabled, the block isn't parsed as CSS
+/// but kept as written, and a `{` or `;` nested inside brackets of the prelude doesn't end it.
 #[inline]
 pub(crate) fn parse_unknown_at_rule(p: &mut CssParser) -> ParsedSyntax {
     if !is_at_unknown_at_rule(p) {
@@ -32,22 +36,32 @@ pub(crate) fn parse_unknown_at_rule(p: &mut CssParser) -> ParsedSyntax {
 
     parse_regular_identifier(p).ok(); // we've checked that the next token is an identifier
 
+    let is_raw = p.options().is_raw_unknown_at_rules_enabled();
+
     {
         let m = p.start();
 
-        // Skip all tokens until the end of the property value or the next property.
-        // EOF indicates the end of the file.
-        // '{' indicates the start of a block.
-        // ';' indicates the end of the property value.
-        while !(p.at(EOF) || p.at(T!['{']) || p.at(T![;])) {
-            p.bump_any();
+        if is_raw {
+            skip_balanced_tokens(p, UNKNOWN_AT_RULE_PRELUDE_END_SET);
+        } else {
+            // Skip all tokens until the end of the property value or the next property.
+            // EOF indicates the end of the file.
+            // '{' indicates the start of a block.
+            // ';' indicates the end of the property value.
+            while !(p.at(EOF) || p.at(T!['{']) || p.at(T![;])) {
+                p.bump_any();
+            }
         }
 
         m.complete(p, CSS_UNKNOWN_AT_RULE_COMPONENT_LIST);
     }
 
     let kind = if p.at(T!['{']) {
-        parse_declaration_or_rule_list_block(p);
+        if is_raw {
+            parse_unknown_at_rule_raw_block(p);
+        } else {
+            parse_declaration_or_rule_list_block(p);
+        }
         CSS_UNKNOWN_BLOCK_AT_RULE
     } else {
         p.expect(T![;]);
@@ -56,3 +70,54 @@ pub(crate) fn parse_unknown_at_rule(p: &mut CssParser) -> ParsedSyntax {
 
     Present(m.complete(p, kind))
 }
+
+/// Parses the block of an unknown at-rule without interpreting its content.
+///
+/// ```css
+/// @custom-rule {
+///     any tokens with (balanced) [brackets] { and braces }
+/// }
+/// ```
+#[inline]
+fn parse_unknown_at_rule_raw_block(p: &mut CssParser) -> CompletedMarker {
+    let m = p.start();
+
+    p.bump(T!['{']);
+
+    {
+        let m = p.start();
+        skip_balanced_tokens(p, token_set![T!['}']]);
+        m.complete(p, CSS_UNKNOWN_AT_RULE_COMPONENT_LIST);
+    }
+
+    p.expect(T!['}']);
+
+    m.complete(p, CSS_UNKNOWN_AT_RULE_RAW_BLOCK)
+}
+
+/// Skips all tokens until one of the `end` tokens is found outside of any
+/// parentheses, brackets or braces, or until the end of the file.
+fn skip_balanced_tokens(p: &mut CssParser, end: TokenSet<CssSyntaxKind>) {
+    let mut closing = Vec::new();
+
+    while !p.at(EOF) {
+        if closing.is_empty() && p.at_ts(end) {
+            break;
+        }
+
+        match p.cur() {
+            T!['('] => closing.push(T![')']),
+            T!['['] => closing.push(T![']']),
+            T!['{'] => closing.push(T!['}']),
+            kind if closing.last() == Some(&kind) => {
+                closing.pop();
+            }
+            _ => {}
+        }
+
+        p.bump_any();
+    }
+}
+
+const UNKNOWN_AT_RULE_PRELUDE_END_SET: TokenSet<CssSyntaxKind> =
+    token_set![T!['{'], T!['}'], T![;]];
@@ -0,0 +1,36 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_block;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::parsed_syntax::ParsedSyntax::Present;
+use biome_parser::prelude::ParsedSyntax::Absent;
+use biome_parser::prelude::*;
+
+#[inline]
+pub(crate) fn is_at_view_transition_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![view_transition])
+}
+
+/// Parses the `@view-transition` at-rule.
+///
+/// ```css
+/// @view-transition {
+///     navigation: auto;
+/// }
+/// ```
+///
+/// https://drafts.csswg.org/css-view-transitions-2/#view-transition-rule
+#[inline]
+pub(crate) fn parse_view_transition_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_view_transition_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![view_transition]);
+
+    parse_declaration_block(p);
+
+    Present(m.complete(p, CSS_VIEW_TRANSITION_AT_RULE))
+}
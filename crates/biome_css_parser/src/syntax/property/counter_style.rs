@@ -0,0 +1,252 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::value::function::{is_at_any_function, parse_any_function};
+use crate::syntax::{
+    is_at_identifier, is_at_string, parse_custom_identifier, parse_regular_identifier,
+    parse_regular_number, parse_string, try_parse,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parsed_syntax::ParsedSyntax;
+use biome_parser::parsed_syntax::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, TokenSet};
+
+const COUNTER_STYLE_DESCRIPTOR_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![system], T![symbols], T![additive_symbols], T![range]];
+
+const END_OF_COUNTER_STYLE_DESCRIPTOR_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![;], T!['}'], T![!], EOF];
+
+/// The keywords of the `system` descriptor that don't take any argument.
+const COUNTER_STYLE_SYSTEM_KEYWORDS: [&str; 5] =
+    ["cyclic", "numeric", "alphabetic", "symbolic", "additive"];
+
+/// Checks if the current position is at one of the `@counter-style`
+/// descriptors that have a dedicated node.
+#[inline]
+pub(crate) fn is_at_counter_style_descriptor(p: &mut CssParser) -> bool {
+    p.at_ts(COUNTER_STYLE_DESCRIPTOR_SET) && p.nth_at(1, T![:])
+}
+
+/// Parses the `system`, `symbols`, `additive-symbols` and `range` descriptors
+/// of a `@counter-style` block.
+///
+/// The value of the descriptor must match its grammar entirely, otherwise the
+/// parser rewinds and the caller parses the descriptor as a generic property.
+/// This keeps unknown or invalid values free of parse errors, and leaves their
+/// validation to the linter.
+///
+/// # Examples
+///
+/// ```css
+/// @counter-style roman {
+///     system: additive;
+///     additive-symbols: 10 X, 5 V, 4 IV, 1 I;
+///     range: 1 3999;
+/// }
+/// ```
+///
+/// https://drafts.csswg.org/css-counter-styles-3/#the-counter-style-rule
+#[inline]
+pub(crate) fn parse_counter_style_descriptor(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_counter_style_descriptor(p) {
+        return Absent;
+    }
+
+    try_parse(p, |p| {
+        let m = p.start();
+        let descriptor = p.cur();
+
+        // remap the descriptor keyword to a regular identifier
+        parse_regular_identifier(p).ok();
+        p.bump(T![:]);
+
+        let kind = match descriptor {
+            T![system] => parse_counter_style_system(p).map(|_| CSS_COUNTER_STYLE_SYSTEM_PROPERTY),
+            T![symbols] => {
+                parse_counter_style_symbol_list(p).map(|_| CSS_COUNTER_STYLE_SYMBOLS_PROPERTY)
+            }
+            T![additive_symbols] => parse_counter_style_additive_symbol_list(p)
+                .map(|_| CSS_COUNTER_STYLE_ADDITIVE_SYMBOLS_PROPERTY),
+            _ => parse_counter_style_range(p).map(|_| CSS_COUNTER_STYLE_RANGE_PROPERTY),
+        };
+
+        match kind {
+            Ok(kind) if p.at_ts(END_OF_COUNTER_STYLE_DESCRIPTOR_SET) => {
+                Ok(Present(m.complete(p, kind)))
+            }
+            _ => {
+                m.abandon(p);
+                Err(())
+            }
+        }
+    })
+    .unwrap_or(Absent)
+}
+
+/// Parses the value of the `system` descriptor.
+///
+/// ```css
+/// system: cyclic;
+/// system: fixed 1;
+/// system: extends decimal;
+/// ```
+#[inline]
+fn parse_counter_style_system(p: &mut CssParser) -> Result<(), ()> {
+    if p.at(T![fixed]) {
+        let m = p.start();
+        p.bump(T![fixed]);
+        parse_regular_number(p).ok();
+        m.complete(p, CSS_COUNTER_STYLE_FIXED_SYSTEM);
+
+        Ok(())
+    } else if p.at(T![extends]) {
+        let m = p.start();
+        p.bump(T![extends]);
+
+        if parse_custom_identifier(p, CssLexContext::Regular).is_absent() {
+            m.abandon(p);
+            return Err(());
+        }
+
+        m.complete(p, CSS_COUNTER_STYLE_EXTENDS_SYSTEM);
+
+        Ok(())
+    } else if is_at_identifier(p)
+        && COUNTER_STYLE_SYSTEM_KEYWORDS
+            .iter()
+            .any(|keyword| p.cur_text().eq_ignore_ascii_case(keyword))
+    {
+        parse_regular_identifier(p).ok();
+
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+/// Parses the non-empty list of symbols of the `symbols` descriptor.
+#[inline]
+fn parse_counter_style_symbol_list(p: &mut CssParser) -> Result<(), ()> {
+    let m = p.start();
+
+    while parse_counter_style_symbol(p).is_present() {}
+
+    let list = m.complete(p, CSS_COUNTER_STYLE_SYMBOL_LIST);
+
+    if list.range(p).is_empty() {
+        Err(())
+    } else {
+        Ok(())
+    }
+}
+
+/// Parses a `<symbol>`, which is either a string, an image or a custom identifier.
+#[inline]
+fn parse_counter_style_symbol(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_any_function(p) {
+        parse_any_function(p)
+    } else if is_at_string(p) {
+        parse_string(p)
+    } else {
+        parse_custom_identifier(p, CssLexContext::Regular)
+    }
+}
+
+/// Parses the comma separated list of weighted symbols of the
+/// `additive-symbols` descriptor.
+///
+/// ```css
+/// additive-symbols: 10 X, 5 V, 1 I;
+/// ```
+#[inline]
+fn parse_counter_style_additive_symbol_list(p: &mut CssParser) -> Result<(), ()> {
+    let m = p.start();
+
+    loop {
+        if parse_counter_style_additive_symbol(p).is_err() {
+            m.abandon(p);
+            return Err(());
+        }
+
+        if !p.eat(T![,]) {
+            break;
+        }
+    }
+
+    m.complete(p, CSS_COUNTER_STYLE_ADDITIVE_SYMBOL_LIST);
+
+    Ok(())
+}
+
+#[inline]
+fn parse_counter_style_additive_symbol(p: &mut CssParser) -> Result<(), ()> {
+    if !p.at(CSS_NUMBER_LITERAL) {
+        return Err(());
+    }
+
+    let m = p.start();
+
+    parse_regular_number(p).ok();
+
+    if parse_counter_style_symbol(p).is_absent() {
+        m.abandon(p);
+        return Err(());
+    }
+
+    m.complete(p, CSS_COUNTER_STYLE_ADDITIVE_SYMBOL);
+
+    Ok(())
+}
+
+/// Parses the value of the `range` descriptor, which is either `auto` or a
+/// comma separated list of ranges.
+///
+/// ```css
+/// range: auto;
+/// range: infinite -1, 1 infinite;
+/// ```
+#[inline]
+fn parse_counter_style_range(p: &mut CssParser) -> Result<(), ()> {
+    if p.at(T![auto]) {
+        parse_regular_identifier(p).ok();
+        return Ok(());
+    }
+
+    let m = p.start();
+    let list = p.start();
+
+    loop {
+        let range = p.start();
+
+        if parse_counter_style_range_bound(p).is_absent()
+            || parse_counter_style_range_bound(p).is_absent()
+        {
+            range.abandon(p);
+            list.abandon(p);
+            m.abandon(p);
+            return Err(());
+        }
+
+        range.complete(p, CSS_COUNTER_STYLE_RANGE);
+
+        if !p.eat(T![,]) {
+            break;
+        }
+    }
+
+    list.complete(p, CSS_COUNTER_STYLE_RANGE_LIST);
+    m.complete(p, CSS_COUNTER_STYLE_RANGE_VALUE);
+
+    Ok(())
+}
+
+/// Parses a bound of a range, which is either an integer or `infinite`.
+#[inline]
+fn parse_counter_style_range_bound(p: &mut CssParser) -> ParsedSyntax {
+    if p.at(T![infinite]) {
+        parse_regular_identifier(p)
+    } else {
+        parse_regular_number(p)
+    }
+}
@@ -1,4 +1,5 @@
 pub(crate) mod color;
+pub(crate) mod counter_style;
 pub(crate) mod unicode_range;
 
 use crate::lexer::CssLexContext;
@@ -7,6 +8,9 @@ use crate::syntax::css_modules::{
     composes_not_allowed, expected_classes_list, expected_composes_import_source,
 };
 use crate::syntax::parse_error::{expected_component_value, expected_identifier};
+use crate::syntax::property::counter_style::{
+    is_at_counter_style_descriptor, parse_counter_style_descriptor,
+};
 use crate::syntax::{
     is_at_any_value, is_at_dashed_identifier, is_at_identifier, is_at_string, parse_any_value,
     parse_custom_identifier_with_keywords, parse_dashed_identifier, parse_regular_identifier,
@@ -31,8 +35,16 @@ pub(crate) fn parse_any_property(p: &mut CssParser) -> ParsedSyntax {
         return Absent;
     }
 
+    if let Some(mut descriptors) = p.state().property_descriptors {
+        descriptors.record(p.cur_text());
+        p.state_mut().property_descriptors = Some(descriptors);
+    }
+
     match p.cur() {
         T![composes] => parse_composes_property(p),
+        _ if p.state().is_counter_style_block && is_at_counter_style_descriptor(p) => {
+            parse_counter_style_descriptor(p).or_else(|| parse_generic_property(p))
+        }
         _ => parse_generic_property(p),
     }
 }
@@ -49,7 +49,7 @@ pub(crate) fn parse_pseudo_class_function_selector(p: &mut CssParser) -> ParsedS
 
         // Skip the entire pseudo-class function selector
         // Skip until the next closing parenthesis
-        while !p.eat(T![')']) {
+        while !(p.at(EOF) || p.eat(T![')'])) {
             p.bump_any();
         }
 
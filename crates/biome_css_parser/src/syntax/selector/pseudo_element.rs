@@ -1,12 +1,15 @@
+use crate::lexer::CssLexContext;
 use crate::parser::CssParser;
 use crate::syntax::parse_error::{
-    expected_any_pseudo_element, expected_identifier, expected_selector,
+    expected_any_pseudo_element, expected_compound_selector, expected_identifier, expected_selector,
 };
 use crate::syntax::selector::{
-    eat_or_recover_selector_function_close_token, parse_selector, parse_selector_identifier,
-    recover_selector_function_parameter,
+    eat_or_recover_selector_function_close_token, parse_compound_selector, parse_selector,
+    parse_selector_identifier, recover_selector_function_parameter,
+};
+use crate::syntax::{
+    is_at_identifier, parse_custom_identifier_with_keywords, parse_regular_identifier,
 };
-use crate::syntax::{is_at_identifier, parse_regular_identifier};
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::{CssSyntaxKind, T};
 use biome_parser::prelude::ParsedSyntax;
@@ -34,6 +37,10 @@ pub(crate) fn parse_pseudo_element(p: &mut CssParser) -> ParsedSyntax {
 
     if is_at_pseudo_element_function_identifier(p) {
         parse_pseudo_element_function_identifier(p)
+    } else if is_at_pseudo_element_function_custom_identifier_list(p) {
+        parse_pseudo_element_function_custom_identifier_list(p)
+    } else if is_at_pseudo_element_function_compound_selector(p) {
+        parse_pseudo_element_function_compound_selector(p)
     } else if is_at_pseudo_element_function_selector(p) {
         parse_pseudo_element_function_selector(p)
     } else {
@@ -41,11 +48,13 @@ pub(crate) fn parse_pseudo_element(p: &mut CssParser) -> ParsedSyntax {
     }
 }
 
-const PSEUDO_ELEMENT_FUNCTION_IDENTIFIER_SET: TokenSet<CssSyntaxKind> =
-    token_set![HIGHLIGHT_KW, PART_KW];
+/// The functional pseudo-elements that have a dedicated node.
+const PSEUDO_ELEMENT_FUNCTION_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![highlight], T![part], T![slotted]];
+
 #[inline]
 pub(crate) fn is_at_pseudo_element_function_identifier(p: &mut CssParser) -> bool {
-    p.at_ts(PSEUDO_ELEMENT_FUNCTION_IDENTIFIER_SET) && p.nth_at(1, T!['('])
+    p.at(T![highlight]) && p.nth_at(1, T!['('])
 }
 #[inline]
 pub(crate) fn parse_pseudo_element_function_identifier(p: &mut CssParser) -> ParsedSyntax {
@@ -55,7 +64,7 @@ pub(crate) fn parse_pseudo_element_function_identifier(p: &mut CssParser) -> Par
 
     let m = p.start();
 
-    p.bump_ts(PSEUDO_ELEMENT_FUNCTION_IDENTIFIER_SET);
+    p.bump(T![highlight]);
     p.bump(T!['(']);
 
     let kind = match parse_regular_identifier(p) {
@@ -76,9 +85,98 @@ pub(crate) fn parse_pseudo_element_function_identifier(p: &mut CssParser) -> Par
     Present(m.complete(p, kind))
 }
 
+#[inline]
+pub(crate) fn is_at_pseudo_element_function_custom_identifier_list(p: &mut CssParser) -> bool {
+    p.at(T![part]) && p.nth_at(1, T!['('])
+}
+
+/// Parses the `::part()` pseudo-element, which takes one or more
+/// case-sensitive part names.
+///
+/// ```css
+/// ::part(label active) {}
+/// ```
+///
+/// https://drafts.csswg.org/css-shadow-parts/#part
+#[inline]
+pub(crate) fn parse_pseudo_element_function_custom_identifier_list(
+    p: &mut CssParser,
+) -> ParsedSyntax {
+    if !is_at_pseudo_element_function_custom_identifier_list(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![part]);
+    p.bump(T!['(']);
+
+    let kind = if is_at_identifier(p) {
+        let names = p.start();
+        while is_at_identifier(p) {
+            parse_custom_identifier_with_keywords(p, CssLexContext::Regular, true).ok();
+        }
+        let names = names.complete(p, CSS_CUSTOM_IDENTIFIER_LIST);
+
+        if eat_or_recover_selector_function_close_token(p, names, expected_identifier) {
+            CSS_PSEUDO_ELEMENT_FUNCTION_CUSTOM_IDENTIFIER_LIST
+        } else {
+            CSS_BOGUS_PSEUDO_ELEMENT
+        }
+    } else {
+        recover_selector_function_parameter(p, expected_identifier);
+        p.expect(T![')']);
+        CSS_BOGUS_PSEUDO_ELEMENT
+    };
+
+    Present(m.complete(p, kind))
+}
+
+#[inline]
+pub(crate) fn is_at_pseudo_element_function_compound_selector(p: &mut CssParser) -> bool {
+    p.at(T![slotted]) && p.nth_at(1, T!['('])
+}
+
+/// Parses the `::slotted()` pseudo-element, which only accepts a compound selector.
+///
+/// ```css
+/// ::slotted(span.active) {}
+/// ```
+///
+/// https://drafts.csswg.org/css-scoping/#slotted-pseudo
+#[inline]
+pub(crate) fn parse_pseudo_element_function_compound_selector(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_pseudo_element_function_compound_selector(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![slotted]);
+    p.bump(T!['(']);
+
+    let kind = match parse_compound_selector(p) {
+        Present(selector) => {
+            if eat_or_recover_selector_function_close_token(p, selector, expected_compound_selector)
+            {
+                CSS_PSEUDO_ELEMENT_FUNCTION_COMPOUND_SELECTOR
+            } else {
+                CSS_BOGUS_PSEUDO_ELEMENT
+            }
+        }
+        Absent => {
+            recover_selector_function_parameter(p, expected_compound_selector);
+            p.expect(T![')']);
+            CSS_BOGUS_PSEUDO_ELEMENT
+        }
+    };
+
+    Present(m.complete(p, kind))
+}
+
 #[inline]
 pub(crate) fn is_at_pseudo_element_function_selector(p: &mut CssParser) -> bool {
-    is_at_identifier(p) && !p.at_ts(PSEUDO_ELEMENT_FUNCTION_IDENTIFIER_SET) && p.nth_at(1, T!['('])
+    is_at_identifier(p) && !p.at_ts(PSEUDO_ELEMENT_FUNCTION_SET) && p.nth_at(1, T!['('])
 }
 
 #[inline]
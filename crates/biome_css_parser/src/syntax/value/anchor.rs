@@ -0,0 +1,155 @@
+use crate::parser::CssParser;
+use crate::syntax::value::dimension::{is_at_percentage_dimension, parse_percentage_dimension};
+use crate::syntax::{
+    is_at_dashed_identifier, is_at_identifier, parse_any_value, parse_dashed_identifier,
+    parse_regular_identifier, try_parse,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::parsed_syntax::ParsedSyntax;
+use biome_parser::parsed_syntax::ParsedSyntax::{Absent, Present};
+use biome_parser::Parser;
+
+/// Checks if the current position is at the start of an `anchor()` function.
+#[inline]
+pub(crate) fn is_at_anchor_function(p: &mut CssParser) -> bool {
+    p.at(T![anchor]) && p.nth_at(1, T!['('])
+}
+
+/// Parses the `anchor()` function of the CSS Anchor Positioning module.
+///
+/// The spec allows the anchor name and the anchor side in any order, but only
+/// `anchor(<anchor-name>? <anchor-side>, <length-percentage>?)` is parsed as a
+/// [CSS_ANCHOR_FUNCTION]. Any other argument list is rewound and parsed as a
+/// generic function instead, so valid stylesheets never produce errors here.
+///
+/// # Examples
+///
+/// ```css
+/// .tooltip {
+///     top: anchor(--my-anchor bottom);
+///     left: anchor(50%, 10px);
+/// }
+/// ```
+///
+/// https://drafts.csswg.org/css-anchor-position-1/#anchor-pos
+#[inline]
+pub(crate) fn parse_anchor_function(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_anchor_function(p) {
+        return Absent;
+    }
+
+    try_parse(p, |p| {
+        let m = p.start();
+
+        p.bump(T![anchor]);
+        p.bump(T!['(']);
+
+        parse_dashed_identifier(p).ok();
+
+        if parse_anchor_side(p).is_absent() {
+            m.abandon(p);
+            return Err(());
+        }
+
+        if parse_anchor_fallback(p).is_err() || !p.eat(T![')']) {
+            m.abandon(p);
+            return Err(());
+        }
+
+        Ok(Present(m.complete(p, CSS_ANCHOR_FUNCTION)))
+    })
+    .unwrap_or(Absent)
+}
+
+/// Checks if the current position is at the start of an `anchor-size()` function.
+#[inline]
+pub(crate) fn is_at_anchor_size_function(p: &mut CssParser) -> bool {
+    p.at(T![anchor_size]) && p.nth_at(1, T!['('])
+}
+
+/// Parses the `anchor-size()` function of the CSS Anchor Positioning module.
+///
+/// Like [parse_anchor_function], only the canonical argument order
+/// `anchor-size(<anchor-name>? <anchor-size>?, <length-percentage>?)` is
+/// parsed as a [CSS_ANCHOR_SIZE_FUNCTION].
+///
+/// # Examples
+///
+/// ```css
+/// .tooltip {
+///     width: anchor-size(--my-anchor width);
+///     max-height: anchor-size(block, 200px);
+/// }
+/// ```
+///
+/// https://drafts.csswg.org/css-anchor-position-1/#anchor-size-fn
+#[inline]
+pub(crate) fn parse_anchor_size_function(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_anchor_size_function(p) {
+        return Absent;
+    }
+
+    try_parse(p, |p| {
+        let m = p.start();
+
+        p.bump(T![anchor_size]);
+        p.bump(T!['(']);
+
+        let anchor_name = parse_dashed_identifier(p);
+
+        let size = if is_at_identifier(p) && !is_at_dashed_identifier(p) {
+            parse_regular_identifier(p)
+        } else {
+            Absent
+        };
+
+        // The comma before the fallback can only be omitted when there is
+        // nothing in front of it: `anchor-size(, 10px)` isn't valid.
+        if p.at(T![,]) && anchor_name.is_absent() && size.is_absent() {
+            m.abandon(p);
+            return Err(());
+        }
+
+        if parse_anchor_fallback(p).is_err() || !p.eat(T![')']) {
+            m.abandon(p);
+            return Err(());
+        }
+
+        Ok(Present(m.complete(p, CSS_ANCHOR_SIZE_FUNCTION)))
+    })
+    .unwrap_or(Absent)
+}
+
+/// Parses the `<anchor-side>` argument of the `anchor()` function, which is
+/// either a keyword or a percentage.
+#[inline]
+fn parse_anchor_side(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_percentage_dimension(p) {
+        parse_percentage_dimension(p)
+    } else if is_at_identifier(p) && !is_at_dashed_identifier(p) {
+        parse_regular_identifier(p)
+    } else {
+        Absent
+    }
+}
+
+/// Parses the optional `, <length-percentage>` fallback of the anchor
+/// functions. Returns an error if the comma isn't followed by a value.
+#[inline]
+fn parse_anchor_fallback(p: &mut CssParser) -> Result<ParsedSyntax, ()> {
+    if !p.at(T![,]) {
+        return Ok(Absent);
+    }
+
+    let m = p.start();
+
+    p.bump(T![,]);
+
+    if parse_any_value(p).is_absent() {
+        m.abandon(p);
+        return Err(());
+    }
+
+    Ok(Present(m.complete(p, CSS_ANCHOR_FALLBACK)))
+}
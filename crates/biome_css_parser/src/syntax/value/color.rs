@@ -0,0 +1,203 @@
+use crate::parser::CssParser;
+use crate::syntax::value::dimension::{is_at_percentage_dimension, parse_percentage_dimension};
+use crate::syntax::value::function::is_nth_at_function;
+use crate::syntax::{is_at_identifier, parse_any_value, parse_regular_identifier, try_parse};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::parsed_syntax::ParsedSyntax;
+use biome_parser::parsed_syntax::ParsedSyntax::{Absent, Present};
+use biome_parser::Parser;
+
+/// The color functions that accept the relative color syntax.
+const RELATIVE_COLOR_FUNCTIONS: [&str; 10] = [
+    "rgb", "rgba", "hsl", "hsla", "hwb", "lab", "lch", "oklab", "oklch", "color",
+];
+
+/// Checks if the current position is at the start of a `color-mix()` function.
+#[inline]
+pub(crate) fn is_at_color_mix_function(p: &mut CssParser) -> bool {
+    p.at(T![color_mix]) && p.nth_at(1, T!['('])
+}
+
+/// Parses the `color-mix()` function of the CSS Color module.
+///
+/// Only `color-mix(<color-interpolation-method>, [<color> <percentage>?]#)` is
+/// parsed as a [CSS_COLOR_MIX_FUNCTION]. A percentage in front of its color or
+/// any other argument list is rewound and parsed as a generic function instead.
+///
+/// # Examples
+///
+/// ```css
+/// .button {
+///     color: color-mix(in oklch, red 30%, blue);
+///     background: color-mix(in hsl longer hue, var(--primary), white 10%);
+/// }
+/// ```
+///
+/// https://drafts.csswg.org/css-color-5/#color-mix
+#[inline]
+pub(crate) fn parse_color_mix_function(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_color_mix_function(p) {
+        return Absent;
+    }
+
+    try_parse(p, |p| {
+        let m = p.start();
+
+        p.bump(T![color_mix]);
+        p.bump(T!['(']);
+
+        if parse_color_interpolation_method(p).is_absent() || !p.eat(T![,]) {
+            m.abandon(p);
+            return Err(());
+        }
+
+        let colors = p.start();
+
+        loop {
+            if parse_color_mix_color(p).is_absent() {
+                colors.abandon(p);
+                m.abandon(p);
+                return Err(());
+            }
+
+            if !p.eat(T![,]) {
+                break;
+            }
+        }
+
+        colors.complete(p, CSS_COLOR_MIX_COLOR_LIST);
+
+        if !p.eat(T![')']) {
+            m.abandon(p);
+            return Err(());
+        }
+
+        Ok(Present(m.complete(p, CSS_COLOR_MIX_FUNCTION)))
+    })
+    .unwrap_or(Absent)
+}
+
+/// Parses a `<color-interpolation-method>`, e.g. `in srgb` or `in hsl longer hue`.
+#[inline]
+fn parse_color_interpolation_method(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![in]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![in]);
+
+    if parse_regular_identifier(p).is_absent() {
+        m.abandon(p);
+        return Absent;
+    }
+
+    if is_at_identifier(p) && p.nth_at(1, T![hue]) {
+        let hue = p.start();
+        parse_regular_identifier(p).ok();
+        p.bump(T![hue]);
+        hue.complete(p, CSS_COLOR_HUE_INTERPOLATION_METHOD);
+    }
+
+    Present(m.complete(p, CSS_COLOR_INTERPOLATION_METHOD))
+}
+
+/// Parses a color of the `color-mix()` function with its optional percentage.
+#[inline]
+fn parse_color_mix_color(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_percentage_dimension(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    if parse_any_value(p).is_absent() {
+        m.abandon(p);
+        return Absent;
+    }
+
+    if is_at_percentage_dimension(p) {
+        parse_percentage_dimension(p).ok();
+    }
+
+    Present(m.complete(p, CSS_COLOR_MIX_COLOR))
+}
+
+/// Checks if the current position is at the start of a color function that
+/// uses the relative color syntax, e.g. `rgb(from ...)`.
+#[inline]
+pub(crate) fn is_at_relative_color_function(p: &mut CssParser) -> bool {
+    is_nth_at_function(p, 0)
+        && p.nth_at(2, T![from])
+        && RELATIVE_COLOR_FUNCTIONS
+            .iter()
+            .any(|name| p.cur_text().eq_ignore_ascii_case(name))
+}
+
+/// Parses a color function that uses the relative color syntax.
+///
+/// The origin color and the channels are parsed as plain values, the optional
+/// alpha channel follows a `/`. Any other argument list is rewound and parsed
+/// as a generic function instead.
+///
+/// # Examples
+///
+/// ```css
+/// .button {
+///     color: oklch(from var(--primary) l c h / 0.5);
+///     background: rgb(from red r g calc(b + 20));
+/// }
+/// ```
+///
+/// https://drafts.csswg.org/css-color-5/#relative-colors
+#[inline]
+pub(crate) fn parse_relative_color_function(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_relative_color_function(p) {
+        return Absent;
+    }
+
+    try_parse(p, |p| {
+        let m = p.start();
+
+        parse_regular_identifier(p).ok();
+        p.bump(T!['(']);
+        p.bump(T![from]);
+
+        if parse_any_value(p).is_absent() {
+            m.abandon(p);
+            return Err(());
+        }
+
+        let channels = p.start();
+        while parse_any_value(p).is_present() {}
+        let channels = channels.complete(p, CSS_COMPONENT_VALUE_LIST);
+
+        if channels.range(p).is_empty() {
+            m.abandon(p);
+            return Err(());
+        }
+
+        if p.at(T![/]) {
+            let alpha = p.start();
+            p.bump(T![/]);
+
+            if parse_any_value(p).is_absent() {
+                alpha.abandon(p);
+                m.abandon(p);
+                return Err(());
+            }
+
+            alpha.complete(p, CSS_RELATIVE_COLOR_ALPHA);
+        }
+
+        if !p.eat(T![')']) {
+            m.abandon(p);
+            return Err(());
+        }
+
+        Ok(Present(m.complete(p, CSS_RELATIVE_COLOR_FUNCTION)))
+    })
+    .unwrap_or(Absent)
+}
@@ -1,3 +1,11 @@
+use super::anchor::{
+    is_at_anchor_function, is_at_anchor_size_function, parse_anchor_function,
+    parse_anchor_size_function,
+};
+use super::color::{
+    is_at_color_mix_function, is_at_relative_color_function, parse_color_mix_function,
+    parse_relative_color_function,
+};
 use super::parse_error::expected_expression;
 use super::url::{is_at_url_function, parse_url_function};
 use crate::parser::CssParser;
@@ -15,7 +23,7 @@ use biome_parser::{token_set, Parser, TokenSet};
 
 /// Checks if the current position in the `CssParser` is at the start of any recognized CSS function.
 ///
-/// This function combines checks for specific CSS functions like `url()` and simple functions.
+/// This function combines checks for specific CSS functions like `url()` or `anchor()` and simple functions.
 /// It's used to quickly determine if the parser is positioned at a relevant function.
 #[inline]
 pub(crate) fn is_at_any_function(p: &mut CssParser) -> bool {
@@ -25,8 +33,10 @@ pub(crate) fn is_at_any_function(p: &mut CssParser) -> bool {
 /// Parses any recognized CSS function at the current position in the `CssParser`.
 ///
 /// This function first checks if the parser is positioned at a valid function.
-/// If it is, the function will parse either a URL function or a simple function,
-/// based on what is detected.
+/// If it is, the function will parse either a URL function, an anchor positioning
+/// function, a color function or a simple function, based on what is detected. Anchor
+/// positioning and color functions whose arguments don't match their typed grammar are
+/// parsed as simple functions.
 #[inline]
 pub(crate) fn parse_any_function(p: &mut CssParser) -> ParsedSyntax {
     if !is_at_any_function(p) {
@@ -35,6 +45,14 @@ pub(crate) fn parse_any_function(p: &mut CssParser) -> ParsedSyntax {
 
     if is_at_url_function(p) {
         parse_url_function(p)
+    } else if is_at_anchor_function(p) {
+        parse_anchor_function(p).or_else(|| parse_function(p))
+    } else if is_at_anchor_size_function(p) {
+        parse_anchor_size_function(p).or_else(|| parse_function(p))
+    } else if is_at_color_mix_function(p) {
+        parse_color_mix_function(p).or_else(|| parse_function(p))
+    } else if is_at_relative_color_function(p) {
+        parse_relative_color_function(p).or_else(|| parse_function(p))
     } else {
         parse_function(p)
     }
@@ -1,3 +1,5 @@
+pub(crate) mod anchor;
+pub(crate) mod color;
 pub(crate) mod dimension;
 pub(crate) mod function;
 mod parse_error;
@@ -67,6 +67,18 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
             options = options.allow_css_modules();
         }
 
+        if settings.custom_media.unwrap_or_default() {
+            options = options.allow_custom_media();
+        }
+
+        if settings.tailwind_directives.unwrap_or_default() {
+            options = options.allow_tailwind_directives();
+        }
+
+        if settings.raw_unknown_at_rules.unwrap_or_default() {
+            options = options.allow_raw_unknown_at_rules();
+        }
+
         if settings.allow_wrong_line_comments.unwrap_or_default() {
             options = options.allow_wrong_line_comments();
         }
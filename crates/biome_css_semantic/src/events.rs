@@ -1,7 +1,7 @@
 use std::{borrow::Cow, collections::VecDeque};
 
 use biome_css_syntax::{
-    AnyCssSelector, CssDeclarationBlock, CssRelativeSelector, CssSyntaxKind::*,
+    AnyCssSelector, CssPropertyAtRuleBlock, CssRelativeSelector, CssSyntaxKind::*,
 };
 use biome_rowan::{AstNode, SyntaxNodeCast, SyntaxNodeOptionExt, TextRange};
 
@@ -171,7 +171,7 @@ impl SemanticEventExtractor {
             None => return,
         };
 
-        let decls = match value.cast::<CssDeclarationBlock>() {
+        let decls = match value.cast::<CssPropertyAtRuleBlock>() {
             Some(d) => d,
             None => return,
         };
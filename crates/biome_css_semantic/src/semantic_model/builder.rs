@@ -6,7 +6,7 @@ use rustc_hash::FxHashMap;
 
 use super::model::{
     CssDeclaration, CssGlobalCustomVariable, Rule, RuleId, Selector, SemanticModel,
-    SemanticModelData, Specificity,
+    SemanticModelData,
 };
 use crate::events::SemanticEvent;
 
@@ -59,6 +59,12 @@ impl SemanticModelBuilder {
 
                 let parent_id = self.current_rule_stack.last().copied();
 
+                // Rules without selectors, such as `@media`, keep the specificity context of their parent
+                let specificity = parent_id
+                    .and_then(|parent_id| self.rules_by_id.get(&parent_id))
+                    .map(|parent| parent.specificity.clone())
+                    .unwrap_or_default();
+
                 let new_rule = Rule {
                     id: new_rule_id,
                     selectors: Vec::new(),
@@ -66,7 +72,7 @@ impl SemanticModelBuilder {
                     range,
                     parent_id,
                     child_ids: Vec::new(),
-                    specificity: Specificity::default(),
+                    specificity,
                 };
 
                 if let Some(&parent_id) = self.current_rule_stack.last() {
@@ -110,14 +116,18 @@ impl SemanticModelBuilder {
 
                 if let Some(current_rule) = self.current_rule_stack.last() {
                     let current_rule = self.rules_by_id.get_mut(current_rule).unwrap();
+                    let specificity = parent_specificity + specificity;
+                    // The nested rules use the highest specificity of the selector list, like `:is()`
+                    // See https://drafts.csswg.org/css-nesting/#nest-selector
+                    if current_rule.selectors.is_empty() || current_rule.specificity < specificity {
+                        current_rule.specificity = specificity.clone();
+                    }
                     current_rule.selectors.push(Selector {
                         name,
                         range,
                         original,
-                        specificity: parent_specificity + specificity.clone(),
+                        specificity,
                     });
-
-                    current_rule.specificity += specificity;
                 }
             }
             SemanticEvent::PropertyDeclaration {
@@ -36,6 +36,8 @@ mod tests {
     use biome_css_parser::CssParserOptions;
     use biome_rowan::TextRange;
 
+    use super::model::Specificity;
+
     #[test]
     fn test_simple_ruleset() {
         let parse = parse_css(
@@ -257,6 +259,34 @@ mod tests {
         assert_eq!(parent.declarations[1].value.text, "12px");
     }
 
+    #[test]
+    fn test_nested_specificity() {
+        let parse = parse_css(
+            r#"#id, .a {
+  @media screen {
+    .b {
+      & .c {}
+    }
+  }
+}"#,
+            CssParserOptions::default(),
+        );
+
+        let root = parse.tree();
+        let model = super::semantic_model(&root);
+        let rule = model.rules().first().unwrap();
+        assert_eq!(rule.specificity, Specificity(1, 0, 0));
+
+        let media = model.get_rule_by_id(rule.child_ids[0]).unwrap();
+        assert_eq!(media.specificity, Specificity(1, 0, 0));
+
+        let nested = model.get_rule_by_id(media.child_ids[0]).unwrap();
+        assert_eq!(nested.selectors[0].specificity, Specificity(1, 1, 0));
+
+        let deeply_nested = model.get_rule_by_id(nested.child_ids[0]).unwrap();
+        assert_eq!(deeply_nested.selectors[0].specificity, Specificity(1, 2, 0));
+    }
+
     #[ignore]
     #[test]
     fn quick_test() {
@@ -164,9 +164,9 @@ impl std::ops::Add for Specificity {
 
 impl std::ops::AddAssign for Specificity {
     fn add_assign(&mut self, rhs: Self) {
-        self.0 = rhs.0;
-        self.1 = rhs.1;
-        self.2 = rhs.2;
+        self.0 += rhs.0;
+        self.1 += rhs.1;
+        self.2 += rhs.2;
     }
 }
 
@@ -16,6 +16,18 @@ macro_rules! map_syntax_node {
     ($ node : expr , $ pattern : pat => $ body : expr) => {
         match $node {
             node => match $crate::CssSyntaxNode::kind(&node) {
+                $crate::CssSyntaxKind::CSS_ANCHOR_FALLBACK => {
+                    let $pattern = unsafe { $crate::CssAnchorFallback::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_ANCHOR_FUNCTION => {
+                    let $pattern = unsafe { $crate::CssAnchorFunction::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_ANCHOR_SIZE_FUNCTION => {
+                    let $pattern = unsafe { $crate::CssAnchorSizeFunction::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_AT_RULE => {
                     let $pattern = unsafe { $crate::CssAtRule::new_unchecked(node) };
                     $body
@@ -56,6 +68,24 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssColor::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_COLOR_HUE_INTERPOLATION_METHOD => {
+                    let $pattern =
+                        unsafe { $crate::CssColorHueInterpolationMethod::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COLOR_INTERPOLATION_METHOD => {
+                    let $pattern =
+                        unsafe { $crate::CssColorInterpolationMethod::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COLOR_MIX_COLOR => {
+                    let $pattern = unsafe { $crate::CssColorMixColor::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COLOR_MIX_FUNCTION => {
+                    let $pattern = unsafe { $crate::CssColorMixFunction::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_COLOR_PROFILE_AT_RULE => {
                     let $pattern = unsafe { $crate::CssColorProfileAtRule::new_unchecked(node) };
                     $body
@@ -102,6 +132,32 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssContainerQueryInParens::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_CONTAINER_SCROLL_STATE_AND_QUERY => {
+                    let $pattern =
+                        unsafe { $crate::CssContainerScrollStateAndQuery::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_CONTAINER_SCROLL_STATE_IN_PARENS => {
+                    let $pattern =
+                        unsafe { $crate::CssContainerScrollStateInParens::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_CONTAINER_SCROLL_STATE_NOT_QUERY => {
+                    let $pattern =
+                        unsafe { $crate::CssContainerScrollStateNotQuery::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_CONTAINER_SCROLL_STATE_OR_QUERY => {
+                    let $pattern =
+                        unsafe { $crate::CssContainerScrollStateOrQuery::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_CONTAINER_SCROLL_STATE_QUERY_IN_PARENS => {
+                    let $pattern = unsafe {
+                        $crate::CssContainerScrollStateQueryInParens::new_unchecked(node)
+                    };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_CONTAINER_SIZE_FEATURE_IN_PARENS => {
                     let $pattern =
                         unsafe { $crate::CssContainerSizeFeatureInParens::new_unchecked(node) };
@@ -131,14 +187,72 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssContainerStyleQueryInParens::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_CONTAINER_STYLE_RANGE => {
+                    let $pattern = unsafe { $crate::CssContainerStyleRange::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_CONTAINER_STYLE_RANGE_INTERVAL => {
+                    let $pattern =
+                        unsafe { $crate::CssContainerStyleRangeInterval::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COUNTER_STYLE_ADDITIVE_SYMBOL => {
+                    let $pattern =
+                        unsafe { $crate::CssCounterStyleAdditiveSymbol::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COUNTER_STYLE_ADDITIVE_SYMBOLS_PROPERTY => {
+                    let $pattern = unsafe {
+                        $crate::CssCounterStyleAdditiveSymbolsProperty::new_unchecked(node)
+                    };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_COUNTER_STYLE_AT_RULE => {
                     let $pattern = unsafe { $crate::CssCounterStyleAtRule::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_COUNTER_STYLE_EXTENDS_SYSTEM => {
+                    let $pattern =
+                        unsafe { $crate::CssCounterStyleExtendsSystem::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COUNTER_STYLE_FIXED_SYSTEM => {
+                    let $pattern =
+                        unsafe { $crate::CssCounterStyleFixedSystem::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COUNTER_STYLE_RANGE => {
+                    let $pattern = unsafe { $crate::CssCounterStyleRange::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COUNTER_STYLE_RANGE_PROPERTY => {
+                    let $pattern =
+                        unsafe { $crate::CssCounterStyleRangeProperty::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COUNTER_STYLE_RANGE_VALUE => {
+                    let $pattern =
+                        unsafe { $crate::CssCounterStyleRangeValue::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COUNTER_STYLE_SYMBOLS_PROPERTY => {
+                    let $pattern =
+                        unsafe { $crate::CssCounterStyleSymbolsProperty::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COUNTER_STYLE_SYSTEM_PROPERTY => {
+                    let $pattern =
+                        unsafe { $crate::CssCounterStyleSystemProperty::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_CUSTOM_IDENTIFIER => {
                     let $pattern = unsafe { $crate::CssCustomIdentifier::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_CUSTOM_MEDIA_AT_RULE => {
+                    let $pattern = unsafe { $crate::CssCustomMediaAtRule::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_DASHED_IDENTIFIER => {
                     let $pattern = unsafe { $crate::CssDashedIdentifier::new_unchecked(node) };
                     $body
@@ -403,10 +517,18 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssPercentage::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_POSITION_TRY_AT_RULE => {
+                    let $pattern = unsafe { $crate::CssPositionTryAtRule::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_PROPERTY_AT_RULE => {
                     let $pattern = unsafe { $crate::CssPropertyAtRule::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_PROPERTY_AT_RULE_BLOCK => {
+                    let $pattern = unsafe { $crate::CssPropertyAtRuleBlock::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR => {
                     let $pattern = unsafe {
                         $crate::CssPseudoClassFunctionCompoundSelector::new_unchecked(node)
@@ -481,6 +603,18 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssPseudoClassSelector::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_PSEUDO_ELEMENT_FUNCTION_COMPOUND_SELECTOR => {
+                    let $pattern = unsafe {
+                        $crate::CssPseudoElementFunctionCompoundSelector::new_unchecked(node)
+                    };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_PSEUDO_ELEMENT_FUNCTION_CUSTOM_IDENTIFIER_LIST => {
+                    let $pattern = unsafe {
+                        $crate::CssPseudoElementFunctionCustomIdentifierList::new_unchecked(node)
+                    };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER => {
                     let $pattern =
                         unsafe { $crate::CssPseudoElementFunctionIdentifier::new_unchecked(node) };
@@ -539,6 +673,14 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssRegularDimension::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_RELATIVE_COLOR_ALPHA => {
+                    let $pattern = unsafe { $crate::CssRelativeColorAlpha::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_RELATIVE_COLOR_FUNCTION => {
+                    let $pattern = unsafe { $crate::CssRelativeColorFunction::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_RELATIVE_SELECTOR => {
                     let $pattern = unsafe { $crate::CssRelativeSelector::new_unchecked(node) };
                     $body
@@ -597,6 +739,16 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssSupportsFeatureDeclaration::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_SUPPORTS_FEATURE_FONT_FORMAT => {
+                    let $pattern =
+                        unsafe { $crate::CssSupportsFeatureFontFormat::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_SUPPORTS_FEATURE_FONT_TECH => {
+                    let $pattern =
+                        unsafe { $crate::CssSupportsFeatureFontTech::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_SUPPORTS_FEATURE_SELECTOR => {
                     let $pattern =
                         unsafe { $crate::CssSupportsFeatureSelector::new_unchecked(node) };
@@ -610,6 +762,31 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssSupportsOrCondition::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_TAILWIND_APPLY_AT_RULE => {
+                    let $pattern = unsafe { $crate::CssTailwindApplyAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_TAILWIND_AT_RULE => {
+                    let $pattern = unsafe { $crate::CssTailwindAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_TAILWIND_CONFIG_AT_RULE => {
+                    let $pattern = unsafe { $crate::CssTailwindConfigAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_TAILWIND_THEME_AT_RULE => {
+                    let $pattern = unsafe { $crate::CssTailwindThemeAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_TAILWIND_UTILITY => {
+                    let $pattern = unsafe { $crate::CssTailwindUtility::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_TAILWIND_VARIANTS_AT_RULE => {
+                    let $pattern =
+                        unsafe { $crate::CssTailwindVariantsAtRule::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_TYPE_SELECTOR => {
                     let $pattern = unsafe { $crate::CssTypeSelector::new_unchecked(node) };
                     $body
@@ -639,6 +816,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssUniversalSelector::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_UNKNOWN_AT_RULE_RAW_BLOCK => {
+                    let $pattern = unsafe { $crate::CssUnknownAtRuleRawBlock::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_UNKNOWN_BLOCK_AT_RULE => {
                     let $pattern = unsafe { $crate::CssUnknownBlockAtRule::new_unchecked(node) };
                     $body
@@ -688,6 +869,10 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssValueAtRuleNamedImportSpecifier::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_VIEW_TRANSITION_AT_RULE => {
+                    let $pattern = unsafe { $crate::CssViewTransitionAtRule::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_BOGUS => {
                     let $pattern = unsafe { $crate::CssBogus::new_unchecked(node) };
                     $body
@@ -700,6 +885,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssBogusBlock::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_BOGUS_CONTAINER_QUERY => {
+                    let $pattern = unsafe { $crate::CssBogusContainerQuery::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_BOGUS_CUSTOM_IDENTIFIER => {
                     let $pattern = unsafe { $crate::CssBogusCustomIdentifier::new_unchecked(node) };
                     $body
@@ -801,6 +990,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssBracketedValueList::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_COLOR_MIX_COLOR_LIST => {
+                    let $pattern = unsafe { $crate::CssColorMixColorList::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_COMPONENT_VALUE_LIST => {
                     let $pattern = unsafe { $crate::CssComponentValueList::new_unchecked(node) };
                     $body
@@ -813,6 +1006,20 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssCompoundSelectorList::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_COUNTER_STYLE_ADDITIVE_SYMBOL_LIST => {
+                    let $pattern =
+                        unsafe { $crate::CssCounterStyleAdditiveSymbolList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COUNTER_STYLE_RANGE_LIST => {
+                    let $pattern = unsafe { $crate::CssCounterStyleRangeList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COUNTER_STYLE_SYMBOL_LIST => {
+                    let $pattern =
+                        unsafe { $crate::CssCounterStyleSymbolList::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_CUSTOM_IDENTIFIER_LIST => {
                     let $pattern = unsafe { $crate::CssCustomIdentifierList::new_unchecked(node) };
                     $body
@@ -909,6 +1116,19 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssSubSelectorList::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_TAILWIND_THEME_MODIFIER_LIST => {
+                    let $pattern =
+                        unsafe { $crate::CssTailwindThemeModifierList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_TAILWIND_UTILITY_LIST => {
+                    let $pattern = unsafe { $crate::CssTailwindUtilityList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_TAILWIND_VARIANT_LIST => {
+                    let $pattern = unsafe { $crate::CssTailwindVariantList::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_URL_MODIFIER_LIST => {
                     let $pattern = unsafe { $crate::CssUrlModifierList::new_unchecked(node) };
                     $body
@@ -86,6 +86,7 @@ impl biome_rowan::SyntaxKind for CssSyntaxKind {
                 | CSS_BOGUS_PSEUDO_ELEMENT
                 | CSS_BOGUS_AT_RULE
                 | CSS_BOGUS_MEDIA_QUERY
+                | CSS_BOGUS_CONTAINER_QUERY
                 | CSS_BOGUS_KEYFRAMES_ITEM
                 | CSS_BOGUS_PAGE_SELECTOR_PSEUDO
                 | CSS_BOGUS_LAYER
@@ -108,6 +109,7 @@ impl biome_rowan::SyntaxKind for CssSyntaxKind {
             kind if AnyCssPseudoElement::can_cast(*kind) => CSS_BOGUS_PSEUDO_ELEMENT,
             kind if AnyCssAtRule::can_cast(*kind) => CSS_BOGUS_AT_RULE,
             kind if AnyCssMediaQuery::can_cast(*kind) => CSS_BOGUS_MEDIA_QUERY,
+            kind if AnyCssContainerQueryInParens::can_cast(*kind) => CSS_BOGUS_CONTAINER_QUERY,
             kind if AnyCssDeclarationBlock::can_cast(*kind) => CSS_BOGUS_BLOCK,
             kind if AnyCssRuleBlock::can_cast(*kind) => CSS_BOGUS_BLOCK,
             kind if AnyCssKeyframesSelector::can_cast(*kind) => CSS_BOGUS_SELECTOR,
@@ -123,6 +125,8 @@ impl biome_rowan::SyntaxKind for CssSyntaxKind {
             kind if AnyCssDeclarationOrRuleBlock::can_cast(*kind) => CSS_BOGUS_BLOCK,
             kind if AnyCssConditionalBlock::can_cast(*kind) => CSS_BOGUS_BLOCK,
             kind if AnyCssFontFeatureValuesBlock::can_cast(*kind) => CSS_BOGUS_BLOCK,
+            kind if AnyCssPropertyAtRuleBlock::can_cast(*kind) => CSS_BOGUS_BLOCK,
+            kind if AnyCssUnknownAtRuleBlock::can_cast(*kind) => CSS_BOGUS_BLOCK,
             kind if AnyCssUnicodeValue::can_cast(*kind) => CSS_BOGUS_UNICODE_RANGE_VALUE,
 
             _ => CSS_BOGUS,
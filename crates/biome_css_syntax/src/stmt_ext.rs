@@ -1,12 +1,13 @@
 use crate::generated::{
     CssDeclarationBlock, CssDeclarationOrAtRuleBlock, CssDeclarationOrRuleBlock,
-    CssFontFeatureValuesBlock, CssKeyframesBlock, CssPageAtRuleBlock, CssRuleBlock,
+    CssFontFeatureValuesBlock, CssKeyframesBlock, CssLayerNameList, CssPageAtRuleBlock,
+    CssPropertyAtRuleBlock, CssRuleBlock,
 };
 use crate::CssSyntaxToken;
-use biome_rowan::{declare_node_union, AstNodeList, SyntaxResult};
+use biome_rowan::{declare_node_union, AstNodeList, AstSeparatedList, SyntaxResult};
 
 declare_node_union! {
-    pub CssBlockLike = CssKeyframesBlock | CssDeclarationOrAtRuleBlock | CssDeclarationBlock | CssRuleBlock | CssFontFeatureValuesBlock | CssPageAtRuleBlock | CssDeclarationOrRuleBlock
+    pub CssBlockLike = CssKeyframesBlock | CssDeclarationOrAtRuleBlock | CssDeclarationBlock | CssRuleBlock | CssFontFeatureValuesBlock | CssPageAtRuleBlock | CssDeclarationOrRuleBlock | CssPropertyAtRuleBlock
 }
 
 impl CssBlockLike {
@@ -20,6 +21,7 @@ impl CssBlockLike {
             CssBlockLike::CssFontFeatureValuesBlock(block) => block.l_curly_token(),
             CssBlockLike::CssPageAtRuleBlock(block) => block.l_curly_token(),
             CssBlockLike::CssDeclarationOrRuleBlock(block) => block.l_curly_token(),
+            CssBlockLike::CssPropertyAtRuleBlock(block) => block.l_curly_token(),
         }
     }
 
@@ -33,6 +35,7 @@ impl CssBlockLike {
             CssBlockLike::CssFontFeatureValuesBlock(block) => block.r_curly_token(),
             CssBlockLike::CssPageAtRuleBlock(block) => block.r_curly_token(),
             CssBlockLike::CssDeclarationOrRuleBlock(block) => block.r_curly_token(),
+            CssBlockLike::CssPropertyAtRuleBlock(block) => block.r_curly_token(),
         }
     }
 
@@ -46,6 +49,7 @@ impl CssBlockLike {
             CssBlockLike::CssFontFeatureValuesBlock(block) => block.items().is_empty(),
             CssBlockLike::CssPageAtRuleBlock(block) => block.items().is_empty(),
             CssBlockLike::CssDeclarationOrRuleBlock(block) => block.items().is_empty(),
+            CssBlockLike::CssPropertyAtRuleBlock(block) => block.declarations().is_empty(),
         }
     }
 
@@ -60,3 +64,21 @@ impl CssBlockLike {
                 .is_ok_and(|token| token.has_leading_comments())
     }
 }
+
+impl CssLayerNameList {
+    /// Returns the name of the layer with its nested names joined by a dot,
+    /// e.g. `framework.base` for `@layer framework.base {}`.
+    pub fn to_qualified_name(&self) -> SyntaxResult<String> {
+        let mut name = String::new();
+
+        for (index, identifier) in self.iter().enumerate() {
+            if index > 0 {
+                name.push('.');
+            }
+
+            name.push_str(identifier?.value_token()?.text_trimmed());
+        }
+
+        Ok(name)
+    }
+}
@@ -133,9 +133,19 @@ define_categories! {
     "lint/correctness/useValidForDirection": "https://biomejs.dev/linter/rules/use-valid-for-direction",
     "lint/correctness/useYield": "https://biomejs.dev/linter/rules/use-yield",
     "lint/nursery/colorNoInvalidHex": "https://biomejs.dev/linter/rules/color-no-invalid-hex",
+    "lint/nursery/maxNestingDepth": "https://biomejs.dev/linter/rules/max-nesting-depth",
+    "lint/nursery/noArbitraryZIndex": "https://biomejs.dev/linter/rules/no-arbitrary-z-index",
+    "lint/nursery/noAwaitInLoop": "https://biomejs.dev/linter/rules/no-await-in-loop",
+    "lint/nursery/noCircularImports": "https://biomejs.dev/linter/rules/no-circular-imports",
     "lint/nursery/noColorInvalidHex": "https://biomejs.dev/linter/rules/no-color-invalid-hex",
+    "lint/nursery/noCommentedOutCode": "https://biomejs.dev/linter/rules/no-commented-out-code",
     "lint/nursery/noCommonJs": "https://biomejs.dev/linter/rules/no-common-js",
+    "lint/nursery/noConditionalExpect": "https://biomejs.dev/linter/rules/no-conditional-expect",
     "lint/nursery/noConsole": "https://biomejs.dev/linter/rules/no-console",
+    "lint/nursery/noCustomPropertyTypos": "https://biomejs.dev/linter/rules/no-custom-property-typos",
+    "lint/nursery/noDeepRelativeImports": "https://biomejs.dev/linter/rules/no-deep-relative-imports",
+    "lint/nursery/noDeprecatedApis": "https://biomejs.dev/linter/rules/no-deprecated-apis",
+    "lint/nursery/noDeprecatedProperties": "https://biomejs.dev/linter/rules/no-deprecated-properties",
     "lint/nursery/noDescendingSpecificity": "https://biomejs.dev/linter/rules/no-descending-specificity",
     "lint/nursery/noDocumentCookie": "https://biomejs.dev/linter/rules/no-document-cookie",
     "lint/nursery/noDocumentImportInPage": "https://biomejs.dev/linter/rules/no-document-import-in-page",
@@ -144,35 +154,55 @@ define_categories! {
     "lint/nursery/noDuplicateCustomProperties": "https://biomejs.dev/linter/rules/no-duplicate-custom-properties",
     "lint/nursery/noDuplicateElseIf": "https://biomejs.dev/linter/rules/no-duplicate-else-if",
     "lint/nursery/noDuplicateProperties": "https://biomejs.dev/linter/rules/no-duplicate-properties",
+    "lint/nursery/noDuplicateSelectorsAcrossFiles": "https://biomejs.dev/linter/rules/no-duplicate-selectors-across-files",
+    "lint/nursery/noDuplicateTestTitles": "https://biomejs.dev/linter/rules/no-duplicate-test-titles",
     "lint/nursery/noDuplicatedFields": "https://biomejs.dev/linter/rules/no-duplicated-fields",
     "lint/nursery/noDynamicNamespaceImportAccess": "https://biomejs.dev/linter/rules/no-dynamic-namespace-import-access",
     "lint/nursery/noEnum": "https://biomejs.dev/linter/rules/no-enum",
     "lint/nursery/noExportedImports": "https://biomejs.dev/linter/rules/no-exported-imports",
+    "lint/nursery/noFloatingPromises": "https://biomejs.dev/linter/rules/no-floating-promises",
     "lint/nursery/noGlobalDirnameFilename": "https://biomejs.dev/linter/rules/no-global-dirname-filename",
+    "lint/nursery/noHardcodedColors": "https://biomejs.dev/linter/rules/no-hardcoded-colors",
+    "lint/nursery/noHardcodedUserFacingStrings": "https://biomejs.dev/linter/rules/no-hardcoded-user-facing-strings",
     "lint/nursery/noHeadElement": "https://biomejs.dev/linter/rules/no-head-element",
     "lint/nursery/noHeadImportInDocument": "https://biomejs.dev/linter/rules/no-head-import-in-document",
+    "lint/nursery/noHighSpecificitySelectors": "https://biomejs.dev/linter/rules/no-high-specificity-selectors",
+    "lint/nursery/noIdSelectors": "https://biomejs.dev/linter/rules/no-id-selectors",
     "lint/nursery/noImgElement": "https://biomejs.dev/linter/rules/no-img-element",
     "lint/nursery/noImportantInKeyframe": "https://biomejs.dev/linter/rules/no-important-in-keyframe",
     "lint/nursery/noInvalidDirectionInLinearGradient": "https://biomejs.dev/linter/rules/no-invalid-direction-in-linear-gradient",
     "lint/nursery/noInvalidGridAreas": "https://biomejs.dev/linter/rules/use-consistent-grid-areas",
     "lint/nursery/noInvalidPositionAtImportRule": "https://biomejs.dev/linter/rules/no-invalid-position-at-import-rule",
     "lint/nursery/noIrregularWhitespace": "https://biomejs.dev/linter/rules/no-irregular-whitespace",
+    "lint/nursery/noLeakedEventListeners": "https://biomejs.dev/linter/rules/no-leaked-event-listeners",
+    "lint/nursery/noLowContrastColorPairs": "https://biomejs.dev/linter/rules/no-low-contrast-color-pairs",
+    "lint/nursery/noMagicNumbers": "https://biomejs.dev/linter/rules/no-magic-numbers",
     "lint/nursery/noMissingGenericFamilyKeyword": "https://biomejs.dev/linter/rules/no-missing-generic-family-keyword",
     "lint/nursery/noMissingVarFunction": "https://biomejs.dev/linter/rules/no-missing-var-function",
+    "lint/nursery/noMisusedPromises": "https://biomejs.dev/linter/rules/no-misused-promises",
+    "lint/nursery/noModuleScopeSideEffects": "https://biomejs.dev/linter/rules/no-module-scope-side-effects",
     "lint/nursery/noNestedTernary": "https://biomejs.dev/linter/rules/no-nested-ternary",
     "lint/nursery/noOctalEscape": "https://biomejs.dev/linter/rules/no-octal-escape",
     "lint/nursery/noProcessEnv": "https://biomejs.dev/linter/rules/no-process-env",
     "lint/nursery/noProcessGlobal": "https://biomejs.dev/linter/rules/no-process-global",
     "lint/nursery/noReactSpecificProps": "https://biomejs.dev/linter/rules/no-react-specific-props",
     "lint/nursery/noRestrictedImports": "https://biomejs.dev/linter/rules/no-restricted-imports",
+    "lint/nursery/noRestrictedSyntax": "https://biomejs.dev/linter/rules/no-restricted-syntax",
     "lint/nursery/noRestrictedTypes": "https://biomejs.dev/linter/rules/no-restricted-types",
     "lint/nursery/noSecrets": "https://biomejs.dev/linter/rules/no-secrets",
     "lint/nursery/noShorthandPropertyOverrides": "https://biomejs.dev/linter/rules/no-shorthand-property-overrides",
+    "lint/nursery/noSqlInjection": "https://biomejs.dev/linter/rules/no-sql-injection",
     "lint/nursery/noStaticElementInteractions": "https://biomejs.dev/linter/rules/no-static-element-interactions",
     "lint/nursery/noSubstr": "https://biomejs.dev/linter/rules/no-substr",
+    "lint/nursery/noSuperLinearRegex": "https://biomejs.dev/linter/rules/no-super-linear-regex",
+    "lint/nursery/noSyncFsInAsyncContexts": "https://biomejs.dev/linter/rules/no-sync-fs-in-async-contexts",
     "lint/nursery/noTemplateCurlyInString": "https://biomejs.dev/linter/rules/no-template-curly-in-string",
+    "lint/nursery/noTodoWithoutTicket": "https://biomejs.dev/linter/rules/no-todo-without-ticket",
+    "lint/nursery/noTransitionAll": "https://biomejs.dev/linter/rules/no-transition-all",
     "lint/nursery/noUndeclaredDependencies": "https://biomejs.dev/linter/rules/no-undeclared-dependencies",
+    "lint/nursery/noUnknownAnimationName": "https://biomejs.dev/linter/rules/no-unknown-animation-name",
     "lint/nursery/noUnknownAtRule": "https://biomejs.dev/linter/rules/no-unknown-at-rule",
+    "lint/nursery/noUnknownContainerFeatureName": "https://biomejs.dev/linter/rules/no-unknown-container-feature-name",
     "lint/nursery/noUnknownFunction": "https://biomejs.dev/linter/rules/no-unknown-function",
     "lint/nursery/noUnknownMediaFeatureName": "https://biomejs.dev/linter/rules/no-unknown-media-feature-name",
     "lint/nursery/noUnknownProperty": "https://biomejs.dev/linter/rules/no-unknown-property",
@@ -183,35 +213,67 @@ define_categories! {
     "lint/nursery/noUnknownTypeSelector": "https://biomejs.dev/linter/rules/no-unknown-type-selector",
     "lint/nursery/noUnknownUnit": "https://biomejs.dev/linter/rules/no-unknown-unit",
     "lint/nursery/noUnmatchableAnbSelector": "https://biomejs.dev/linter/rules/no-unmatchable-anb-selector",
+    "lint/nursery/noUnnecessaryConditions": "https://biomejs.dev/linter/rules/no-unnecessary-conditions",
+    "lint/nursery/noUnsafeTypeAssertions": "https://biomejs.dev/linter/rules/no-unsafe-type-assertions",
+    "lint/nursery/noUnsanitizedDomInsertion": "https://biomejs.dev/linter/rules/no-unsanitized-dom-insertion",
+    "lint/nursery/noUnstableDefaultProps": "https://biomejs.dev/linter/rules/no-unstable-default-props",
+    "lint/nursery/noUnusedCustomProperties": "https://biomejs.dev/linter/rules/no-unused-custom-properties",
+    "lint/nursery/noUnusedExports": "https://biomejs.dev/linter/rules/no-unused-exports",
     "lint/nursery/noUnusedFunctionParameters": "https://biomejs.dev/linter/rules/no-unused-function-parameters",
+    "lint/nursery/noUnusedKeyframes": "https://biomejs.dev/linter/rules/no-unused-keyframes",
     "lint/nursery/noUselessEscapeInRegex": "https://biomejs.dev/linter/rules/no-useless-escape-in-regex",
     "lint/nursery/noUselessStringRaw": "https://biomejs.dev/linter/rules/no-useless-string-raw",
     "lint/nursery/noUselessUndefined": "https://biomejs.dev/linter/rules/no-useless-undefined",
     "lint/nursery/noValueAtRule": "https://biomejs.dev/linter/rules/no-value-at-rule",
+    "lint/nursery/noVendorPrefixes": "https://biomejs.dev/linter/rules/no-vendor-prefixes",
     "lint/nursery/useAdjacentOverloadSignatures": "https://biomejs.dev/linter/rules/use-adjacent-overload-signatures",
+    "lint/nursery/useAllowedUnits": "https://biomejs.dev/linter/rules/use-allowed-units",
     "lint/nursery/useAriaPropsSupportedByRole": "https://biomejs.dev/linter/rules/use-aria-props-supported-by-role",
     "lint/nursery/useAtIndex": "https://biomejs.dev/linter/rules/use-at-index",
     "lint/nursery/useBiomeSuppressionComment": "https://biomejs.dev/linter/rules/use-biome-suppression-comment",
+    "lint/nursery/useBrowserCompat": "https://biomejs.dev/linter/rules/use-browser-compat",
+    "lint/nursery/useClassNamingConvention": "https://biomejs.dev/linter/rules/use-class-naming-convention",
     "lint/nursery/useCollapsedIf": "https://biomejs.dev/linter/rules/use-collapsed-if",
     "lint/nursery/useComponentExportOnlyModules": "https://biomejs.dev/linter/rules/use-components-only-module",
+    "lint/nursery/useConsistentColorFormat": "https://biomejs.dev/linter/rules/use-consistent-color-format",
     "lint/nursery/useConsistentCurlyBraces": "https://biomejs.dev/linter/rules/use-consistent-curly-braces",
     "lint/nursery/useConsistentMemberAccessibility": "https://biomejs.dev/linter/rules/use-consistent-member-accessibility",
+    "lint/nursery/useConsistentTestNaming": "https://biomejs.dev/linter/rules/use-consistent-test-naming",
     "lint/nursery/useDeprecatedReason": "https://biomejs.dev/linter/rules/use-deprecated-reason",
+    "lint/nursery/useErrorCause": "https://biomejs.dev/linter/rules/use-error-cause",
+    "lint/nursery/useExhaustiveSwitchCases": "https://biomejs.dev/linter/rules/use-exhaustive-switch-cases",
     "lint/nursery/useExplicitFunctionReturnType": "https://biomejs.dev/linter/rules/use-explicit-function-return-type",
+    "lint/nursery/useExplicitResourceManagement": "https://biomejs.dev/linter/rules/use-explicit-resource-management",
     "lint/nursery/useExplicitType": "https://biomejs.dev/linter/rules/use-explicit-function-return-type",
     "lint/nursery/useExportsLast": "https://biomejs.dev/linter/rules/use-exports-last",
+    "lint/nursery/useFontFamilyFallbacks": "https://biomejs.dev/linter/rules/use-font-family-fallbacks",
     "lint/nursery/useGoogleFontDisplay": "https://biomejs.dev/linter/rules/use-google-font-display",
     "lint/nursery/useGoogleFontPreconnect": "https://biomejs.dev/linter/rules/use-google-font-preconnect",
     "lint/nursery/useGuardForIn": "https://biomejs.dev/linter/rules/use-guard-for-in",
+    "lint/nursery/useImportAliases": "https://biomejs.dev/linter/rules/use-import-aliases",
     "lint/nursery/useImportRestrictions": "https://biomejs.dev/linter/rules/use-import-restrictions",
+    "lint/nursery/useJsDocConsistency": "https://biomejs.dev/linter/rules/use-js-doc-consistency",
     "lint/nursery/useJsxCurlyBraceConvention": "https://biomejs.dev/linter/rules/use-jsx-curly-brace-convention",
+    "lint/nursery/useLogicalProperties": "https://biomejs.dev/linter/rules/use-logical-properties",
+    "lint/nursery/useMaxFunctionSize": "https://biomejs.dev/linter/rules/use-max-function-size",
+    "lint/nursery/useMaxParams": "https://biomejs.dev/linter/rules/use-max-params",
     "lint/nursery/useNamedOperation": "https://biomejs.dev/linter/rules/use-named-operation",
     "lint/nursery/useNamingConvention": "https://biomejs.dev/linter/rules/use-naming-convention",
+    "lint/nursery/useNumericSeparators": "https://biomejs.dev/linter/rules/use-numeric-separators",
     "lint/nursery/useParseIntRadix": "https://biomejs.dev/linter/rules/use-parse-int-radix",
+    "lint/nursery/usePlatformAgnosticPaths": "https://biomejs.dev/linter/rules/use-platform-agnostic-paths",
+    "lint/nursery/usePreferDestructuring": "https://biomejs.dev/linter/rules/use-prefer-destructuring",
+    "lint/nursery/usePreferObjectSpread": "https://biomejs.dev/linter/rules/use-prefer-object-spread",
+    "lint/nursery/useReadonlyClassProperties": "https://biomejs.dev/linter/rules/use-readonly-class-properties",
+    "lint/nursery/useShorthand": "https://biomejs.dev/linter/rules/use-shorthand",
+    "lint/nursery/useSimplifiedBooleanReturn": "https://biomejs.dev/linter/rules/use-simplified-boolean-return",
     "lint/nursery/useSortedClasses": "https://biomejs.dev/linter/rules/use-sorted-classes",
     "lint/nursery/useStrictMode": "https://biomejs.dev/linter/rules/use-strict-mode",
+    "lint/nursery/useStructuredClone": "https://biomejs.dev/linter/rules/use-structured-clone",
     "lint/nursery/useTrimStartEnd": "https://biomejs.dev/linter/rules/use-trim-start-end",
     "lint/nursery/useValidAutocomplete": "https://biomejs.dev/linter/rules/use-valid-autocomplete",
+    "lint/nursery/useValidGraphqlOperations": "https://biomejs.dev/linter/rules/use-valid-graphql-operations",
+    "lint/nursery/useVarFallbacks": "https://biomejs.dev/linter/rules/use-var-fallbacks",
     "lint/performance/noAccumulatingSpread": "https://biomejs.dev/linter/rules/no-accumulating-spread",
     "lint/performance/noBarrelFile": "https://biomejs.dev/linter/rules/no-barrel-file",
     "lint/performance/noDelete": "https://biomejs.dev/linter/rules/no-delete",
@@ -340,6 +402,7 @@ define_categories! {
     // end lint rules
     // start assists rules
     "assists/source/useSortedKeys": "https://biomejs.dev/linter/actions/use-sorted-keys",
+    "assists/source/useSortedProperties": "https://biomejs.dev/linter/actions/use-sorted-properties",
     // end assists rules
     ; // start syntax rules
     "syntax/correctness/noTypeOnlyImportAttributes",
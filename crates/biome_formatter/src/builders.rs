@@ -132,6 +132,39 @@ pub const fn empty_line() -> Line {
     Line::new(LineMode::Empty)
 }
 
+/// Forced empty lines. Inserts enough line breaks in the output for the previous and next element
+/// to be separated by `count` empty lines. Consecutive empty lines are merged, and print
+/// as many empty lines as the largest `count`.
+///
+/// # Examples
+///
+/// ```
+/// use biome_formatter::{format, format_args};
+/// use biome_formatter::prelude::*;
+///
+/// fn main() -> FormatResult<()> {
+/// let elements = format!(
+///     SimpleFormatContext::default(), [
+///     group(&format_args![
+///         text("a,"),
+///         empty_lines(2),
+///         empty_line(),
+///         text("b"),
+///     ])
+/// ])?;
+///
+/// assert_eq!(
+///     "a,\n\n\nb",
+///     elements.print()?.as_code()
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub const fn empty_lines(count: u8) -> Line {
+    Line::new(LineMode::EmptyLines(count))
+}
+
 /// A line break if the enclosing `Group` doesn't fit on a single line, a space otherwise.
 ///
 /// # Examples
@@ -2487,6 +2520,8 @@ pub struct JoinNodesBuilder<'fmt, 'buf, Separator, Context> {
     separator: Separator,
     fmt: &'fmt mut Formatter<'buf, Context>,
     has_elements: bool,
+    /// The maximum number of empty lines preserved between two nodes
+    max_empty_lines: u8,
 }
 
 impl<'fmt, 'buf, Separator, Context> JoinNodesBuilder<'fmt, 'buf, Separator, Context>
@@ -2499,18 +2534,31 @@ where
             separator,
             fmt,
             has_elements: false,
+            max_empty_lines: 1,
         }
     }
 
+    /// Sets the maximum number of consecutive empty lines preserved between two nodes. Defaults to 1.
+    ///
+    /// The empty lines are removed when it's 0.
+    pub fn with_max_empty_lines(mut self, max_empty_lines: u8) -> Self {
+        self.max_empty_lines = max_empty_lines;
+        self
+    }
+
     /// Adds a new node with the specified formatted content to the output, respecting any new lines
     /// that appear before the node in the input source.
     pub fn entry<L: Language>(&mut self, node: &SyntaxNode<L>, content: &dyn Format<Context>) {
         self.result = self.result.and_then(|_| {
             if self.has_elements {
-                if get_lines_before(node) > 1 {
-                    write!(self.fmt, [empty_line()])?;
-                } else {
-                    self.separator.fmt(self.fmt)?;
+                let empty_lines = get_lines_before(node)
+                    .saturating_sub(1)
+                    .min(usize::from(self.max_empty_lines));
+                match empty_lines {
+                    0 => self.separator.fmt(self.fmt)?,
+                    1 => write!(self.fmt, [empty_line()])?,
+                    // `empty_lines` is at most `max_empty_lines`
+                    count => write!(self.fmt, [self::empty_lines(count as u8)])?,
                 }
             }
 
@@ -115,7 +115,9 @@ impl Document {
                     FormatElement::DynamicText { text, .. } => text.contains('\n'),
                     FormatElement::LocatedTokenText { slice, .. } => slice.contains('\n'),
                     FormatElement::ExpandParent
-                    | FormatElement::Line(LineMode::Hard | LineMode::Empty) => true,
+                    | FormatElement::Line(
+                        LineMode::Hard | LineMode::Empty | LineMode::EmptyLines(_),
+                    ) => true,
                     _ => false,
                 };
 
@@ -309,6 +311,15 @@ impl Format<IrFormatContext> for &[FormatElement] {
                     LineMode::Empty => {
                         write!(f, [text("empty_line")])?;
                     }
+                    LineMode::EmptyLines(count) => {
+                        write!(
+                            f,
+                            [dynamic_text(
+                                &std::format!("empty_lines({count})"),
+                                TextSize::default()
+                            )]
+                        )?;
+                    }
                 },
                 FormatElement::ExpandParent => {
                     write!(f, [text("expand_parent")])?;
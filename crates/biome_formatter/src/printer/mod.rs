@@ -117,7 +117,7 @@ impl<'a> Printer<'a> {
                             }
                             return Ok(());
                         }
-                        LineMode::Hard | LineMode::Empty => {
+                        LineMode::Hard | LineMode::Empty | LineMode::EmptyLines(_) => {
                             self.state.measured_group_fits = false;
                         }
                     }
@@ -133,11 +133,17 @@ impl<'a> Printer<'a> {
                     self.print_str("\n");
                 }
 
-                // Print a second line break if this is an empty line
-                if line_mode == &LineMode::Empty && !self.state.has_empty_line {
+                // Print the missing line breaks if this is an empty line
+                let empty_lines = match line_mode {
+                    LineMode::Empty => 1,
+                    LineMode::EmptyLines(count) => *count,
+                    _ => 0,
+                };
+                let printed_empty_lines = self.state.empty_lines;
+                for _ in printed_empty_lines..empty_lines {
                     self.print_str("\n");
-                    self.state.has_empty_line = true;
                 }
+                self.state.empty_lines = printed_empty_lines.max(empty_lines);
 
                 self.state.pending_space = false;
                 self.state.pending_indent = indent_stack.indention();
@@ -707,7 +713,7 @@ impl<'a> Printer<'a> {
         for char in content.chars() {
             self.print_char(char);
 
-            self.state.has_empty_line = false;
+            self.state.empty_lines = 0;
         }
     }
 
@@ -770,7 +776,8 @@ struct PrinterState<'a> {
     generated_line: usize,
     generated_column: usize,
     line_width: usize,
-    has_empty_line: bool,
+    /// The number of consecutive empty lines printed last.
+    empty_lines: u8,
     line_suffixes: LineSuffixes<'a>,
     verbatim_markers: Vec<TextRange>,
     group_modes: GroupModes,
@@ -1065,7 +1072,7 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
                             self.state.pending_space = true;
                         }
                         LineMode::Soft => {}
-                        LineMode::Hard | LineMode::Empty => {
+                        LineMode::Hard | LineMode::Empty | LineMode::EmptyLines(_) => {
                             // Even in flat mode, content that _directly_ contains a hard or empty
                             // line is considered to fit when a hard break is reached, since that
                             // break is always going to exist, regardless of the print mode.
@@ -1612,6 +1619,20 @@ two lines`,
         assert_eq!("a\n\nb", result.as_code())
     }
 
+    #[test]
+    fn it_prints_consecutive_empty_lines_as_the_largest_count() {
+        let result = format(&format_args![
+            text("a"),
+            empty_line(),
+            empty_lines(3),
+            hard_line_break(),
+            empty_lines(2),
+            text("b"),
+        ]);
+
+        assert_eq!("a\n\n\n\nb", result.as_code())
+    }
+
     #[test]
     fn it_prints_consecutive_mixed_lines_as_one() {
         let result = format(&format_args![
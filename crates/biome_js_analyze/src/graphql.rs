@@ -0,0 +1,182 @@
+//! The GraphQL schemas that the rules check the GraphQL documents embedded in JavaScript against.
+//!
+//! The workspace computes a [GraphqlSchema] for every GraphQL file it parses,
+//! and stores it in the [JsProjectIndex](crate::project::JsProjectIndex).
+
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlPrimitiveType, AnyGraphqlType, AnyGraphqlTypeDefinition,
+    AnyGraphqlTypeExtension, GraphqlFieldsDefinition, GraphqlNameReference, GraphqlRoot,
+    GraphqlRootOperationTypes,
+};
+use rustc_hash::FxHashMap;
+
+/// A field of an object type or of an interface type.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct GraphqlSchemaField {
+    /// The names of the arguments of the field.
+    pub(crate) arguments: Vec<Box<str>>,
+    /// The name of the type of the field, without its list and non-null wrappers.
+    pub(crate) type_name: Box<str>,
+}
+
+/// The object types and the interface types of a schema, as declared in a GraphQL file
+/// written with the schema definition language (SDL).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GraphqlSchema {
+    /// The fields of the object types and of the interface types, including the fields
+    /// of their extensions.
+    types: FxHashMap<Box<str>, FxHashMap<Box<str>, GraphqlSchemaField>>,
+    /// The root operation types declared by the `schema` definition and its extensions,
+    /// by operation type: `query`, `mutation`, or `subscription`.
+    root_operation_types: FxHashMap<Box<str>, Box<str>>,
+}
+
+impl GraphqlSchema {
+    pub fn from_root(root: &GraphqlRoot) -> Self {
+        let mut schema = Self::default();
+        for definition in root.definitions() {
+            let (name, fields) = match definition {
+                AnyGraphqlDefinition::AnyGraphqlTypeDefinition(
+                    AnyGraphqlTypeDefinition::GraphqlObjectTypeDefinition(definition),
+                ) => (
+                    definition.name().and_then(|name| name.value_token()),
+                    definition.fields(),
+                ),
+                AnyGraphqlDefinition::AnyGraphqlTypeDefinition(
+                    AnyGraphqlTypeDefinition::GraphqlInterfaceTypeDefinition(definition),
+                ) => (
+                    definition.name().and_then(|name| name.value_token()),
+                    definition.fields(),
+                ),
+                AnyGraphqlDefinition::AnyGraphqlTypeExtension(
+                    AnyGraphqlTypeExtension::GraphqlObjectTypeExtension(extension),
+                ) => (
+                    extension.name().and_then(|name| name.value_token()),
+                    extension.fields(),
+                ),
+                AnyGraphqlDefinition::AnyGraphqlTypeExtension(
+                    AnyGraphqlTypeExtension::GraphqlInterfaceTypeExtension(extension),
+                ) => (
+                    extension.name().and_then(|name| name.value_token()),
+                    extension.fields(),
+                ),
+                AnyGraphqlDefinition::GraphqlSchemaDefinition(definition) => {
+                    if let Ok(root_operation_types) = definition.root_operation_types() {
+                        schema.add_root_operation_types(&root_operation_types);
+                    }
+                    continue;
+                }
+                AnyGraphqlDefinition::GraphqlSchemaExtension(extension) => {
+                    if let Some(root_operation_types) = extension.root_operation_types() {
+                        schema.add_root_operation_types(&root_operation_types);
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            let Ok(name) = name else {
+                continue;
+            };
+            let type_fields = schema.types.entry(name.text_trimmed().into()).or_default();
+            if let Some(fields) = fields {
+                add_fields(type_fields, &fields);
+            }
+        }
+        schema
+    }
+
+    fn add_root_operation_types(&mut self, root_operation_types: &GraphqlRootOperationTypes) {
+        for root_operation_type in root_operation_types.root_operation_type() {
+            let (Ok(operation_type), Ok(named_type)) = (
+                root_operation_type
+                    .operation_type()
+                    .and_then(|operation_type| operation_type.value_token()),
+                root_operation_type
+                    .named_type()
+                    .and_then(|named_type| named_type.value_token()),
+            ) else {
+                continue;
+            };
+            self.root_operation_types.insert(
+                operation_type.text_trimmed().into(),
+                named_type.text_trimmed().into(),
+            );
+        }
+    }
+
+    /// Returns the name of the root type of the operations of `operation_type`,
+    /// e.g. `Query` for `query`.
+    pub(crate) fn root_type<'a>(&'a self, operation_type: &'a str) -> &'a str {
+        if let Some(root_type) = self.root_operation_types.get(operation_type) {
+            return root_type;
+        }
+        match operation_type {
+            "query" => "Query",
+            "mutation" => "Mutation",
+            "subscription" => "Subscription",
+            _ => operation_type,
+        }
+    }
+
+    /// Returns the fields of the object type or of the interface type named `name`.
+    pub(crate) fn fields(&self, name: &str) -> Option<&FxHashMap<Box<str>, GraphqlSchemaField>> {
+        self.types.get(name)
+    }
+}
+
+fn add_fields(
+    type_fields: &mut FxHashMap<Box<str>, GraphqlSchemaField>,
+    fields: &GraphqlFieldsDefinition,
+) {
+    for field in fields.fields() {
+        let (Ok(name), Some(type_name)) = (
+            field.name().and_then(|name| name.value_token()),
+            field
+                .ty()
+                .ok()
+                .as_ref()
+                .and_then(named_type)
+                .and_then(|type_name| type_name.value_token().ok()),
+        ) else {
+            continue;
+        };
+        let arguments = field
+            .arguments()
+            .into_iter()
+            .flat_map(|arguments| arguments.arguments())
+            .filter_map(|argument| {
+                let name = argument.name().and_then(|name| name.value_token()).ok()?;
+                Some(name.text_trimmed().into())
+            })
+            .collect();
+        type_fields.insert(
+            name.text_trimmed().into(),
+            GraphqlSchemaField {
+                arguments,
+                type_name: type_name.text_trimmed().into(),
+            },
+        );
+    }
+}
+
+/// Returns the named type wrapped by the list and non-null types of `ty`,
+/// e.g. `User` for `[User!]!`.
+fn named_type(ty: &AnyGraphqlType) -> Option<GraphqlNameReference> {
+    let mut primitive = match ty {
+        AnyGraphqlType::AnyGraphqlPrimitiveType(primitive) => primitive.clone(),
+        AnyGraphqlType::GraphqlNonNullType(non_null) => non_null.base().ok()?,
+        AnyGraphqlType::GraphqlBogusType(_) => return None,
+    };
+    loop {
+        match primitive {
+            AnyGraphqlPrimitiveType::GraphqlNameReference(name) => return Some(name),
+            AnyGraphqlPrimitiveType::GraphqlListType(list) => {
+                primitive = match list.element().ok()? {
+                    AnyGraphqlType::AnyGraphqlPrimitiveType(primitive) => primitive,
+                    AnyGraphqlType::GraphqlNonNullType(non_null) => non_null.base().ok()?,
+                    AnyGraphqlType::GraphqlBogusType(_) => return None,
+                };
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 #![warn(clippy::needless_pass_by_value)]
 
+use crate::project::JsProjectIndex;
 use crate::suppression_action::JsSuppressionAction;
 use biome_analyze::{
     AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
@@ -17,8 +18,10 @@ use std::sync::{Arc, LazyLock};
 pub mod assists;
 mod ast_utils;
 pub mod globals;
+pub mod graphql;
 pub mod lint;
 pub mod options;
+pub mod project;
 mod react;
 mod registry;
 mod services;
@@ -43,6 +46,7 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
 /// Additionally, this function takes a `inspect_matcher` function that can be
 /// used to inspect the "query matches" emitted by the analyzer before they are
 /// processed by the lint rules registry
+#[allow(clippy::too_many_arguments)]
 pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
@@ -50,6 +54,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    project: Arc<JsProjectIndex>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -123,6 +128,7 @@ where
 
     services.insert_service(Arc::new(AriaRoles));
     services.insert_service(Arc::new(manifest));
+    services.insert_service(project);
     services.insert_service(source_type);
     (
         analyzer.run(AnalyzerContext {
@@ -144,6 +150,7 @@ pub fn analyze<'a, F, B>(
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    project: Arc<JsProjectIndex>,
     emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -157,6 +164,7 @@ where
         options,
         source_type,
         manifest,
+        project,
         emit_signal,
     )
 }
@@ -210,6 +218,7 @@ mod tests {
                 dependencies,
                 ..Default::default()
             }),
+            Default::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -302,6 +311,7 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            Default::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let span = diag.get_span();
@@ -388,6 +398,7 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            Default::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let code = diag.category().unwrap();
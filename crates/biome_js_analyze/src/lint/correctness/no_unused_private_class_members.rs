@@ -2,9 +2,10 @@ use biome_analyze::{
     context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleDiagnostic, RuleSource,
 };
 use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
 use biome_js_syntax::{
-    AnyJsClassMember, AnyJsClassMemberName, AnyJsFormalParameter, AnyJsName,
-    JsAssignmentExpression, JsAssignmentOperator, JsClassDeclaration, JsSyntaxKind, JsSyntaxNode,
+    AnyJsClass, AnyJsClassMember, AnyJsClassMemberName, AnyJsFormalParameter, AnyJsName,
+    JsAssignmentExpression, JsAssignmentOperator, JsSyntaxKind, JsSyntaxNode,
     TsAccessibilityModifier, TsPropertyParameter,
 };
 use biome_rowan::{
@@ -12,6 +13,7 @@ use biome_rowan::{
     SyntaxNodeOptionExt, TextRange,
 };
 use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
 
 use crate::{utils::is_node_equal, JsRuleAction};
 
@@ -59,6 +61,39 @@ declare_lint_rule! {
     /// }
     /// ```
     ///
+    /// ```js
+    /// const Counter = class {
+    ///   #count = 0;
+    ///
+    ///   get count() {
+    ///     return this.#count;
+    ///   }
+    /// };
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `ignoreTsPrivateMembers`
+    ///
+    /// Only check the `#private` members, and ignore the members declared with the TypeScript `private` modifier.
+    /// Unlike the `#private` members, the TypeScript `private` members are accessible at runtime,
+    /// for example with `this["member"]` or from the code that ignores the type errors.
+    /// Defaults to `false`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignoreTsPrivateMembers": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,use_options
+    /// class Store {
+    ///   private cache = new Map();
+    /// }
+    /// ```
+    ///
     pub NoUnusedPrivateClassMembers {
         version: "1.3.3",
         name: "noUnusedPrivateClassMembers",
@@ -69,19 +104,29 @@ declare_lint_rule! {
     }
 }
 
+/// Options for the rule `noUnusedPrivateClassMembers`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoUnusedPrivateClassMembersOptions {
+    /// Ignore the members declared with the TypeScript `private` modifier.
+    pub ignore_ts_private_members: bool,
+}
+
 declare_node_union! {
     pub AnyMember = AnyJsClassMember | TsPropertyParameter
 }
 
 impl Rule for NoUnusedPrivateClassMembers {
-    type Query = Ast<JsClassDeclaration>;
+    type Query = Ast<AnyJsClass>;
     type State = AnyMember;
     type Signals = Box<[Self::State]>;
-    type Options = ();
+    type Options = Box<NoUnusedPrivateClassMembersOptions>;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
-        let private_members: FxHashSet<AnyMember> = get_all_declared_private_members(node);
+        let private_members: FxHashSet<AnyMember> =
+            get_all_declared_private_members(node, ctx.options().ignore_ts_private_members);
         if private_members.is_empty() {
             Vec::new()
         } else {
@@ -157,18 +202,19 @@ fn traverse_members_usage(
 }
 
 fn get_all_declared_private_members(
-    class_declaration: &JsClassDeclaration,
+    class_declaration: &AnyJsClass,
+    ignore_ts_private_members: bool,
 ) -> FxHashSet<AnyMember> {
     class_declaration
         .members()
         .iter()
         .map(AnyMember::AnyJsClassMember)
         .chain(get_constructor_params(class_declaration))
-        .filter(|member| member.is_private() == Some(true))
+        .filter(|member| member.is_private(ignore_ts_private_members) == Some(true))
         .collect()
 }
 
-fn get_constructor_params(class_declaration: &JsClassDeclaration) -> FxHashSet<AnyMember> {
+fn get_constructor_params(class_declaration: &AnyJsClass) -> FxHashSet<AnyMember> {
     let constructor_member = class_declaration
         .members()
         .iter()
@@ -253,7 +299,9 @@ impl AnyMember {
         )
     }
 
-    fn is_private(&self) -> Option<bool> {
+    /// Returns `true` if the member is a `#private` member,
+    /// or a TypeScript `private` member when they aren't ignored.
+    fn is_private(&self, ignore_ts_private_members: bool) -> Option<bool> {
         match self {
             AnyMember::AnyJsClassMember(member) => {
                 let is_es_private = matches!(
@@ -284,14 +332,15 @@ impl AnyMember {
                     _ => false,
                 };
 
-                Some(is_es_private || is_ts_private)
+                Some(is_es_private || (is_ts_private && !ignore_ts_private_members))
             }
             AnyMember::TsPropertyParameter(param) => Some(
-                param
-                    .modifiers()
-                    .iter()
-                    .filter_map(|x| TsAccessibilityModifier::cast(x.into_syntax()))
-                    .any(|accessibility| accessibility.is_private()),
+                !ignore_ts_private_members
+                    && param
+                        .modifiers()
+                        .iter()
+                        .filter_map(|x| TsAccessibilityModifier::cast(x.into_syntax()))
+                        .any(|accessibility| accessibility.is_private()),
             ),
         }
     }
@@ -1,17 +1,21 @@
 use crate::react::hooks::*;
 use crate::services::semantic::Semantic;
-use biome_analyze::RuleSource;
+use crate::JsRuleAction;
 use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_analyze::{FixKind, RuleSource};
 use biome_console::markup;
 use biome_deserialize::{non_empty, DeserializableValidator, DeserializationDiagnostic};
 use biome_deserialize_macros::Deserializable;
+use biome_js_factory::make;
 use biome_js_semantic::{Capture, SemanticModel};
 use biome_js_syntax::{
     binding_ext::AnyJsBindingDeclaration, JsCallExpression, JsSyntaxKind, JsSyntaxNode,
-    JsVariableDeclaration, TextRange,
+    JsVariableDeclaration, TextRange, T,
 };
-use biome_js_syntax::{AnyJsExpression, AnyJsMemberExpression, TsTypeofType};
-use biome_rowan::{AstNode, SyntaxNodeCast};
+use biome_js_syntax::{
+    AnyJsArrayElement, AnyJsExpression, AnyJsMemberExpression, JsArrayExpression, TsTypeofType,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxNodeCast, TriviaPieceKind};
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -233,6 +237,31 @@ declare_lint_rule! {
     /// const doAction = useCallback(() => dispatch(someAction()), []);
     /// ```
     ///
+    /// ### Custom hooks and stable results together
+    ///
+    /// Both options can be combined for the same hook. This is useful for
+    /// state management libraries such as Zustand or Jotai, whose wrappers
+    /// often take a dependencies array _and_ return stable setters:
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "hooks": [
+    ///             { "name": "useStoreEffect", "closureIndex": 0, "dependenciesIndex": 1 },
+    ///             { "name": "useAtom", "stableResult": [1] }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Code fix
+    ///
+    /// When a dependency is missing and the hook is called with an array
+    /// literal, the rule provides an unsafe fix that appends the dependency to
+    /// the end of that array. The fix is unsafe because it changes when the
+    /// hook runs again: an effect that updates its new dependency can end up
+    /// in a render loop.
+    ///
     /// ## Preact support
     ///
     /// This rule recognizes rules imported from `preact/compat` and
@@ -244,6 +273,7 @@ declare_lint_rule! {
         language: "jsx",
         sources: &[RuleSource::EslintReactHooks("exhaustive-deps")],
         recommended: true,
+        fix_kind: FixKind::Unsafe,
     }
 }
 
@@ -417,6 +447,11 @@ pub enum Fix {
         function_name_range: TextRange,
         captures: (Box<str>, Box<[TextRange]>),
         dependencies_len: usize,
+        /// The array literal in which the dependency should be inserted, if
+        /// the dependencies argument is one.
+        dependencies_array: Option<JsArrayExpression>,
+        /// The expression to insert in the dependencies array.
+        dependency: AnyJsExpression,
     },
     /// When a dependency needs to be removed.
     RemoveDependency {
@@ -444,6 +479,19 @@ pub enum UnstableDependencyKind {
     ObjectLiteral,
 }
 
+/// Returns the expression that should be added to the dependency list in order
+/// to cover the given capture.
+fn dependency_of_capture(capture_text: &str, capture_path: &JsSyntaxNode) -> AnyJsExpression {
+    // Captured member expressions are reused as they are, plain references are
+    // rebuilt because their node isn't an expression (e.g. JSX references).
+    AnyJsExpression::cast_ref(capture_path)
+        .and_then(|expression| expression.trim_trivia())
+        .unwrap_or_else(|| {
+            make::js_identifier_expression(make::js_reference_identifier(make::ident(capture_text)))
+                .into()
+        })
+}
+
 fn get_whole_static_member_expression(reference: &JsSyntaxNode) -> Option<AnyJsMemberExpression> {
     let root = reference
         .ancestors()
@@ -792,8 +840,13 @@ impl Rule for UseExhaustiveDependencies {
 
             let deps: Vec<_> = result.all_dependencies().collect();
             let dependencies_len = deps.len();
+            let dependencies_array = result
+                .dependencies_node
+                .as_ref()
+                .and_then(|node| node.as_js_array_expression().cloned());
 
-            let mut add_deps: BTreeMap<Box<str>, Vec<TextRange>> = BTreeMap::new();
+            let mut add_deps: BTreeMap<Box<str>, (AnyJsExpression, Vec<TextRange>)> =
+                BTreeMap::new();
 
             // Evaluate all the captures
             for (capture_text, capture_range, capture_path) in captures.iter() {
@@ -843,7 +896,15 @@ impl Rule for UseExhaustiveDependencies {
                 }
 
                 if !is_captured_covered {
-                    let captures = add_deps.entry(capture_text.clone().into()).or_default();
+                    let (_, captures) =
+                        add_deps
+                            .entry(capture_text.clone().into())
+                            .or_insert_with(|| {
+                                (
+                                    dependency_of_capture(capture_text, capture_path),
+                                    Vec::new(),
+                                )
+                            });
                     captures.push(*capture_range);
                 }
             }
@@ -882,11 +943,13 @@ impl Rule for UseExhaustiveDependencies {
             });
 
             // Generate signals
-            for (name, ranges) in add_deps {
+            for (name, (dependency, ranges)) in add_deps {
                 signals.push(Fix::AddDependency {
                     function_name_range: result.function_name_range,
                     captures: (name, ranges.into_boxed_slice()),
                     dependencies_len,
+                    dependencies_array: dependencies_array.clone(),
+                    dependency,
                 });
             }
 
@@ -945,6 +1008,7 @@ impl Rule for UseExhaustiveDependencies {
                 function_name_range,
                 captures,
                 dependencies_len,
+                ..
             } => {
                 let (capture_text, captures_range) = captures;
                 let mut diag = RuleDiagnostic::new(
@@ -1046,4 +1110,52 @@ impl Rule for UseExhaustiveDependencies {
             }
         }
     }
+
+    fn action(ctx: &RuleContext<Self>, dep: &Self::State) -> Option<JsRuleAction> {
+        let Fix::AddDependency {
+            captures,
+            dependencies_array: Some(dependencies_array),
+            dependency,
+            ..
+        } = dep
+        else {
+            return None;
+        };
+
+        let elements = dependencies_array.elements();
+        let mut items = elements.iter().collect::<Result<Vec<_>, _>>().ok()?;
+        let mut separators = elements.separators().collect::<Result<Vec<_>, _>>().ok()?;
+        let has_trailing_separator = !items.is_empty() && separators.len() == items.len();
+
+        if has_trailing_separator {
+            // `[a, b,]` becomes `[a, b, c,]`: the trailing comma now separates
+            // `b` from the new dependency, and a new trailing comma is added.
+            let separator = separators.pop()?;
+            separators.push(if separator.trailing_trivia().pieces().next().is_none() {
+                separator.with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+            } else {
+                separator
+            });
+            items.push(AnyJsArrayElement::AnyJsExpression(dependency.clone()));
+            separators.push(make::token(T![,]));
+        } else {
+            if !items.is_empty() {
+                separators.push(
+                    make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                );
+            }
+            items.push(AnyJsArrayElement::AnyJsExpression(dependency.clone()));
+        }
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(elements, make::js_array_element_list(items, separators));
+
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Add the missing dependency "<Emphasis>{captures.0.as_ref()}</Emphasis>" to the list." }
+                .to_owned(),
+            mutation,
+        ))
+    }
 }
@@ -2,28 +2,51 @@
 
 use biome_analyze::declare_lint_group;
 
+pub mod no_await_in_loop;
+pub mod no_circular_imports;
+pub mod no_commented_out_code;
 pub mod no_common_js;
+pub mod no_conditional_expect;
+pub mod no_deep_relative_imports;
+pub mod no_deprecated_apis;
 pub mod no_document_cookie;
 pub mod no_document_import_in_page;
 pub mod no_duplicate_else_if;
+pub mod no_duplicate_test_titles;
 pub mod no_dynamic_namespace_import_access;
 pub mod no_enum;
 pub mod no_exported_imports;
+pub mod no_floating_promises;
 pub mod no_global_dirname_filename;
+pub mod no_hardcoded_user_facing_strings;
 pub mod no_head_element;
 pub mod no_head_import_in_document;
 pub mod no_img_element;
 pub mod no_irregular_whitespace;
+pub mod no_leaked_event_listeners;
+pub mod no_magic_numbers;
+pub mod no_misused_promises;
+pub mod no_module_scope_side_effects;
 pub mod no_nested_ternary;
 pub mod no_octal_escape;
 pub mod no_process_env;
 pub mod no_process_global;
 pub mod no_restricted_imports;
+pub mod no_restricted_syntax;
 pub mod no_restricted_types;
 pub mod no_secrets;
+pub mod no_sql_injection;
 pub mod no_static_element_interactions;
 pub mod no_substr;
+pub mod no_super_linear_regex;
+pub mod no_sync_fs_in_async_contexts;
 pub mod no_template_curly_in_string;
+pub mod no_todo_without_ticket;
+pub mod no_unnecessary_conditions;
+pub mod no_unsafe_type_assertions;
+pub mod no_unsanitized_dom_insertion;
+pub mod no_unstable_default_props;
+pub mod no_unused_exports;
 pub mod no_useless_escape_in_regex;
 pub mod no_useless_string_raw;
 pub mod no_useless_undefined;
@@ -34,44 +57,83 @@ pub mod use_collapsed_if;
 pub mod use_component_export_only_modules;
 pub mod use_consistent_curly_braces;
 pub mod use_consistent_member_accessibility;
+pub mod use_consistent_test_naming;
+pub mod use_error_cause;
+pub mod use_exhaustive_switch_cases;
+pub mod use_explicit_resource_management;
 pub mod use_explicit_type;
 pub mod use_exports_last;
 pub mod use_google_font_display;
 pub mod use_google_font_preconnect;
 pub mod use_guard_for_in;
+pub mod use_import_aliases;
 pub mod use_import_restrictions;
+pub mod use_js_doc_consistency;
+pub mod use_max_function_size;
+pub mod use_max_params;
+pub mod use_numeric_separators;
 pub mod use_parse_int_radix;
+pub mod use_platform_agnostic_paths;
+pub mod use_prefer_destructuring;
+pub mod use_prefer_object_spread;
+pub mod use_readonly_class_properties;
+pub mod use_simplified_boolean_return;
 pub mod use_sorted_classes;
 pub mod use_strict_mode;
+pub mod use_structured_clone;
 pub mod use_trim_start_end;
 pub mod use_valid_autocomplete;
+pub mod use_valid_graphql_operations;
 
 declare_lint_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_await_in_loop :: NoAwaitInLoop ,
+            self :: no_circular_imports :: NoCircularImports ,
+            self :: no_commented_out_code :: NoCommentedOutCode ,
             self :: no_common_js :: NoCommonJs ,
+            self :: no_conditional_expect :: NoConditionalExpect ,
+            self :: no_deep_relative_imports :: NoDeepRelativeImports ,
+            self :: no_deprecated_apis :: NoDeprecatedApis ,
             self :: no_document_cookie :: NoDocumentCookie ,
             self :: no_document_import_in_page :: NoDocumentImportInPage ,
             self :: no_duplicate_else_if :: NoDuplicateElseIf ,
+            self :: no_duplicate_test_titles :: NoDuplicateTestTitles ,
             self :: no_dynamic_namespace_import_access :: NoDynamicNamespaceImportAccess ,
             self :: no_enum :: NoEnum ,
             self :: no_exported_imports :: NoExportedImports ,
+            self :: no_floating_promises :: NoFloatingPromises ,
             self :: no_global_dirname_filename :: NoGlobalDirnameFilename ,
+            self :: no_hardcoded_user_facing_strings :: NoHardcodedUserFacingStrings ,
             self :: no_head_element :: NoHeadElement ,
             self :: no_head_import_in_document :: NoHeadImportInDocument ,
             self :: no_img_element :: NoImgElement ,
             self :: no_irregular_whitespace :: NoIrregularWhitespace ,
+            self :: no_leaked_event_listeners :: NoLeakedEventListeners ,
+            self :: no_magic_numbers :: NoMagicNumbers ,
+            self :: no_misused_promises :: NoMisusedPromises ,
+            self :: no_module_scope_side_effects :: NoModuleScopeSideEffects ,
             self :: no_nested_ternary :: NoNestedTernary ,
             self :: no_octal_escape :: NoOctalEscape ,
             self :: no_process_env :: NoProcessEnv ,
             self :: no_process_global :: NoProcessGlobal ,
             self :: no_restricted_imports :: NoRestrictedImports ,
+            self :: no_restricted_syntax :: NoRestrictedSyntax ,
             self :: no_restricted_types :: NoRestrictedTypes ,
             self :: no_secrets :: NoSecrets ,
+            self :: no_sql_injection :: NoSqlInjection ,
             self :: no_static_element_interactions :: NoStaticElementInteractions ,
             self :: no_substr :: NoSubstr ,
+            self :: no_super_linear_regex :: NoSuperLinearRegex ,
+            self :: no_sync_fs_in_async_contexts :: NoSyncFsInAsyncContexts ,
             self :: no_template_curly_in_string :: NoTemplateCurlyInString ,
+            self :: no_todo_without_ticket :: NoTodoWithoutTicket ,
+            self :: no_unnecessary_conditions :: NoUnnecessaryConditions ,
+            self :: no_unsafe_type_assertions :: NoUnsafeTypeAssertions ,
+            self :: no_unsanitized_dom_insertion :: NoUnsanitizedDomInsertion ,
+            self :: no_unstable_default_props :: NoUnstableDefaultProps ,
+            self :: no_unused_exports :: NoUnusedExports ,
             self :: no_useless_escape_in_regex :: NoUselessEscapeInRegex ,
             self :: no_useless_string_raw :: NoUselessStringRaw ,
             self :: no_useless_undefined :: NoUselessUndefined ,
@@ -82,17 +144,33 @@ declare_lint_group! {
             self :: use_component_export_only_modules :: UseComponentExportOnlyModules ,
             self :: use_consistent_curly_braces :: UseConsistentCurlyBraces ,
             self :: use_consistent_member_accessibility :: UseConsistentMemberAccessibility ,
+            self :: use_consistent_test_naming :: UseConsistentTestNaming ,
+            self :: use_error_cause :: UseErrorCause ,
+            self :: use_exhaustive_switch_cases :: UseExhaustiveSwitchCases ,
+            self :: use_explicit_resource_management :: UseExplicitResourceManagement ,
             self :: use_explicit_type :: UseExplicitType ,
             self :: use_exports_last :: UseExportsLast ,
             self :: use_google_font_display :: UseGoogleFontDisplay ,
             self :: use_google_font_preconnect :: UseGoogleFontPreconnect ,
             self :: use_guard_for_in :: UseGuardForIn ,
+            self :: use_import_aliases :: UseImportAliases ,
             self :: use_import_restrictions :: UseImportRestrictions ,
+            self :: use_js_doc_consistency :: UseJsDocConsistency ,
+            self :: use_max_function_size :: UseMaxFunctionSize ,
+            self :: use_max_params :: UseMaxParams ,
+            self :: use_numeric_separators :: UseNumericSeparators ,
             self :: use_parse_int_radix :: UseParseIntRadix ,
+            self :: use_platform_agnostic_paths :: UsePlatformAgnosticPaths ,
+            self :: use_prefer_destructuring :: UsePreferDestructuring ,
+            self :: use_prefer_object_spread :: UsePreferObjectSpread ,
+            self :: use_readonly_class_properties :: UseReadonlyClassProperties ,
+            self :: use_simplified_boolean_return :: UseSimplifiedBooleanReturn ,
             self :: use_sorted_classes :: UseSortedClasses ,
             self :: use_strict_mode :: UseStrictMode ,
+            self :: use_structured_clone :: UseStructuredClone ,
             self :: use_trim_start_end :: UseTrimStartEnd ,
             self :: use_valid_autocomplete :: UseValidAutocomplete ,
+            self :: use_valid_graphql_operations :: UseValidGraphqlOperations ,
         ]
      }
 }
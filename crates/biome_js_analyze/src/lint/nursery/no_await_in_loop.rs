@@ -0,0 +1,196 @@
+use crate::services::control_flow::AnyJsControlFlowRoot;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyJsStatement, JsAwaitExpression, JsDoWhileStatement, JsForInStatement, JsForOfStatement,
+    JsForStatement, JsLabeledStatement, JsSyntaxNode, JsWhileStatement,
+};
+use biome_rowan::{declare_node_union, AstNode, SyntaxResult, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow `await` inside of loops.
+    ///
+    /// Awaiting in the body of a loop waits for every iteration to complete before starting the next one.
+    /// When the iterations are independent, they run one after the other instead of concurrently,
+    /// and the loop is slower than needed.
+    /// Start all the operations in the loop, and wait for them at once with `Promise.all()`.
+    ///
+    /// The rule reports the `await` expressions and the `for await` loops in the body of a `for`, `for...in`, `for...of`,
+    /// `while`, or `do...while` loop, and in the condition or the update of the loops that evaluate them on every iteration.
+    /// The body of a `for await` loop is already sequential, and isn't reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function saveAll(items) {
+    ///   for (const item of items) {
+    ///     await save(item);
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function poll() {
+    ///   while (await isPending()) {
+    ///     wait();
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function saveAll(items) {
+    ///   await Promise.all(items.map((item) => save(item)));
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// async function printAll(stream) {
+    ///   for await (const chunk of stream) {
+    ///     await print(chunk);
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `sequentialComment`
+    ///
+    /// Allow `await` in the loops preceded by a comment that contains the given text,
+    /// for the loops whose iterations depend on each other or must not run concurrently.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "sequentialComment": "sequential"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options
+    /// async function migrate(migrations) {
+    ///   // sequential: a migration depends on the previous ones
+    ///   for (const migration of migrations) {
+    ///     await migration.run();
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoAwaitInLoop {
+        version: "next",
+        name: "noAwaitInLoop",
+        language: "js",
+        sources: &[RuleSource::Eslint("no-await-in-loop")],
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noAwaitInLoop`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoAwaitInLoopOptions {
+    /// The text of the comment that marks a loop as explicitly sequential.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequential_comment: Option<Box<str>>,
+}
+
+declare_node_union! {
+    pub AnyJsAwait = JsAwaitExpression | JsForOfStatement
+}
+
+impl Rule for NoAwaitInLoop {
+    type Query = Ast<AnyJsAwait>;
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = Box<NoAwaitInLoopOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let range = match ctx.query() {
+            AnyJsAwait::JsAwaitExpression(expression) => expression.await_token().ok()?,
+            AnyJsAwait::JsForOfStatement(statement) => statement.await_token()?,
+        }
+        .text_trimmed_range();
+        let sequential_comment = ctx.options().sequential_comment.as_deref();
+        let mut node = ctx.query().syntax().clone();
+        for parent in node.clone().ancestors().skip(1) {
+            if is_boundary(&parent) {
+                return None;
+            }
+            if is_looped(&node, &parent)
+                && !sequential_comment.is_some_and(|text| has_comment(&parent, text))
+            {
+                return Some(range);
+            }
+            node = parent;
+        }
+        None
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Avoid using "<Emphasis>"await"</Emphasis>" inside of loops."
+                },
+            )
+            .note(markup! {
+                "Every iteration waits for the previous one to complete, instead of running concurrently."
+            })
+            .note(markup! {
+                "Start all the operations in the loop, and wait for them at once with "<Emphasis>"Promise.all()"</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if the awaits inside of `node` don't belong to the loops around it:
+/// the functions, and the `for await` loops that are already sequential.
+fn is_boundary(node: &JsSyntaxNode) -> bool {
+    AnyJsControlFlowRoot::can_cast(node.kind())
+        || JsForOfStatement::cast_ref(node)
+            .is_some_and(|statement| statement.await_token().is_some())
+}
+
+/// Returns `true` if `child` is evaluated on every iteration of the loop `parent`.
+fn is_looped(child: &JsSyntaxNode, parent: &JsSyntaxNode) -> bool {
+    if let Some(statement) = JsForStatement::cast_ref(parent) {
+        statement
+            .initializer()
+            .map_or(true, |initializer| initializer.syntax() != child)
+    } else if let Some(statement) = JsForInStatement::cast_ref(parent) {
+        is_body(statement.body(), child)
+    } else if let Some(statement) = JsForOfStatement::cast_ref(parent) {
+        is_body(statement.body(), child)
+    } else {
+        JsWhileStatement::can_cast(parent.kind()) || JsDoWhileStatement::can_cast(parent.kind())
+    }
+}
+
+fn is_body(body: SyntaxResult<AnyJsStatement>, child: &JsSyntaxNode) -> bool {
+    body.is_ok_and(|body| body.syntax() == child)
+}
+
+/// Returns `true` if a comment that precedes `statement`, or its label, contains `text`.
+fn has_comment(statement: &JsSyntaxNode, text: &str) -> bool {
+    let statement = statement
+        .parent()
+        .filter(|parent| JsLabeledStatement::can_cast(parent.kind()))
+        .unwrap_or_else(|| statement.clone());
+    statement.first_token().is_some_and(|token| {
+        token
+            .leading_trivia()
+            .pieces()
+            .filter_map(|piece| piece.as_comments())
+            .any(|comment| comment.text().contains(text))
+    })
+}
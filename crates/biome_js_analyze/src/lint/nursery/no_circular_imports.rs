@@ -0,0 +1,190 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::AnyJsRoot;
+use biome_rowan::TextRange;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU16;
+use std::path::{Component, Path, PathBuf};
+
+use crate::project::JsModuleSummary;
+use crate::services::project::Project;
+
+declare_lint_rule! {
+    /// Disallow the imports that form a cycle between modules.
+    ///
+    /// When a module imports, directly or through other modules, a module that imports it back,
+    /// the modules of the cycle are evaluated in an order that depends on which one is imported first.
+    /// A module can then access a binding of another module before its initialization, and throw a `ReferenceError`.
+    /// Cycles also make the modules harder to understand, test, and split into chunks.
+    ///
+    /// The rule looks for the imports, the re-exports, the dynamic imports, and the calls to `require()`
+    /// of all the modules handled by Biome, and resolves their relative specifiers in the same way as
+    /// [noUnusedExports](https://biomejs.dev/linter/rules/no-unused-exports).
+    /// Every module of a cycle reports its import of the next module, along with the full chain of modules.
+    ///
+    /// Note that the modules that aren't handled by Biome, as well as the imports that go through a package name
+    /// or a path alias, aren't taken into account.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js
+    /// // a.js, while b.js does `import { a } from "./a.js"`
+    /// import { b } from "./b.js";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// // a.js, while b.js imports nothing
+    /// import { b } from "./b.js";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxCycleLength`
+    ///
+    /// The maximum number of modules of a reported cycle.
+    /// A long cycle is usually harder to break than a short one, so this option allows fixing the short cycles first.
+    /// By default, all the cycles are reported.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "maxCycleLength": 3
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### `ignoreTypeOnlyImports`
+    ///
+    /// Whether to ignore the imports that are erased at compile time, such as `import type { T } from "./types"`.
+    /// These imports can't change the order of evaluation of the modules.
+    ///
+    /// Default: `false`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignoreTypeOnlyImports": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoCircularImports {
+        version: "next",
+        name: "noCircularImports",
+        language: "js",
+        sources: &[RuleSource::EslintImport("no-cycle")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noCircularImports`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoCircularImportsOptions {
+    /// The maximum number of modules of a reported cycle. All the cycles are reported if it's unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_cycle_length: Option<NonZeroU16>,
+    /// Whether to ignore the imports that are erased at compile time.
+    ignore_type_only_imports: bool,
+}
+
+pub struct CircularImport {
+    range: TextRange,
+    /// The modules of the cycle, starting with the module that contains the import.
+    cycle: Box<[PathBuf]>,
+}
+
+impl Rule for NoCircularImports {
+    type Query = Project<AnyJsRoot>;
+    type State = CircularImport;
+    type Signals = Box<[Self::State]>;
+    type Options = NoCircularImportsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let path = ctx.file_path();
+        let project = ctx.project();
+        let options = ctx.options();
+        let max_length = options
+            .max_cycle_length
+            .map_or(usize::MAX, |max_length| max_length.get().into());
+        let summary = JsModuleSummary::from_root(ctx.query());
+        let mut visited = FxHashSet::default();
+        let mut signals = Vec::new();
+        for import in summary.imports() {
+            if options.ignore_type_only_imports && import.is_type_only {
+                continue;
+            }
+            let Some(dependency) = project.resolve(path, &import.specifier) else {
+                continue;
+            };
+            if !visited.insert(dependency.clone()) {
+                // Only the first import of a module is reported
+                continue;
+            }
+            if let Some(cycle) = project.find_cycle(
+                path,
+                &dependency,
+                max_length,
+                options.ignore_type_only_imports,
+            ) {
+                signals.push(CircularImport {
+                    range: import.range,
+                    cycle: cycle.into_boxed_slice(),
+                });
+            }
+        }
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let directory = state.cycle[0].parent().unwrap_or(Path::new(""));
+        let chain = state
+            .cycle
+            .iter()
+            .chain(state.cycle.first())
+            .map(|module| relative_path(directory, module).display().to_string())
+            .collect::<Vec<_>>()
+            .join(" → ");
+        let length = state.cycle.len();
+        let message = if length == 1 {
+            markup! { "This module imports itself." }.to_owned()
+        } else {
+            markup! { "This import is part of a cycle of "{length}" modules." }.to_owned()
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), state.range, message)
+            .note(markup! {
+                "The cycle is: "<Emphasis>{chain}</Emphasis>
+            })
+            .note(markup! {
+                "The modules of a cycle can access the bindings of each other before their initialization, depending on which module is imported first."
+            })
+            .note(markup! {
+                "Move the code shared by the modules to a new module, or remove one of the imports."
+            }),
+        )
+    }
+}
+
+/// Returns the path of `path` relative to `directory`, e.g. `../b.js` for `/src/b.js` relative to `/src/a`.
+fn relative_path(directory: &Path, path: &Path) -> PathBuf {
+    let mut directory = directory.components().peekable();
+    let mut path = path.components().peekable();
+    while directory.peek().is_some() && directory.peek() == path.peek() {
+        directory.next();
+        path.next();
+    }
+    let mut relative: PathBuf = directory.map(|_| Component::ParentDir).collect();
+    relative.extend(path);
+    relative
+}
@@ -0,0 +1,347 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsModuleItem, AnyJsRoot, AnyJsStatement, JsFileSource, JsStatementList,
+    JsSyntaxKind, ModuleKind,
+};
+use biome_rowan::{AstNode, Direction, TextRange, TriviaPieceKind};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow commented-out code.
+    ///
+    /// Commented-out code is usually forgotten after a change:
+    /// it quickly becomes outdated, and it distracts the readers from the comments that explain the code.
+    /// The version control system already keeps the code that was removed.
+    ///
+    /// The rule reports the comments, or the consecutive line comments, that parse as JavaScript or TypeScript code,
+    /// and that contain at least a statement that is unlikely to be prose, such as a declaration, a call, or an assignment.
+    /// A comment that only contains an identifier or a literal, such as `// TODO`, isn't reported.
+    /// The JSDoc comments, which often contain examples, and the triple-slash directives of TypeScript are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function sum(values) {
+    ///     // console.log(values);
+    ///     return values.reduce((total, value) => total + value, 0);
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /*
+    /// if (values.length === 0) {
+    ///     return 0;
+    /// }
+    /// */
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// // Returns the sum of the values.
+    /// function sum(values) {
+    ///     // TODO
+    ///     return values.reduce((total, value) => total + value, 0);
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// /**
+    ///  * @example
+    ///  * sum([1, 2, 3]);
+    ///  */
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `minimumLines`
+    ///
+    /// The minimum number of lines of a reported comment, or of the consecutive line comments.
+    ///
+    /// Default: `1`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "minimumLines": 2
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options
+    /// // console.log(values);
+    /// ```
+    ///
+    /// ### `allowedMarkers`
+    ///
+    /// The comments that start with one of these markers are allowed,
+    /// such as the code examples of the documentation.
+    ///
+    /// Default: `[]`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "allowedMarkers": ["example:"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options
+    /// // example:
+    /// // const total = sum([1, 2, 3]);
+    /// ```
+    ///
+    pub NoCommentedOutCode {
+        version: "next",
+        name: "noCommentedOutCode",
+        language: "js",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noCommentedOutCode`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoCommentedOutCodeOptions {
+    /// The minimum number of lines of a reported comment, or of the consecutive line comments.
+    minimum_lines: u8,
+    /// The comments that start with one of these markers are allowed.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    allowed_markers: Box<[Box<str>]>,
+}
+
+impl Default for NoCommentedOutCodeOptions {
+    fn default() -> Self {
+        Self {
+            minimum_lines: 1,
+            allowed_markers: Box::default(),
+        }
+    }
+}
+
+impl Rule for NoCommentedOutCode {
+    type Query = Ast<AnyJsRoot>;
+    type State = TextRange;
+    type Signals = Box<[Self::State]>;
+    type Options = NoCommentedOutCodeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let source_type = ctx
+            .source_type::<JsFileSource>()
+            .with_module_kind(ModuleKind::Module);
+        comment_groups(ctx.query())
+            .into_iter()
+            .filter(|group| group.lines.len() >= usize::from(options.minimum_lines))
+            .filter(|group| {
+                let first_line = group.lines.first().map_or("", |line| line.trim_start());
+                !options
+                    .allowed_markers
+                    .iter()
+                    .any(|marker| first_line.starts_with(marker.as_ref()))
+            })
+            .filter(|group| is_code(&group.lines.join("\n"), source_type))
+            .map(|group| group.range)
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This comment contains commented-out code."
+                },
+            )
+            .note(markup! {
+                "Commented-out code quickly becomes outdated, and distracts from the comments that explain the code."
+            })
+            .note(markup! {
+                "Remove the comment. The version control system keeps the code that was removed."
+            }),
+        )
+    }
+}
+
+/// A comment, or consecutive line comments, without the comment markers.
+struct CommentGroup {
+    range: TextRange,
+    lines: Vec<String>,
+}
+
+/// Returns the comments of `root`, where the consecutive line comments on their own lines are grouped.
+fn comment_groups(root: &AnyJsRoot) -> Vec<CommentGroup> {
+    let mut groups = Vec::new();
+    // The group of line comments that can be extended by the next line comment
+    let mut current: Option<CommentGroup> = None;
+    let mut is_line_start = true;
+    let mut newlines = 0;
+    for token in root.syntax().descendants_tokens(Direction::Next) {
+        for piece in token.leading_trivia().pieces() {
+            match piece.kind() {
+                TriviaPieceKind::Newline => {
+                    is_line_start = true;
+                    newlines += 1;
+                }
+                TriviaPieceKind::Whitespace => {}
+                TriviaPieceKind::SingleLineComment | TriviaPieceKind::MultiLineComment => {
+                    let text = piece.text();
+                    let range = piece.text_range();
+                    if let Some(line) = text.strip_prefix("//") {
+                        let is_consecutive = is_line_start && newlines == 1;
+                        match current.as_mut() {
+                            Some(group) if is_consecutive => {
+                                group.range = group.range.cover(range);
+                                group.lines.push(line.to_string());
+                            }
+                            _ => {
+                                groups.extend(current.take());
+                                let group = CommentGroup {
+                                    range,
+                                    lines: vec![line.to_string()],
+                                };
+                                if is_line_start {
+                                    current = Some(group);
+                                } else {
+                                    groups.push(group);
+                                }
+                            }
+                        }
+                    } else {
+                        groups.extend(current.take());
+                        groups.push(CommentGroup {
+                            range,
+                            lines: block_comment_lines(text),
+                        });
+                    }
+                    is_line_start = false;
+                    newlines = 0;
+                }
+                TriviaPieceKind::Skipped => {
+                    groups.extend(current.take());
+                    is_line_start = false;
+                }
+            }
+        }
+        groups.extend(current.take());
+        for piece in token.trailing_trivia().pieces() {
+            if piece.is_comments() {
+                let text = piece.text();
+                let lines = match text.strip_prefix("//") {
+                    Some(line) => vec![line.to_string()],
+                    None => block_comment_lines(text),
+                };
+                groups.push(CommentGroup {
+                    range: piece.text_range(),
+                    lines,
+                });
+            }
+        }
+        is_line_start = false;
+        newlines = 0;
+    }
+    groups.retain(|group| !group.lines.is_empty());
+    groups
+}
+
+/// Returns the lines of the block comment `text`, without the comment markers,
+/// or no line for the comments that are documentation.
+fn block_comment_lines(text: &str) -> Vec<String> {
+    // JSDoc comments and triple-slash directives
+    if text.starts_with("/**") || text.starts_with("///") {
+        return Vec::new();
+    }
+    let text = text
+        .strip_prefix("/*")
+        .and_then(|text| text.strip_suffix("*/"))
+        .unwrap_or_default();
+    text.lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            trimmed
+                .strip_prefix('*')
+                .map_or_else(|| line.to_string(), str::to_string)
+        })
+        .collect()
+}
+
+/// Returns `true` if `text` parses as code that is unlikely to be prose.
+fn is_code(text: &str, source_type: JsFileSource) -> bool {
+    if text.trim_start().starts_with('/') {
+        // Triple-slash directives, and comments that were commented out
+        return false;
+    }
+    let module = parse(text, source_type, JsParserOptions::default());
+    if !module.has_errors() {
+        let AnyJsRoot::JsModule(module) = module.tree() else {
+            return false;
+        };
+        return module.items().into_iter().any(|item| match item {
+            AnyJsModuleItem::AnyJsStatement(statement) => is_code_statement(&statement),
+            AnyJsModuleItem::JsExport(_) | AnyJsModuleItem::JsImport(_) => true,
+        });
+    }
+    // The statements that are only valid in a function, such as `return`
+    let function = parse(
+        &format!("async function* f() {{\n{text}\n}}"),
+        source_type,
+        JsParserOptions::default(),
+    );
+    !function.has_errors()
+        && function
+            .syntax()
+            .descendants()
+            .find(|node| node.kind() == JsSyntaxKind::JS_FUNCTION_BODY)
+            .and_then(|body| body.children().find_map(JsStatementList::cast))
+            .is_some_and(|statements| {
+                statements
+                    .into_iter()
+                    .any(|statement| is_code_statement(&statement))
+            })
+}
+
+/// Returns `true` if `statement` is unlikely to be a parsed sentence.
+fn is_code_statement(statement: &AnyJsStatement) -> bool {
+    match statement {
+        AnyJsStatement::JsExpressionStatement(statement) => statement
+            .expression()
+            .is_ok_and(|expression| is_code_expression(&expression)),
+        AnyJsStatement::JsLabeledStatement(statement) => {
+            statement.body().is_ok_and(|body| is_code_statement(&body))
+        }
+        AnyJsStatement::JsBlockStatement(block) => block
+            .statements()
+            .into_iter()
+            .any(|statement| is_code_statement(&statement)),
+        AnyJsStatement::JsEmptyStatement(_) | AnyJsStatement::JsBogusStatement(_) => false,
+        _ => true,
+    }
+}
+
+/// Returns `true` if `expression` contains a call, an assignment, or a function,
+/// unlike the expressions made of words and operators, such as `TODO - fix`.
+fn is_code_expression(expression: &AnyJsExpression) -> bool {
+    expression.syntax().descendants().any(|node| {
+        matches!(
+            node.kind(),
+            JsSyntaxKind::JS_CALL_EXPRESSION
+                | JsSyntaxKind::JS_NEW_EXPRESSION
+                | JsSyntaxKind::JS_IMPORT_CALL_EXPRESSION
+                | JsSyntaxKind::JS_ASSIGNMENT_EXPRESSION
+                | JsSyntaxKind::JS_PRE_UPDATE_EXPRESSION
+                | JsSyntaxKind::JS_POST_UPDATE_EXPRESSION
+                | JsSyntaxKind::JS_AWAIT_EXPRESSION
+                | JsSyntaxKind::JS_YIELD_EXPRESSION
+                | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+                | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+        )
+    })
+}
@@ -0,0 +1,282 @@
+use crate::utils::test::is_test_call;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    JsCallExpression, JsCaseClause, JsCatchClause, JsConditionalExpression, JsDefaultClause,
+    JsDoWhileStatement, JsElseClause, JsForInStatement, JsForOfStatement, JsForStatement,
+    JsIfStatement, JsLogicalExpression, JsSyntaxNode, JsTryFinallyStatement, JsTryStatement,
+    JsWhileStatement,
+};
+use biome_rowan::{declare_node_union, AstNode, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow calling `expect` conditionally.
+    ///
+    /// An assertion inside a condition, a loop, or a `try`/`catch` block only runs when the test
+    /// takes that path. When it doesn't, the test passes without checking anything.
+    /// Tests should be written so that all their assertions always run.
+    ///
+    /// The rule reports the `expect()` calls that are inside a conditional statement or expression,
+    /// a loop, a `switch` case, or a `try`/`catch` block of the callback of a test.
+    /// The `finally` blocks always run, and aren't reported.
+    ///
+    /// The callbacks of the calls whose callee starts with one of the test function names are tests,
+    /// for example `it()`, `it.only()`, and `test.each([...])()`.
+    /// The `expect()` calls outside of a test are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// it("parses the date", () => {
+    ///     if (process.platform === "win32") {
+    ///         expect(parse("2024-01-01")).toBeDefined();
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// test("rejects the empty names", async () => {
+    ///     try {
+    ///         await createUser("");
+    ///     } catch (error) {
+    ///         expect(error).toBeInstanceOf(ValidationError);
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// it("trims the names", () => {
+    ///     for (const name of names) {
+    ///         expect(trim(name)).toBe(name.trim());
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// it("parses the date", () => {
+    ///     expect(parse("2024-01-01")).toBeDefined();
+    /// });
+    /// ```
+    ///
+    /// ```js
+    /// test("rejects the empty names", async () => {
+    ///     await expect(createUser("")).rejects.toThrow(ValidationError);
+    /// });
+    /// ```
+    ///
+    /// ```js
+    /// it.each(names)("trims %s", (name) => {
+    ///     expect(trim(name)).toBe(name.trim());
+    /// });
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `testFunctionNames`
+    ///
+    /// The names of the functions that declare a test.
+    /// A call is a test when the first name of its callee is one of these names.
+    ///
+    /// Default: `["it", "test"]`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "testFunctionNames": ["it", "test", "scenario"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// scenario("logs in", async () => {
+    ///     if (await hasSession()) {
+    ///         expect(page.url()).toContain("/home");
+    ///     }
+    /// });
+    /// ```
+    ///
+    pub NoConditionalExpect {
+        version: "next",
+        name: "noConditionalExpect",
+        language: "js",
+        sources: &[RuleSource::EslintJest("no-conditional-expect")],
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noConditionalExpect`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoConditionalExpectOptions {
+    /// The names of the functions that declare a test.
+    test_function_names: Box<[Box<str>]>,
+}
+
+impl Default for NoConditionalExpectOptions {
+    fn default() -> Self {
+        Self {
+            test_function_names: Box::new(["it".into(), "test".into()]),
+        }
+    }
+}
+
+declare_node_union! {
+    /// A construct whose body doesn't always run.
+    pub AnyConditionalConstruct =
+        JsIfStatement
+        | JsElseClause
+        | JsConditionalExpression
+        | JsLogicalExpression
+        | JsCaseClause
+        | JsDefaultClause
+        | JsTryStatement
+        | JsTryFinallyStatement
+        | JsCatchClause
+        | JsForStatement
+        | JsForInStatement
+        | JsForOfStatement
+        | JsWhileStatement
+        | JsDoWhileStatement
+}
+
+impl AnyConditionalConstruct {
+    /// Returns `true` if `child`, a direct child of the construct, doesn't always run.
+    fn is_conditional_child(&self, child: &JsSyntaxNode) -> bool {
+        match self {
+            Self::JsIfStatement(node) => node.consequent().is_ok_and(|n| n.syntax() == child),
+            Self::JsElseClause(_) | Self::JsCaseClause(_) | Self::JsDefaultClause(_) => true,
+            Self::JsConditionalExpression(node) => {
+                node.consequent().is_ok_and(|n| n.syntax() == child)
+                    || node.alternate().is_ok_and(|n| n.syntax() == child)
+            }
+            Self::JsLogicalExpression(node) => node.right().is_ok_and(|n| n.syntax() == child),
+            Self::JsTryStatement(node) => node.body().is_ok_and(|n| n.syntax() == child),
+            Self::JsTryFinallyStatement(node) => node.body().is_ok_and(|n| n.syntax() == child),
+            Self::JsCatchClause(node) => node.body().is_ok_and(|n| n.syntax() == child),
+            Self::JsForStatement(node) => node.body().is_ok_and(|n| n.syntax() == child),
+            Self::JsForInStatement(node) => node.body().is_ok_and(|n| n.syntax() == child),
+            Self::JsForOfStatement(node) => node.body().is_ok_and(|n| n.syntax() == child),
+            Self::JsWhileStatement(node) => node.body().is_ok_and(|n| n.syntax() == child),
+            Self::JsDoWhileStatement(node) => node.body().is_ok_and(|n| n.syntax() == child),
+        }
+    }
+
+    /// Returns the range of the keyword, or of the operator, that introduces the construct.
+    fn keyword_range(&self) -> Option<TextRange> {
+        let token = match self {
+            Self::JsIfStatement(node) => node.if_token().ok()?,
+            Self::JsElseClause(node) => node.else_token().ok()?,
+            Self::JsConditionalExpression(node) => node.question_mark_token().ok()?,
+            Self::JsLogicalExpression(node) => node.operator_token().ok()?,
+            Self::JsCaseClause(node) => node.case_token().ok()?,
+            Self::JsDefaultClause(node) => node.default_token().ok()?,
+            Self::JsTryStatement(node) => node.try_token().ok()?,
+            Self::JsTryFinallyStatement(node) => node.try_token().ok()?,
+            Self::JsCatchClause(node) => node.catch_token().ok()?,
+            Self::JsForStatement(node) => node.for_token().ok()?,
+            Self::JsForInStatement(node) => node.for_token().ok()?,
+            Self::JsForOfStatement(node) => node.for_token().ok()?,
+            Self::JsWhileStatement(node) => node.while_token().ok()?,
+            Self::JsDoWhileStatement(node) => node.do_token().ok()?,
+        };
+        Some(token.text_trimmed_range())
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            Self::JsIfStatement(_) | Self::JsElseClause(_) => "This condition",
+            Self::JsConditionalExpression(_) => "This conditional expression",
+            Self::JsLogicalExpression(_) => "This logical expression",
+            Self::JsCaseClause(_) | Self::JsDefaultClause(_) => "This switch case",
+            Self::JsTryStatement(_) | Self::JsTryFinallyStatement(_) => "This try block",
+            Self::JsCatchClause(_) => "This catch clause",
+            Self::JsForStatement(_)
+            | Self::JsForInStatement(_)
+            | Self::JsForOfStatement(_)
+            | Self::JsWhileStatement(_)
+            | Self::JsDoWhileStatement(_) => "This loop",
+        }
+    }
+}
+
+pub struct ConditionalExpect {
+    /// The range of the keyword or of the operator of the innermost conditional construct.
+    construct_range: TextRange,
+    construct_description: &'static str,
+}
+
+impl Rule for NoConditionalExpect {
+    type Query = Ast<JsCallExpression>;
+    type State = ConditionalExpect;
+    type Signals = Option<Self::State>;
+    type Options = NoConditionalExpectOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let callee = call.callee().ok()?.omit_parentheses();
+        let callee = callee.as_js_identifier_expression()?.name().ok()?;
+        if callee.value_token().ok()?.text_trimmed() != "expect" {
+            return None;
+        }
+
+        let test_function_names = &ctx.options().test_function_names;
+        let mut construct = None;
+        let mut child = call.syntax().clone();
+        for ancestor in call.syntax().ancestors().skip(1) {
+            if construct.is_none() {
+                construct = AnyConditionalConstruct::cast_ref(&ancestor)
+                    .filter(|node| node.is_conditional_child(&child));
+            }
+            if let Some(test_call) = JsCallExpression::cast_ref(&ancestor) {
+                // The assertion must be in a callback, not in the callee of the test.
+                if is_test_call(&test_call, test_function_names)
+                    && test_call
+                        .arguments()
+                        .is_ok_and(|arguments| arguments.syntax() == &child)
+                {
+                    let construct = construct?;
+                    return Some(ConditionalExpect {
+                        construct_range: construct.keyword_range()?,
+                        construct_description: construct.description(),
+                    });
+                }
+            }
+            child = ancestor;
+        }
+
+        None
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Avoid calling "<Emphasis>"expect"</Emphasis>" conditionally."
+                },
+            )
+            .detail(
+                state.construct_range,
+                markup! {
+                    {state.construct_description}" doesn't always run the assertion."
+                },
+            )
+            .note(markup! {
+                "When the assertion doesn't run, the test passes without checking anything."
+            })
+            .note(markup! {
+                "Rewrite the test so that the assertion always runs, for example by splitting it into several tests."
+            }),
+        )
+    }
+}
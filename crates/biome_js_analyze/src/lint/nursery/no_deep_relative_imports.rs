@@ -0,0 +1,182 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_glob::{CandidatePath, Glob};
+use biome_js_syntax::{inner_string_text, AnyJsImportLike, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow the relative imports that go through too many parent directories.
+    ///
+    /// A specifier such as `../../../shared/format` depends on the location of the importing module:
+    /// it's hard to read, and it breaks when the module is moved.
+    /// Import the module through a path alias, such as `@/shared/format`,
+    /// or through the entry point of its package instead.
+    ///
+    /// The rule reports the relative imports, the `require()` calls, and the dynamic imports
+    /// whose specifier starts with more `../` than the `maxDepth` option allows.
+    ///
+    /// The rule [useImportAliases](https://biomejs.dev/linter/rules/use-import-aliases/) also reports the deep relative imports,
+    /// but only when the `tsconfig.json` of the project declares a path alias for the imported module.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { formatDate } from "../../../shared/format.js";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const { render } = require("../../../test/utils.js");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { formatDate } from "@/shared/format.js";
+    /// import { Form } from "./form.js";
+    /// import { Layout } from "../../layout.js";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxDepth`
+    ///
+    /// The number of parent directories that a relative import can go through, with `../`, without being reported.
+    ///
+    /// Default: `2`
+    ///
+    /// ### `overrides`
+    ///
+    /// A list of maximum depths that apply to the modules matched by globs, such as the test files that import shared test utilities.
+    /// Every override has a list of globs, `include`, and a maximum depth, `maxDepth`.
+    /// The first override whose globs match the path of the importing module replaces the `maxDepth` option.
+    /// A glob prefixed with `!` is an exception.
+    /// See the [glob syntax](https://biomejs.dev/reference/configuration/#glob-syntax-reference).
+    ///
+    /// Default: `[]`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "maxDepth": 1,
+    ///         "overrides": [
+    ///             {
+    ///                 "include": ["**/*.test.js"],
+    ///                 "maxDepth": 3
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// import { Layout } from "../../layout.js";
+    /// ```
+    ///
+    pub NoDeepRelativeImports {
+        version: "next",
+        name: "noDeepRelativeImports",
+        language: "js",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noDeepRelativeImports`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoDeepRelativeImportsOptions {
+    /// The number of parent directories that a relative import can go through without being reported.
+    max_depth: u8,
+    /// The maximum depths that apply to the modules matched by globs, e.g. the test files.
+    overrides: Box<[ImportDepthOverride]>,
+}
+
+impl Default for NoDeepRelativeImportsOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 2,
+            overrides: [].into(),
+        }
+    }
+}
+
+/// A maximum depth that applies to the modules matched by globs.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct ImportDepthOverride {
+    /// Globs of the importing modules. The globs prefixed with `!` are exceptions.
+    include: Box<[Glob]>,
+    /// The number of parent directories that a relative import of these modules can go through.
+    max_depth: u8,
+}
+
+pub struct DeepRelativeImport {
+    /// The range of the specifier.
+    range: TextRange,
+    /// The number of parent directories that the import goes through.
+    depth: usize,
+    /// The maximum depth that applies to the importing module.
+    max_depth: u8,
+}
+
+impl Rule for NoDeepRelativeImports {
+    type Query = Ast<AnyJsImportLike>;
+    type State = DeepRelativeImport;
+    type Signals = Option<Self::State>;
+    type Options = NoDeepRelativeImportsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.is_in_ts_module_declaration() {
+            return None;
+        }
+        let module_name = node.module_name_token()?;
+        let depth = inner_string_text(&module_name)
+            .split('/')
+            .skip_while(|segment| *segment == ".")
+            .take_while(|segment| *segment == "..")
+            .count();
+        let options = ctx.options();
+        let file_path = CandidatePath::new(&ctx.file_path());
+        let max_depth = options
+            .overrides
+            .iter()
+            .find(|depth_override| file_path.matches_with_exceptions(&depth_override.include))
+            .map_or(options.max_depth, |depth_override| depth_override.max_depth);
+        if depth <= usize::from(max_depth) {
+            return None;
+        }
+        Some(DeepRelativeImport {
+            range: module_name.text_trimmed_range(),
+            depth,
+            max_depth,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let depth = state.depth;
+        let max_depth = state.max_depth;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This relative import has a depth of "{depth}", while the maximum is "{max_depth}"."
+                },
+            )
+            .note(markup! {
+                "The depth is the number of parent directories that the import goes through with "<Emphasis>"../"</Emphasis>"."
+            })
+            .note(markup! {
+                "Deep relative imports are hard to read, and break when the importing module is moved."
+            })
+            .note(markup! {
+                "Import the module through a path alias, or through the entry point of its package."
+            }),
+        )
+    }
+}
@@ -0,0 +1,289 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
+use biome_js_syntax::{
+    AnyJsClass, AnyJsExpression, AnyJsName, ClassMemberName, JsReferenceIdentifier,
+    JsStaticMemberExpression, JsSyntaxKind, JsSyntaxNode, JsxReferenceIdentifier,
+    TsEnumDeclaration,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, AstSeparatedList, TextRange};
+
+use crate::services::semantic::Semantic;
+use crate::utils::jsdoc::{documented_node, JsDoc};
+
+declare_lint_rule! {
+    /// Disallow the use of declarations marked as deprecated with the JSDoc tag `@deprecated`.
+    ///
+    /// A declaration documented with the [`@deprecated`](https://jsdoc.app/tags-deprecated) tag
+    /// shouldn't be used anymore, and is likely to be removed in a future version.
+    /// The diagnostic includes the message of the tag, that usually indicates the replacement.
+    ///
+    /// The rule checks the references to the functions, classes, variables, types, and enums of the file,
+    /// and the accesses to the members of the classes and enums of the file,
+    /// such as `this.member`, `Class.member`, or `Enum.Member`.
+    ///
+    /// Biome analyzes each file in isolation:
+    /// the rule doesn't report the uses of imported declarations, even if they're deprecated in their module.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /** @deprecated Use `fetchUser` instead. */
+    /// function getUser(id) {}
+    ///
+    /// getUser(1);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// class Cache {
+    ///     /**
+    ///      * @deprecated The cache is cleared automatically.
+    ///      */
+    ///     clear() {}
+    ///
+    ///     reset() {
+    ///         this.clear();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /** Fetches a user. */
+    /// function fetchUser(id) {}
+    ///
+    /// fetchUser(1);
+    /// ```
+    ///
+    pub NoDeprecatedApis {
+        version: "next",
+        name: "noDeprecatedApis",
+        language: "js",
+        sources: &[RuleSource::EslintTypeScript("no-deprecated")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyDeprecatedCandidate = JsReferenceIdentifier | JsxReferenceIdentifier | JsStaticMemberExpression
+}
+
+pub struct DeprecatedUse {
+    range: TextRange,
+    name: Box<str>,
+    /// The message of the `@deprecated` tag.
+    message: Box<str>,
+}
+
+impl Rule for NoDeprecatedApis {
+    type Query = Semantic<AnyDeprecatedCandidate>;
+    type State = DeprecatedUse;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        let (range, name, documented_node) = match ctx.query() {
+            AnyDeprecatedCandidate::JsReferenceIdentifier(reference) => {
+                if matches!(
+                    reference.syntax().parent()?.kind(),
+                    JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER
+                        | JsSyntaxKind::JS_EXPORT_NAMED_SPECIFIER
+                ) {
+                    // Re-exporting a deprecated declaration isn't a use.
+                    return None;
+                }
+                let declaration = model.binding(reference)?.tree().declaration()?;
+                let name = reference.value_token().ok()?;
+                (
+                    name.text_trimmed_range(),
+                    Box::<str>::from(name.text_trimmed()),
+                    documented_declaration(&declaration)?,
+                )
+            }
+            AnyDeprecatedCandidate::JsxReferenceIdentifier(reference) => {
+                let declaration = model.binding(reference)?.tree().declaration()?;
+                let name = reference.value_token().ok()?;
+                (
+                    name.text_trimmed_range(),
+                    Box::<str>::from(name.text_trimmed()),
+                    documented_declaration(&declaration)?,
+                )
+            }
+            AnyDeprecatedCandidate::JsStaticMemberExpression(member_expression) => {
+                let member = member_expression.member().ok()?;
+                let member_name = match &member {
+                    AnyJsName::JsName(name) => {
+                        ClassMemberName::Public(name.value_token().ok()?.token_text_trimmed())
+                    }
+                    AnyJsName::JsPrivateName(name) => {
+                        ClassMemberName::Private(name.value_token().ok()?.token_text_trimmed())
+                    }
+                    AnyJsName::JsMetavariable(_) => return None,
+                };
+                let object = member_expression.object().ok()?.omit_parentheses();
+                let documented_node = documented_member(model, &object, &member_name)?;
+                (
+                    member.range(),
+                    member.syntax().text_trimmed().to_string().into_boxed_str(),
+                    documented_node,
+                )
+            }
+        };
+        // A deprecated declaration can use itself.
+        if documented_node.text_range().contains_range(range) {
+            return None;
+        }
+        let message = deprecation_message(&documented_node)?;
+        Some(DeprecatedUse {
+            range,
+            name,
+            message,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.name.as_ref();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.range,
+            markup! {
+                <Emphasis>{name}</Emphasis>" is deprecated."
+            },
+        );
+        let message = state.message.as_ref();
+        let diagnostic = if message.is_empty() {
+            diagnostic.note(markup! {
+                "Its declaration is marked with the "<Emphasis>"@deprecated"</Emphasis>" tag."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "Its declaration is marked with the "<Emphasis>"@deprecated"</Emphasis>" tag: "{message}
+            })
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the node that holds the documentation comment of `declaration`.
+///
+/// The comment of a variable precedes its statement, and the comment of an exported declaration precedes `export`.
+fn documented_declaration(declaration: &AnyJsBindingDeclaration) -> Option<JsSyntaxNode> {
+    match declaration {
+        AnyJsBindingDeclaration::JsVariableDeclarator(_)
+        | AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassDeclaration(_)
+        | AnyJsBindingDeclaration::TsInterfaceDeclaration(_)
+        | AnyJsBindingDeclaration::TsTypeAliasDeclaration(_)
+        | AnyJsBindingDeclaration::TsEnumDeclaration(_)
+        | AnyJsBindingDeclaration::TsModuleDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionExportDefaultDeclaration(_) => {
+            Some(documented_node(declaration.syntax()))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the member `member_name` of the class or enum `object`, such as `this`, `Class`, or `Enum`.
+fn documented_member(
+    model: &SemanticModel,
+    object: &AnyJsExpression,
+    member_name: &ClassMemberName,
+) -> Option<JsSyntaxNode> {
+    let class = match object {
+        AnyJsExpression::JsThisExpression(this) => enclosing_class(this.syntax())?,
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let reference = identifier.name().ok()?;
+            match model.binding(&reference)?.tree().declaration()? {
+                AnyJsBindingDeclaration::JsClassDeclaration(class) => AnyJsClass::from(class),
+                AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(class) => {
+                    AnyJsClass::from(class)
+                }
+                AnyJsBindingDeclaration::TsEnumDeclaration(declaration) => {
+                    return documented_enum_member(&declaration, member_name);
+                }
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    class
+        .members()
+        .iter()
+        .find(|member| {
+            member
+                .name()
+                .ok()
+                .flatten()
+                .and_then(|name| name.name())
+                .is_some_and(|name| is_same_member_name(&name, member_name))
+        })
+        .map(AstNode::into_syntax)
+}
+
+fn documented_enum_member(
+    declaration: &TsEnumDeclaration,
+    member_name: &ClassMemberName,
+) -> Option<JsSyntaxNode> {
+    let ClassMemberName::Public(member_name) = member_name else {
+        return None;
+    };
+    declaration
+        .members()
+        .iter()
+        .filter_map(|member| member.ok())
+        .find(|member| {
+            member
+                .name()
+                .ok()
+                .and_then(|name| name.name())
+                .is_some_and(|name| name.text() == member_name.text())
+        })
+        .map(AstNode::into_syntax)
+}
+
+/// Returns the class of `this`, if `this` isn't in a function that has its own `this`.
+fn enclosing_class(this: &JsSyntaxNode) -> Option<AnyJsClass> {
+    for ancestor in this.ancestors() {
+        match ancestor.kind() {
+            JsSyntaxKind::JS_FUNCTION_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+            | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER => return None,
+            _ => {
+                if let Some(class) = AnyJsClass::cast(ancestor) {
+                    return Some(class);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn is_same_member_name(left: &ClassMemberName, right: &ClassMemberName) -> bool {
+    match (left, right) {
+        (ClassMemberName::Private(left), ClassMemberName::Private(right))
+        | (ClassMemberName::Public(left), ClassMemberName::Public(right)) => left == right,
+        _ => false,
+    }
+}
+
+/// Returns the message of the `@deprecated` tag of the JSDoc comment that precedes `node`.
+/// The message is empty if the tag has no message.
+fn deprecation_message(node: &JsSyntaxNode) -> Option<Box<str>> {
+    let doc = JsDoc::for_node(node)?;
+    let tag = doc.find_tag(&["deprecated"])?;
+    Some(tag.description().into())
+}
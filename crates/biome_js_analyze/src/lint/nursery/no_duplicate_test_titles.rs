@@ -0,0 +1,252 @@
+use crate::utils::test::is_test_call;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, AddVisitor, Phases, QueryMatch, Queryable, Rule,
+    RuleDiagnostic, RuleSource, RuleSourceKind, ServiceBag, Visitor, VisitorContext,
+};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsExpression, JsCallExpression, JsLanguage, TextRange};
+use biome_rowan::{AstNode, Language, SyntaxNode, WalkEvent};
+use rustc_hash::FxHashMap;
+
+declare_lint_rule! {
+    /// Disallow tests with the same title in the same `describe` block.
+    ///
+    /// The test runners identify a test by its title and the titles of the `describe` blocks
+    /// that contain it. When two tests of the same block have the same title, the reports
+    /// don't tell them apart, and a filter such as `--testNamePattern` selects both.
+    ///
+    /// The rule compares the titles of the `it()` and `test()` calls, including `it.only()`
+    /// and `test.skip()`, that belong to the same `describe()` block or to the top level of the file.
+    /// The tests of the nested `describe()` blocks are compared separately.
+    /// Only the titles that are string literals, or template literals without substitutions, are compared.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// describe("parser", () => {
+    ///     it("parses the numbers", () => {});
+    ///     it("parses the numbers", () => {});
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// test("logs in", () => {});
+    /// test.skip("logs in", () => {});
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// describe("parser", () => {
+    ///     it("parses the numbers", () => {});
+    ///     it("parses the strings", () => {});
+    /// });
+    /// ```
+    ///
+    /// ```js
+    /// describe("parser", () => {
+    ///     it("handles the errors", () => {});
+    /// });
+    /// describe("printer", () => {
+    ///     it("handles the errors", () => {});
+    /// });
+    /// ```
+    ///
+    pub NoDuplicateTestTitles {
+        version: "next",
+        name: "noDuplicateTestTitles",
+        language: "js",
+        sources: &[RuleSource::EslintJest("no-identical-title")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+impl Rule for NoDuplicateTestTitles {
+    type Query = DuplicateTestTitle;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(_: &RuleContext<Self>) -> Self::Signals {
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let duplicate = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                duplicate.title_range,
+                markup! {
+                    "Another test of the same block already has this title."
+                },
+            )
+            .detail(
+                duplicate.first_title_range,
+                markup! {
+                    "The first test with this title is here."
+                },
+            )
+            .note(markup! {
+                "The reports of the test runner can't tell these tests apart, and a filter on the test names selects both."
+            })
+            .note(markup! {
+                "Give a distinct title to each test of the block."
+            }),
+        )
+    }
+}
+
+enum TestBlock {
+    Describe,
+    Test,
+}
+
+/// Returns the kind of block that `call` declares, if any.
+fn test_block(call: &JsCallExpression) -> Option<TestBlock> {
+    let callee = call.callee().ok()?;
+    // `describe.each([...])()` declares a block with its own tests.
+    if let AnyJsExpression::JsCallExpression(each_call) = &callee {
+        let each_callee = each_call.callee().ok()?;
+        return matches!(
+            each_callee.text().as_str(),
+            "describe.each" | "describe.only.each" | "describe.skip.each"
+        )
+        .then_some(TestBlock::Describe);
+    }
+    if !is_test_call(call, &["describe", "it", "test", "Deno"]) {
+        return None;
+    }
+    let object = callee.get_callee_object_name()?;
+    let member = callee.get_callee_member_name()?;
+    match (object.text_trimmed(), member.text_trimmed()) {
+        ("describe", _) | ("test", "describe") => Some(TestBlock::Describe),
+        ("test", "step") => None,
+        ("it" | "test" | "Deno", _) => Some(TestBlock::Test),
+        _ => None,
+    }
+}
+
+/// Returns the text of the title of `call`, and its range.
+///
+/// Returns [None] if the title isn't a string literal or a template literal without substitutions.
+fn test_title(call: &JsCallExpression) -> Option<(String, TextRange)> {
+    let [Some(title)] = call.arguments().ok()?.get_arguments_by_index([0]) else {
+        return None;
+    };
+    let title = title.as_any_js_expression()?;
+    let text = match title.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(literal) => literal
+            .as_js_string_literal_expression()?
+            .inner_string_text()
+            .ok()?
+            .text()
+            .to_string(),
+        AnyJsExpression::JsTemplateExpression(template) if template.tag().is_none() => {
+            let mut text = String::new();
+            for element in template.elements() {
+                let chunk = element.as_js_template_chunk_element()?;
+                text.push_str(chunk.template_chunk_token().ok()?.text_trimmed());
+            }
+            text
+        }
+        _ => return None,
+    };
+    Some((text, title.range()))
+}
+
+struct DuplicateTestTitlesVisitor {
+    /// The titles of the tests of each `describe` block that contains the visited node,
+    /// starting with the tests of the top level of the file.
+    stack: Vec<FxHashMap<String, TextRange>>,
+}
+
+impl Default for DuplicateTestTitlesVisitor {
+    fn default() -> Self {
+        Self {
+            stack: vec![FxHashMap::default()],
+        }
+    }
+}
+
+impl Visitor for DuplicateTestTitlesVisitor {
+    type Language = JsLanguage;
+
+    fn visit(
+        &mut self,
+        event: &WalkEvent<SyntaxNode<Self::Language>>,
+        mut ctx: VisitorContext<Self::Language>,
+    ) {
+        match event {
+            WalkEvent::Enter(node) => {
+                let Some(node) = JsCallExpression::cast_ref(node) else {
+                    return;
+                };
+                match test_block(&node) {
+                    Some(TestBlock::Describe) => {
+                        self.stack.push(FxHashMap::default());
+                    }
+                    Some(TestBlock::Test) => {
+                        let Some((title, title_range)) = test_title(&node) else {
+                            return;
+                        };
+                        let Some(titles) = self.stack.last_mut() else {
+                            return;
+                        };
+                        if let Some(first_title_range) = titles.get(&title) {
+                            ctx.match_query(DuplicateTestTitle {
+                                title_range,
+                                first_title_range: *first_title_range,
+                            });
+                        } else {
+                            titles.insert(title, title_range);
+                        }
+                    }
+                    None => {}
+                }
+            }
+            WalkEvent::Leave(node) => {
+                if let Some(node) = JsCallExpression::cast_ref(node) {
+                    if matches!(test_block(&node), Some(TestBlock::Describe)) {
+                        self.stack.pop();
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct DuplicateTestTitle {
+    /// The range of the title of the duplicate test.
+    title_range: TextRange,
+    /// The range of the title of the first test with the same title.
+    first_title_range: TextRange,
+}
+
+impl QueryMatch for DuplicateTestTitle {
+    fn text_range(&self) -> TextRange {
+        self.title_range
+    }
+}
+
+impl Queryable for DuplicateTestTitle {
+    type Input = Self;
+    type Language = JsLanguage;
+    type Output = Self;
+    type Services = ();
+
+    fn build_visitor(
+        analyzer: &mut impl AddVisitor<Self::Language>,
+        _: &<Self::Language as Language>::Root,
+    ) {
+        analyzer.add_visitor(Phases::Syntax, DuplicateTestTitlesVisitor::default);
+    }
+
+    fn unwrap_match(_: &ServiceBag, query: &Self::Input) -> Self::Output {
+        query.clone()
+    }
+}
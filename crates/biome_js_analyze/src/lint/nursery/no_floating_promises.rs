@@ -0,0 +1,209 @@
+use crate::services::control_flow::AnyJsControlFlowRoot;
+use crate::services::semantic::Semantic;
+use crate::utils::promise::is_promise;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyFunctionLike, AnyJsExpression, JsCallExpression, JsExpressionStatement, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, TriviaPieceKind};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Require the promises created in a statement to be awaited or handled.
+    ///
+    /// A promise that is neither awaited, returned, nor given a rejection handler is a _floating_ promise.
+    /// The code after it doesn't wait for the promise to settle, and its rejection is unhandled:
+    /// the error is lost in the browsers, and terminates the process in Node.js.
+    ///
+    /// The rule reports the expression statements that create a promise without handling it.
+    /// Biome doesn't infer the types of the expressions, so the rule only detects the following promises:
+    ///
+    /// - the calls to the `async` functions, and to the functions whose return type is annotated as `Promise`,
+    ///   that are declared in the same file;
+    /// - the calls to the static methods of `Promise`, such as `Promise.all()`, and `new Promise()`;
+    /// - the calls to `then()` and `finally()` on one of these promises;
+    /// - the calls to the functions configured in the `functions` option.
+    ///
+    /// A promise is handled when it's awaited, returned, or when it's given a rejection handler
+    /// with `catch()`, or with the second argument of `then()`.
+    /// Use the `void` operator to explicitly ignore a promise.
+    ///
+    /// The rule provides an unsafe fix that adds `await` in the `async` functions, and `void` elsewhere.
+    /// Adding `await` changes when the code that follows runs, and makes the rejection of the promise throw.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function save() {}
+    ///
+    /// save();
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function load() {
+    ///   Promise.all([fetchUser(), fetchPosts()]);
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// declare function send(): Promise<void>;
+    ///
+    /// send().then(() => console.log("sent"));
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function save() {}
+    ///
+    /// async function run() {
+    ///   await save();
+    ///   save().catch((error) => console.error(error));
+    ///   save().then(() => console.log("saved"), (error) => console.error(error));
+    ///   void save();
+    ///   return save();
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `functions`
+    ///
+    /// The names of the functions that return a promise, in addition to the ones that the rule detects.
+    /// A name is compared to the callee as it's written, such as `fetch` or `api.get`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "functions": ["fetch", "api.get"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// fetch("/ping");
+    /// ```
+    ///
+    pub NoFloatingPromises {
+        version: "next",
+        name: "noFloatingPromises",
+        language: "js",
+        sources: &[RuleSource::EslintTypeScript("no-floating-promises")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `noFloatingPromises`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoFloatingPromisesOptions {
+    /// The names of the functions that return a promise, such as `fetch` or `api.get`.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub functions: Box<[Box<str>]>,
+}
+
+impl Rule for NoFloatingPromises {
+    type Query = Semantic<JsExpressionStatement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = Box<NoFloatingPromisesOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let expression = ctx.query().expression().ok()?.omit_parentheses();
+        if let AnyJsExpression::JsCallExpression(call) = &expression {
+            if is_handled(call) {
+                return None;
+            }
+        }
+        is_promise(&expression, ctx.model(), &ctx.options().functions).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let expression = ctx.query().expression().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                expression.range(),
+                markup! {
+                    "This promise is neither awaited nor handled."
+                },
+            )
+            .note(markup! {
+                "The code that follows doesn't wait for the promise to settle, and its rejection is unhandled."
+            })
+            .note(markup! {
+                "Await the promise, add a rejection handler with "<Emphasis>"catch()"</Emphasis>", or use the "<Emphasis>"void"</Emphasis>" operator to explicitly ignore it."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<JsRuleAction> {
+        let expression = ctx.query().expression().ok()?;
+        let is_in_async_function = expression
+            .syntax()
+            .ancestors()
+            .find(|ancestor| AnyJsControlFlowRoot::can_cast(ancestor.kind()))
+            .and_then(AnyFunctionLike::cast)
+            .is_some_and(|function| function.is_async());
+        // The leading trivia of the statement moves to the operator
+        let argument = expression.clone().with_leading_trivia_pieces([])?;
+        let operator = if is_in_async_function {
+            T![await]
+        } else {
+            T![void]
+        };
+        let operator_token =
+            make::token(operator).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+        let new_expression: AnyJsExpression = if is_in_async_function {
+            make::js_await_expression(operator_token, argument).into()
+        } else {
+            make::js_unary_expression(operator_token, argument).into()
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(expression, new_expression);
+        let message = if is_in_async_function {
+            markup! { "Await the promise." }.to_owned()
+        } else {
+            markup! { "Explicitly ignore the promise with the "<Emphasis>"void"</Emphasis>" operator." }
+                .to_owned()
+        };
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            message,
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `call` gives a rejection handler to the promise:
+/// `promise.catch(handler)` or `promise.then(handler, handler)`.
+fn is_handled(call: &JsCallExpression) -> bool {
+    let Some(method) = call
+        .callee()
+        .ok()
+        .and_then(|callee| callee.get_callee_member_name())
+    else {
+        return false;
+    };
+    let argument_count = call
+        .arguments()
+        .map_or(0, |arguments| arguments.args().len());
+    match method.text_trimmed() {
+        "catch" => argument_count >= 1,
+        "then" => argument_count >= 2,
+        _ => false,
+    }
+}
@@ -0,0 +1,342 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, AnyJsTemplateElement, AnyJsxAttributeValue,
+    JsBinaryOperator, JsxAttribute, JsxExpressionChild, JsxText, TextRange, TextSize,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow the strings displayed to the user that aren't passed to a translation function.
+    ///
+    /// An application that supports several languages displays its text through a translation function,
+    /// such as `t("welcome")`, which returns the text in the language of the user.
+    /// A string written directly in the JSX is always displayed in the same language.
+    /// This rule finds these strings, to help auditing the internationalization (i18n) of an application.
+    ///
+    /// The rule reports the following strings:
+    ///
+    /// - the text of the JSX elements, such as `<p>Welcome</p>`;
+    /// - the values of the attributes listed in the `attributes` option, such as `<img alt="Logo" />`;
+    /// - the string literals and the template literals rendered in a JSX expression, such as `{isOpen ? "Close" : "Open"}`.
+    ///
+    /// The strings passed as arguments to a function are also reported, since the function can return them,
+    /// unless this function is a translation function listed in the `translationFunctions` option.
+    /// The strings without any letter, such as `"-"` or `"42"`, and the HTML entities, such as `&nbsp;`, are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <h1>Welcome back!</h1>;
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <input placeholder="Search" />;
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <button>{isOpen ? "Close" : "Open"}</button>;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <h1>{t("welcome")}</h1>;
+    /// ```
+    ///
+    /// ```jsx
+    /// <input placeholder={t("search")} className="search-input" />;
+    /// ```
+    ///
+    /// ```jsx
+    /// <span>{count}&nbsp;/&nbsp;{total}</span>;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `attributes`
+    ///
+    /// The attributes whose value is displayed to the user.
+    /// Setting this option replaces the default list:
+    /// `alt`, `aria-description`, `aria-label`, `aria-placeholder`, `aria-roledescription`, `aria-valuetext`,
+    /// `label`, `placeholder`, and `title`.
+    ///
+    /// ### `translationFunctions`
+    ///
+    /// The functions that translate a string, such as `t` or `i18n.t`.
+    /// A function matches when it's called with the same name, e.g. `i18n.t` doesn't match `t`.
+    /// Defaults to `["t"]`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "attributes": ["title", "tooltip"],
+    ///         "translationFunctions": ["i18n.t"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```jsx,use_options,expect_diagnostic
+    /// <Button tooltip="Save the document">{i18n.t("save")}</Button>;
+    /// ```
+    ///
+    pub NoHardcodedUserFacingStrings {
+        version: "next",
+        name: "noHardcodedUserFacingStrings",
+        language: "jsx",
+        sources: &[RuleSource::EslintReact("jsx-no-literals")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noHardcodedUserFacingStrings`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoHardcodedUserFacingStringsOptions {
+    /// The attributes whose value is displayed to the user, e.g. `title`.
+    attributes: Box<[Box<str>]>,
+    /// The functions that translate a string, e.g. `t`.
+    translation_functions: Box<[Box<str>]>,
+}
+
+impl Default for NoHardcodedUserFacingStringsOptions {
+    fn default() -> Self {
+        Self {
+            attributes: [
+                "alt",
+                "aria-description",
+                "aria-label",
+                "aria-placeholder",
+                "aria-roledescription",
+                "aria-valuetext",
+                "label",
+                "placeholder",
+                "title",
+            ]
+            .into_iter()
+            .map(Box::from)
+            .collect(),
+            translation_functions: [Box::from("t")].into(),
+        }
+    }
+}
+
+declare_node_union! {
+    pub AnyJsxUserFacingString = JsxText | JsxAttribute | JsxExpressionChild
+}
+
+impl Rule for NoHardcodedUserFacingStrings {
+    type Query = Ast<AnyJsxUserFacingString>;
+    /// The range of a string that isn't translated.
+    type State = TextRange;
+    type Signals = Box<[Self::State]>;
+    type Options = NoHardcodedUserFacingStringsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let mut ranges = Vec::new();
+        match ctx.query() {
+            AnyJsxUserFacingString::JsxText(text) => {
+                if let Ok(token) = text.value_token() {
+                    ranges.extend(text_range_without_whitespace(
+                        token.text_trimmed(),
+                        token.text_trimmed_range(),
+                    ));
+                }
+            }
+            AnyJsxUserFacingString::JsxAttribute(attribute) => {
+                let is_displayed = attribute.name_value_token().is_ok_and(|name| {
+                    options
+                        .attributes
+                        .iter()
+                        .any(|attribute| attribute.as_ref() == name.text_trimmed())
+                });
+                if !is_displayed {
+                    return ranges.into();
+                }
+                match attribute
+                    .initializer()
+                    .and_then(|initializer| initializer.value().ok())
+                {
+                    Some(AnyJsxAttributeValue::JsxString(string)) => {
+                        if string
+                            .inner_string_text()
+                            .is_ok_and(|text| has_words(text.text()))
+                        {
+                            ranges.push(string.range());
+                        }
+                    }
+                    Some(AnyJsxAttributeValue::JsxExpressionAttributeValue(value)) => {
+                        if let Ok(expression) = value.expression() {
+                            collect_rendered_strings(
+                                &expression,
+                                &options.translation_functions,
+                                &mut ranges,
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            AnyJsxUserFacingString::JsxExpressionChild(child) => {
+                if let Some(expression) = child.expression() {
+                    collect_rendered_strings(
+                        &expression,
+                        &options.translation_functions,
+                        &mut ranges,
+                    );
+                }
+            }
+        }
+        ranges.into()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This string is displayed to the user, but isn't translated."
+                },
+            )
+            .note(markup! {
+                "Pass the string to a translation function listed in the "<Emphasis>"translationFunctions"</Emphasis>" option, so it can be displayed in the language of the user."
+            }),
+        )
+    }
+}
+
+/// Adds to `ranges` the strings of `expression` that can be rendered, and that aren't translated.
+fn collect_rendered_strings(
+    expression: &AnyJsExpression,
+    translation_functions: &[Box<str>],
+    ranges: &mut Vec<TextRange>,
+) {
+    let mut collect = |expression: &AnyJsExpression| {
+        collect_rendered_strings(expression, translation_functions, ranges)
+    };
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsStringLiteralExpression(string),
+        ) => {
+            if string
+                .inner_string_text()
+                .is_ok_and(|text| has_words(text.text()))
+            {
+                ranges.push(string.range());
+            }
+        }
+        AnyJsExpression::JsTemplateExpression(template) => {
+            if template.tag().is_some() {
+                return;
+            }
+            let mut has_text = false;
+            for element in template.elements() {
+                match element {
+                    AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                        has_text |= chunk
+                            .template_chunk_token()
+                            .is_ok_and(|chunk| has_words(chunk.text_trimmed()));
+                    }
+                    AnyJsTemplateElement::JsTemplateElement(element) => {
+                        if let Ok(expression) = element.expression() {
+                            collect(&expression);
+                        }
+                    }
+                }
+            }
+            if has_text {
+                ranges.push(template.range());
+            }
+        }
+        AnyJsExpression::JsConditionalExpression(conditional) => {
+            if let Ok(consequent) = conditional.consequent() {
+                collect(&consequent);
+            }
+            if let Ok(alternate) = conditional.alternate() {
+                collect(&alternate);
+            }
+        }
+        AnyJsExpression::JsLogicalExpression(logical) => {
+            if let Ok(left) = logical.left() {
+                collect(&left);
+            }
+            if let Ok(right) = logical.right() {
+                collect(&right);
+            }
+        }
+        AnyJsExpression::JsBinaryExpression(binary) => {
+            if !matches!(binary.operator(), Ok(JsBinaryOperator::Plus)) {
+                return;
+            }
+            if let Ok(left) = binary.left() {
+                collect(&left);
+            }
+            if let Ok(right) = binary.right() {
+                collect(&right);
+            }
+        }
+        AnyJsExpression::JsCallExpression(call) => {
+            let is_translated = call.callee().is_ok_and(|callee| {
+                let callee = callee.omit_parentheses();
+                translation_functions
+                    .iter()
+                    .any(|function| callee.syntax().text_trimmed() == function.as_ref())
+            });
+            if is_translated {
+                return;
+            }
+            let Ok(arguments) = call.arguments() else {
+                return;
+            };
+            for argument in arguments.args().iter().flatten() {
+                if let Some(argument) = argument.as_any_js_expression() {
+                    collect(argument);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the range of `text` without its leading and trailing whitespace,
+/// or `None` if `text` doesn't contain any word.
+fn text_range_without_whitespace(text: &str, range: TextRange) -> Option<TextRange> {
+    if !has_words(text) {
+        return None;
+    }
+    let trimmed_start = text.len() - text.trim_start().len();
+    let trimmed_end = text.len() - text.trim_end().len();
+    Some(TextRange::new(
+        range.start() + TextSize::from(trimmed_start as u32),
+        range.end() - TextSize::from(trimmed_end as u32),
+    ))
+}
+
+/// Returns `true` if `text` contains a letter outside of the HTML entities, such as `&nbsp;`.
+fn has_words(text: &str) -> bool {
+    let mut rest = text;
+    while let Some(index) = rest.find(|c: char| c.is_alphabetic() || c == '&') {
+        rest = &rest[index..];
+        if !rest.starts_with('&') {
+            return true;
+        }
+        let entity_length = rest[1..]
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '#')
+            .filter(|&length| length > 0 && rest[1 + length..].starts_with(';'));
+        rest = match entity_length {
+            Some(length) => &rest[length + 2..],
+            None => &rest[1..],
+        };
+    }
+    false
+}
@@ -0,0 +1,384 @@
+use crate::react::{is_react_call_api, ReactLibrary};
+use crate::services::semantic::Semantic;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, function_ext::AnyFunctionLike, AnyJsExpression,
+    AnyJsFunction, AnyJsObjectMember, JsCallExpression, JsReturnStatement, JsSyntaxNode, TextRange,
+};
+use biome_rowan::{AstNode, AstSeparatedList, WalkEvent};
+use rustc_hash::FxHashSet;
+
+declare_lint_rule! {
+    /// Require the event listeners added by an effect or by a subscription to be removed by its cleanup function.
+    ///
+    /// A listener added with `addEventListener()` stays attached to its target until it's removed
+    /// with `removeEventListener()`. When a React effect adds a listener without removing it in its cleanup function,
+    /// the listener keeps running after the component is unmounted, and a new listener is added every time the effect runs again.
+    /// The listener also keeps the variables it captures in memory.
+    ///
+    /// The rule checks the callbacks of `useEffect()`, `useLayoutEffect()`, and `useInsertionEffect()`,
+    /// as well as the functions that return a cleanup function, such as `subscribe()` functions.
+    /// Every listener added directly in the function must be removed by a cleanup function that it returns,
+    /// with the same target, the same event, and the same listener.
+    /// The targets, the events, and the listeners are compared by their source text.
+    ///
+    /// A listener written as an inline function can't be removed, since `removeEventListener()` needs the same function.
+    /// The listeners added with a `signal` option are ignored, since aborting the signal removes them.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import { useEffect } from "react";
+    ///
+    /// function Component() {
+    ///     useEffect(() => {
+    ///         window.addEventListener("resize", onResize);
+    ///     }, []);
+    /// }
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import { useEffect } from "react";
+    ///
+    /// function Component() {
+    ///     useEffect(() => {
+    ///         document.addEventListener("keydown", (event) => onKeyDown(event));
+    ///         return () => document.removeEventListener("keydown", (event) => onKeyDown(event));
+    ///     }, []);
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function subscribe(element, callback) {
+    ///     element.addEventListener("click", callback);
+    ///     element.addEventListener("keydown", callback);
+    ///     return () => {
+    ///         element.removeEventListener("click", callback);
+    ///     };
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// import { useEffect } from "react";
+    ///
+    /// function Component() {
+    ///     useEffect(() => {
+    ///         window.addEventListener("resize", onResize);
+    ///         return () => {
+    ///             window.removeEventListener("resize", onResize);
+    ///         };
+    ///     }, []);
+    /// }
+    /// ```
+    ///
+    /// ```jsx
+    /// import { useEffect } from "react";
+    ///
+    /// function Component() {
+    ///     useEffect(() => {
+    ///         const controller = new AbortController();
+    ///         window.addEventListener("resize", onResize, { signal: controller.signal });
+    ///         return () => controller.abort();
+    ///     }, []);
+    /// }
+    /// ```
+    ///
+    pub NoLeakedEventListeners {
+        version: "next",
+        name: "noLeakedEventListeners",
+        language: "js",
+        recommended: false,
+    }
+}
+
+/// The React hooks whose callbacks return a cleanup function.
+const EFFECT_HOOKS: [&str; 3] = ["useEffect", "useLayoutEffect", "useInsertionEffect"];
+
+pub struct LeakedEventListener {
+    /// The range of the call to `addEventListener()`.
+    range: TextRange,
+    /// Whether the listener is an inline function.
+    is_inline_listener: bool,
+    /// The range of the first cleanup function returned by the function, if any.
+    cleanup_range: Option<TextRange>,
+    /// Whether the function is the callback of an effect.
+    is_effect: bool,
+}
+
+impl Rule for NoLeakedEventListeners {
+    type Query = Semantic<AnyFunctionLike>;
+    type State = LeakedEventListener;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let function = ctx.query();
+        let model = ctx.model();
+        let Ok(body) = function.body() else {
+            return Box::default();
+        };
+        let is_effect = is_effect_callback(function, model);
+        let mut added_listeners = Vec::new();
+        let mut returned_values = Vec::new();
+        if let Some(expression) = body.as_any_js_expression() {
+            // `() => () => {}` returns the inner function
+            returned_values.push(expression.clone());
+        }
+        let mut events = body.syntax().preorder();
+        while let Some(event) = events.next() {
+            let WalkEvent::Enter(node) = event else {
+                continue;
+            };
+            // The listeners of the nested functions are checked with these functions
+            if AnyFunctionLike::can_cast(node.kind()) {
+                events.skip_subtree();
+            } else if let Some(statement) = JsReturnStatement::cast_ref(&node) {
+                returned_values.extend(statement.argument());
+            } else if let Some(call) = JsCallExpression::cast_ref(&node) {
+                added_listeners.extend(ListenerCall::new(&call, "addEventListener"));
+            }
+        }
+        if added_listeners.is_empty() {
+            return Box::default();
+        }
+        let cleanups: Vec<JsSyntaxNode> = returned_values
+            .iter()
+            .filter_map(|value| cleanup_function(value, model))
+            .collect();
+        if !is_effect && cleanups.is_empty() {
+            return Box::default();
+        }
+        let removed_listeners: FxHashSet<(String, String, String)> = cleanups
+            .iter()
+            .flat_map(|cleanup| cleanup.descendants())
+            .filter_map(|node| {
+                let call = JsCallExpression::cast(node)?;
+                ListenerCall::new(&call, "removeEventListener")
+            })
+            .map(|listener| listener.key)
+            .collect();
+        let cleanup_range = cleanups.first().map(JsSyntaxNode::text_trimmed_range);
+        added_listeners
+            .into_iter()
+            .filter(|listener| listener.is_inline || !removed_listeners.contains(&listener.key))
+            .map(|listener| LeakedEventListener {
+                range: listener.range,
+                is_inline_listener: listener.is_inline,
+                cleanup_range,
+                is_effect,
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let mut diagnostic = if state.is_effect {
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This event listener isn't removed when the effect is cleaned up."
+                },
+            )
+        } else {
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This event listener isn't removed by the returned cleanup function."
+                },
+            )
+        };
+        if let Some(cleanup_range) = state.cleanup_range {
+            diagnostic = diagnostic.detail(
+                cleanup_range,
+                markup! {
+                    "The cleanup function is here."
+                },
+            );
+        }
+        diagnostic = if state.is_effect {
+            diagnostic.note(markup! {
+                "The listener keeps running after the component is unmounted, and another listener is added every time the effect runs again."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "The listener keeps running after the cleanup function is called."
+            })
+        };
+        diagnostic = if state.is_inline_listener {
+            diagnostic.note(markup! {
+                "This listener is an inline function, so "<Emphasis>"removeEventListener()"</Emphasis>" can't remove it. Declare the listener in a variable, and pass this variable to both calls."
+            })
+        } else if state.is_effect && state.cleanup_range.is_none() {
+            diagnostic.note(markup! {
+                "Return a cleanup function from the effect that calls "<Emphasis>"removeEventListener()"</Emphasis>" with the same target, event, and listener."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "Call "<Emphasis>"removeEventListener()"</Emphasis>" in the cleanup function with the same target, event, and listener."
+            })
+        };
+        Some(diagnostic)
+    }
+}
+
+/// A call to `addEventListener()` or to `removeEventListener()`.
+struct ListenerCall {
+    /// The source text of the target, the event, and the listener.
+    key: (String, String, String),
+    /// Whether the listener is an inline function.
+    is_inline: bool,
+    range: TextRange,
+}
+
+impl ListenerCall {
+    /// Returns the call to `method` that `call` makes, if any.
+    ///
+    /// The calls with a `signal` option are ignored.
+    fn new(call: &JsCallExpression, method: &str) -> Option<Self> {
+        let target = match call.callee().ok()?.omit_parentheses() {
+            AnyJsExpression::JsStaticMemberExpression(member) => {
+                if member.member().ok()?.value_token().ok()?.text_trimmed() != method {
+                    return None;
+                }
+                member.object().ok()?.syntax().text_trimmed().to_string()
+            }
+            // `addEventListener()` is `window.addEventListener()`
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                if identifier.name().ok()?.value_token().ok()?.text_trimmed() != method {
+                    return None;
+                }
+                "window".to_string()
+            }
+            _ => return None,
+        };
+        let [Some(event), Some(listener), options] =
+            call.arguments().ok()?.get_arguments_by_index([0, 1, 2])
+        else {
+            return None;
+        };
+        let event = event.as_any_js_expression()?.clone().omit_parentheses();
+        let listener = listener.as_any_js_expression()?.clone().omit_parentheses();
+        let has_signal = options
+            .as_ref()
+            .and_then(|options| {
+                options
+                    .as_any_js_expression()?
+                    .as_js_object_expression()
+                    .cloned()
+            })
+            .is_some_and(|options| {
+                options
+                    .members()
+                    .iter()
+                    .flatten()
+                    .any(|member| match member {
+                        AnyJsObjectMember::JsPropertyObjectMember(property) => property
+                            .name()
+                            .ok()
+                            .and_then(|name| name.name())
+                            .is_some_and(|name| name.text() == "signal"),
+                        AnyJsObjectMember::JsShorthandPropertyObjectMember(property) => property
+                            .name()
+                            .ok()
+                            .and_then(|name| name.value_token().ok())
+                            .is_some_and(|name| name.text_trimmed() == "signal"),
+                        _ => false,
+                    })
+            });
+        if has_signal {
+            return None;
+        }
+        let event_text = match event
+            .as_any_js_literal_expression()
+            .and_then(|literal| literal.as_js_string_literal_expression())
+        {
+            Some(string) => string.inner_string_text().ok()?.text().to_string(),
+            None => event.syntax().text_trimmed().to_string(),
+        };
+        Some(Self {
+            key: (
+                target,
+                event_text,
+                listener.syntax().text_trimmed().to_string(),
+            ),
+            is_inline: matches!(
+                listener,
+                AnyJsExpression::JsArrowFunctionExpression(_)
+                    | AnyJsExpression::JsFunctionExpression(_)
+            ),
+            range: call.range(),
+        })
+    }
+}
+
+/// Returns `true` if `function` is the callback of a React effect, such as `useEffect(() => {})`.
+fn is_effect_callback(function: &AnyFunctionLike, model: &SemanticModel) -> bool {
+    let AnyFunctionLike::AnyJsFunction(
+        AnyJsFunction::JsArrowFunctionExpression(_) | AnyJsFunction::JsFunctionExpression(_),
+    ) = function
+    else {
+        return false;
+    };
+    let Some(call) = function
+        .syntax()
+        .ancestors()
+        .nth(3)
+        .and_then(JsCallExpression::cast)
+    else {
+        return false;
+    };
+    let is_first_argument = call
+        .arguments()
+        .ok()
+        .and_then(|arguments| arguments.args().first()?.ok())
+        .is_some_and(|argument| argument.syntax() == function.syntax());
+    let Ok(callee) = call.callee() else {
+        return false;
+    };
+    let callee = callee.omit_parentheses();
+    is_first_argument
+        && EFFECT_HOOKS
+            .iter()
+            .any(|hook| is_react_call_api(&callee, model, ReactLibrary::React, hook))
+}
+
+/// Returns the function that `value` evaluates to, if any, either directly or through
+/// a function declaration or a `const` variable.
+fn cleanup_function(value: &AnyJsExpression, model: &SemanticModel) -> Option<JsSyntaxNode> {
+    match value.clone().omit_parentheses() {
+        AnyJsExpression::JsArrowFunctionExpression(function) => Some(function.into_syntax()),
+        AnyJsExpression::JsFunctionExpression(function) => Some(function.into_syntax()),
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let binding = model.binding(&identifier.name().ok()?)?;
+            match binding.tree().declaration()? {
+                AnyJsBindingDeclaration::JsFunctionDeclaration(function) => {
+                    Some(function.into_syntax())
+                }
+                AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+                    if !declarator.declaration()?.is_const() {
+                        return None;
+                    }
+                    let initializer = declarator.initializer()?.expression().ok()?;
+                    match initializer.omit_parentheses() {
+                        AnyJsExpression::JsArrowFunctionExpression(function) => {
+                            Some(function.into_syntax())
+                        }
+                        AnyJsExpression::JsFunctionExpression(function) => {
+                            Some(function.into_syntax())
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
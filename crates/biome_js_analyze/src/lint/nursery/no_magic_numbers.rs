@@ -0,0 +1,365 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize::{Deserializable, DeserializableValue, DeserializationDiagnostic};
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::numbers::parse_js_number;
+use biome_js_syntax::{
+    AnyJsExpression, JsAssignmentExpression, JsBigintLiteralExpression, JsCallArgumentList,
+    JsCallArguments, JsCallExpression, JsComputedMemberExpression, JsInitializerClause,
+    JsNumberLiteralExpression, JsPropertyObjectMember, JsSyntaxKind, JsSyntaxNode,
+    JsUnaryExpression, JsUnaryOperator, JsVariableDeclarator,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow magic numbers.
+    ///
+    /// A _magic number_ is a number that appears in the code without a name that explains what it means,
+    /// such as `86400` instead of `SECONDS_PER_DAY`.
+    /// Declaring the numbers as named constants makes the code easier to read and to change:
+    /// the value is changed once, in the declaration of the constant.
+    ///
+    /// The rule reports the numbers, including the BigInt literals such as `10n`, that aren't the value of a variable declaration,
+    /// of an object property, of a class property, of an assignment, or of a TypeScript enum member.
+    /// The radix passed to `parseInt()` and `Number.parseInt()` is also allowed.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const total = price * 1.2;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// setTimeout(refresh, 60000);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const VAT_RATE = 1.2;
+    /// const total = price * VAT_RATE;
+    /// ```
+    ///
+    /// ```js
+    /// class Timer {
+    ///     interval = 1000;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// const REFRESH_INTERVAL = 60000;
+    /// setTimeout(refresh, REFRESH_INTERVAL);
+    /// const value = parseInt(input, 10);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `ignore`
+    ///
+    /// The numbers that are allowed everywhere, such as `0` or `1`.
+    /// The negative numbers are written with their sign, such as `-1`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignore": [0, 1, -1]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options
+    /// const last = items[items.length - 1];
+    /// ```
+    ///
+    /// ### `ignoreArrayIndexes`
+    ///
+    /// Allow the numbers used as array indexes, which are the integers from `0` to `4294967294`.
+    /// Defaults to `false`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignoreArrayIndexes": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options
+    /// const first = items[0];
+    /// ```
+    ///
+    /// ### `ignoreDefaultValues`
+    ///
+    /// Allow the numbers used as default values of the parameters and of the destructuring patterns.
+    /// Defaults to `false`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignoreDefaultValues": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options
+    /// function paginate(items, pageSize = 20) {}
+    /// ```
+    ///
+    /// ### `enforceConst`
+    ///
+    /// Require the numbers declared in variables to be declared with `const`.
+    /// Defaults to `false`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "enforceConst": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// let retries = 3;
+    /// ```
+    ///
+    pub NoMagicNumbers {
+        version: "next",
+        name: "noMagicNumbers",
+        language: "js",
+        sources: &[RuleSource::Eslint("no-magic-numbers")],
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noMagicNumbers`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoMagicNumbersOptions {
+    /// The numbers that are allowed everywhere.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub ignore: Box<[IgnoredNumber]>,
+    /// Allow the numbers used as array indexes.
+    pub ignore_array_indexes: bool,
+    /// Allow the numbers used as default values.
+    pub ignore_default_values: bool,
+    /// Require the numbers declared in variables to be declared with `const`.
+    pub enforce_const: bool,
+}
+
+/// A number of the option `ignore`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct IgnoredNumber(f64);
+
+// The configuration can't contain `NaN`
+impl Eq for IgnoredNumber {}
+
+impl Deserializable for IgnoredNumber {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        <f64 as Deserializable>::deserialize(value, name, diagnostics).map(Self)
+    }
+}
+
+declare_node_union! {
+    pub AnyJsNumericLiteralExpression = JsNumberLiteralExpression | JsBigintLiteralExpression
+}
+
+impl AnyJsNumericLiteralExpression {
+    /// Returns the value of the literal. The value of a BigInt literal is rounded to the nearest number.
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::JsNumberLiteralExpression(literal) => literal.as_number(),
+            Self::JsBigintLiteralExpression(literal) => {
+                let value = literal.value_token().ok()?;
+                parse_js_number(value.text_trimmed().strip_suffix('n')?)
+            }
+        }
+    }
+}
+
+pub enum MagicNumber {
+    /// A number without a name, and its text.
+    Magic(TextRange, Box<str>),
+    /// A number declared in a variable that isn't declared with `const`.
+    NotConst(TextRange),
+}
+
+impl Rule for NoMagicNumbers {
+    type Query = Ast<AnyJsNumericLiteralExpression>;
+    type State = MagicNumber;
+    type Signals = Option<Self::State>;
+    type Options = Box<NoMagicNumbersOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let literal = ctx.query();
+        let options = ctx.options();
+        let mut value = literal.as_number()?;
+        let mut number = literal.syntax().clone();
+        // `-1` is a single number
+        if let Some(negation) = number
+            .parent()
+            .and_then(JsUnaryExpression::cast)
+            .filter(|unary| unary.operator() == Ok(JsUnaryOperator::Minus))
+        {
+            value = -value;
+            number = negation.into_syntax();
+        }
+        if options.ignore.iter().any(|ignored| ignored.0 == value) {
+            return None;
+        }
+        let range = number.text_trimmed_range();
+        let text: Box<str> = number.text_trimmed().to_string().into();
+        let magic = move || MagicNumber::Magic(range, text);
+        let (number, parent) = parent_omitting_parentheses(number)?;
+        if let Some(initializer) = JsInitializerClause::cast_ref(&parent) {
+            let owner = initializer.syntax().parent()?;
+            return match owner.kind() {
+                JsSyntaxKind::JS_VARIABLE_DECLARATOR => {
+                    let is_const = JsVariableDeclarator::unwrap_cast(owner)
+                        .declaration()
+                        .is_some_and(|declaration| declaration.is_const());
+                    (options.enforce_const && !is_const).then_some(MagicNumber::NotConst(range))
+                }
+                JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER | JsSyntaxKind::TS_ENUM_MEMBER => None,
+                JsSyntaxKind::JS_FORMAL_PARAMETER
+                | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_PROPERTY
+                | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_SHORTHAND_PROPERTY
+                | JsSyntaxKind::JS_ARRAY_BINDING_PATTERN_ELEMENT
+                | JsSyntaxKind::JS_OBJECT_ASSIGNMENT_PATTERN_PROPERTY
+                | JsSyntaxKind::JS_OBJECT_ASSIGNMENT_PATTERN_SHORTHAND_PROPERTY
+                | JsSyntaxKind::JS_ARRAY_ASSIGNMENT_PATTERN_ELEMENT
+                    if options.ignore_default_values =>
+                {
+                    None
+                }
+                _ => Some(magic()),
+            };
+        }
+        let is_allowed = if let Some(assignment) = JsAssignmentExpression::cast_ref(&parent) {
+            assignment
+                .right()
+                .is_ok_and(|right| right.syntax() == &number)
+        } else if let Some(property) = JsPropertyObjectMember::cast_ref(&parent) {
+            property
+                .value()
+                .is_ok_and(|value| value.syntax() == &number)
+        } else if let Some(member) = JsComputedMemberExpression::cast_ref(&parent) {
+            options.ignore_array_indexes
+                && is_array_index(value)
+                && member
+                    .member()
+                    .is_ok_and(|member| member.syntax() == &number)
+        } else {
+            is_parse_int_radix(&number, &parent)
+        };
+        (!is_allowed).then(magic)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            MagicNumber::Magic(range, text) => {
+                let text = text.as_ref();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "The number "<Emphasis>{text}</Emphasis>" is a magic number."
+                    },
+                )
+                .note(markup! {
+                    "A number without a name doesn't explain what it means, and is hard to change when it's repeated."
+                })
+                .note(markup! {
+                    "Declare the number as a constant with a descriptive name."
+                })
+            }
+            MagicNumber::NotConst(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This number is declared in a variable that isn't declared with "<Emphasis>"const"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "A variable that can be reassigned isn't a constant: its value can differ from the declared number."
+            })
+            .note(markup! {
+                "Declare the variable with "<Emphasis>"const"</Emphasis>"."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the outermost parenthesized expression around `node`, or `node` itself, and its parent.
+fn parent_omitting_parentheses(mut node: JsSyntaxNode) -> Option<(JsSyntaxNode, JsSyntaxNode)> {
+    loop {
+        let parent = node.parent()?;
+        if parent.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION {
+            return Some((node, parent));
+        }
+        node = parent;
+    }
+}
+
+/// Returns `true` if `value` is a valid index of an array.
+fn is_array_index(value: f64) -> bool {
+    value.fract() == 0.0 && value >= 0.0 && value < f64::from(u32::MAX)
+}
+
+/// Returns `true` if `number` is the radix of `parseInt()` or `Number.parseInt()`.
+fn is_parse_int_radix(number: &JsSyntaxNode, parent: &JsSyntaxNode) -> bool {
+    let Some(arguments) = JsCallArgumentList::cast_ref(parent) else {
+        return false;
+    };
+    let is_second_argument = arguments
+        .iter()
+        .nth(1)
+        .and_then(|argument| argument.ok())
+        .is_some_and(|argument| argument.syntax() == number);
+    if !is_second_argument {
+        return false;
+    }
+    let Some(callee) = arguments
+        .syntax()
+        .parent()
+        .and_then(JsCallArguments::cast)
+        .and_then(|arguments| arguments.syntax().parent())
+        .and_then(JsCallExpression::cast)
+        .and_then(|call| call.callee().ok())
+    else {
+        return false;
+    };
+    match callee.omit_parentheses() {
+        AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+            .name()
+            .and_then(|name| name.value_token())
+            .is_ok_and(|name| name.text_trimmed() == "parseInt"),
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            let is_parse_int = member
+                .member()
+                .and_then(|member| member.value_token())
+                .is_ok_and(|name| name.text_trimmed() == "parseInt");
+            let is_number = member.object().is_ok_and(|object| {
+                object
+                    .as_js_identifier_expression()
+                    .and_then(|identifier| identifier.name().ok())
+                    .and_then(|name| name.value_token().ok())
+                    .is_some_and(|name| name.text_trimmed() == "Number")
+            });
+            is_parse_int && is_number
+        }
+        _ => false,
+    }
+}
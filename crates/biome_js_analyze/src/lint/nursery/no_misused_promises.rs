@@ -0,0 +1,287 @@
+use crate::services::semantic::Semantic;
+use crate::utils::promise::{is_promise, is_promise_function};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsxAttributeName, AnyJsxAttributeValue,
+    JsCallExpression, JsConditionalExpression, JsDoWhileStatement, JsForStatement, JsIfStatement,
+    JsUnaryExpression, JsUnaryOperator, JsWhileStatement, JsxAttribute,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, TextRange};
+
+declare_lint_rule! {
+    /// Disallow promises where they aren't expected.
+    ///
+    /// A promise used where a value is expected behaves in a surprising way:
+    ///
+    /// - an `async` function passed as a callback whose result is ignored, such as the callback of `forEach()`
+    ///   or an event handler, runs without anyone waiting for it, and its rejection is unhandled;
+    /// - an `async` function passed as a predicate, such as the callback of `filter()` or `some()`,
+    ///   always returns a promise, which is always truthy;
+    /// - a promise used as a condition, such as `if (promise)`, is always truthy,
+    ///   and the condition doesn't depend on the value of the promise.
+    ///
+    /// The rule reports the `async` functions passed to `forEach()`, `setTimeout()`, `setInterval()`,
+    /// `queueMicrotask()`, `requestAnimationFrame()`, and `addEventListener()`,
+    /// to the JSX attributes of the event handlers, such as `onClick`,
+    /// and to the predicates of `filter()`, `find()`, `findIndex()`, `findLast()`, `findLastIndex()`, `some()`, and `every()`.
+    /// It also reports the promises used as the condition of an `if` statement, a loop, or a conditional expression,
+    /// and the promises negated with `!`.
+    ///
+    /// Biome doesn't infer the types of the expressions, so the rule only detects
+    /// the same promises as [noFloatingPromises](https://biomejs.dev/linter/rules/no-floating-promises/):
+    /// the `async` functions, the functions whose return type is annotated as `Promise`,
+    /// and the calls to these functions and to the static methods of `Promise`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// items.forEach(async (item) => {
+    ///   await save(item);
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const visible = items.filter(async (item) => await isVisible(item));
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <button onClick={async () => await save()}>Save</button>;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function isAllowed() {}
+    ///
+    /// if (isAllowed()) {
+    ///   run();
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// for (const item of items) {
+    ///   await save(item);
+    /// }
+    ///
+    /// await Promise.all(items.map(async (item) => await save(item)));
+    /// ```
+    ///
+    /// ```js
+    /// async function isAllowed() {}
+    ///
+    /// if (await isAllowed()) {
+    ///   run();
+    /// }
+    /// ```
+    ///
+    pub NoMisusedPromises {
+        version: "next",
+        name: "noMisusedPromises",
+        language: "js",
+        sources: &[RuleSource::EslintTypeScript("no-misused-promises")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// The methods and functions whose callback is expected to return nothing,
+/// and the index of the callback in their arguments.
+const VOID_CALLBACKS: [(&str, usize); 6] = [
+    ("addEventListener", 1),
+    ("forEach", 0),
+    ("queueMicrotask", 0),
+    ("requestAnimationFrame", 0),
+    ("setInterval", 0),
+    ("setTimeout", 0),
+];
+
+/// The array methods whose callback is a predicate.
+const PREDICATE_METHODS: [&str; 7] = [
+    "every",
+    "filter",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "some",
+];
+
+declare_node_union! {
+    pub AnyPromiseMisuseCandidate = JsCallExpression
+        | JsxAttribute
+        | JsIfStatement
+        | JsWhileStatement
+        | JsDoWhileStatement
+        | JsForStatement
+        | JsConditionalExpression
+        | JsUnaryExpression
+}
+
+pub enum MisusedPromise {
+    /// An `async` function passed where a function that returns nothing is expected.
+    VoidReturn(TextRange),
+    /// An `async` function passed as a predicate.
+    Predicate(TextRange),
+    /// A promise used as a condition.
+    Condition(TextRange),
+}
+
+impl Rule for NoMisusedPromises {
+    type Query = Semantic<AnyPromiseMisuseCandidate>;
+    type State = MisusedPromise;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        match ctx.query() {
+            AnyPromiseMisuseCandidate::JsCallExpression(call) => misused_callback(call, model),
+            AnyPromiseMisuseCandidate::JsxAttribute(attribute) => {
+                let AnyJsxAttributeName::JsxName(name) = attribute.name().ok()? else {
+                    return None;
+                };
+                let name = name.value_token().ok()?;
+                let is_event_handler = name
+                    .text_trimmed()
+                    .strip_prefix("on")
+                    .is_some_and(|event| event.starts_with(|c: char| c.is_ascii_uppercase()));
+                if !is_event_handler {
+                    return None;
+                }
+                let AnyJsxAttributeValue::JsxExpressionAttributeValue(value) =
+                    attribute.initializer()?.value().ok()?
+                else {
+                    return None;
+                };
+                let handler = value.expression().ok()?;
+                is_promise_function(&handler, model)
+                    .then(|| MisusedPromise::VoidReturn(handler.range()))
+            }
+            AnyPromiseMisuseCandidate::JsIfStatement(statement) => {
+                promise_condition(&statement.test().ok()?, model)
+            }
+            AnyPromiseMisuseCandidate::JsWhileStatement(statement) => {
+                promise_condition(&statement.test().ok()?, model)
+            }
+            AnyPromiseMisuseCandidate::JsDoWhileStatement(statement) => {
+                promise_condition(&statement.test().ok()?, model)
+            }
+            AnyPromiseMisuseCandidate::JsForStatement(statement) => {
+                promise_condition(&statement.test()?, model)
+            }
+            AnyPromiseMisuseCandidate::JsConditionalExpression(expression) => {
+                promise_condition(&expression.test().ok()?, model)
+            }
+            AnyPromiseMisuseCandidate::JsUnaryExpression(expression) => {
+                if expression.operator().ok()? != JsUnaryOperator::LogicalNot {
+                    return None;
+                }
+                promise_condition(&expression.argument().ok()?, model)
+            }
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            MisusedPromise::VoidReturn(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This "<Emphasis>"async"</Emphasis>" function is passed where a function that returns nothing is expected."
+                },
+            )
+            .note(markup! {
+                "The promise that it returns is ignored: nothing waits for it to settle, and its rejection is unhandled."
+            })
+            .note(markup! {
+                "Handle the rejection inside of the function, or use a loop or "<Emphasis>"Promise.all()"</Emphasis>" to wait for the promises."
+            }),
+            MisusedPromise::Predicate(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This "<Emphasis>"async"</Emphasis>" function is passed where a predicate is expected."
+                },
+            )
+            .note(markup! {
+                "The promise that it returns is always truthy, regardless of the value it resolves to."
+            })
+            .note(markup! {
+                "Resolve the values before calling the method, for example with "<Emphasis>"Promise.all()"</Emphasis>"."
+            }),
+            MisusedPromise::Condition(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This promise is used as a condition."
+                },
+            )
+            .note(markup! {
+                "A promise is always truthy, regardless of the value it resolves to."
+            })
+            .note(markup! {
+                "Did you forget to "<Emphasis>"await"</Emphasis>" it?"
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the `async` function passed to `call` where a callback that returns nothing,
+/// or a predicate, is expected.
+fn misused_callback(call: &JsCallExpression, model: &SemanticModel) -> Option<MisusedPromise> {
+    let callee = call.callee().ok()?.omit_parentheses();
+    let name = match &callee {
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            identifier.name().ok()?.value_token().ok()?
+        }
+        _ => callee.get_callee_member_name()?,
+    };
+    let name = name.text_trimmed();
+    let (index, is_predicate) = if PREDICATE_METHODS.contains(&name) {
+        (0, true)
+    } else {
+        let (_, index) = VOID_CALLBACKS
+            .iter()
+            .find(|(function, _)| *function == name)?;
+        (*index, false)
+    };
+    // `forEach()` and the predicates are methods
+    if (is_predicate || name == "forEach" || name == "addEventListener")
+        && callee.as_js_identifier_expression().is_some()
+    {
+        return None;
+    }
+    let AnyJsCallArgument::AnyJsExpression(callback) =
+        call.arguments().ok()?.args().iter().nth(index)?.ok()?
+    else {
+        return None;
+    };
+    if !is_promise_function(&callback, model) {
+        return None;
+    }
+    Some(if is_predicate {
+        MisusedPromise::Predicate(callback.range())
+    } else {
+        MisusedPromise::VoidReturn(callback.range())
+    })
+}
+
+/// Returns the promise used as `condition`, or as an operand of the logical expressions of `condition`.
+fn promise_condition(condition: &AnyJsExpression, model: &SemanticModel) -> Option<MisusedPromise> {
+    match condition.clone().omit_parentheses() {
+        AnyJsExpression::JsLogicalExpression(expression) => {
+            promise_condition(&expression.left().ok()?, model)
+                .or_else(|| promise_condition(&expression.right().ok()?, model))
+        }
+        condition => {
+            is_promise(&condition, model, &[]).then(|| MisusedPromise::Condition(condition.range()))
+        }
+    }
+}
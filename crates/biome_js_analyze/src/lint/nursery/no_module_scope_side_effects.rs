@@ -0,0 +1,381 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_glob::{CandidatePath, Glob};
+use biome_js_semantic::{HasDeclarationAstNode, SemanticModel};
+use biome_js_syntax::{
+    global_identifier, AnyJsAssignment, AnyJsAssignmentPattern, AnyJsExpression,
+    AnyJsMemberExpression, AnyJsModuleItem, AnyJsPropertyModifier, JsAssignmentExpression,
+    JsCallExpression, JsModule, JsNewExpression, JsPostUpdateExpression, JsPreUpdateExpression,
+    JsPropertyClassMember, JsSyntaxKind, JsSyntaxNode, JsTemplateExpression, JsUnaryExpression,
+    JsUnaryOperator,
+};
+use biome_rowan::{AstNode, AstNodeList, TextRange, WalkEvent};
+use serde::{Deserialize, Serialize};
+
+use crate::services::semantic::Semantic;
+
+declare_lint_rule! {
+    /// Disallow the statements with side effects at the top level of a module.
+    ///
+    /// The top-level statements of a module run as soon as the module is imported.
+    /// When they have side effects, bundlers have to keep the module in the bundle even if none of its exports are used,
+    /// which defeats tree-shaking. This matters most for the modules of library packages.
+    ///
+    /// The rule reports the following code when it runs at the top level,
+    /// including in the initializers of the variables and of the static class properties:
+    ///
+    /// - the calls, the instantiations, and the tagged templates;
+    /// - the mutations of the imported bindings, e.g. `imported.value = 1` or `imported.count++`;
+    /// - the mutations of the global variables, e.g. `window.onload = init`.
+    ///
+    /// The code of the functions and of the class methods isn't reported, since it doesn't run when the module is imported.
+    /// Mutating the variables declared in the module isn't reported either.
+    ///
+    /// The following calls are considered free of side effects:
+    ///
+    /// - the calls annotated with `/*#__PURE__*/` or `/*@__PURE__*/`, which bundlers understand;
+    /// - the calls of `require()`;
+    /// - the calls of a few global functions that don't have side effects,
+    ///   such as `Symbol()`, `Object.freeze()`, and `new Map()`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { registry } from "./registry.js";
+    /// registry.register("button");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// export const client = createClient();
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import { config } from "./config.js";
+    /// config.debug = true;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// window.analytics = {};
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// export const client = /*#__PURE__*/ createClient();
+    /// export const KEY = Symbol("key");
+    /// export const DEFAULTS = Object.freeze({ debug: false });
+    /// export function register(registry) {
+    ///     registry.register("button");
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `allowedFiles`
+    ///
+    /// A list of globs that select the modules whose top-level side effects are allowed,
+    /// such as the entry points of an application, the polyfills, and the test setup files.
+    /// A glob prefixed with `!` is an exception.
+    /// See the [glob syntax](https://biomejs.dev/reference/configuration/#glob-syntax-reference).
+    ///
+    /// Default: `[]`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "allowedFiles": ["src/main.ts", "**/*.test.ts"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoModuleScopeSideEffects {
+        version: "next",
+        name: "noModuleScopeSideEffects",
+        language: "js",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noModuleScopeSideEffects`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoModuleScopeSideEffectsOptions {
+    /// Globs of the modules whose top-level side effects are allowed. The globs prefixed with `!` are exceptions.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    allowed_files: Box<[Glob]>,
+}
+
+pub enum SideEffectKind {
+    Call,
+    ImportMutation,
+    GlobalMutation,
+}
+
+pub struct SideEffect {
+    range: TextRange,
+    kind: SideEffectKind,
+}
+
+impl Rule for NoModuleScopeSideEffects {
+    type Query = Semantic<JsModule>;
+    type State = SideEffect;
+    type Signals = Box<[Self::State]>;
+    type Options = NoModuleScopeSideEffectsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if CandidatePath::new(&ctx.file_path())
+            .matches_with_exceptions(&ctx.options().allowed_files)
+        {
+            return Box::default();
+        }
+        let model = ctx.model();
+        let mut side_effects = Vec::new();
+        for item in ctx.query().items() {
+            if matches!(item, AnyJsModuleItem::JsImport(_)) {
+                continue;
+            }
+            let mut events = item.syntax().preorder();
+            while let Some(event) = events.next() {
+                let WalkEvent::Enter(node) = event else {
+                    continue;
+                };
+                if is_deferred(&node) {
+                    events.skip_subtree();
+                } else if let Some(kind) = side_effect_of(model, &node) {
+                    side_effects.push(SideEffect {
+                        range: node.text_trimmed_range(),
+                        kind,
+                    });
+                    // Only the outermost side effect is reported
+                    events.skip_subtree();
+                }
+            }
+        }
+        side_effects.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (message, advice) = match state.kind {
+            SideEffectKind::Call => (
+                markup! { "This call runs when the module is imported, and may have side effects." },
+                markup! { "Move the call into a function, or annotate it with "<Emphasis>"/*#__PURE__*/"</Emphasis>" if it doesn't have side effects." },
+            ),
+            SideEffectKind::ImportMutation => (
+                markup! { "This statement mutates an imported binding when the module is imported." },
+                markup! { "Move the mutation into a function that the importers call explicitly." },
+            ),
+            SideEffectKind::GlobalMutation => (
+                markup! { "This statement mutates a global variable when the module is imported." },
+                markup! { "Move the mutation into a function that the importers call explicitly." },
+            ),
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), state.range, message)
+                .note(markup! {
+                    "Bundlers keep a module with side effects at the top level, even if none of its exports are used."
+                })
+                .note(advice),
+        )
+    }
+}
+
+/// Returns `true` if the code of `node` doesn't run when the module is evaluated,
+/// e.g. the body of a function, or the initializer of an instance property.
+fn is_deferred(node: &JsSyntaxNode) -> bool {
+    match node.kind() {
+        JsSyntaxKind::JS_FUNCTION_DECLARATION
+        | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+        | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+        | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+        | JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+        | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+        | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+        | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+        | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+        | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+        | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+        | JsSyntaxKind::TS_DECLARE_STATEMENT
+        | JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+        | JsSyntaxKind::TS_INTERFACE_DECLARATION
+        | JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION => true,
+        JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER => JsPropertyClassMember::cast_ref(node)
+            .is_some_and(|member| {
+                !member
+                    .modifiers()
+                    .iter()
+                    .any(|modifier| matches!(modifier, AnyJsPropertyModifier::JsStaticModifier(_)))
+            }),
+        _ => false,
+    }
+}
+
+/// Returns the kind of side effect that `node` has when it's evaluated, without taking its children into account.
+fn side_effect_of(model: &SemanticModel, node: &JsSyntaxNode) -> Option<SideEffectKind> {
+    match node.kind() {
+        JsSyntaxKind::JS_CALL_EXPRESSION => {
+            let call = JsCallExpression::cast_ref(node)?;
+            let callee = call.callee().ok()?.omit_parentheses();
+            let is_pure = has_pure_annotation(node)
+                || is_global_call(model, &callee, PURE_FUNCTIONS)
+                || is_global_method_call(model, &callee);
+            (!is_pure).then_some(SideEffectKind::Call)
+        }
+        JsSyntaxKind::JS_NEW_EXPRESSION => {
+            let new = JsNewExpression::cast_ref(node)?;
+            let callee = new.callee().ok()?.omit_parentheses();
+            let is_pure =
+                has_pure_annotation(node) || is_global_call(model, &callee, PURE_CONSTRUCTORS);
+            (!is_pure).then_some(SideEffectKind::Call)
+        }
+        JsSyntaxKind::JS_IMPORT_CALL_EXPRESSION => Some(SideEffectKind::Call),
+        JsSyntaxKind::JS_TEMPLATE_EXPRESSION => JsTemplateExpression::cast_ref(node)?
+            .tag()
+            .map(|_| SideEffectKind::Call),
+        JsSyntaxKind::JS_ASSIGNMENT_EXPRESSION => {
+            match JsAssignmentExpression::cast_ref(node)?.left().ok()? {
+                AnyJsAssignmentPattern::AnyJsAssignment(assignment) => {
+                    mutation_of_assignment(model, &assignment)
+                }
+                _ => None,
+            }
+        }
+        JsSyntaxKind::JS_PRE_UPDATE_EXPRESSION => mutation_of_assignment(
+            model,
+            &JsPreUpdateExpression::cast_ref(node)?.operand().ok()?,
+        ),
+        JsSyntaxKind::JS_POST_UPDATE_EXPRESSION => mutation_of_assignment(
+            model,
+            &JsPostUpdateExpression::cast_ref(node)?.operand().ok()?,
+        ),
+        JsSyntaxKind::JS_UNARY_EXPRESSION => {
+            let unary = JsUnaryExpression::cast_ref(node)?;
+            if unary.operator().ok()? != JsUnaryOperator::Delete {
+                return None;
+            }
+            let member = AnyJsMemberExpression::cast(unary.argument().ok()?.into_syntax())?;
+            mutation_of_object(model, member.object().ok()?)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the kind of mutation of an assignment to `assignment`,
+/// or [None] if the mutated variable is declared in the module.
+fn mutation_of_assignment(
+    model: &SemanticModel,
+    assignment: &AnyJsAssignment,
+) -> Option<SideEffectKind> {
+    match assignment {
+        AnyJsAssignment::JsIdentifierAssignment(identifier) => {
+            mutation_of_reference(model, identifier)
+        }
+        AnyJsAssignment::JsStaticMemberAssignment(member) => {
+            mutation_of_object(model, member.object().ok()?)
+        }
+        AnyJsAssignment::JsComputedMemberAssignment(member) => {
+            mutation_of_object(model, member.object().ok()?)
+        }
+        AnyJsAssignment::JsParenthesizedAssignment(parenthesized) => {
+            mutation_of_assignment(model, &parenthesized.assignment().ok()?)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the kind of mutation of a property of `object`, e.g. `a` in `a.b.c = 0`.
+fn mutation_of_object(model: &SemanticModel, object: AnyJsExpression) -> Option<SideEffectKind> {
+    let mut object = object.omit_parentheses();
+    while let Some(member) = AnyJsMemberExpression::cast_ref(object.syntax()) {
+        object = member.object().ok()?.omit_parentheses();
+    }
+    mutation_of_reference(model, &object.as_js_reference_identifier()?)
+}
+
+fn mutation_of_reference(
+    model: &SemanticModel,
+    reference: &impl HasDeclarationAstNode,
+) -> Option<SideEffectKind> {
+    match model.binding(reference) {
+        None => Some(SideEffectKind::GlobalMutation),
+        Some(binding) if binding.is_imported() => Some(SideEffectKind::ImportMutation),
+        Some(_) => None,
+    }
+}
+
+/// Returns `true` if `node` is preceded by a `/*#__PURE__*/` or `/*@__PURE__*/` comment.
+fn has_pure_annotation(node: &JsSyntaxNode) -> bool {
+    let Some(first_token) = node.first_token() else {
+        return false;
+    };
+    // The comment belongs to the previous token when it's on the same line
+    let trailing_trivia = first_token
+        .prev_token()
+        .map(|token| token.trailing_trivia().pieces());
+    first_token
+        .leading_trivia()
+        .pieces()
+        .chain(trailing_trivia.into_iter().flatten())
+        .any(|piece| {
+            piece.is_comments() && {
+                let text = piece.text();
+                text.contains("#__PURE__") || text.contains("@__PURE__")
+            }
+        })
+}
+
+/// The global functions that don't have side effects, sorted.
+/// `require()` is included because it's the CommonJS counterpart of the imports.
+const PURE_FUNCTIONS: &[&str] = &["BigInt", "Boolean", "Number", "String", "Symbol", "require"];
+
+/// The global constructors that don't have side effects, sorted.
+const PURE_CONSTRUCTORS: &[&str] = &[
+    "Array", "Date", "Error", "Map", "Object", "RegExp", "Set", "URL", "WeakMap", "WeakSet",
+];
+
+/// The static methods of the global objects that don't have side effects, sorted.
+/// All the methods of `Math` are also considered pure.
+const PURE_METHODS: &[(&str, &str)] = &[
+    ("Array", "from"),
+    ("Array", "isArray"),
+    ("Array", "of"),
+    ("JSON", "parse"),
+    ("JSON", "stringify"),
+    ("Number", "isFinite"),
+    ("Number", "isInteger"),
+    ("Number", "isNaN"),
+    ("Number", "parseFloat"),
+    ("Number", "parseInt"),
+    ("Object", "create"),
+    ("Object", "entries"),
+    ("Object", "freeze"),
+    ("Object", "fromEntries"),
+    ("Object", "keys"),
+    ("Object", "values"),
+    ("Symbol", "for"),
+];
+
+/// Returns `true` if `callee` refers to one of the global `names` that isn't shadowed.
+fn is_global_call(model: &SemanticModel, callee: &AnyJsExpression, names: &[&str]) -> bool {
+    global_identifier(callee).is_some_and(|(reference, name)| {
+        names.binary_search(&name.text()).is_ok() && model.binding(&reference).is_none()
+    })
+}
+
+/// Returns `true` if `callee` is one of the [PURE_METHODS], e.g. `Object.freeze`.
+fn is_global_method_call(model: &SemanticModel, callee: &AnyJsExpression) -> bool {
+    let Some(member) = AnyJsMemberExpression::cast_ref(callee.syntax()) else {
+        return false;
+    };
+    let (Some(method), Ok(object)) = (member.member_name(), member.object()) else {
+        return false;
+    };
+    global_identifier(&object.omit_parentheses()).is_some_and(|(reference, object)| {
+        let object = object.text();
+        (object == "Math" || PURE_METHODS.binary_search(&(object, method.text())).is_ok())
+            && model.binding(&reference).is_none()
+    })
+}
@@ -2,8 +2,13 @@ use biome_analyze::{
     context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
 };
 use biome_console::markup;
-use biome_js_syntax::{AnyJsExpression, JsConditionalExpression};
-use biome_rowan::{AstNode, TextRange};
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, JsConditionalExpression, JsSyntaxKind,
+};
+use biome_rowan::AstNode;
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU8;
 
 declare_lint_rule! {
     /// Disallow nested ternary expressions.
@@ -40,6 +45,52 @@ declare_lint_rule! {
     /// }
     /// ```
     ///
+    /// ## Options
+    ///
+    /// ### `maxDepth`
+    ///
+    /// The maximum depth of the ternary expressions.
+    /// A ternary expression that isn't in a branch of another ternary expression has a depth of 1.
+    ///
+    /// Default: `1`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "maxDepth": 2
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options
+    /// const thing = foo ? bar : baz === qux ? quxx : foobar;
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// const thing = foo ? bar : baz ? qux : quxx ? quxxx : foobar;
+    /// ```
+    ///
+    /// ### `allowJsxConditionalRendering`
+    ///
+    /// Whether to allow the nested ternary expressions that select the JSX to render,
+    /// when all their branches are JSX elements, JSX fragments, or `null`.
+    ///
+    /// Default: `false`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "allowJsxConditionalRendering": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```jsx,use_options
+    /// <div>
+    ///     {isLoading ? <Spinner /> : hasError ? <Error /> : <Content />}
+    /// </div>
+    /// ```
+    ///
     pub NoNestedTernary {
         version: "1.9.3",
         name: "noNestedTernary",
@@ -49,33 +100,55 @@ declare_lint_rule! {
     }
 }
 
+/// Options for the rule `noNestedTernary`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoNestedTernaryOptions {
+    /// The maximum depth of the ternary expressions.
+    max_depth: NonZeroU8,
+    /// Whether to allow the nested ternary expressions whose branches are all JSX elements, JSX fragments, or `null`.
+    allow_jsx_conditional_rendering: bool,
+}
+
+impl Default for NoNestedTernaryOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: NonZeroU8::MIN,
+            allow_jsx_conditional_rendering: false,
+        }
+    }
+}
+
 impl Rule for NoNestedTernary {
     type Query = Ast<JsConditionalExpression>;
-    type State = TextRange;
+    type State = ();
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = NoNestedTernaryOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
-        let alternate = node.alternate().ok()?;
-        let consequent = node.consequent().ok()?;
-
-        if let AnyJsExpression::JsConditionalExpression(expr) = consequent {
-            return Some(expr.range());
+        let options = ctx.options();
+        let mut outermost = node.clone();
+        let mut depth = 1;
+        while let Some(parent) = parent_ternary(&outermost) {
+            outermost = parent;
+            depth += 1;
         }
-
-        if let AnyJsExpression::JsConditionalExpression(expr) = alternate {
-            return Some(expr.range());
+        if depth <= options.max_depth.get() {
+            return None;
         }
-
-        None
+        if options.allow_jsx_conditional_rendering && is_jsx_conditional_rendering(&outermost) {
+            return None;
+        }
+        Some(())
     }
 
-    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
         Some(
             RuleDiagnostic::new(
                 rule_category!(),
-                state,
+                ctx.query().range(),
                 markup! {
                     "Do not nest ternary expressions."
                 },
@@ -89,3 +162,39 @@ impl Rule for NoNestedTernary {
         )
     }
 }
+
+/// Returns the ternary expression that has `node` as consequent or alternate.
+fn parent_ternary(node: &JsConditionalExpression) -> Option<JsConditionalExpression> {
+    let parent = node
+        .syntax()
+        .ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)?;
+    let parent = JsConditionalExpression::cast(parent)?;
+    let is_branch = [parent.consequent(), parent.alternate()]
+        .into_iter()
+        .flatten()
+        .any(|branch| branch.omit_parentheses().syntax() == node.syntax());
+    is_branch.then_some(parent)
+}
+
+/// Returns `true` if all the branches of the ternary expression `node`,
+/// and of the ternary expressions nested in its branches, are JSX or `null`.
+fn is_jsx_conditional_rendering(node: &JsConditionalExpression) -> bool {
+    [node.consequent(), node.alternate()]
+        .into_iter()
+        .all(
+            |branch| match branch.map(|branch| branch.omit_parentheses()) {
+                Ok(AnyJsExpression::JsConditionalExpression(ternary)) => {
+                    is_jsx_conditional_rendering(&ternary)
+                }
+                Ok(
+                    AnyJsExpression::JsxTagExpression(_)
+                    | AnyJsExpression::AnyJsLiteralExpression(
+                        AnyJsLiteralExpression::JsNullLiteralExpression(_),
+                    ),
+                ) => true,
+                _ => false,
+            },
+        )
+}
@@ -1,7 +1,10 @@
 use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource};
 use biome_console::markup;
-use biome_js_syntax::{global_identifier, JsStaticMemberExpression};
+use biome_deserialize_macros::Deserializable;
+use biome_glob::{CandidatePath, Glob};
+use biome_js_syntax::{global_identifier, AnyJsMemberExpression, JsStaticMemberExpression};
 use biome_rowan::AstNode;
+use serde::{Deserialize, Serialize};
 
 use crate::services::semantic::Semantic;
 
@@ -35,6 +38,38 @@ declare_lint_rule! {
     /// }
     /// ```
     ///
+    /// ## Options
+    ///
+    /// ### `allowedVariables`
+    ///
+    /// A list of environment variables that can be read anywhere, such as `NODE_ENV`.
+    /// The rule allows `process.env.NAME` and `process.env["NAME"]` when `NAME` is in the list.
+    ///
+    /// Default: `[]`
+    ///
+    /// ### `allowedFiles`
+    ///
+    /// A list of globs that select the files where `process.env` can be used, such as the configuration modules.
+    /// A glob prefixed with `!` is an exception.
+    /// See the [glob syntax](https://biomejs.dev/reference/configuration/#glob-syntax-reference).
+    ///
+    /// Default: `[]`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "allowedVariables": ["NODE_ENV"],
+    ///         "allowedFiles": ["**/config/*.js"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options
+    /// if (process.env.NODE_ENV === 'development') {
+    ///   // ...
+    /// }
+    /// ```
+    ///
     pub NoProcessEnv {
         version: "1.9.1",
         name: "noProcessEnv",
@@ -44,13 +79,31 @@ declare_lint_rule! {
     }
 }
 
+/// Options for the rule `noProcessEnv`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoProcessEnvOptions {
+    /// Environment variables that can be read anywhere.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    allowed_variables: Box<[Box<str>]>,
+
+    /// Globs of the files where `process.env` can be used. The globs prefixed with `!` are exceptions.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    allowed_files: Box<[Glob]>,
+}
+
 impl Rule for NoProcessEnv {
     type Query = Semantic<JsStaticMemberExpression>;
     type State = ();
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = NoProcessEnvOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        if CandidatePath::new(&ctx.file_path()).matches_with_exceptions(&options.allowed_files) {
+            return None;
+        }
         let static_member_expr = ctx.query();
         let model = ctx.model();
         let object = static_member_expr.object().ok()?;
@@ -63,7 +116,29 @@ impl Rule for NoProcessEnv {
         if name.text() != "process" {
             return None;
         }
-        model.binding(&reference).is_none().then_some(())
+        if model.binding(&reference).is_some() {
+            return None;
+        }
+
+        let variable = static_member_expr
+            .syntax()
+            .parent()
+            .and_then(AnyJsMemberExpression::cast)
+            .filter(|parent| {
+                parent
+                    .object()
+                    .is_ok_and(|object| object.syntax() == static_member_expr.syntax())
+            })
+            .and_then(|parent| parent.member_name());
+        if variable.is_some_and(|variable| {
+            options
+                .allowed_variables
+                .iter()
+                .any(|allowed| allowed.as_ref() == variable.text())
+        }) {
+            return None;
+        }
+        Some(())
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
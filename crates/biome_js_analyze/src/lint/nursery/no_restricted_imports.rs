@@ -2,8 +2,10 @@ use biome_analyze::context::RuleContext;
 use biome_analyze::{declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource};
 use biome_console::markup;
 use biome_deserialize::{
-    Deserializable, DeserializableType, DeserializableValue, DeserializationDiagnostic,
+    Deserializable, DeserializableType, DeserializableTypes, DeserializableValidator,
+    DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
 };
+use biome_glob::{CandidatePath, Glob};
 use biome_js_syntax::{
     inner_string_text, AnyJsArrowFunctionParameters, AnyJsBindingPattern, AnyJsCombinedSpecifier,
     AnyJsExpression, AnyJsImportLike, AnyJsNamedImportSpecifier, AnyJsObjectBindingPatternMember,
@@ -230,7 +232,7 @@ declare_lint_rule! {
     /// - **Namespace import:** `"*"` (`import * as alias1 from 'namespace-import'`)
     /// - **Side effect/Bare import:** `""` (`import "sideeffect-import"`)
     ///
-    /// **Only one of `importNames` and `allowImportNames` must be specified.**
+    /// **Only one of `importNames` and `allowImportNames` can be specified.** Specifying both is a configuration error.
     ///
     /// ```json,options
     /// {
@@ -262,7 +264,7 @@ declare_lint_rule! {
     /// Specifies the set of import names that should be explicitly allowed.
     /// See `importNames` for the set of supported import name specifiers.
     ///
-    /// **Only one of `importNames` and `allowImportNames` must be specified.**
+    /// **Only one of `importNames` and `allowImportNames` can be specified.** Specifying both is a configuration error.
     ///
     /// ```json,options
     /// {
@@ -287,6 +289,53 @@ declare_lint_rule! {
     /// ```js,use_options
     /// import { Bar } from 'import-bar';
     /// ```
+    ///
+    /// ### `patterns`
+    ///
+    /// A list of import paths to restrict, selected by globs instead of their exact name.
+    /// An import path is restricted by the first pattern whose globs match it,
+    /// unless it's listed in `paths`, which takes precedence.
+    ///
+    /// Every pattern is an object with the following properties:
+    /// - `group`: the globs that select the import paths.
+    ///   A glob prefixed with `!` is an exception:
+    ///   the import paths that it matches aren't selected, unless a following glob selects them again.
+    ///   See the [glob syntax](https://biomejs.dev/reference/configuration/#glob-syntax-reference).
+    /// - `message`, `importNames`, and `allowImportNames`: the same options as the ones of `paths`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "patterns": [
+    ///             {
+    ///                 "group": ["lodash/*", "!lodash/debounce"],
+    ///                 "message": "Import the whole lodash library instead."
+    ///             },
+    ///             {
+    ///                 "group": ["@app/*/internal"],
+    ///                 "allowImportNames": ["default"]
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// #### Invalid
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// import map from 'lodash/map';
+    /// ```
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// import { helper } from '@app/users/internal';
+    /// ```
+    ///
+    /// #### Valid
+    ///
+    /// ```js,use_options
+    /// import debounce from 'lodash/debounce';
+    /// import users from '@app/users/internal';
+    /// ```
     pub NoRestrictedImports {
         version: "1.6.0",
         name: "noRestrictedImports",
@@ -316,6 +365,24 @@ pub struct RestrictedImportsOptions {
     /// A list of import paths that should trigger the rule.
     #[serde(skip_serializing_if = "FxHashMap::is_empty")]
     paths: FxHashMap<Box<str>, CustomRestrictedImport>,
+
+    /// A list of glob patterns of import paths that should trigger the rule.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    patterns: Box<[RestrictedImportPattern]>,
+}
+
+impl RestrictedImportsOptions {
+    /// Returns the restriction of `import_source`, if any.
+    fn restriction_of(&self, import_source: &str) -> Option<CustomRestrictedImportOptions> {
+        if let Some(restricted_import) = self.paths.get(import_source) {
+            return Some(restricted_import.clone().into());
+        }
+        let candidate = CandidatePath::new(&import_source);
+        self.patterns
+            .iter()
+            .find(|pattern| candidate.matches_with_exceptions(&pattern.group))
+            .map(|pattern| pattern.clone().into())
+    }
 }
 
 /// Specifies why a specific import is allowed or disallowed.
@@ -359,6 +426,7 @@ impl ImportRestrictionStatus {
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+#[deserializable(with_validator)]
 pub struct CustomRestrictedImportOptions {
     /// The message to display when this module is imported.
     #[serde(skip_serializing_if = "str::is_empty")]
@@ -368,7 +436,7 @@ pub struct CustomRestrictedImportOptions {
     #[serde(skip_serializing_if = "<[_]>::is_empty")]
     import_names: Box<[Box<str>]>,
 
-    /// Names of the exported members that allowed to be not be used.
+    /// Names of the exported members that are allowed to be used.
     #[serde(skip_serializing_if = "<[_]>::is_empty")]
     allow_import_names: Box<[Box<str>]>,
 }
@@ -426,6 +494,27 @@ impl CustomRestrictedImportOptions {
     }
 }
 
+impl DeserializableValidator for CustomRestrictedImportOptions {
+    fn validate(
+        &mut self,
+        _name: &str,
+        range: TextRange,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> bool {
+        if !self.import_names.is_empty() && !self.allow_import_names.is_empty() {
+            diagnostics.push(
+                DeserializationDiagnostic::new(markup! {
+                    "The options "<Emphasis>"importNames"</Emphasis>" and "<Emphasis>"allowImportNames"</Emphasis>" can't be used together."
+                })
+                .with_range(range)
+                .with_note("Specify either the names that are forbidden, or the names that are allowed."),
+            );
+            return false;
+        }
+        true
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
@@ -449,6 +538,98 @@ impl From<CustomRestrictedImport> for CustomRestrictedImportOptions {
     }
 }
 
+/// A group of import paths selected by globs, and its restriction.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default)]
+pub struct RestrictedImportPattern {
+    /// The globs of the import paths. The globs prefixed with `!` are exceptions.
+    group: Box<[Glob]>,
+
+    /// The restriction of the import paths of the group.
+    #[serde(flatten)]
+    restriction: CustomRestrictedImportOptions,
+}
+
+impl From<RestrictedImportPattern> for CustomRestrictedImportOptions {
+    fn from(pattern: RestrictedImportPattern) -> Self {
+        pattern.restriction
+    }
+}
+
+const ALLOWED_PATTERN_OPTIONS: &[&str] = &["group", "message", "importNames", "allowImportNames"];
+
+impl Deserializable for RestrictedImportPattern {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(RestrictedImportPatternVisitor, name, diagnostics)
+    }
+}
+
+struct RestrictedImportPatternVisitor;
+impl DeserializationVisitor for RestrictedImportPatternVisitor {
+    type Output = RestrictedImportPattern;
+
+    const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = RestrictedImportPattern::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            let restriction = &mut result.restriction;
+            match key_text.text() {
+                "group" => {
+                    if let Some(group) = Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.group = group;
+                    }
+                }
+                "message" => {
+                    if let Some(message) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        restriction.message = message;
+                    }
+                }
+                "importNames" => {
+                    if let Some(import_names) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        restriction.import_names = import_names;
+                    }
+                }
+                "allowImportNames" => {
+                    if let Some(allow_import_names) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        restriction.allow_import_names = allow_import_names;
+                    }
+                }
+                unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    unknown_key,
+                    key.range(),
+                    ALLOWED_PATTERN_OPTIONS,
+                )),
+            }
+        }
+        if !result.restriction.validate(name, range, diagnostics) {
+            return None;
+        }
+        Some(result)
+    }
+}
+
 impl Deserializable for CustomRestrictedImport {
     fn deserialize(
         value: &impl DeserializableValue,
@@ -945,11 +1126,9 @@ impl Rule for NoRestrictedImports {
         let import_source_text = inner_string_text(&module_name);
         let import_source = import_source_text.text();
 
-        let Some(restricted_import_settings) = ctx.options().paths.get(import_source) else {
+        let Some(restricted_import) = ctx.options().restriction_of(import_source) else {
             return vec![];
         };
-        let restricted_import: CustomRestrictedImportOptions =
-            restricted_import_settings.clone().into();
 
         match node {
             AnyJsImportLike::JsModuleSource(module_source_node) => {
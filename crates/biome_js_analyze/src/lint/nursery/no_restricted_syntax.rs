@@ -0,0 +1,283 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize::{
+    Deserializable, DeserializableValidator, DeserializableValue, DeserializationDiagnostic,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_grit_patterns::{
+    compile_pattern, GritQuery, GritQueryResult, GritTargetFile, JsTargetLanguage,
+};
+use biome_js_syntax::AnyJsRoot;
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, TextRange, TextSize};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Disallow the code matched by the configured patterns.
+    ///
+    /// This rule lets a team ban a specific construct, such as a function call or a statement,
+    /// without writing a plugin.
+    /// Every code that matches one of the patterns of the option `patterns` is reported,
+    /// with the message of the pattern when it has one.
+    ///
+    /// A pattern is a [GritQL](https://biomejs.dev/reference/gritql/) query.
+    /// It's usually a code snippet with metavariables, such as `` `console.log($message)` ``,
+    /// that can be restricted further with a `where` clause.
+    /// The rewrites of the patterns are ignored: the rule only reports the matched code.
+    ///
+    /// The rule doesn't report anything when no pattern is configured.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "patterns": [
+    ///             {
+    ///                 "pattern": "`setTimeout($callback, $delay)`",
+    ///                 "message": "Use the scheduler of the application instead of setTimeout()."
+    ///             },
+    ///             { "pattern": "`debugger`" }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// setTimeout(refresh, 1000);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// function pause() {
+    ///     debugger;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,use_options
+    /// scheduler.schedule(refresh, 1000);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `patterns`
+    ///
+    /// The restricted patterns.
+    /// Every pattern is an object with the following properties:
+    ///
+    /// - `pattern`: the GritQL query of the restricted code.
+    /// - `message`: the message of the diagnostic. It defaults to a generic message.
+    ///
+    /// A pattern that isn't a valid GritQL query is reported when the configuration is loaded.
+    ///
+    pub NoRestrictedSyntax {
+        version: "next",
+        name: "noRestrictedSyntax",
+        language: "js",
+        sources: &[RuleSource::Eslint("no-restricted-syntax")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noRestrictedSyntax`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoRestrictedSyntaxOptions {
+    /// The restricted patterns.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub patterns: Box<[RestrictedPattern]>,
+}
+
+/// A pattern of the option `patterns`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[deserializable(with_validator)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct RestrictedPattern {
+    /// The GritQL query of the restricted code.
+    pub pattern: GritPattern,
+    /// The message of the diagnostic.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Box<str>>,
+}
+
+impl DeserializableValidator for RestrictedPattern {
+    fn validate(
+        &mut self,
+        _name: &str,
+        range: TextRange,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> bool {
+        if self.pattern.as_str().trim().is_empty() {
+            diagnostics.push(
+                DeserializationDiagnostic::new(markup! {
+                    "The "<Emphasis>"pattern"</Emphasis>" of a restricted pattern can't be empty."
+                })
+                .with_range(range),
+            );
+            return false;
+        }
+        if self.pattern.query().is_none() {
+            diagnostics.push(
+                DeserializationDiagnostic::new(markup! {
+                    "The pattern "<Emphasis>{self.pattern.as_str()}</Emphasis>" isn't a valid GritQL query."
+                })
+                .with_range(range)
+                .with_note("Biome ignores this pattern until the issue is fixed."),
+            );
+            return false;
+        }
+        true
+    }
+}
+
+/// A GritQL query, compiled once when the configuration is deserialized.
+///
+/// The compiled query is shared by the copies of the options.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub struct GritPattern {
+    source: Box<str>,
+    /// The compiled query, or `None` when the source isn't a valid GritQL query.
+    query: Option<Arc<GritQuery>>,
+}
+
+impl GritPattern {
+    /// Returns the source of the query.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the compiled query, or `None` when the source isn't a valid GritQL query.
+    pub fn query(&self) -> Option<&GritQuery> {
+        self.query.as_deref()
+    }
+}
+
+impl From<String> for GritPattern {
+    fn from(source: String) -> Self {
+        let query = compile_pattern(&source, None, JsTargetLanguage.into()).ok();
+        Self {
+            source: source.into_boxed_str(),
+            query: query.map(Arc::new),
+        }
+    }
+}
+
+impl From<GritPattern> for String {
+    fn from(value: GritPattern) -> Self {
+        value.source.into()
+    }
+}
+
+// The invalid queries are reported by the validator of `RestrictedPattern`.
+impl Deserializable for GritPattern {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        String::deserialize(value, name, diagnostics).map(Self::from)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for GritPattern {
+    fn schema_name() -> String {
+        "GritPattern".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+impl Eq for GritPattern {}
+
+impl PartialEq for GritPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+/// A code matched by a restricted pattern.
+pub struct RestrictedMatch {
+    range: TextRange,
+    /// The index of the pattern in the option `patterns`.
+    pattern_index: usize,
+}
+
+impl Rule for NoRestrictedSyntax {
+    type Query = Ast<AnyJsRoot>;
+    type State = RestrictedMatch;
+    type Signals = Box<[Self::State]>;
+    type Options = Box<NoRestrictedSyntaxOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let patterns = &ctx.options().patterns;
+        if patterns.is_empty() {
+            return Box::default();
+        }
+        let Some(root) = ctx.query().syntax().as_send() else {
+            return Box::default();
+        };
+        let mut signals = Vec::new();
+        for (pattern_index, pattern) in patterns.iter().enumerate() {
+            let Some(query) = pattern.pattern.query() else {
+                continue;
+            };
+            let file = GritTargetFile {
+                path: ctx.file_path().to_path_buf(),
+                parse: AnyParse::new(root.clone(), Vec::new()),
+            };
+            let Ok((results, _logs)) = query.execute(file) else {
+                continue;
+            };
+            let ranges = results.into_iter().flat_map(|result| match result {
+                GritQueryResult::Match(matched) => matched.ranges,
+                GritQueryResult::Rewrite(rewrite) => rewrite.original.ranges,
+                GritQueryResult::CreateFile(_) => Vec::new(),
+            });
+            signals.extend(ranges.map(|range| RestrictedMatch {
+                range: TextRange::new(
+                    TextSize::from(range.start_byte),
+                    TextSize::from(range.end_byte),
+                ),
+                pattern_index,
+            }));
+        }
+        signals.sort_by_key(|signal| (signal.range.start(), signal.pattern_index));
+        signals.dedup_by_key(|signal| (signal.range, signal.pattern_index));
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let pattern = ctx.options().patterns.get(state.pattern_index)?;
+        let diagnostic = match &pattern.message {
+            Some(message) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! { {message.as_ref()} },
+            ),
+            None => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This code is restricted."
+                },
+            )
+            .note(markup! {
+                "It matches the restricted pattern "<Emphasis>{pattern.pattern.as_str()}</Emphasis>"."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
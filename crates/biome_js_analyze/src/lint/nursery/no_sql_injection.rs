@@ -0,0 +1,321 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, AnyJsExpression, AnyJsTemplateElement, JsBinaryOperator,
+    JsCallExpression, JsReferenceIdentifier, JsTemplateExpression, TextRange,
+};
+use biome_rowan::{AstNode, TokenText};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow building the queries passed to a database with template literals that interpolate values.
+    ///
+    /// A query such as ``db.query(`SELECT * FROM users WHERE id = ${id}`)`` is vulnerable to SQL injection:
+    /// an attacker who controls `id` can change the meaning of the query, and read or modify any data.
+    /// The values should be passed as parameters of the query instead, so the database never interprets them as SQL.
+    ///
+    /// The rule reports the template literals passed as first argument to the query functions, such as `db.query()`
+    /// or `knex.raw()`, that interpolate a value that isn't known to be safe.
+    /// The template literal can also be declared by a `const` variable that is passed to the query function.
+    ///
+    /// The rule follows the `const` variables to tell the safe values apart. The following interpolated values are safe:
+    ///
+    /// - the literals, such as `10` or `"users"`, and the `const` variables initialized with a safe value;
+    /// - the arithmetic operations, the comparisons, and the unary operations, which produce numbers or booleans;
+    /// - the concatenations, the conditional expressions, and the logical expressions of safe values,
+    ///   such as `asc ? "ASC" : "DESC"`;
+    /// - the results of `Number()`, `parseInt()`, and `parseFloat()`.
+    ///
+    /// The tagged templates, such as ``sql`SELECT * FROM users WHERE id = ${id}` ``, are ignored,
+    /// since the query libraries use them to build parameterized queries.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// db.query(`SELECT * FROM users WHERE id = ${request.params.id}`);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const query = `DELETE FROM posts WHERE author = '${author}'`;
+    /// await knex.raw(query);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// db.query("SELECT * FROM users WHERE id = $1", [request.params.id]);
+    /// ```
+    ///
+    /// ```js
+    /// const table = "users";
+    /// db.query(`SELECT * FROM ${table} ORDER BY name ${asc ? "ASC" : "DESC"} LIMIT ${Number(limit)}`);
+    /// ```
+    ///
+    /// ```js
+    /// db.query(sql`SELECT * FROM users WHERE id = ${id}`);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `sinks`
+    ///
+    /// The functions that execute a query, written as the end of the callee:
+    /// `db.query` matches `db.query()` and `this.db.query()`.
+    /// Setting this option replaces the default list:
+    /// `db.query`, `db.execute`, `db.raw`, `knex.raw`, `pool.query`, `pool.execute`, `connection.query`,
+    /// `connection.execute`, `client.query`, `sequelize.query`, `prisma.$queryRawUnsafe`, and `prisma.$executeRawUnsafe`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "sinks": ["db.query", "repository.runQuery"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// repository.runQuery(`SELECT * FROM users WHERE name = '${name}'`);
+    /// ```
+    ///
+    pub NoSqlInjection {
+        version: "next",
+        name: "noSqlInjection",
+        language: "js",
+        sources: &[RuleSource::EslintSonarJs("sql-queries")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noSqlInjection`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoSqlInjectionOptions {
+    /// The functions that execute a query, written as the end of the callee, e.g. `db.query`.
+    sinks: Box<[Box<str>]>,
+}
+
+impl Default for NoSqlInjectionOptions {
+    fn default() -> Self {
+        Self {
+            sinks: [
+                "db.query",
+                "db.execute",
+                "db.raw",
+                "knex.raw",
+                "pool.query",
+                "pool.execute",
+                "connection.query",
+                "connection.execute",
+                "client.query",
+                "sequelize.query",
+                "prisma.$queryRawUnsafe",
+                "prisma.$executeRawUnsafe",
+            ]
+            .into_iter()
+            .map(Box::from)
+            .collect(),
+        }
+    }
+}
+
+/// The maximum number of `const` variables followed to resolve a value.
+const MAX_RESOLUTION_DEPTH: u8 = 8;
+
+impl Rule for NoSqlInjection {
+    type Query = Semantic<JsCallExpression>;
+    /// The range of the interpolation of an unsafe value.
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = NoSqlInjectionOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let model = ctx.model();
+        let path = callee_path(&call.callee().ok()?)?;
+        let is_sink = ctx.options().sinks.iter().any(|sink| {
+            let length = sink.split('.').count();
+            length <= path.len()
+                && path[path.len() - length..]
+                    .iter()
+                    .zip(sink.split('.'))
+                    .all(|(name, segment)| name.text() == segment)
+        });
+        if !is_sink {
+            return None;
+        }
+        let [Some(query)] = call.arguments().ok()?.get_arguments_by_index([0]) else {
+            return None;
+        };
+        let template = query_template(query.as_any_js_expression()?, model)?;
+        template.elements().into_iter().find_map(|element| {
+            let AnyJsTemplateElement::JsTemplateElement(element) = element else {
+                return None;
+            };
+            let expression = element.expression().ok()?;
+            (!is_safe_value(&expression, model, 0)).then(|| element.range())
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This query interpolates a value that isn't known to be safe."
+                },
+            )
+            .note(markup! {
+                "An attacker who controls this value can change the meaning of the query, and read or modify any data."
+            })
+            .note(markup! {
+                "Use a placeholder in the query, such as "<Emphasis>"?"</Emphasis>" or "<Emphasis>"$1"</Emphasis>", and pass the value as a parameter of the query."
+            }),
+        )
+    }
+}
+
+/// Returns the names of the chain of static members of `callee`,
+/// e.g. `["db", "query"]` for `this.db.query`.
+fn callee_path(callee: &AnyJsExpression) -> Option<Vec<TokenText>> {
+    let mut path = Vec::new();
+    let mut expression = callee.clone().omit_parentheses();
+    loop {
+        match expression {
+            AnyJsExpression::JsStaticMemberExpression(member) => {
+                let name = member.member().ok()?.value_token().ok()?;
+                path.push(name.token_text_trimmed());
+                expression = member.object().ok()?.omit_parentheses();
+            }
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                let name = identifier.name().ok()?.value_token().ok()?;
+                path.push(name.token_text_trimmed());
+                break;
+            }
+            AnyJsExpression::JsThisExpression(_) => break,
+            _ => return None,
+        }
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Returns the untagged template literal of `query`, either directly or through a `const` variable.
+fn query_template(query: &AnyJsExpression, model: &SemanticModel) -> Option<JsTemplateExpression> {
+    match query.clone().omit_parentheses() {
+        AnyJsExpression::JsTemplateExpression(template) => {
+            template.tag().is_none().then_some(template)
+        }
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let initializer = const_initializer(&identifier.name().ok()?, model)?;
+            let template = initializer.omit_parentheses();
+            let template = template.as_js_template_expression()?;
+            template.tag().is_none().then(|| template.clone())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the initializer of the `const` variable that `reference` refers to.
+fn const_initializer(
+    reference: &JsReferenceIdentifier,
+    model: &SemanticModel,
+) -> Option<AnyJsExpression> {
+    let binding = model.binding(reference)?;
+    let AnyJsBindingDeclaration::JsVariableDeclarator(declarator) = binding.tree().declaration()?
+    else {
+        return None;
+    };
+    if !declarator.declaration()?.is_const() {
+        return None;
+    }
+    declarator.initializer()?.expression().ok()
+}
+
+/// Returns `true` if `expression` can't contain text chosen by an attacker.
+fn is_safe_value(expression: &AnyJsExpression, model: &SemanticModel, depth: u8) -> bool {
+    if depth > MAX_RESOLUTION_DEPTH {
+        return false;
+    }
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(_) | AnyJsExpression::JsUnaryExpression(_) => true,
+        AnyJsExpression::JsTemplateExpression(template) => {
+            template.tag().is_none()
+                && template
+                    .elements()
+                    .into_iter()
+                    .all(|element| match element {
+                        AnyJsTemplateElement::JsTemplateChunkElement(_) => true,
+                        AnyJsTemplateElement::JsTemplateElement(element) => element
+                            .expression()
+                            .is_ok_and(|expression| is_safe_value(&expression, model, depth)),
+                    })
+        }
+        AnyJsExpression::JsBinaryExpression(binary) => match binary.operator() {
+            // `+` concatenates the strings
+            Ok(JsBinaryOperator::Plus) => {
+                binary
+                    .left()
+                    .is_ok_and(|left| is_safe_value(&left, model, depth))
+                    && binary
+                        .right()
+                        .is_ok_and(|right| is_safe_value(&right, model, depth))
+            }
+            // The other operators produce numbers or booleans
+            Ok(_) => true,
+            Err(_) => false,
+        },
+        AnyJsExpression::JsLogicalExpression(logical) => {
+            logical
+                .left()
+                .is_ok_and(|left| is_safe_value(&left, model, depth))
+                && logical
+                    .right()
+                    .is_ok_and(|right| is_safe_value(&right, model, depth))
+        }
+        AnyJsExpression::JsConditionalExpression(conditional) => {
+            conditional
+                .consequent()
+                .is_ok_and(|consequent| is_safe_value(&consequent, model, depth))
+                && conditional
+                    .alternate()
+                    .is_ok_and(|alternate| is_safe_value(&alternate, model, depth))
+        }
+        AnyJsExpression::JsCallExpression(call) => {
+            let Ok(AnyJsExpression::JsIdentifierExpression(callee)) =
+                call.callee().map(AnyJsExpression::omit_parentheses)
+            else {
+                return false;
+            };
+            let Ok(reference) = callee.name() else {
+                return false;
+            };
+            model.binding(&reference).is_none()
+                && reference.value_token().is_ok_and(|name| {
+                    matches!(name.text_trimmed(), "Number" | "parseInt" | "parseFloat")
+                })
+        }
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let Ok(reference) = identifier.name() else {
+                return false;
+            };
+            if model.binding(&reference).is_none() {
+                return reference.value_token().is_ok_and(|name| {
+                    matches!(name.text_trimmed(), "undefined" | "NaN" | "Infinity")
+                });
+            }
+            const_initializer(&reference, model)
+                .is_some_and(|initializer| is_safe_value(&initializer, model, depth + 1))
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,495 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    static_value::StaticValue, AnyJsExpression, JsCallArguments, JsCallExpression, JsNewExpression,
+    JsRegexLiteralExpression, JsSyntaxKind,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, TextRange, TextSize};
+
+use crate::utils::regex::{parse_regex, CharSet, RegexFlags, RegexNode};
+
+declare_lint_rule! {
+    /// Disallow the regular expressions that can take super-linear time to match some strings.
+    ///
+    /// When a string doesn't match a regular expression, the regex engine of JavaScript backtracks:
+    /// it tries every way of splitting the string between the quantifiers of the pattern.
+    /// When several parts of the pattern can match the same characters, the number of ways grows
+    /// exponentially, or polynomially, with the length of the string.
+    /// A crafted string can then block the program for a long time,
+    /// which is known as a [regular expression denial of service (ReDoS)](https://owasp.org/www-community/attacks/Regular_expression_Denial_of_Service_-_ReDoS).
+    ///
+    /// The rule analyzes the regular expression literals, and the calls to `RegExp` with a constant pattern.
+    /// It reports:
+    ///
+    /// - the repeated groups that contain a quantifier that can match the same characters as the repetitions,
+    ///   such as `(a+)+` or `(\w+\s?)*`, which can take exponential time;
+    /// - the repeated groups whose alternatives can match the same characters, such as `(\w|\d)+`,
+    ///   which can take exponential time;
+    /// - the adjacent quantifiers that can match the same characters, such as `\d+\d*` or `\s*.*`,
+    ///   which can take quadratic time.
+    ///
+    /// The analysis approximates the characters that each part of the pattern can match,
+    /// and ignores the lookarounds and the backreferences.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const email = /^([a-z0-9]+\.?)+@example\.com$/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const words = new RegExp("^(\\w|\\d)+$");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const trailing = /^(.*)\s*$/;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const email = /^[a-z0-9]+(\.[a-z0-9]+)*@example\.com$/;
+    /// ```
+    ///
+    /// ```js
+    /// const list = /^\w+(,\s*\w+)*$/;
+    /// ```
+    ///
+    pub NoSuperLinearRegex {
+        version: "next",
+        name: "noSuperLinearRegex",
+        language: "js",
+        sources: &[RuleSource::EslintSonarJs("slow-regex")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsRegexExpression = JsRegexLiteralExpression | JsNewExpression | JsCallExpression
+}
+
+pub struct SuperLinearPattern {
+    /// The range of the reported part of the pattern.
+    range: TextRange,
+    kind: SuperLinearKind,
+}
+
+pub enum SuperLinearKind {
+    /// A repeated group that contains a quantifier that can match the same characters as its repetitions.
+    NestedQuantifier { inner: TextRange },
+    /// A repeated group whose alternatives can match the same characters.
+    OverlappingAlternatives,
+    /// Adjacent quantifiers that can match the same characters.
+    AdjacentQuantifiers { other: TextRange },
+}
+
+impl Rule for NoSuperLinearRegex {
+    type Query = Ast<AnyJsRegexExpression>;
+    type State = SuperLinearPattern;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(source) = RegexSource::from_expression(ctx.query()) else {
+            return Box::default();
+        };
+        let flags = RegexFlags::parse(&source.flags);
+        let Some(root) = parse_regex(&source.pattern, flags) else {
+            return Box::default();
+        };
+        let mut patterns = Vec::new();
+        find_super_linear_patterns(&root, &source.pattern, flags, &mut patterns);
+        patterns
+            .into_iter()
+            .map(|pattern| SuperLinearPattern {
+                range: source.file_range(pattern.range),
+                kind: match pattern.kind {
+                    SuperLinearKind::NestedQuantifier { inner } => {
+                        SuperLinearKind::NestedQuantifier {
+                            inner: source.file_range(inner),
+                        }
+                    }
+                    SuperLinearKind::AdjacentQuantifiers { other } => {
+                        SuperLinearKind::AdjacentQuantifiers {
+                            other: source.file_range(other),
+                        }
+                    }
+                    SuperLinearKind::OverlappingAlternatives => {
+                        SuperLinearKind::OverlappingAlternatives
+                    }
+                },
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state.kind {
+            SuperLinearKind::NestedQuantifier { inner } => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This repeated group contains a quantifier that can match the same characters, which can take exponential time."
+                },
+            )
+            .detail(inner, markup! {
+                "The characters matched by this quantifier can also be matched by several repetitions of the group."
+            }),
+            SuperLinearKind::OverlappingAlternatives => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The alternatives of this repeated group can match the same characters, which can take exponential time."
+                },
+            ),
+            SuperLinearKind::AdjacentQuantifiers { other } => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This quantifier and the next one can match the same characters, which can take quadratic time."
+                },
+            )
+            .detail(other, markup! {
+                "This quantifier can match the same characters."
+            }),
+        };
+        Some(
+            diagnostic
+                .note(markup! {
+                    "When a string doesn't match, the regex engine tries every way of splitting it between the quantifiers. A crafted string can block the program: this is known as a regular expression denial of service (ReDoS)."
+                })
+                .note(markup! {
+                    "Rewrite the pattern so that each character can only be matched in one way, for example by making the repeated parts mutually exclusive."
+                }),
+        )
+    }
+}
+
+/// The pattern of a regular expression, and how to locate it in the file.
+struct RegexSource {
+    pattern: String,
+    flags: String,
+    /// The position of the pattern in the file.
+    start: TextSize,
+    /// The offset, relative to `start`, of each byte of the pattern and of its end,
+    /// when the pattern is the value of a string literal with escape sequences.
+    offsets: Option<Vec<TextSize>>,
+}
+
+impl RegexSource {
+    fn from_expression(node: &AnyJsRegexExpression) -> Option<Self> {
+        let (callee, arguments) = match node {
+            AnyJsRegexExpression::JsRegexLiteralExpression(literal) => {
+                let (pattern, flags) = literal.decompose().ok()?;
+                return Some(Self {
+                    pattern: pattern.text().to_string(),
+                    flags: flags.text().to_string(),
+                    start: literal.range().start() + TextSize::from(1),
+                    offsets: None,
+                });
+            }
+            AnyJsRegexExpression::JsNewExpression(expression) => {
+                (expression.callee().ok()?, expression.arguments()?)
+            }
+            AnyJsRegexExpression::JsCallExpression(expression) => {
+                (expression.callee().ok()?, expression.arguments().ok()?)
+            }
+        };
+        Self::from_regexp_call(&callee, &arguments)
+    }
+
+    /// Returns the pattern of `RegExp(pattern, flags)`, if it's a constant string.
+    fn from_regexp_call(callee: &AnyJsExpression, arguments: &JsCallArguments) -> Option<Self> {
+        if !callee
+            .as_js_reference_identifier()
+            .is_some_and(|name| name.has_name("RegExp"))
+        {
+            return None;
+        }
+        let mut arguments = arguments.args().iter();
+        let pattern = arguments
+            .next()?
+            .ok()?
+            .as_any_js_expression()?
+            .as_static_value()?;
+        let StaticValue::String(token) = &pattern else {
+            return None;
+        };
+        let flags = match arguments.next() {
+            Some(argument) => match argument.ok()?.as_any_js_expression()?.as_static_value()? {
+                flags @ (StaticValue::String(_) | StaticValue::EmptyString(_)) => {
+                    flags.text().to_string()
+                }
+                _ => return None,
+            },
+            None => String::new(),
+        };
+        let quote_length = if token.kind() == JsSyntaxKind::JS_STRING_LITERAL {
+            TextSize::from(1)
+        } else {
+            TextSize::from(0)
+        };
+        let (pattern, offsets) = unescape_string(pattern.text())?;
+        Some(Self {
+            pattern,
+            flags,
+            start: token.text_trimmed_range().start() + quote_length,
+            offsets: Some(offsets),
+        })
+    }
+
+    /// Returns the range in the file of `range`, a range of the pattern.
+    fn file_range(&self, range: TextRange) -> TextRange {
+        let range = match &self.offsets {
+            Some(offsets) => TextRange::new(
+                offsets[usize::from(range.start())],
+                offsets[usize::from(range.end())],
+            ),
+            None => range,
+        };
+        range + self.start
+    }
+}
+
+/// Returns the value of the string literal text `raw`, without its quotes,
+/// and the offset in `raw` of each byte of the value and of its end.
+///
+/// Returns [None] for the legacy octal escape sequences, and for the lone surrogates.
+fn unescape_string(raw: &str) -> Option<(String, Vec<TextSize>)> {
+    let mut value = String::with_capacity(raw.len());
+    let mut offsets = Vec::with_capacity(raw.len() + 1);
+    let mut chars = raw.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let c = if c == '\\' {
+            let (_, escaped) = chars.next()?;
+            match escaped {
+                'n' => '\n',
+                't' => '\t',
+                'r' => '\r',
+                'b' => '\u{8}',
+                'f' => '\u{C}',
+                'v' => '\u{B}',
+                '0' if !chars.peek().is_some_and(|(_, c)| c.is_ascii_digit()) => '\0',
+                '0'..='9' => return None,
+                'x' => char::from_u32(take_hex_digits(&mut chars, 2)?)?,
+                'u' if chars.next_if(|(_, c)| *c == '{').is_some() => {
+                    let mut code_point = 0u32;
+                    loop {
+                        let (_, c) = chars.next()?;
+                        if c == '}' {
+                            break;
+                        }
+                        code_point = code_point.checked_mul(16)? + c.to_digit(16)?;
+                    }
+                    char::from_u32(code_point)?
+                }
+                'u' => char::from_u32(take_hex_digits(&mut chars, 4)?)?,
+                // Line continuations
+                '\r' => {
+                    chars.next_if(|(_, c)| *c == '\n');
+                    continue;
+                }
+                '\n' | '\u{2028}' | '\u{2029}' => continue,
+                escaped => escaped,
+            }
+        } else {
+            c
+        };
+        offsets.extend(std::iter::repeat(TextSize::from(index as u32)).take(c.len_utf8()));
+        value.push(c);
+    }
+    offsets.push(TextSize::from(raw.len() as u32));
+    Some((value, offsets))
+}
+
+fn take_hex_digits(
+    chars: &mut std::iter::Peekable<std::str::CharIndices>,
+    count: usize,
+) -> Option<u32> {
+    let mut value = 0;
+    for _ in 0..count {
+        let (_, c) = chars.next()?;
+        value = value * 16 + c.to_digit(16)?;
+    }
+    Some(value)
+}
+
+/// A super-linear part of a pattern, with ranges relative to the pattern.
+struct PatternMatch {
+    range: TextRange,
+    kind: SuperLinearKind,
+}
+
+/// Collects the super-linear parts of `node`, without the parts nested in a reported part.
+fn find_super_linear_patterns(
+    node: &RegexNode,
+    pattern: &str,
+    flags: RegexFlags,
+    matches: &mut Vec<PatternMatch>,
+) {
+    match node {
+        RegexNode::Group { kind, .. } if kind.is_lookaround() => return,
+        RegexNode::Quantified {
+            range,
+            quantifier,
+            body,
+        } if quantifier.max.is_none() => {
+            if let Some(inner) = find_ambiguous_quantifier(body, flags) {
+                matches.push(PatternMatch {
+                    range: *range,
+                    kind: SuperLinearKind::NestedQuantifier { inner },
+                });
+                return;
+            }
+            if has_overlapping_alternatives(body, pattern, flags) {
+                matches.push(PatternMatch {
+                    range: *range,
+                    kind: SuperLinearKind::OverlappingAlternatives,
+                });
+                return;
+            }
+        }
+        RegexNode::Alternative { terms, .. } => {
+            find_adjacent_quantifiers(terms, flags, matches);
+        }
+        _ => {}
+    }
+    for child in node.children() {
+        find_super_linear_patterns(child, pattern, flags, matches);
+    }
+}
+
+/// Returns the range of an unbounded quantifier of `body`, the body of a repeated group,
+/// if the characters that it matches can also be matched by the rest of the group.
+fn find_ambiguous_quantifier(body: &RegexNode, flags: RegexFlags) -> Option<TextRange> {
+    let mut stack = vec![body];
+    while let Some(node) = stack.pop() {
+        match node {
+            RegexNode::Group { kind, .. } if kind.is_lookaround() => continue,
+            RegexNode::Quantified {
+                range,
+                quantifier,
+                body: quantified,
+            } if quantifier.max.is_none() => {
+                let set = quantified.char_set(flags);
+                let mut required = Vec::new();
+                collect_required_sets(body, node, flags, &mut required);
+                if !set.is_empty() && required.iter().all(|other| other.intersects(&set)) {
+                    return Some(*range);
+                }
+            }
+            _ => {}
+        }
+        stack.extend(node.children().iter().rev());
+    }
+    None
+}
+
+/// Collects the characters of the terms of `node` that must match for `target` to match,
+/// without the terms that contain `target`.
+fn collect_required_sets(
+    node: &RegexNode,
+    target: &RegexNode,
+    flags: RegexFlags,
+    sets: &mut Vec<CharSet>,
+) {
+    if std::ptr::eq(node, target) {
+        return;
+    }
+    match node {
+        RegexNode::Alternative { terms, .. } => {
+            for term in terms {
+                if contains(term, target) {
+                    collect_required_sets(term, target, flags, sets);
+                } else if !term.can_match_empty() {
+                    sets.push(term.char_set(flags));
+                }
+            }
+        }
+        _ => {
+            if let Some(child) = node.children().iter().find(|child| contains(child, target)) {
+                collect_required_sets(child, target, flags, sets);
+            }
+        }
+    }
+}
+
+fn contains(node: &RegexNode, target: &RegexNode) -> bool {
+    std::ptr::eq(node, target) || node.children().iter().any(|child| contains(child, target))
+}
+
+/// Returns `true` if two alternatives of `body`, the body of a repeated group,
+/// are the same, or match a single character that belongs to both.
+fn has_overlapping_alternatives(body: &RegexNode, pattern: &str, flags: RegexFlags) -> bool {
+    let RegexNode::Disjunction { alternatives, .. } = body.omit_groups() else {
+        return false;
+    };
+    let single_characters: Vec<_> = alternatives
+        .iter()
+        .filter_map(|alternative| match alternative.omit_groups() {
+            node @ (RegexNode::Character { .. }
+            | RegexNode::Dot { .. }
+            | RegexNode::CharacterClassEscape { .. }
+            | RegexNode::CharacterClass { .. }) => Some(node.char_set(flags)),
+            _ => None,
+        })
+        .collect();
+    let has_overlapping_characters = single_characters.iter().enumerate().any(|(index, set)| {
+        single_characters[index + 1..]
+            .iter()
+            .any(|other| set.intersects(other))
+    });
+    has_overlapping_characters
+        || alternatives.iter().enumerate().any(|(index, alternative)| {
+            let text = &pattern[alternative.range()];
+            !text.is_empty()
+                && alternatives[index + 1..]
+                    .iter()
+                    .any(|other| &pattern[other.range()] == text)
+        })
+}
+
+/// Collects the unbounded quantifiers of `terms` that are followed by an unbounded quantifier
+/// that can match the same characters, with only optional terms between them.
+///
+/// The end of the first quantifier must be able to match the characters of the second one,
+/// and the start of the second one the characters of the first one, so that they can share
+/// the characters between them.
+fn find_adjacent_quantifiers(
+    terms: &[RegexNode],
+    flags: RegexFlags,
+    matches: &mut Vec<PatternMatch>,
+) {
+    for (index, term) in terms.iter().enumerate() {
+        if !is_unbounded_quantifier(term) {
+            continue;
+        }
+        let set = term.char_set(flags);
+        let last_set = term.last_char_set(flags);
+        for next in &terms[index + 1..] {
+            if is_unbounded_quantifier(next)
+                && next.first_char_set(flags).intersects(&set)
+                && next.char_set(flags).intersects(&last_set)
+            {
+                matches.push(PatternMatch {
+                    range: term.range(),
+                    kind: SuperLinearKind::AdjacentQuantifiers {
+                        other: next.range(),
+                    },
+                });
+                break;
+            }
+            if !next.can_match_empty() {
+                break;
+            }
+        }
+    }
+}
+
+fn is_unbounded_quantifier(node: &RegexNode) -> bool {
+    matches!(node.omit_groups(), RegexNode::Quantified { quantifier, .. } if quantifier.max.is_none())
+}
@@ -0,0 +1,336 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_glob::{CandidatePath, Glob};
+use biome_js_semantic::{Binding, SemanticModel};
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, function_ext::AnyFunctionLike, AnyJsBindingPattern,
+    AnyJsExpression, AnyJsImportLike, JsCallArgumentList, JsCallArguments, JsCallExpression,
+    JsImport, JsSyntaxToken, TextRange,
+};
+use biome_rowan::{AstNode, TokenText};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow the synchronous file system and child process functions in async functions and request handlers.
+    ///
+    /// Node.js runs the JavaScript code of an application on a single thread, the event loop.
+    /// The synchronous functions of the modules `node:fs` and `node:child_process`, such as `readFileSync()` or `execSync()`,
+    /// block this thread until the operation completes: while they run, the application can't handle any other request or event.
+    /// They are fine in a script or when a module is loaded, but not in the code that runs while the application handles requests.
+    ///
+    /// The rule reports the calls to these functions in an `async` function, or in a request handler,
+    /// i.e. a function passed to one of the functions listed in the `requestHandlers` option, such as `app.get()`.
+    /// The calls at the top level of a module, and in the other functions, are allowed.
+    ///
+    /// The functions are recognized when they are imported or required from `node:fs`, `fs`, `node:child_process`, or `child_process`.
+    /// All the functions of `node:fs` whose name ends with `Sync` are reported,
+    /// as well as `execSync()`, `execFileSync()`, and `spawnSync()` of `node:child_process`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import fs from "node:fs";
+    ///
+    /// async function loadConfig() {
+    ///     return JSON.parse(fs.readFileSync("config.json", "utf8"));
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import { execSync } from "node:child_process";
+    ///
+    /// app.get("/version", (request, response) => {
+    ///     response.send(execSync("git rev-parse HEAD"));
+    /// });
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { readFile } from "node:fs/promises";
+    ///
+    /// async function loadConfig() {
+    ///     return JSON.parse(await readFile("config.json", "utf8"));
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// import { readFileSync } from "node:fs";
+    ///
+    /// const config = JSON.parse(readFileSync("config.json", "utf8"));
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `requestHandlers`
+    ///
+    /// The functions that register a request handler.
+    /// A function matches when it's called with the same name, e.g. `app.get` doesn't match `server.get`.
+    /// Setting this option replaces the default list:
+    /// `app.all`, `app.delete`, `app.get`, `app.patch`, `app.post`, `app.put`, `app.use`,
+    /// `router.all`, `router.delete`, `router.get`, `router.patch`, `router.post`, `router.put`, `router.use`,
+    /// `http.createServer`, and `https.createServer`.
+    ///
+    /// ### `allowedFiles`
+    ///
+    /// A list of globs that select the files where the synchronous functions can be used, such as the command-line scripts.
+    /// A glob prefixed with `!` is an exception.
+    /// See the [glob syntax](https://biomejs.dev/reference/configuration/#glob-syntax-reference).
+    ///
+    /// Default: `[]`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "requestHandlers": ["server.route"],
+    ///         "allowedFiles": ["scripts/**"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// import { existsSync } from "node:fs";
+    ///
+    /// server.route("/health", () => existsSync("ready"));
+    /// ```
+    ///
+    pub NoSyncFsInAsyncContexts {
+        version: "next",
+        name: "noSyncFsInAsyncContexts",
+        language: "js",
+        sources: &[RuleSource::EslintN("no-sync")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noSyncFsInAsyncContexts`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoSyncFsInAsyncContextsOptions {
+    /// The functions that register a request handler, e.g. `app.get`.
+    request_handlers: Box<[Box<str>]>,
+
+    /// Globs of the files where the synchronous functions can be used. The globs prefixed with `!` are exceptions.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    allowed_files: Box<[Glob]>,
+}
+
+impl Default for NoSyncFsInAsyncContextsOptions {
+    fn default() -> Self {
+        Self {
+            request_handlers: [
+                "app.all",
+                "app.delete",
+                "app.get",
+                "app.patch",
+                "app.post",
+                "app.put",
+                "app.use",
+                "router.all",
+                "router.delete",
+                "router.get",
+                "router.patch",
+                "router.post",
+                "router.put",
+                "router.use",
+                "http.createServer",
+                "https.createServer",
+            ]
+            .into_iter()
+            .map(Box::from)
+            .collect(),
+            allowed_files: [].into(),
+        }
+    }
+}
+
+pub struct BlockingCall {
+    /// The name of the synchronous function.
+    name: TokenText,
+    context: BlockingContext,
+}
+
+pub enum BlockingContext {
+    /// The range of the `async` keyword of the function.
+    AsyncFunction(TextRange),
+    /// The range of the callee that registers the request handler.
+    RequestHandler(TextRange),
+}
+
+impl Rule for NoSyncFsInAsyncContexts {
+    type Query = Semantic<JsCallExpression>;
+    type State = BlockingCall;
+    type Signals = Option<Self::State>;
+    type Options = NoSyncFsInAsyncContextsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let options = ctx.options();
+        if CandidatePath::new(&ctx.file_path()).matches_with_exceptions(&options.allowed_files) {
+            return None;
+        }
+        let name = blocking_function(&call.callee().ok()?, ctx.model())?;
+        let context = call
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .filter_map(AnyFunctionLike::cast)
+            .find_map(|function| {
+                if let Some(async_token) = async_token(&function) {
+                    return Some(BlockingContext::AsyncFunction(
+                        async_token.text_trimmed_range(),
+                    ));
+                }
+                let callee = function
+                    .parent::<JsCallArgumentList>()?
+                    .parent::<JsCallArguments>()?
+                    .parent::<JsCallExpression>()?
+                    .callee()
+                    .ok()?
+                    .omit_parentheses();
+                options
+                    .request_handlers
+                    .iter()
+                    .any(|handler| callee.syntax().text_trimmed() == handler.as_ref())
+                    .then(|| BlockingContext::RequestHandler(callee.range()))
+            })?;
+        Some(BlockingCall { name, context })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.name.text();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().range(),
+            markup! {
+                "The synchronous function "<Emphasis>{name}"()"</Emphasis>" blocks the event loop."
+            },
+        );
+        let diagnostic = match state.context {
+            BlockingContext::AsyncFunction(range) => diagnostic.detail(
+                range,
+                markup! {
+                    "The call is in this async function."
+                },
+            ),
+            BlockingContext::RequestHandler(range) => diagnostic.detail(
+                range,
+                markup! {
+                    "The call is in a request handler registered by this function."
+                },
+            ),
+        };
+        Some(
+            diagnostic
+                .note(markup! {
+                    "While the function runs, the application can't handle the other requests and events."
+                })
+                .note(markup! {
+                    "Use the asynchronous version of the function instead, such as the functions of "<Emphasis>"node:fs/promises"</Emphasis>"."
+                }),
+        )
+    }
+}
+
+/// Returns the `async` keyword of `function`, if any.
+fn async_token(function: &AnyFunctionLike) -> Option<JsSyntaxToken> {
+    match function {
+        AnyFunctionLike::AnyJsFunction(function) => function.async_token(),
+        AnyFunctionLike::JsMethodClassMember(method) => method.async_token(),
+        AnyFunctionLike::JsMethodObjectMember(method) => method.async_token(),
+    }
+}
+
+/// Returns the name of the function called by `callee`
+/// if it's a synchronous function of `node:fs` or `node:child_process`.
+fn blocking_function(callee: &AnyJsExpression, model: &SemanticModel) -> Option<TokenText> {
+    let (name, module) = match callee.clone().omit_parentheses() {
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            let name = member.member().ok()?.value_token().ok()?;
+            let object = member.object().ok()?.omit_parentheses();
+            let reference = object.as_js_identifier_expression()?.name().ok()?;
+            let binding = model.binding(&reference)?;
+            let module = match binding.tree().declaration()? {
+                AnyJsBindingDeclaration::JsDefaultImportSpecifier(_)
+                | AnyJsBindingDeclaration::JsNamespaceImportSpecifier(_) => {
+                    import_source(&binding)?
+                }
+                AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+                    if !matches!(declarator.id(), Ok(AnyJsBindingPattern::AnyJsBinding(_))) {
+                        return None;
+                    }
+                    required_module(declarator.initializer()?.expression().ok()?)?
+                }
+                _ => return None,
+            };
+            (name.token_text_trimmed(), module)
+        }
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let reference = identifier.name().ok()?;
+            let binding = model.binding(&reference)?;
+            let declaration = binding.tree().declaration()?;
+            match &declaration {
+                AnyJsBindingDeclaration::JsShorthandNamedImportSpecifier(_) => (
+                    reference.value_token().ok()?.token_text_trimmed(),
+                    import_source(&binding)?,
+                ),
+                AnyJsBindingDeclaration::JsNamedImportSpecifier(specifier) => (
+                    specifier.name().ok()?.inner_string_text().ok()?,
+                    import_source(&binding)?,
+                ),
+                AnyJsBindingDeclaration::JsObjectBindingPatternShorthandProperty(_)
+                | AnyJsBindingDeclaration::JsObjectBindingPatternProperty(_) => {
+                    let name = match &declaration {
+                        AnyJsBindingDeclaration::JsObjectBindingPatternProperty(property) => {
+                            property.member().ok()?.name()?
+                        }
+                        _ => reference.value_token().ok()?.token_text_trimmed(),
+                    };
+                    let AnyJsBindingDeclaration::JsVariableDeclarator(declarator) =
+                        declaration.parent_binding_pattern_declaration()?
+                    else {
+                        return None;
+                    };
+                    (
+                        name,
+                        required_module(declarator.initializer()?.expression().ok()?)?,
+                    )
+                }
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    let is_blocking = match module.text() {
+        "fs" | "node:fs" => name.ends_with("Sync"),
+        "child_process" | "node:child_process" => {
+            matches!(name.text(), "execSync" | "execFileSync" | "spawnSync")
+        }
+        _ => false,
+    };
+    is_blocking.then_some(name)
+}
+
+/// Returns the source of the import that declares `binding`.
+fn import_source(binding: &Binding) -> Option<TokenText> {
+    binding
+        .syntax()
+        .ancestors()
+        .find_map(|ancestor| JsImport::cast(ancestor)?.source_text().ok())
+}
+
+/// Returns the module required by `expression` if it's a call to `require()`.
+fn required_module(expression: AnyJsExpression) -> Option<TokenText> {
+    let AnyJsExpression::JsCallExpression(call) = expression.omit_parentheses() else {
+        return None;
+    };
+    AnyJsImportLike::JsCallExpression(call).inner_string_text()
+}
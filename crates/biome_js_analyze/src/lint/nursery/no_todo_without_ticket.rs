@@ -0,0 +1,219 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, restricted_regex::RestrictedRegex, Ast, Rule,
+    RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::AnyJsRoot;
+use biome_rowan::{AstNode, Direction, TextRange, TextSize};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Require the `TODO` and `FIXME` comments to reference a ticket.
+    ///
+    /// A `TODO` comment without a ticket is easily forgotten:
+    /// nobody tracks the work, and the comment stays in the code long after its author left.
+    /// Referencing the ticket of the issue tracker that tracks the work,
+    /// such as `TODO(PROJ-123): ...`, keeps the comment actionable.
+    ///
+    /// The rule reports the comments, and the lines of the block comments, that start with one of the keywords,
+    /// when no word of the comment matches the pattern of the ticket references.
+    /// The keywords are case-insensitive.
+    ///
+    /// The rule is often enabled only for a part of a project, such as the sources of an application,
+    /// with the [`overrides`](https://biomejs.dev/reference/configuration/#overrides) of the configuration.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// // TODO: handle the empty lists
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /*
+    ///  * FIXME the total is rounded twice
+    ///  */
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// // TODO(PROJ-123): handle the empty lists
+    /// ```
+    ///
+    /// ```js
+    /// // FIXME the total is rounded twice, see #42
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `ticketPattern`
+    ///
+    /// A regular expression that matches a ticket reference.
+    /// The pattern must match a whole word of the comment, without the punctuation around it,
+    /// such as `PROJ-123` in `TODO(PROJ-123): ...`.
+    ///
+    /// Default: `#[0-9]+|[A-Z][A-Z0-9_]*-[0-9]+`, which matches the references such as `#123` and `PROJ-123`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ticketPattern": "PROJ-[0-9]+"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// // TODO(#42): handle the empty lists
+    /// ```
+    ///
+    /// ### `keywords`
+    ///
+    /// The keywords of the comments that must reference a ticket.
+    ///
+    /// Default: `["TODO", "FIXME"]`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "keywords": ["TODO", "FIXME", "HACK"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// // HACK: the API returns the dates as strings
+    /// ```
+    ///
+    pub NoTodoWithoutTicket {
+        version: "next",
+        name: "noTodoWithoutTicket",
+        language: "js",
+        sources: &[RuleSource::Eslint("no-warning-comments")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noTodoWithoutTicket`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoTodoWithoutTicketOptions {
+    /// A regular expression that matches a ticket reference.
+    ticket_pattern: RestrictedRegex,
+    /// The keywords of the comments that must reference a ticket.
+    keywords: Box<[Box<str>]>,
+}
+
+impl Default for NoTodoWithoutTicketOptions {
+    fn default() -> Self {
+        Self {
+            ticket_pattern: DEFAULT_TICKET_PATTERN
+                .parse()
+                .expect("the default ticket pattern is valid"),
+            keywords: Box::new(["TODO".into(), "FIXME".into()]),
+        }
+    }
+}
+
+const DEFAULT_TICKET_PATTERN: &str = "#[0-9]+|[A-Z][A-Z0-9_]*-[0-9]+";
+
+pub struct TodoComment {
+    /// The range of the keyword.
+    range: TextRange,
+    keyword: Box<str>,
+}
+
+impl Rule for NoTodoWithoutTicket {
+    type Query = Ast<AnyJsRoot>;
+    type State = TodoComment;
+    type Signals = Box<[Self::State]>;
+    type Options = NoTodoWithoutTicketOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let mut todos = Vec::new();
+        for token in ctx.query().syntax().descendants_tokens(Direction::Next) {
+            let comments = token
+                .leading_trivia()
+                .pieces()
+                .chain(token.trailing_trivia().pieces())
+                .filter(|piece| piece.is_comments());
+            for comment in comments {
+                let text = comment.text();
+                let comment_todos = find_keywords(text, &options.keywords);
+                if comment_todos.is_empty() || has_ticket(text, &options.ticket_pattern) {
+                    continue;
+                }
+                let start = comment.text_range().start();
+                todos.extend(
+                    comment_todos
+                        .into_iter()
+                        .map(|(range, keyword)| TodoComment {
+                            range: range + start,
+                            keyword: keyword.into(),
+                        }),
+                );
+            }
+        }
+        todos.into_boxed_slice()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let keyword = &state.keyword;
+        let pattern = ctx.options().ticket_pattern.as_str();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This "<Emphasis>{keyword}</Emphasis>" comment doesn't reference a ticket."
+                },
+            )
+            .note(markup! {
+                "A comment without a ticket is easily forgotten, because nobody tracks the work."
+            })
+            .note(markup! {
+                "Reference the ticket that tracks the work, with a word that matches the pattern "<Emphasis>{pattern}</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// Returns the keywords that start the lines of the comment `text`, with their range in `text`.
+fn find_keywords<'a>(text: &'a str, keywords: &[Box<str>]) -> Vec<(TextRange, &'a str)> {
+    let mut found = Vec::new();
+    let mut line_start = 0;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_start_matches(|c: char| c.is_whitespace() || c == '/' || c == '*');
+        let offset = line_start + line.len() - content.len();
+        line_start += line.len();
+        let keyword = keywords.iter().find_map(|keyword| {
+            let prefix = content.get(..keyword.len())?;
+            let is_word_end =
+                !content[keyword.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_');
+            (prefix.eq_ignore_ascii_case(keyword) && is_word_end).then_some(prefix)
+        });
+        if let Some(keyword) = keyword {
+            let start = TextSize::from(offset as u32);
+            found.push((TextRange::at(start, TextSize::of(keyword)), keyword));
+        }
+    }
+    found
+}
+
+/// Returns `true` if a word of the comment `text` matches `pattern`.
+fn has_ticket(text: &str, pattern: &RestrictedRegex) -> bool {
+    text.split(|c: char| {
+        c.is_whitespace()
+            || matches!(
+                c,
+                '(' | ')' | '[' | ']' | '{' | '}' | '<' | '>' | ',' | ';' | '"' | '\'' | '`'
+            )
+    })
+    .map(|word| word.trim_end_matches([':', '.', '!', '?']))
+    .any(|word| !word.is_empty() && pattern.is_match(word))
+}
@@ -0,0 +1,361 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, AnyTsType, AnyTsVariableAnnotation,
+    JsBinaryExpression, JsBinaryOperator, JsCallExpression, JsComputedMemberExpression,
+    JsFileSource, JsFormalParameter, JsIdentifierExpression, JsLogicalExpression,
+    JsLogicalOperator, JsStaticMemberExpression, JsSyntaxToken, JsVariableDeclarator, T,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, BatchMutationExt, TextRange};
+
+use crate::{services::semantic::Semantic, JsRuleAction};
+
+declare_lint_rule! {
+    /// Disallow conditions that are always truthy or always falsy according to the types.
+    ///
+    /// A value whose type excludes `null` and `undefined` never needs to be checked for them.
+    /// Such a check is dead code, and it often reveals a wrong type or a misunderstanding of the code.
+    ///
+    /// Biome doesn't have a type checker yet, so the rule only reports the checks of variables and parameters
+    /// whose type is known without inference:
+    ///
+    /// - a type annotation made of primitive types, literal types, object types, array types, tuple types, and function types,
+    ///   or of unions and intersections of them;
+    /// - the literal, object, array, function, class, or `new` expression that initializes a variable without a type annotation.
+    ///
+    /// Type references, such as `Foo` or `T`, are never considered non-nullable, because they can include `null` or `undefined`.
+    /// The narrowing of the types by the control flow isn't taken into account.
+    ///
+    /// The rule reports the following checks of a non-nullable value `value`:
+    ///
+    /// - the comparisons to `null` and `undefined`, such as `value === null` or `value != undefined`;
+    /// - the optional chains, such as `value?.length` or `value?.()`;
+    /// - the nullish coalescing operator, such as `value ?? fallback`.
+    ///
+    /// The rule provides an unsafe fix that removes the unnecessary optional chains.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// function greet(name: string) {
+    ///     if (name === undefined) {
+    ///         return "Hello!";
+    ///     }
+    ///     return `Hello ${name}!`;
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// function count(items: string[]) {
+    ///     return items?.length;
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// const separator = ",";
+    /// const value = separator ?? ";";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// function greet(name?: string) {
+    ///     if (name === undefined) {
+    ///         return "Hello!";
+    ///     }
+    ///     return `Hello ${name}!`;
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// function count(items: string[] | null) {
+    ///     return items?.length;
+    /// }
+    /// ```
+    ///
+    pub NoUnnecessaryConditions {
+        version: "next",
+        name: "noUnnecessaryConditions",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("no-unnecessary-condition")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub AnyNullableCheck =
+        JsBinaryExpression
+        | JsLogicalExpression
+        | JsStaticMemberExpression
+        | JsComputedMemberExpression
+        | JsCallExpression
+}
+
+pub enum UnnecessaryCondition {
+    /// A comparison to `null` or `undefined` that is always `true`.
+    AlwaysTrue,
+    /// A comparison to `null` or `undefined` that is always `false`.
+    AlwaysFalse,
+    /// An optional chain on a non-nullable value.
+    OptionalChain,
+    /// A nullish coalescing on a non-nullable value.
+    NullishCoalescing,
+}
+
+pub struct RuleState {
+    kind: UnnecessaryCondition,
+    /// The name of the checked value.
+    name: Box<str>,
+    /// The range of the type annotation or of the initializer that makes the value non-nullable.
+    declaration_range: TextRange,
+}
+
+impl Rule for NoUnnecessaryConditions {
+    type Query = Semantic<AnyNullableCheck>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.source_type::<JsFileSource>().language().is_typescript() {
+            return None;
+        }
+        let model = ctx.model();
+        let (kind, value) = match ctx.query() {
+            AnyNullableCheck::JsBinaryExpression(node) => {
+                let is_equality = match node.operator().ok()? {
+                    JsBinaryOperator::Equality | JsBinaryOperator::StrictEquality => true,
+                    JsBinaryOperator::Inequality | JsBinaryOperator::StrictInequality => false,
+                    _ => return None,
+                };
+                let left = node.left().ok()?;
+                let right = node.right().ok()?;
+                let value = if is_nullish(model, &right) {
+                    left
+                } else if is_nullish(model, &left) {
+                    right
+                } else {
+                    return None;
+                };
+                let kind = if is_equality {
+                    UnnecessaryCondition::AlwaysFalse
+                } else {
+                    UnnecessaryCondition::AlwaysTrue
+                };
+                (kind, value)
+            }
+            AnyNullableCheck::JsLogicalExpression(node) => {
+                if node.operator().ok()? != JsLogicalOperator::NullishCoalescing {
+                    return None;
+                }
+                (UnnecessaryCondition::NullishCoalescing, node.left().ok()?)
+            }
+            AnyNullableCheck::JsStaticMemberExpression(node) => {
+                if !node.is_optional() {
+                    return None;
+                }
+                (UnnecessaryCondition::OptionalChain, node.object().ok()?)
+            }
+            AnyNullableCheck::JsComputedMemberExpression(node) => {
+                if !node.is_optional() {
+                    return None;
+                }
+                (UnnecessaryCondition::OptionalChain, node.object().ok()?)
+            }
+            AnyNullableCheck::JsCallExpression(node) => {
+                if !node.is_optional() {
+                    return None;
+                }
+                (UnnecessaryCondition::OptionalChain, node.callee().ok()?)
+            }
+        };
+        let AnyJsExpression::JsIdentifierExpression(value) = value.omit_parentheses() else {
+            return None;
+        };
+        let reference = value.name().ok()?;
+        let declaration_range = non_nullable_declaration(model, &value)?;
+        Some(RuleState {
+            kind,
+            name: reference.value_token().ok()?.text_trimmed().into(),
+            declaration_range,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.name.as_ref();
+        let (range, message) = match state.kind {
+            UnnecessaryCondition::AlwaysTrue => (
+                ctx.query().range(),
+                markup! {
+                    "This condition is always "<Emphasis>"true"</Emphasis>" because "<Emphasis>{name}</Emphasis>" is never "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>"."
+                }.to_owned(),
+            ),
+            UnnecessaryCondition::AlwaysFalse => (
+                ctx.query().range(),
+                markup! {
+                    "This condition is always "<Emphasis>"false"</Emphasis>" because "<Emphasis>{name}</Emphasis>" is never "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>"."
+                }.to_owned(),
+            ),
+            UnnecessaryCondition::OptionalChain => (
+                optional_chain_token(ctx.query())?.text_trimmed_range(),
+                markup! {
+                    "This optional chain is unnecessary because "<Emphasis>{name}</Emphasis>" is never "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>"."
+                }.to_owned(),
+            ),
+            UnnecessaryCondition::NullishCoalescing => (
+                ctx.query().range(),
+                markup! {
+                    "The right operand is never used because "<Emphasis>{name}</Emphasis>" is never "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>"."
+                }.to_owned(),
+            ),
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), range, message)
+                .detail(
+                    state.declaration_range,
+                    markup! {
+                        "The type of "<Emphasis>{name}</Emphasis>" is determined here."
+                    },
+                )
+                .note(markup! {
+                    "Remove the check, or fix the type if the value can be "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>"."
+                }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        if !matches!(state.kind, UnnecessaryCondition::OptionalChain) {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        let token = optional_chain_token(ctx.query())?;
+        match ctx.query() {
+            AnyNullableCheck::JsStaticMemberExpression(_) => {
+                mutation.replace_token_transfer_trivia(token, make::token(T![.]));
+            }
+            _ => {
+                mutation.remove_token(token);
+            }
+        }
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Remove the optional chain." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the `?.` token of an optional chain.
+fn optional_chain_token(node: &AnyNullableCheck) -> Option<JsSyntaxToken> {
+    match node {
+        AnyNullableCheck::JsStaticMemberExpression(node) => node.operator_token().ok(),
+        AnyNullableCheck::JsComputedMemberExpression(node) => node.optional_chain_token(),
+        AnyNullableCheck::JsCallExpression(node) => node.optional_chain_token(),
+        AnyNullableCheck::JsBinaryExpression(_) | AnyNullableCheck::JsLogicalExpression(_) => None,
+    }
+}
+
+/// Returns `true` if `expression` is `null` or the global `undefined`.
+fn is_nullish(model: &SemanticModel, expression: &AnyJsExpression) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNullLiteralExpression(_),
+        ) => true,
+        AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+            .name()
+            .is_ok_and(|name| name.is_undefined() && model.binding(&name).is_none()),
+        _ => false,
+    }
+}
+
+/// Returns the range of the type annotation or the initializer that makes the variable or the parameter non-nullable.
+fn non_nullable_declaration(
+    model: &SemanticModel,
+    value: &JsIdentifierExpression,
+) -> Option<TextRange> {
+    let binding = model.binding(&value.name().ok()?)?;
+    // Destructured bindings are ignored: their declarations don't give their types.
+    let declaration = binding.tree().syntax().parent()?;
+    if let Some(parameter) = JsFormalParameter::cast_ref(&declaration) {
+        if parameter.question_mark_token().is_some() {
+            return None;
+        }
+        let annotation = parameter.type_annotation()?;
+        return is_non_nullable_type(&annotation.ty().ok()?).then(|| annotation.range());
+    }
+    let declarator = JsVariableDeclarator::cast(declaration)?;
+    match declarator.variable_annotation() {
+        Some(AnyTsVariableAnnotation::TsTypeAnnotation(annotation)) => {
+            is_non_nullable_type(&annotation.ty().ok()?).then(|| annotation.range())
+        }
+        Some(AnyTsVariableAnnotation::TsDefiniteVariableAnnotation(annotation)) => {
+            let annotation = annotation.type_annotation().ok()?;
+            is_non_nullable_type(&annotation.ty().ok()?).then(|| annotation.range())
+        }
+        None => {
+            let initializer = declarator.initializer()?.expression().ok()?;
+            is_non_nullable_expression(&initializer).then(|| initializer.range())
+        }
+    }
+}
+
+/// Returns `true` if `ty` excludes `null` and `undefined`.
+fn is_non_nullable_type(ty: &AnyTsType) -> bool {
+    match ty {
+        AnyTsType::TsNumberType(_)
+        | AnyTsType::TsBooleanType(_)
+        | AnyTsType::TsBigintType(_)
+        | AnyTsType::TsStringType(_)
+        | AnyTsType::TsSymbolType(_)
+        | AnyTsType::TsNonPrimitiveType(_)
+        | AnyTsType::TsNumberLiteralType(_)
+        | AnyTsType::TsBigintLiteralType(_)
+        | AnyTsType::TsStringLiteralType(_)
+        | AnyTsType::TsBooleanLiteralType(_)
+        | AnyTsType::TsTemplateLiteralType(_)
+        | AnyTsType::TsArrayType(_)
+        | AnyTsType::TsTupleType(_)
+        | AnyTsType::TsObjectType(_)
+        | AnyTsType::TsMappedType(_)
+        | AnyTsType::TsTypeOperatorType(_)
+        | AnyTsType::TsFunctionType(_)
+        | AnyTsType::TsConstructorType(_) => true,
+        AnyTsType::TsParenthesizedType(ty) => ty.ty().is_ok_and(|ty| is_non_nullable_type(&ty)),
+        AnyTsType::TsUnionType(ty) => ty
+            .types()
+            .iter()
+            .all(|ty| ty.is_ok_and(|ty| is_non_nullable_type(&ty))),
+        AnyTsType::TsIntersectionType(ty) => ty
+            .types()
+            .iter()
+            .all(|ty| ty.is_ok_and(|ty| is_non_nullable_type(&ty))),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the value of `expression` is never `null` or `undefined`.
+fn is_non_nullable_expression(expression: &AnyJsExpression) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(literal) => {
+            !matches!(literal, AnyJsLiteralExpression::JsNullLiteralExpression(_))
+        }
+        AnyJsExpression::JsTemplateExpression(template) => template.tag().is_none(),
+        AnyJsExpression::JsObjectExpression(_)
+        | AnyJsExpression::JsArrayExpression(_)
+        | AnyJsExpression::JsArrowFunctionExpression(_)
+        | AnyJsExpression::JsFunctionExpression(_)
+        | AnyJsExpression::JsClassExpression(_)
+        | AnyJsExpression::JsNewExpression(_) => true,
+        _ => false,
+    }
+}
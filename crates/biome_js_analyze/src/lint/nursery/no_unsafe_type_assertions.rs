@@ -0,0 +1,289 @@
+use std::path::Path;
+
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyJsExpression, AnyTsType, JsSyntaxKind, TsAsExpression, TsNonNullAssertionAssignment,
+    TsNonNullAssertionExpression, TsTypeAssertionExpression,
+};
+use biome_rowan::{declare_node_union, AstNode};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow type assertions that bypass the type checking.
+    ///
+    /// A type assertion tells the compiler to trust the developer about the type of a value.
+    /// Some assertions are never checked by the compiler, and hide bugs that TypeScript would otherwise catch:
+    ///
+    /// - An assertion to `any`, such as `value as any` or `<any>value`, disables the type checking of the value.
+    /// - A double assertion, such as `value as unknown as T`, asserts a type that isn't related to the type of the value.
+    ///   TypeScript rejects `value as T` in this case, and the intermediate `unknown` or `any` silences the error.
+    /// - A non-null assertion, such as `value!`, asserts that a value isn't `null` or `undefined`.
+    ///   The code throws at runtime if the assertion is wrong.
+    ///
+    /// Every kind of assertion can be allowed with an option.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// const config = JSON.parse(text) as any;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// const id = user as unknown as number;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// const name = map.get(key)!;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// const config = JSON.parse(text) as Config;
+    /// ```
+    ///
+    /// ```ts
+    /// const name = map.get(key) ?? "anonymous";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `anyAssertions`
+    ///
+    /// Set to `false` to allow the assertions to `any`.
+    ///
+    /// Default: `true`
+    ///
+    /// ### `doubleAssertions`
+    ///
+    /// Set to `false` to allow the double assertions.
+    ///
+    /// Default: `true`
+    ///
+    /// ### `nonNullAssertions`
+    ///
+    /// Set to `false` to allow the non-null assertions.
+    ///
+    /// Default: `true`
+    ///
+    /// ### `allowInTestFiles`
+    ///
+    /// Set to `true` to allow all the assertions in test files.
+    /// Tests often build partial objects or mocks that don't match the declared types.
+    ///
+    /// A file is a test file when its name contains `.test.` or `.spec.`,
+    /// or when it's inside a `__tests__` directory.
+    ///
+    /// Default: `false`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "nonNullAssertions": false
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,use_options
+    /// const name = map.get(key)!;
+    /// ```
+    ///
+    pub NoUnsafeTypeAssertions {
+        version: "next",
+        name: "noUnsafeTypeAssertions",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("no-unsafe-type-assertion")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noUnsafeTypeAssertions`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoUnsafeTypeAssertionsOptions {
+    /// Report the assertions to `any`.
+    pub any_assertions: bool,
+    /// Report the double assertions, such as `value as unknown as T`.
+    pub double_assertions: bool,
+    /// Report the non-null assertions.
+    pub non_null_assertions: bool,
+    /// Allow all the assertions in test files.
+    pub allow_in_test_files: bool,
+}
+
+impl Default for NoUnsafeTypeAssertionsOptions {
+    fn default() -> Self {
+        Self {
+            any_assertions: true,
+            double_assertions: true,
+            non_null_assertions: true,
+            allow_in_test_files: false,
+        }
+    }
+}
+
+declare_node_union! {
+    pub AnyTsUnsafeAssertion =
+        TsAsExpression
+        | TsTypeAssertionExpression
+        | TsNonNullAssertionExpression
+        | TsNonNullAssertionAssignment
+}
+
+pub enum UnsafeAssertion {
+    Any,
+    Double,
+    NonNull,
+}
+
+impl Rule for NoUnsafeTypeAssertions {
+    type Query = Ast<AnyTsUnsafeAssertion>;
+    type State = UnsafeAssertion;
+    type Signals = Option<Self::State>;
+    type Options = NoUnsafeTypeAssertionsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        if options.allow_in_test_files && is_test_file(ctx.file_path()) {
+            return None;
+        }
+        let node = ctx.query();
+        match node {
+            AnyTsUnsafeAssertion::TsAsExpression(_)
+            | AnyTsUnsafeAssertion::TsTypeAssertionExpression(_) => {
+                let (expression, ty) = assertion_parts(node)?;
+                if options.double_assertions
+                    && as_type_assertion(expression.omit_parentheses())
+                        .and_then(|inner| assertion_parts(&inner))
+                        .is_some_and(|(_, inner_ty)| is_top_type(&inner_ty))
+                {
+                    return Some(UnsafeAssertion::Double);
+                }
+                if options.any_assertions && matches!(ty, AnyTsType::TsAnyType(_)) {
+                    // The assertion is reported as part of the enclosing double assertion.
+                    let is_in_double_assertion = options.double_assertions
+                        && node
+                            .syntax()
+                            .ancestors()
+                            .skip(1)
+                            .find(|ancestor| {
+                                ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION
+                            })
+                            .and_then(AnyTsUnsafeAssertion::cast)
+                            .is_some_and(|parent| {
+                                matches!(
+                                    parent,
+                                    AnyTsUnsafeAssertion::TsAsExpression(_)
+                                        | AnyTsUnsafeAssertion::TsTypeAssertionExpression(_)
+                                )
+                            });
+                    return (!is_in_double_assertion).then_some(UnsafeAssertion::Any);
+                }
+                None
+            }
+            AnyTsUnsafeAssertion::TsNonNullAssertionExpression(node) => {
+                // Only report the outermost assertion of `value!!`.
+                (options.non_null_assertions
+                    && node.parent::<TsNonNullAssertionExpression>().is_none())
+                .then_some(UnsafeAssertion::NonNull)
+            }
+            AnyTsUnsafeAssertion::TsNonNullAssertionAssignment(node) => (options
+                .non_null_assertions
+                && node.parent::<TsNonNullAssertionAssignment>().is_none())
+            .then_some(UnsafeAssertion::NonNull),
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let range = ctx.query().range();
+        let diagnostic = match state {
+            UnsafeAssertion::Any => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This assertion to "<Emphasis>"any"</Emphasis>" disables the type checking of the value."
+                },
+            )
+            .note(markup! {
+                "The compiler accepts any use of the value, even the ones that fail at runtime."
+            })
+            .note(markup! {
+                "Assert a specific type, or check the value with a type guard."
+            }),
+            UnsafeAssertion::Double => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This double assertion bypasses the type checking."
+                },
+            )
+            .note(markup! {
+                "The intermediate assertion silences the error that the compiler reports when the asserted type isn't related to the type of the value."
+            })
+            .note(markup! {
+                "Convert the value to the asserted type, or fix the types so that a single assertion is enough."
+            }),
+            UnsafeAssertion::NonNull => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This non-null assertion hides a possible "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>" value."
+                },
+            )
+            .note(markup! {
+                "The code throws a "<Emphasis>"TypeError"</Emphasis>" at runtime if the value is missing."
+            })
+            .note(markup! {
+                "Check the value explicitly, or use the optional chaining operator "<Emphasis>"?."</Emphasis>"."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns `expression` as an assertion if it's `value as T` or `<T>value`.
+fn as_type_assertion(expression: AnyJsExpression) -> Option<AnyTsUnsafeAssertion> {
+    match expression {
+        AnyJsExpression::TsAsExpression(node) => Some(node.into()),
+        AnyJsExpression::TsTypeAssertionExpression(node) => Some(node.into()),
+        _ => None,
+    }
+}
+
+/// Returns the asserted expression and the asserted type of `value as T` and `<T>value`.
+fn assertion_parts(node: &AnyTsUnsafeAssertion) -> Option<(AnyJsExpression, AnyTsType)> {
+    match node {
+        AnyTsUnsafeAssertion::TsAsExpression(node) => {
+            Some((node.expression().ok()?, node.ty().ok()?))
+        }
+        AnyTsUnsafeAssertion::TsTypeAssertionExpression(node) => {
+            Some((node.expression().ok()?, node.ty().ok()?))
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if `ty` is `unknown` or `any`.
+fn is_top_type(ty: &AnyTsType) -> bool {
+    matches!(ty, AnyTsType::TsUnknownType(_) | AnyTsType::TsAnyType(_))
+}
+
+/// Returns `true` if `path` is the path of a test file.
+fn is_test_file(path: &Path) -> bool {
+    let is_test_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.contains(".test.") || name.contains(".spec."));
+    is_test_name
+        || path
+            .components()
+            .any(|component| component.as_os_str() == "__tests__")
+}
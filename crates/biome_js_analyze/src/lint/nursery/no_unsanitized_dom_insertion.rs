@@ -0,0 +1,301 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, global_identifier, AnyJsAssignment,
+    AnyJsAssignmentPattern, AnyJsExpression, AnyJsTemplateElement, JsAssignmentExpression,
+    JsAssignmentOperator, JsBinaryOperator, JsCallExpression, TextRange,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow inserting HTML that isn't sanitized into the document.
+    ///
+    /// The properties `innerHTML` and `outerHTML`, and the methods `insertAdjacentHTML()`, `document.write()`,
+    /// and `document.writeln()` parse their value as HTML.
+    /// When a part of this value comes from an attacker, such as a query parameter or a comment written by another user,
+    /// the inserted HTML can run scripts in the page: this is a cross-site scripting (XSS) vulnerability.
+    ///
+    /// The rule reports the values inserted as HTML that aren't known to be safe. The following values are safe:
+    ///
+    /// - the string literals, and the template literals that only interpolate safe values;
+    /// - the concatenations and the conditional expressions of safe values;
+    /// - the `const` variables initialized with a safe value;
+    /// - the results of the sanitizer functions listed in the `sanitizers` option.
+    ///
+    /// Use `textContent` to insert text, or sanitize the HTML with a library such as
+    /// [DOMPurify](https://github.com/cure53/DOMPurify).
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// element.innerHTML = comment.body;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// list.insertAdjacentHTML("beforeend", `<li>${item.name}</li>`);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// document.write(location.hash);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// element.innerHTML = "<p>Loading…</p>";
+    /// ```
+    ///
+    /// ```js
+    /// element.textContent = comment.body;
+    /// ```
+    ///
+    /// ```js
+    /// const icon = "<svg></svg>";
+    /// button.innerHTML = isOpen ? icon + "<span>Close</span>" : icon;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `sanitizers`
+    ///
+    /// The functions that sanitize HTML, such as `DOMPurify.sanitize`.
+    /// The values returned by these functions are safe.
+    /// A function matches when it's called with the same name, e.g. `DOMPurify.sanitize` doesn't match `purify.sanitize`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "sanitizers": ["DOMPurify.sanitize", "sanitizeHtml"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options
+    /// element.innerHTML = DOMPurify.sanitize(comment.body);
+    /// ```
+    ///
+    pub NoUnsanitizedDomInsertion {
+        version: "next",
+        name: "noUnsanitizedDomInsertion",
+        language: "js",
+        sources: &[
+            RuleSource::EslintNoUnsanitized("property"),
+            RuleSource::EslintNoUnsanitized("method"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noUnsanitizedDomInsertion`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoUnsanitizedDomInsertionOptions {
+    /// The functions whose return values are safe to insert as HTML, e.g. `DOMPurify.sanitize`.
+    sanitizers: Box<[Box<str>]>,
+}
+
+declare_node_union! {
+    pub AnyDomInsertion = JsAssignmentExpression | JsCallExpression
+}
+
+/// The maximum number of `const` variables followed to resolve a value.
+const MAX_RESOLUTION_DEPTH: u8 = 8;
+
+pub struct UnsanitizedInsertion {
+    /// The range of the inserted value.
+    range: TextRange,
+    /// The property or the method that inserts the value.
+    sink: &'static str,
+}
+
+impl Rule for NoUnsanitizedDomInsertion {
+    type Query = Semantic<AnyDomInsertion>;
+    type State = UnsanitizedInsertion;
+    type Signals = Option<Self::State>;
+    type Options = NoUnsanitizedDomInsertionOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        let sanitizers = &ctx.options().sanitizers;
+        let (sink, values) = match ctx.query() {
+            AnyDomInsertion::JsAssignmentExpression(assignment) => {
+                if !matches!(
+                    assignment.operator(),
+                    Ok(JsAssignmentOperator::Assign | JsAssignmentOperator::AddAssign)
+                ) {
+                    return None;
+                }
+                let sink = html_property(&assignment.left().ok()?)?;
+                (sink, vec![assignment.right().ok()?])
+            }
+            AnyDomInsertion::JsCallExpression(call) => {
+                let callee = call.callee().ok()?.omit_parentheses();
+                let callee = callee.as_js_static_member_expression()?;
+                let method = callee.member().ok()?.value_token().ok()?;
+                let arguments = call.arguments().ok()?;
+                match method.text_trimmed() {
+                    "insertAdjacentHTML" => {
+                        let [_, Some(html)] = arguments.get_arguments_by_index([0, 1]) else {
+                            return None;
+                        };
+                        (
+                            "insertAdjacentHTML()",
+                            vec![html.as_any_js_expression()?.clone()],
+                        )
+                    }
+                    "write" | "writeln" => {
+                        let object = callee.object().ok()?;
+                        let (reference, name) = global_identifier(&object)?;
+                        if name.text() != "document" || model.binding(&reference).is_some() {
+                            return None;
+                        }
+                        let sink = if method.text_trimmed() == "write" {
+                            "document.write()"
+                        } else {
+                            "document.writeln()"
+                        };
+                        let values = arguments
+                            .args()
+                            .iter()
+                            .filter_map(|argument| argument.ok()?.as_any_js_expression().cloned())
+                            .collect();
+                        (sink, values)
+                    }
+                    _ => return None,
+                }
+            }
+        };
+        let value = values
+            .into_iter()
+            .find(|value| !is_safe_html(value, model, sanitizers, 0))?;
+        Some(UnsanitizedInsertion {
+            range: value.range(),
+            sink,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let sink = state.sink;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This HTML is inserted with "<Emphasis>{sink}</Emphasis>" without being sanitized."
+                },
+            )
+            .note(markup! {
+                "An attacker who controls a part of this HTML can run scripts in the page, and steal the data of the user."
+            })
+            .note(markup! {
+                "Use "<Emphasis>"textContent"</Emphasis>" to insert text, or sanitize the HTML with a function listed in the "<Emphasis>"sanitizers"</Emphasis>" option."
+            }),
+        )
+    }
+}
+
+/// Returns the name of the property that parses HTML assigned by `left`, if any.
+fn html_property(left: &AnyJsAssignmentPattern) -> Option<&'static str> {
+    let name = match left.as_any_js_assignment()? {
+        AnyJsAssignment::JsStaticMemberAssignment(assignment) => assignment
+            .member()
+            .ok()?
+            .value_token()
+            .ok()?
+            .token_text_trimmed(),
+        AnyJsAssignment::JsComputedMemberAssignment(assignment) => assignment
+            .member()
+            .ok()?
+            .as_any_js_literal_expression()?
+            .as_js_string_literal_expression()?
+            .inner_string_text()
+            .ok()?,
+        _ => return None,
+    };
+    match name.text() {
+        "innerHTML" => Some("innerHTML"),
+        "outerHTML" => Some("outerHTML"),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `value` can't contain HTML chosen by an attacker.
+fn is_safe_html(
+    value: &AnyJsExpression,
+    model: &SemanticModel,
+    sanitizers: &[Box<str>],
+    depth: u8,
+) -> bool {
+    if depth > MAX_RESOLUTION_DEPTH {
+        return false;
+    }
+    let is_safe = |value: &AnyJsExpression| is_safe_html(value, model, sanitizers, depth);
+    match value.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(_) => true,
+        AnyJsExpression::JsTemplateExpression(template) => {
+            template.tag().is_none()
+                && template
+                    .elements()
+                    .into_iter()
+                    .all(|element| match element {
+                        AnyJsTemplateElement::JsTemplateChunkElement(_) => true,
+                        AnyJsTemplateElement::JsTemplateElement(element) => {
+                            element.expression().is_ok_and(|value| is_safe(&value))
+                        }
+                    })
+        }
+        AnyJsExpression::JsBinaryExpression(binary) => {
+            matches!(binary.operator(), Ok(JsBinaryOperator::Plus))
+                && binary.left().is_ok_and(|left| is_safe(&left))
+                && binary.right().is_ok_and(|right| is_safe(&right))
+        }
+        AnyJsExpression::JsConditionalExpression(conditional) => {
+            conditional
+                .consequent()
+                .is_ok_and(|consequent| is_safe(&consequent))
+                && conditional
+                    .alternate()
+                    .is_ok_and(|alternate| is_safe(&alternate))
+        }
+        AnyJsExpression::JsCallExpression(call) => call.callee().is_ok_and(|callee| {
+            let callee = callee.omit_parentheses();
+            sanitizers
+                .iter()
+                .any(|sanitizer| callee.syntax().text_trimmed() == sanitizer.as_ref())
+        }),
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let Some(binding) = identifier
+                .name()
+                .ok()
+                .and_then(|reference| model.binding(&reference))
+            else {
+                return false;
+            };
+            let Some(AnyJsBindingDeclaration::JsVariableDeclarator(declarator)) =
+                binding.tree().declaration()
+            else {
+                return false;
+            };
+            declarator
+                .declaration()
+                .is_some_and(|declaration| declaration.is_const())
+                && declarator
+                    .initializer()
+                    .and_then(|initializer| initializer.expression().ok())
+                    .is_some_and(|initializer| {
+                        is_safe_html(&initializer, model, sanitizers, depth + 1)
+                    })
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,266 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_semantic::{ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsBindingPattern, AnyJsExpression,
+    AnyJsFunction, AnyJsObjectBindingPatternMember, JsArrayExpression, JsCallExpression,
+    JsIdentifierBinding, JsObjectBindingPattern, JsSyntaxKind, TextRange,
+};
+use biome_rowan::AstNode;
+use rustc_hash::FxHashMap;
+
+use crate::lint::correctness::use_exhaustive_dependencies::HookConfigMaps;
+use crate::react::hooks::{is_react_component, react_hook_with_dependency, ReactHookConfiguration};
+use crate::react::{is_react_call_api, ReactLibrary};
+use crate::services::semantic::Semantic;
+
+declare_lint_rule! {
+    /// Disallow the object, array, and function literals as default values of the props of a component,
+    /// when the props are dependencies of a hook.
+    ///
+    /// A default value of a destructured prop is evaluated at each render of the component
+    /// that doesn't receive the prop.
+    /// When the default value is an object, an array, a function, or a class literal,
+    /// it's a new value at each render.
+    /// A hook that depends on the prop, such as `useEffect` or `useMemo`,
+    /// then runs again at each render, which defeats its purpose and can even cause infinite loops of renders.
+    ///
+    /// The rule reports these default values of the props destructured in the first parameter of a function component,
+    /// when the prop is in the dependencies of `useEffect`, `useLayoutEffect`, `useInsertionEffect`,
+    /// `useCallback`, `useMemo`, or `useImperativeHandle`.
+    /// A function is a component when its name starts with an uppercase letter,
+    /// or when it's passed to `memo` or `forwardRef`.
+    ///
+    /// Move the default value to a constant outside of the component, or memoize it.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import { useMemo } from "react";
+    ///
+    /// function List({ items = [] }) {
+    ///     const sorted = useMemo(() => items.toSorted(), [items]);
+    ///     return <ul>{sorted.map((item) => <li key={item}>{item}</li>)}</ul>;
+    /// }
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import { useEffect } from "react";
+    ///
+    /// const Chart = ({ options = { animated: true } }) => {
+    ///     useEffect(() => {
+    ///         draw(options);
+    ///     }, [options]);
+    ///     return <canvas />;
+    /// };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// import { useMemo } from "react";
+    ///
+    /// const NO_ITEMS = [];
+    ///
+    /// function List({ items = NO_ITEMS }) {
+    ///     const sorted = useMemo(() => items.toSorted(), [items]);
+    ///     return <ul>{sorted.map((item) => <li key={item}>{item}</li>)}</ul>;
+    /// }
+    /// ```
+    ///
+    /// ```jsx
+    /// function List({ items = [] }) {
+    ///     return <ul>{items.map((item) => <li key={item}>{item}</li>)}</ul>;
+    /// }
+    /// ```
+    ///
+    pub NoUnstableDefaultProps {
+        version: "next",
+        name: "noUnstableDefaultProps",
+        language: "jsx",
+        sources: &[RuleSource::EslintReact("no-object-type-as-default-prop")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+pub struct UnstableDefaultProp {
+    /// The range of the default value.
+    range: TextRange,
+    kind: UnstableValueKind,
+    name: Box<str>,
+    /// The range of the dependency of the hook.
+    dependency: TextRange,
+}
+
+#[derive(Clone, Copy)]
+pub enum UnstableValueKind {
+    Object,
+    Array,
+    Function,
+    Class,
+}
+
+impl UnstableValueKind {
+    fn from_expression(expression: &AnyJsExpression) -> Option<Self> {
+        match expression.clone().omit_parentheses() {
+            AnyJsExpression::JsObjectExpression(_) => Some(Self::Object),
+            AnyJsExpression::JsArrayExpression(_) => Some(Self::Array),
+            AnyJsExpression::JsArrowFunctionExpression(_)
+            | AnyJsExpression::JsFunctionExpression(_) => Some(Self::Function),
+            AnyJsExpression::JsClassExpression(_) => Some(Self::Class),
+            _ => None,
+        }
+    }
+
+    const fn description(self) -> &'static str {
+        match self {
+            Self::Object => "object",
+            Self::Array => "array",
+            Self::Function => "function",
+            Self::Class => "class",
+        }
+    }
+}
+
+impl Rule for NoUnstableDefaultProps {
+    type Query = Semantic<AnyJsFunction>;
+    type State = UnstableDefaultProp;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let function = ctx.query();
+        let model = ctx.model();
+        let Some(props) = props_pattern(function) else {
+            return Box::default();
+        };
+        if !is_function_component(function, model) {
+            return Box::default();
+        }
+        let mut hooks = None;
+        props
+            .properties()
+            .into_iter()
+            .filter_map(|property| {
+                let (binding, default_value, name) = match property.ok()? {
+                    AnyJsObjectBindingPatternMember::JsObjectBindingPatternShorthandProperty(
+                        property,
+                    ) => (property.identifier().ok()?, property.init()?, None),
+                    AnyJsObjectBindingPatternMember::JsObjectBindingPatternProperty(property) => {
+                        let AnyJsBindingPattern::AnyJsBinding(binding) = property.pattern().ok()?
+                        else {
+                            return None;
+                        };
+                        let name = property.member().ok()?.name();
+                        (binding, property.init()?, name)
+                    }
+                    _ => return None,
+                };
+                let default_value = default_value.expression().ok()?;
+                let kind = UnstableValueKind::from_expression(&default_value)?;
+                let AnyJsBinding::JsIdentifierBinding(binding) = binding else {
+                    return None;
+                };
+                let hooks = hooks.get_or_insert_with(|| HookConfigMaps::default().hooks_config);
+                let dependency = find_hook_dependency(&binding, hooks, model)?;
+                Some(UnstableDefaultProp {
+                    range: default_value.range(),
+                    kind,
+                    name: match name {
+                        Some(name) => name.text().into(),
+                        None => binding.name_token().ok()?.text_trimmed().into(),
+                    },
+                    dependency,
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = &state.name;
+        let kind = state.kind.description();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The default value of the prop "<Emphasis>{name}</Emphasis>" is a new "{kind}" at each render."
+                },
+            )
+            .detail(
+                state.dependency,
+                markup! {
+                    "This hook runs again at each render that doesn't receive the prop, because its dependency changes."
+                },
+            )
+            .note(markup! {
+                "Move the default value to a constant outside of the component, or memoize it."
+            }),
+        )
+    }
+}
+
+/// Returns the object pattern of the first parameter of `function`.
+fn props_pattern(function: &AnyJsFunction) -> Option<JsObjectBindingPattern> {
+    let AnyJsArrowFunctionParameters::JsParameters(parameters) = function.parameters().ok()? else {
+        return None;
+    };
+    let parameter = parameters.items().into_iter().next()?.ok()?;
+    parameter
+        .as_any_js_formal_parameter()?
+        .as_js_formal_parameter()?
+        .binding()
+        .ok()?
+        .as_js_object_binding_pattern()
+        .cloned()
+}
+
+/// Returns `true` if `function` is named as a component, or passed to `memo` or `forwardRef`.
+fn is_function_component(function: &AnyJsFunction, model: &SemanticModel) -> bool {
+    if let Some(binding) = function.binding() {
+        return is_react_component(&binding.text());
+    }
+    let Some(call) = function
+        .syntax()
+        .parent()
+        .filter(|parent| parent.kind() == JsSyntaxKind::JS_CALL_ARGUMENT_LIST)
+        .and_then(|arguments| arguments.grand_parent())
+        .and_then(JsCallExpression::cast)
+    else {
+        return false;
+    };
+    call.callee().is_ok_and(|callee| {
+        is_react_call_api(&callee, model, ReactLibrary::React, "memo")
+            || is_react_call_api(&callee, model, ReactLibrary::React, "forwardRef")
+    })
+}
+
+/// Returns the range of a reference to `binding` in the dependencies of a hook.
+fn find_hook_dependency(
+    binding: &JsIdentifierBinding,
+    hooks: &FxHashMap<String, ReactHookConfiguration>,
+    model: &SemanticModel,
+) -> Option<TextRange> {
+    binding.all_references(model).find_map(|reference| {
+        let mut expression = reference.syntax().parent()?;
+        while let Some(parent) = expression
+            .parent()
+            .filter(|parent| parent.kind() == JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+        {
+            expression = parent;
+        }
+        let elements = expression
+            .parent()
+            .filter(|parent| parent.kind() == JsSyntaxKind::JS_ARRAY_ELEMENT_LIST)?;
+        let array = JsArrayExpression::cast(elements.parent()?)?;
+        let call = JsCallExpression::cast(array.syntax().ancestors().nth(3)?)?;
+        let hook = react_hook_with_dependency(&call, hooks, model)?;
+        let dependencies = hook.dependencies_node?;
+        (dependencies.syntax() == array.syntax()).then(|| reference.syntax().text_trimmed_range())
+    })
+}
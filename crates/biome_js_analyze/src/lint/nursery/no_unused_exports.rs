@@ -0,0 +1,312 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_glob::{CandidatePath, Glob};
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsDeclarationClause, AnyJsExportClause, AnyJsExportNamedSpecifier,
+    AnyJsModuleItem, AnyTsIdentifierBinding, AnyTsModuleName, JsIdentifierBinding,
+    JsLiteralExportName, JsModule, JsSyntaxKind, JsSyntaxToken,
+};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+use serde::{Deserialize, Serialize};
+
+use crate::services::project::Project;
+
+declare_lint_rule! {
+    /// Disallow exports that no module of the project imports.
+    ///
+    /// An export that is never imported is dead code: it can be removed, along with its declaration when it isn't used locally.
+    /// The rule looks for the imports, the re-exports, the dynamic imports, and the calls to `require()`
+    /// of all the modules handled by Biome, and resolves their relative specifiers,
+    /// omitting the extension and the `/index` suffix as Node.js and TypeScript allow.
+    ///
+    /// A namespace import such as `import * as utils from "./utils"`, a dynamic import,
+    /// and a call to `require()` use all the exports of the imported module.
+    ///
+    /// Note that the modules that aren't handled by Biome, as well as the imports that go through a package name
+    /// or a path alias, aren't taken into account.
+    /// The rule doesn't report anything when Biome doesn't know any other module of the project,
+    /// e.g. when a single file is checked.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js
+    /// // No module imports `formatDate`
+    /// export function formatDate(date) {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// // Another module does `import { formatDate } from "./format.js"`
+    /// export function formatDate(date) {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `entryPoints`
+    ///
+    /// A list of globs that select the modules whose exports aren't reported,
+    /// such as the entry points of the application, the modules that form the public API of a library,
+    /// and the configuration files loaded by tools.
+    /// A glob prefixed with `!` is an exception.
+    /// See the [glob syntax](https://biomejs.dev/reference/configuration/#glob-syntax-reference).
+    ///
+    /// Default: `[]`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "entryPoints": ["src/index.ts", "**/*.config.ts", "**/*.test.ts"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoUnusedExports {
+        version: "next",
+        name: "noUnusedExports",
+        language: "js",
+        sources: &[RuleSource::EslintImport("no-unused-modules")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noUnusedExports`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoUnusedExportsOptions {
+    /// Globs of the modules whose exports aren't reported. The globs prefixed with `!` are exceptions.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    entry_points: Box<[Glob]>,
+}
+
+pub struct UnusedExport {
+    range: TextRange,
+    name: Box<str>,
+}
+
+impl Rule for NoUnusedExports {
+    type Query = Project<JsModule>;
+    type State = UnusedExport;
+    type Signals = Box<[Self::State]>;
+    type Options = NoUnusedExportsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let path = ctx.file_path();
+        let project = ctx.project();
+        if CandidatePath::new(&path).matches_with_exceptions(&ctx.options().entry_points)
+            || !project.has_other_file(path)
+        {
+            return Box::default();
+        }
+        let mut exports = Vec::new();
+        for item in ctx.query().items() {
+            if let AnyJsModuleItem::JsExport(export) = item {
+                if let Ok(clause) = export.export_clause() {
+                    collect_exported_names(&clause, &mut exports);
+                }
+            }
+        }
+        if exports.is_empty() {
+            return Box::default();
+        }
+        project.with_used_exports(path, |used_exports| {
+            exports
+                .into_iter()
+                .filter(|export| !used_exports.is_some_and(|used| used.contains(&export.name)))
+                .collect()
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.name.as_ref();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The export "<Emphasis>{name}</Emphasis>" is never imported."
+                },
+            )
+            .note(markup! {
+                "No module of the project imports it."
+            })
+            .note(markup! {
+                "Remove the export, or add the module to the "<Emphasis>"entryPoints"</Emphasis>" option if it's imported from outside the project."
+            }),
+        )
+    }
+}
+
+/// Pushes the names exported by `clause` to `exports`.
+///
+/// `export * from "./mod"` doesn't name its exports, so it isn't reported.
+fn collect_exported_names(clause: &AnyJsExportClause, exports: &mut Vec<UnusedExport>) {
+    match clause {
+        AnyJsExportClause::AnyJsDeclarationClause(declaration) => {
+            collect_declared_names(declaration, exports);
+        }
+        AnyJsExportClause::TsExportDeclareClause(clause) => {
+            if let Ok(declaration) = clause.declaration() {
+                collect_declared_names(&declaration, exports);
+            }
+        }
+        AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => {
+            if let Ok(token) = clause.default_token() {
+                exports.push(export_of_token(&token));
+            }
+        }
+        AnyJsExportClause::JsExportDefaultExpressionClause(clause) => {
+            if let Ok(token) = clause.default_token() {
+                exports.push(export_of_token(&token));
+            }
+        }
+        AnyJsExportClause::JsExportNamedClause(clause) => {
+            for specifier in clause.specifiers().iter().flatten() {
+                match specifier {
+                    AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(specifier) => {
+                        if let Ok(token) = specifier.name().and_then(|name| name.value_token()) {
+                            exports.push(export_of_token(&token));
+                        }
+                    }
+                    AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) => {
+                        if let Ok(name) = specifier.exported_name() {
+                            exports.extend(export_of_literal_name(&name));
+                        }
+                    }
+                }
+            }
+        }
+        AnyJsExportClause::JsExportFromClause(clause) => {
+            if let Some(name) = clause
+                .export_as()
+                .and_then(|export_as| export_as.exported_name().ok())
+            {
+                exports.extend(export_of_literal_name(&name));
+            }
+        }
+        AnyJsExportClause::JsExportNamedFromClause(clause) => {
+            for specifier in clause.specifiers().iter().flatten() {
+                let name = match specifier.export_as() {
+                    Some(export_as) => export_as.exported_name(),
+                    None => specifier.source_name(),
+                };
+                if let Ok(name) = name {
+                    exports.extend(export_of_literal_name(&name));
+                }
+            }
+        }
+        AnyJsExportClause::TsExportAsNamespaceClause(_)
+        | AnyJsExportClause::TsExportAssignmentClause(_) => {}
+    }
+}
+
+/// Pushes the names of the bindings declared by `declaration` to `exports`.
+fn collect_declared_names(declaration: &AnyJsDeclarationClause, exports: &mut Vec<UnusedExport>) {
+    let binding = match declaration {
+        AnyJsDeclarationClause::JsClassDeclaration(declaration) => declaration.id().ok(),
+        AnyJsDeclarationClause::JsFunctionDeclaration(declaration) => declaration.id().ok(),
+        AnyJsDeclarationClause::TsEnumDeclaration(declaration) => declaration.id().ok(),
+        AnyJsDeclarationClause::TsDeclareFunctionDeclaration(declaration) => declaration.id().ok(),
+        AnyJsDeclarationClause::TsImportEqualsDeclaration(declaration) => declaration.id().ok(),
+        AnyJsDeclarationClause::TsTypeAliasDeclaration(declaration) => {
+            exports.extend(
+                declaration
+                    .binding_identifier()
+                    .ok()
+                    .as_ref()
+                    .and_then(export_of_ts_binding),
+            );
+            return;
+        }
+        AnyJsDeclarationClause::TsInterfaceDeclaration(declaration) => {
+            exports.extend(
+                declaration
+                    .id()
+                    .ok()
+                    .as_ref()
+                    .and_then(export_of_ts_binding),
+            );
+            return;
+        }
+        AnyJsDeclarationClause::TsModuleDeclaration(declaration) => {
+            // `export namespace A.B {}` exports `A`
+            let mut name = declaration.name().ok();
+            while let Some(AnyTsModuleName::TsQualifiedModuleName(qualified)) = name {
+                name = qualified.left().ok();
+            }
+            if let Some(AnyTsModuleName::AnyTsIdentifierBinding(binding)) = name {
+                exports.extend(export_of_ts_binding(&binding));
+            }
+            return;
+        }
+        AnyJsDeclarationClause::JsVariableDeclarationClause(clause) => {
+            let Ok(declaration) = clause.declaration() else {
+                return;
+            };
+            for declarator in declaration.declarators().iter().flatten() {
+                let Ok(id) = declarator.id() else {
+                    continue;
+                };
+                // The bindings of a destructuring pattern, but not the parameters of the
+                // functions in its default values and computed keys
+                for binding in id
+                    .syntax()
+                    .descendants()
+                    .filter_map(JsIdentifierBinding::cast)
+                {
+                    let is_in_expression = binding
+                        .syntax()
+                        .ancestors()
+                        .take_while(|ancestor| ancestor != id.syntax())
+                        .any(|ancestor| {
+                            matches!(
+                                ancestor.kind(),
+                                JsSyntaxKind::JS_INITIALIZER_CLAUSE
+                                    | JsSyntaxKind::JS_COMPUTED_MEMBER_NAME
+                            )
+                        });
+                    if !is_in_expression {
+                        if let Ok(token) = binding.name_token() {
+                            exports.push(export_of_token(&token));
+                        }
+                    }
+                }
+            }
+            return;
+        }
+        AnyJsDeclarationClause::TsExternalModuleDeclaration(_)
+        | AnyJsDeclarationClause::TsGlobalDeclaration(_) => return,
+    };
+    if let Some(AnyJsBinding::JsIdentifierBinding(binding)) = binding {
+        if let Ok(token) = binding.name_token() {
+            exports.push(export_of_token(&token));
+        }
+    }
+}
+
+fn export_of_ts_binding(binding: &AnyTsIdentifierBinding) -> Option<UnusedExport> {
+    Some(export_of_token(
+        &binding.as_ts_identifier_binding()?.name_token().ok()?,
+    ))
+}
+
+fn export_of_literal_name(name: &JsLiteralExportName) -> Option<UnusedExport> {
+    Some(UnusedExport {
+        range: name.range(),
+        name: name.inner_string_text().ok()?.text().into(),
+    })
+}
+
+fn export_of_token(token: &JsSyntaxToken) -> UnusedExport {
+    UnusedExport {
+        range: token.text_trimmed_range(),
+        name: token.text_trimmed().into(),
+    }
+}
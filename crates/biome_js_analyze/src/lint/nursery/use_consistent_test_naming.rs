@@ -0,0 +1,353 @@
+use crate::utils::test::is_test_call;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, restricted_regex::RestrictedRegex, Ast, FixKind, Rule,
+    RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsTemplateElement, JsCallExpression, JsStringLiteralExpression,
+    JsSyntaxToken, JsTemplateExpression,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce a consistent convention for the titles of the tests.
+    ///
+    /// The titles of the `describe()`, `it()`, and `test()` blocks are shown in the reports of the
+    /// test runner. Writing them with the same convention makes the reports easier to read.
+    ///
+    /// The rule checks the titles that are string literals or template literals,
+    /// including the calls such as `it.only()` and `describe.each([...])()`.
+    /// By default, a title must start with a lowercase letter and must not end with a period.
+    /// The titles that start with a character that isn't a letter, such as a digit, are accepted.
+    ///
+    /// For a template literal, the first letter is checked when the template starts with text,
+    /// and the trailing period is checked when the template ends with text.
+    /// The templates with substitutions are never checked against the `pattern` option.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// describe("Parser", () => {});
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// it("returns the default value.", () => {});
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// test(`Handles ${kind} files`, () => {});
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// describe("parser", () => {
+    ///     it("returns the default value", () => {});
+    /// });
+    /// ```
+    ///
+    /// ```js
+    /// test(`handles ${kind} files`, () => {});
+    /// ```
+    ///
+    /// ```js
+    /// it("404 page is shown", () => {});
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `firstLetter`
+    ///
+    /// The case of the first letter of the titles: `"lowercase"`, `"uppercase"`, or `"any"`.
+    ///
+    /// Default: `"lowercase"`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "firstLetter": "uppercase"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// it("returns the default value", () => {});
+    /// ```
+    ///
+    /// ### `allowTrailingPeriod`
+    ///
+    /// Whether the titles may end with a period.
+    ///
+    /// Default: `false`
+    ///
+    /// ### `pattern`
+    ///
+    /// A regular expression that the whole titles must match.
+    /// The titles aren't checked against a pattern by default.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "pattern": "(should|does not) .+"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// it("returns the default value", () => {});
+    /// ```
+    ///
+    pub UseConsistentTestNaming {
+        version: "next",
+        name: "useConsistentTestNaming",
+        language: "js",
+        sources: &[RuleSource::EslintJest("prefer-lowercase-title")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `useConsistentTestNaming`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseConsistentTestNamingOptions {
+    /// The case of the first letter of the titles.
+    first_letter: TestTitleFirstLetter,
+    /// Whether the titles may end with a period.
+    allow_trailing_period: bool,
+    /// A regular expression that the whole titles must match.
+    pattern: Option<RestrictedRegex>,
+}
+
+impl Default for UseConsistentTestNamingOptions {
+    fn default() -> Self {
+        Self {
+            first_letter: TestTitleFirstLetter::Lowercase,
+            allow_trailing_period: false,
+            pattern: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum TestTitleFirstLetter {
+    /// The titles start with a lowercase letter.
+    #[default]
+    Lowercase,
+    /// The titles start with an uppercase letter.
+    Uppercase,
+    /// The case of the first letter isn't checked.
+    Any,
+}
+
+declare_node_union! {
+    /// A title that the rule can check.
+    pub AnyTestTitle = JsStringLiteralExpression | JsTemplateExpression
+}
+
+impl AnyTestTitle {
+    /// Returns the token that contains the start of the title, and the offset of the title in
+    /// the text of the token.
+    fn first_token(&self) -> Option<(JsSyntaxToken, usize)> {
+        match self {
+            Self::JsStringLiteralExpression(literal) => Some((literal.value_token().ok()?, 1)),
+            Self::JsTemplateExpression(template) => match template.elements().iter().next()? {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    Some((chunk.template_chunk_token().ok()?, 0))
+                }
+                AnyJsTemplateElement::JsTemplateElement(_) => None,
+            },
+        }
+    }
+
+    /// Returns the token that contains the end of the title, and the length of the text that
+    /// follows the title in the token.
+    fn last_token(&self) -> Option<(JsSyntaxToken, usize)> {
+        match self {
+            Self::JsStringLiteralExpression(literal) => Some((literal.value_token().ok()?, 1)),
+            Self::JsTemplateExpression(template) => match template.elements().iter().last()? {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    Some((chunk.template_chunk_token().ok()?, 0))
+                }
+                AnyJsTemplateElement::JsTemplateElement(_) => None,
+            },
+        }
+    }
+
+    /// Returns the whole title, or [None] if it has substitutions.
+    fn text(&self) -> Option<String> {
+        match self {
+            Self::JsStringLiteralExpression(literal) => {
+                Some(literal.inner_string_text().ok()?.text().to_string())
+            }
+            Self::JsTemplateExpression(template) => {
+                let mut text = String::new();
+                for element in template.elements() {
+                    let chunk = element.as_js_template_chunk_element()?;
+                    text.push_str(chunk.template_chunk_token().ok()?.text_trimmed());
+                }
+                Some(text)
+            }
+        }
+    }
+}
+
+pub enum TitleViolation {
+    FirstLetter(TestTitleFirstLetter),
+    TrailingPeriod,
+    Pattern,
+}
+
+pub struct InconsistentTitle {
+    violation: TitleViolation,
+    /// The token to replace and its new text, when the violation can be fixed.
+    replacement: Option<(JsSyntaxToken, String)>,
+}
+
+impl Rule for UseConsistentTestNaming {
+    type Query = Ast<JsCallExpression>;
+    type State = InconsistentTitle;
+    type Signals = Option<Self::State>;
+    type Options = UseConsistentTestNamingOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let title = test_title(call)?;
+        let options = ctx.options();
+
+        if let Some((token, offset)) = title.first_token() {
+            let text = token.text_trimmed();
+            if let Some(first) = text[offset..].chars().next() {
+                let fixed_first = match options.first_letter {
+                    TestTitleFirstLetter::Lowercase if first.is_uppercase() => {
+                        Some(first.to_lowercase().collect::<String>())
+                    }
+                    TestTitleFirstLetter::Uppercase if first.is_lowercase() => {
+                        Some(first.to_uppercase().collect::<String>())
+                    }
+                    _ => None,
+                };
+                if let Some(fixed_first) = fixed_first {
+                    let rest = &text[offset + first.len_utf8()..];
+                    let new_text = format!("{}{fixed_first}{rest}", &text[..offset]);
+                    return Some(InconsistentTitle {
+                        violation: TitleViolation::FirstLetter(options.first_letter),
+                        replacement: Some((token, new_text)),
+                    });
+                }
+            }
+        }
+
+        if !options.allow_trailing_period {
+            if let Some((token, suffix_len)) = title.last_token() {
+                let text = token.text_trimmed();
+                let end = text.len() - suffix_len;
+                // A string literal token always has its quotes.
+                if end >= suffix_len && text[suffix_len..end].ends_with('.') {
+                    let new_text = format!("{}{}", &text[..end - 1], &text[end..]);
+                    return Some(InconsistentTitle {
+                        violation: TitleViolation::TrailingPeriod,
+                        replacement: Some((token, new_text)),
+                    });
+                }
+            }
+        }
+
+        if let Some(pattern) = &options.pattern {
+            let text = title.text()?;
+            if !pattern.is_match(&text) {
+                return Some(InconsistentTitle {
+                    violation: TitleViolation::Pattern,
+                    replacement: None,
+                });
+            }
+        }
+
+        None
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let title = test_title(ctx.query())?;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            title.range(),
+            markup! {
+                "This test title doesn't follow the naming convention of the project."
+            },
+        );
+        let diagnostic = match state.violation {
+            TitleViolation::FirstLetter(TestTitleFirstLetter::Uppercase) => {
+                diagnostic.note(markup! {
+                    "The titles should start with an uppercase letter."
+                })
+            }
+            TitleViolation::FirstLetter(_) => diagnostic.note(markup! {
+                "The titles should start with a lowercase letter."
+            }),
+            TitleViolation::TrailingPeriod => diagnostic.note(markup! {
+                "The titles shouldn't end with a period."
+            }),
+            TitleViolation::Pattern => {
+                let pattern = ctx.options().pattern.as_ref()?.as_str();
+                diagnostic.note(markup! {
+                    "The titles should match the pattern "<Emphasis>{pattern}</Emphasis>"."
+                })
+            }
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let (token, new_text) = state.replacement.as_ref()?;
+        let new_token = JsSyntaxToken::new_detached(token.kind(), new_text, [], []);
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(token.clone(), new_token);
+        let message = match state.violation {
+            TitleViolation::FirstLetter(TestTitleFirstLetter::Uppercase) => {
+                markup! { "Capitalize the first letter of the title." }
+            }
+            TitleViolation::FirstLetter(_) => {
+                markup! { "Lowercase the first letter of the title." }
+            }
+            TitleViolation::TrailingPeriod => markup! { "Remove the trailing period." },
+            TitleViolation::Pattern => return None,
+        };
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            message.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the title of `call` if it's a `describe()`, `it()`, or `test()` block.
+fn test_title(call: &JsCallExpression) -> Option<AnyTestTitle> {
+    if !is_test_call(call, &["describe", "it", "test"]) {
+        return None;
+    }
+    let [Some(title)] = call.arguments().ok()?.get_arguments_by_index([0]) else {
+        return None;
+    };
+    let title = title.as_any_js_expression()?.clone().omit_parentheses();
+    match title {
+        AnyJsExpression::AnyJsLiteralExpression(literal) => {
+            let literal = literal.as_js_string_literal_expression()?;
+            Some(AnyTestTitle::JsStringLiteralExpression(literal.clone()))
+        }
+        AnyJsExpression::JsTemplateExpression(template) if template.tag().is_none() => {
+            Some(AnyTestTitle::JsTemplateExpression(template))
+        }
+        _ => None,
+    }
+}
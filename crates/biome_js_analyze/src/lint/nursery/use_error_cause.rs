@@ -0,0 +1,403 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    global_identifier, AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument, AnyJsExpression,
+    AnyJsObjectMember, AnyJsObjectMemberName, JsCallArguments, JsCatchClause, JsIdentifierBinding,
+    JsLanguage, JsObjectExpression, JsReferenceIdentifier, JsSyntaxKind, JsSyntaxToken,
+    JsThrowStatement, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, TokenText, TriviaPieceKind};
+
+use crate::{services::semantic::Semantic, JsRuleAction};
+
+declare_lint_rule! {
+    /// Require the caught error to be preserved as the `cause` of the error thrown in a `catch` clause.
+    ///
+    /// When a `catch` clause throws a new error, the original error is lost,
+    /// with its message and its stack trace.
+    /// The [`cause`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/cause)
+    /// option of the error constructors attaches the caught error to the new error,
+    /// so that debuggers and loggers can display the chain of errors.
+    ///
+    /// The rule checks the errors created with the built-in error constructors, such as `Error` or `TypeError`,
+    /// and thrown directly in a `catch` clause that declares its parameter.
+    /// Rethrowing the caught error is allowed.
+    ///
+    /// The fix adds the caught error as the `cause` of the new error.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// try {
+    ///     loadConfig();
+    /// } catch (error) {
+    ///     throw new Error("The configuration can't be loaded.");
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// try {
+    ///     loadConfig();
+    /// } catch (error) {
+    ///     throw new TypeError("The configuration is invalid.", { cause: otherError });
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// try {
+    ///     loadConfig();
+    /// } catch (error) {
+    ///     throw new Error("The configuration can't be loaded.", { cause: error });
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// try {
+    ///     loadConfig();
+    /// } catch (error) {
+    ///     throw error;
+    /// }
+    /// ```
+    ///
+    pub UseErrorCause {
+        version: "next",
+        name: "useErrorCause",
+        language: "js",
+        sources: &[RuleSource::Eslint("preserve-caught-error")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// The built-in error constructors that accept the `cause` option.
+const ERROR_CONSTRUCTORS: [&str; 8] = [
+    "AggregateError",
+    "Error",
+    "EvalError",
+    "RangeError",
+    "ReferenceError",
+    "SyntaxError",
+    "TypeError",
+    "URIError",
+];
+
+pub struct MissingErrorCause {
+    /// The parameter of the `catch` clause.
+    catch_binding: JsIdentifierBinding,
+    fix: Option<ErrorCauseFix>,
+}
+
+pub enum ErrorCauseFix {
+    /// Append an options object with the `cause` to the arguments.
+    AppendOptions(JsCallArguments),
+    /// Append the `cause` to the members of the options object.
+    AppendCause(JsObjectExpression),
+}
+
+impl Rule for UseErrorCause {
+    type Query = Semantic<JsThrowStatement>;
+    type State = MissingErrorCause;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let model = ctx.model();
+        let AnyJsExpression::JsNewExpression(new_expression) =
+            node.argument().ok()?.omit_parentheses()
+        else {
+            return None;
+        };
+        let (reference, name) = global_identifier(&new_expression.callee().ok()?)?;
+        if !ERROR_CONSTRUCTORS.contains(&name.text()) || model.binding(&reference).is_some() {
+            return None;
+        }
+        let catch_binding = enclosing_catch_binding(node)?;
+        // `AggregateError` takes the errors before the message.
+        let options_index = if name.text() == "AggregateError" {
+            2
+        } else {
+            1
+        };
+        let Some(arguments) = new_expression.arguments() else {
+            // `new Error` has no message to preserve the position of the options.
+            return Some(MissingErrorCause {
+                catch_binding,
+                fix: None,
+            });
+        };
+        let mut options = None;
+        for (index, argument) in arguments.args().iter().enumerate() {
+            match argument.ok()? {
+                AnyJsCallArgument::JsSpread(_) => return None,
+                AnyJsCallArgument::AnyJsExpression(argument) if index == options_index => {
+                    options = Some(argument);
+                }
+                AnyJsCallArgument::AnyJsExpression(_) => {}
+            }
+        }
+        let is_fixable = !is_shadowed(model, node, &catch_binding);
+        let fix = match options {
+            None => (arguments.args().len() == options_index && is_fixable)
+                .then_some(ErrorCauseFix::AppendOptions(arguments)),
+            Some(AnyJsExpression::JsObjectExpression(object)) => {
+                let mut cause = None;
+                for member in object.members().iter() {
+                    let member = member.ok()?;
+                    if member_name(&member)?.text() == "cause" {
+                        cause = Some(member);
+                    }
+                }
+                match cause {
+                    Some(cause) => {
+                        return (!references_binding(model, &cause, &catch_binding)).then_some(
+                            MissingErrorCause {
+                                catch_binding,
+                                fix: None,
+                            },
+                        );
+                    }
+                    None => is_fixable.then_some(ErrorCauseFix::AppendCause(object)),
+                }
+            }
+            // The options are computed elsewhere.
+            Some(_) => return None,
+        };
+        Some(MissingErrorCause { catch_binding, fix })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let name = state.catch_binding.name_token().ok()?;
+        let name = name.text_trimmed();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The caught error isn't preserved as the "<Emphasis>"cause"</Emphasis>" of the thrown error."
+                },
+            )
+            .detail(
+                state.catch_binding.range(),
+                markup! {
+                    "This is the caught error."
+                },
+            )
+            .note(markup! {
+                "The message and the stack trace of the original error are lost."
+            })
+            .note(markup! {
+                "Pass the caught error with the "<Emphasis>"cause"</Emphasis>" option, such as "<Emphasis>"{ cause: "{name}" }"</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let name = state.catch_binding.name_token().ok()?;
+        let name = name.text_trimmed();
+        let cause = if name == "cause" {
+            AnyJsObjectMember::from(make::js_shorthand_property_object_member(
+                make::js_reference_identifier(make::ident(name)),
+            ))
+        } else {
+            make::js_property_object_member(
+                make::js_literal_member_name(make::ident("cause")).into(),
+                make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_identifier_expression(make::js_reference_identifier(make::ident(name)))
+                    .into(),
+            )
+            .into()
+        };
+        match state.fix.as_ref()? {
+            ErrorCauseFix::AppendOptions(arguments) => {
+                let options = make::js_object_expression(
+                    make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    make::js_object_member_list([cause], []),
+                    make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                );
+                let list = arguments.args();
+                let (elements, separators) = append_element(
+                    list.iter().collect::<Result<_, _>>().ok()?,
+                    list.separators().collect::<Result<_, _>>().ok()?,
+                    AnyJsCallArgument::AnyJsExpression(options.into()),
+                )?;
+                mutation.replace_node(list, make::js_call_argument_list(elements, separators));
+            }
+            ErrorCauseFix::AppendCause(object) => {
+                let list = object.members();
+                if list.is_empty() {
+                    let new_object = make::js_object_expression(
+                        object
+                            .l_curly_token()
+                            .ok()?
+                            .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                        make::js_object_member_list([cause], []),
+                        object
+                            .r_curly_token()
+                            .ok()?
+                            .with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    );
+                    mutation.replace_node(object.clone(), new_object);
+                } else {
+                    let (elements, separators) = append_element(
+                        list.iter().collect::<Result<_, _>>().ok()?,
+                        list.separators().collect::<Result<_, _>>().ok()?,
+                        cause,
+                    )?;
+                    mutation.replace_node(list, make::js_object_member_list(elements, separators));
+                }
+            }
+        }
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Pass the caught error as the "<Emphasis>"cause"</Emphasis>" of the thrown error." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the parameter of the `catch` clause that contains `node`,
+/// if `node` isn't in a function nested in the clause.
+fn enclosing_catch_binding(node: &JsThrowStatement) -> Option<JsIdentifierBinding> {
+    for ancestor in node.syntax().ancestors() {
+        match ancestor.kind() {
+            JsSyntaxKind::JS_FUNCTION_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+            | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+            | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+            | JsSyntaxKind::JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER
+            | JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER => return None,
+            _ => {
+                if let Some(catch_clause) = JsCatchClause::cast(ancestor) {
+                    // A destructured parameter can't be passed as the cause.
+                    let AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(
+                        binding,
+                    )) = catch_clause.declaration()?.binding().ok()?
+                    else {
+                        return None;
+                    };
+                    return Some(binding);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Returns `true` if the name of `catch_binding` refers to another binding at the position of `node`.
+fn is_shadowed(
+    model: &SemanticModel,
+    node: &JsThrowStatement,
+    catch_binding: &JsIdentifierBinding,
+) -> bool {
+    let Ok(name) = catch_binding.name_token() else {
+        return true;
+    };
+    model
+        .scope(node.syntax())
+        .ancestors()
+        .find_map(|scope| scope.get_binding(name.text_trimmed()))
+        .is_none_or(|binding| binding.syntax() != catch_binding.syntax())
+}
+
+/// Returns `true` if `member` refers to `catch_binding`.
+fn references_binding(
+    model: &SemanticModel,
+    member: &AnyJsObjectMember,
+    catch_binding: &JsIdentifierBinding,
+) -> bool {
+    member
+        .syntax()
+        .descendants()
+        .filter_map(JsReferenceIdentifier::cast)
+        .any(|reference| {
+            model
+                .binding(&reference)
+                .is_some_and(|binding| binding.syntax() == catch_binding.syntax())
+        })
+}
+
+/// Returns the name of `member`, or `None` if the name isn't static.
+fn member_name(member: &AnyJsObjectMember) -> Option<TokenText> {
+    let name = match member {
+        AnyJsObjectMember::JsPropertyObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsMethodObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsGetterObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsSetterObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsShorthandPropertyObjectMember(member) => {
+            return Some(member.name().ok()?.value_token().ok()?.token_text_trimmed());
+        }
+        AnyJsObjectMember::JsSpread(_) | AnyJsObjectMember::JsBogusMember(_) => return None,
+    };
+    match name {
+        AnyJsObjectMemberName::JsMetavariable(_) => None,
+        name => name.name(),
+    }
+}
+
+/// Appends `element` to the elements and the separators of a list, and preserves the layout of the list.
+///
+/// In a multiline list, the new element goes on its own line, with the indentation of the last element.
+/// A trailing separator stays at the end of the list.
+fn append_element<N: AstNode<Language = JsLanguage>>(
+    mut elements: Vec<N>,
+    mut separators: Vec<JsSyntaxToken>,
+    element: N,
+) -> Option<(Vec<N>, Vec<JsSyntaxToken>)> {
+    let last = elements.pop()?;
+    let leading_trivia = last.syntax().first_leading_trivia()?;
+    let leading_pieces = leading_trivia.pieces().collect::<Vec<_>>();
+    let is_multiline = leading_pieces.iter().any(|piece| piece.is_newline());
+    // The newline and the indentation of the last element.
+    let indentation = leading_pieces
+        .iter()
+        .rposition(|piece| piece.is_newline())
+        .map_or(&[][..], |index| &leading_pieces[index..]);
+    let element = if is_multiline {
+        element.with_leading_trivia_pieces(indentation.iter().cloned())?
+    } else {
+        element
+    };
+    let separator_trivia = if is_multiline {
+        vec![]
+    } else {
+        vec![(TriviaPieceKind::Whitespace, " ")]
+    };
+    if separators.len() > elements.len() {
+        // The list has a trailing separator, that moves after the new element.
+        let trailing_separator = separators.pop()?;
+        let trailing_trivia = trailing_separator.trailing_trivia().pieces();
+        separators.push(trailing_separator.with_trailing_trivia(separator_trivia));
+        separators.push(make::token(T![,]).with_trailing_trivia_pieces(trailing_trivia));
+        elements.push(last);
+        elements.push(element);
+    } else {
+        // The trailing trivia of the last element, such as the space before `}`, moves after the new element.
+        let trailing_trivia = last.syntax().last_trailing_trivia()?;
+        separators.push(make::token(T![,]).with_trailing_trivia(separator_trivia));
+        elements.push(last.trim_trailing_trivia()?);
+        elements.push(element.with_trailing_trivia_pieces(trailing_trivia.pieces())?);
+    }
+    Some((elements, separators))
+}
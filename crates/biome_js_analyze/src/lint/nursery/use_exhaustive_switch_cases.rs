@@ -0,0 +1,487 @@
+use crate::services::semantic::Semantic;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
+use biome_js_syntax::static_value::StaticValue;
+use biome_js_syntax::{
+    inner_string_text, numbers::parse_js_number, AnyJsCallArgument, AnyJsExpression,
+    AnyJsLiteralExpression, AnyJsMemberExpression, AnyJsSwitchClause, AnyTsName, AnyTsType,
+    JsSwitchStatement, JsSyntaxKind, JsSyntaxToken, JsUnaryOperator, TsEnumDeclaration, T,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, TriviaPieceKind};
+use biome_unicode_table::is_js_ident;
+
+declare_lint_rule! {
+    /// Require the `switch` statements over a union of literal types or over an enum to handle every case.
+    ///
+    /// When a new member is added to a union type or to an enum,
+    /// the `switch` statements that don't handle it silently do nothing for the new value.
+    /// Handling every member, or adding a `default` clause, makes the missing cases explicit.
+    ///
+    /// Biome doesn't infer the types of the expressions, so the rule only checks the `switch` statements
+    /// whose discriminant is a variable or a parameter with a type annotation declared in the same file.
+    /// The annotation can be a union of string, number, and boolean literal types, `null`, and `undefined`,
+    /// an enum, or a type alias of one of them.
+    /// The `switch` statements with a `default` clause are ignored.
+    ///
+    /// The fix adds a clause that throws an error for every missing case.
+    /// The added clauses change the behavior of a last clause that falls through: this fix is unsafe.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// type Status = "idle" | "loading" | "done";
+    ///
+    /// function render(status: Status) {
+    ///     switch (status) {
+    ///         case "idle":
+    ///             return "Idle";
+    ///         case "loading":
+    ///             return "Loading";
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// enum Direction {
+    ///     Up,
+    ///     Down,
+    /// }
+    ///
+    /// function move(direction: Direction) {
+    ///     switch (direction) {
+    ///         case Direction.Up:
+    ///             break;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// type Status = "idle" | "loading" | "done";
+    ///
+    /// function render(status: Status) {
+    ///     switch (status) {
+    ///         case "idle":
+    ///             return "Idle";
+    ///         case "loading":
+    ///             return "Loading";
+    ///         case "done":
+    ///             return "Done";
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// function describe(code: 200 | 404 | 500) {
+    ///     switch (code) {
+    ///         case 200:
+    ///             return "OK";
+    ///         default:
+    ///             return "Error";
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseExhaustiveSwitchCases {
+        version: "next",
+        name: "useExhaustiveSwitchCases",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("switch-exhaustiveness-check")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// The maximum number of type aliases followed to resolve the declared type of the discriminant.
+const MAX_ALIAS_DEPTH: u8 = 8;
+
+/// A case of a `switch` statement.
+#[derive(Debug, PartialEq)]
+enum CaseValue {
+    /// The inner text of a string literal.
+    String(Box<str>),
+    Number(f64),
+    Boolean(bool),
+    Null,
+    Undefined,
+    /// A member of an enum, such as `Direction.Up`.
+    EnumMember {
+        enum_name: Box<str>,
+        member: Box<str>,
+    },
+}
+
+/// A member of the declared type of the discriminant.
+#[derive(Debug)]
+pub struct Case {
+    value: CaseValue,
+    /// The text of the case as it's written in the type, such as `"idle"` or `-1`.
+    text: Box<str>,
+}
+
+impl Rule for UseExhaustiveSwitchCases {
+    type Query = Semantic<JsSwitchStatement>;
+    type State = Box<[Case]>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let switch = ctx.query();
+        let mut handled = Vec::new();
+        for clause in switch.cases() {
+            match clause {
+                AnyJsSwitchClause::JsDefaultClause(_) => return None,
+                AnyJsSwitchClause::JsCaseClause(clause) => {
+                    if let Some(value) = clause.test().ok().as_ref().and_then(case_value) {
+                        handled.push(value);
+                    }
+                }
+            }
+        }
+        let discriminant = switch.discriminant().ok()?.omit_parentheses();
+        let ty = declared_type(&discriminant, ctx.model())?;
+        let mut cases = Vec::new();
+        collect_cases(&ty, ctx.model(), 0, &mut cases)?;
+        let missing: Box<[Case]> = cases
+            .into_iter()
+            .filter(|case| !handled.contains(&case.value))
+            .collect();
+        (!missing.is_empty()).then_some(missing)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let switch = ctx.query();
+        let range = switch
+            .switch_token()
+            .ok()?
+            .text_trimmed_range()
+            .cover(switch.r_paren_token().ok()?.text_trimmed_range());
+        let missing = state
+            .iter()
+            .map(|case| case.text.as_ref())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This "<Emphasis>"switch"</Emphasis>" statement doesn't handle every case."
+                },
+            )
+            .note(markup! {
+                "The following cases are missing: "<Emphasis>{missing}</Emphasis>"."
+            })
+            .note(markup! {
+                "Handle the missing cases, or add a "<Emphasis>"default"</Emphasis>" clause."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let switch = ctx.query();
+        let cases = switch.cases();
+        // The new clauses are indented like the last clause,
+        // or one level deeper than the `switch` statement when it has no clause
+        let indentation: Vec<_> = match cases.last() {
+            Some(clause) => clause
+                .syntax()
+                .first_token()?
+                .leading_trivia()
+                .pieces()
+                .filter(|piece| piece.is_newline() || piece.is_whitespace())
+                .map(|piece| (piece.kind(), piece.text().to_string()))
+                .collect(),
+            None => {
+                let switch_token = switch.switch_token().ok()?;
+                let trivia = switch_token.leading_trivia();
+                let switch_indentation = trivia
+                    .pieces()
+                    .rev()
+                    .take_while(|piece| piece.is_whitespace())
+                    .map(|piece| piece.text().to_string())
+                    .collect::<Vec<_>>();
+                let switch_indentation: String = switch_indentation.into_iter().rev().collect();
+                vec![
+                    (TriviaPieceKind::Newline, "\n".to_string()),
+                    (
+                        TriviaPieceKind::Whitespace,
+                        format!("{switch_indentation}\t"),
+                    ),
+                ]
+            }
+        };
+        let mut clauses: Vec<_> = cases.iter().collect();
+        for case in state.iter() {
+            let case_token = make::token(T![case])
+                .with_leading_trivia(
+                    indentation
+                        .iter()
+                        .map(|(kind, text)| (*kind, text.as_str())),
+                )
+                .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+            let clause = make::js_case_clause(
+                case_token,
+                case_expression(case),
+                make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_statement_list([make::js_throw_statement(
+                    make::token(T![throw])
+                        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    not_implemented_error(),
+                )
+                .with_semicolon_token(make::token(T![;]))
+                .build()
+                .into()]),
+            );
+            clauses.push(clause.into());
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(cases, make::js_switch_case_list(clauses));
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Add the missing cases." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the type annotation of the variable or of the parameter referenced by `discriminant`.
+fn declared_type(discriminant: &AnyJsExpression, model: &SemanticModel) -> Option<AnyTsType> {
+    let reference = discriminant.as_js_identifier_expression()?.name().ok()?;
+    let annotation = match model.binding(&reference)?.tree().declaration()? {
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            declarator.variable_annotation()?.type_annotation().ok()??
+        }
+        AnyJsBindingDeclaration::JsFormalParameter(parameter) => parameter.type_annotation()?,
+        _ => return None,
+    };
+    annotation.ty().ok()
+}
+
+/// Collects the members of `ty` in `cases`.
+/// Returns `None` if `ty` has a member that isn't a literal type or an enum member.
+fn collect_cases(
+    ty: &AnyTsType,
+    model: &SemanticModel,
+    depth: u8,
+    cases: &mut Vec<Case>,
+) -> Option<()> {
+    match ty {
+        AnyTsType::TsUnionType(union) => {
+            for ty in union.types() {
+                collect_cases(&ty.ok()?, model, depth, cases)?;
+            }
+        }
+        AnyTsType::TsParenthesizedType(ty) => collect_cases(&ty.ty().ok()?, model, depth, cases)?,
+        AnyTsType::TsReferenceType(reference) => {
+            if depth >= MAX_ALIAS_DEPTH || reference.type_arguments().is_some() {
+                return None;
+            }
+            let AnyTsName::JsReferenceIdentifier(name) = reference.name().ok()? else {
+                return None;
+            };
+            match model.binding(&name)?.tree().declaration()? {
+                AnyJsBindingDeclaration::TsTypeAliasDeclaration(alias) => {
+                    if alias.type_parameters().is_some() {
+                        return None;
+                    }
+                    collect_cases(&alias.ty().ok()?, model, depth + 1, cases)?;
+                }
+                AnyJsBindingDeclaration::TsEnumDeclaration(declaration) => {
+                    collect_enum_cases(&declaration, cases)?;
+                }
+                _ => return None,
+            }
+        }
+        _ => {
+            let case = literal_type_case(ty)?;
+            if cases.iter().all(|other| other.value != case.value) {
+                cases.push(case);
+            }
+        }
+    }
+    Some(())
+}
+
+/// Collects the members of the enum `declaration` in `cases`.
+fn collect_enum_cases(declaration: &TsEnumDeclaration, cases: &mut Vec<Case>) -> Option<()> {
+    let id = declaration.id().ok()?;
+    let enum_name = id.as_js_identifier_binding()?.name_token().ok()?;
+    let enum_name = enum_name.text_trimmed();
+    for member in declaration.members() {
+        let member = member.ok()?.name().ok()?.name()?;
+        let member = member.text();
+        let text = if is_js_ident(member) {
+            format!("{enum_name}.{member}")
+        } else {
+            format!("{enum_name}[\"{member}\"]")
+        };
+        let value = CaseValue::EnumMember {
+            enum_name: enum_name.into(),
+            member: member.into(),
+        };
+        if cases.iter().all(|other| other.value != value) {
+            cases.push(Case {
+                value,
+                text: text.into(),
+            });
+        }
+    }
+    Some(())
+}
+
+/// Returns the case of the literal type `ty`.
+fn literal_type_case(ty: &AnyTsType) -> Option<Case> {
+    let value = match ty {
+        AnyTsType::TsStringLiteralType(ty) => {
+            CaseValue::String(inner_string_text(&ty.literal_token().ok()?).text().into())
+        }
+        AnyTsType::TsNumberLiteralType(ty) => {
+            let value = parse_js_number(ty.literal_token().ok()?.text_trimmed())?;
+            CaseValue::Number(if ty.minus_token().is_some() {
+                -value
+            } else {
+                value
+            })
+        }
+        AnyTsType::TsBooleanLiteralType(ty) => {
+            CaseValue::Boolean(ty.literal().ok()?.kind() == T![true])
+        }
+        AnyTsType::TsNullLiteralType(_) => CaseValue::Null,
+        AnyTsType::TsUndefinedType(_) => CaseValue::Undefined,
+        _ => return None,
+    };
+    Some(Case {
+        value,
+        text: ty.syntax().text_trimmed().to_string().into(),
+    })
+}
+
+/// Returns the case tested by `test`, or `None` if it isn't a literal or an enum member.
+fn case_value(test: &AnyJsExpression) -> Option<CaseValue> {
+    let test = test.clone().omit_parentheses();
+    if let Some(negation) = test
+        .as_js_unary_expression()
+        .filter(|unary| unary.operator() == Ok(JsUnaryOperator::Minus))
+    {
+        let argument = negation.argument().ok()?.omit_parentheses();
+        let AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNumberLiteralExpression(number),
+        ) = argument
+        else {
+            return None;
+        };
+        return Some(CaseValue::Number(-number.as_number()?));
+    }
+    if let Some(member) = AnyJsMemberExpression::cast_ref(test.syntax()) {
+        let object = member.object().ok()?.omit_parentheses();
+        let enum_name = object.as_js_identifier_expression()?.name().ok()?;
+        let enum_name = enum_name.value_token().ok()?;
+        return Some(CaseValue::EnumMember {
+            enum_name: enum_name.text_trimmed().into(),
+            member: member.member_name()?.text().into(),
+        });
+    }
+    let value = match test.as_static_value()? {
+        StaticValue::String(token) => CaseValue::String(inner_string_text(&token).text().into()),
+        StaticValue::EmptyString(_) => CaseValue::String("".into()),
+        StaticValue::Number(token) => CaseValue::Number(parse_js_number(token.text_trimmed())?),
+        StaticValue::Boolean(token) => CaseValue::Boolean(token.kind() == T![true]),
+        StaticValue::Null(_) => CaseValue::Null,
+        StaticValue::Undefined(_) => CaseValue::Undefined,
+        StaticValue::BigInt(_) => return None,
+    };
+    Some(value)
+}
+
+/// Returns the expression tested by the clause that handles `case`.
+fn case_expression(case: &Case) -> AnyJsExpression {
+    let literal = match &case.value {
+        CaseValue::String(_) => AnyJsLiteralExpression::from(make::js_string_literal_expression(
+            JsSyntaxToken::new_detached(JsSyntaxKind::JS_STRING_LITERAL, &case.text, [], []),
+        )),
+        CaseValue::Number(_) => {
+            let Some(text) = case.text.strip_prefix('-') else {
+                return make_number(&case.text);
+            };
+            return make::js_unary_expression(make::token(T![-]), make_number(text.trim_start()))
+                .into();
+        }
+        CaseValue::Boolean(value) => {
+            let value = if *value { T![true] } else { T![false] };
+            make::js_boolean_literal_expression(make::token(value)).into()
+        }
+        CaseValue::Null => make::js_null_literal_expression(make::token(T![null])).into(),
+        CaseValue::Undefined => {
+            return make::js_identifier_expression(make::js_reference_identifier(make::ident(
+                "undefined",
+            )))
+            .into();
+        }
+        CaseValue::EnumMember { enum_name, member } => {
+            let object: AnyJsExpression = make::js_identifier_expression(
+                make::js_reference_identifier(make::ident(enum_name)),
+            )
+            .into();
+            if is_js_ident(member) {
+                return make::js_static_member_expression(
+                    object,
+                    make::token(T![.]),
+                    make::js_name(make::ident(member)).into(),
+                )
+                .into();
+            }
+            let member = make::js_string_literal_expression(make::js_string_literal(member));
+            return make::js_computed_member_expression(
+                object,
+                make::token(T!['[']),
+                AnyJsExpression::AnyJsLiteralExpression(member.into()),
+                make::token(T![']']),
+            )
+            .build()
+            .into();
+        }
+    };
+    AnyJsExpression::AnyJsLiteralExpression(literal)
+}
+
+/// Returns the number literal `text`.
+fn make_number(text: &str) -> AnyJsExpression {
+    AnyJsExpression::AnyJsLiteralExpression(
+        make::js_number_literal_expression(make::js_number_literal(text)).into(),
+    )
+}
+
+/// Returns `new Error("TODO: Not implemented yet")`.
+fn not_implemented_error() -> AnyJsExpression {
+    let message =
+        make::js_string_literal_expression(make::js_string_literal("TODO: Not implemented yet"));
+    make::js_new_expression(
+        make::token(T![new]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        make::js_identifier_expression(make::js_reference_identifier(make::ident("Error"))).into(),
+    )
+    .with_arguments(make::js_call_arguments(
+        make::token(T!['(']),
+        make::js_call_argument_list(
+            [AnyJsCallArgument::AnyJsExpression(
+                AnyJsExpression::AnyJsLiteralExpression(message.into()),
+            )],
+            [],
+        ),
+        make::token(T![')']),
+    ))
+    .build()
+    .into()
+}
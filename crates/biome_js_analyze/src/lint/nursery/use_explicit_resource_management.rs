@@ -0,0 +1,345 @@
+use crate::services::semantic::Semantic;
+use crate::JsRuleAction;
+use biome_analyze::{context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    global_identifier, AnyJsExpression, AnyJsMemberExpression, AnyJsStatement, JsStatementList,
+    JsTryFinallyStatement, JsVariableStatement, TriviaPieceKind, T,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Prefer `using` declarations to disposing of resources in a `finally` clause.
+    ///
+    /// A resource, such as a file handle or a database connection, must be released after use,
+    /// even when an exception is thrown: it's usually released in the `finally` clause of a `try` statement.
+    /// The [explicit resource management](https://github.com/tc39/proposal-explicit-resource-management) declarations
+    /// `using` and `await using` release the resource automatically at the end of the block,
+    /// by calling its method `[Symbol.dispose]()` or `[Symbol.asyncDispose]()`.
+    ///
+    /// The rule reports a `try`-`finally` statement without `catch` clause, whose `finally` clause only closes the resource
+    /// declared with `const` by the statement just before it.
+    /// The resource is closed either by calling its method `[Symbol.dispose]()` or `[Symbol.asyncDispose]()`,
+    /// or by calling a method without arguments, such as `close()`,
+    /// when the resource is created by one of the functions listed in the `factories` option.
+    /// The declaration becomes `await using` when the call that closes the resource is awaited.
+    ///
+    /// The rule provides an unsafe code fix that declares the resource with `using` and removes the `try` statement,
+    /// when the `try` statement is the last statement of its block.
+    /// The body of the `try` statement is kept in a block when it declares a variable, a function, or a class,
+    /// so that its declarations don't collide with the ones of the enclosing block.
+    /// The fix assumes that the method `[Symbol.dispose]()` of the resources created by a factory closes them.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function logMessage(message) {
+    ///     const file = openLogFile();
+    ///     try {
+    ///         file.write(message);
+    ///     } finally {
+    ///         file[Symbol.dispose]();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function logMessage(message) {
+    ///     using file = openLogFile();
+    ///     file.write(message);
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// function logMessage(message) {
+    ///     const file = openLogFile();
+    ///     try {
+    ///         file.write(message);
+    ///     } catch (error) {
+    ///         console.error(error);
+    ///     } finally {
+    ///         file[Symbol.dispose]();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `factories`
+    ///
+    /// The functions that create a disposable resource, such as `openDatabase` or `pool.connect`.
+    /// A function matches when it's called with the same name, e.g. `pool.connect` doesn't match `connect`.
+    ///
+    /// Default: `[]`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "factories": ["openDatabase"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// async function countUsers() {
+    ///     const database = await openDatabase();
+    ///     try {
+    ///         return await database.count("users");
+    ///     } finally {
+    ///         await database.close();
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseExplicitResourceManagement {
+        version: "next",
+        name: "useExplicitResourceManagement",
+        language: "js",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `useExplicitResourceManagement`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseExplicitResourceManagementOptions {
+    /// The functions that create a disposable resource, e.g. `pool.connect`.
+    factories: Box<[Box<str>]>,
+}
+
+pub struct ManualDisposal {
+    /// The statement that declares the resource.
+    variable: JsVariableStatement,
+    /// The expression that closes the resource in the `finally` clause.
+    close: AnyJsExpression,
+    /// Whether the call that closes the resource is awaited.
+    is_async: bool,
+}
+
+impl ManualDisposal {
+    /// Returns the keyword that declares the resource.
+    fn keyword(&self) -> &'static str {
+        if self.is_async {
+            "await using"
+        } else {
+            "using"
+        }
+    }
+}
+
+impl Rule for UseExplicitResourceManagement {
+    type Query = Semantic<JsTryFinallyStatement>;
+    type State = ManualDisposal;
+    type Signals = Option<Self::State>;
+    type Options = UseExplicitResourceManagementOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let statement = ctx.query();
+        if statement.catch_clause().is_some() {
+            return None;
+        }
+        let mut finally_statements = statement
+            .finally_clause()
+            .ok()?
+            .body()
+            .ok()?
+            .statements()
+            .iter();
+        let (Some(AnyJsStatement::JsExpressionStatement(close)), None) =
+            (finally_statements.next(), finally_statements.next())
+        else {
+            return None;
+        };
+        let close = close.expression().ok()?;
+        let (call, is_async) = match close.clone().omit_parentheses() {
+            AnyJsExpression::JsAwaitExpression(await_expression) => {
+                (await_expression.argument().ok()?, true)
+            }
+            expression => (expression, false),
+        };
+        let AnyJsExpression::JsCallExpression(call) = call.omit_parentheses() else {
+            return None;
+        };
+        if call.is_optional_chain() || !call.arguments().ok()?.args().is_empty() {
+            return None;
+        }
+        let callee =
+            AnyJsMemberExpression::cast(call.callee().ok()?.omit_parentheses().into_syntax())?;
+        if callee.is_optional_chain() {
+            return None;
+        }
+        let is_dispose_method = match &callee {
+            AnyJsMemberExpression::JsComputedMemberExpression(member) => member
+                .member()
+                .is_ok_and(|member| is_dispose_symbol(&member, is_async, ctx.model())),
+            AnyJsMemberExpression::JsStaticMemberExpression(_) => false,
+        };
+        let object = callee.object().ok()?.omit_parentheses();
+        let reference = object.as_js_identifier_expression()?.name().ok()?;
+        let variable = JsVariableStatement::cast(statement.syntax().prev_sibling()?)?;
+        let declaration = variable.declaration().ok()?;
+        if declaration.await_token().is_some() || !declaration.is_const() {
+            return None;
+        }
+        let mut declarators = declaration.declarators().iter();
+        let (Some(Ok(declarator)), None) = (declarators.next(), declarators.next()) else {
+            return None;
+        };
+        let id = declarator.id().ok()?;
+        let name = id
+            .as_any_js_binding()?
+            .as_js_identifier_binding()?
+            .name_token()
+            .ok()?;
+        if name.text_trimmed() != reference.value_token().ok()?.text_trimmed() {
+            return None;
+        }
+        let is_disposable = is_dispose_method || {
+            let initializer = declarator
+                .initializer()?
+                .expression()
+                .ok()?
+                .omit_parentheses();
+            let initializer = match initializer {
+                AnyJsExpression::JsAwaitExpression(await_expression) => {
+                    await_expression.argument().ok()?.omit_parentheses()
+                }
+                initializer => initializer,
+            };
+            let AnyJsExpression::JsCallExpression(factory_call) = initializer else {
+                return None;
+            };
+            let factory = factory_call.callee().ok()?.omit_parentheses();
+            ctx.options()
+                .factories
+                .iter()
+                .any(|name| factory.syntax().text_trimmed() == name.as_ref())
+        };
+        is_disposable.then_some(ManualDisposal {
+            variable,
+            close,
+            is_async,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let declarator = state
+            .variable
+            .declaration()
+            .ok()?
+            .declarators()
+            .first()?
+            .ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.close.range(),
+                markup! {
+                    "This resource is disposed of manually in a "<Emphasis>"finally"</Emphasis>" clause."
+                },
+            )
+            .detail(
+                declarator.id().ok()?.range(),
+                markup! {
+                    "The resource is declared here."
+                },
+            )
+            .note(markup! {
+                "Declare the resource with "<Emphasis>{state.keyword()}</Emphasis>" instead, which disposes of it automatically at the end of the block, even when an exception is thrown."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let statement = ctx.query();
+        let list = statement.parent::<JsStatementList>()?;
+        // The resource is disposed of at the end of the block, after the statements that follow the `try` statement
+        if list.last()?.syntax() != statement.syntax()
+            || statement.syntax().has_comments_descendants()
+        {
+            return None;
+        }
+        let declaration = state.variable.declaration().ok()?;
+        let kind = declaration.kind().ok()?;
+        let using =
+            make::token(T![using]).with_trailing_trivia_pieces(kind.trailing_trivia().pieces());
+        let declaration = if state.is_async {
+            let await_token = make::token(T![await])
+                .with_leading_trivia_pieces(kind.leading_trivia().pieces())
+                .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+            declaration
+                .with_await_token(Some(await_token))
+                .with_kind_token(using)
+        } else {
+            declaration
+                .with_kind_token(using.with_leading_trivia_pieces(kind.leading_trivia().pieces()))
+        };
+        let variable = state.variable.clone().with_declaration(declaration);
+        let mut statements: Vec<_> = list.iter().collect();
+        // Replace the declaration and the `try` statement, which are the last statements of the list
+        statements.truncate(statements.len().checked_sub(2)?);
+        statements.push(variable.into());
+        let body = statement.body().ok()?;
+        if body
+            .statements()
+            .iter()
+            .any(|statement| has_block_level_declaration(&statement))
+        {
+            let body = body
+                .with_leading_trivia_pieces(statement.syntax().first_leading_trivia()?.pieces())?;
+            statements.push(body.into());
+        } else {
+            statements.extend(body.statements().iter());
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(list, make::js_statement_list(statements));
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Declare the resource with "<Emphasis>{state.keyword()}</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `statement` declares a name scoped to its block.
+fn has_block_level_declaration(statement: &AnyJsStatement) -> bool {
+    match statement {
+        AnyJsStatement::JsVariableStatement(variable) => variable
+            .declaration()
+            .is_ok_and(|declaration| !declaration.is_var()),
+        AnyJsStatement::JsFunctionDeclaration(_) | AnyJsStatement::JsClassDeclaration(_) => true,
+        _ => false,
+    }
+}
+
+/// Returns `true` if `member` is the global `Symbol.dispose`,
+/// or `Symbol.asyncDispose` when the call is awaited.
+fn is_dispose_symbol(member: &AnyJsExpression, is_async: bool, model: &SemanticModel) -> bool {
+    let Some(member) = AnyJsMemberExpression::cast(member.clone().omit_parentheses().into_syntax())
+    else {
+        return false;
+    };
+    let is_dispose = member.member_name().is_some_and(|name| match name.text() {
+        "dispose" => true,
+        "asyncDispose" => is_async,
+        _ => false,
+    });
+    is_dispose
+        && member.object().is_ok_and(|object| {
+            global_identifier(&object.omit_parentheses()).is_some_and(|(reference, name)| {
+                name.text() == "Symbol" && model.binding(&reference).is_none()
+            })
+        })
+}
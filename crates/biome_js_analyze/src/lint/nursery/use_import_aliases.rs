@@ -0,0 +1,174 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{inner_string_text, AnyJsImportLike, JsSyntaxKind, JsSyntaxToken};
+use biome_rowan::BatchMutationExt;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::services::project::Project;
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Enforce the use of the path aliases of the project instead of deep relative imports.
+    ///
+    /// A specifier such as `../../../shared/format` depends on the location of the importing module:
+    /// it's hard to read, and it breaks when the module is moved.
+    /// When the `paths` compiler option of the `tsconfig.json` or `jsconfig.json` of the project
+    /// declares an alias that covers the imported module, the rule reports the relative import
+    /// and suggests the aliased specifier, e.g. `@/shared/format`.
+    ///
+    /// Biome reads the `tsconfig.json` or `jsconfig.json` found in the working directory or in one of its parents.
+    /// The `extends` field isn't followed, and only the aliases that are exact,
+    /// or that end with `*` and map to targets that end with `*`, are supported.
+    /// The rule doesn't report anything when the project has no path alias.
+    ///
+    /// The code fix is unsafe because the tools that don't read the `tsconfig.json`,
+    /// such as some bundlers and test runners, need their own configuration of the aliases.
+    ///
+    /// ## Examples
+    ///
+    /// In the following examples, the `tsconfig.json` at the root of the project contains:
+    ///
+    /// ```json
+    /// {
+    ///     "compilerOptions": {
+    ///         "paths": {
+    ///             "@/*": ["./src/*"]
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```js
+    /// // src/features/settings/page.js
+    /// import { formatDate } from "../../shared/format.js";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// // src/features/settings/page.js
+    /// import { formatDate } from "@/shared/format.js";
+    /// import { Form } from "./form.js";
+    /// import { Layout } from "../layout.js";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxRelativeDepth`
+    ///
+    /// The number of parent directories that a relative import can go through, with `../`, without being reported.
+    ///
+    /// Default: `1`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "maxRelativeDepth": 0
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseImportAliases {
+        version: "next",
+        name: "useImportAliases",
+        language: "js",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `useImportAliases`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseImportAliasesOptions {
+    /// The number of parent directories that a relative import can go through without being reported.
+    max_relative_depth: u8,
+}
+
+impl Default for UseImportAliasesOptions {
+    fn default() -> Self {
+        Self {
+            max_relative_depth: 1,
+        }
+    }
+}
+
+pub struct AliasedImport {
+    module_name: JsSyntaxToken,
+    alias: Box<str>,
+}
+
+impl Rule for UseImportAliases {
+    type Query = Project<AnyJsImportLike>;
+    type State = AliasedImport;
+    type Signals = Option<Self::State>;
+    type Options = UseImportAliasesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.is_in_ts_module_declaration() {
+            return None;
+        }
+        let module_name = node.module_name_token()?;
+        let specifier = inner_string_text(&module_name);
+        let depth = specifier
+            .split('/')
+            .skip_while(|segment| *segment == ".")
+            .take_while(|segment| *segment == "..")
+            .count();
+        if depth <= usize::from(ctx.options().max_relative_depth) {
+            return None;
+        }
+        let target = ctx
+            .file_path()
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join(specifier.text());
+        let alias = ctx.project().alias_of(&target)?;
+        Some(AliasedImport {
+            module_name,
+            alias: alias.into(),
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let alias = state.alias.as_ref();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.module_name.text_trimmed_range(),
+                markup! {
+                    "This relative import can use the path alias "<Emphasis>{alias}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Deep relative imports are hard to read, and break when the importing module is moved."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let module_name = &state.module_name;
+        let alias = state.alias.as_ref();
+        let delimiter = (*module_name.text_trimmed().as_bytes().first()?) as char;
+        let new_module_name = JsSyntaxToken::new_detached(
+            JsSyntaxKind::JS_STRING_LITERAL,
+            &format!("{delimiter}{alias}{delimiter}"),
+            [],
+            [],
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token(module_name.clone(), new_module_name);
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Use the path alias "<Emphasis>{alias}</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
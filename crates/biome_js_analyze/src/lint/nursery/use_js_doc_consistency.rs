@@ -0,0 +1,682 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsBindingPattern, AnyJsConstructorParameter,
+    AnyJsFormalParameter, AnyJsFunction, AnyJsFunctionBody, AnyJsParameter, AnyTsReturnType,
+    AnyTsType, JsConstructorClassMember, JsFunctionBody, JsInitializerClause, JsMethodClassMember,
+    JsMethodObjectMember, JsParameters, JsPropertyClassMember, JsPropertyObjectMember,
+    JsReturnStatement, JsSyntaxKind, JsSyntaxNode, JsThrowStatement, JsVariableDeclarator,
+    TsDeclareFunctionDeclaration, TsDeclareFunctionExportDefaultDeclaration,
+    TsMethodSignatureClassMember, TsReturnTypeAnnotation,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstSeparatedList, BatchMutationExt, TextRange, TextSize, WalkEvent,
+};
+
+use crate::utils::jsdoc::{documented_node, JsDoc, JsDocTag, PARAM_TAGS, RETURNS_TAGS};
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Enforce that the JSDoc comments of the functions match their signatures.
+    ///
+    /// A JSDoc comment that documents a parameter that was renamed or removed,
+    /// or a returned value that the function doesn't return, misleads the readers and the tools that rely on it.
+    ///
+    /// When a function, a method, or a constructor has a JSDoc comment, the rule reports:
+    ///
+    /// - the `@param` tags that don't document any parameter of the function;
+    /// - the `@param` tags that document a parameter that is already documented;
+    /// - the parameters that aren't documented by any `@param` tag;
+    /// - the `@returns` tag of a function that doesn't return any value;
+    /// - the missing `@returns` tag of a function that returns a value.
+    ///
+    /// A JSDoc comment that only contains a description, without any `@param` or `@returns` tag,
+    /// isn't checked for missing tags.
+    /// A destructured parameter, such as `{ name, age }`, can be documented under any name,
+    /// and is matched with the `@param` tag at its position.
+    /// The tags of the nested properties, such as `@param options.name`, are ignored.
+    ///
+    /// A function returns a value when it contains a `return` statement with a value,
+    /// when it's an arrow function with an expression body,
+    /// or when its TypeScript return type isn't `void`, `undefined`, `never`, or `Promise<void>`.
+    /// The generators and the functions that throw aren't checked for the `@returns` tag.
+    /// A `@returns` tag with the type `void` or `undefined` is allowed on a function that doesn't return any value.
+    ///
+    /// The code fixes rename, add, or remove the tags.
+    /// The tags are only added and removed in the JSDoc comments that span multiple lines.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /**
+    ///  * @param {number} a The first value.
+    ///  * @param {number} c The second value.
+    ///  * @returns {number} The sum of the values.
+    ///  */
+    /// function sum(a, b) {
+    ///     return a + b;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /**
+    ///  * @param {string} message The message to log.
+    ///  * @returns {string} The message.
+    ///  */
+    /// function log(message) {
+    ///     console.log(message);
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /**
+    ///  * @param {number} a The first value.
+    ///  * @returns {number} The sum of the values.
+    ///  */
+    /// const sum = (a, b) => a + b;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /**
+    ///  * @param {number} a The first value.
+    ///  * @param {number} b The second value.
+    ///  * @returns {number} The sum of the values.
+    ///  */
+    /// function sum(a, b) {
+    ///     return a + b;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// /**
+    ///  * Creates a user.
+    ///  *
+    ///  * @param {object} user The user.
+    ///  * @param {string} user.name The name of the user.
+    ///  */
+    /// function createUser({ name }) {}
+    /// ```
+    ///
+    /// ```js
+    /// /** Logs the message. */
+    /// function log(message) {
+    ///     console.log(message);
+    /// }
+    /// ```
+    ///
+    pub UseJsDocConsistency {
+        version: "next",
+        name: "useJsDocConsistency",
+        language: "js",
+        sources: &[
+            RuleSource::EslintJsDoc("check-param-names"),
+            RuleSource::EslintJsDoc("require-param"),
+            RuleSource::EslintJsDoc("require-returns"),
+            RuleSource::EslintJsDoc("require-returns-check"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsDocumentableFunction =
+        AnyJsFunction
+        | JsMethodClassMember
+        | JsMethodObjectMember
+        | JsConstructorClassMember
+        | TsMethodSignatureClassMember
+        | TsDeclareFunctionDeclaration
+        | TsDeclareFunctionExportDefaultDeclaration
+}
+
+pub enum JsDocInconsistency {
+    /// A `@param` tag that doesn't document any parameter,
+    /// with the name of the undocumented parameter at its position, if any.
+    UnknownParam {
+        tag: JsDocTag,
+        replacement: Option<Box<str>>,
+    },
+    /// A `@param` tag that documents a parameter that is already documented.
+    DuplicateParam { tag: JsDocTag },
+    /// A parameter that isn't documented,
+    /// with the offset where its tag should be inserted in the comment.
+    MissingParam {
+        name: Box<str>,
+        range: TextRange,
+        insertion: Option<TextSize>,
+    },
+    /// A `@returns` tag on a function that doesn't return any value.
+    UnexpectedReturns { tag: JsDocTag },
+    /// A missing `@returns` tag on a function that returns a value.
+    MissingReturns,
+}
+
+impl Rule for UseJsDocConsistency {
+    type Query = Ast<AnyJsDocumentableFunction>;
+    type State = JsDocInconsistency;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let Some(doc) = node.documentation() else {
+            return Box::default();
+        };
+        let parameters = node.parameters();
+        let is_signature_documented = doc
+            .tags()
+            .iter()
+            .any(|tag| PARAM_TAGS.contains(&tag.name()) || RETURNS_TAGS.contains(&tag.name()));
+        let mut inconsistencies = Vec::new();
+
+        // The top-level `@param` tags, without the tags of the nested properties
+        let param_tags = doc.tags().iter().filter_map(|tag| {
+            if !PARAM_TAGS.contains(&tag.name()) {
+                return None;
+            }
+            let (name, _) = tag.parameter()?;
+            let name = name.strip_prefix("...").unwrap_or(name);
+            (!name.contains('.')).then_some((tag, name))
+        });
+        let mut is_documented = vec![false; parameters.len()];
+        let mut unmatched_tags = Vec::new();
+        for (position, (tag, name)) in param_tags.enumerate() {
+            match parameters
+                .iter()
+                .position(|parameter| parameter.name() == Some(name))
+            {
+                Some(index) if is_documented[index] => {
+                    inconsistencies.push(JsDocInconsistency::DuplicateParam { tag: tag.clone() });
+                }
+                Some(index) => is_documented[index] = true,
+                None => unmatched_tags.push((position, tag)),
+            }
+        }
+        for (position, tag) in unmatched_tags {
+            let replacement = match parameters.get(position) {
+                Some(parameter) if !is_documented[position] => {
+                    is_documented[position] = true;
+                    match parameter {
+                        // A destructured parameter can be documented under any name
+                        Parameter::Pattern => continue,
+                        Parameter::Named { name, .. } => Some(name.clone()),
+                    }
+                }
+                _ => None,
+            };
+            inconsistencies.push(JsDocInconsistency::UnknownParam {
+                tag: tag.clone(),
+                replacement,
+            });
+        }
+        if is_signature_documented {
+            for (index, parameter) in parameters.iter().enumerate() {
+                if let (Parameter::Named { name, range }, false) = (parameter, is_documented[index])
+                {
+                    inconsistencies.push(JsDocInconsistency::MissingParam {
+                        name: name.clone(),
+                        range: *range,
+                        insertion: param_insertion_offset(&doc, &parameters[..index]),
+                    });
+                }
+            }
+        }
+
+        match (node.returns(), doc.find_tag(RETURNS_TAGS)) {
+            (Returns::Nothing, Some(tag))
+                if !matches!(
+                    tag.type_expression(),
+                    Some("void" | "undefined" | "Promise<void>")
+                ) =>
+            {
+                inconsistencies.push(JsDocInconsistency::UnexpectedReturns { tag: tag.clone() });
+            }
+            (Returns::Value, None) if is_signature_documented => {
+                inconsistencies.push(JsDocInconsistency::MissingReturns);
+            }
+            _ => {}
+        }
+        inconsistencies.into_boxed_slice()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let doc = ctx.query().documentation()?;
+        let diagnostic = match state {
+            JsDocInconsistency::UnknownParam { tag, .. } => {
+                let (name, range) = tag.parameter()?;
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    doc.absolute_range(range),
+                    markup! {
+                        "The JSDoc comment documents the parameter "<Emphasis>{name}</Emphasis>", which isn't a parameter of the function."
+                    },
+                )
+                .note(markup! {
+                    "The parameter was probably renamed or removed, and the comment wasn't updated."
+                })
+            }
+            JsDocInconsistency::DuplicateParam { tag } => {
+                let (name, range) = tag.parameter()?;
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    doc.absolute_range(range),
+                    markup! {
+                        "The JSDoc comment documents the parameter "<Emphasis>{name}</Emphasis>" more than once."
+                    },
+                )
+            }
+            JsDocInconsistency::MissingParam { name, range, .. } => {
+                let name = name.as_ref();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "The parameter "<Emphasis>{name}</Emphasis>" isn't documented in the JSDoc comment of the function."
+                    },
+                )
+                .detail(doc.range(), markup! {
+                    "Add a "<Emphasis>"@param"</Emphasis>" tag to this comment."
+                })
+            }
+            JsDocInconsistency::UnexpectedReturns { tag } => RuleDiagnostic::new(
+                rule_category!(),
+                doc.absolute_range(tag.name_range()),
+                markup! {
+                    "The JSDoc comment documents a returned value, but the function doesn't return any value."
+                },
+            ),
+            JsDocInconsistency::MissingReturns => RuleDiagnostic::new(
+                rule_category!(),
+                doc.range(),
+                markup! {
+                    "The JSDoc comment doesn't document the value returned by the function."
+                },
+            )
+            .note(markup! {
+                "Add a "<Emphasis>"@returns"</Emphasis>" tag to the comment."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let doc = ctx.query().documentation()?;
+        let (range, text, message) = match state {
+            JsDocInconsistency::UnknownParam {
+                tag,
+                replacement: Some(replacement),
+            } => {
+                let (_, range) = tag.parameter()?;
+                let replacement = replacement.as_ref();
+                (
+                    range,
+                    replacement.to_string(),
+                    markup! { "Rename the documented parameter to "<Emphasis>{replacement}</Emphasis>"." }
+                        .to_owned(),
+                )
+            }
+            JsDocInconsistency::UnknownParam { tag, .. }
+            | JsDocInconsistency::DuplicateParam { tag } => (
+                removable_range(&doc, tag)?,
+                String::new(),
+                markup! { "Remove the "<Emphasis>"@param"</Emphasis>" tag." }.to_owned(),
+            ),
+            JsDocInconsistency::UnexpectedReturns { tag } => (
+                removable_range(&doc, tag)?,
+                String::new(),
+                markup! { "Remove the "<Emphasis>"@returns"</Emphasis>" tag." }.to_owned(),
+            ),
+            JsDocInconsistency::MissingParam {
+                name, insertion, ..
+            } => (
+                TextRange::empty((*insertion)?),
+                format!("{}@param {name}\n", doc.line_prefix()),
+                markup! { "Add a "<Emphasis>"@param"</Emphasis>" tag." }.to_owned(),
+            ),
+            JsDocInconsistency::MissingReturns => {
+                if !doc.is_multiline() {
+                    return None;
+                }
+                (
+                    TextRange::empty(doc.closing_line_start()?),
+                    format!("{}@returns\n", doc.line_prefix()),
+                    markup! { "Add a "<Emphasis>"@returns"</Emphasis>" tag." }.to_owned(),
+                )
+            }
+        };
+        let mut comment = doc.text().to_string();
+        comment.replace_range(std::ops::Range::<usize>::from(range), &text);
+        let token = doc.token()?;
+        let trivia: Vec<_> = token
+            .leading_trivia()
+            .pieces()
+            .map(|piece| {
+                let text = if piece.text_range() == doc.range() {
+                    comment.clone()
+                } else {
+                    piece.text().to_string()
+                };
+                (piece.kind(), text)
+            })
+            .collect();
+        let new_token =
+            token.with_leading_trivia(trivia.iter().map(|(kind, text)| (*kind, text.as_str())));
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_discard_trivia(token.clone(), new_token);
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            message,
+            mutation,
+        ))
+    }
+}
+
+impl AnyJsDocumentableFunction {
+    /// Returns the JSDoc comment of the function.
+    ///
+    /// The comment of a function assigned to a variable or a property precedes the declaration.
+    fn documentation(&self) -> Option<JsDoc> {
+        let node = self.syntax();
+        let documented = match self {
+            Self::AnyJsFunction(
+                AnyJsFunction::JsArrowFunctionExpression(_)
+                | AnyJsFunction::JsFunctionExpression(_),
+            ) => {
+                let parent = node
+                    .ancestors()
+                    .skip(1)
+                    .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION);
+                match parent {
+                    Some(parent) if JsInitializerClause::can_cast(parent.kind()) => {
+                        match parent.parent() {
+                            Some(declarator)
+                                if JsVariableDeclarator::can_cast(declarator.kind()) =>
+                            {
+                                documented_node(&declarator)
+                            }
+                            Some(member) if JsPropertyClassMember::can_cast(member.kind()) => {
+                                member
+                            }
+                            _ => node.clone(),
+                        }
+                    }
+                    Some(parent) if JsPropertyObjectMember::can_cast(parent.kind()) => parent,
+                    _ => node.clone(),
+                }
+            }
+            _ => documented_node(node),
+        };
+        JsDoc::for_node(&documented)
+    }
+
+    /// Returns the parameters of the function, without the `this` parameter of TypeScript.
+    fn parameters(&self) -> Vec<Parameter> {
+        let parameters = match self {
+            Self::AnyJsFunction(function) => match function.parameters() {
+                Ok(AnyJsArrowFunctionParameters::AnyJsBinding(binding)) => {
+                    return vec![Parameter::from_binding(&binding)];
+                }
+                Ok(AnyJsArrowFunctionParameters::JsParameters(parameters)) => Some(parameters),
+                Err(_) => None,
+            },
+            Self::JsMethodClassMember(member) => member.parameters().ok(),
+            Self::JsMethodObjectMember(member) => member.parameters().ok(),
+            Self::TsMethodSignatureClassMember(member) => member.parameters().ok(),
+            Self::TsDeclareFunctionDeclaration(declaration) => declaration.parameters().ok(),
+            Self::TsDeclareFunctionExportDefaultDeclaration(declaration) => {
+                declaration.parameters().ok()
+            }
+            Self::JsConstructorClassMember(member) => {
+                let Ok(parameters) = member.parameters() else {
+                    return Vec::new();
+                };
+                return parameters
+                    .parameters()
+                    .iter()
+                    .flatten()
+                    .map(|parameter| match parameter {
+                        AnyJsConstructorParameter::AnyJsFormalParameter(parameter) => {
+                            Parameter::from_formal_parameter(&parameter)
+                        }
+                        AnyJsConstructorParameter::JsRestParameter(parameter) => {
+                            Parameter::from_pattern(parameter.binding().ok())
+                        }
+                        AnyJsConstructorParameter::TsPropertyParameter(parameter) => parameter
+                            .formal_parameter()
+                            .map_or(Parameter::Pattern, |parameter| {
+                                Parameter::from_formal_parameter(&parameter)
+                            }),
+                    })
+                    .collect();
+            }
+        };
+        parameters
+            .as_ref()
+            .map(JsParameters::items)
+            .into_iter()
+            .flat_map(|items| items.iter())
+            .flatten()
+            .filter_map(|parameter| match parameter {
+                AnyJsParameter::AnyJsFormalParameter(parameter) => {
+                    Some(Parameter::from_formal_parameter(&parameter))
+                }
+                AnyJsParameter::JsRestParameter(parameter) => {
+                    Some(Parameter::from_pattern(parameter.binding().ok()))
+                }
+                AnyJsParameter::TsThisParameter(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns whether the function returns a value.
+    fn returns(&self) -> Returns {
+        let (annotation, body, is_generator) = match self {
+            Self::AnyJsFunction(function) => (
+                function.return_type_annotation(),
+                function.body().ok(),
+                function.is_generator(),
+            ),
+            Self::JsMethodClassMember(member) => (
+                member.return_type_annotation(),
+                member.body().ok().map(AnyJsFunctionBody::JsFunctionBody),
+                member.star_token().is_some(),
+            ),
+            Self::JsMethodObjectMember(member) => (
+                member.return_type_annotation(),
+                member.body().ok().map(AnyJsFunctionBody::JsFunctionBody),
+                member.star_token().is_some(),
+            ),
+            Self::TsMethodSignatureClassMember(member) => {
+                (member.return_type_annotation(), None, false)
+            }
+            Self::TsDeclareFunctionDeclaration(declaration) => {
+                (declaration.return_type_annotation(), None, false)
+            }
+            Self::TsDeclareFunctionExportDefaultDeclaration(declaration) => {
+                (declaration.return_type_annotation(), None, false)
+            }
+            // A constructor doesn't document a returned value
+            Self::JsConstructorClassMember(_) => return Returns::Unknown,
+        };
+        if is_generator {
+            return Returns::Unknown;
+        }
+        if let Some(annotation) = annotation {
+            return Returns::from_annotation(&annotation);
+        }
+        match body {
+            Some(AnyJsFunctionBody::AnyJsExpression(_)) => Returns::Value,
+            Some(AnyJsFunctionBody::JsFunctionBody(body)) => Returns::from_body(&body),
+            None => Returns::Unknown,
+        }
+    }
+}
+
+/// A parameter of a function.
+enum Parameter {
+    /// A parameter with a name, and the range of its binding.
+    Named { name: Box<str>, range: TextRange },
+    /// A destructured parameter, or a parameter that can't be parsed.
+    Pattern,
+}
+
+impl Parameter {
+    fn name(&self) -> Option<&str> {
+        match self {
+            Self::Named { name, .. } => Some(name),
+            Self::Pattern => None,
+        }
+    }
+
+    fn from_binding(binding: &AnyJsBinding) -> Self {
+        match binding
+            .as_js_identifier_binding()
+            .and_then(|binding| binding.name_token().ok())
+        {
+            Some(token) => Self::Named {
+                name: token.text_trimmed().into(),
+                range: token.text_trimmed_range(),
+            },
+            None => Self::Pattern,
+        }
+    }
+
+    fn from_pattern(pattern: Option<AnyJsBindingPattern>) -> Self {
+        match pattern {
+            Some(AnyJsBindingPattern::AnyJsBinding(binding)) => Self::from_binding(&binding),
+            _ => Self::Pattern,
+        }
+    }
+
+    fn from_formal_parameter(parameter: &AnyJsFormalParameter) -> Self {
+        match parameter {
+            AnyJsFormalParameter::JsFormalParameter(parameter) => {
+                Self::from_pattern(parameter.binding().ok())
+            }
+            AnyJsFormalParameter::JsBogusParameter(_) | AnyJsFormalParameter::JsMetavariable(_) => {
+                Self::Pattern
+            }
+        }
+    }
+}
+
+/// Whether a function returns a value.
+enum Returns {
+    Value,
+    Nothing,
+    /// The function is a generator, throws, or doesn't have a body.
+    Unknown,
+}
+
+impl Returns {
+    fn from_annotation(annotation: &TsReturnTypeAnnotation) -> Self {
+        match annotation.ty() {
+            Ok(
+                AnyTsReturnType::AnyTsType(
+                    AnyTsType::TsVoidType(_)
+                    | AnyTsType::TsUndefinedType(_)
+                    | AnyTsType::TsNeverType(_),
+                )
+                | AnyTsReturnType::TsAssertsReturnType(_),
+            ) => Self::Nothing,
+            Ok(AnyTsReturnType::AnyTsType(ty)) if ty.syntax().text_trimmed() == "Promise<void>" => {
+                Self::Nothing
+            }
+            Ok(_) => Self::Value,
+            Err(_) => Self::Unknown,
+        }
+    }
+
+    fn from_body(body: &JsFunctionBody) -> Self {
+        let mut throws = false;
+        let mut events = body.syntax().preorder();
+        while let Some(event) = events.next() {
+            let WalkEvent::Enter(node) = event else {
+                continue;
+            };
+            if is_nested_scope(&node) {
+                events.skip_subtree();
+            } else if let Some(statement) = JsReturnStatement::cast_ref(&node) {
+                if statement.argument().is_some() {
+                    return Self::Value;
+                }
+            } else if JsThrowStatement::can_cast(node.kind()) {
+                throws = true;
+            }
+        }
+        if throws {
+            Self::Unknown
+        } else {
+            Self::Nothing
+        }
+    }
+}
+
+/// Returns `true` if the `return` statements of `node` belong to another function.
+fn is_nested_scope(node: &JsSyntaxNode) -> bool {
+    AnyJsDocumentableFunction::can_cast(node.kind())
+        || matches!(
+            node.kind(),
+            JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+                | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+                | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+                | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+                | JsSyntaxKind::JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER
+        )
+}
+
+/// Returns the offset where the `@param` tag of the parameter that follows `preceding_parameters`
+/// should be inserted, i.e. after the tag of the closest preceding parameter,
+/// or before the first `@param` or `@returns` tag, or at the end of the comment.
+fn param_insertion_offset(doc: &JsDoc, preceding_parameters: &[Parameter]) -> Option<TextSize> {
+    if !doc.is_multiline() {
+        return None;
+    }
+    let tags = doc.tags();
+    let preceding_tag = preceding_parameters
+        .iter()
+        .rev()
+        .filter_map(Parameter::name)
+        .find_map(|name| {
+            tags.iter()
+                .filter(|tag| PARAM_TAGS.contains(&tag.name()))
+                .filter(|tag| {
+                    tag.parameter().is_some_and(|(tag_name, _)| {
+                        // The tags of the nested properties follow the tag of their parameter
+                        tag_name == name || tag_name.starts_with(&format!("{name}."))
+                    })
+                })
+                .last()
+        });
+    if let Some(tag) = preceding_tag {
+        return Some(tag.lines_range().end());
+    }
+    let following_tag = tags
+        .iter()
+        .find(|tag| PARAM_TAGS.contains(&tag.name()) || RETURNS_TAGS.contains(&tag.name()));
+    match following_tag {
+        Some(tag) if is_own_line_tag(doc, tag) => Some(tag.lines_range().start()),
+        Some(_) => None,
+        None => doc.closing_line_start(),
+    }
+}
+
+/// Returns the range of the lines of `tag`, if the tag can be removed from the comment.
+fn removable_range(doc: &JsDoc, tag: &JsDocTag) -> Option<TextRange> {
+    (doc.is_multiline() && is_own_line_tag(doc, tag)).then(|| tag.lines_range())
+}
+
+/// Returns `true` if `tag` doesn't start on the line of `/**`.
+fn is_own_line_tag(doc: &JsDoc, tag: &JsDocTag) -> bool {
+    doc.text()[..usize::from(tag.lines_range().start())].contains('\n')
+}
@@ -0,0 +1,249 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyJsFunction, JsConstructorClassMember, JsGetterClassMember, JsGetterObjectMember,
+    JsMethodClassMember, JsMethodObjectMember, JsSetterClassMember, JsSetterObjectMember,
+    JsStatementList, JsSyntaxNode,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, Direction, TextRange, WalkEvent};
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU16;
+
+declare_lint_rule! {
+    /// Enforce a maximum size of the functions.
+    ///
+    /// A long function usually does several things, which makes it hard to understand, test, and reuse.
+    /// Splitting it into smaller functions with descriptive names makes the code easier to follow.
+    ///
+    /// The rule reports the functions, the methods, the getters, the setters, and the constructors
+    /// whose number of lines exceeds `maxLines`, or whose number of statements exceeds `maxStatements`.
+    ///
+    /// The lines that only contain comments, and the blank lines, aren't counted.
+    /// The statements of the nested blocks are counted, while the statements of the nested functions
+    /// are only counted for these functions.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "maxLines": 4
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// function sum(values) {
+    ///     let total = 0;
+    ///     for (const value of values) {
+    ///         total += value;
+    ///     }
+    ///     return total;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,use_options
+    /// function sum(values) {
+    ///     // Blank lines and comments aren't counted
+    ///
+    ///     return values.reduce((total, value) => total + value, 0);
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxLines`
+    ///
+    /// The maximum number of lines of a function, including the lines of its signature.
+    /// The number of lines isn't checked when the option is `null`.
+    ///
+    /// Default: `50`
+    ///
+    /// ### `maxStatements`
+    ///
+    /// The maximum number of statements of a function.
+    /// The number of statements isn't checked when the option is `null`.
+    ///
+    /// Default: `null`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "maxLines": null,
+    ///         "maxStatements": 2
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// function init() {
+    ///     const config = loadConfig();
+    ///     validate(config);
+    ///     start(config);
+    /// }
+    /// ```
+    ///
+    pub UseMaxFunctionSize {
+        version: "next",
+        name: "useMaxFunctionSize",
+        language: "js",
+        sources: &[
+            RuleSource::Eslint("max-lines-per-function"),
+            RuleSource::Eslint("max-statements"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useMaxFunctionSize`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseMaxFunctionSizeOptions {
+    /// The maximum number of lines of a function, without the blank lines and the comments.
+    max_lines: Option<NonZeroU16>,
+    /// The maximum number of statements of a function.
+    max_statements: Option<NonZeroU16>,
+}
+
+impl Default for UseMaxFunctionSizeOptions {
+    fn default() -> Self {
+        Self {
+            max_lines: NonZeroU16::new(50),
+            max_statements: None,
+        }
+    }
+}
+
+declare_node_union! {
+    pub AnyJsFunctionLikeMember =
+        AnyJsFunction
+        | JsMethodClassMember
+        | JsMethodObjectMember
+        | JsConstructorClassMember
+        | JsGetterClassMember
+        | JsGetterObjectMember
+        | JsSetterClassMember
+        | JsSetterObjectMember
+}
+
+pub struct FunctionSize {
+    /// The number of lines, if it exceeds the maximum.
+    lines: Option<usize>,
+    /// The number of statements, if it exceeds the maximum.
+    statements: Option<usize>,
+}
+
+impl Rule for UseMaxFunctionSize {
+    type Query = Ast<AnyJsFunctionLikeMember>;
+    type State = FunctionSize;
+    type Signals = Option<Self::State>;
+    type Options = UseMaxFunctionSizeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query().syntax();
+        let options = ctx.options();
+        let lines = options.max_lines.and_then(|max_lines| {
+            let lines = count_lines(node);
+            (lines > max_lines.get().into()).then_some(lines)
+        });
+        let statements = options.max_statements.and_then(|max_statements| {
+            let statements = count_statements(node);
+            (statements > max_statements.get().into()).then_some(statements)
+        });
+        (lines.is_some() || statements.is_some()).then_some(FunctionSize { lines, statements })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let options = ctx.options();
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            signature_range(ctx.query().syntax()),
+            markup! {
+                "This function is too long."
+            },
+        );
+        if let (Some(lines), Some(max_lines)) = (state.lines, options.max_lines) {
+            let max_lines = max_lines.get();
+            diagnostic = diagnostic.note(markup! {
+                "It has "{lines}" lines of code, while the maximum is "{max_lines}"."
+            });
+        }
+        if let (Some(statements), Some(max_statements)) = (state.statements, options.max_statements)
+        {
+            let max_statements = max_statements.get();
+            diagnostic = diagnostic.note(markup! {
+                "It has "{statements}" statements, while the maximum is "{max_statements}"."
+            });
+        }
+        Some(diagnostic.note(markup! {
+            "Extract some of its code into smaller functions with descriptive names."
+        }))
+    }
+}
+
+/// Returns the number of lines of `node` that contain code.
+fn count_lines(node: &JsSyntaxNode) -> usize {
+    let mut line = 0;
+    let mut last_counted_line = None;
+    let mut count = 0;
+    for (index, token) in node.descendants_tokens(Direction::Next).enumerate() {
+        // The leading trivia of the first token precedes the function
+        if index > 0 {
+            line += newline_count(token.leading_trivia().text());
+        }
+        if last_counted_line != Some(line) {
+            count += 1;
+            last_counted_line = Some(line);
+        }
+        // The lines of a multiline token, such as a template chunk, all contain code
+        let token_lines = newline_count(token.text_trimmed());
+        line += token_lines;
+        count += token_lines;
+        if token_lines > 0 {
+            last_counted_line = Some(line);
+        }
+        line += newline_count(token.trailing_trivia().text());
+    }
+    count
+}
+
+fn newline_count(text: &str) -> usize {
+    text.chars().filter(|c| *c == '\n').count()
+}
+
+/// Returns the number of statements of `node`, without the statements of the nested functions.
+fn count_statements(node: &JsSyntaxNode) -> usize {
+    let mut count = 0;
+    let mut events = node.preorder();
+    while let Some(event) = events.next() {
+        let WalkEvent::Enter(descendant) = event else {
+            continue;
+        };
+        if &descendant != node && AnyJsFunctionLikeMember::can_cast(descendant.kind()) {
+            events.skip_subtree();
+        } else if let Some(statements) = JsStatementList::cast(descendant) {
+            count += statements.len();
+        }
+    }
+    count
+}
+
+/// Returns the range of the signature of the function `node`, i.e. everything before its body.
+fn signature_range(node: &JsSyntaxNode) -> TextRange {
+    let range = node.text_trimmed_range();
+    let signature_end = node
+        .last_child()
+        .and_then(|body| body.first_token()?.prev_token())
+        .map(|token| token.text_trimmed_range().end())
+        .filter(|end| range.contains(*end));
+    signature_end.map_or(range, |end| TextRange::new(range.start(), end))
+}
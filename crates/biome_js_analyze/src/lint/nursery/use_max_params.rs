@@ -0,0 +1,175 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyJsConstructorParameter, AnyJsParameter, JsConstructorParameters, JsParameters,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce a maximum number of parameters in the function signatures.
+    ///
+    /// A function with many parameters is hard to call correctly,
+    /// because the arguments have to be passed in the right order,
+    /// and it often does too many things.
+    /// Grouping the related parameters into an object makes the calls easier to read.
+    ///
+    /// A destructured parameter, such as `{ name, age }`, counts as a single parameter.
+    /// The `this` parameter of TypeScript isn't counted.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function createUser(name, email, age, role) {}
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// type Callback = (error: Error, result: string, attempt: number, retry: boolean) => void;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function createUser(name, email, { age, role }) {}
+    /// ```
+    ///
+    /// ```ts
+    /// function handle(this: Window, event: Event, target: Element, options: object) {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxParameters`
+    ///
+    /// The maximum number of parameters of a function.
+    ///
+    /// Default: `3`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "maxParameters": 4
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options
+    /// function createUser(name, email, age, role) {}
+    /// ```
+    ///
+    /// ### `ignoreParameterProperties`
+    ///
+    /// Whether to ignore the constructors that declare parameter properties,
+    /// such as the constructors of the classes that receive their dependencies from a dependency injection container.
+    ///
+    /// Default: `false`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignoreParameterProperties": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,use_options
+    /// class UserService {
+    ///     constructor(
+    ///         private readonly repository: UserRepository,
+    ///         private readonly mailer: Mailer,
+    ///         private readonly logger: Logger,
+    ///         private readonly clock: Clock,
+    ///     ) {}
+    /// }
+    /// ```
+    ///
+    pub UseMaxParams {
+        version: "next",
+        name: "useMaxParams",
+        language: "js",
+        sources: &[RuleSource::Eslint("max-params"), RuleSource::EslintTypeScript("max-params")],
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useMaxParams`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseMaxParamsOptions {
+    /// The maximum number of parameters of a function.
+    max_parameters: u8,
+    /// Whether to ignore the constructors that declare parameter properties.
+    ignore_parameter_properties: bool,
+}
+
+impl Default for UseMaxParamsOptions {
+    fn default() -> Self {
+        Self {
+            max_parameters: 3,
+            ignore_parameter_properties: false,
+        }
+    }
+}
+
+declare_node_union! {
+    pub AnyJsParameters = JsParameters | JsConstructorParameters
+}
+
+impl Rule for UseMaxParams {
+    type Query = Ast<AnyJsParameters>;
+    /// The number of parameters.
+    type State = usize;
+    type Signals = Option<Self::State>;
+    type Options = UseMaxParamsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let count = match ctx.query() {
+            AnyJsParameters::JsParameters(parameters) => parameters
+                .items()
+                .iter()
+                .filter(|parameter| !matches!(parameter, Ok(AnyJsParameter::TsThisParameter(_))))
+                .count(),
+            AnyJsParameters::JsConstructorParameters(parameters) => {
+                let parameters = parameters.parameters();
+                if options.ignore_parameter_properties
+                    && parameters.iter().any(|parameter| {
+                        matches!(
+                            parameter,
+                            Ok(AnyJsConstructorParameter::TsPropertyParameter(_))
+                        )
+                    })
+                {
+                    return None;
+                }
+                parameters.len()
+            }
+        };
+        (count > options.max_parameters.into()).then_some(count)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let max_parameters = ctx.options().max_parameters;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This function has "{state}" parameters, while the maximum is "{max_parameters}"."
+                },
+            )
+            .note(markup! {
+                "A function with many parameters is hard to call correctly."
+            })
+            .note(markup! {
+                "Group the related parameters into an object, or split the function into smaller functions."
+            }),
+        )
+    }
+}
@@ -0,0 +1,226 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{JsBigintLiteralExpression, JsNumberLiteralExpression, JsSyntaxToken};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Enforce the use of numeric separators in long numeric literals.
+    ///
+    /// A long sequence of digits, such as `1000000000`, is hard to read.
+    /// Since ES2021, the digits of a numeric literal can be separated by underscores, such as `1_000_000_000`,
+    /// without changing its value.
+    ///
+    /// The rule reports the numeric literals without any separator that have a long sequence of digits.
+    /// The code fix groups the digits by three, and the digits of the hexadecimal and binary literals by four.
+    /// The digits of the integer part are grouped from the right, and the digits of the fractional part from the left.
+    /// The exponent is left unchanged.
+    ///
+    /// The legacy octal literals, such as `0777`, and the decimal literals with a leading zero,
+    /// such as `0999`, are ignored because they don't accept separators.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const budget = 1000000;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const mask = 0xFFFFFFFF;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const precision = 0.0000001;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const budget = 1_000_000;
+    /// const mask = 0xFFFF_FFFF;
+    /// const port = 8080;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `minimumDigits`
+    ///
+    /// The minimum number of digits of the integer part or of the fractional part of a reported literal.
+    ///
+    /// Default: `5`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "minimumDigits": 7
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options
+    /// const timeout = 300000;
+    /// ```
+    ///
+    pub UseNumericSeparators {
+        version: "next",
+        name: "useNumericSeparators",
+        language: "js",
+        sources: &[RuleSource::EslintUnicorn("numeric-separators-style")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// Options for the rule `useNumericSeparators`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseNumericSeparatorsOptions {
+    /// The minimum number of digits of the integer part or of the fractional part of a reported literal.
+    minimum_digits: u8,
+}
+
+impl Default for UseNumericSeparatorsOptions {
+    fn default() -> Self {
+        Self { minimum_digits: 5 }
+    }
+}
+
+declare_node_union! {
+    pub AnyJsNumericLiteralExpression = JsNumberLiteralExpression | JsBigintLiteralExpression
+}
+
+impl AnyJsNumericLiteralExpression {
+    fn value_token(&self) -> Option<JsSyntaxToken> {
+        match self {
+            Self::JsNumberLiteralExpression(literal) => literal.value_token().ok(),
+            Self::JsBigintLiteralExpression(literal) => literal.value_token().ok(),
+        }
+    }
+}
+
+impl Rule for UseNumericSeparators {
+    type Query = Ast<AnyJsNumericLiteralExpression>;
+    /// The text of the literal with separators.
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = UseNumericSeparatorsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let token = ctx.query().value_token()?;
+        let text = token.text_trimmed();
+        if text.contains('_') {
+            return None;
+        }
+        let separated = with_separators(text, ctx.options().minimum_digits.into())?;
+        (separated != text).then_some(separated)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This long numeric literal is hard to read."
+                },
+            )
+            .note(markup! {
+                "Separate its digits with underscores, such as "<Emphasis>{state}</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let token = ctx.query().value_token()?;
+        let new_token = JsSyntaxToken::new_detached(token.kind(), state, [], []);
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(token, new_token);
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Add numeric separators." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `text`, the text of a numeric literal without separators,
+/// with separators in its sequences of at least `minimum_digits` digits.
+///
+/// Returns [None] for the literals that don't accept separators.
+fn with_separators(text: &str, minimum_digits: usize) -> Option<String> {
+    let (text, suffix) = match text.strip_suffix('n') {
+        Some(text) => (text, "n"),
+        None => (text, ""),
+    };
+    let prefix = text.get(..2).unwrap_or_default();
+    let group_length = match prefix {
+        "0x" | "0X" | "0b" | "0B" => Some(4),
+        "0o" | "0O" => Some(3),
+        _ => None,
+    };
+    if let Some(group_length) = group_length {
+        let digits = group_from_right(&text[2..], group_length, minimum_digits);
+        return Some(format!("{prefix}{digits}{suffix}"));
+    }
+    if text.len() > 1 && text.starts_with('0') && text.as_bytes()[1].is_ascii_digit() {
+        // Legacy octal literal, or decimal literal with a leading zero
+        return None;
+    }
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(index) => text.split_at(index),
+        None => (text, ""),
+    };
+    let (integer, fraction) = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (mantissa, None),
+    };
+    let mut result = group_from_right(integer, 3, minimum_digits);
+    if let Some(fraction) = fraction {
+        result.push('.');
+        result.push_str(&group_from_left(fraction, 3, minimum_digits));
+    }
+    result.push_str(exponent);
+    result.push_str(suffix);
+    Some(result)
+}
+
+/// Groups `digits` by `group_length` from the right, e.g. `1_000_000`.
+fn group_from_right(digits: &str, group_length: usize, minimum_digits: usize) -> String {
+    if digits.len() < minimum_digits {
+        return digits.to_string();
+    }
+    let mut result = String::with_capacity(digits.len() + digits.len() / group_length);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % group_length == 0 {
+            result.push('_');
+        }
+        result.push(digit);
+    }
+    result
+}
+
+/// Groups `digits` by `group_length` from the left, e.g. `000_000_1`.
+fn group_from_left(digits: &str, group_length: usize, minimum_digits: usize) -> String {
+    if digits.len() < minimum_digits {
+        return digits.to_string();
+    }
+    let mut result = String::with_capacity(digits.len() + digits.len() / group_length);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && index % group_length == 0 {
+            result.push('_');
+        }
+        result.push(digit);
+    }
+    result
+}
@@ -0,0 +1,330 @@
+use crate::services::semantic::Semantic;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_semantic::{Binding, SemanticModel};
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, global_identifier, inner_string_text,
+    AnyJsBindingPattern, AnyJsCallArgument, AnyJsExpression, AnyJsImportLike,
+    AnyJsLiteralExpression, AnyJsTemplateElement, JsBinaryExpression, JsBinaryOperator,
+    JsCallExpression, JsImport, JsSyntaxKind, JsTemplateExpression, TriviaPieceKind, T,
+};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, TokenText};
+
+declare_lint_rule! {
+    /// Disallow building file paths with string concatenation and hardcoded separators.
+    ///
+    /// Windows separates the segments of a file path with `\`, while the other platforms use `/`.
+    /// A path built with a hardcoded separator, such as `__dirname + "/data/users.json"`,
+    /// mixes the separators on Windows, which breaks the comparisons of paths and some tools.
+    /// The function `path.join()` of Node.js joins the segments with the separator of the platform,
+    /// and `path.posix.join()` always uses `/`.
+    ///
+    /// The rule reports the concatenations and the template literals that join a directory with a string
+    /// that contains `/` or `\`.
+    /// The following expressions are directories: `__dirname`, `import.meta.dirname`, and `process.cwd()`.
+    ///
+    /// The rule provides an unsafe code fix that replaces the expression with a call to `path.join()`,
+    /// when every segment of the path is either a string or an expression,
+    /// and when the module `node:path` is imported or required as `path`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const config = __dirname + "/config/default.json";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const output = `${process.cwd()}\\dist\\${name}`;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import path from "node:path";
+    /// const config = path.join(__dirname, "config", "default.json");
+    /// ```
+    ///
+    /// ```js
+    /// const url = baseUrl + "/api/users";
+    /// ```
+    ///
+    pub UsePlatformAgnosticPaths {
+        version: "next",
+        name: "usePlatformAgnosticPaths",
+        language: "js",
+        sources: &[RuleSource::EslintN("no-path-concat")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsPathConcatenation = JsBinaryExpression | JsTemplateExpression
+}
+
+/// A part of a concatenated path.
+pub enum PathPart {
+    /// The source text of a string or of a template chunk, without its delimiters.
+    Text(TokenText),
+    Expression(AnyJsExpression),
+}
+
+impl Rule for UsePlatformAgnosticPaths {
+    type Query = Semantic<AnyJsPathConcatenation>;
+    /// The parts of the path, in order.
+    type State = Box<[PathPart]>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        let expression = concatenation_expression(ctx.query())?;
+        // The parts of a concatenation are collected from the outermost expression
+        let is_operand = expression
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+            .and_then(JsBinaryExpression::cast)
+            .is_some_and(|binary| matches!(binary.operator(), Ok(JsBinaryOperator::Plus)));
+        if is_operand {
+            return None;
+        }
+        let mut parts = Vec::new();
+        collect_path_parts(expression, &mut parts);
+        let has_directory = parts.iter().any(|part| {
+            matches!(part, PathPart::Expression(expression) if is_directory(expression, model))
+        });
+        let has_separator = parts
+            .iter()
+            .any(|part| matches!(part, PathPart::Text(text) if text.contains(['/', '\\'])));
+        (has_directory && has_separator).then(|| parts.into())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This file path is built with a hardcoded separator."
+                },
+            )
+            .note(markup! {
+                "The separator of the file paths depends on the platform: Windows uses "<Emphasis>"\\"</Emphasis>", while the other platforms use "<Emphasis>"/"</Emphasis>"."
+            })
+            .note(markup! {
+                "Use "<Emphasis>"path.join()"</Emphasis>" to join the segments with the separator of the platform, or "<Emphasis>"path.posix.join()"</Emphasis>" to always use "<Emphasis>"/"</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, parts: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        if node.syntax().has_comments_descendants() {
+            return None;
+        }
+        let path = ctx
+            .model()
+            .scope(node.syntax())
+            .ancestors()
+            .find_map(|scope| scope.get_binding("path"))?;
+        if !is_path_module(&path) {
+            return None;
+        }
+        let mut segments = Vec::new();
+        let mut expression = None;
+        let mut text = String::new();
+        for part in parts.iter() {
+            match part {
+                PathPart::Expression(part) => {
+                    if expression.is_some() || !text.is_empty() {
+                        // A segment that mixes an expression with some text
+                        return None;
+                    }
+                    expression = Some(part.clone().trim_trivia()?);
+                }
+                PathPart::Text(part) => {
+                    let mut chars = part.chars();
+                    while let Some(char) = chars.next() {
+                        match char {
+                            '/' => push_segment(&mut segments, &mut expression, &mut text)?,
+                            // Only the escaped backslashes are supported
+                            '\\' if chars.next() == Some('\\') => {
+                                push_segment(&mut segments, &mut expression, &mut text)?
+                            }
+                            '\\' | '"' => return None,
+                            _ if expression.is_some() => return None,
+                            _ => text.push(char),
+                        }
+                    }
+                }
+            }
+        }
+        push_segment(&mut segments, &mut expression, &mut text)?;
+        let separators = (1..segments.len())
+            .map(|_| make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]));
+        let join = make::js_static_member_expression(
+            make::js_identifier_expression(make::js_reference_identifier(make::ident("path")))
+                .into(),
+            make::token(T![.]),
+            make::js_name(make::ident("join")).into(),
+        );
+        let call = make::js_call_expression(
+            join.into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(segments, separators),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(concatenation_expression(node)?, AnyJsExpression::from(call));
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>"path.join()"</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the expression of `node` if it can concatenate strings.
+fn concatenation_expression(node: &AnyJsPathConcatenation) -> Option<AnyJsExpression> {
+    match node {
+        AnyJsPathConcatenation::JsBinaryExpression(binary) => {
+            matches!(binary.operator(), Ok(JsBinaryOperator::Plus)).then(|| binary.clone().into())
+        }
+        AnyJsPathConcatenation::JsTemplateExpression(template) => {
+            template.tag().is_none().then(|| template.clone().into())
+        }
+    }
+}
+
+/// Adds to `parts` the strings and the expressions concatenated by `expression`.
+fn collect_path_parts(expression: AnyJsExpression, parts: &mut Vec<PathPart>) {
+    match expression.omit_parentheses() {
+        AnyJsExpression::JsBinaryExpression(binary)
+            if matches!(binary.operator(), Ok(JsBinaryOperator::Plus)) =>
+        {
+            if let (Ok(left), Ok(right)) = (binary.left(), binary.right()) {
+                collect_path_parts(left, parts);
+                collect_path_parts(right, parts);
+            }
+        }
+        AnyJsExpression::JsTemplateExpression(template) if template.tag().is_none() => {
+            for element in template.elements() {
+                match element {
+                    AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                        if let Ok(chunk) = chunk.template_chunk_token() {
+                            parts.push(PathPart::Text(chunk.token_text_trimmed()));
+                        }
+                    }
+                    AnyJsTemplateElement::JsTemplateElement(element) => {
+                        if let Ok(expression) = element.expression() {
+                            parts.push(PathPart::Expression(expression));
+                        }
+                    }
+                }
+            }
+        }
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsStringLiteralExpression(string),
+        ) => {
+            if let Ok(token) = string.value_token() {
+                parts.push(PathPart::Text(inner_string_text(&token)));
+            }
+        }
+        expression => parts.push(PathPart::Expression(expression)),
+    }
+}
+
+/// Returns `true` if `expression` is `__dirname`, `import.meta.dirname`, or `process.cwd()`.
+fn is_directory(expression: &AnyJsExpression, model: &SemanticModel) -> bool {
+    let (global, name) = match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            return matches!(
+                member.object(),
+                Ok(AnyJsExpression::JsImportMetaExpression(_))
+            ) && member
+                .member()
+                .is_ok_and(|member| member.syntax().text_trimmed() == "dirname");
+        }
+        AnyJsExpression::JsCallExpression(call) => {
+            let Some(object) = cwd_object(&call) else {
+                return false;
+            };
+            (object, "process")
+        }
+        expression => (expression, "__dirname"),
+    };
+    global_identifier(&global.omit_parentheses()).is_some_and(|(reference, global)| {
+        global.text() == name && model.binding(&reference).is_none()
+    })
+}
+
+/// Returns the object of `call` if it's a call to the method `cwd()` without arguments.
+fn cwd_object(call: &JsCallExpression) -> Option<AnyJsExpression> {
+    if call.is_optional_chain() || !call.arguments().ok()?.args().is_empty() {
+        return None;
+    }
+    let callee = call.callee().ok()?.omit_parentheses();
+    let callee = callee.as_js_static_member_expression()?;
+    if callee.member().ok()?.syntax().text_trimmed() != "cwd" {
+        return None;
+    }
+    callee.object().ok()
+}
+
+/// Adds the segment made of `expression` or `text` to `segments`.
+/// Returns `None` if the segment is empty.
+fn push_segment(
+    segments: &mut Vec<AnyJsCallArgument>,
+    expression: &mut Option<AnyJsExpression>,
+    text: &mut String,
+) -> Option<()> {
+    let segment = match expression.take() {
+        Some(expression) => expression,
+        None if !text.is_empty() => {
+            make::js_string_literal_expression(make::js_string_literal(text)).into()
+        }
+        None => return None,
+    };
+    text.clear();
+    segments.push(segment.into());
+    Some(())
+}
+
+/// Returns `true` if `binding` is the module `node:path`, imported or required as a whole.
+fn is_path_module(binding: &Binding) -> bool {
+    let source = match binding.tree().declaration() {
+        Some(
+            AnyJsBindingDeclaration::JsDefaultImportSpecifier(_)
+            | AnyJsBindingDeclaration::JsNamespaceImportSpecifier(_),
+        ) => binding
+            .syntax()
+            .ancestors()
+            .find_map(|ancestor| JsImport::cast(ancestor)?.source_text().ok()),
+        Some(AnyJsBindingDeclaration::JsVariableDeclarator(declarator)) => {
+            if !matches!(declarator.id(), Ok(AnyJsBindingPattern::AnyJsBinding(_))) {
+                return false;
+            }
+            declarator
+                .initializer()
+                .and_then(|initializer| initializer.expression().ok())
+                .and_then(|expression| AnyJsImportLike::cast(expression.into_syntax()))
+                .and_then(|import| import.inner_string_text())
+        }
+        _ => None,
+    };
+    source.is_some_and(|source| matches!(source.text(), "path" | "node:path"))
+}
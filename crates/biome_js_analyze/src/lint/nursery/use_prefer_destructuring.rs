@@ -0,0 +1,310 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsAssignment, AnyJsAssignmentPattern, AnyJsBinding, AnyJsBindingPattern, AnyJsExpression,
+    AnyJsName, AnyJsObjectBindingPatternMember, JsAssignmentExpression, JsAssignmentOperator,
+    JsStaticMemberExpression, JsVariableDeclarator, JsVariableKind, T,
+};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, TextRange, TriviaPieceKind};
+use serde::{Deserialize, Serialize};
+
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Require destructuring from arrays and objects.
+    ///
+    /// When a variable is assigned a property of an object, or an element of an array,
+    /// destructuring avoids repeating the name of the property, and makes the intent clearer.
+    ///
+    /// The rule reports the variable declarations and the assignments whose value is:
+    ///
+    /// - an element of an array accessed by an integer index, e.g. `const first = array[0]`;
+    /// - a property of an object with the same name as the variable, e.g. `const name = user.name`.
+    ///
+    /// Only the declarations of a single identifier are reported, and the optional chains,
+    /// the private properties, and the properties of `super` are ignored.
+    ///
+    /// The code fix converts `const name = user.name` into `const { name } = user`.
+    /// It isn't provided for the assignments, the array accesses, and the declarations with a type annotation.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const name = user.name;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const first = array[0];
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// let name;
+    /// name = user.name;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const { name } = user;
+    /// const [first] = array;
+    /// const fullName = user.name;
+    /// const last = array[array.length - 1];
+    /// user.name = other.name;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `array`
+    ///
+    /// Whether to report the accesses of an array element by an integer index.
+    ///
+    /// Default: `true`
+    ///
+    /// ### `object`
+    ///
+    /// Whether to report the accesses of an object property.
+    ///
+    /// Default: `true`
+    ///
+    /// ### `enforceForRenamedProperties`
+    ///
+    /// Whether to also report the accesses of an object property whose name differs from the name of the variable,
+    /// e.g. `const fullName = user.name`, which can be written `const { name: fullName } = user`.
+    /// The computed accesses that aren't an array access, such as `object[key]`, are also reported.
+    ///
+    /// Default: `false`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "array": false,
+    ///         "enforceForRenamedProperties": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// const fullName = user.name;
+    /// ```
+    ///
+    pub UsePreferDestructuring {
+        version: "next",
+        name: "usePreferDestructuring",
+        language: "js",
+        sources: &[RuleSource::Eslint("prefer-destructuring")],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// Options for the rule `usePreferDestructuring`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UsePreferDestructuringOptions {
+    /// Whether to report the accesses of an array element by an integer index.
+    array: bool,
+    /// Whether to report the accesses of an object property.
+    object: bool,
+    /// Whether to also report the accesses of an object property whose name differs from the name of the variable.
+    enforce_for_renamed_properties: bool,
+}
+
+impl Default for UsePreferDestructuringOptions {
+    fn default() -> Self {
+        Self {
+            array: true,
+            object: true,
+            enforce_for_renamed_properties: false,
+        }
+    }
+}
+
+declare_node_union! {
+    pub AnyJsDestructurableAssignment = JsVariableDeclarator | JsAssignmentExpression
+}
+
+pub enum DestructuringKind {
+    Array,
+    Object,
+}
+
+pub struct PreferDestructuring {
+    range: TextRange,
+    kind: DestructuringKind,
+}
+
+impl Rule for UsePreferDestructuring {
+    type Query = Ast<AnyJsDestructurableAssignment>;
+    type State = PreferDestructuring;
+    type Signals = Option<Self::State>;
+    type Options = UsePreferDestructuringOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let (name, value) = match ctx.query() {
+            AnyJsDestructurableAssignment::JsVariableDeclarator(declarator) => {
+                // `using` declarations don't accept patterns
+                if declarator.declaration().is_some_and(|declaration| {
+                    declaration.variable_kind() == Ok(JsVariableKind::Using)
+                }) {
+                    return None;
+                }
+                let AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(id)) =
+                    declarator.id().ok()?
+                else {
+                    return None;
+                };
+                let value = declarator.initializer()?.expression().ok()?;
+                (id.name_token().ok()?.token_text_trimmed(), value)
+            }
+            AnyJsDestructurableAssignment::JsAssignmentExpression(assignment) => {
+                if assignment.operator().ok()? != JsAssignmentOperator::Assign {
+                    return None;
+                }
+                let AnyJsAssignmentPattern::AnyJsAssignment(
+                    AnyJsAssignment::JsIdentifierAssignment(id),
+                ) = assignment.left().ok()?
+                else {
+                    return None;
+                };
+                (
+                    id.name_token().ok()?.token_text_trimmed(),
+                    assignment.right().ok()?,
+                )
+            }
+        };
+        let (object, kind) = match value.omit_parentheses() {
+            AnyJsExpression::JsStaticMemberExpression(member) => {
+                if member.is_optional_chain() {
+                    return None;
+                }
+                let AnyJsName::JsName(property) = member.member().ok()? else {
+                    return None;
+                };
+                let is_same_name = property.value_token().ok()?.text_trimmed() == name.text();
+                let kind = (options.object
+                    && (is_same_name || options.enforce_for_renamed_properties))
+                    .then_some(DestructuringKind::Object)?;
+                (member.object().ok()?, kind)
+            }
+            AnyJsExpression::JsComputedMemberExpression(member) => {
+                if member.is_optional_chain() {
+                    return None;
+                }
+                let property = member.member().ok()?.omit_parentheses();
+                let kind = if let Some(index) = property
+                    .as_any_js_literal_expression()
+                    .and_then(|literal| literal.as_js_number_literal_expression()?.as_number())
+                {
+                    (options.array && index.fract() == 0.0).then_some(DestructuringKind::Array)?
+                } else {
+                    let is_same_name = property
+                        .as_static_value()
+                        .is_some_and(|value| value.as_string_constant() == Some(name.text()));
+                    (options.object && (is_same_name || options.enforce_for_renamed_properties))
+                        .then_some(DestructuringKind::Object)?
+                };
+                (member.object().ok()?, kind)
+            }
+            _ => return None,
+        };
+        if matches!(object, AnyJsExpression::JsSuperExpression(_)) {
+            return None;
+        }
+        Some(PreferDestructuring {
+            range: ctx.query().range(),
+            kind,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (message, example) = match state.kind {
+            DestructuringKind::Array => (
+                markup! { "Use array destructuring instead of accessing an element by its index." },
+                markup! { "For example, "<Emphasis>"const [first] = array"</Emphasis>" assigns the first element of the array." },
+            ),
+            DestructuringKind::Object => (
+                markup! { "Use object destructuring instead of accessing a property." },
+                markup! { "For example, "<Emphasis>"const { name } = user"</Emphasis>" assigns the property "<Emphasis>"name"</Emphasis>" of the object." },
+            ),
+        };
+        Some(RuleDiagnostic::new(rule_category!(), state.range, message).note(example))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        if !matches!(state.kind, DestructuringKind::Object) {
+            return None;
+        }
+        let AnyJsDestructurableAssignment::JsVariableDeclarator(declarator) = ctx.query() else {
+            return None;
+        };
+        if declarator.variable_annotation().is_some()
+            || declarator.syntax().has_comments_descendants()
+        {
+            return None;
+        }
+        let id = declarator.id().ok()?;
+        let member = declarator.initializer()?.expression().ok()?;
+        let AnyJsExpression::JsStaticMemberExpression(static_member) = member.omit_parentheses()
+        else {
+            return None;
+        };
+        // Only the properties with the same name as the variable can use the shorthand syntax
+        if !is_same_name(&id, &static_member) {
+            return None;
+        }
+        let object = static_member.object().ok()?;
+        let name = id
+            .as_any_js_binding()?
+            .as_js_identifier_binding()?
+            .name_token()
+            .ok()?;
+        let pattern = make::js_object_binding_pattern(
+            make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            make::js_object_binding_pattern_property_list(
+                [
+                    AnyJsObjectBindingPatternMember::JsObjectBindingPatternShorthandProperty(
+                        make::js_object_binding_pattern_shorthand_property(
+                            make::js_identifier_binding(
+                                make::ident(name.text_trimmed())
+                                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                            )
+                            .into(),
+                        )
+                        .build(),
+                    ),
+                ],
+                [],
+            ),
+            make::token(T!['}']),
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(id, pattern.into());
+        mutation.replace_node(AnyJsExpression::from(static_member), object);
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Use object destructuring." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+fn is_same_name(id: &AnyJsBindingPattern, member: &JsStaticMemberExpression) -> bool {
+    let Some(AnyJsBinding::JsIdentifierBinding(id)) = id.as_any_js_binding() else {
+        return false;
+    };
+    let (Ok(name), Ok(AnyJsName::JsName(property))) = (id.name_token(), member.member()) else {
+        return false;
+    };
+    property
+        .value_token()
+        .is_ok_and(|property| property.text_trimmed() == name.text_trimmed())
+}
@@ -0,0 +1,243 @@
+use crate::services::semantic::Semantic;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    global_identifier, AnyJsCallArgument, AnyJsExpression, AnyJsMemberExpression,
+    AnyJsObjectMember, JsCallExpression, JsObjectExpression, JsSyntaxKind, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, Direction, TriviaPieceKind};
+
+declare_lint_rule! {
+    /// Prefer object spread over `Object.assign()` when constructing a new object.
+    ///
+    /// `Object.assign({}, a, b)` copies the properties of `a` and `b` into a new object.
+    /// The object spread syntax `{ ...a, ...b }` does the same, and is more concise.
+    ///
+    /// The rule reports the calls to `Object.assign()` whose first argument is an object literal,
+    /// since the object is created by the call. The calls whose first argument is another value are ignored,
+    /// because they modify this value.
+    /// The calls that spread an array, such as `Object.assign({}, ...objects)`, and the object literals
+    /// with a getter or a setter are also ignored.
+    ///
+    /// The code fix is safe when the first argument is an empty object.
+    /// Otherwise, the members of the first object are merged with the spread arguments, and the code fix is only a suggestion.
+    /// The code fix isn't provided when a comment would be removed, such as a comment inside `Object.assign(`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const merged = Object.assign({}, defaults, options);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const user = Object.assign({ role: "admin" }, profile);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const merged = { ...defaults, ...options };
+    /// ```
+    ///
+    /// ```js
+    /// Object.assign(target, source);
+    /// ```
+    ///
+    /// ```js
+    /// const merged = Object.assign({}, ...sources);
+    /// ```
+    ///
+    pub UsePreferObjectSpread {
+        version: "next",
+        name: "usePreferObjectSpread",
+        language: "js",
+        sources: &[RuleSource::Eslint("prefer-object-spread")],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for UsePreferObjectSpread {
+    type Query = Semantic<JsCallExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let model = ctx.model();
+        if call.optional_chain_token().is_some() {
+            return None;
+        }
+        let callee =
+            AnyJsMemberExpression::cast(call.callee().ok()?.omit_parentheses().into_syntax())?;
+        if callee.member_name()?.text() != "assign" {
+            return None;
+        }
+        let (reference, name) = global_identifier(&callee.object().ok()?.omit_parentheses())?;
+        if name.text() != "Object" || model.binding(&reference).is_some() {
+            return None;
+        }
+        let argument_list = call.arguments().ok()?.args();
+        let mut arguments = argument_list.iter();
+        let object = first_object(arguments.next()?.ok()?)?;
+        let has_accessor = object.members().iter().flatten().any(|member| {
+            matches!(
+                member,
+                AnyJsObjectMember::JsGetterObjectMember(_)
+                    | AnyJsObjectMember::JsSetterObjectMember(_)
+            )
+        });
+        // `Object.assign({}, ...objects)` can't be written with an object spread
+        let has_spread =
+            arguments.any(|argument| matches!(argument, Ok(AnyJsCallArgument::JsSpread(_))));
+        (!has_accessor && !has_spread).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This "<Emphasis>"Object.assign()"</Emphasis>" call creates a new object."
+                },
+            )
+            .note(markup! {
+                "Use an object literal with spread properties instead, which is more concise."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let call = ctx.query();
+        let arguments = call.arguments().ok()?;
+        let argument_list = arguments.args();
+        let mut elements = argument_list.elements();
+        let first = elements.next()?;
+        let object = first_object(first.node().ok()?.clone())?;
+        let first_separator = first.trailing_separator().ok()?;
+        // These tokens are removed, or lose their trivia, so their comments would be lost
+        let has_removed_comments = call
+            .callee()
+            .ok()?
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .enumerate()
+            .any(|(index, token)| {
+                token.has_trailing_comments() || (index > 0 && token.has_leading_comments())
+            })
+            || arguments.l_paren_token().ok()?.has_trailing_comments()
+            || arguments.l_paren_token().ok()?.has_leading_comments()
+            || arguments.r_paren_token().ok()?.has_leading_comments()
+            || object.r_curly_token().ok()?.has_trailing_comments()
+            || first_separator.is_some_and(|separator| {
+                separator.has_leading_comments() || separator.has_trailing_comments()
+            });
+        if has_removed_comments {
+            return None;
+        }
+        let mut members = Vec::new();
+        let mut separators = Vec::new();
+        for element in object.members().elements() {
+            members.push(element.node().ok()?.clone());
+            separators.extend(element.trailing_separator().ok()?.cloned());
+        }
+        let is_empty = members.is_empty();
+        let mut has_spread = false;
+        for element in elements {
+            let argument = element.node().ok()?.as_any_js_expression()?.clone();
+            // The spread operator takes the leading newline and comments of the argument
+            let leading_trivia = argument.syntax().first_leading_trivia()?;
+            let argument = argument.with_leading_trivia_pieces([])?;
+            let dotdotdot =
+                make::token(T![...]).with_leading_trivia_pieces(leading_trivia.pieces());
+            if separators.len() < members.len() {
+                // Separates the members of the first object from the spread arguments
+                separators.push(first_separator?.clone());
+                // `{ a: 1 }` becomes `{ a: 1, ...b }` instead of `{ a: 1 , ...b}`
+                if let Some(member) = members.pop() {
+                    members.push(member.trim_trailing_trivia()?);
+                }
+            }
+            members.push(make::js_spread(dotdotdot, argument).into());
+            separators.extend(element.trailing_separator().ok()?.cloned());
+            has_spread = true;
+        }
+        let mut l_curly = object.l_curly_token().ok()?;
+        let mut r_curly = object.r_curly_token().ok()?;
+        if has_spread {
+            if is_empty && l_curly.trailing_trivia().is_empty() {
+                l_curly = l_curly.with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+            }
+            let is_padded = l_curly
+                .trailing_trivia()
+                .pieces()
+                .last()
+                .is_some_and(|piece| piece.is_whitespace());
+            if is_padded && r_curly.leading_trivia().is_empty() {
+                r_curly = r_curly.with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]);
+            }
+        }
+        let object = make::js_object_expression(
+            l_curly,
+            make::js_object_member_list(members, separators),
+            r_curly,
+        );
+        let new_expression: AnyJsExpression = if is_first_in_statement_or_arrow_body(call) {
+            make::parenthesized(object).into()
+        } else {
+            object.into()
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(call.clone()), new_expression);
+        let applicability = if is_empty {
+            ctx.metadata().applicability()
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            applicability,
+            markup! { "Use an object literal with spread properties." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the object literal passed as `argument`, if any.
+fn first_object(argument: AnyJsCallArgument) -> Option<JsObjectExpression> {
+    match argument {
+        AnyJsCallArgument::AnyJsExpression(AnyJsExpression::JsObjectExpression(object)) => {
+            Some(object)
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if an object literal that replaces `call` would be parsed as a block,
+/// e.g. `Object.assign({}, a).b;` or `() => Object.assign({}, a)`.
+fn is_first_in_statement_or_arrow_body(call: &JsCallExpression) -> bool {
+    let mut node = call.syntax().clone();
+    while let Some(parent) = node.parent() {
+        match parent.kind() {
+            JsSyntaxKind::JS_EXPRESSION_STATEMENT | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION => {
+                return true
+            }
+            _ if AnyJsExpression::can_cast(parent.kind())
+                && parent.text_trimmed_range().start() == node.text_trimmed_range().start() =>
+            {
+                node = parent;
+            }
+            _ => return false,
+        }
+    }
+    false
+}
@@ -0,0 +1,350 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    modifier_ext::Modifier, AnyJsClass, AnyJsClassMember, AnyJsClassMemberName,
+    AnyJsConstructorParameter, AnyJsExpression, AnyJsFormalParameter, AnyJsName, ClassMemberName,
+    JsComputedMemberAssignment, JsFileSource, JsPropertyClassMember, JsStaticMemberAssignment,
+    JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, TsPropertyParameter, T,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeList, AstSeparatedList, BatchMutationExt, TextRange,
+    TriviaPieceKind,
+};
+use enumflags2::BitFlags;
+
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Enforce marking private properties as `readonly` if they're never modified outside the constructor.
+    ///
+    /// A private property can only be assigned by the code of its class.
+    /// When the class only assigns it in the constructor, the property can be marked as `readonly`.
+    /// This documents that the property doesn't change after the construction of the instance,
+    /// and lets the compiler report any later assignment.
+    ///
+    /// The rule checks the properties declared with the TypeScript `private` modifier or with a `#private` name,
+    /// and the private parameter properties of the constructor.
+    /// A property is reported when all its assignments are `this` assignments directly in the constructor of the class.
+    /// Static properties are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Counter {
+    ///     private step: number;
+    ///
+    ///     constructor(step: number) {
+    ///         this.step = step;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Store {
+    ///     #cache = new Map();
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Service {
+    ///     constructor(private client: Client) {}
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// class Counter {
+    ///     private count = 0;
+    ///
+    ///     increment() {
+    ///         this.count++;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// class Service {
+    ///     constructor(private readonly client: Client) {}
+    /// }
+    /// ```
+    ///
+    pub UseReadonlyClassProperties {
+        version: "next",
+        name: "useReadonlyClassProperties",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("prefer-readonly")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    pub AnyPrivateProperty = JsPropertyClassMember | TsPropertyParameter
+}
+
+impl Rule for UseReadonlyClassProperties {
+    type Query = Ast<AnyJsClass>;
+    type State = AnyPrivateProperty;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.source_type::<JsFileSource>().language().is_typescript() {
+            return Box::default();
+        }
+        let class = ctx.query();
+        let mut properties: Vec<_> = class
+            .members()
+            .iter()
+            .filter_map(|member| match member {
+                AnyJsClassMember::JsPropertyClassMember(property) => {
+                    Some(AnyPrivateProperty::from(property))
+                }
+                _ => None,
+            })
+            .chain(constructor_property_parameters(class))
+            .filter_map(|property| {
+                let name = property.readonly_candidate_name()?;
+                Some((name, property))
+            })
+            .collect();
+        if properties.is_empty() {
+            return Box::default();
+        }
+        for node in class.syntax().descendants() {
+            let Some(assigned_member) = assigned_member(&node) else {
+                continue;
+            };
+            if !assigned_member.is_this_member || !is_in_constructor_of(&node, class) {
+                properties.retain(|(name, _)| !assigned_member.is_member(name));
+                if properties.is_empty() {
+                    break;
+                }
+            }
+        }
+        properties
+            .into_iter()
+            .map(|(_, property)| property)
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.name_range()?,
+                markup! {
+                    "This private property is never modified outside the constructor."
+                },
+            )
+            .note(markup! {
+                "Marking it as "<Emphasis>"readonly"</Emphasis>" lets the compiler report the assignments that happen after the construction of the instance."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let readonly_token =
+            make::token(T![readonly]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+        match state {
+            AnyPrivateProperty::JsPropertyClassMember(property) => {
+                let modifiers = property.modifiers();
+                let mut new_property = property.clone();
+                let mut new_modifiers: Vec<_> = modifiers.iter().collect();
+                let readonly_token = if new_modifiers.is_empty() {
+                    // The modifier takes the leading trivia of the name.
+                    let name = property.name().ok()?;
+                    let leading_trivia = name.syntax().first_leading_trivia()?;
+                    new_property = new_property.with_name(name.with_leading_trivia_pieces([])?);
+                    readonly_token.with_leading_trivia_pieces(leading_trivia.pieces())
+                } else {
+                    readonly_token
+                };
+                new_modifiers.push(make::ts_readonly_modifier(readonly_token).into());
+                let new_property =
+                    new_property.with_modifiers(make::js_property_modifier_list(new_modifiers));
+                mutation.replace_node(property.clone(), new_property);
+            }
+            AnyPrivateProperty::TsPropertyParameter(parameter) => {
+                let mut new_modifiers: Vec<_> = parameter.modifiers().iter().collect();
+                new_modifiers.push(make::ts_readonly_modifier(readonly_token).into());
+                let new_parameter = parameter
+                    .clone()
+                    .with_modifiers(make::ts_property_parameter_modifier_list(new_modifiers));
+                mutation.replace_node(parameter.clone(), new_parameter);
+            }
+        }
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Add the "<Emphasis>"readonly"</Emphasis>" modifier." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+impl AnyPrivateProperty {
+    /// Returns the name of the property if it's a private property that can be marked as `readonly`.
+    fn readonly_candidate_name(&self) -> Option<ClassMemberName> {
+        match self {
+            Self::JsPropertyClassMember(property) => {
+                let modifiers = BitFlags::<Modifier>::from(&property.modifiers());
+                if modifiers.intersects(Modifier::Static | Modifier::Readonly | Modifier::Accessor)
+                {
+                    return None;
+                }
+                let name = property.name().ok()?;
+                let is_private = matches!(name, AnyJsClassMemberName::JsPrivateClassMemberName(_))
+                    || modifiers.contains(Modifier::Private);
+                if is_private {
+                    name.name()
+                } else {
+                    None
+                }
+            }
+            Self::TsPropertyParameter(parameter) => {
+                let modifiers = parameter
+                    .modifiers()
+                    .iter()
+                    .map(|modifier| Modifier::from(&modifier))
+                    .collect::<BitFlags<_>>();
+                if !modifiers.contains(Modifier::Private) || modifiers.contains(Modifier::Readonly)
+                {
+                    return None;
+                }
+                let name = parameter_identifier(parameter)?;
+                Some(ClassMemberName::Public(name.token_text_trimmed()))
+            }
+        }
+    }
+
+    fn name_range(&self) -> Option<TextRange> {
+        match self {
+            Self::JsPropertyClassMember(property) => Some(property.name().ok()?.range()),
+            Self::TsPropertyParameter(parameter) => {
+                Some(parameter_identifier(parameter)?.text_trimmed_range())
+            }
+        }
+    }
+}
+
+/// Returns the name token of the parameter property `parameter`.
+fn parameter_identifier(parameter: &TsPropertyParameter) -> Option<JsSyntaxToken> {
+    match parameter.formal_parameter().ok()? {
+        AnyJsFormalParameter::JsFormalParameter(parameter) => parameter
+            .binding()
+            .ok()?
+            .as_any_js_binding()?
+            .as_js_identifier_binding()?
+            .name_token()
+            .ok(),
+        AnyJsFormalParameter::JsBogusParameter(_) | AnyJsFormalParameter::JsMetavariable(_) => None,
+    }
+}
+
+/// Returns the parameter properties of the constructor of `class`.
+fn constructor_property_parameters(class: &AnyJsClass) -> Vec<AnyPrivateProperty> {
+    class
+        .members()
+        .iter()
+        .find_map(|member| match member {
+            AnyJsClassMember::JsConstructorClassMember(constructor) => {
+                constructor.parameters().ok()
+            }
+            _ => None,
+        })
+        .map(|parameters| {
+            parameters
+                .parameters()
+                .iter()
+                .filter_map(|parameter| match parameter.ok()? {
+                    AnyJsConstructorParameter::TsPropertyParameter(parameter) => {
+                        Some(parameter.into())
+                    }
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The name of a member that is the target of an assignment.
+struct AssignedMember {
+    is_private: bool,
+    name: String,
+    /// `true` if the member is assigned on `this`.
+    is_this_member: bool,
+}
+
+impl AssignedMember {
+    fn is_member(&self, name: &ClassMemberName) -> bool {
+        match name {
+            ClassMemberName::Private(name) => self.is_private && self.name == name.text(),
+            ClassMemberName::Public(name) => !self.is_private && self.name == name.text(),
+        }
+    }
+}
+
+/// Returns the assigned member if `node` is the target of an assignment to a member,
+/// such as `this.a = 0` or `other.#b++`.
+fn assigned_member(node: &JsSyntaxNode) -> Option<AssignedMember> {
+    let (object, is_private, name) = match node.kind() {
+        JsSyntaxKind::JS_STATIC_MEMBER_ASSIGNMENT => {
+            let assignment = JsStaticMemberAssignment::cast_ref(node)?;
+            let member = assignment.member().ok()?;
+            let is_private = matches!(member, AnyJsName::JsPrivateName(_));
+            let name = member.value_token().ok()?.text_trimmed().to_string();
+            (assignment.object().ok()?, is_private, name)
+        }
+        JsSyntaxKind::JS_COMPUTED_MEMBER_ASSIGNMENT => {
+            let assignment = JsComputedMemberAssignment::cast_ref(node)?;
+            let member = assignment.member().ok()?.omit_parentheses();
+            let name = member.as_static_value()?.as_string_constant()?.to_string();
+            (assignment.object().ok()?, false, name)
+        }
+        _ => return None,
+    };
+    Some(AssignedMember {
+        is_private,
+        name,
+        is_this_member: matches!(
+            object.omit_parentheses(),
+            AnyJsExpression::JsThisExpression(_)
+        ),
+    })
+}
+
+/// Returns `true` if the closest function of `node` is the constructor of `class`.
+fn is_in_constructor_of(node: &JsSyntaxNode, class: &AnyJsClass) -> bool {
+    let Some(function) = node.ancestors().find(|ancestor| {
+        matches!(
+            ancestor.kind(),
+            JsSyntaxKind::JS_FUNCTION_DECLARATION
+                | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+                | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+                | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+                | JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+                | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+                | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+                | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+                | JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER
+                | JsSyntaxKind::JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER
+                | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+                | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+                | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+        )
+    }) else {
+        return false;
+    };
+    function.kind() == JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+        && function.grand_parent().as_ref() == Some(class.syntax())
+}
@@ -0,0 +1,338 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsStatement, JsBinaryOperator, JsConditionalExpression, JsIfStatement,
+    JsReturnStatement, JsStatementList, JsSyntaxNode, JsUnaryExpression, JsUnaryOperator,
+    OperatorPrecedence, T,
+};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, Direction, TriviaPieceKind};
+
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Disallow returning boolean literals that depend on a condition, instead of the condition itself.
+    ///
+    /// An `if` statement that returns `true` in one branch and `false` in the other one,
+    /// or a `return` of a conditional expression between `true` and `false`,
+    /// can be replaced by a single `return` of the condition.
+    ///
+    /// The code fix returns the condition, negated with `!` when the condition returns `false`.
+    /// When the condition isn't known to be a boolean, unlike a comparison or a negation,
+    /// the code fix converts it to a boolean with `!!` to preserve the returned value.
+    ///
+    /// The conditional expressions between boolean literals outside of `return` statements
+    /// are reported by [noUselessTernary](https://biomejs.dev/linter/rules/no-useless-ternary/).
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function isAdult(age) {
+    ///     if (age >= 18) {
+    ///         return true;
+    ///     } else {
+    ///         return false;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function isEmpty(list) {
+    ///     if (list.length) return false;
+    ///     return true;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function hasName(user) {
+    ///     return user.name ? true : false;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function isAdult(age) {
+    ///     return age >= 18;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// function check(value) {
+    ///     if (value) {
+    ///         log(value);
+    ///         return true;
+    ///     }
+    ///     return false;
+    /// }
+    /// ```
+    ///
+    pub UseSimplifiedBooleanReturn {
+        version: "next",
+        name: "useSimplifiedBooleanReturn",
+        language: "js",
+        sources: &[
+            RuleSource::EslintSonarJs("prefer-single-boolean-return"),
+            RuleSource::Clippy("needless_bool"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsBooleanReturn = JsIfStatement | JsReturnStatement
+}
+
+pub struct BooleanReturn {
+    /// The condition that determines the returned value.
+    test: AnyJsExpression,
+    /// Whether `false` is returned when the condition is truthy.
+    negated: bool,
+    /// The conditional expression between the boolean literals, for a `return` statement.
+    conditional: Option<JsConditionalExpression>,
+    /// The `return` statement that follows an `if` statement without `else` clause.
+    following_return: Option<JsReturnStatement>,
+}
+
+impl Rule for UseSimplifiedBooleanReturn {
+    type Query = Ast<AnyJsBooleanReturn>;
+    type State = BooleanReturn;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        match ctx.query() {
+            AnyJsBooleanReturn::JsIfStatement(statement) => {
+                let consequent = returned_boolean(&statement.consequent().ok()?)?;
+                let (alternate, following_return) = match statement.else_clause() {
+                    Some(else_clause) => (returned_boolean(&else_clause.alternate().ok()?)?, None),
+                    None => {
+                        // The `if` statement must be followed by the other `return` statement
+                        JsStatementList::cast(statement.syntax().parent()?)?;
+                        let following = AnyJsStatement::cast(statement.syntax().next_sibling()?)?;
+                        let alternate = returned_boolean(&following)?;
+                        (alternate, following.as_js_return_statement().cloned())
+                    }
+                };
+                if consequent == alternate {
+                    return None;
+                }
+                Some(BooleanReturn {
+                    test: statement.test().ok()?,
+                    negated: !consequent,
+                    conditional: None,
+                    following_return,
+                })
+            }
+            AnyJsBooleanReturn::JsReturnStatement(statement) => {
+                let argument = statement.argument()?.omit_parentheses();
+                let conditional = argument.as_js_conditional_expression()?;
+                let consequent = boolean_literal(&conditional.consequent().ok()?)?;
+                let alternate = boolean_literal(&conditional.alternate().ok()?)?;
+                if consequent == alternate {
+                    return None;
+                }
+                Some(BooleanReturn {
+                    test: conditional.test().ok()?,
+                    negated: !consequent,
+                    conditional: Some(conditional.clone()),
+                    following_return: None,
+                })
+            }
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = if let Some(conditional) = &state.conditional {
+            RuleDiagnostic::new(
+                rule_category!(),
+                conditional.range(),
+                markup! {
+                    "This conditional expression returns boolean literals instead of its condition."
+                },
+            )
+        } else {
+            let range = match &state.following_return {
+                Some(following_return) => ctx.query().range().cover(following_return.range()),
+                None => ctx.query().range(),
+            };
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This "<Emphasis>"if"</Emphasis>" statement returns boolean literals instead of its condition."
+                },
+            )
+        };
+        Some(diagnostic.note(markup! {
+            "Return the condition directly to simplify the code."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let test = simplified_condition(state.test.clone().trim_trivia()?, state.negated)?;
+        let mut mutation = ctx.root().begin();
+        if let Some(conditional) = &state.conditional {
+            if conditional.syntax().has_comments_descendants() {
+                return None;
+            }
+            let leading_trivia = conditional.syntax().first_leading_trivia()?;
+            let test = test.with_leading_trivia_pieces(leading_trivia.pieces())?;
+            mutation.replace_node(AnyJsExpression::from(conditional.clone()), test);
+        } else {
+            let AnyJsBooleanReturn::JsIfStatement(statement) = ctx.query() else {
+                return None;
+            };
+            if has_inner_comments(statement.syntax())
+                || state
+                    .following_return
+                    .as_ref()
+                    .is_some_and(|following| following.syntax().has_comments_descendants())
+            {
+                return None;
+            }
+            let if_token = statement.if_token().ok()?;
+            let return_token = make::token(T![return])
+                .with_leading_trivia_pieces(if_token.leading_trivia().pieces())
+                .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+            let mut return_statement = make::js_return_statement(return_token).with_argument(test);
+            let consequent = statement.consequent().ok()?;
+            if returned_statement(&consequent)?.semicolon_token().is_some() {
+                return_statement = return_statement.with_semicolon_token(make::token(T![;]));
+            }
+            mutation.replace_node(
+                AnyJsStatement::from(statement.clone()),
+                AnyJsStatement::from(return_statement.build()),
+            );
+            if let Some(following_return) = &state.following_return {
+                mutation.remove_node(following_return.clone());
+            }
+        }
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Return the condition." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the `return` statement of `statement`, if it's the only statement of a block.
+fn returned_statement(statement: &AnyJsStatement) -> Option<JsReturnStatement> {
+    match statement {
+        AnyJsStatement::JsReturnStatement(statement) => Some(statement.clone()),
+        AnyJsStatement::JsBlockStatement(block) => {
+            let mut statements = block.statements().into_iter();
+            let statement = statements.next()?;
+            if statements.next().is_some() {
+                return None;
+            }
+            returned_statement(&statement)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the value of the boolean literal returned by `statement`.
+fn returned_boolean(statement: &AnyJsStatement) -> Option<bool> {
+    boolean_literal(&returned_statement(statement)?.argument()?)
+}
+
+fn boolean_literal(expression: &AnyJsExpression) -> Option<bool> {
+    let literal = expression
+        .clone()
+        .omit_parentheses()
+        .as_any_js_literal_expression()?
+        .as_js_boolean_literal_expression()?
+        .value_token()
+        .ok()?;
+    Some(literal.kind() == T![true])
+}
+
+/// Returns `true` if the tokens of `node` have comments, without the leading comments of the node.
+fn has_inner_comments(node: &JsSyntaxNode) -> bool {
+    let mut tokens = node.descendants_tokens(Direction::Next);
+    tokens
+        .next()
+        .is_some_and(|first| first.has_trailing_comments())
+        || tokens.any(|token| token.has_leading_comments() || token.has_trailing_comments())
+}
+
+/// Returns the expression that evaluates to the boolean returned for the condition `test`.
+fn simplified_condition(test: AnyJsExpression, negated: bool) -> Option<AnyJsExpression> {
+    if negated {
+        if let Some(binary) = test.as_js_binary_expression() {
+            let operator = match binary.operator().ok()? {
+                JsBinaryOperator::Equality => Some(T![!=]),
+                JsBinaryOperator::StrictEquality => Some(T![!==]),
+                JsBinaryOperator::Inequality => Some(T![==]),
+                JsBinaryOperator::StrictInequality => Some(T![===]),
+                _ => None,
+            };
+            if let Some(operator) = operator {
+                let operator_token = binary.operator_token().ok()?;
+                return Some(
+                    binary
+                        .clone()
+                        .with_operator_token_token(
+                            make::token(operator)
+                                .with_leading_trivia_pieces(
+                                    operator_token.leading_trivia().pieces(),
+                                )
+                                .with_trailing_trivia_pieces(
+                                    operator_token.trailing_trivia().pieces(),
+                                ),
+                        )
+                        .into(),
+                );
+            }
+        }
+        Some(negation(test)?.into())
+    } else if is_boolean_expression(&test) {
+        Some(test)
+    } else {
+        let negation = make::js_unary_expression(make::token(T![!]), negation(test)?.into());
+        Some(negation.into())
+    }
+}
+
+/// Returns `!test`, with parentheses around `test` when they're needed.
+fn negation(test: AnyJsExpression) -> Option<JsUnaryExpression> {
+    let argument = if test.precedence().ok()? < OperatorPrecedence::Unary {
+        make::parenthesized(test).into()
+    } else {
+        test
+    };
+    Some(make::js_unary_expression(make::token(T![!]), argument))
+}
+
+/// Returns `true` if `expression` is known to evaluate to a boolean.
+fn is_boolean_expression(expression: &AnyJsExpression) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(literal) => {
+            literal.as_js_boolean_literal_expression().is_some()
+        }
+        AnyJsExpression::JsBinaryExpression(binary) => binary.is_comparison_operator(),
+        AnyJsExpression::JsInExpression(_) | AnyJsExpression::JsInstanceofExpression(_) => true,
+        AnyJsExpression::JsUnaryExpression(unary) => {
+            matches!(unary.operator(), Ok(JsUnaryOperator::LogicalNot))
+        }
+        AnyJsExpression::JsLogicalExpression(logical) => {
+            logical
+                .left()
+                .is_ok_and(|left| is_boolean_expression(&left))
+                && logical
+                    .right()
+                    .is_ok_and(|right| is_boolean_expression(&right))
+        }
+        AnyJsExpression::JsCallExpression(call) => call.has_callee("Boolean"),
+        _ => false,
+    }
+}
@@ -0,0 +1,151 @@
+use crate::services::semantic::Semantic;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    global_identifier, AnyJsExpression, AnyJsMemberExpression, JsCallExpression,
+};
+use biome_rowan::{AstNode, BatchMutationExt, Direction};
+
+declare_lint_rule! {
+    /// Prefer `structuredClone()` to deep clone a value with `JSON.parse()` and `JSON.stringify()`.
+    ///
+    /// `JSON.parse(JSON.stringify(value))` is a common idiom to deep clone a value.
+    /// The global function `structuredClone()`, available in browsers and in Node.js 17,
+    /// clones the value directly without serializing it to a string, and expresses the intent more clearly.
+    ///
+    /// The two approaches don't support the same values:
+    /// `structuredClone()` keeps the `Date`, `Map`, `Set`, and `RegExp` objects, and the `undefined` properties,
+    /// which JSON converts or drops. However, it throws a `DataCloneError` on the functions, the symbols,
+    /// and the DOM nodes, which `JSON.stringify()` silently ignores.
+    /// For this reason, the code fix is unsafe.
+    /// It isn't provided when the expression contains comments.
+    ///
+    /// The calls with a reviver or a replacer, such as `JSON.parse(JSON.stringify(value), reviver)`, are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const copy = JSON.parse(JSON.stringify(state));
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const copy = structuredClone(state);
+    /// ```
+    ///
+    /// ```js
+    /// const copy = JSON.parse(JSON.stringify(state, replacer));
+    /// ```
+    ///
+    pub UseStructuredClone {
+        version: "next",
+        name: "useStructuredClone",
+        language: "js",
+        sources: &[RuleSource::EslintUnicorn("prefer-structured-clone")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseStructuredClone {
+    type Query = Semantic<JsCallExpression>;
+    /// The call to `JSON.stringify()`.
+    type State = JsCallExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let model = ctx.model();
+        let text = single_argument_of_json_call(call, "parse", model)?;
+        let AnyJsExpression::JsCallExpression(stringify_call) = text.omit_parentheses() else {
+            return None;
+        };
+        single_argument_of_json_call(&stringify_call, "stringify", model)?;
+        Some(stringify_call)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This value is deep cloned with "<Emphasis>"JSON.parse()"</Emphasis>" and "<Emphasis>"JSON.stringify()"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Use "<Emphasis>"structuredClone()"</Emphasis>" instead, which clones the value without serializing it to a string."
+            })
+            .note(markup! {
+                "Unlike JSON, "<Emphasis>"structuredClone()"</Emphasis>" keeps the dates, the maps, and the sets, but it throws on the functions, the symbols, and the DOM nodes."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, stringify_call: &Self::State) -> Option<JsRuleAction> {
+        let call = ctx.query();
+        // Only the comments at the edges of the expression are kept
+        let last_token = call.syntax().last_token()?;
+        let has_inner_comments = call
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .enumerate()
+            .any(|(index, token)| {
+                (index > 0 && token.has_leading_comments())
+                    || (token != last_token && token.has_trailing_comments())
+            });
+        if has_inner_comments {
+            return None;
+        }
+        let structured_clone = make::js_call_expression(
+            make::js_identifier_expression(make::js_reference_identifier(make::ident(
+                "structuredClone",
+            )))
+            .into(),
+            stringify_call.arguments().ok()?,
+        )
+        .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(call.clone(), structured_clone);
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>"structuredClone()"</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the single argument of `call` if it calls the method `method` of the global `JSON`.
+fn single_argument_of_json_call(
+    call: &JsCallExpression,
+    method: &str,
+    model: &SemanticModel,
+) -> Option<AnyJsExpression> {
+    if call.is_optional_chain() {
+        return None;
+    }
+    let callee = AnyJsMemberExpression::cast(call.callee().ok()?.omit_parentheses().into_syntax())?;
+    if callee.member_name()?.text() != method {
+        return None;
+    }
+    let (reference, name) = global_identifier(&callee.object().ok()?.omit_parentheses())?;
+    if name.text() != "JSON" || model.binding(&reference).is_some() {
+        return None;
+    }
+    let [Some(argument), None] = call.arguments().ok()?.get_arguments_by_index([0, 1]) else {
+        return None;
+    };
+    argument.as_any_js_expression().cloned()
+}
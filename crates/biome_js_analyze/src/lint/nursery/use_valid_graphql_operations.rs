@@ -0,0 +1,495 @@
+use crate::graphql::GraphqlSchema;
+use crate::services::project::Project;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_graphql_parser::parse_graphql;
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlSelection, GraphqlOperationDefinition, GraphqlRoot,
+    GraphqlSelectionSet, GraphqlVariableReference,
+};
+use biome_js_syntax::{AnyJsTemplateElement, JsTemplateExpression, TextRange, TextSize};
+use biome_rowan::AstNode;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+declare_lint_rule! {
+    /// Enforce that the GraphQL operations of the `gql` and `graphql` tagged templates are valid
+    /// against the schema of the project.
+    ///
+    /// The rule parses the GraphQL document of the templates tagged with `gql` or `graphql`,
+    /// and reports:
+    ///
+    /// - the fields that don't exist on their type;
+    /// - the arguments that don't exist on their field;
+    /// - the variables that an operation uses without defining them.
+    ///
+    /// The rule requires the schema of the project: it's read from a GraphQL file of the project,
+    /// written with the schema definition language (SDL), that is set with the `schema` option.
+    /// The rule doesn't report anything without this option.
+    /// Biome must process the schema file, for example by enabling the GraphQL linter with `graphql.linter.enabled`,
+    /// and the file must not be ignored.
+    ///
+    /// When Biome analyzes the whole project, e.g. with the `lint` or the `check` command,
+    /// the rule reports the tagged templates if the schema file isn't found.
+    /// In editors, the rule doesn't report anything until the schema file is open.
+    ///
+    /// The substitutions of the templates, such as the fragments defined in other templates,
+    /// are ignored, as well as the documents that have syntax errors.
+    /// The fragments are checked against the type of their type condition.
+    ///
+    /// The following examples use this schema, declared in the file `schema.graphql`:
+    ///
+    /// ```graphql
+    /// type Query {
+    ///     user(id: ID!): User
+    /// }
+    ///
+    /// type User {
+    ///     id: ID!
+    ///     name: String!
+    ///     friends(first: Int): [User!]!
+    /// }
+    /// ```
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "schema": "schema.graphql"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// const GET_USER = gql`
+    ///     query GetUser($id: ID!) {
+    ///         user(id: $id) {
+    ///             email
+    ///         }
+    ///     }
+    /// `;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// const GET_FRIENDS = gql`
+    ///     query GetFriends($id: ID!) {
+    ///         user(id: $id) {
+    ///             friends(last: 10) {
+    ///                 name
+    ///             }
+    ///         }
+    ///     }
+    /// `;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// const GET_USER = gql`
+    ///     query GetUser {
+    ///         user(id: $id) {
+    ///             name
+    ///         }
+    ///     }
+    /// `;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,use_options
+    /// const GET_USER = gql`
+    ///     query GetUser($id: ID!, $first: Int) {
+    ///         user(id: $id) {
+    ///             name
+    ///             friends(first: $first) {
+    ///                 ...UserName
+    ///             }
+    ///         }
+    ///     }
+    ///     ${USER_NAME}
+    /// `;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `schema`
+    ///
+    /// The path of the GraphQL file that declares the schema.
+    /// The rule uses the schema file of the project whose path ends with this path,
+    /// for example `src/schema.graphql` for `schema.graphql`.
+    /// When several files match, the one with the shortest path is used.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "schema": "src/schema.graphql"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseValidGraphqlOperations {
+        version: "next",
+        name: "useValidGraphqlOperations",
+        language: "js",
+        sources: &[
+            RuleSource::EslintGraphql("fields-on-correct-type"),
+            RuleSource::EslintGraphql("known-argument-names"),
+            RuleSource::EslintGraphql("no-undefined-variables"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useValidGraphqlOperations`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseValidGraphqlOperationsOptions {
+    /// The path of the GraphQL file that declares the schema.
+    schema: Box<str>,
+}
+
+pub enum InvalidOperationKind {
+    MissingSchema,
+    UnknownField { name: Box<str>, type_name: Box<str> },
+    UnknownArgument { name: Box<str>, field: Box<str> },
+    UndefinedVariable { name: Box<str> },
+}
+
+pub struct InvalidOperation {
+    range: TextRange,
+    kind: InvalidOperationKind,
+}
+
+impl Rule for UseValidGraphqlOperations {
+    type Query = Project<JsTemplateExpression>;
+    type State = InvalidOperation;
+    type Signals = Box<[Self::State]>;
+    type Options = UseValidGraphqlOperationsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let template = ctx.query();
+        let is_graphql_tag = template
+            .tag()
+            .and_then(|tag| {
+                tag.omit_parentheses()
+                    .as_js_identifier_expression()
+                    .cloned()
+            })
+            .and_then(|identifier| identifier.name().ok()?.value_token().ok())
+            .is_some_and(|name| matches!(name.text_trimmed(), "gql" | "graphql"));
+        if !is_graphql_tag {
+            return Box::default();
+        }
+        let schema_path = ctx.options().schema.as_ref();
+        if schema_path.is_empty() {
+            return Box::default();
+        }
+        let Some(schema) = ctx.project().graphql_schema(Path::new(schema_path)) else {
+            // The schema could be in a file that isn't indexed yet
+            if !ctx.project().is_complete() {
+                return Box::default();
+            }
+            let Some(tag) = template.tag() else {
+                return Box::default();
+            };
+            return Box::new([InvalidOperation {
+                range: tag.range(),
+                kind: InvalidOperationKind::MissingSchema,
+            }]);
+        };
+        let Some((source, offset)) = document_source(template) else {
+            return Box::default();
+        };
+        let parsed = parse_graphql(&source);
+        if parsed.has_errors() {
+            return Box::default();
+        }
+        let mut invalid_operations = Vec::new();
+        validate_document(&parsed.tree(), &schema, &mut invalid_operations);
+        for invalid_operation in &mut invalid_operations {
+            invalid_operation.range += offset;
+        }
+        invalid_operations.into_boxed_slice()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let schema = ctx.options().schema.as_ref();
+        let diagnostic = match &state.kind {
+            InvalidOperationKind::MissingSchema => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The GraphQL schema "<Emphasis>{schema}</Emphasis>" isn't found in the project."
+                },
+            )
+            .note(markup! {
+                "The operations of the template can't be checked without the schema."
+            })
+            .note(markup! {
+                "Set the "<Emphasis>"schema"</Emphasis>" option to the path of a GraphQL file of the project, and make sure that Biome doesn't ignore it."
+            }),
+            InvalidOperationKind::UnknownField { name, type_name } => {
+                let name = name.as_ref();
+                let type_name = type_name.as_ref();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    state.range,
+                    markup! {
+                        "The type "<Emphasis>{type_name}</Emphasis>" doesn't have a field "<Emphasis>{name}</Emphasis>"."
+                    },
+                )
+                .note(markup! {
+                    "The operations are checked against the schema "<Emphasis>{schema}</Emphasis>"."
+                })
+            }
+            InvalidOperationKind::UnknownArgument { name, field } => {
+                let name = name.as_ref();
+                let field = field.as_ref();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    state.range,
+                    markup! {
+                        "The field "<Emphasis>{field}</Emphasis>" doesn't have an argument "<Emphasis>{name}</Emphasis>"."
+                    },
+                )
+                .note(markup! {
+                    "The operations are checked against the schema "<Emphasis>{schema}</Emphasis>"."
+                })
+            }
+            InvalidOperationKind::UndefinedVariable { name } => {
+                let name = name.as_ref();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    state.range,
+                    markup! {
+                        "The variable "<Emphasis>"$"{name}</Emphasis>" isn't defined by the operation."
+                    },
+                )
+                .note(markup! {
+                    "Define the variable and its type after the name of the operation, such as "<Emphasis>"query Name($"{name}": Type)"</Emphasis>"."
+                })
+            }
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the GraphQL document of `template`, and the offset of the document in the file.
+///
+/// The substitutions are replaced with spaces, so the ranges of the document match
+/// the ranges of the template.
+fn document_source(template: &JsTemplateExpression) -> Option<(String, TextSize)> {
+    let offset = template.l_tick_token().ok()?.text_trimmed_range().end();
+    let mut source = String::new();
+    for element in template.elements() {
+        match element {
+            AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                source.push_str(chunk.template_chunk_token().ok()?.text_trimmed());
+            }
+            AnyJsTemplateElement::JsTemplateElement(element) => {
+                let length = usize::from(element.syntax().text_trimmed_range().len());
+                source.extend(std::iter::repeat(' ').take(length));
+            }
+        }
+    }
+    Some((source, offset))
+}
+
+fn validate_document(
+    root: &GraphqlRoot,
+    schema: &GraphqlSchema,
+    invalid_operations: &mut Vec<InvalidOperation>,
+) {
+    for definition in root.definitions() {
+        match definition {
+            AnyGraphqlDefinition::GraphqlOperationDefinition(operation) => {
+                let Ok(operation_type) = operation.ty().and_then(|ty| ty.value_token()) else {
+                    continue;
+                };
+                if let Ok(selection_set) = operation.selection_set() {
+                    let root_type = schema.root_type(operation_type.text_trimmed());
+                    validate_selection_set(&selection_set, root_type, schema, invalid_operations);
+                }
+                validate_variables(&operation, invalid_operations);
+            }
+            AnyGraphqlDefinition::GraphqlSelectionSet(selection_set) => {
+                // `{ field }` is a query without name nor variables
+                let root_type = schema.root_type("query");
+                validate_selection_set(&selection_set, root_type, schema, invalid_operations);
+                for variable in selection_set
+                    .syntax()
+                    .descendants()
+                    .filter_map(GraphqlVariableReference::cast)
+                {
+                    if let Ok(name) = variable.name().and_then(|name| name.value_token()) {
+                        invalid_operations.push(InvalidOperation {
+                            range: variable.range(),
+                            kind: InvalidOperationKind::UndefinedVariable {
+                                name: name.text_trimmed().into(),
+                            },
+                        });
+                    }
+                }
+            }
+            AnyGraphqlDefinition::GraphqlFragmentDefinition(fragment) => {
+                let (Ok(type_condition), Ok(selection_set)) = (
+                    fragment
+                        .type_condition()
+                        .and_then(|type_condition| type_condition.ty())
+                        .and_then(|ty| ty.value_token()),
+                    fragment.selection_set(),
+                ) else {
+                    continue;
+                };
+                validate_selection_set(
+                    &selection_set,
+                    type_condition.text_trimmed(),
+                    schema,
+                    invalid_operations,
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Reports the unknown fields and the unknown arguments of `selection_set`,
+/// a selection set of the type named `type_name`.
+///
+/// The selection sets of the types that aren't object types nor interface types,
+/// such as the unions, aren't checked, except for their inline fragments.
+fn validate_selection_set(
+    selection_set: &GraphqlSelectionSet,
+    type_name: &str,
+    schema: &GraphqlSchema,
+    invalid_operations: &mut Vec<InvalidOperation>,
+) {
+    let fields = schema.fields(type_name);
+    for selection in selection_set.selections() {
+        match selection {
+            AnyGraphqlSelection::GraphqlField(field) => {
+                let Some(fields) = fields else {
+                    continue;
+                };
+                let Ok(name) = field.name().and_then(|name| name.value_token()) else {
+                    continue;
+                };
+                let name = name.text_trimmed();
+                // `__typename`, `__schema`, and `__type` are always available
+                if name.starts_with("__") {
+                    continue;
+                }
+                let Some(schema_field) = fields.get(name) else {
+                    invalid_operations.push(InvalidOperation {
+                        range: field.name().map_or(field.range(), |name| name.range()),
+                        kind: InvalidOperationKind::UnknownField {
+                            name: name.into(),
+                            type_name: type_name.into(),
+                        },
+                    });
+                    continue;
+                };
+                for argument in field
+                    .arguments()
+                    .into_iter()
+                    .flat_map(|arguments| arguments.arguments())
+                {
+                    let Ok(argument_name) = argument.name() else {
+                        continue;
+                    };
+                    let Ok(argument_token) = argument_name.value_token() else {
+                        continue;
+                    };
+                    let argument_text = argument_token.text_trimmed();
+                    if !schema_field
+                        .arguments
+                        .iter()
+                        .any(|schema_argument| schema_argument.as_ref() == argument_text)
+                    {
+                        invalid_operations.push(InvalidOperation {
+                            range: argument_name.range(),
+                            kind: InvalidOperationKind::UnknownArgument {
+                                name: argument_text.into(),
+                                field: name.into(),
+                            },
+                        });
+                    }
+                }
+                if let Some(field_selection_set) = field.selection_set() {
+                    validate_selection_set(
+                        &field_selection_set,
+                        &schema_field.type_name,
+                        schema,
+                        invalid_operations,
+                    );
+                }
+            }
+            AnyGraphqlSelection::GraphqlInlineFragment(fragment) => {
+                let Ok(fragment_selection_set) = fragment.selection_set() else {
+                    continue;
+                };
+                let type_condition = fragment
+                    .type_condition()
+                    .and_then(|type_condition| type_condition.ty().ok())
+                    .and_then(|ty| ty.value_token().ok());
+                let fragment_type_name = type_condition
+                    .as_ref()
+                    .map_or(type_name, |type_condition| type_condition.text_trimmed());
+                validate_selection_set(
+                    &fragment_selection_set,
+                    fragment_type_name,
+                    schema,
+                    invalid_operations,
+                );
+            }
+            AnyGraphqlSelection::GraphqlFragmentSpread(_)
+            | AnyGraphqlSelection::GraphqlBogusSelection(_) => {}
+        }
+    }
+}
+
+/// Reports the variables that `operation` uses without defining them.
+fn validate_variables(
+    operation: &GraphqlOperationDefinition,
+    invalid_operations: &mut Vec<InvalidOperation>,
+) {
+    let defined_variables: FxHashSet<String> = operation
+        .variables()
+        .into_iter()
+        .flat_map(|variables| variables.elements())
+        .filter_map(|definition| {
+            let name = definition
+                .variable()
+                .ok()?
+                .name()
+                .ok()?
+                .value_token()
+                .ok()?;
+            Some(name.text_trimmed().to_string())
+        })
+        .collect();
+    for variable in operation
+        .syntax()
+        .descendants()
+        .filter_map(GraphqlVariableReference::cast)
+    {
+        let Ok(name) = variable.name().and_then(|name| name.value_token()) else {
+            continue;
+        };
+        if !defined_variables.contains(name.text_trimmed()) {
+            invalid_operations.push(InvalidOperation {
+                range: variable.range(),
+                kind: InvalidOperationKind::UndefinedVariable {
+                    name: name.text_trimmed().into(),
+                },
+            });
+        }
+    }
+}
@@ -1,9 +1,15 @@
 use crate::services::semantic::Semantic;
 use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource};
 use biome_console::markup;
-use biome_js_semantic::{AllBindingWriteReferencesIter, Reference, ReferencesExtensions};
-use biome_js_syntax::{AnyJsBinding, AnyJsBindingPattern, AnyJsFormalParameter, AnyJsParameter};
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::{Reference, ReferencesExtensions};
+use biome_js_syntax::{
+    AnyJsAssignment, AnyJsBinding, AnyJsBindingPattern, AnyJsFormalParameter,
+    AnyJsMemberExpression, AnyJsParameter, JsIdentifierExpression, JsParenthesizedExpression,
+    JsUnaryExpression, JsUnaryOperator, TextRange, TsNonNullAssertionExpression,
+};
 use biome_rowan::AstNode;
+use serde::{Deserialize, Serialize};
 
 declare_lint_rule! {
     /// Disallow reassigning `function` parameters.
@@ -12,8 +18,8 @@ declare_lint_rule! {
     /// as modifying parameters will also mutate the `arguments` object.
     /// It is often unintended and indicative of a programmer error.
     ///
-    /// In contrast to the _ESLint_ rule, this rule cannot be configured to report
-    /// assignments to a property of a parameter.
+    /// By default, the rule doesn't report the assignments to a property of a parameter.
+    /// They can be reported with the `props` option.
     ///
     /// ## Examples
     ///
@@ -53,6 +59,52 @@ declare_lint_rule! {
     /// }
     /// ```
     ///
+    /// ## Options
+    ///
+    /// ### `props`
+    ///
+    /// When `true`, the rule also reports the modifications of a property of a parameter:
+    /// the assignments, the increments and decrements, and the `delete` operations.
+    /// These modifications mutate the object passed by the caller.
+    ///
+    /// Default: `false`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "props": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options,expect_diagnostic
+    /// function f(param) {
+    ///     param.prop = 13;
+    /// }
+    /// ```
+    ///
+    /// ### `ignorePropertyModificationsFor`
+    ///
+    /// The names of the parameters whose properties can be modified when `props` is `true`,
+    /// such as the accumulator of a reducer.
+    /// The parameters of this list are still reported when they are reassigned.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "props": true,
+    ///         "ignorePropertyModificationsFor": ["acc"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,use_options
+    /// const counts = words.reduce((acc, word) => {
+    ///     acc[word] = (acc[word] ?? 0) + 1;
+    ///     return acc;
+    /// }, {});
+    /// ```
+    ///
     pub NoParameterAssign {
         version: "1.0.0",
         name: "noParameterAssign",
@@ -62,43 +114,95 @@ declare_lint_rule! {
     }
 }
 
+/// Options for the rule `noParameterAssign`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoParameterAssignOptions {
+    /// Whether to report the modifications of a property of a parameter.
+    props: bool,
+    /// The names of the parameters whose properties can be modified when `props` is enabled.
+    ignore_property_modifications_for: Box<[Box<str>]>,
+}
+
+pub enum ParameterAssignment {
+    /// The parameter is reassigned, e.g. `param = 0`.
+    Reassignment(Reference),
+    /// A property of the parameter is modified, e.g. `param.prop = 0`.
+    PropertyModification(TextRange),
+}
+
 impl Rule for NoParameterAssign {
     type Query = Semantic<AnyJsParameter>;
-    type State = Reference;
-    type Signals = AllBindingWriteReferencesIter;
-    type Options = ();
+    type State = ParameterAssignment;
+    type Signals = Box<[Self::State]>;
+    type Options = NoParameterAssignOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let param = ctx.query();
         let model = ctx.model();
-        if let Some(AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding))) =
+        let options = ctx.options();
+        let Some(AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding))) =
             binding_of(param)
-        {
-            return binding.all_writes(model);
+        else {
+            return Box::default();
+        };
+        let mut signals: Vec<_> = binding
+            .all_writes(model)
+            .map(ParameterAssignment::Reassignment)
+            .collect();
+        let is_ignored = binding.name_token().is_ok_and(|name| {
+            options
+                .ignore_property_modifications_for
+                .iter()
+                .any(|ignored| name.text_trimmed() == ignored.as_ref())
+        });
+        if options.props && !is_ignored {
+            signals.extend(
+                binding
+                    .all_reads(model)
+                    .filter_map(|reference| modified_property(&reference))
+                    .map(ParameterAssignment::PropertyModification),
+            );
         }
-        // Empty iterator that conforms to `AllBindingWriteReferencesIter` type.
-        std::iter::successors(None, |_| None)
+        signals.into_boxed_slice()
     }
 
-    fn diagnostic(ctx: &RuleContext<Self>, reference: &Self::State) -> Option<RuleDiagnostic> {
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
         let param = ctx.query();
-        Some(
-            RuleDiagnostic::new(
+        let diagnostic = match state {
+            ParameterAssignment::Reassignment(reference) => RuleDiagnostic::new(
                 rule_category!(),
                 reference.syntax().text_trimmed_range(),
                 markup! {
                     "Reassigning a "<Emphasis>"function parameter"</Emphasis>" is confusing."
                 },
-            )
-            .detail(
-                param.syntax().text_trimmed_range(),
+            ),
+            ParameterAssignment::PropertyModification(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
                 markup! {
-                    "The "<Emphasis>"parameter"</Emphasis>" is declared here:"
+                    "Modifying a property of a "<Emphasis>"function parameter"</Emphasis>" mutates the object passed by the caller."
                 },
-            )
-            .note(markup! {
+            ),
+        };
+        let note = match state {
+            ParameterAssignment::Reassignment(_) => markup! {
                 "Use a local variable instead."
-            }),
+            },
+            ParameterAssignment::PropertyModification(_) => markup! {
+                "Copy the object to a local variable, and modify this copy instead."
+            },
+        };
+        Some(
+            diagnostic
+                .detail(
+                    param.syntax().text_trimmed_range(),
+                    markup! {
+                        "The "<Emphasis>"parameter"</Emphasis>" is declared here:"
+                    },
+                )
+                .note(note),
         )
     }
 }
@@ -115,3 +219,39 @@ fn binding_of(param: &AnyJsParameter) -> Option<AnyJsBindingPattern> {
         AnyJsParameter::TsThisParameter(_) => None,
     }
 }
+
+/// Returns the range of the property that `reference` modifies, if any,
+/// e.g. `param.prop` in `param.prop = 0` or in `delete param.prop`.
+fn modified_property(reference: &Reference) -> Option<TextRange> {
+    let mut object = reference.syntax().parent()?;
+    if !JsIdentifierExpression::can_cast(object.kind()) {
+        return None;
+    }
+    // Whether `object` is a member of the parameter, e.g. `param.prop`
+    let mut is_member = false;
+    loop {
+        let parent = object.parent()?;
+        if let Some(member) = AnyJsMemberExpression::cast_ref(&parent) {
+            if member.object().ok()?.syntax() != &object {
+                return None;
+            }
+            is_member = true;
+        } else if let Some(assignment) = AnyJsAssignment::cast_ref(&parent) {
+            let assigned_object = match &assignment {
+                AnyJsAssignment::JsStaticMemberAssignment(assignment) => assignment.object(),
+                AnyJsAssignment::JsComputedMemberAssignment(assignment) => assignment.object(),
+                _ => return None,
+            };
+            return (assigned_object.ok()?.syntax() == &object)
+                .then(|| assignment.syntax().text_trimmed_range());
+        } else if let Some(unary) = JsUnaryExpression::cast_ref(&parent) {
+            return (is_member && unary.operator().ok()? == JsUnaryOperator::Delete)
+                .then(|| object.text_trimmed_range());
+        } else if !JsParenthesizedExpression::can_cast(parent.kind())
+            && !TsNonNullAssertionExpression::can_cast(parent.kind())
+        {
+            return None;
+        }
+        object = parent;
+    }
+}
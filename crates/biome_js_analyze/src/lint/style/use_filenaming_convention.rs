@@ -1,6 +1,7 @@
-use crate::{services::semantic::SemanticServices, utils::restricted_regex::RestrictedRegex};
+use crate::services::semantic::SemanticServices;
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+    context::RuleContext, declare_lint_rule, restricted_regex::RestrictedRegex, Rule,
+    RuleDiagnostic, RuleSource, RuleSourceKind,
 };
 use biome_console::markup;
 use biome_deserialize::DeserializableValidator;
@@ -8,13 +8,14 @@ use biome_analyze::{
     RuleSource, RuleSourceKind,
 };
 use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
 use biome_js_factory::make;
 use biome_js_semantic::{ReferencesExtensions, SemanticModel};
 use biome_js_syntax::{
     AnyJsCombinedSpecifier, AnyJsIdentifierUsage, AnyJsImportClause, AnyJsModuleItem,
-    AnyJsNamedImportSpecifier, JsFileSource, JsIdentifierBinding, JsImport, JsImportCombinedClause,
-    JsImportDefaultClause, JsLanguage, JsModuleItemList, JsNamedImportSpecifierList,
-    JsNamedImportSpecifiers, JsSyntaxNode, JsSyntaxToken, T,
+    AnyJsModuleSource, AnyJsNamedImportSpecifier, JsFileSource, JsIdentifierBinding, JsImport,
+    JsImportCombinedClause, JsImportDefaultClause, JsLanguage, JsModuleItemList,
+    JsNamedImportSpecifierList, JsNamedImportSpecifiers, JsSyntaxNode, JsSyntaxToken, T,
 };
 use biome_rowan::{
     chain_trivia_pieces, trim_leading_trivia_pieces, trim_trailing_trivia_pieces, AstNode,
@@ -22,6 +23,7 @@ use biome_rowan::{
     TriviaPieceKind,
 };
 use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
 
 declare_lint_rule! {
     /// Promotes the use of `import type` for types.
@@ -57,12 +59,6 @@ declare_lint_rule! {
     /// We haven't found a way to support this pattern yet.
     /// We recommend disabling this rule when using such decorators.
     ///
-    /// ## Options
-    ///
-    /// This rule respects the [`jsxRuntime`](https://biomejs.dev/reference/configuration/#javascriptjsxruntime)
-    /// setting and will make an exception for React globals if it is set to
-    /// `"reactClassic"`.
-    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -109,6 +105,48 @@ declare_lint_rule! {
     /// import { B } from "./mod.js" with {};
     /// export type { B };
     /// ```
+    ///
+    /// ## Options
+    ///
+    /// This rule respects the [`jsxRuntime`](https://biomejs.dev/reference/configuration/#javascriptjsxruntime)
+    /// setting and will make an exception for React globals if it is set to
+    /// `"reactClassic"`.
+    ///
+    /// ### style
+    ///
+    /// The style of the type imports:
+    ///
+    /// - `auto`: the rule uses an `import type` when all the imports of an `import` are types,
+    ///   and inline `type` keywords when an `import` mixes types and values.
+    /// - `inlineType`: the rule always uses inline `type` keywords, such as `import { type A } from "./mod.js"`.
+    ///   An `import type` is only used for default and namespace imports,
+    ///   because they can't have an inline `type` keyword.
+    /// - `separatedType`: the rule always uses an `import type`.
+    ///   An `import` that mixes types and values is split into an `import` of the values
+    ///   and an `import type` of the types.
+    ///
+    /// Default: `auto`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "style": "separatedType"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic,use_options
+    /// import { type A, B } from "./mod.js";
+    /// let c: A;
+    /// let d = new B();
+    /// ```
+    ///
+    /// ```ts,use_options
+    /// import type { A } from "./mod.js";
+    /// import { B } from "./mod.js";
+    /// let c: A;
+    /// let d = new B();
+    /// ```
     pub UseImportType {
         version: "1.5.0",
         name: "useImportType",
@@ -124,7 +162,7 @@ impl Rule for UseImportType {
     type Query = Semantic<JsImport>;
     type State = ImportTypeFix;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = ImportTypeOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let source_type = ctx.source_type::<JsFileSource>();
@@ -138,6 +176,7 @@ impl Rule for UseImportType {
             return None;
         }
         let model = ctx.model();
+        let style = ctx.options().style;
         match import_clause {
             AnyJsImportClause::JsImportBareClause(_) => None,
             AnyJsImportClause::JsImportCombinedClause(clause) => {
@@ -155,7 +194,14 @@ impl Rule for UseImportType {
                         match named_import_type_fix(model, &named_specifiers, false) {
                             Some(NamedImportTypeFix::UseImportType(specifiers)) => {
                                 if is_default_used_as_type {
-                                    Some(ImportTypeFix::UseImportType)
+                                    if style == ImportTypeStyle::InlineType {
+                                        // A default import can't have an inline type qualifier.
+                                        Some(ImportTypeFix::ExtractDefaultImportType(specifiers))
+                                    } else {
+                                        Some(ImportTypeFix::UseImportType)
+                                    }
+                                } else if style == ImportTypeStyle::SeparatedType {
+                                    Some(ImportTypeFix::ExtractCombinedImportType)
                                 } else if specifiers.is_empty() {
                                     // Don't group inline type-imports,
                                     // when the default import is not only used as a type.
@@ -166,8 +212,14 @@ impl Rule for UseImportType {
                                     Some(ImportTypeFix::AddInlineTypeQualifiers(specifiers))
                                 }
                             }
-                            Some(NamedImportTypeFix::AddInlineTypeQualifiers(specifiers)) => {
-                                if is_default_used_as_type {
+                            Some(NamedImportTypeFix::AddInlineTypeQualifiers(mut specifiers)) => {
+                                if style == ImportTypeStyle::SeparatedType {
+                                    specifiers.extend(inline_type_specifiers(&named_specifiers));
+                                    Some(ImportTypeFix::SeparateTypeImports {
+                                        is_default_type: is_default_used_as_type,
+                                        specifiers,
+                                    })
+                                } else if is_default_used_as_type {
                                     Some(ImportTypeFix::ExtractDefaultImportType(specifiers))
                                 } else if specifiers.is_empty() {
                                     None
@@ -179,8 +231,22 @@ impl Rule for UseImportType {
                                 // Should not be reached because we pass `false` to `named_import_type_fix`.
                                 None
                             }
-                            None => is_default_used_as_type
-                                .then_some(ImportTypeFix::ExtractDefaultImportType(vec![])),
+                            None => {
+                                let specifiers = if style == ImportTypeStyle::SeparatedType {
+                                    inline_type_specifiers(&named_specifiers)
+                                } else {
+                                    Vec::new()
+                                };
+                                if !specifiers.is_empty() {
+                                    Some(ImportTypeFix::SeparateTypeImports {
+                                        is_default_type: is_default_used_as_type,
+                                        specifiers,
+                                    })
+                                } else {
+                                    is_default_used_as_type
+                                        .then_some(ImportTypeFix::ExtractDefaultImportType(vec![]))
+                                }
+                            }
                         }
                     }
                     AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(namespace_specifier) => {
@@ -219,18 +285,47 @@ impl Rule for UseImportType {
                 is_only_used_as_type(model, default_binding).then_some(ImportTypeFix::UseImportType)
             }
             AnyJsImportClause::JsImportNamedClause(clause) => {
-                match named_import_type_fix(
-                    model,
-                    &clause.named_specifiers().ok()?,
-                    clause.type_token().is_some(),
-                )? {
-                    NamedImportTypeFix::UseImportType(_) => Some(ImportTypeFix::UseImportType),
-                    NamedImportTypeFix::AddInlineTypeQualifiers(specifiers) => {
-                        Some(ImportTypeFix::AddInlineTypeQualifiers(specifiers))
+                let named_specifiers = clause.named_specifiers().ok()?;
+                let has_type_token = clause.type_token().is_some();
+                if has_type_token && style == ImportTypeStyle::InlineType {
+                    return (!named_specifiers.specifiers().is_empty())
+                        .then_some(ImportTypeFix::UseInlineTypeQualifiers);
+                }
+                match named_import_type_fix(model, &named_specifiers, has_type_token) {
+                    Some(NamedImportTypeFix::UseImportType(specifiers)) => {
+                        if style != ImportTypeStyle::InlineType {
+                            Some(ImportTypeFix::UseImportType)
+                        } else if specifiers.is_empty() {
+                            None
+                        } else {
+                            Some(ImportTypeFix::AddInlineTypeQualifiers(specifiers))
+                        }
                     }
-                    NamedImportTypeFix::RemoveInlineTypeQualifiers(type_tokens) => {
+                    Some(NamedImportTypeFix::AddInlineTypeQualifiers(mut specifiers)) => {
+                        if style == ImportTypeStyle::SeparatedType {
+                            specifiers.extend(inline_type_specifiers(&named_specifiers));
+                            Some(ImportTypeFix::SeparateTypeImports {
+                                is_default_type: false,
+                                specifiers,
+                            })
+                        } else {
+                            Some(ImportTypeFix::AddInlineTypeQualifiers(specifiers))
+                        }
+                    }
+                    Some(NamedImportTypeFix::RemoveInlineTypeQualifiers(type_tokens)) => {
                         Some(ImportTypeFix::RemoveTypeQualifiers(type_tokens))
                     }
+                    None => {
+                        if style == ImportTypeStyle::SeparatedType && !has_type_token {
+                            let specifiers = inline_type_specifiers(&named_specifiers);
+                            (!specifiers.is_empty()).then_some(ImportTypeFix::SeparateTypeImports {
+                                is_default_type: false,
+                                specifiers,
+                            })
+                        } else {
+                            None
+                        }
+                    }
                 }
             }
             AnyJsImportClause::JsImportNamespaceClause(clause) => {
@@ -300,6 +395,25 @@ impl Rule for UseImportType {
                     ),
                 }
             }
+            ImportTypeFix::SeparateTypeImports {
+                is_default_type,
+                specifiers,
+            } => {
+                let mut diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    import_clause.range(),
+                    if *is_default_type {
+                        "The default import and some named imports are only used as types."
+                    } else {
+                        "Some named imports are only used as types."
+                    },
+                );
+                for specifier in specifiers {
+                    diagnostic =
+                        diagnostic.detail(specifier.range(), "This import is only used as a type.")
+                }
+                diagnostic
+            }
             ImportTypeFix::AddInlineTypeQualifiers(named_specifiers) => {
                 let mut diagnostic = RuleDiagnostic::new(
                     rule_category!(),
@@ -330,6 +444,20 @@ impl Rule for UseImportType {
                 }
                 return Some(diagnostic);
             }
+            ImportTypeFix::UseInlineTypeQualifiers => {
+                return Some(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        import_clause.type_token()?.text_trimmed_range(),
+                        markup! {
+                            "Use inline "<Emphasis>"type"</Emphasis>" keywords instead of "<Emphasis>"import type"</Emphasis>"."
+                        },
+                    )
+                    .note(markup! {
+                        "The option "<Emphasis>"style"</Emphasis>" requires inline "<Emphasis>"type"</Emphasis>" keywords for the named imports."
+                    }),
+                );
+            }
         };
         Some(diagnostic.note(markup! {
             "Importing the types with "<Emphasis>"import type"</Emphasis>" ensures that they are removed by the compilers and avoids loading unnecessary modules."
@@ -498,6 +626,12 @@ impl Rule for UseImportType {
                         import_clause,
                     )
                     .build()
+                    .with_semicolon_token(
+                        import
+                            .semicolon_token()
+                            .is_some()
+                            .then_some(make::token(T![;])),
+                    )
                 } else {
                     extract_combined_specifier_in_new_import(
                         import_combined_clause,
@@ -563,6 +697,146 @@ impl Rule for UseImportType {
                     mutation,
                 ));
             }
+            ImportTypeFix::SeparateTypeImports {
+                is_default_type,
+                specifiers,
+            } => {
+                let type_specifiers = specifiers
+                    .iter()
+                    .map(|specifier| specifier.range().start())
+                    .collect::<FxHashSet<_>>();
+                let is_type_specifier = |specifier: &AnyJsNamedImportSpecifier| {
+                    type_specifiers.contains(&specifier.range().start())
+                };
+                let semicolon_token = import
+                    .semicolon_token()
+                    .is_some()
+                    .then(|| make::token(T![;]));
+                let type_token = make::token(T![type])
+                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+                let mut new_items: Vec<AnyJsModuleItem> = Vec::with_capacity(3);
+                let (named_specifiers, source) = match &import_clause {
+                    AnyJsImportClause::JsImportNamedClause(import_clause) => {
+                        let named_specifiers = import_clause.named_specifiers().ok()?;
+                        let value_specifiers =
+                            filter_named_specifiers(&named_specifiers, |specifier| {
+                                !is_type_specifier(specifier)
+                            })?;
+                        let new_import_clause = import_clause
+                            .clone()
+                            .with_named_specifiers(value_specifiers);
+                        new_items.push(
+                            import
+                                .clone()
+                                .with_import_clause(new_import_clause.into())
+                                .into(),
+                        );
+                        (named_specifiers, import_clause.source().ok()?)
+                    }
+                    AnyJsImportClause::JsImportCombinedClause(import_clause) => {
+                        let AnyJsCombinedSpecifier::JsNamedImportSpecifiers(named_specifiers) =
+                            import_clause.specifier().ok()?
+                        else {
+                            return None;
+                        };
+                        let value_specifiers =
+                            filter_named_specifiers(&named_specifiers, |specifier| {
+                                !is_type_specifier(specifier)
+                            })?;
+                        let source = import_clause.source().ok()?;
+                        if *is_default_type {
+                            let default_import_clause = extract_into_default_import_clause(
+                                import_clause,
+                                Some(type_token.clone()),
+                            )
+                            .ok()?;
+                            new_items.push(
+                                import
+                                    .clone()
+                                    .with_import_clause(default_import_clause.into())
+                                    .into(),
+                            );
+                            new_items.push(
+                                make_named_import(
+                                    value_specifiers,
+                                    None,
+                                    source.clone(),
+                                    semicolon_token.clone(),
+                                )?
+                                .into(),
+                            );
+                        } else {
+                            let new_import_clause = import_clause
+                                .clone()
+                                .with_specifier(value_specifiers.into());
+                            new_items.push(
+                                import
+                                    .clone()
+                                    .with_import_clause(new_import_clause.into())
+                                    .into(),
+                            );
+                        }
+                        (named_specifiers, source)
+                    }
+                    _ => return None,
+                };
+                let type_specifiers =
+                    filter_named_specifiers(&named_specifiers, is_type_specifier)?;
+                new_items.push(
+                    make_named_import(type_specifiers, Some(type_token), source, semicolon_token)?
+                        .into(),
+                );
+                add_module_items(&mut mutation, import.syntax(), new_items);
+                return Some(JsRuleAction::new(
+                    ctx.metadata().action_category(ctx.category(), ctx.group()),
+                    ctx.metadata().applicability(),
+                    markup! { "Move the types in a separate "<Emphasis>"import type"</Emphasis>"." }
+                        .to_owned(),
+                    mutation,
+                ));
+            }
+            ImportTypeFix::UseInlineTypeQualifiers => {
+                let import_clause = import_clause.as_js_import_named_clause()?;
+                let named_specifiers = import_clause.named_specifiers().ok()?;
+                let specifiers = named_specifiers.specifiers();
+                let mut new_specifiers = Vec::with_capacity(specifiers.len());
+                let mut new_separators = Vec::with_capacity(specifiers.len());
+                for specifier_element in specifiers.elements() {
+                    let specifier = specifier_element.node().ok()?.clone();
+                    let trailing_sep = specifier_element.into_trailing_separator().ok()?;
+                    if specifier.type_token().is_some() {
+                        new_specifiers.push(specifier);
+                    } else {
+                        let new_specifier = specifier
+                            .clone()
+                            .with_leading_trivia_pieces([])?
+                            .with_type_token(Some(
+                                make::token(T![type])
+                                    .with_leading_trivia_pieces(
+                                        specifier.syntax().first_leading_trivia()?.pieces(),
+                                    )
+                                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                            ));
+                        new_specifiers.push(new_specifier);
+                    }
+                    if let Some(trailing_sep) = trailing_sep {
+                        new_separators.push(trailing_sep);
+                    }
+                }
+                let new_specifiers =
+                    make::js_named_import_specifier_list(new_specifiers, new_separators);
+                let new_import_clause = import_clause
+                    .clone()
+                    .with_type_token(None)
+                    .with_named_specifiers(named_specifiers.with_specifiers(new_specifiers));
+                mutation.replace_node(import_clause.clone(), new_import_clause);
+                return Some(JsRuleAction::new(
+                    ctx.metadata().action_category(ctx.category(), ctx.group()),
+                    ctx.metadata().applicability(),
+                    markup! { "Use inline "<Emphasis>"type"</Emphasis>" keywords." }.to_owned(),
+                    mutation,
+                ));
+            }
             ImportTypeFix::RemoveTypeQualifiers(type_tokens) => {
                 for type_token in type_tokens {
                     mutation.remove_token(type_token.clone());
@@ -592,6 +866,35 @@ pub enum ImportTypeFix {
     ExtractCombinedImportType,
     AddInlineTypeQualifiers(Vec<AnyJsNamedImportSpecifier>),
     RemoveTypeQualifiers(Vec<JsSyntaxToken>),
+    /// Move the named imports `specifiers` into a separate `import type`.
+    /// `is_default_type` is `true` if the default import is also only used as a type.
+    SeparateTypeImports {
+        is_default_type: bool,
+        specifiers: Vec<AnyJsNamedImportSpecifier>,
+    },
+    UseInlineTypeQualifiers,
+}
+
+/// Options for the rule `useImportType`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct ImportTypeOptions {
+    /// The style of the type imports.
+    pub style: ImportTypeStyle,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ImportTypeStyle {
+    /// Use `import type` when all the imports are types, and inline `type` keywords otherwise.
+    #[default]
+    Auto,
+    /// Use inline `type` keywords for the named imports.
+    InlineType,
+    /// Use `import type`, and split the imports that mix types and values.
+    SeparatedType,
 }
 
 /// Returns `true` if all references of `binding` are only used as a type.
@@ -676,6 +979,101 @@ fn named_import_type_fix(
     }
 }
 
+/// Returns the named imports with an inline `type` qualifier.
+fn inline_type_specifiers(
+    named_specifiers: &JsNamedImportSpecifiers,
+) -> Vec<AnyJsNamedImportSpecifier> {
+    named_specifiers
+        .specifiers()
+        .iter()
+        .filter_map(|specifier| specifier.ok())
+        .filter(|specifier| specifier.type_token().is_some())
+        .collect()
+}
+
+/// Returns `named_specifiers` with only the specifiers that satisfy `predicate`.
+/// The inline `type` qualifiers of the kept specifiers are removed.
+fn filter_named_specifiers(
+    named_specifiers: &JsNamedImportSpecifiers,
+    predicate: impl Fn(&AnyJsNamedImportSpecifier) -> bool,
+) -> Option<JsNamedImportSpecifiers> {
+    let specifiers = named_specifiers.specifiers();
+    let last_specifier = specifiers.last()?.ok()?;
+    let trailing_separator = specifiers.trailing_separator();
+    let mut kept = Vec::with_capacity(specifiers.len());
+    for specifier_element in specifiers.elements() {
+        let specifier = specifier_element.node().ok()?.clone();
+        let trailing_sep = specifier_element.into_trailing_separator().ok()?;
+        if predicate(&specifier) {
+            kept.push((specifier, trailing_sep));
+        }
+    }
+    let kept_len = kept.len();
+    let mut new_specifiers = Vec::with_capacity(kept_len);
+    let mut new_separators = Vec::with_capacity(kept_len);
+    for (index, (specifier, trailing_sep)) in kept.into_iter().enumerate() {
+        let is_last = index + 1 == kept_len;
+        let mut specifier = if let Some(type_token) = specifier.type_token() {
+            specifier
+                .with_type_token(None)
+                .trim_leading_trivia()?
+                .prepend_trivia_pieces(chain_trivia_pieces(
+                    type_token.leading_trivia().pieces(),
+                    trim_leading_trivia_pieces(type_token.trailing_trivia().pieces()),
+                ))?
+        } else {
+            specifier
+        };
+        if is_last {
+            if specifier.range() != last_specifier.range() {
+                // Take the place of the last specifier.
+                specifier = specifier.with_trailing_trivia_pieces(
+                    last_specifier.syntax().last_trailing_trivia()?.pieces(),
+                )?;
+            }
+            if trailing_separator.is_some() {
+                new_separators.push(trailing_sep.or_else(|| trailing_separator.clone())?);
+            }
+        } else {
+            new_separators.push(trailing_sep.unwrap_or_else(|| {
+                make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+            }));
+        }
+        new_specifiers.push(specifier);
+    }
+    let new_specifiers = make::js_named_import_specifier_list(new_specifiers, new_separators);
+    Some(named_specifiers.clone().with_specifiers(new_specifiers))
+}
+
+/// Returns `import <type_token> <named_specifiers> from <source>` preceded by a newline.
+fn make_named_import(
+    named_specifiers: JsNamedImportSpecifiers,
+    type_token: Option<JsSyntaxToken>,
+    source: AnyJsModuleSource,
+    semicolon_token: Option<JsSyntaxToken>,
+) -> Option<JsImport> {
+    let source = source
+        .with_leading_trivia_pieces([])?
+        .with_trailing_trivia_pieces([])?;
+    let import_clause = make::js_import_named_clause(
+        named_specifiers,
+        make::token(T![from]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        source,
+    )
+    .build()
+    .with_type_token(type_token);
+    Some(
+        make::js_import(
+            make::token(T![import])
+                .with_leading_trivia([(TriviaPieceKind::Newline, "\n")])
+                .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            import_clause.into(),
+        )
+        .build()
+        .with_semicolon_token(semicolon_token),
+    )
+}
+
 fn add_module_items(
     mutation: &mut BatchMutation<JsLanguage>,
     preceding_item: &JsSyntaxNode,
@@ -2,15 +2,12 @@ use std::ops::{Deref, Range};
 
 use crate::{
     services::{control_flow::AnyJsControlFlowRoot, semantic::Semantic},
-    utils::{
-        rename::{AnyJsRenamableDeclaration, RenameSymbolExtensions},
-        restricted_regex::RestrictedRegex,
-    },
+    utils::rename::{AnyJsRenamableDeclaration, RenameSymbolExtensions},
     JsRuleAction,
 };
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic, RuleSource,
-    RuleSourceKind,
+    context::RuleContext, declare_lint_rule, restricted_regex::RestrictedRegex, FixKind, Rule,
+    RuleDiagnostic, RuleSource, RuleSourceKind,
 };
 use biome_console::markup;
 use biome_deserialize::{DeserializableValidator, DeserializationDiagnostic};
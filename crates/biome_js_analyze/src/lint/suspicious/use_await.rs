@@ -1,12 +1,23 @@
+use crate::services::semantic::{SemanticModelBuilderVisitor, SemanticServices};
+use crate::JsRuleAction;
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, AddVisitor, Phases, QueryMatch, Queryable, Rule,
-    RuleDiagnostic, RuleSource, ServiceBag, Visitor, VisitorContext,
+    context::RuleContext, declare_lint_rule, AddVisitor, FixKind, Phases, QueryMatch, Queryable,
+    Rule, RuleDiagnostic, RuleSource, ServiceBag, Visitor, VisitorContext,
 };
 use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::SemanticModel;
 use biome_js_syntax::{
-    AnyFunctionLike, JsAwaitExpression, JsForOfStatement, JsLanguage, TextRange, WalkEvent,
+    AnyFunctionLike, AnyJsBinding, AnyJsClass, AnyJsExpression, AnyJsFunction, JsAwaitExpression,
+    JsCallExpression, JsExpressionStatement, JsForOfStatement, JsIdentifierBinding,
+    JsIdentifierExpression, JsInitializerClause, JsLanguage, JsSyntaxNode, JsVariableDeclarator,
+    TextRange, WalkEvent,
 };
-use biome_rowan::{AstNode, AstNodeList, Language, SyntaxNode, TextSize};
+use biome_rowan::{
+    chain_trivia_pieces, trim_leading_trivia_pieces, AstNode, AstNodeList, BatchMutationExt,
+    Language, SyntaxNode, TextSize,
+};
+use serde::{Deserialize, Serialize};
 
 declare_lint_rule! {
     /// Ensure `async` functions utilize `await`.
@@ -45,6 +56,55 @@ declare_lint_rule! {
     /// // Nor does it warn about empty `async` functions
     /// async function noop() { }
     /// ```
+    ///
+    /// The rule provides a fix that removes the `async` keyword when the promise returned by the function isn't observed:
+    /// the function is declared in the current module, isn't exported,
+    /// has no return type annotation, isn't a generator, is called at least once,
+    /// and all its references are calls whose result is ignored or awaited in an expression statement.
+    ///
+    /// Even then, the fix is unsafe and isn't applied automatically:
+    /// an exception thrown by the function is no longer turned into a rejected promise,
+    /// so a rejection that was ignored becomes a synchronous exception thrown at the call sites, e.g. `f();`.
+    ///
+    /// ## Options
+    ///
+    /// ### `ignoreEmptyFunctions`
+    ///
+    /// Ignore the `async` functions whose body is empty. Defaults to `true`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignoreEmptyFunctions": false
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// async function noop() { }
+    /// ```
+    ///
+    /// ### `ignoreInterfaceImplementations`
+    ///
+    /// Ignore the `async` methods of the classes that implement an interface.
+    /// The interface can require a method to return a promise, even when the method doesn't need to await anything.
+    /// Defaults to `false`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignoreInterfaceImplementations": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,use_options
+    /// class MemoryStore implements Store {
+    ///   async get(key: string): Promise<string> {
+    ///     return this.values[key];
+    ///   }
+    /// }
+    /// ```
     pub UseAwait {
         version: "1.4.0",
         name: "useAwait",
@@ -54,6 +114,27 @@ declare_lint_rule! {
             RuleSource::EslintTypeScript("require-await"),
         ],
         recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `useAwait`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseAwaitOptions {
+    /// Ignore the `async` functions whose body is empty.
+    pub ignore_empty_functions: bool,
+    /// Ignore the `async` methods of the classes that implement an interface.
+    pub ignore_interface_implementations: bool,
+}
+
+impl Default for UseAwaitOptions {
+    fn default() -> Self {
+        Self {
+            ignore_empty_functions: true,
+            ignore_interface_implementations: false,
+        }
     }
 }
 
@@ -115,13 +196,14 @@ impl Queryable for MissingAwait {
     type Input = Self;
     type Language = JsLanguage;
     type Output = AnyFunctionLike;
-    type Services = ();
+    type Services = SemanticServices;
 
     fn build_visitor(
         analyzer: &mut impl AddVisitor<Self::Language>,
-        _: &<Self::Language as Language>::Root,
+        root: &<Self::Language as Language>::Root,
     ) {
-        analyzer.add_visitor(Phases::Syntax, MissingAwaitVisitor::default);
+        analyzer.add_visitor(Phases::Syntax, || SemanticModelBuilderVisitor::new(root));
+        analyzer.add_visitor(Phases::Semantic, MissingAwaitVisitor::default);
     }
 
     fn unwrap_match(_: &ServiceBag, query: &Self::Input) -> Self::Output {
@@ -133,11 +215,16 @@ impl Rule for UseAwait {
     type Query = MissingAwait;
     type State = ();
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = Box<UseAwaitOptions>;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let query = ctx.query();
-        if query.statements()?.is_empty() {
+        let options = ctx.options();
+        let statements = query.statements()?;
+        if options.ignore_empty_functions && statements.is_empty() {
+            return None;
+        }
+        if options.ignore_interface_implementations && is_interface_implementation(query) {
             return None;
         }
         Some(())
@@ -159,4 +246,117 @@ impl Rule for UseAwait {
             }),
         )
     }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let AnyFunctionLike::AnyJsFunction(function) = ctx.query() else {
+            return None;
+        };
+        if function.is_generator() || function.return_type_annotation().is_some() {
+            return None;
+        }
+        let binding = function_binding(function)?;
+        if !is_result_ignored(&binding, ctx.model()) {
+            return None;
+        }
+        let async_token = function.async_token()?;
+        let next_token = async_token.next_token()?;
+        let new_next_token = next_token.prepend_trivia_pieces(chain_trivia_pieces(
+            async_token.leading_trivia().pieces(),
+            trim_leading_trivia_pieces(async_token.trailing_trivia().pieces()),
+        ));
+        let mut mutation = ctx.root().begin();
+        mutation.remove_token(async_token);
+        mutation.replace_token_discard_trivia(next_token, new_next_token);
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! {
+                "Remove the "<Emphasis>"async"</Emphasis>" modifier."
+            }
+            .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `function` is a method of a class that implements an interface.
+fn is_interface_implementation(function: &AnyFunctionLike) -> bool {
+    let AnyFunctionLike::JsMethodClassMember(method) = function else {
+        return false;
+    };
+    method
+        .syntax()
+        .parent()
+        .and_then(|parent| parent.parent())
+        .and_then(AnyJsClass::cast)
+        .is_some_and(|class| class.implements_clause().is_some())
+}
+
+/// Returns the binding of the function declaration, or of the variable initialized with `function`.
+fn function_binding(function: &AnyJsFunction) -> Option<JsIdentifierBinding> {
+    let binding = match function {
+        AnyJsFunction::JsFunctionDeclaration(declaration) => declaration.id().ok()?,
+        AnyJsFunction::JsArrowFunctionExpression(_) | AnyJsFunction::JsFunctionExpression(_) => {
+            let declarator = function
+                .syntax()
+                .parent()
+                .and_then(JsInitializerClause::cast)?
+                .syntax()
+                .parent()
+                .and_then(JsVariableDeclarator::cast)?;
+            declarator.id().ok()?.as_any_js_binding()?.clone()
+        }
+        AnyJsFunction::JsFunctionExportDefaultDeclaration(_) => return None,
+    };
+    match binding {
+        AnyJsBinding::JsIdentifierBinding(binding) => Some(binding),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the function bound to `binding` is called at least once, only called,
+/// and the result of the calls is ignored or awaited in an expression statement.
+fn is_result_ignored(binding: &JsIdentifierBinding, model: &SemanticModel) -> bool {
+    if model.is_exported(binding) {
+        return false;
+    }
+    let mut references = model.as_binding(binding).all_references().peekable();
+    references.peek().is_some() && references.all(|reference| is_ignored_call(reference.syntax()))
+}
+
+/// Returns `true` if `reference` is the callee of a call that is ignored, such as `f();`,
+/// or awaited and ignored, such as `await f();`.
+fn is_ignored_call(reference: &JsSyntaxNode) -> bool {
+    let Some(expression) = reference.parent().and_then(JsIdentifierExpression::cast) else {
+        return false;
+    };
+    let Some(call) = expression
+        .syntax()
+        .parent()
+        .and_then(JsCallExpression::cast)
+        .filter(|call| {
+            call.callee()
+                .is_ok_and(|callee| callee.syntax() == expression.syntax())
+        })
+    else {
+        return false;
+    };
+    let mut node = AnyJsExpression::from(call);
+    let mut is_awaited = false;
+    loop {
+        let Some(parent) = node.syntax().parent() else {
+            return false;
+        };
+        if JsExpressionStatement::can_cast(parent.kind()) {
+            return true;
+        }
+        node = match AnyJsExpression::cast(parent) {
+            Some(parent @ AnyJsExpression::JsParenthesizedExpression(_)) => parent,
+            Some(parent @ AnyJsExpression::JsAwaitExpression(_)) if !is_awaited => {
+                is_awaited = true;
+                parent
+            }
+            _ => return false,
+        };
+    }
 }
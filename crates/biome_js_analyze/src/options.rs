@@ -14,6 +14,8 @@ pub type NoArrayIndexKey =
 pub type NoAssignInExpressions = < lint :: suspicious :: no_assign_in_expressions :: NoAssignInExpressions as biome_analyze :: Rule > :: Options ;
 pub type NoAsyncPromiseExecutor = < lint :: suspicious :: no_async_promise_executor :: NoAsyncPromiseExecutor as biome_analyze :: Rule > :: Options ;
 pub type NoAutofocus = <lint::a11y::no_autofocus::NoAutofocus as biome_analyze::Rule>::Options;
+pub type NoAwaitInLoop =
+    <lint::nursery::no_await_in_loop::NoAwaitInLoop as biome_analyze::Rule>::Options;
 pub type NoBannedTypes =
     <lint::complexity::no_banned_types::NoBannedTypes as biome_analyze::Rule>::Options;
 pub type NoBarrelFile =
@@ -24,15 +26,21 @@ pub type NoCatchAssign =
     <lint::suspicious::no_catch_assign::NoCatchAssign as biome_analyze::Rule>::Options;
 pub type NoChildrenProp =
     <lint::correctness::no_children_prop::NoChildrenProp as biome_analyze::Rule>::Options;
+pub type NoCircularImports =
+    <lint::nursery::no_circular_imports::NoCircularImports as biome_analyze::Rule>::Options;
 pub type NoClassAssign =
     <lint::suspicious::no_class_assign::NoClassAssign as biome_analyze::Rule>::Options;
 pub type NoCommaOperator =
     <lint::style::no_comma_operator::NoCommaOperator as biome_analyze::Rule>::Options;
 pub type NoCommentText =
     <lint::suspicious::no_comment_text::NoCommentText as biome_analyze::Rule>::Options;
+pub type NoCommentedOutCode =
+    <lint::nursery::no_commented_out_code::NoCommentedOutCode as biome_analyze::Rule>::Options;
 pub type NoCommonJs = <lint::nursery::no_common_js::NoCommonJs as biome_analyze::Rule>::Options;
 pub type NoCompareNegZero =
     <lint::suspicious::no_compare_neg_zero::NoCompareNegZero as biome_analyze::Rule>::Options;
+pub type NoConditionalExpect =
+    <lint::nursery::no_conditional_expect::NoConditionalExpect as biome_analyze::Rule>::Options;
 pub type NoConfusingLabels =
     <lint::suspicious::no_confusing_labels::NoConfusingLabels as biome_analyze::Rule>::Options;
 pub type NoConfusingVoidType =
@@ -53,9 +61,12 @@ pub type NoControlCharactersInRegex = < lint :: suspicious :: no_control_charact
 pub type NoDangerouslySetInnerHtml = < lint :: security :: no_dangerously_set_inner_html :: NoDangerouslySetInnerHtml as biome_analyze :: Rule > :: Options ;
 pub type NoDangerouslySetInnerHtmlWithChildren = < lint :: security :: no_dangerously_set_inner_html_with_children :: NoDangerouslySetInnerHtmlWithChildren as biome_analyze :: Rule > :: Options ;
 pub type NoDebugger = <lint::suspicious::no_debugger::NoDebugger as biome_analyze::Rule>::Options;
+pub type NoDeepRelativeImports = < lint :: nursery :: no_deep_relative_imports :: NoDeepRelativeImports as biome_analyze :: Rule > :: Options ;
 pub type NoDefaultExport =
     <lint::style::no_default_export::NoDefaultExport as biome_analyze::Rule>::Options;
 pub type NoDelete = <lint::performance::no_delete::NoDelete as biome_analyze::Rule>::Options;
+pub type NoDeprecatedApis =
+    <lint::nursery::no_deprecated_apis::NoDeprecatedApis as biome_analyze::Rule>::Options;
 pub type NoDistractingElements =
     <lint::a11y::no_distracting_elements::NoDistractingElements as biome_analyze::Rule>::Options;
 pub type NoDocumentCookie =
@@ -75,6 +86,7 @@ pub type NoDuplicateJsxProps =
 pub type NoDuplicateObjectKeys = < lint :: suspicious :: no_duplicate_object_keys :: NoDuplicateObjectKeys as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateParameters = < lint :: suspicious :: no_duplicate_parameters :: NoDuplicateParameters as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateTestHooks = < lint :: suspicious :: no_duplicate_test_hooks :: NoDuplicateTestHooks as biome_analyze :: Rule > :: Options ;
+pub type NoDuplicateTestTitles = < lint :: nursery :: no_duplicate_test_titles :: NoDuplicateTestTitles as biome_analyze :: Rule > :: Options ;
 pub type NoDynamicNamespaceImportAccess = < lint :: nursery :: no_dynamic_namespace_import_access :: NoDynamicNamespaceImportAccess as biome_analyze :: Rule > :: Options ;
 pub type NoEmptyBlockStatements = < lint :: suspicious :: no_empty_block_statements :: NoEmptyBlockStatements as biome_analyze :: Rule > :: Options ;
 pub type NoEmptyCharacterClassInRegex = < lint :: correctness :: no_empty_character_class_in_regex :: NoEmptyCharacterClassInRegex as biome_analyze :: Rule > :: Options ;
@@ -100,6 +112,8 @@ pub type NoExtraNonNullAssertion = < lint :: suspicious :: no_extra_non_null_ass
 pub type NoFallthroughSwitchClause = < lint :: suspicious :: no_fallthrough_switch_clause :: NoFallthroughSwitchClause as biome_analyze :: Rule > :: Options ;
 pub type NoFlatMapIdentity =
     <lint::correctness::no_flat_map_identity::NoFlatMapIdentity as biome_analyze::Rule>::Options;
+pub type NoFloatingPromises =
+    <lint::nursery::no_floating_promises::NoFloatingPromises as biome_analyze::Rule>::Options;
 pub type NoFocusedTests =
     <lint::suspicious::no_focused_tests::NoFocusedTests as biome_analyze::Rule>::Options;
 pub type NoForEach = <lint::complexity::no_for_each::NoForEach as biome_analyze::Rule>::Options;
@@ -115,6 +129,7 @@ pub type NoGlobalIsFinite =
 pub type NoGlobalIsNan =
     <lint::suspicious::no_global_is_nan::NoGlobalIsNan as biome_analyze::Rule>::Options;
 pub type NoGlobalObjectCalls = < lint :: correctness :: no_global_object_calls :: NoGlobalObjectCalls as biome_analyze :: Rule > :: Options ;
+pub type NoHardcodedUserFacingStrings = < lint :: nursery :: no_hardcoded_user_facing_strings :: NoHardcodedUserFacingStrings as biome_analyze :: Rule > :: Options ;
 pub type NoHeadElement =
     <lint::nursery::no_head_element::NoHeadElement as biome_analyze::Rule>::Options;
 pub type NoHeadImportInDocument = < lint :: nursery :: no_head_import_in_document :: NoHeadImportInDocument as biome_analyze :: Rule > :: Options ;
@@ -142,10 +157,16 @@ pub type NoIrregularWhitespace =
 pub type NoLabelVar = <lint::suspicious::no_label_var::NoLabelVar as biome_analyze::Rule>::Options;
 pub type NoLabelWithoutControl =
     <lint::a11y::no_label_without_control::NoLabelWithoutControl as biome_analyze::Rule>::Options;
+pub type NoLeakedEventListeners = < lint :: nursery :: no_leaked_event_listeners :: NoLeakedEventListeners as biome_analyze :: Rule > :: Options ;
+pub type NoMagicNumbers =
+    <lint::nursery::no_magic_numbers::NoMagicNumbers as biome_analyze::Rule>::Options;
 pub type NoMisleadingCharacterClass = < lint :: suspicious :: no_misleading_character_class :: NoMisleadingCharacterClass as biome_analyze :: Rule > :: Options ;
 pub type NoMisleadingInstantiator = < lint :: suspicious :: no_misleading_instantiator :: NoMisleadingInstantiator as biome_analyze :: Rule > :: Options ;
 pub type NoMisplacedAssertion = < lint :: suspicious :: no_misplaced_assertion :: NoMisplacedAssertion as biome_analyze :: Rule > :: Options ;
 pub type NoMisrefactoredShorthandAssign = < lint :: suspicious :: no_misrefactored_shorthand_assign :: NoMisrefactoredShorthandAssign as biome_analyze :: Rule > :: Options ;
+pub type NoMisusedPromises =
+    <lint::nursery::no_misused_promises::NoMisusedPromises as biome_analyze::Rule>::Options;
+pub type NoModuleScopeSideEffects = < lint :: nursery :: no_module_scope_side_effects :: NoModuleScopeSideEffects as biome_analyze :: Rule > :: Options ;
 pub type NoMultipleSpacesInRegularExpressionLiterals = < lint :: complexity :: no_multiple_spaces_in_regular_expression_literals :: NoMultipleSpacesInRegularExpressionLiterals as biome_analyze :: Rule > :: Options ;
 pub type NoNamespace = <lint::style::no_namespace::NoNamespace as biome_analyze::Rule>::Options;
 pub type NoNamespaceImport =
@@ -194,6 +215,8 @@ pub type NoRestrictedGlobals =
     <lint::style::no_restricted_globals::NoRestrictedGlobals as biome_analyze::Rule>::Options;
 pub type NoRestrictedImports =
     <lint::nursery::no_restricted_imports::NoRestrictedImports as biome_analyze::Rule>::Options;
+pub type NoRestrictedSyntax =
+    <lint::nursery::no_restricted_syntax::NoRestrictedSyntax as biome_analyze::Rule>::Options;
 pub type NoRestrictedTypes =
     <lint::nursery::no_restricted_types::NoRestrictedTypes as biome_analyze::Rule>::Options;
 pub type NoSecrets = <lint::nursery::no_secrets::NoSecrets as biome_analyze::Rule>::Options;
@@ -210,22 +233,30 @@ pub type NoSkippedTests =
     <lint::suspicious::no_skipped_tests::NoSkippedTests as biome_analyze::Rule>::Options;
 pub type NoSparseArray =
     <lint::suspicious::no_sparse_array::NoSparseArray as biome_analyze::Rule>::Options;
+pub type NoSqlInjection =
+    <lint::nursery::no_sql_injection::NoSqlInjection as biome_analyze::Rule>::Options;
 pub type NoStaticElementInteractions = < lint :: nursery :: no_static_element_interactions :: NoStaticElementInteractions as biome_analyze :: Rule > :: Options ;
 pub type NoStaticOnlyClass =
     <lint::complexity::no_static_only_class::NoStaticOnlyClass as biome_analyze::Rule>::Options;
 pub type NoStringCaseMismatch = < lint :: correctness :: no_string_case_mismatch :: NoStringCaseMismatch as biome_analyze :: Rule > :: Options ;
 pub type NoSubstr = <lint::nursery::no_substr::NoSubstr as biome_analyze::Rule>::Options;
+pub type NoSuperLinearRegex =
+    <lint::nursery::no_super_linear_regex::NoSuperLinearRegex as biome_analyze::Rule>::Options;
 pub type NoSuspiciousSemicolonInJsx = < lint :: suspicious :: no_suspicious_semicolon_in_jsx :: NoSuspiciousSemicolonInJsx as biome_analyze :: Rule > :: Options ;
 pub type NoSvgWithoutTitle =
     <lint::a11y::no_svg_without_title::NoSvgWithoutTitle as biome_analyze::Rule>::Options;
 pub type NoSwitchDeclarations = < lint :: correctness :: no_switch_declarations :: NoSwitchDeclarations as biome_analyze :: Rule > :: Options ;
+pub type NoSyncFsInAsyncContexts = < lint :: nursery :: no_sync_fs_in_async_contexts :: NoSyncFsInAsyncContexts as biome_analyze :: Rule > :: Options ;
 pub type NoTemplateCurlyInString = < lint :: nursery :: no_template_curly_in_string :: NoTemplateCurlyInString as biome_analyze :: Rule > :: Options ;
 pub type NoThenProperty =
     <lint::suspicious::no_then_property::NoThenProperty as biome_analyze::Rule>::Options;
 pub type NoThisInStatic =
     <lint::complexity::no_this_in_static::NoThisInStatic as biome_analyze::Rule>::Options;
+pub type NoTodoWithoutTicket =
+    <lint::nursery::no_todo_without_ticket::NoTodoWithoutTicket as biome_analyze::Rule>::Options;
 pub type NoUndeclaredDependencies = < lint :: correctness :: no_undeclared_dependencies :: NoUndeclaredDependencies as biome_analyze :: Rule > :: Options ;
 pub type NoUndeclaredVariables = < lint :: correctness :: no_undeclared_variables :: NoUndeclaredVariables as biome_analyze :: Rule > :: Options ;
+pub type NoUnnecessaryConditions = < lint :: nursery :: no_unnecessary_conditions :: NoUnnecessaryConditions as biome_analyze :: Rule > :: Options ;
 pub type NoUnnecessaryContinue = < lint :: correctness :: no_unnecessary_continue :: NoUnnecessaryContinue as biome_analyze :: Rule > :: Options ;
 pub type NoUnreachable =
     <lint::correctness::no_unreachable::NoUnreachable as biome_analyze::Rule>::Options;
@@ -237,6 +268,11 @@ pub type NoUnsafeFinally =
 pub type NoUnsafeNegation =
     <lint::suspicious::no_unsafe_negation::NoUnsafeNegation as biome_analyze::Rule>::Options;
 pub type NoUnsafeOptionalChaining = < lint :: correctness :: no_unsafe_optional_chaining :: NoUnsafeOptionalChaining as biome_analyze :: Rule > :: Options ;
+pub type NoUnsafeTypeAssertions = < lint :: nursery :: no_unsafe_type_assertions :: NoUnsafeTypeAssertions as biome_analyze :: Rule > :: Options ;
+pub type NoUnsanitizedDomInsertion = < lint :: nursery :: no_unsanitized_dom_insertion :: NoUnsanitizedDomInsertion as biome_analyze :: Rule > :: Options ;
+pub type NoUnstableDefaultProps = < lint :: nursery :: no_unstable_default_props :: NoUnstableDefaultProps as biome_analyze :: Rule > :: Options ;
+pub type NoUnusedExports =
+    <lint::nursery::no_unused_exports::NoUnusedExports as biome_analyze::Rule>::Options;
 pub type NoUnusedFunctionParameters = < lint :: correctness :: no_unused_function_parameters :: NoUnusedFunctionParameters as biome_analyze :: Rule > :: Options ;
 pub type NoUnusedImports =
     <lint::correctness::no_unused_imports::NoUnusedImports as biome_analyze::Rule>::Options;
@@ -312,6 +348,7 @@ pub type UseConsistentArrayType = < lint :: style :: use_consistent_array_type :
 pub type UseConsistentBuiltinInstantiation = < lint :: style :: use_consistent_builtin_instantiation :: UseConsistentBuiltinInstantiation as biome_analyze :: Rule > :: Options ;
 pub type UseConsistentCurlyBraces = < lint :: nursery :: use_consistent_curly_braces :: UseConsistentCurlyBraces as biome_analyze :: Rule > :: Options ;
 pub type UseConsistentMemberAccessibility = < lint :: nursery :: use_consistent_member_accessibility :: UseConsistentMemberAccessibility as biome_analyze :: Rule > :: Options ;
+pub type UseConsistentTestNaming = < lint :: nursery :: use_consistent_test_naming :: UseConsistentTestNaming as biome_analyze :: Rule > :: Options ;
 pub type UseConst = <lint::style::use_const::UseConst as biome_analyze::Rule>::Options;
 pub type UseDateNow = <lint::complexity::use_date_now::UseDateNow as biome_analyze::Rule>::Options;
 pub type UseDefaultParameterLast = < lint :: style :: use_default_parameter_last :: UseDefaultParameterLast as biome_analyze :: Rule > :: Options ;
@@ -319,10 +356,14 @@ pub type UseDefaultSwitchClause = < lint :: style :: use_default_switch_clause :
 pub type UseDefaultSwitchClauseLast = < lint :: suspicious :: use_default_switch_clause_last :: UseDefaultSwitchClauseLast as biome_analyze :: Rule > :: Options ;
 pub type UseEnumInitializers =
     <lint::style::use_enum_initializers::UseEnumInitializers as biome_analyze::Rule>::Options;
+pub type UseErrorCause =
+    <lint::nursery::use_error_cause::UseErrorCause as biome_analyze::Rule>::Options;
 pub type UseErrorMessage =
     <lint::suspicious::use_error_message::UseErrorMessage as biome_analyze::Rule>::Options;
 pub type UseExhaustiveDependencies = < lint :: correctness :: use_exhaustive_dependencies :: UseExhaustiveDependencies as biome_analyze :: Rule > :: Options ;
+pub type UseExhaustiveSwitchCases = < lint :: nursery :: use_exhaustive_switch_cases :: UseExhaustiveSwitchCases as biome_analyze :: Rule > :: Options ;
 pub type UseExplicitLengthCheck = < lint :: style :: use_explicit_length_check :: UseExplicitLengthCheck as biome_analyze :: Rule > :: Options ;
+pub type UseExplicitResourceManagement = < lint :: nursery :: use_explicit_resource_management :: UseExplicitResourceManagement as biome_analyze :: Rule > :: Options ;
 pub type UseExplicitType =
     <lint::nursery::use_explicit_type::UseExplicitType as biome_analyze::Rule>::Options;
 pub type UseExponentiationOperator = < lint :: style :: use_exponentiation_operator :: UseExponentiationOperator as biome_analyze :: Rule > :: Options ;
@@ -350,6 +391,8 @@ pub type UseHookAtTopLevel =
 pub type UseHtmlLang = <lint::a11y::use_html_lang::UseHtmlLang as biome_analyze::Rule>::Options;
 pub type UseIframeTitle =
     <lint::a11y::use_iframe_title::UseIframeTitle as biome_analyze::Rule>::Options;
+pub type UseImportAliases =
+    <lint::nursery::use_import_aliases::UseImportAliases as biome_analyze::Rule>::Options;
 pub type UseImportExtensions =
     <lint::correctness::use_import_extensions::UseImportExtensions as biome_analyze::Rule>::Options;
 pub type UseImportRestrictions =
@@ -358,6 +401,8 @@ pub type UseImportType =
     <lint::style::use_import_type::UseImportType as biome_analyze::Rule>::Options;
 pub type UseIsArray = <lint::suspicious::use_is_array::UseIsArray as biome_analyze::Rule>::Options;
 pub type UseIsNan = <lint::correctness::use_is_nan::UseIsNan as biome_analyze::Rule>::Options;
+pub type UseJsDocConsistency =
+    <lint::nursery::use_js_doc_consistency::UseJsDocConsistency as biome_analyze::Rule>::Options;
 pub type UseJsxKeyInIterable = < lint :: correctness :: use_jsx_key_in_iterable :: UseJsxKeyInIterable as biome_analyze :: Rule > :: Options ;
 pub type UseKeyWithClickEvents =
     <lint::a11y::use_key_with_click_events::UseKeyWithClickEvents as biome_analyze::Rule>::Options;
@@ -367,6 +412,10 @@ pub type UseLiteralEnumMembers =
     <lint::style::use_literal_enum_members::UseLiteralEnumMembers as biome_analyze::Rule>::Options;
 pub type UseLiteralKeys =
     <lint::complexity::use_literal_keys::UseLiteralKeys as biome_analyze::Rule>::Options;
+pub type UseMaxFunctionSize =
+    <lint::nursery::use_max_function_size::UseMaxFunctionSize as biome_analyze::Rule>::Options;
+pub type UseMaxParams =
+    <lint::nursery::use_max_params::UseMaxParams as biome_analyze::Rule>::Options;
 pub type UseMediaCaption =
     <lint::a11y::use_media_caption::UseMediaCaption as biome_analyze::Rule>::Options;
 pub type UseNamespaceKeyword =
@@ -381,10 +430,16 @@ pub type UseNumberNamespace =
 pub type UseNumberToFixedDigitsArgument = < lint :: suspicious :: use_number_to_fixed_digits_argument :: UseNumberToFixedDigitsArgument as biome_analyze :: Rule > :: Options ;
 pub type UseNumericLiterals =
     <lint::style::use_numeric_literals::UseNumericLiterals as biome_analyze::Rule>::Options;
+pub type UseNumericSeparators =
+    <lint::nursery::use_numeric_separators::UseNumericSeparators as biome_analyze::Rule>::Options;
 pub type UseOptionalChain =
     <lint::complexity::use_optional_chain::UseOptionalChain as biome_analyze::Rule>::Options;
 pub type UseParseIntRadix =
     <lint::nursery::use_parse_int_radix::UseParseIntRadix as biome_analyze::Rule>::Options;
+pub type UsePlatformAgnosticPaths = < lint :: nursery :: use_platform_agnostic_paths :: UsePlatformAgnosticPaths as biome_analyze :: Rule > :: Options ;
+pub type UsePreferDestructuring = < lint :: nursery :: use_prefer_destructuring :: UsePreferDestructuring as biome_analyze :: Rule > :: Options ;
+pub type UsePreferObjectSpread = < lint :: nursery :: use_prefer_object_spread :: UsePreferObjectSpread as biome_analyze :: Rule > :: Options ;
+pub type UseReadonlyClassProperties = < lint :: nursery :: use_readonly_class_properties :: UseReadonlyClassProperties as biome_analyze :: Rule > :: Options ;
 pub type UseRegexLiterals =
     <lint::complexity::use_regex_literals::UseRegexLiterals as biome_analyze::Rule>::Options;
 pub type UseSelfClosingElements = < lint :: style :: use_self_closing_elements :: UseSelfClosingElements as biome_analyze :: Rule > :: Options ;
@@ -397,6 +452,7 @@ pub type UseShorthandAssign =
 pub type UseShorthandFunctionType = < lint :: style :: use_shorthand_function_type :: UseShorthandFunctionType as biome_analyze :: Rule > :: Options ;
 pub type UseSimpleNumberKeys =
     <lint::complexity::use_simple_number_keys::UseSimpleNumberKeys as biome_analyze::Rule>::Options;
+pub type UseSimplifiedBooleanReturn = < lint :: nursery :: use_simplified_boolean_return :: UseSimplifiedBooleanReturn as biome_analyze :: Rule > :: Options ;
 pub type UseSimplifiedLogicExpression = < lint :: complexity :: use_simplified_logic_expression :: UseSimplifiedLogicExpression as biome_analyze :: Rule > :: Options ;
 pub type UseSingleCaseStatement = < lint :: style :: use_single_case_statement :: UseSingleCaseStatement as biome_analyze :: Rule > :: Options ;
 pub type UseSingleVarDeclarator = < lint :: style :: use_single_var_declarator :: UseSingleVarDeclarator as biome_analyze :: Rule > :: Options ;
@@ -406,6 +462,8 @@ pub type UseSortedClasses =
     <lint::nursery::use_sorted_classes::UseSortedClasses as biome_analyze::Rule>::Options;
 pub type UseStrictMode =
     <lint::nursery::use_strict_mode::UseStrictMode as biome_analyze::Rule>::Options;
+pub type UseStructuredClone =
+    <lint::nursery::use_structured_clone::UseStructuredClone as biome_analyze::Rule>::Options;
 pub type UseTemplate = <lint::style::use_template::UseTemplate as biome_analyze::Rule>::Options;
 pub type UseThrowNewError =
     <lint::style::use_throw_new_error::UseThrowNewError as biome_analyze::Rule>::Options;
@@ -426,6 +484,7 @@ pub type UseValidAriaValues =
 pub type UseValidAutocomplete =
     <lint::nursery::use_valid_autocomplete::UseValidAutocomplete as biome_analyze::Rule>::Options;
 pub type UseValidForDirection = < lint :: correctness :: use_valid_for_direction :: UseValidForDirection as biome_analyze :: Rule > :: Options ;
+pub type UseValidGraphqlOperations = < lint :: nursery :: use_valid_graphql_operations :: UseValidGraphqlOperations as biome_analyze :: Rule > :: Options ;
 pub type UseValidLang = <lint::a11y::use_valid_lang::UseValidLang as biome_analyze::Rule>::Options;
 pub type UseValidTypeof =
     <lint::suspicious::use_valid_typeof::UseValidTypeof as biome_analyze::Rule>::Options;
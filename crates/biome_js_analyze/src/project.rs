@@ -0,0 +1,613 @@
+//! Project-level information shared by the rules that need to look beyond the module being analyzed.
+//!
+//! The workspace computes a [JsModuleSummary] for every module it parses and stores it in a
+//! [JsProjectIndex], along with the path aliases of the `tsconfig.json` of the project
+//! and the GraphQL schemas of the project.
+//! The index is then provided to the analyzer of every module.
+
+use crate::graphql::GraphqlSchema;
+use biome_analyze::RuleFilter;
+use biome_js_syntax::{
+    inner_string_text, AnyJsCombinedSpecifier, AnyJsImportClause, AnyJsImportLike,
+    AnyJsNamedImportSpecifier, AnyJsRoot, AnyTsType, JsExportFromClause, JsExportNamedFromClause,
+    JsNamedImportSpecifiers, TsExternalModuleReference, TsImportEqualsDeclaration, TsImportType,
+};
+use biome_project::TsConfigJson;
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::VecDeque;
+use std::ffi::OsStr;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// The rules that query the [JsProjectIndex].
+///
+/// Modules are parsed as soon as they're opened only when one of these rules is enabled.
+pub const PROJECT_RULES: &[RuleFilter<'static>] = &[
+    RuleFilter::Rule("nursery", "noCircularImports"),
+    RuleFilter::Rule("nursery", "noUnusedExports"),
+    RuleFilter::Rule("nursery", "useValidGraphqlOperations"),
+];
+
+/// The extensions that can be omitted in a relative import, in order of precedence.
+const RESOLVED_EXTENSIONS: &[&str] =
+    &["ts", "tsx", "d.ts", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// The exports that an import refers to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ImportedNames {
+    /// All the exports, e.g. with `import * as ns from "./mod"` or `await import("./mod")`.
+    All,
+    /// The listed exports, e.g. `default` and `a` in `import d, { a } from "./mod"`.
+    Names(Vec<Box<str>>),
+}
+
+/// A module imported or re-exported by another module.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct JsModuleImport {
+    /// The specifier of the imported module, e.g. `./utils` in `import "./utils"`.
+    pub(crate) specifier: Box<str>,
+    pub(crate) names: ImportedNames,
+    /// Whether the import is erased at compile time, e.g. `import type { T } from "./types"`.
+    pub(crate) is_type_only: bool,
+    /// The range of the specifier, quotes included.
+    pub(crate) range: TextRange,
+}
+
+/// The dependencies of a module on other modules.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct JsModuleSummary {
+    /// The static imports, the re-exports, the dynamic imports, and the calls to `require()` of the module.
+    imports: Vec<JsModuleImport>,
+}
+
+impl JsModuleSummary {
+    pub fn from_root(root: &AnyJsRoot) -> Self {
+        let mut summary = Self::default();
+        for node in root.syntax().descendants() {
+            if let Some(import_type) = TsImportType::cast_ref(&node) {
+                // `import("./mod").T` in a type
+                let Some(AnyTsType::TsStringLiteralType(argument)) = import_type
+                    .arguments()
+                    .ok()
+                    .and_then(|arguments| arguments.argument().ok())
+                else {
+                    continue;
+                };
+                if let Ok(token) = argument.literal_token() {
+                    summary.imports.push(JsModuleImport {
+                        specifier: inner_string_text(&token).text().into(),
+                        names: ImportedNames::All,
+                        is_type_only: true,
+                        range: token.text_trimmed_range(),
+                    });
+                }
+                continue;
+            }
+            let Some(import_like) = AnyJsImportLike::cast(node) else {
+                continue;
+            };
+            let (Some(specifier), Some(token)) = (
+                import_like.inner_string_text(),
+                import_like.module_name_token(),
+            ) else {
+                continue;
+            };
+            let (names, is_type_only) = match &import_like {
+                AnyJsImportLike::JsModuleSource(source) => {
+                    let Some(parent) = source.syntax().parent() else {
+                        continue;
+                    };
+                    if let Some(clause) = AnyJsImportClause::cast_ref(&parent) {
+                        (
+                            imported_names(&clause),
+                            clause.type_token().is_some() || imports_only_types(&clause),
+                        )
+                    } else if let Some(clause) = JsExportFromClause::cast_ref(&parent) {
+                        (ImportedNames::All, clause.type_token().is_some())
+                    } else if let Some(clause) = JsExportNamedFromClause::cast_ref(&parent) {
+                        let specifiers = clause.specifiers();
+                        let names = specifiers
+                            .iter()
+                            .filter_map(|specifier| {
+                                let name = specifier.ok()?.source_name().ok()?;
+                                Some(name.inner_string_text().ok()?.text().into())
+                            })
+                            .collect();
+                        let is_type_only = clause.type_token().is_some()
+                            || specifiers.iter().all(|specifier| {
+                                specifier.is_ok_and(|specifier| specifier.type_token().is_some())
+                            });
+                        (ImportedNames::Names(names), is_type_only)
+                    } else if let Some(reference) = TsExternalModuleReference::cast(parent) {
+                        // `import mod = require("./mod")`
+                        let is_type_only = reference
+                            .parent::<TsImportEqualsDeclaration>()
+                            .is_some_and(|declaration| declaration.type_token().is_some());
+                        (ImportedNames::All, is_type_only)
+                    } else {
+                        // `declare module "mod" {}`
+                        continue;
+                    }
+                }
+                AnyJsImportLike::JsCallExpression(_)
+                | AnyJsImportLike::JsImportCallExpression(_) => (ImportedNames::All, false),
+            };
+            summary.imports.push(JsModuleImport {
+                specifier: specifier.text().into(),
+                names,
+                is_type_only,
+                range: token.text_trimmed_range(),
+            });
+        }
+        summary
+    }
+
+    pub(crate) fn imports(&self) -> &[JsModuleImport] {
+        &self.imports
+    }
+}
+
+/// Returns the exports that an import clause refers to.
+fn imported_names(clause: &AnyJsImportClause) -> ImportedNames {
+    let named_specifiers = match clause {
+        AnyJsImportClause::JsImportBareClause(_) => return ImportedNames::Names(Vec::new()),
+        AnyJsImportClause::JsImportDefaultClause(_) => {
+            return ImportedNames::Names(vec!["default".into()])
+        }
+        AnyJsImportClause::JsImportNamespaceClause(_) => return ImportedNames::All,
+        AnyJsImportClause::JsImportNamedClause(clause) => clause.named_specifiers().ok(),
+        AnyJsImportClause::JsImportCombinedClause(clause) => match clause.specifier() {
+            Ok(AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers)) => Some(specifiers),
+            Ok(AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(_)) => return ImportedNames::All,
+            Err(_) => None,
+        },
+    };
+    let mut names: Vec<Box<str>> = named_specifiers
+        .iter()
+        .flat_map(JsNamedImportSpecifiers::specifiers)
+        .filter_map(|specifier| imported_name(&specifier.ok()?))
+        .collect();
+    if matches!(clause, AnyJsImportClause::JsImportCombinedClause(_)) {
+        names.push("default".into());
+    }
+    ImportedNames::Names(names)
+}
+
+/// Returns the name of the export that `specifier` refers to, e.g. `a` in `import { a as b } from "./mod"`.
+fn imported_name(specifier: &AnyJsNamedImportSpecifier) -> Option<Box<str>> {
+    match specifier {
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => Some(
+            specifier
+                .name()
+                .ok()?
+                .inner_string_text()
+                .ok()?
+                .text()
+                .into(),
+        ),
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => Some(
+            specifier
+                .local_name()
+                .ok()?
+                .as_js_identifier_binding()?
+                .name_token()
+                .ok()?
+                .text_trimmed()
+                .into(),
+        ),
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => None,
+    }
+}
+
+/// Returns `true` if all the specifiers of a non-empty named import have the `type` modifier,
+/// e.g. `import { type A, type B } from "./mod"`.
+fn imports_only_types(clause: &AnyJsImportClause) -> bool {
+    let AnyJsImportClause::JsImportNamedClause(clause) = clause else {
+        return false;
+    };
+    let Ok(named_specifiers) = clause.named_specifiers() else {
+        return false;
+    };
+    let specifiers = named_specifiers.specifiers();
+    !specifiers.is_empty()
+        && specifiers
+            .iter()
+            .all(|specifier| specifier.is_ok_and(|specifier| specifier.type_token().is_some()))
+}
+
+/// The exports of a module that other modules use.
+#[derive(Debug, Default)]
+pub(crate) struct UsedExports {
+    /// Whether another module uses all the exports, e.g. with `import * as ns from "./mod"`.
+    all: bool,
+    names: FxHashSet<Box<str>>,
+}
+
+impl UsedExports {
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.all || self.names.contains(name)
+    }
+}
+
+/// A module imported by another module of the project.
+#[derive(Debug)]
+struct Dependency {
+    path: PathBuf,
+    /// Whether all the imports of the module are erased at compile time.
+    is_type_only: bool,
+}
+
+/// The imports of the modules of the project, resolved to the modules they refer to.
+#[derive(Debug, Default)]
+struct ResolvedImports {
+    /// The exports used by the modules of the project, keyed by the path of the module that declares them.
+    used_exports: FxHashMap<PathBuf, UsedExports>,
+    /// The modules imported by every module of the project, in order of first import.
+    dependencies: FxHashMap<PathBuf, Vec<Dependency>>,
+}
+
+/// A path alias of the `paths` compiler option of a `tsconfig.json`,
+/// e.g. `"@/*": ["./src/*"]` or `"@config": ["./src/config.ts"]`.
+#[derive(Debug)]
+struct PathAlias {
+    /// The alias, without its trailing `*`.
+    alias: Box<str>,
+    /// The path of the target, without its trailing `*`.
+    target: PathBuf,
+    /// Whether the alias ends with a `*`, matching any path under the target.
+    is_wildcard: bool,
+}
+
+/// The summaries of all the modules known by the workspace.
+#[derive(Debug, Default)]
+pub struct JsProjectIndex {
+    files: RwLock<FxHashMap<PathBuf, JsModuleSummary>>,
+    /// The resolved imports of the modules of the project.
+    ///
+    /// They're computed the first time a rule needs them, and cleared when a summary changes,
+    /// so analyzing all the modules of the project doesn't resolve all their imports again and again.
+    resolved_imports: RwLock<Option<Arc<ResolvedImports>>>,
+    /// The path aliases of the `tsconfig.json` or `jsconfig.json` of the project.
+    path_aliases: RwLock<Vec<PathAlias>>,
+    /// The schemas declared by the GraphQL files of the project.
+    graphql_schemas: RwLock<FxHashMap<PathBuf, Arc<GraphqlSchema>>>,
+    /// Whether all the files of the project are indexed.
+    ///
+    /// That's the case when the CLI analyzes the project, but not in an editor,
+    /// which only knows the files that are open.
+    complete: AtomicBool,
+}
+
+impl JsProjectIndex {
+    /// Stores the summary of the module at `path`, replacing the previous one.
+    pub fn insert(&self, path: &Path, summary: JsModuleSummary) {
+        let path = normalize_path(path);
+        {
+            let mut files = self.files.write().unwrap();
+            if files.get(&path) == Some(&summary) {
+                return;
+            }
+            files.insert(path, summary);
+        }
+        self.resolved_imports.write().unwrap().take();
+    }
+
+    /// Forgets the module or the GraphQL schema at `path`.
+    pub fn remove(&self, path: &Path) {
+        let path = normalize_path(path);
+        self.graphql_schemas.write().unwrap().remove(&path);
+        let removed = self.files.write().unwrap().remove(&path).is_some();
+        if removed {
+            self.resolved_imports.write().unwrap().take();
+        }
+    }
+
+    /// Sets whether all the files of the project are indexed.
+    pub fn set_complete(&self, complete: bool) {
+        self.complete.store(complete, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if all the files of the project are indexed.
+    ///
+    /// A file that isn't found in the index, such as a GraphQL schema, can only be reported
+    /// as missing when the index is complete.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.complete.load(Ordering::Relaxed)
+    }
+
+    /// Stores the schema declared by the GraphQL file at `path`, replacing the previous one.
+    pub fn insert_graphql_schema(&self, path: &Path, schema: GraphqlSchema) {
+        self.graphql_schemas
+            .write()
+            .unwrap()
+            .insert(normalize_path(path), Arc::new(schema));
+    }
+
+    /// Returns the schema of the GraphQL file whose path ends with `path`,
+    /// e.g. `src/schema.graphql` for `schema.graphql`.
+    ///
+    /// When several files match, the one with the shortest path is used.
+    pub(crate) fn graphql_schema(&self, path: &Path) -> Option<Arc<GraphqlSchema>> {
+        let path = normalize_path(path);
+        if path.as_os_str().is_empty() {
+            return None;
+        }
+        self.graphql_schemas
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(schema_path, _)| schema_path.ends_with(&path))
+            .min_by(|(a, _), (b, _)| {
+                (a.components().count(), a.as_path()).cmp(&(b.components().count(), b.as_path()))
+            })
+            .map(|(_, schema)| schema.clone())
+    }
+
+    /// Stores the path aliases declared in the `paths` compiler option of `tsconfig`,
+    /// replacing the previous ones. `path` is the path of the `tsconfig.json` or `jsconfig.json` file.
+    ///
+    /// Only the aliases that are exact, or that end with a `*` mapped to targets that end with a `*`,
+    /// are supported.
+    pub fn set_tsconfig(&self, path: &Path, tsconfig: &TsConfigJson) {
+        let directory = path.parent().unwrap_or(Path::new(""));
+        let compiler_options = &tsconfig.compiler_options;
+        let base = match &compiler_options.base_url {
+            Some(base_url) => directory.join(base_url),
+            None => directory.to_path_buf(),
+        };
+        let mut path_aliases = Vec::new();
+        for (alias, targets) in &compiler_options.paths {
+            let alias_prefix = alias.strip_suffix('*');
+            // `"*": ["*"]` would turn every module under `baseUrl` into a bare specifier
+            if alias_prefix.is_some_and(|prefix| prefix.is_empty() || prefix.contains('*'))
+                || (alias_prefix.is_none() && alias.contains('*'))
+            {
+                continue;
+            }
+            for target in targets {
+                let (target, is_wildcard) = match target.strip_suffix('*') {
+                    Some(target) => (target, true),
+                    None => (target.as_str(), false),
+                };
+                if target.contains('*') || is_wildcard != alias_prefix.is_some() {
+                    continue;
+                }
+                path_aliases.push(PathAlias {
+                    alias: alias_prefix.unwrap_or(alias).into(),
+                    target: normalize_path(&base.join(target)),
+                    is_wildcard,
+                });
+            }
+        }
+        // The map of the `paths` isn't ordered
+        path_aliases.sort_by(|a, b| a.alias.cmp(&b.alias).then_with(|| a.target.cmp(&b.target)));
+        *self.path_aliases.write().unwrap() = path_aliases;
+    }
+
+    /// Returns the specifier that refers to `target` through a path alias, e.g. `@/utils/format`
+    /// for `src/utils/format` with the alias `"@/*": ["./src/*"]`.
+    ///
+    /// When several aliases cover `target`, the one with the most specific target is used.
+    pub(crate) fn alias_of(&self, target: &Path) -> Option<String> {
+        let target = normalize_path(target);
+        let path_aliases = self.path_aliases.read().unwrap();
+        path_aliases
+            .iter()
+            .filter_map(|path_alias| {
+                let specifier = if path_alias.is_wildcard {
+                    let rest = target.strip_prefix(&path_alias.target).ok()?;
+                    let mut specifier = path_alias.alias.to_string();
+                    for (index, component) in rest.components().enumerate() {
+                        if index > 0 {
+                            specifier.push('/');
+                        }
+                        specifier.push_str(component.as_os_str().to_str()?);
+                    }
+                    if specifier.len() == path_alias.alias.len() {
+                        return None;
+                    }
+                    specifier
+                } else if target == path_alias.target
+                    || target == path_alias.target.with_extension("")
+                {
+                    path_alias.alias.to_string()
+                } else {
+                    return None;
+                };
+                Some((path_alias.target.components().count(), specifier))
+            })
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, specifier)| specifier)
+    }
+
+    /// Returns `true` if the index knows a module other than `path`.
+    pub(crate) fn has_other_file(&self, path: &Path) -> bool {
+        let path = normalize_path(path);
+        self.files
+            .read()
+            .unwrap()
+            .keys()
+            .any(|file_path| *file_path != path)
+    }
+
+    /// Returns the path of the indexed module that `specifier` refers to when it's imported by `importer`.
+    pub(crate) fn resolve(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        let files = self.files.read().unwrap();
+        resolve_import(&normalize_path(importer), specifier, &files).map(Path::to_path_buf)
+    }
+
+    /// Calls `f` with the exports of the module at `path` that are used by the modules of the project,
+    /// or with [None] if no module imports it.
+    pub(crate) fn with_used_exports<R>(
+        &self,
+        path: &Path,
+        f: impl FnOnce(Option<&UsedExports>) -> R,
+    ) -> R {
+        let resolved_imports = self.resolved_imports();
+        f(resolved_imports.used_exports.get(&normalize_path(path)))
+    }
+
+    /// Returns the shortest cycle of imports that goes from the module at `path` to the module at `dependency`,
+    /// and then back to `path`.
+    ///
+    /// The cycle is returned as the list of its modules, starting with `path` and `dependency`.
+    /// The cycles of more than `max_length` modules are ignored,
+    /// as well as the type-only imports when `ignore_type_only` is `true`.
+    pub(crate) fn find_cycle(
+        &self,
+        path: &Path,
+        dependency: &Path,
+        max_length: usize,
+        ignore_type_only: bool,
+    ) -> Option<Vec<PathBuf>> {
+        let path = normalize_path(path);
+        if dependency == path {
+            return (max_length >= 1).then(|| vec![path]);
+        }
+        if max_length < 2 {
+            return None;
+        }
+        let resolved_imports = self.resolved_imports();
+        // Breadth-first search, so the first path found is the shortest one.
+        // `previous` maps every visited module to the module that imports it.
+        let mut previous = FxHashMap::<&Path, &Path>::default();
+        previous.insert(dependency, &path);
+        let mut queue = VecDeque::from([(dependency, 2)]);
+        while let Some((module, length)) = queue.pop_front() {
+            let Some(dependencies) = resolved_imports.dependencies.get(module) else {
+                continue;
+            };
+            for next in dependencies {
+                if ignore_type_only && next.is_type_only {
+                    continue;
+                }
+                if next.path == path {
+                    let mut cycle = vec![module.to_path_buf()];
+                    let mut current = module;
+                    while let Some(&importer) = previous.get(current) {
+                        cycle.push(importer.to_path_buf());
+                        current = importer;
+                    }
+                    cycle.reverse();
+                    return Some(cycle);
+                }
+                if length < max_length && !previous.contains_key(next.path.as_path()) {
+                    previous.insert(&next.path, module);
+                    queue.push_back((&next.path, length + 1));
+                }
+            }
+        }
+        None
+    }
+
+    fn resolved_imports(&self) -> Arc<ResolvedImports> {
+        if let Some(resolved_imports) = self.resolved_imports.read().unwrap().as_ref() {
+            return resolved_imports.clone();
+        }
+        let mut cache = self.resolved_imports.write().unwrap();
+        if let Some(resolved_imports) = cache.as_ref() {
+            return resolved_imports.clone();
+        }
+        let files = self.files.read().unwrap();
+        let mut resolved_imports = ResolvedImports::default();
+        for (importer, summary) in files.iter() {
+            let mut dependencies = Vec::<Dependency>::new();
+            for import in summary.imports() {
+                let Some(resolved) = resolve_import(importer, &import.specifier, &files) else {
+                    continue;
+                };
+                let used = resolved_imports
+                    .used_exports
+                    .entry(resolved.to_path_buf())
+                    .or_default();
+                match &import.names {
+                    ImportedNames::All => used.all = true,
+                    ImportedNames::Names(names) => used.names.extend(names.iter().cloned()),
+                }
+                match dependencies
+                    .iter_mut()
+                    .find(|dependency| dependency.path == resolved)
+                {
+                    Some(dependency) => dependency.is_type_only &= import.is_type_only,
+                    None => dependencies.push(Dependency {
+                        path: resolved.to_path_buf(),
+                        is_type_only: import.is_type_only,
+                    }),
+                }
+            }
+            resolved_imports
+                .dependencies
+                .insert(importer.clone(), dependencies);
+        }
+        let resolved_imports = Arc::new(resolved_imports);
+        *cache = Some(resolved_imports.clone());
+        resolved_imports
+    }
+}
+
+/// Returns the path of the indexed module that `specifier` refers to when it's imported by `importer`.
+///
+/// Only relative specifiers are resolved. As in Node.js and TypeScript, the extension and
+/// the `/index` suffix can be omitted, and a `.js` extension can refer to a TypeScript module.
+pub(crate) fn resolve_import<'a>(
+    importer: &Path,
+    specifier: &str,
+    files: &'a FxHashMap<PathBuf, JsModuleSummary>,
+) -> Option<&'a Path> {
+    if !(specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || matches!(specifier, "." | ".."))
+    {
+        return None;
+    }
+    let base = normalize_path(&importer.parent()?.join(specifier));
+    let find = |candidate: &Path| {
+        files
+            .get_key_value(candidate)
+            .map(|(path, _)| path.as_path())
+    };
+    if let Some(resolved) = find(&base) {
+        return Some(resolved);
+    }
+    let typescript_extensions: &[&str] = match base.extension().and_then(OsStr::to_str) {
+        Some("js") => &["ts", "tsx", "d.ts"],
+        Some("jsx") => &["tsx"],
+        Some("mjs") => &["mts"],
+        Some("cjs") => &["cts"],
+        _ => &[],
+    };
+    typescript_extensions
+        .iter()
+        .find_map(|extension| find(&base.with_extension(extension)))
+        .or_else(|| {
+            RESOLVED_EXTENSIONS.iter().find_map(|extension| {
+                let mut candidate = base.clone().into_os_string();
+                candidate.push(".");
+                candidate.push(extension);
+                find(Path::new(&candidate))
+            })
+        })
+        .or_else(|| {
+            let index = base.join("index");
+            RESOLVED_EXTENSIONS
+                .iter()
+                .find_map(|extension| find(&index.with_extension(extension)))
+        })
+}
+
+/// Removes the `.` components of `path`, and resolves its `..` components lexically.
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
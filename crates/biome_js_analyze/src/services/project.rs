@@ -0,0 +1,67 @@
+use crate::project::JsProjectIndex;
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleKey, ServiceBag, SyntaxVisitor,
+};
+use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode};
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+/// The [ProjectServices] give access to the [JsProjectIndex] of the workspace.
+#[derive(Debug, Clone)]
+pub struct ProjectServices {
+    project: Arc<JsProjectIndex>,
+}
+
+impl ProjectServices {
+    pub fn project(&self) -> &JsProjectIndex {
+        &self.project
+    }
+}
+
+impl FromServices for ProjectServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> Result<Self, MissingServicesDiagnostic> {
+        let project: &Arc<JsProjectIndex> = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["JsProjectIndex"])
+        })?;
+        Ok(Self {
+            project: project.clone(),
+        })
+    }
+}
+
+impl Phase for ProjectServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the project index** to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct Project<N>(pub N);
+
+impl<N> Queryable for Project<N>
+where
+    N: AstNode<Language = JsLanguage> + 'static,
+{
+    type Input = JsSyntaxNode;
+    type Output = N;
+
+    type Language = JsLanguage;
+    type Services = ProjectServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, _: &AnyJsRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
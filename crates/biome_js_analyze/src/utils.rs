@@ -3,8 +3,11 @@ use biome_rowan::{AstNode, Direction, WalkEvent};
 use std::iter;
 
 pub mod batch;
+pub(crate) mod jsdoc;
+pub(crate) mod promise;
+pub(crate) mod regex;
 pub mod rename;
-pub mod restricted_regex;
+pub(crate) mod test;
 #[cfg(test)]
 pub mod tests;
 
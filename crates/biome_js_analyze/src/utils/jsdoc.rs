@@ -0,0 +1,368 @@
+//! A minimal parser of the JSDoc comments, i.e. the block comments that start with `/**`.
+//!
+//! The parser only extracts the block tags of a comment, such as `@param` or `@returns`,
+//! with the ranges of their parts, so that the rules can report and fix them.
+
+use biome_js_syntax::{JsSyntaxKind, JsSyntaxNode, JsSyntaxToken};
+use biome_rowan::{TextRange, TextSize};
+
+/// A JSDoc comment attached to a node.
+#[derive(Debug, Clone)]
+pub struct JsDoc {
+    /// The token that holds the comment in its leading trivia.
+    token: Option<JsSyntaxToken>,
+    /// The range of the comment in the file.
+    range: TextRange,
+    /// The text of the comment, including the comment markers.
+    text: String,
+    tags: Vec<JsDocTag>,
+}
+
+/// A block tag of a JSDoc comment, such as `@param {string} name The name.`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct JsDocTag {
+    /// The name of the tag, without `@`.
+    name: String,
+    /// The range of the tag name, including `@`, relative to the start of the comment.
+    name_range: TextRange,
+    /// The type of the tag, without the braces, for the tags that accept a type.
+    type_expression: Option<String>,
+    /// The name of the documented parameter or property, and its range relative to the start of the comment.
+    parameter: Option<(String, TextRange)>,
+    /// The description of the tag, where the lines are joined by spaces.
+    description: String,
+    /// The range of the lines of the tag, relative to the start of the comment.
+    ///
+    /// The range includes the indentation of the first line, and the line break of the last line.
+    lines_range: TextRange,
+}
+
+impl JsDoc {
+    /// Returns the JSDoc comment that documents `node`,
+    /// i.e. the last comment of its leading trivia, if it's a JSDoc comment.
+    pub(crate) fn for_node(node: &JsSyntaxNode) -> Option<Self> {
+        let token = node.first_token()?;
+        let comment = token
+            .leading_trivia()
+            .pieces()
+            .filter(|piece| piece.is_comments())
+            .last()?;
+        let mut doc = Self::parse(comment.text(), comment.text_range())?;
+        doc.token = Some(token);
+        Some(doc)
+    }
+
+    /// Parses the comment `text` located at `range`.
+    ///
+    /// Returns [None] if `text` isn't a JSDoc comment.
+    pub(crate) fn parse(text: &str, range: TextRange) -> Option<Self> {
+        let content = text.strip_prefix("/**")?.strip_suffix("*/")?;
+        if content.starts_with('*') {
+            // `/***` isn't a JSDoc comment
+            return None;
+        }
+        let content_start = 3;
+        let content_end = content_start + content.len();
+        let mut tags: Vec<JsDocTag> = Vec::new();
+        let mut line_start = content_start;
+        while line_start < content_end {
+            let line_end = text[line_start..content_end]
+                .find('\n')
+                .map_or(content_end, |index| line_start + index + 1);
+            let line = &text[line_start..line_end];
+            let trimmed = line.trim_start();
+            let trimmed = if line_start == content_start {
+                trimmed
+            } else {
+                trimmed.strip_prefix('*').unwrap_or(trimmed).trim_start()
+            };
+            let offset = line_start + (line.len() - trimmed.len());
+            let trimmed = trimmed.trim_end();
+            if trimmed.starts_with('@') {
+                tags.push(JsDocTag::parse(trimmed, offset, line_start, line_end));
+            } else if let Some(tag) = tags.last_mut() {
+                if !trimmed.is_empty() {
+                    if !tag.description.is_empty() {
+                        tag.description.push(' ');
+                    }
+                    tag.description.push_str(trimmed);
+                }
+                if line_end < content_end || !trimmed.is_empty() {
+                    tag.lines_range = TextRange::new(tag.lines_range.start(), text_size(line_end));
+                }
+            }
+            line_start = line_end;
+        }
+        Some(Self {
+            token: None,
+            range,
+            text: text.to_string(),
+            tags,
+        })
+    }
+
+    /// Returns the token that holds the comment in its leading trivia.
+    pub(crate) fn token(&self) -> Option<&JsSyntaxToken> {
+        self.token.as_ref()
+    }
+
+    /// Returns the range of the comment in the file.
+    pub(crate) fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Returns the text of the comment, including the comment markers.
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the block tags of the comment, in their order of appearance.
+    pub(crate) fn tags(&self) -> &[JsDocTag] {
+        &self.tags
+    }
+
+    /// Returns the first tag named `name`, or one of its synonyms.
+    pub(crate) fn find_tag(&self, names: &[&str]) -> Option<&JsDocTag> {
+        self.tags.iter().find(|tag| names.contains(&tag.name()))
+    }
+
+    /// Returns `true` if the comment spans multiple lines,
+    /// with the closing `*/` on its own line.
+    pub(crate) fn is_multiline(&self) -> bool {
+        self.text.contains('\n') && self.closing_line_start().is_some()
+    }
+
+    /// Returns the file range of `range`, a range relative to the start of the comment.
+    pub(crate) fn absolute_range(&self, range: TextRange) -> TextRange {
+        range + self.range.start()
+    }
+
+    /// Returns the offset, relative to the start of the comment, of the line that only contains `*/`.
+    pub(crate) fn closing_line_start(&self) -> Option<TextSize> {
+        let content_end = self.text.len() - 2;
+        let line_start = self.text[..content_end].rfind('\n')? + 1;
+        self.text[line_start..content_end]
+            .trim()
+            .is_empty()
+            .then(|| text_size(line_start))
+    }
+
+    /// Returns the text that precedes the content of the lines of the comment, such as `   * `.
+    pub(crate) fn line_prefix(&self) -> String {
+        let closing_line = self.closing_line_start().map_or("", |start| {
+            &self.text[usize::from(start)..self.text.len() - 2]
+        });
+        format!("{closing_line}* ")
+    }
+}
+
+impl JsDocTag {
+    /// Parses the tag at the start of `line`, a line of the comment without the leading `*`.
+    fn parse(line: &str, offset: usize, line_start: usize, line_end: usize) -> Self {
+        let name_end = line[1..]
+            .find(|c: char| c.is_whitespace() || c == '{')
+            .map_or(line.len(), |index| index + 1);
+        let name = line[1..name_end].to_string();
+        let mut rest = &line[name_end..];
+        let mut type_expression = None;
+        let mut parameter = None;
+        if is_typed_tag(&name) {
+            let trimmed = rest.trim_start();
+            if let Some(type_text) = trimmed.strip_prefix('{') {
+                let mut depth = 1;
+                let type_end = type_text.find(|c| {
+                    match c {
+                        '{' => depth += 1,
+                        '}' => depth -= 1,
+                        _ => {}
+                    }
+                    depth == 0
+                });
+                if let Some(type_end) = type_end {
+                    type_expression = Some(type_text[..type_end].trim().to_string());
+                    rest = &type_text[type_end + 1..];
+                }
+            }
+        }
+        if is_parameter_tag(&name) {
+            let trimmed = rest.trim_start();
+            let name_start = offset + (line.len() - trimmed.len());
+            let (parameter_text, parameter_start, end) = match trimmed.strip_prefix('[') {
+                // Optional parameter, such as `[name=default]`
+                Some(optional) => {
+                    let end = optional.find(']').unwrap_or(optional.len());
+                    let text = optional[..end].split('=').next().unwrap_or_default();
+                    let leading_spaces = text.len() - text.trim_start().len();
+                    (text.trim(), name_start + 1 + leading_spaces, end + 2)
+                }
+                None => {
+                    let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+                    (&trimmed[..end], name_start, end)
+                }
+            };
+            if !parameter_text.is_empty() {
+                parameter = Some((
+                    parameter_text.to_string(),
+                    TextRange::at(text_size(parameter_start), text_size(parameter_text.len())),
+                ));
+            }
+            rest = trimmed.get(end..).unwrap_or_default();
+        }
+        let rest = rest.trim();
+        // For the `@returns` tag, a hyphen may separate the type from the description
+        let description = rest.strip_prefix("- ").unwrap_or(rest).to_string();
+        Self {
+            name,
+            name_range: TextRange::at(text_size(offset), text_size(name_end)),
+            type_expression,
+            parameter,
+            description,
+            lines_range: TextRange::new(text_size(line_start), text_size(line_end)),
+        }
+    }
+
+    /// Returns the name of the tag, without `@`.
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the range of the tag name, including `@`, relative to the start of the comment.
+    pub(crate) fn name_range(&self) -> TextRange {
+        self.name_range
+    }
+
+    /// Returns the type of the tag, without the braces.
+    pub(crate) fn type_expression(&self) -> Option<&str> {
+        self.type_expression.as_deref()
+    }
+
+    /// Returns the name of the documented parameter or property, and its range relative to the start of the comment.
+    pub(crate) fn parameter(&self) -> Option<(&str, TextRange)> {
+        self.parameter
+            .as_ref()
+            .map(|(name, range)| (name.as_str(), *range))
+    }
+
+    /// Returns the description of the tag.
+    pub(crate) fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the range of the lines of the tag, relative to the start of the comment.
+    pub(crate) fn lines_range(&self) -> TextRange {
+        self.lines_range
+    }
+}
+
+/// The names of the tags that document a parameter.
+pub(crate) const PARAM_TAGS: &[&str] = &["param", "arg", "argument"];
+
+/// The names of the tags that document the returned value.
+pub(crate) const RETURNS_TAGS: &[&str] = &["returns", "return"];
+
+fn is_parameter_tag(name: &str) -> bool {
+    PARAM_TAGS.contains(&name) || matches!(name, "property" | "prop")
+}
+
+fn is_typed_tag(name: &str) -> bool {
+    is_parameter_tag(name) || RETURNS_TAGS.contains(&name) || matches!(name, "throws" | "type")
+}
+
+fn text_size(offset: usize) -> TextSize {
+    TextSize::from(offset as u32)
+}
+
+/// Returns the node that a JSDoc comment of `node` precedes,
+/// i.e. the statement or the export that wraps the declaration `node`.
+pub(crate) fn documented_node(node: &JsSyntaxNode) -> JsSyntaxNode {
+    let mut node = node.clone();
+    while let Some(parent) = node.parent() {
+        if !matches!(
+            parent.kind(),
+            JsSyntaxKind::JS_VARIABLE_DECLARATOR_LIST
+                | JsSyntaxKind::JS_VARIABLE_DECLARATION
+                | JsSyntaxKind::JS_VARIABLE_STATEMENT
+                | JsSyntaxKind::JS_VARIABLE_DECLARATION_CLAUSE
+                | JsSyntaxKind::JS_EXPORT
+                | JsSyntaxKind::JS_EXPORT_DEFAULT_DECLARATION_CLAUSE
+                | JsSyntaxKind::TS_DECLARE_STATEMENT
+                | JsSyntaxKind::TS_EXPORT_DECLARE_CLAUSE
+        ) {
+            break;
+        }
+        node = parent;
+    }
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> JsDoc {
+        JsDoc::parse(
+            text,
+            TextRange::at(TextSize::from(0), text_size(text.len())),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn not_jsdoc() {
+        let range = TextRange::default();
+        assert!(JsDoc::parse("/* comment */", range).is_none());
+        assert!(JsDoc::parse("/*** banner ***/", range).is_none());
+        assert!(JsDoc::parse("// comment", range).is_none());
+    }
+
+    #[test]
+    fn parameter_tags() {
+        let text = "/**\n * Sums.\n * @param {number} a The first\n *   value.\n * @param [b=0] - The second value.\n * @returns {number} The sum.\n */";
+        let doc = parse(text);
+        let tags = doc.tags();
+        assert_eq!(tags.len(), 3);
+
+        assert_eq!(tags[0].name(), "param");
+        assert_eq!(&text[tags[0].name_range()], "@param");
+        assert_eq!(tags[0].type_expression(), Some("number"));
+        let (name, range) = tags[0].parameter().unwrap();
+        assert_eq!(name, "a");
+        assert_eq!(&text[range], "a");
+        assert_eq!(tags[0].description(), "The first value.");
+        assert_eq!(
+            &text[tags[0].lines_range()],
+            " * @param {number} a The first\n *   value.\n"
+        );
+
+        let (name, range) = tags[1].parameter().unwrap();
+        assert_eq!(name, "b");
+        assert_eq!(&text[range], "b");
+        assert_eq!(tags[1].description(), "The second value.");
+
+        assert_eq!(tags[2].name(), "returns");
+        assert_eq!(tags[2].type_expression(), Some("number"));
+        assert!(tags[2].parameter().is_none());
+        assert_eq!(tags[2].description(), "The sum.");
+
+        assert!(doc.is_multiline());
+        assert_eq!(doc.line_prefix(), " * ");
+    }
+
+    #[test]
+    fn single_line() {
+        let text = "/** @deprecated Use `b` instead. */";
+        let doc = parse(text);
+        assert!(!doc.is_multiline());
+        let tag = doc.find_tag(&["deprecated"]).unwrap();
+        assert_eq!(tag.description(), "Use `b` instead.");
+        assert_eq!(&text[tag.name_range()], "@deprecated");
+    }
+
+    #[test]
+    fn nested_type_braces() {
+        let doc = parse("/** @param {{ a: string }} options */");
+        let tag = &doc.tags()[0];
+        assert_eq!(tag.type_expression(), Some("{ a: string }"));
+        assert_eq!(tag.parameter().unwrap().0, "options");
+    }
+}
@@ -0,0 +1,176 @@
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
+use biome_js_syntax::{
+    global_identifier, AnyJsExpression, AnyJsFunction, AnyTsReturnType, AnyTsType,
+};
+use biome_rowan::AstNode;
+
+/// The static methods of `Promise` that return a promise.
+const PROMISE_STATIC_METHODS: [&str; 7] = [
+    "all",
+    "allSettled",
+    "any",
+    "race",
+    "reject",
+    "resolve",
+    "try",
+];
+
+/// Returns `true` if `expression` is known to evaluate to a promise.
+pub(crate) fn is_promise(
+    expression: &AnyJsExpression,
+    model: &SemanticModel,
+    functions: &[Box<str>],
+) -> bool {
+    match expression {
+        AnyJsExpression::JsParenthesizedExpression(expression) => expression
+            .expression()
+            .is_ok_and(|expression| is_promise(&expression, model, functions)),
+        AnyJsExpression::JsNewExpression(expression) => expression
+            .callee()
+            .is_ok_and(|callee| is_global_promise(&callee, model)),
+        AnyJsExpression::JsCallExpression(call) => {
+            let Ok(callee) = call.callee() else {
+                return false;
+            };
+            let callee = callee.omit_parentheses();
+            if callee_name(&callee)
+                .is_some_and(|name| functions.iter().any(|function| function.as_ref() == name))
+            {
+                return true;
+            }
+            match &callee {
+                // `save()` or `(async () => {})()`
+                AnyJsExpression::JsIdentifierExpression(_)
+                | AnyJsExpression::JsArrowFunctionExpression(_)
+                | AnyJsExpression::JsFunctionExpression(_) => is_promise_function(&callee, model),
+                AnyJsExpression::JsStaticMemberExpression(member) => {
+                    let Ok(object) = member.object() else {
+                        return false;
+                    };
+                    let Some(method) = callee.get_callee_member_name() else {
+                        return false;
+                    };
+                    match method.text_trimmed() {
+                        "then" | "catch" | "finally" => is_promise(&object, model, functions),
+                        method => {
+                            PROMISE_STATIC_METHODS.contains(&method)
+                                && is_global_promise(&object, model)
+                        }
+                    }
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `expression` is known to be a function that returns a promise:
+/// an `async` function, a function whose return type is annotated as `Promise`,
+/// or a reference to such a function declared in the same file.
+pub(crate) fn is_promise_function(expression: &AnyJsExpression, model: &SemanticModel) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+            .name()
+            .ok()
+            .and_then(|reference| model.binding(&reference))
+            .and_then(|binding| binding.tree().declaration())
+            .is_some_and(|declaration| returns_promise(&declaration)),
+        expression => AnyJsFunction::cast(expression.into_syntax())
+            .is_some_and(|function| returns_promise_function(&function)),
+    }
+}
+
+/// Returns `true` if `expression` references the global `Promise`.
+fn is_global_promise(expression: &AnyJsExpression, model: &SemanticModel) -> bool {
+    global_identifier(&expression.clone().omit_parentheses()).is_some_and(|(reference, name)| {
+        name.text() == "Promise" && model.binding(&reference).is_none()
+    })
+}
+
+/// Returns `true` if the function declared by `declaration` returns a promise.
+fn returns_promise(declaration: &AnyJsBindingDeclaration) -> bool {
+    match declaration {
+        AnyJsBindingDeclaration::JsFunctionDeclaration(function) => {
+            returns_promise_function(&function.clone().into())
+        }
+        AnyJsBindingDeclaration::JsFunctionExpression(function) => {
+            returns_promise_function(&function.clone().into())
+        }
+        AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(function) => {
+            function.async_token().is_some()
+                || function
+                    .return_type_annotation()
+                    .and_then(|annotation| annotation.ty().ok())
+                    .is_some_and(|ty| is_promise_type(&ty))
+        }
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            // `const load: () => Promise<void> = ...`
+            let is_annotated = declarator
+                .variable_annotation()
+                .and_then(|annotation| annotation.type_annotation().ok().flatten())
+                .and_then(|annotation| annotation.ty().ok())
+                .is_some_and(|ty| match ty {
+                    AnyTsType::TsFunctionType(function) => {
+                        function.return_type().is_ok_and(|ty| is_promise_type(&ty))
+                    }
+                    _ => false,
+                });
+            is_annotated
+                || declarator
+                    .initializer()
+                    .and_then(|initializer| initializer.expression().ok())
+                    .and_then(|expression| {
+                        AnyJsFunction::cast(expression.omit_parentheses().into_syntax())
+                    })
+                    .is_some_and(|function| returns_promise_function(&function))
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `function` is `async`, or if its return type is annotated as `Promise`.
+///
+/// The `async` generators return an async iterator, not a promise.
+fn returns_promise_function(function: &AnyJsFunction) -> bool {
+    (function.is_async() && !function.is_generator())
+        || function
+            .return_type_annotation()
+            .and_then(|annotation| annotation.ty().ok())
+            .is_some_and(|ty| is_promise_type(&ty))
+}
+
+/// Returns `true` if `ty` is `Promise<T>`.
+fn is_promise_type(ty: &AnyTsReturnType) -> bool {
+    let AnyTsReturnType::AnyTsType(AnyTsType::TsReferenceType(reference)) = ty else {
+        return false;
+    };
+    reference
+        .name()
+        .is_ok_and(|name| name.syntax().text_trimmed() == "Promise")
+}
+
+/// Returns the name of `callee` as it's written, such as `fetch` or `api.get`,
+/// or `None` if it isn't an identifier or a chain of static member accesses.
+fn callee_name(callee: &AnyJsExpression) -> Option<String> {
+    match callee {
+        AnyJsExpression::JsIdentifierExpression(identifier) => Some(
+            identifier
+                .name()
+                .ok()?
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                .to_string(),
+        ),
+        AnyJsExpression::JsThisExpression(_) => Some("this".to_string()),
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            let object = callee_name(&member.object().ok()?.omit_parentheses())?;
+            let member = member.member().ok()?;
+            let member = member.as_js_name()?.value_token().ok()?;
+            Some(format!("{object}.{}", member.text_trimmed()))
+        }
+        _ => None,
+    }
+}
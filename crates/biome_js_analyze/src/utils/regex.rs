@@ -0,0 +1,1020 @@
+//! A parser of the patterns of the regular expressions, that builds their syntax tree.
+//!
+//! The parser follows the syntax of the ECMAScript regular expressions, including the syntax of
+//! [Annex B](https://tc39.es/ecma262/#sec-regular-expressions-patterns) in the patterns without the `u` and `v` flags.
+//! The ranges of the nodes are relative to the start of the pattern.
+
+use biome_rowan::{TextRange, TextSize};
+
+/// The flags of a regular expression that change how its pattern is parsed and matched.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub(crate) struct RegexFlags {
+    /// The `u` or `v` flag.
+    pub(crate) unicode: bool,
+    /// The `v` flag.
+    pub(crate) unicode_sets: bool,
+    /// The `i` flag.
+    pub(crate) ignore_case: bool,
+    /// The `s` flag.
+    pub(crate) dot_all: bool,
+}
+
+impl RegexFlags {
+    pub(crate) fn parse(flags: &str) -> Self {
+        Self {
+            unicode: flags.contains(['u', 'v']),
+            unicode_sets: flags.contains('v'),
+            ignore_case: flags.contains('i'),
+            dot_all: flags.contains('s'),
+        }
+    }
+}
+
+/// A node of the syntax tree of a pattern.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum RegexNode {
+    /// Alternatives separated by `|`, such as `a|b`.
+    Disjunction {
+        range: TextRange,
+        alternatives: Vec<RegexNode>,
+    },
+    /// A sequence of terms, such as `ab`.
+    Alternative {
+        range: TextRange,
+        terms: Vec<RegexNode>,
+    },
+    /// A group or a lookaround, such as `(a)`, `(?:a)`, or `(?=a)`.
+    Group {
+        range: TextRange,
+        kind: GroupKind,
+        body: Box<RegexNode>,
+    },
+    /// A quantified term, such as `a*` or `(ab){2,}`.
+    Quantified {
+        range: TextRange,
+        quantifier: Quantifier,
+        body: Box<RegexNode>,
+    },
+    /// A literal character, or an escape sequence that matches a character, such as `a` or `\n`.
+    Character { range: TextRange, value: char },
+    /// The dot `.`.
+    Dot { range: TextRange },
+    /// A character class escape, such as `\d` or `\p{Letter}`.
+    CharacterClassEscape {
+        range: TextRange,
+        kind: ClassEscapeKind,
+    },
+    /// A character class, such as `[a-z]` or `[^\d]`.
+    CharacterClass {
+        range: TextRange,
+        negated: bool,
+        items: Vec<ClassItem>,
+    },
+    /// An assertion, such as `^` or `\b`.
+    Assertion {
+        range: TextRange,
+        kind: AssertionKind,
+    },
+    /// A backreference, such as `\1` or `\k<name>`.
+    Backreference { range: TextRange },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum GroupKind {
+    /// A capturing group, with its name if it's a named group.
+    Capturing(Option<String>),
+    /// A non-capturing group, such as `(?:a)` or `(?i:a)`.
+    NonCapturing,
+    Lookahead,
+    NegativeLookahead,
+    Lookbehind,
+    NegativeLookbehind,
+}
+
+impl GroupKind {
+    pub(crate) const fn is_lookaround(&self) -> bool {
+        matches!(
+            self,
+            Self::Lookahead | Self::NegativeLookahead | Self::Lookbehind | Self::NegativeLookbehind
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct Quantifier {
+    pub(crate) min: u32,
+    /// The maximum number of repetitions, or [None] if it's unbounded.
+    pub(crate) max: Option<u32>,
+    pub(crate) lazy: bool,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ClassEscapeKind {
+    Digit,
+    NotDigit,
+    Word,
+    NotWord,
+    Space,
+    NotSpace,
+    /// A Unicode property escape, such as `\p{Letter}`.
+    Property,
+    /// A negated Unicode property escape, such as `\P{Letter}`.
+    NotProperty,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum AssertionKind {
+    Start,
+    End,
+    WordBoundary,
+    NotWordBoundary,
+}
+
+/// An item of a character class.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum ClassItem {
+    Character(char),
+    /// A range of characters, such as `a-z`.
+    Range(char, char),
+    Escape(ClassEscapeKind),
+    /// A nested class, or a class operation, of a class with the `v` flag.
+    Nested(CharSet),
+    /// A string disjunction `\q{...}` of a class with the `v` flag.
+    Strings,
+}
+
+impl RegexNode {
+    pub(crate) fn range(&self) -> TextRange {
+        match self {
+            Self::Disjunction { range, .. }
+            | Self::Alternative { range, .. }
+            | Self::Group { range, .. }
+            | Self::Quantified { range, .. }
+            | Self::Character { range, .. }
+            | Self::Dot { range }
+            | Self::CharacterClassEscape { range, .. }
+            | Self::CharacterClass { range, .. }
+            | Self::Assertion { range, .. }
+            | Self::Backreference { range } => *range,
+        }
+    }
+
+    /// Returns the direct children of the node.
+    pub(crate) fn children(&self) -> &[RegexNode] {
+        match self {
+            Self::Disjunction { alternatives, .. } => alternatives,
+            Self::Alternative { terms, .. } => terms,
+            Self::Group { body, .. } | Self::Quantified { body, .. } => std::slice::from_ref(body),
+            _ => &[],
+        }
+    }
+
+    /// Returns the node without the groups that wrap it.
+    pub(crate) fn omit_groups(&self) -> &Self {
+        let mut node = self;
+        loop {
+            node = match node {
+                Self::Group { kind, body, .. } if !kind.is_lookaround() => body,
+                Self::Alternative { terms, .. } if terms.len() == 1 => &terms[0],
+                _ => return node,
+            };
+        }
+    }
+
+    /// Returns `true` if the node can match an empty string.
+    pub(crate) fn can_match_empty(&self) -> bool {
+        match self {
+            Self::Disjunction { alternatives, .. } => {
+                alternatives.iter().any(Self::can_match_empty)
+            }
+            Self::Alternative { terms, .. } => terms.iter().all(Self::can_match_empty),
+            Self::Group { kind, body, .. } => kind.is_lookaround() || body.can_match_empty(),
+            Self::Quantified {
+                quantifier, body, ..
+            } => quantifier.min == 0 || body.can_match_empty(),
+            Self::Assertion { .. } | Self::Backreference { .. } => true,
+            Self::CharacterClass { items, .. } => items.contains(&ClassItem::Strings),
+            Self::Character { .. } | Self::Dot { .. } | Self::CharacterClassEscape { .. } => false,
+        }
+    }
+
+    /// Returns the characters that the node can match at its start.
+    pub(crate) fn first_char_set(&self, flags: RegexFlags) -> CharSet {
+        self.edge_char_set(flags, false)
+    }
+
+    /// Returns the characters that the node can match at its end.
+    pub(crate) fn last_char_set(&self, flags: RegexFlags) -> CharSet {
+        self.edge_char_set(flags, true)
+    }
+
+    fn edge_char_set(&self, flags: RegexFlags, from_end: bool) -> CharSet {
+        match self {
+            Self::Alternative { terms, .. } => {
+                // The terms until the first one that can't match an empty string
+                let edge_terms = if from_end {
+                    let start = terms.iter().rposition(|term| !term.can_match_empty());
+                    &terms[start.unwrap_or(0)..]
+                } else {
+                    let end = terms.iter().position(|term| !term.can_match_empty());
+                    &terms[..end.map_or(terms.len(), |end| end + 1)]
+                };
+                edge_terms.iter().fold(CharSet::empty(), |set, term| {
+                    set.union(&term.edge_char_set(flags, from_end))
+                })
+            }
+            Self::Group { kind, .. } if kind.is_lookaround() => CharSet::empty(),
+            Self::Disjunction { .. } | Self::Group { .. } | Self::Quantified { .. } => {
+                self.children().iter().fold(CharSet::empty(), |set, child| {
+                    set.union(&child.edge_char_set(flags, from_end))
+                })
+            }
+            _ => self.char_set(flags),
+        }
+    }
+
+    /// Returns the characters that the node can match, at any position.
+    pub(crate) fn char_set(&self, flags: RegexFlags) -> CharSet {
+        let set = match self {
+            Self::Character { value, .. } => CharSet::from_char(*value),
+            Self::Dot { .. } => {
+                if flags.dot_all {
+                    CharSet::all()
+                } else {
+                    CharSet::line_terminators().negate()
+                }
+            }
+            Self::CharacterClassEscape { kind, .. } => kind.char_set(),
+            Self::CharacterClass { negated, items, .. } => {
+                let set = items.iter().fold(CharSet::empty(), |set, item| {
+                    set.union(&match item {
+                        ClassItem::Character(value) => CharSet::from_char(*value),
+                        ClassItem::Range(start, end) => CharSet::from_range(*start, *end),
+                        ClassItem::Escape(kind) => kind.char_set(),
+                        ClassItem::Nested(set) => set.clone(),
+                        ClassItem::Strings => CharSet::all(),
+                    })
+                });
+                if *negated {
+                    set.negate()
+                } else {
+                    set
+                }
+            }
+            Self::Group { kind, .. } if kind.is_lookaround() => CharSet::empty(),
+            Self::Assertion { .. } => CharSet::empty(),
+            Self::Backreference { .. } => CharSet::all(),
+            Self::Disjunction { .. }
+            | Self::Alternative { .. }
+            | Self::Group { .. }
+            | Self::Quantified { .. } => {
+                self.children().iter().fold(CharSet::empty(), |set, child| {
+                    set.union(&child.char_set(flags))
+                })
+            }
+        };
+        if flags.ignore_case {
+            set.with_ascii_case_variants()
+        } else {
+            set
+        }
+    }
+}
+
+impl ClassEscapeKind {
+    fn char_set(self) -> CharSet {
+        match self {
+            Self::Digit => CharSet::digits(),
+            Self::NotDigit => CharSet::digits().negate(),
+            Self::Word => CharSet::word(),
+            Self::NotWord => CharSet::word().negate(),
+            Self::Space => CharSet::spaces(),
+            Self::NotSpace => CharSet::spaces().negate(),
+            // The properties aren't resolved
+            Self::Property | Self::NotProperty => CharSet::all(),
+        }
+    }
+}
+
+/// A set of characters, represented by sorted and disjoint ranges of code points.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub(crate) struct CharSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+const MAX_CODE_POINT: u32 = 0x0010_FFFF;
+
+impl CharSet {
+    pub(crate) fn empty() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn all() -> Self {
+        Self {
+            ranges: vec![(0, MAX_CODE_POINT)],
+        }
+    }
+
+    fn from_ranges(ranges: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut ranges: Vec<_> = ranges
+            .into_iter()
+            .filter(|(start, end)| start <= end)
+            .collect();
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub(crate) fn from_char(value: char) -> Self {
+        Self::from_range(value, value)
+    }
+
+    pub(crate) fn from_range(start: char, end: char) -> Self {
+        Self::from_ranges([(start.into(), end.into())])
+    }
+
+    fn digits() -> Self {
+        Self::from_range('0', '9')
+    }
+
+    fn word() -> Self {
+        Self::from_ranges([
+            ('0'.into(), '9'.into()),
+            ('A'.into(), 'Z'.into()),
+            ('_'.into(), '_'.into()),
+            ('a'.into(), 'z'.into()),
+        ])
+    }
+
+    fn spaces() -> Self {
+        Self::from_ranges(
+            [
+                0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x20, 0xA0, 0x1680, 0x2028, 0x2029, 0x202F, 0x205F,
+                0x3000, 0xFEFF,
+            ]
+            .into_iter()
+            .map(|code_point| (code_point, code_point))
+            .chain([(0x2000, 0x200A)]),
+        )
+    }
+
+    fn line_terminators() -> Self {
+        Self::from_ranges([(0x0A, 0x0A), (0x0D, 0x0D), (0x2028, 0x2029)])
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub(crate) fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).copied())
+    }
+
+    pub(crate) fn negate(&self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        let mut start = 0;
+        for &(range_start, range_end) in &self.ranges {
+            if range_start > start {
+                ranges.push((start, range_start - 1));
+            }
+            start = range_end + 1;
+        }
+        if start <= MAX_CODE_POINT {
+            ranges.push((start, MAX_CODE_POINT));
+        }
+        Self { ranges }
+    }
+
+    /// Returns `true` if a character belongs to both sets.
+    pub(crate) fn intersects(&self, other: &Self) -> bool {
+        let (mut left, mut right) = (self.ranges.iter(), other.ranges.iter());
+        let (mut left_range, mut right_range) = (left.next(), right.next());
+        while let (Some(&(left_start, left_end)), Some(&(right_start, right_end))) =
+            (left_range, right_range)
+        {
+            if left_start <= right_end && right_start <= left_end {
+                return true;
+            }
+            if left_end < right_end {
+                left_range = left.next();
+            } else {
+                right_range = right.next();
+            }
+        }
+        false
+    }
+
+    /// Returns the set with the other case of its ASCII letters.
+    fn with_ascii_case_variants(self) -> Self {
+        let variants: Vec<_> = self
+            .ranges
+            .iter()
+            .flat_map(|&(start, end)| {
+                [
+                    (u32::from('a'), u32::from('z')),
+                    (u32::from('A'), u32::from('Z')),
+                ]
+                .into_iter()
+                .filter_map(move |(letters_start, letters_end)| {
+                    let (start, end) = (start.max(letters_start), end.min(letters_end));
+                    // `a` and `A` are 32 code points apart
+                    (start <= end).then_some((start ^ 0x20, end ^ 0x20))
+                })
+            })
+            .collect();
+        Self::from_ranges(self.ranges.into_iter().chain(variants))
+    }
+}
+
+/// Parses `pattern`, the pattern of a regular expression with the flags `flags`.
+///
+/// Returns [None] if the pattern isn't valid.
+pub(crate) fn parse_regex(pattern: &str, flags: RegexFlags) -> Option<RegexNode> {
+    let mut parser = RegexParser {
+        pattern,
+        position: 0,
+        flags,
+    };
+    let node = parser.parse_disjunction()?;
+    (parser.position == pattern.len()).then_some(node)
+}
+
+struct RegexParser<'a> {
+    pattern: &'a str,
+    /// The byte offset of the next character.
+    position: usize,
+    flags: RegexFlags,
+}
+
+impl RegexParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.pattern[self.position..].chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.pattern[self.position..].chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.eat_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    fn eat_str(&mut self, text: &str) -> bool {
+        let is_next = self.pattern[self.position..].starts_with(text);
+        if is_next {
+            self.position += text.len();
+        }
+        is_next
+    }
+
+    fn range_from(&self, start: usize) -> TextRange {
+        TextRange::new(text_size(start), text_size(self.position))
+    }
+
+    fn parse_disjunction(&mut self) -> Option<RegexNode> {
+        let start = self.position;
+        let mut alternatives = vec![self.parse_alternative()?];
+        while self.eat('|') {
+            alternatives.push(self.parse_alternative()?);
+        }
+        if alternatives.len() == 1 {
+            return alternatives.pop();
+        }
+        Some(RegexNode::Disjunction {
+            range: self.range_from(start),
+            alternatives,
+        })
+    }
+
+    fn parse_alternative(&mut self) -> Option<RegexNode> {
+        let start = self.position;
+        let mut terms = Vec::new();
+        while !matches!(self.peek(), None | Some('|' | ')')) {
+            terms.push(self.parse_term()?);
+        }
+        Some(RegexNode::Alternative {
+            range: self.range_from(start),
+            terms,
+        })
+    }
+
+    fn parse_term(&mut self) -> Option<RegexNode> {
+        let start = self.position;
+        let atom = self.parse_atom()?;
+        let is_quantifiable = match &atom {
+            RegexNode::Assertion { .. } => false,
+            // Annex B allows the quantified lookaheads
+            RegexNode::Group { kind, .. } => {
+                !kind.is_lookaround()
+                    || (!self.flags.unicode
+                        && matches!(kind, GroupKind::Lookahead | GroupKind::NegativeLookahead))
+            }
+            _ => true,
+        };
+        let Some(quantifier) = self.parse_quantifier() else {
+            return Some(atom);
+        };
+        if !is_quantifiable {
+            return None;
+        }
+        Some(RegexNode::Quantified {
+            range: self.range_from(start),
+            quantifier,
+            body: Box::new(atom),
+        })
+    }
+
+    fn parse_quantifier(&mut self) -> Option<Quantifier> {
+        let start = self.position;
+        let (min, max) = match self.bump()? {
+            '*' => (0, None),
+            '+' => (1, None),
+            '?' => (0, Some(1)),
+            '{' => match self.parse_braced_bounds() {
+                Some(bounds) => bounds,
+                None => {
+                    self.position = start;
+                    return None;
+                }
+            },
+            _ => {
+                self.position = start;
+                return None;
+            }
+        };
+        let lazy = self.eat('?');
+        Some(Quantifier { min, max, lazy })
+    }
+
+    /// Parses `n}`, `n,}`, or `n,m}` after `{`.
+    fn parse_braced_bounds(&mut self) -> Option<(u32, Option<u32>)> {
+        let min = self.parse_decimal()?;
+        let max = if self.eat(',') {
+            if self.peek() == Some('}') {
+                None
+            } else {
+                Some(self.parse_decimal()?)
+            }
+        } else {
+            Some(min)
+        };
+        if !self.eat('}') || max.is_some_and(|max| max < min) {
+            return None;
+        }
+        Some((min, max))
+    }
+
+    fn parse_decimal(&mut self) -> Option<u32> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+        }
+        let digits = &self.pattern[start..self.position];
+        if digits.is_empty() {
+            return None;
+        }
+        Some(digits.parse().unwrap_or(u32::MAX))
+    }
+
+    fn parse_atom(&mut self) -> Option<RegexNode> {
+        let start = self.position;
+        let c = self.bump()?;
+        let node = match c {
+            '^' => RegexNode::Assertion {
+                range: self.range_from(start),
+                kind: AssertionKind::Start,
+            },
+            '$' => RegexNode::Assertion {
+                range: self.range_from(start),
+                kind: AssertionKind::End,
+            },
+            '.' => RegexNode::Dot {
+                range: self.range_from(start),
+            },
+            '(' => self.parse_group(start)?,
+            '[' => {
+                let (negated, items) = self.parse_class()?;
+                RegexNode::CharacterClass {
+                    range: self.range_from(start),
+                    negated,
+                    items,
+                }
+            }
+            '\\' => self.parse_atom_escape(start)?,
+            // Nothing to repeat
+            '*' | '+' | '?' => return None,
+            '{' => {
+                let position = self.position;
+                if self.flags.unicode || self.parse_braced_bounds().is_some() {
+                    return None;
+                }
+                self.position = position;
+                RegexNode::Character {
+                    range: self.range_from(start),
+                    value: c,
+                }
+            }
+            ')' => return None,
+            ']' | '}' if self.flags.unicode => return None,
+            _ => RegexNode::Character {
+                range: self.range_from(start),
+                value: c,
+            },
+        };
+        Some(node)
+    }
+
+    /// Parses a group after `(`.
+    fn parse_group(&mut self, start: usize) -> Option<RegexNode> {
+        let kind = if self.eat('?') {
+            if self.eat(':') {
+                GroupKind::NonCapturing
+            } else if self.eat('=') {
+                GroupKind::Lookahead
+            } else if self.eat('!') {
+                GroupKind::NegativeLookahead
+            } else if self.eat_str("<=") {
+                GroupKind::Lookbehind
+            } else if self.eat_str("<!") {
+                GroupKind::NegativeLookbehind
+            } else if self.eat('<') {
+                GroupKind::Capturing(Some(self.parse_group_name()?))
+            } else {
+                // Modifiers, such as `(?i:a)` or `(?-i:a)`
+                while self
+                    .peek()
+                    .is_some_and(|c| matches!(c, 'i' | 'm' | 's' | '-'))
+                {
+                    self.bump();
+                }
+                if !self.eat(':') {
+                    return None;
+                }
+                GroupKind::NonCapturing
+            }
+        } else {
+            GroupKind::Capturing(None)
+        };
+        let body = self.parse_disjunction()?;
+        if !self.eat(')') {
+            return None;
+        }
+        Some(RegexNode::Group {
+            range: self.range_from(start),
+            kind,
+            body: Box::new(body),
+        })
+    }
+
+    /// Parses `name>` after `<`.
+    fn parse_group_name(&mut self) -> Option<String> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c != '>') {
+            self.bump();
+        }
+        let name = self.pattern[start..self.position].to_string();
+        (self.eat('>') && !name.is_empty()).then_some(name)
+    }
+
+    /// Parses an escape after `\`, outside a character class.
+    fn parse_atom_escape(&mut self, start: usize) -> Option<RegexNode> {
+        let node = match self.peek()? {
+            'b' | 'B' => {
+                let kind = if self.bump()? == 'b' {
+                    AssertionKind::WordBoundary
+                } else {
+                    AssertionKind::NotWordBoundary
+                };
+                RegexNode::Assertion {
+                    range: self.range_from(start),
+                    kind,
+                }
+            }
+            '1'..='9' => {
+                self.parse_decimal();
+                RegexNode::Backreference {
+                    range: self.range_from(start),
+                }
+            }
+            'k' if self.peek_nth(1) == Some('<') => {
+                self.position += 2;
+                self.parse_group_name()?;
+                RegexNode::Backreference {
+                    range: self.range_from(start),
+                }
+            }
+            _ => match self.parse_character_escape()? {
+                Escape::Character(value) => RegexNode::Character {
+                    range: self.range_from(start),
+                    value,
+                },
+                Escape::Class(kind) => RegexNode::CharacterClassEscape {
+                    range: self.range_from(start),
+                    kind,
+                },
+            },
+        };
+        Some(node)
+    }
+
+    /// Parses an escape that matches a character, or a character class escape, after `\`.
+    fn parse_character_escape(&mut self) -> Option<Escape> {
+        let c = self.bump()?;
+        let escape = match c {
+            'd' => Escape::Class(ClassEscapeKind::Digit),
+            'D' => Escape::Class(ClassEscapeKind::NotDigit),
+            'w' => Escape::Class(ClassEscapeKind::Word),
+            'W' => Escape::Class(ClassEscapeKind::NotWord),
+            's' => Escape::Class(ClassEscapeKind::Space),
+            'S' => Escape::Class(ClassEscapeKind::NotSpace),
+            'p' | 'P' if self.flags.unicode => {
+                if !self.eat('{') {
+                    return None;
+                }
+                while self.peek().is_some_and(|c| c != '}') {
+                    self.bump();
+                }
+                if !self.eat('}') {
+                    return None;
+                }
+                Escape::Class(if c == 'p' {
+                    ClassEscapeKind::Property
+                } else {
+                    ClassEscapeKind::NotProperty
+                })
+            }
+            't' => Escape::Character('\t'),
+            'n' => Escape::Character('\n'),
+            'v' => Escape::Character('\u{B}'),
+            'f' => Escape::Character('\u{C}'),
+            'r' => Escape::Character('\r'),
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => Escape::Character('\0'),
+            'c' if self.peek().is_some_and(|c| c.is_ascii_alphabetic()) => {
+                let letter = self.bump()?;
+                Escape::Character(char::from(letter as u8 % 32))
+            }
+            'x' => match self.parse_hex_digits(2) {
+                Some(value) => Escape::Character(value),
+                None if !self.flags.unicode => Escape::Character('x'),
+                None => return None,
+            },
+            'u' => match self.parse_unicode_escape() {
+                Some(value) => Escape::Character(value),
+                None if !self.flags.unicode => Escape::Character('u'),
+                None => return None,
+            },
+            // Legacy octal escapes
+            '0'..='7' if !self.flags.unicode => {
+                let mut value = c.to_digit(8)?;
+                while value < 32 {
+                    let Some(digit) = self.peek().and_then(|c| c.to_digit(8)) else {
+                        break;
+                    };
+                    self.bump();
+                    value = value * 8 + digit;
+                }
+                Escape::Character(char::from_u32(value)?)
+            }
+            _ if self.flags.unicode && !is_syntax_character(c) && c != '/' && c != '-' => {
+                return None;
+            }
+            _ => Escape::Character(c),
+        };
+        Some(escape)
+    }
+
+    /// Parses `XXXX` or `{X...}` after `\u`.
+    fn parse_unicode_escape(&mut self) -> Option<char> {
+        let start = self.position;
+        if self.flags.unicode && self.eat('{') {
+            let digits_start = self.position;
+            while self.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+                self.bump();
+            }
+            let value = u32::from_str_radix(&self.pattern[digits_start..self.position], 16).ok();
+            if let (Some(value), true) = (value, self.eat('}')) {
+                if let Some(value) = char::from_u32(value) {
+                    return Some(value);
+                }
+            }
+            self.position = start;
+            return None;
+        }
+        let value = self.parse_hex_digits(4);
+        if value.is_none() {
+            self.position = start;
+        }
+        value
+    }
+
+    fn parse_hex_digits(&mut self, count: usize) -> Option<char> {
+        let digits = self.pattern.get(self.position..self.position + count)?;
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+        let value = u32::from_str_radix(digits, 16).ok()?;
+        self.position += count;
+        // A lone surrogate doesn't match any character of a valid string
+        Some(char::from_u32(value).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Parses a character class after `[`, and returns whether it's negated, and its items.
+    fn parse_class(&mut self) -> Option<(bool, Vec<ClassItem>)> {
+        let negated = self.eat('^');
+        let mut items = Vec::new();
+        loop {
+            if self.eat(']') {
+                return Some((negated, items));
+            }
+            if self.flags.unicode_sets {
+                if self.eat('[') {
+                    let (nested_negated, nested_items) = self.parse_class()?;
+                    let set = RegexNode::CharacterClass {
+                        range: TextRange::default(),
+                        negated: nested_negated,
+                        items: nested_items,
+                    }
+                    .char_set(RegexFlags::default());
+                    items.push(ClassItem::Nested(set));
+                    continue;
+                }
+                // The intersections and subtractions are over-approximated by the union of their operands
+                if self.eat_str("&&") || self.eat_str("--") {
+                    continue;
+                }
+                if self.eat_str("\\q{") {
+                    while self.peek().is_some_and(|c| c != '}') {
+                        self.bump();
+                    }
+                    if !self.eat('}') {
+                        return None;
+                    }
+                    items.push(ClassItem::Strings);
+                    continue;
+                }
+            }
+            let item = self.parse_class_atom()?;
+            if self.peek() != Some('-') || matches!(self.peek_nth(1), None | Some(']')) {
+                items.push(item);
+                continue;
+            }
+            self.bump();
+            match (item, self.parse_class_atom()?) {
+                (ClassItem::Character(range_start), ClassItem::Character(range_end)) => {
+                    if range_start > range_end {
+                        return None;
+                    }
+                    items.push(ClassItem::Range(range_start, range_end));
+                }
+                _ if self.flags.unicode => return None,
+                // Annex B: a range with a class escape is a union
+                (range_start, range_end) => {
+                    items.push(range_start);
+                    items.push(ClassItem::Character('-'));
+                    items.push(range_end);
+                }
+            }
+        }
+    }
+
+    fn parse_class_atom(&mut self) -> Option<ClassItem> {
+        let c = self.bump()?;
+        if c != '\\' {
+            return Some(ClassItem::Character(c));
+        }
+        let item = match self.peek()? {
+            'b' => {
+                self.bump();
+                ClassItem::Character('\u{8}')
+            }
+            '-' => {
+                self.bump();
+                ClassItem::Character('-')
+            }
+            _ => match self.parse_character_escape()? {
+                Escape::Character(value) => ClassItem::Character(value),
+                Escape::Class(kind) => ClassItem::Escape(kind),
+            },
+        };
+        Some(item)
+    }
+}
+
+enum Escape {
+    Character(char),
+    Class(ClassEscapeKind),
+}
+
+fn is_syntax_character(c: char) -> bool {
+    matches!(
+        c,
+        '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+    )
+}
+
+fn text_size(offset: usize) -> TextSize {
+    TextSize::from(offset as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(pattern: &str, flags: &str) -> Option<RegexNode> {
+        parse_regex(pattern, RegexFlags::parse(flags))
+    }
+
+    #[test]
+    fn valid_patterns() {
+        for pattern in [
+            "",
+            "a|b",
+            "(a)(?:b)(?<name>c)\\k<name>\\1",
+            "(?=a)(?!b)(?<=c)(?<!d)",
+            "a*b+c?d{2}e{2,}f{2,3}g*?",
+            "[a-z\\d\\-\\]]",
+            "[^]",
+            "\\x41\\u0041\\cA\\0\\n",
+            "^\\bword\\B$",
+            "a{",
+            "]",
+            "(?i:a)",
+        ] {
+            assert!(parse(pattern, "").is_some(), "{pattern}");
+        }
+        assert!(parse("\\u{1F600}\\p{Letter}", "u").is_some());
+        assert!(parse("[[a-z]--[aeiou]\\q{ab}]", "v").is_some());
+    }
+
+    #[test]
+    fn invalid_patterns() {
+        for pattern in ["(", ")", "*", "a**", "[a", "[z-a]", "(?<name)", "^*"] {
+            assert!(parse(pattern, "").is_none(), "{pattern}");
+        }
+        for pattern in ["a{", "]", "\\a", "[\\d-z]"] {
+            assert!(parse(pattern, "u").is_none(), "{pattern}");
+        }
+    }
+
+    #[test]
+    fn quantified_group() {
+        let node = parse("(ab)+?", "").unwrap();
+        let RegexNode::Alternative { terms, .. } = node else {
+            panic!("{node:?}");
+        };
+        let RegexNode::Quantified {
+            range,
+            quantifier,
+            body,
+        } = &terms[0]
+        else {
+            panic!("{terms:?}");
+        };
+        assert_eq!(*range, TextRange::new(0.into(), 6.into()));
+        assert_eq!(
+            *quantifier,
+            Quantifier {
+                min: 1,
+                max: None,
+                lazy: true
+            }
+        );
+        assert!(matches!(
+            body.as_ref(),
+            RegexNode::Group {
+                kind: GroupKind::Capturing(None),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn char_sets() {
+        let flags = RegexFlags::default();
+        let digit = parse("\\d", "").unwrap().char_set(flags);
+        let word = parse("\\w", "").unwrap().char_set(flags);
+        let space = parse("\\s", "").unwrap().char_set(flags);
+        let letters = parse("[a-f]", "").unwrap().char_set(flags);
+        assert!(digit.intersects(&word));
+        assert!(!digit.intersects(&space));
+        assert!(letters.intersects(&word));
+        assert!(!letters.intersects(&parse("[^a-z]", "").unwrap().char_set(flags)));
+        let upper = parse("A", "").unwrap();
+        assert!(!upper.char_set(flags).intersects(&letters));
+        assert!(upper
+            .char_set(RegexFlags::parse("i"))
+            .intersects(&parse("a", "").unwrap().char_set(flags)));
+    }
+}
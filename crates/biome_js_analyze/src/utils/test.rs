@@ -0,0 +1,30 @@
+use biome_js_syntax::{AnyJsExpression, JsCallExpression};
+
+/// Returns `true` if the first name of the callee of `call` is one of `names`.
+///
+/// The callee is followed through the member accesses, the calls, and the template tags,
+/// so that `it.only()`, `describe.each([])()`, and ``test.each`[]`()`` are test calls
+/// of `it`, `describe`, and `test`.
+pub(crate) fn is_test_call(call: &JsCallExpression, names: &[impl AsRef<str>]) -> bool {
+    let mut callee = call.callee().ok();
+    while let Some(expression) = callee {
+        callee = match expression.omit_parentheses() {
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                return identifier
+                    .name()
+                    .and_then(|name| name.value_token())
+                    .is_ok_and(|name| {
+                        names
+                            .iter()
+                            .any(|test_name| test_name.as_ref() == name.text_trimmed())
+                    });
+            }
+            AnyJsExpression::JsStaticMemberExpression(member) => member.object().ok(),
+            AnyJsExpression::JsComputedMemberExpression(member) => member.object().ok(),
+            AnyJsExpression::JsCallExpression(call) => call.callee().ok(),
+            AnyJsExpression::JsTemplateExpression(template) => template.tag(),
+            _ => None,
+        };
+    }
+    false
+}
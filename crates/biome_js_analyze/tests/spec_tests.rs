@@ -1,16 +1,23 @@
 use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
+use biome_deserialize::json::deserialize_from_json_str;
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
+use biome_graphql_parser::parse_graphql;
+use biome_js_analyze::graphql::GraphqlSchema;
+use biome_js_analyze::project::{JsModuleSummary, JsProjectIndex};
 use biome_js_parser::{parse, JsParserOptions};
-use biome_js_syntax::{JsFileSource, JsLanguage, ModuleKind};
-use biome_project::PackageType;
+use biome_js_syntax::{AnyJsRoot, JsFileSource, JsLanguage, ModuleKind};
+use biome_json_parser::JsonParserOptions;
+use biome_project::{PackageType, TsConfigJson};
 use biome_rowan::AstNode;
 use biome_test_utils::{
     assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
     has_bogus_nodes_or_empty_slots, load_manifest, parse_test_path, register_leak_checker,
     scripts_from_json, write_analyzer_snapshot, CheckActionType,
 };
+use std::collections::BTreeMap;
 use std::ops::Deref;
+use std::sync::Arc;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{cjs,cts,js,jsx,tsx,ts,json,jsonc,svelte}", crate::run_test, "module"}
@@ -119,9 +126,16 @@ pub(crate) fn analyze_and_snap(
 
     //
     let options = create_analyzer_options(input_file, &mut diagnostics);
+    let project = create_project_index(input_file, &root, &parser_options);
 
-    let (_, errors) =
-        biome_js_analyze::analyze(&root, filter, &options, source_type, manifest, |event| {
+    let (_, errors) = biome_js_analyze::analyze(
+        &root,
+        filter,
+        &options,
+        source_type,
+        manifest,
+        project,
+        |event| {
             if let Some(mut diag) = event.diagnostic() {
                 for action in event.actions() {
                     if check_action_type.is_suppression() {
@@ -177,7 +191,8 @@ pub(crate) fn analyze_and_snap(
             }
 
             ControlFlow::<Never>::Continue(())
-        });
+        },
+    );
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -194,6 +209,59 @@ pub(crate) fn analyze_and_snap(
     diagnostics.len()
 }
 
+/// Builds the project index of a test file.
+///
+/// A test file can simulate other modules of the project using a special file with
+/// the same name as the test but with extension ".project.json". This file maps file names to
+/// their source code. The test file itself is indexed alongside them. A file mapped to `null`
+/// simulates a file that isn't indexed, in which case the index isn't complete.
+///
+/// The path aliases of the project are read from a file with the extension ".tsconfig.json",
+/// which is treated as a `tsconfig.json` placed next to the test file.
+fn create_project_index(
+    input_file: &Path,
+    root: &AnyJsRoot,
+    options: &JsParserOptions,
+) -> Arc<JsProjectIndex> {
+    let project = JsProjectIndex::default();
+    let mut complete = true;
+    let project_file = input_file.with_extension("project.json");
+    if let Ok(json) = read_to_string(&project_file) {
+        project.insert(input_file, JsModuleSummary::from_root(root));
+        let files: BTreeMap<String, Option<String>> = serde_json::from_str(&json)
+            .unwrap_or_else(|err| panic!("failed to parse {project_file:?}: {err:?}"));
+        for (file_name, source) in files {
+            let Some(source) = source else {
+                complete = false;
+                continue;
+            };
+            let path = input_file.with_file_name(file_name);
+            if path.extension() == Some(OsStr::new("graphql")) {
+                let parsed = parse_graphql(&source);
+                project.insert_graphql_schema(&path, GraphqlSchema::from_root(&parsed.tree()));
+                continue;
+            }
+            let source_type = JsFileSource::try_from(path.as_path())
+                .unwrap_or_else(|err| panic!("unsupported file {path:?}: {err:?}"));
+            let parsed = parse(&source, source_type, options.clone());
+            project.insert(&path, JsModuleSummary::from_root(&parsed.tree()));
+        }
+    }
+    let tsconfig_file = input_file.with_extension("tsconfig.json");
+    if let Ok(json) = read_to_string(&tsconfig_file) {
+        let tsconfig = deserialize_from_json_str::<TsConfigJson>(
+            &json,
+            JsonParserOptions::default().with_allow_comments(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap_or_else(|| panic!("failed to deserialize {tsconfig_file:?}"));
+        project.set_tsconfig(&input_file.with_file_name("tsconfig.json"), &tsconfig);
+    }
+    project.set_complete(complete);
+    Arc::new(project)
+}
+
 fn check_code_action(
     path: &Path,
     source: &str,
@@ -0,0 +1,7 @@
+class Store {
+	private cache = new Map();
+
+	#unused = 0;
+
+	constructor(private readonly client: Client) {}
+}
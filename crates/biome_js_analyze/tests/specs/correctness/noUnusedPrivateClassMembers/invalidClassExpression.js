@@ -0,0 +1,9 @@
+const Counter = class {
+	#unused = 0;
+
+	#unusedMethod() {}
+};
+
+export default class {
+	#unused = 0;
+}
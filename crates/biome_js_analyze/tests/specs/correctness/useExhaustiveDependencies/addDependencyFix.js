@@ -0,0 +1,17 @@
+import { useEffect } from "react";
+
+function MyComponent(props) {
+    const a = 1;
+    const b = 2;
+    useEffect(() => {
+        console.log(a, b);
+    }, [a]);
+
+    useEffect(() => {
+        console.log(a, b);
+    }, [a,]);
+
+    useEffect(() => {
+        console.log(props.value);
+    }, []);
+}
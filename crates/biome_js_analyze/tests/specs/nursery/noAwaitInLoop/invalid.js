@@ -0,0 +1,56 @@
+async function forOf(items) {
+	for (const item of items) {
+		await save(item);
+	}
+}
+
+async function forIn(items) {
+	for (const key in items) {
+		await save(items[key]);
+	}
+}
+
+async function forStatement(items) {
+	for (let i = 0; i < items.length; i++) {
+		await save(items[i]);
+	}
+}
+
+async function forTestAndUpdate() {
+	for (let i = 0; await isPending(i); i = await next(i)) {}
+}
+
+async function whileStatement() {
+	while (await isPending()) {
+		wait();
+	}
+}
+
+async function doWhile() {
+	do {
+		await wait();
+	} while (await isPending());
+}
+
+async function nestedForAwait(streams) {
+	for (const stream of streams) {
+		for await (const chunk of stream) {
+			print(chunk);
+		}
+	}
+}
+
+async function nestedBlock(items) {
+	for (const item of items) {
+		if (item) {
+			const value = (await load(item)) + 1;
+		}
+	}
+}
+
+async function unmarked(items) {
+	// loads the items
+	for (const item of items) {
+		await load(item);
+	}
+}
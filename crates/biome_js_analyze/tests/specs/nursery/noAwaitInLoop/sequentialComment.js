@@ -0,0 +1,28 @@
+async function marked(migrations) {
+	// sequential: a migration depends on the previous ones
+	for (const migration of migrations) {
+		await migration.run();
+	}
+}
+
+async function labeled(pages) {
+	/* sequential */
+	outer: while (pages.length > 0) {
+		await pages.shift().load();
+	}
+}
+
+async function unmarked(items) {
+	for (const item of items) {
+		await save(item);
+	}
+}
+
+async function nested(groups) {
+	for (const group of groups) {
+		// sequential
+		for (const item of group) {
+			await save(item);
+		}
+	}
+}
@@ -0,0 +1,36 @@
+async function forAwait(stream) {
+	for await (const chunk of stream) {
+		await print(chunk);
+	}
+}
+
+async function iterable() {
+	for (const item of await load()) {
+		print(item);
+	}
+	for (const key in await load()) {
+		print(key);
+	}
+}
+
+async function initializer() {
+	for (let i = await start(); i < 10; i++) {
+		print(i);
+	}
+}
+
+async function concurrent(items) {
+	await Promise.all(items.map(async (item) => await save(item)));
+}
+
+async function nestedFunction(items) {
+	for (const item of items) {
+		callbacks.push(async () => {
+			await save(item);
+		});
+	}
+}
+
+async function outside() {
+	await save();
+}
@@ -0,0 +1,9 @@
+import { a } from "./a.js";
+import { b } from "./b";
+import { a as again } from "./a.js";
+export * from "./sub";
+const self = require("./invalid.js");
+import { unrelated } from "./unrelated.js";
+import { useState } from "react";
+
+export const value = a + b + again + self + unrelated + useState;
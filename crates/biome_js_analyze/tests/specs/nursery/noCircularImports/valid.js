@@ -0,0 +1,5 @@
+import { a } from "./a.js";
+import { b } from "./b.js";
+import { readFile } from "node:fs";
+
+export const value = a + b + readFile;
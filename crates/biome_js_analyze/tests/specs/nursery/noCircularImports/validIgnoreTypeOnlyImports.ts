@@ -0,0 +1,5 @@
+import type { User } from "./user";
+
+export interface Session {
+	user: User;
+}
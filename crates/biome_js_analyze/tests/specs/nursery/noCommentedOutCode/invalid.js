@@ -0,0 +1,33 @@
+// console.log("debug");
+const value = compute();
+
+// const first = values[0];
+// const last = values.at(-1);
+// return first + last;
+
+function sum(values) {
+	// return 0;
+	let total = 0; // total = values.length;
+	for (const value of values) {
+		total += value;
+		/* total *= 2; */
+	}
+	/*
+	if (total > 100) {
+		throw new Error("Too large");
+	}
+	*/
+	return total;
+}
+
+/*
+ * export function legacy() {
+ *     return sum([]);
+ * }
+ */
+
+// import { helper } from "./helper.js";
+
+// value++;
+
+// label: doSomething();
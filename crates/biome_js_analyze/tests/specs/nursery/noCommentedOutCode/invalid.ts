@@ -0,0 +1,3 @@
+// const typed: Map<string, number> = new Map();
+
+// type Alias = { value: string };
@@ -0,0 +1,2 @@
+// const first = values[0];
+// const last = values.at(-1);
@@ -0,0 +1,28 @@
+// Computes the sum of the values.
+// TODO
+// FIXME: handle the empty arrays
+// eslint-disable-next-line no-console
+// @ts-expect-error: the types are wrong
+// See https://example.com/docs for the details.
+// NOTE - this is prose
+// e.g. the total of the values
+// "use strict"
+// 42
+// value
+
+/**
+ * Returns the sum of the values.
+ *
+ * @example
+ * sum([1, 2, 3]);
+ */
+function sum(values) {
+	return values.reduce((total, value) => total + value, 0); // Sum of the values
+}
+
+/* istanbul ignore next */
+const value = /*#__PURE__*/ compute();
+
+//
+
+/// <reference path="./types.d.ts" />
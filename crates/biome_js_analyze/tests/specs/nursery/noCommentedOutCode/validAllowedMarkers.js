@@ -0,0 +1,6 @@
+// example:
+// const total = sum([1, 2, 3]);
+
+/* example: sum([1, 2, 3]); */
+
+// Example: sum([1, 2, 3]);
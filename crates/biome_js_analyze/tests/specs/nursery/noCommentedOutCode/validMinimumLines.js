@@ -0,0 +1,3 @@
+// console.log("debug");
+const value = compute();
+/* value++; */
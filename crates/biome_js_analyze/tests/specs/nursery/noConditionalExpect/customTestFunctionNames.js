@@ -0,0 +1,11 @@
+scenario("logs in", () => {
+	if (isReady) {
+		expect(value).toBe(1);
+	}
+});
+
+it("isn't a test function anymore", () => {
+	if (isReady) {
+		expect(value).toBe(1);
+	}
+});
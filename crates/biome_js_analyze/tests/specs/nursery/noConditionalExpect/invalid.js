@@ -0,0 +1,71 @@
+it("checks the platform", () => {
+	if (process.platform === "win32") {
+		expect(value).toBe(1);
+	}
+});
+
+it("checks the else branch", () => {
+	if (isReady) {
+		run();
+	} else {
+		expect(value).toBe(2);
+	}
+});
+
+test("catches the error", async () => {
+	try {
+		await run();
+	} catch (error) {
+		expect(error).toBeDefined();
+	}
+});
+
+test("tries to run", async () => {
+	try {
+		expect(await run()).toBe(1);
+	} finally {
+		cleanUp();
+	}
+});
+
+it("loops over the cases", () => {
+	for (const item of items) {
+		expect(item).toBeTruthy();
+	}
+});
+
+it("waits", () => {
+	while (pending()) {
+		expect(next()).toBeDefined();
+	}
+});
+
+it("switches", () => {
+	switch (kind) {
+		case "a":
+			expect(value).toBe("a");
+			break;
+	}
+});
+
+it("uses a logical expression", () => {
+	isReady && expect(value).toBe(1);
+});
+
+it("uses a conditional expression", () => {
+	isReady ? expect(value).toBe(1) : run();
+});
+
+it.only("is focused", () => {
+	items.forEach((item) => {
+		if (item) {
+			expect(item).toBeTruthy();
+		}
+	});
+});
+
+test.each([1, 2])("runs %i", (n) => {
+	if (n > 1) {
+		expect(n).toBeGreaterThan(1);
+	}
+});
@@ -0,0 +1,50 @@
+it("checks the value", () => {
+	expect(value).toBe(1);
+});
+
+test("rejects", async () => {
+	await expect(run()).rejects.toThrow();
+});
+
+test("cleans up", async () => {
+	try {
+		await run();
+	} finally {
+		expect(cleanUp()).toBe(true);
+	}
+});
+
+it("checks the condition", () => {
+	expect(isReady ? 1 : 2).toBe(1);
+	expect(isReady && value).toBe(1);
+});
+
+it.each(items)("checks %s", (item) => {
+	expect(item).toBeTruthy();
+});
+
+// Not in a test
+if (isReady) {
+	expect(value).toBe(1);
+}
+
+describe("suite", () => {
+	if (isReady) {
+		it("checks the value", () => {
+			expect(value).toBe(1);
+		});
+	}
+});
+
+function helper(value) {
+	if (value) {
+		expect(value).toBe(1);
+	}
+}
+
+// Unknown test function name
+scenario("logs in", () => {
+	if (isReady) {
+		expect(value).toBe(1);
+	}
+});
@@ -0,0 +1,5 @@
+import { formatDate } from "../../../shared/format.js";
+import "../../../../styles/global.css";
+export { Button } from "./../../../components/button.js";
+const { render } = require("../../../test/utils.js");
+const module = await import('../../../lazy/module.js');
@@ -0,0 +1,2 @@
+import { Form } from "./form.js";
+import { Layout } from "../layout.js";
@@ -0,0 +1,2 @@
+import { render } from "../../../test/utils.js";
+import { fixtures } from "../../../../fixtures/index.js";
@@ -0,0 +1,9 @@
+import { formatDate } from "@/shared/format.js";
+import { Form } from "./form.js";
+import { Layout } from "../layout.js";
+import { Page } from "../../page.js";
+import { Header } from "./../../header.js";
+import { Footer } from "../../components/../../footer.js";
+import React from "react";
+const { render } = require("../../test/utils.js");
+const module = await import(`../../../${name}.js`);
@@ -0,0 +1,32 @@
+/** @deprecated Use `fetchUser` instead. */
+function getUser(id) {}
+getUser(1);
+
+/**
+ * The maximum size.
+ *
+ * @deprecated There's no maximum anymore,
+ *   the size is unlimited.
+ * @see SIZE
+ */
+export const MAX_SIZE = 10;
+console.log(MAX_SIZE);
+
+/** @deprecated */
+export default class LegacyStore {
+	/** @deprecated Use `clear()` instead. */
+	static reset() {}
+
+	/** @deprecated */
+	#flush() {}
+
+	clear() {
+		this.#flush();
+	}
+}
+new LegacyStore();
+LegacyStore.reset();
+
+const element = <Legacy />;
+/** @deprecated Use `<Modern />` instead. */
+function Legacy() {}
@@ -0,0 +1,22 @@
+/** @deprecated Use `User` instead. */
+interface Person {}
+let person: Person;
+
+/** @deprecated */
+type Id = string;
+let id: Id;
+
+enum Color {
+	Red,
+	/** @deprecated Use `Red` instead. */
+	Crimson,
+}
+Color.Crimson;
+
+/** @deprecated Use `format` instead. */
+declare function legacyFormat(value: string): string;
+legacyFormat("");
+
+/** @deprecated Use `parse` instead. */
+export declare function legacyParse(value: string): number;
+legacyParse("");
@@ -0,0 +1,46 @@
+/** Fetches a user. */
+function fetchUser(id) {}
+fetchUser(1);
+
+/** @deprecatedSince 2.0 */
+function getUser(id) {}
+getUser(1);
+
+// @deprecated
+function legacyFormat() {}
+legacyFormat();
+
+/* @deprecated */
+function legacyParse() {}
+legacyParse();
+
+/** @deprecated */
+function recursive(n) {
+	return n > 0 ? recursive(n - 1) : 0;
+}
+export { recursive };
+
+import { imported } from "./mod.js";
+imported();
+
+/** @deprecated */
+class Store {
+	/** @deprecated */
+	clear() {}
+}
+function callClear() {
+	this.clear();
+}
+const store = {
+	clear() {},
+	reset() {
+		this.clear();
+	},
+};
+
+function shadowing() {
+	/** The parameter isn't deprecated. */
+	function inner(Store) {
+		return Store;
+	}
+}
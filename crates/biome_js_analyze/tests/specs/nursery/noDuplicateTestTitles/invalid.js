@@ -0,0 +1,19 @@
+describe("parser", () => {
+	it("parses the numbers", () => {});
+	it("parses the numbers", () => {});
+
+	test.only('parses the numbers', () => {});
+
+	it(`parses the strings`, () => {});
+	it.skip("parses the strings", () => {});
+
+	describe("errors", () => {
+		it("reports the unexpected tokens", () => {});
+		it("reports the unexpected tokens", () => {});
+	});
+
+	it("parses the numbers", () => {});
+});
+
+test("logs in", () => {});
+test("logs in", () => {});
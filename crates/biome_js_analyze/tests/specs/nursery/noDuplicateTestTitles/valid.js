@@ -0,0 +1,33 @@
+describe("parser", () => {
+	it("parses the numbers", () => {});
+	it("parses the strings", () => {});
+
+	describe("errors", () => {
+		it("parses the numbers", () => {});
+	});
+});
+
+describe("printer", () => {
+	it("parses the numbers", () => {});
+});
+
+it("parses the numbers", () => {});
+
+describe.each([1, 2])("parser %i", () => {
+	it("parses the value", () => {});
+});
+
+describe.each([3, 4])("printer %i", () => {
+	it("parses the value", () => {});
+});
+
+it.each([1, 2])("handles %i", () => {});
+it.each([3, 4])("handles %i", () => {});
+
+test(`renders ${kind}`, () => {});
+test(`renders ${kind}`, () => {});
+
+test("checkout", async () => {
+	await test.step("fills the form", async () => {});
+	await test.step("fills the form", async () => {});
+});
@@ -0,0 +1,45 @@
+async function save() {}
+const load = async () => {};
+const fetchData = async function () {};
+
+save();
+load();
+(fetchData());
+save().then(() => {});
+save().finally(() => {});
+save().then(() => {}).finally(() => {});
+Promise.resolve(1);
+Promise.all([save(), load()]);
+globalThis.Promise.reject(new Error());
+new Promise((resolve) => resolve());
+(async () => {})();
+
+async function run() {
+	// leading comment
+	save();
+	Promise.race([save(), load()]);
+}
+
+function sync() {
+	save();
+}
+
+class Store {
+	async persist() {
+		load();
+	}
+
+	constructor() {
+		load();
+	}
+}
+
+async function collect() {
+	async function* numbers() {
+		yield 1;
+	}
+	for await (const number of numbers()) {
+	}
+}
+
+collect();
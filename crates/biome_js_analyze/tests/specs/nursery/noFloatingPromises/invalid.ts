@@ -0,0 +1,9 @@
+declare function send(): Promise<void>;
+function read(): Promise<string> {
+	return Promise.resolve("");
+}
+const write: (value: string) => Promise<void> = (value) => Promise.resolve();
+
+send();
+read().then((value) => value);
+write("");
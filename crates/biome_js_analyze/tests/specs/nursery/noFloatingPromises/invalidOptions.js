@@ -0,0 +1,3 @@
+fetch("/ping");
+api.get("/items").then((items) => items);
+this.api.get("/items");
@@ -0,0 +1,30 @@
+/* should not generate diagnostics */
+async function save() {}
+function compute() {}
+
+async function run() {
+	await save();
+	void save();
+	save().catch(() => {});
+	save().then(() => {}, () => {});
+	save().then(() => {}).catch(() => {});
+	const promise = save();
+	compute();
+	return save();
+}
+
+const Promise = { all() {} };
+Promise.all([]);
+
+function load() {}
+load();
+fetch("/ping");
+
+async function* numbers() {
+	yield 1;
+}
+numbers();
+const letters = async function* () {
+	yield "a";
+};
+letters();
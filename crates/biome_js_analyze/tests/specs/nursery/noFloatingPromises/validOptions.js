@@ -0,0 +1,3 @@
+/* should not generate diagnostics */
+fetch("/ping").catch(() => {});
+api.post("/items");
@@ -0,0 +1,12 @@
+<h1>Welcome back!</h1>;
+<p>
+	You have {count} new messages.
+</p>;
+<img src={logo} alt="Company logo" />;
+<input placeholder="Search" aria-label={"Search the site"} />;
+<button title={isOpen ? "Close" : "Open"}>{isOpen ? "Close" : "Open"}</button>;
+<span>{name || "Anonymous"}</span>;
+<span>{`Hello ${name}`}</span>;
+<span>{"Total: " + total}</span>;
+<span>{capitalize("hello")}</span>;
+<Button label={`${count} items`} />;
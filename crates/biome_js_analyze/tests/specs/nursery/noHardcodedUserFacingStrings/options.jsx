@@ -0,0 +1,3 @@
+<Button tooltip="Save the document" title={i18n.t("save")}>{i18n.t("save")}</Button>;
+<input placeholder="Search" />;
+<span>{t("hello")}</span>;
@@ -0,0 +1,12 @@
+<h1>{t("welcome")}</h1>;
+<input placeholder={t("search")} className="search-input" />;
+<span>{count}&nbsp;/&nbsp;{total}</span>;
+<span>{count} - {total}</span>;
+<span>{price}€</span>;
+<div className="card" data-testid="card-title" />;
+<span>{isOpen ? t("close") : t("open")}</span>;
+<span>{capitalize(t("hello"))}</span>;
+<span>{`${count}`}</span>;
+<span>{format`Hello ${name}`}</span>;
+<img src={logo} alt="" />;
+<span>{" "}</span>;
@@ -0,0 +1,43 @@
+import { useEffect, useLayoutEffect } from "react";
+import * as React from "react";
+
+function WithoutCleanup() {
+	useEffect(() => {
+		window.addEventListener("resize", onResize);
+	}, []);
+}
+
+function WithIncompleteCleanup({ element }) {
+	useLayoutEffect(() => {
+		element.addEventListener("click", onClick);
+		element.addEventListener("keydown", onKeyDown);
+		return () => {
+			element.removeEventListener("click", onClick);
+		};
+	}, [element]);
+}
+
+function WithDifferentListener() {
+	React.useEffect(() => {
+		document.addEventListener("keydown", onKeyDown);
+		return () => document.removeEventListener("keydown", onKeyUp);
+	}, []);
+}
+
+function WithInlineListener() {
+	useEffect(() => {
+		document.addEventListener("scroll", () => setScrolled(true));
+		return () => document.removeEventListener("scroll", () => setScrolled(true));
+	}, []);
+}
+
+function WithExpressionBody() {
+	useEffect(() => window.addEventListener("focus", onFocus), []);
+}
+
+function subscribe(element, callback) {
+	element.addEventListener("change", callback);
+	return function unsubscribe() {
+		element.removeEventListener("input", callback);
+	};
+}
@@ -0,0 +1,49 @@
+import { useEffect } from "react";
+
+function WithCleanup() {
+	useEffect(() => {
+		window.addEventListener("resize", onResize);
+		return () => {
+			window.removeEventListener("resize", onResize);
+		};
+	}, []);
+}
+
+function WithNamedCleanup({ element }) {
+	useEffect(() => {
+		const onClick = () => setOpen(false);
+		element.addEventListener('click', onClick, { capture: true });
+		function cleanup() {
+			element.removeEventListener("click", onClick, { capture: true });
+		}
+		return cleanup;
+	}, [element]);
+}
+
+function WithSignal() {
+	useEffect(() => {
+		const controller = new AbortController();
+		window.addEventListener("resize", () => setWidth(window.innerWidth), {
+			signal: controller.signal,
+		});
+		return () => controller.abort();
+	}, []);
+}
+
+function subscribe(element, callback) {
+	element.addEventListener("change", callback);
+	return () => element.removeEventListener("change", callback);
+}
+
+// Not an effect, and doesn't return a cleanup function
+function setup(button) {
+	button.addEventListener("click", onClick);
+}
+
+// Not the React hook
+function WithLocalEffect() {
+	const useEffect = (callback) => callback();
+	useEffect(() => {
+		window.addEventListener("resize", onResize);
+	});
+}
@@ -0,0 +1,4 @@
+const RETRIES = 3;
+let attempts = 3;
+var limit = -5;
+for (let i = 0; i < items.length; i++) {}
@@ -0,0 +1,5 @@
+const last = items[items.length - 1];
+const next = index + 1;
+const previous = index - 1;
+const half = size * 0.5;
+const double = size * 2;
@@ -0,0 +1,6 @@
+items[0];
+items[42];
+items[(1)];
+items[-1];
+items[1.5];
+items[4294967295];
@@ -0,0 +1,7 @@
+function paginate(items, pageSize = 20) {}
+const { retries = 3 } = options;
+const [first = 0] = values;
+({ delay = 100 } = options);
+class Timer {
+	interval = 1000;
+}
@@ -0,0 +1,15 @@
+const total = price * 1.2;
+setTimeout(refresh, 60000);
+const negative = offset - -1;
+const parenthesized = (2) * width;
+items[3];
+function paginate(items, pageSize = 20) {}
+const { retries = 3 } = options;
+const [first = 0] = values;
+if (count > 10) {
+}
+const array = [1, 2, 3];
+parseInt(input, 10, 5);
+Number.parseFloat(input, 10);
+const huge = size * 1024n;
+const negativeBig = offset - -5n;
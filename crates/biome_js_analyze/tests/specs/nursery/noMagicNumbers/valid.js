@@ -0,0 +1,12 @@
+const VAT_RATE = 1.2;
+let attempts = 3;
+var limit = -5;
+const config = { timeout: 5000 };
+value = 42;
+timeout.duration = (5000);
+const decimal = parseInt(input, 10);
+const hex = Number.parseInt(input, 16);
+class Timer {
+	interval = 1000;
+}
+const MAX_SIZE = 1024n;
@@ -0,0 +1,5 @@
+enum Direction {
+	Up = 1,
+	Down = -1,
+}
+type Status = 200 | 404;
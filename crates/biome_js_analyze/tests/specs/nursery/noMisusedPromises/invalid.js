@@ -0,0 +1,25 @@
+async function save() {}
+const isVisible = async () => true;
+
+items.forEach(async (item) => {
+	await save(item);
+});
+items.forEach(save);
+setTimeout(async () => {
+	await save();
+}, 100);
+window.setInterval(save, 1000);
+queueMicrotask(async function () {});
+element.addEventListener("click", async () => {});
+
+items.filter(async (item) => await isVisible(item));
+items.some(isVisible);
+items.find(async function (item) {});
+
+if (save()) {}
+while (isVisible()) {}
+do {} while (Promise.resolve(true));
+for (; save(); ) {}
+const value = save() ? 1 : 2;
+if (!isVisible()) {}
+if (ready && (save())) {}
@@ -0,0 +1,4 @@
+async function save() {}
+
+<button onClick={async () => await save()}>Save</button>;
+<form onSubmit={save} />;
@@ -0,0 +1,23 @@
+/* should not generate diagnostics */
+async function save() {}
+function check() {}
+
+for (const item of items) {
+	await save(item);
+}
+await Promise.all(items.map(async (item) => await save(item)));
+items.forEach((item) => {
+	save(item).catch(() => {});
+});
+items.filter((item) => check(item));
+setTimeout(() => {}, 100);
+element.addEventListener("click", () => {});
+// A local function named like a method
+function forEach() {}
+forEach(async () => {});
+
+if (await save()) {}
+if (check()) {}
+const value = (await save()) ? 1 : 2;
+if (!(await save())) {}
+if (save) {}
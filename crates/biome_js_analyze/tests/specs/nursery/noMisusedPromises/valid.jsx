@@ -0,0 +1,6 @@
+/* should not generate diagnostics */
+async function save() {}
+
+<button onClick={() => void save()}>Save</button>;
+<Loader load={async () => await save()} />;
+<Menu once={save} />;
@@ -0,0 +1,30 @@
+import { registry, config, counter } from "./registry.js";
+import * as namespace from "./namespace.js";
+import { Map } from "./map.js";
+
+registry.register("button");
+export const client = createClient();
+export const instance = new Client();
+const tagged = html`<div></div>`;
+const lazy = import("./lazy.js");
+config.debug = true;
+config["mode"] = "production";
+namespace.value.nested = 1;
+counter++;
+--namespace.count;
+delete config.debug;
+window.onload = init;
+globalThis.value ??= {};
+undeclared = 1;
+export default setup();
+class Service {
+	static instance = createService();
+}
+if (condition) {
+	initialize();
+}
+for (const plugin of plugins) {
+	plugin.install();
+}
+const shadowed = new Map();
+console.log("loaded");
@@ -0,0 +1,46 @@
+import "./polyfill.js";
+import { registry } from "./registry.js";
+
+export const client = /*#__PURE__*/ createClient();
+export const annotated = /* @__PURE__ */ createClient();
+export const instance = /*#__PURE__*/ new Client();
+export const KEY = Symbol("key");
+export const SHARED = Symbol.for("shared");
+export const DEFAULTS = Object.freeze({ debug: false });
+export const NAMES = Object.keys({ a: 1, b: 2 });
+export const CACHE = new Map();
+export const SEEN = new WeakSet();
+export const LIMIT = Math.max(1, 2);
+export const PARSED = JSON.parse("{}");
+export const LIST = Array.from("abc");
+const fs = require("node:fs");
+
+export function register() {
+	registry.register("button");
+	window.onload = init;
+}
+export const arrow = () => createClient();
+export default function setup() {
+	registry.value = 1;
+}
+class Service {
+	instance = createService();
+	static create() {
+		return new Service();
+	}
+	get value() {
+		return compute();
+	}
+}
+const object = {
+	method() {
+		registry.value = 1;
+	},
+};
+
+let local = 0;
+local++;
+local = 1;
+const state = {};
+state.value = 1;
+delete state.value;
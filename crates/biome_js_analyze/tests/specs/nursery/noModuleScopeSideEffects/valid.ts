@@ -0,0 +1,9 @@
+declare const config: { debug: boolean };
+declare function setup(): void;
+declare module "library" {
+	export function run(): void;
+}
+interface Options {
+	value: string;
+}
+type Callback = () => void;
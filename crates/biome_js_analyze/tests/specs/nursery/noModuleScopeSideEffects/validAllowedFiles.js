@@ -0,0 +1,4 @@
+import { registry } from "./registry.js";
+
+registry.register("button");
+window.onload = init;
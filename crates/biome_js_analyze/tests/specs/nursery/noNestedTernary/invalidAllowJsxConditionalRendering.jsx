@@ -0,0 +1,2 @@
+const mixed = <div>{isLoading ? <Spinner /> : hasError ? "Error" : <Content />}</div>;
+const value = foo ? bar : baz ? qux : quxx;
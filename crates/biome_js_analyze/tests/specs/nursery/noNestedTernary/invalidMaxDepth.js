@@ -0,0 +1 @@
+const thing = foo ? bar : baz ? qux : quxx ? quxxx : foobar;
@@ -0,0 +1,5 @@
+const parenthesized = foo ? (bar ? baz : qux) : quxx;
+
+const deep = a ? b : c ? d : e ? f : g;
+
+const jsx = <div>{isLoading ? <Spinner /> : hasError ? <Error /> : <Content />}</div>;
@@ -9,4 +9,6 @@ if (foo) {
   thing = quxx;
 } else {
   thing = foobar;
-}
\ No newline at end of file
+}
+
+const nestedInTest = (foo ? bar : baz) ? qux : quxx;
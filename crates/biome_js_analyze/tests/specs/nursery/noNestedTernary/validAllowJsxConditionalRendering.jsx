@@ -0,0 +1,3 @@
+const jsx = <div>{isLoading ? <Spinner /> : hasError ? <Error /> : <Content />}</div>;
+const withNull = <div>{isLoading ? <Spinner /> : hasError ? null : <><Content /></>}</div>;
+const deep = isLoading ? <Spinner /> : hasError ? <Error /> : isEmpty ? null : <Content />;
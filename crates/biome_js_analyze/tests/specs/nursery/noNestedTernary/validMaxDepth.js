@@ -0,0 +1,2 @@
+const thing = foo ? bar : baz === qux ? quxx : foobar;
+const other = foo ? (bar ? baz : qux) : quxx;
@@ -0,0 +1,5 @@
+/* should not generate diagnostics */
+export const config = {
+	port: process.env.PORT,
+	env: process.env,
+};
@@ -0,0 +1,5 @@
+const mode = process.env.NODE_ENV;
+const debug = process.env["DEBUG"];
+const port = process.env.PORT;
+const env = process.env;
+const { NODE_ENV } = process.env;
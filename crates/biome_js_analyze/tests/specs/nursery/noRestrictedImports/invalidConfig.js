@@ -0,0 +1,2 @@
+import { Foo } from "import-foo";
+import { map } from "lodash/map";
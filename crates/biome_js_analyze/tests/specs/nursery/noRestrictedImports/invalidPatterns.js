@@ -0,0 +1,10 @@
+import map from "lodash/map";
+import throttle from "lodash/throttle";
+const filter = require("lodash/filter");
+import { helper } from "@app/users/internal";
+import * as internal from "@app/users/internal";
+import { render } from "./components/legacy/widget";
+import { render as legacyRender } from "../legacy/widget";
+export { render } from "./legacy/widget";
+import fs from "node:fs";
+const path = await import("node:path");
@@ -0,0 +1,7 @@
+import debounce from "lodash/debounce";
+import lodash from "lodash";
+import users from "@app/users/internal";
+import internal from "@app/users/internal/helpers";
+import { mount } from "./components/legacy/widget";
+import { render } from "./components/modern/widget";
+import fs from "fs";
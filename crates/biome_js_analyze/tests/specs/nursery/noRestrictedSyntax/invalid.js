@@ -0,0 +1,6 @@
+setTimeout(refresh, 1000);
+function pause() {
+	debugger;
+}
+element.innerHTML = html;
+console.log("debug");
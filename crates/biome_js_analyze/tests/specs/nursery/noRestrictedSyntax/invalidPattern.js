@@ -0,0 +1 @@
+setTimeout(refresh, 1000);
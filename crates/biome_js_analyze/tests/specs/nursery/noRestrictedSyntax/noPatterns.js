@@ -0,0 +1,4 @@
+setTimeout(refresh, 1000);
+with (object) {
+	value;
+}
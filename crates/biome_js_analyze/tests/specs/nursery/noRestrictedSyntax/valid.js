@@ -0,0 +1,6 @@
+scheduler.schedule(refresh, 1000);
+setTimeout(refresh);
+window.setTimeout(refresh, 1000);
+element.textContent = text;
+const debuggerEnabled = false;
+console.info("info");
@@ -0,0 +1,3 @@
+const key = "acme_live_0123456789abcdef0123456789abcdef";
+const message = `Use acme_test_0123456789abcdef0123456789abcdef in development`;
+const short = "acme_live_0123";
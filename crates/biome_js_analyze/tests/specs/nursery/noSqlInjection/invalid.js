@@ -0,0 +1,25 @@
+db.query(`SELECT * FROM users WHERE id = ${request.params.id}`);
+
+this.db.query(`SELECT * FROM users WHERE name = '${name}'`);
+
+await knex.raw(`SELECT * FROM posts WHERE title LIKE '%${search}%'`);
+
+const query = `DELETE FROM posts WHERE author = '${author}'`;
+pool.query(query);
+
+let table = "users";
+connection.execute(`SELECT * FROM ${table}`);
+
+const column = request.query.sort;
+client.query(`SELECT * FROM users ORDER BY ${column}`);
+
+db.query(`SELECT * FROM users WHERE name = ${"'" + name + "'"}`);
+
+db.query(`SELECT * FROM users LIMIT ${limit ?? 10}`);
+
+db.query(`SELECT * FROM users WHERE id = ${String(id)}`);
+
+function find(id) {
+	const Number = (value) => value;
+	return db.query(`SELECT * FROM users WHERE id = ${Number(id)}`);
+}
@@ -0,0 +1,6 @@
+repository.runQuery(`SELECT * FROM users WHERE name = '${name}'`);
+this.repository.runQuery(`SELECT * FROM users WHERE id = ${id}`);
+
+// The default sinks are replaced
+db.query(`SELECT * FROM users WHERE id = ${id}`);
+runQuery(`SELECT * FROM users WHERE id = ${id}`);
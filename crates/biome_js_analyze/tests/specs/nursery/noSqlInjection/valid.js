@@ -0,0 +1,24 @@
+db.query("SELECT * FROM users WHERE id = $1", [request.params.id]);
+
+db.query(`SELECT * FROM users`);
+
+const table = "users";
+const order = asc ? "ASC" : "DESC";
+db.query(`SELECT * FROM ${table} ORDER BY name ${order}`);
+
+db.query(`SELECT * FROM users LIMIT ${Number(limit)} OFFSET ${parseInt(page, 10) * 20}`);
+
+db.query(`SELECT * FROM users WHERE active = ${-1} AND age > ${age > 18}`);
+
+db.query(`SELECT * FROM users WHERE id IN (${typeof id})`);
+
+const query = `SELECT * FROM ${table} LIMIT ${10 + 5}`;
+knex.raw(query);
+
+// Tagged templates build parameterized queries
+db.query(sql`SELECT * FROM users WHERE id = ${id}`);
+prisma.$queryRaw`SELECT * FROM users WHERE id = ${id}`;
+
+// Not a query function
+logger.info(`Looking for the user ${id}`);
+db.find(`users/${id}`);
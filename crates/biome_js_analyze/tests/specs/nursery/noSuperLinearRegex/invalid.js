@@ -0,0 +1,18 @@
+/(a+)+$/;
+/^(\d+)*$/;
+/^([a-z0-9]+\.?)+@example\.com$/;
+/^(\w+\s?)*$/;
+/(?:a|.)*b/;
+/^(\w|\d)+$/;
+/(ab|ab)*c/;
+/^(.*)\s*$/;
+/\d+\d*x/;
+/\s*.*$/;
+/[a-z]+\d*[a-z]+!/;
+/(?<word>\w+)+!/;
+/(a+)+$/u;
+/(A+a+)+$/i;
+new RegExp("(a+)+$");
+new RegExp("^(\\w|\\d)+$");
+RegExp("^(\\d+)*$", "u");
+new RegExp(`^(.*)\\s*$`);
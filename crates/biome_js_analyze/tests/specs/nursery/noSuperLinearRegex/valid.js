@@ -0,0 +1,16 @@
+/^[a-z0-9]+(\.[a-z0-9]+)*@example\.com$/;
+/^\w+(,\s*\w+)*$/;
+/(?:[^"\\]|\\.)*/;
+/^(a|b)+$/;
+/^(a+b)+$/;
+/^(\d+,)*\d+$/;
+/\s*\w+/;
+/^(.*)\.(.*)$/;
+/a{1,5}a{1,5}/;
+/(a{2})+$/;
+/(?=(a+)+)/;
+/(A+a+)+$/;
+new RegExp("^(a|b)+$");
+new RegExp(pattern);
+new RegExp("(a+)+$\\1" + suffix);
+new Foo("(a+)+$");
@@ -0,0 +1,4 @@
+import fs from "node:fs";
+async function clean() {
+	fs.rmSync("dist", { recursive: true });
+}
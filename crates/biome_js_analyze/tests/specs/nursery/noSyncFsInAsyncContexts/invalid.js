@@ -0,0 +1,33 @@
+import fs from "node:fs";
+import * as childProcess from "child_process";
+import { existsSync, readFileSync as read } from "fs";
+const { execSync } = require("node:child_process");
+const nodeFs = require("fs");
+
+async function loadConfig() {
+	return JSON.parse(fs.readFileSync("config.json", "utf8"));
+}
+
+const handler = async (request) => {
+	if (existsSync(request.path)) {
+		return read(request.path);
+	}
+};
+
+class Deployer {
+	async deploy() {
+		childProcess.execSync("npm publish");
+	}
+}
+
+app.get("/version", (request, response) => {
+	response.send(execSync("git rev-parse HEAD"));
+});
+
+http.createServer(function (request, response) {
+	nodeFs.writeFileSync("last-request.log", request.url);
+});
+
+async function build(files) {
+	files.forEach((file) => nodeFs.unlinkSync(file));
+}
@@ -0,0 +1,3 @@
+import { existsSync } from "node:fs";
+server.route("/health", () => existsSync("ready"));
+app.get("/health", () => existsSync("ready"));
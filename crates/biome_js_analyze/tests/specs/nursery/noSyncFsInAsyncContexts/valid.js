@@ -0,0 +1,24 @@
+import fs from "node:fs";
+import { readFile } from "node:fs/promises";
+import { execSync } from "./utils.js";
+
+const config = JSON.parse(fs.readFileSync("config.json", "utf8"));
+
+function loadConfig() {
+	return fs.readFileSync("config.json", "utf8");
+}
+
+async function load() {
+	return await readFile("config.json", "utf8");
+}
+
+async function run() {
+	execSync("build");
+	fs.readFile("config.json", () => {});
+}
+
+server.get("/", () => fs.existsSync("ready"));
+
+async function shadowed(fs) {
+	fs.readFileSync("config.json");
+}
@@ -0,0 +1,13 @@
+// TODO: handle the empty lists
+// todo handle the empty lists
+// FIXME
+/* TODO: round once */
+/*
+ * FIXME the total is rounded twice
+ * TODO: round once
+ */
+function sum(values) {
+	return values.reduce((total, value) => total + value, 0); // TODO check the types
+}
+// TODO: see PROJ123
+// TODO: see #
@@ -0,0 +1,4 @@
+// HACK: the API returns the dates as strings
+// XXX: check the types
+// TODO: handle the empty lists
+// HACK(#12): the API returns the dates as strings
@@ -0,0 +1,3 @@
+// TODO(#42): handle the empty lists
+// TODO(OTHER-42): handle the empty lists
+// TODO(PROJ-42): handle the empty lists
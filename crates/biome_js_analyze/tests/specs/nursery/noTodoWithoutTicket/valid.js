@@ -0,0 +1,12 @@
+// TODO(PROJ-123): handle the empty lists
+// FIXME the total is rounded twice, see #42
+/*
+ * TODO: round once
+ * https://example.com/issues PROJ-42.
+ */
+// TODOS are listed in the tracker
+// The TODO list is empty
+// Handle the empty lists
+function sum(values) {
+	return values.reduce((total, value) => total + value, 0); // TODO [ABC_1-7] check the types
+}
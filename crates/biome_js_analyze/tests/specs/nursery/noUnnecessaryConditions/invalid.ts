@@ -0,0 +1,38 @@
+function comparisons(name: string, count: number | bigint, flags: readonly boolean[]) {
+	if (name === null) {}
+	if (name == undefined) {}
+	if (null !== count) {}
+	if (flags != null) {}
+}
+
+function optionalChains(items: string[], callback: () => void, map: { [key: string]: number }) {
+	items?.length;
+	items?.[0];
+	callback?.();
+	map?.["key"];
+	(items)?.at(0);
+}
+
+function nullishCoalescing(value: "a" | "b" | `prefix-${string}`, fallback: string) {
+	return value ?? fallback;
+}
+
+function withDefault(value: string = "") {
+	return value ?? "default";
+}
+
+const separator = ",";
+separator ?? ";";
+
+let options = { verbose: true };
+options?.verbose;
+
+const instance = new Map();
+if (instance === undefined) {}
+
+let definite!: object;
+definite?.toString();
+
+function parenthesized(value: (string | number)) {
+	return value === null;
+}
@@ -0,0 +1,3 @@
+/* should not generate diagnostics */
+const separator = ",";
+separator ?? ";";
@@ -0,0 +1,37 @@
+/* should not generate diagnostics */
+function nullable(name: string | null, count?: number, value: string | undefined, other: any, unknown: unknown) {
+	if (name === null) {}
+	if (count === undefined) {}
+	value?.length;
+	other ?? 0;
+	unknown?.toString();
+}
+
+function references<T>(value: T, user: User, items: Array<string>) {
+	if (value === null) {}
+	user?.name;
+	items?.length;
+}
+
+function destructured({ name }: { name: string }) {
+	if (name === null) {}
+}
+
+function comparisons(name: string) {
+	if (name === "") {}
+	if (name.length === null) {}
+}
+
+let uninitialized;
+uninitialized?.toString();
+
+const nullValue = null;
+nullValue ?? 0;
+
+const fromCall = compute();
+fromCall?.toString();
+
+function shadowedUndefined(name: string) {
+	const undefined = 0;
+	if (name === undefined) {}
+}
@@ -0,0 +1,9 @@
+const a = value as any;
+const b = <any>value;
+const c = value as unknown as number;
+const d = (value as any) as string;
+const e = <string><unknown>value;
+const f = value!;
+const g = value!!.length;
+value!.prop = 1;
+const h = map.get(key)!.name;
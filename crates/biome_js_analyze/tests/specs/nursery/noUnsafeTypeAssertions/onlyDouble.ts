@@ -0,0 +1,2 @@
+const a = value as any as number;
+const b = value as any;
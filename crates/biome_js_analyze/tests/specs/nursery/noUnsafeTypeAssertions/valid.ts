@@ -0,0 +1,7 @@
+const a = value as Config;
+const b = <Config>value;
+const c = value as unknown;
+const d = value as const;
+const e = value as Config as Base;
+const f = value?.prop;
+const g = value satisfies Config;
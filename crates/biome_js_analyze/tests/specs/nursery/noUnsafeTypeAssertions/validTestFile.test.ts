@@ -0,0 +1,3 @@
+const a = value as any;
+const b = value as unknown as number;
+const c = value!;
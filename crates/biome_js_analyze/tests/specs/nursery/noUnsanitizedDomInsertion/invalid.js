@@ -0,0 +1,18 @@
+element.innerHTML = comment.body;
+
+element.outerHTML = `<div>${user.name}</div>`;
+
+element["innerHTML"] = html;
+
+element.innerHTML += "<li>" + item + "</li>";
+
+list.insertAdjacentHTML("beforeend", `<li>${item.name}</li>`);
+
+document.write(location.hash);
+
+window.document.writeln("<p>", message, "</p>");
+
+let template = "<p></p>";
+element.innerHTML = template;
+
+element.innerHTML = DOMPurify.sanitize(comment.body);
@@ -0,0 +1,8 @@
+element.innerHTML = DOMPurify.sanitize(comment.body);
+
+element.innerHTML = sanitizeHtml(comment.body) + "<hr>";
+
+const safe = sanitizeHtml(comment.body);
+list.insertAdjacentHTML("beforeend", `<li>${safe}</li>`);
+
+element.innerHTML = purify.sanitize(comment.body);
@@ -0,0 +1,25 @@
+element.innerHTML = "<p>Loading…</p>";
+
+element.innerHTML = "";
+
+element.outerHTML = `<div class="spinner"></div>`;
+
+const icon = "<svg></svg>";
+const label = `<span>${"Close"}</span>`;
+button.innerHTML = isOpen ? icon + label : icon;
+
+element.textContent = comment.body;
+
+element.innerText = comment.body;
+
+list.insertAdjacentHTML("beforeend", "<li></li>");
+
+list.insertAdjacentText("beforeend", item.name);
+
+document.write("<p>Hello</p>");
+
+// Not the global document
+function render(document) {
+	document.write(content);
+}
+stream.write(content);
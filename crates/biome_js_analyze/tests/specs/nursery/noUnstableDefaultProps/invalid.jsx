@@ -0,0 +1,39 @@
+import { useCallback, useEffect, useMemo, memo, forwardRef } from "react";
+import * as React from "react";
+
+function List({ items = [] }) {
+	const sorted = useMemo(() => items.toSorted(), [items]);
+	return <ul>{sorted}</ul>;
+}
+
+const Chart = ({ options = { animated: true } }) => {
+	useEffect(() => {
+		draw(options);
+	}, [options]);
+	return <canvas />;
+};
+
+function Button({ onClick = () => {}, label }) {
+	const handleClick = useCallback(() => onClick(label), [label, onClick]);
+	return <button onClick={handleClick}>{label}</button>;
+}
+
+function Renamed({ items: values = [] }) {
+	useEffect(() => {}, [(values)]);
+	return null;
+}
+
+const Memoized = memo(function ({ filters = {} }) {
+	useEffect(() => {}, [filters]);
+	return null;
+});
+
+const Forwarded = forwardRef(({ style = {} }, ref) => {
+	React.useLayoutEffect(() => {}, [style]);
+	return <div ref={ref} />;
+});
+
+export default function Page({ tags = [], items = [] }) {
+	const count = useMemo(() => tags.length + items.length, [tags, items]);
+	return count;
+}
@@ -0,0 +1,45 @@
+import { useEffect, useMemo, memo } from "react";
+
+const NO_ITEMS = [];
+
+function List({ items = NO_ITEMS }) {
+	const sorted = useMemo(() => items.toSorted(), [items]);
+	return <ul>{sorted}</ul>;
+}
+
+function Plain({ items = [] }) {
+	return <ul>{items}</ul>;
+}
+
+function Primitive({ count = 0, label = "" }) {
+	useEffect(() => {}, [count, label]);
+	return null;
+}
+
+function Length({ items = [] }) {
+	useEffect(() => {}, [items.length]);
+	return null;
+}
+
+function notAComponent({ items = [] }) {
+	useEffect(() => {}, [items]);
+}
+
+const useItems = ({ items = [] }) => {
+	useEffect(() => {}, [items]);
+};
+
+function Second(props, { items = [] }) {
+	useEffect(() => {}, [items]);
+	return null;
+}
+
+function NotAHook({ items = [] }) {
+	somethingElse(() => {}, [items]);
+	return null;
+}
+
+const Anonymous = wrap(function ({ items = [] }) {
+	useEffect(() => {}, [items]);
+	return null;
+});
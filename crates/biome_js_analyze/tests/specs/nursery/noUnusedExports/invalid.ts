@@ -0,0 +1,15 @@
+export function used() {}
+export function unused() {}
+export const a = 1, { b, c = (d) => d } = {}, [e] = [];
+export class UnusedClass {}
+export interface UnusedInterface {}
+export type UnusedType = string;
+export enum UnusedEnum {}
+export namespace Outer.Inner {}
+export default function () {}
+const local = 1;
+export { local as renamed, local };
+export { "string name" as "other name" } from "./other";
+export * as ns from "./other";
+export * from "./other";
+export type { T } from "./other";
@@ -0,0 +1,8 @@
+/* should not generate diagnostics */
+export function named() {}
+export const renamedOnImport = 1;
+export type TypeOnly = string;
+export default class {}
+const local = 1;
+export { local as reexported };
+export * as namespace from "./other";
@@ -0,0 +1,3 @@
+/* should not generate diagnostics */
+export function a() {}
+export function b() {}
@@ -0,0 +1,15 @@
+describe("Parser", () => {
+	it("Returns the default value", () => {});
+});
+
+it("returns the default value.", () => {});
+
+test(`Handles ${kind} files`, () => {});
+
+test(`handles ${kind} files.`, () => {});
+
+it.only("Runs only this test", () => {});
+
+describe.each([1, 2])("Value %i", (value) => {});
+
+test.skip(`Skips this test`, () => {});
@@ -0,0 +1,5 @@
+it("should return the default value", () => {});
+
+it("returns the default value", () => {});
+
+test(`should handle ${kind} files`, () => {});
@@ -0,0 +1,5 @@
+describe("Parser", () => {
+	it("returns the default value", () => {});
+});
+
+test(`Handles ${kind} files`, () => {});
@@ -0,0 +1,17 @@
+describe("parser", () => {
+	it("returns the default value", () => {});
+});
+
+test(`handles ${kind} files`, () => {});
+
+test(`${kind} files are handled`, () => {});
+
+it("404 page is shown", () => {});
+
+it("", () => {});
+
+it(title, () => {});
+
+expect("Value.").toBe("Value.");
+
+render("Home.");
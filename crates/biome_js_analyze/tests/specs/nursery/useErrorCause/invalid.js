@@ -0,0 +1,83 @@
+try {
+	doSomething();
+} catch (error) {
+	throw new Error("Something failed.");
+}
+
+try {
+	doSomething();
+} catch (error) {
+	throw new TypeError("Something failed.", { code: 1 });
+}
+
+try {
+	doSomething();
+} catch (error) {
+	throw new RangeError("Something failed.", {});
+}
+
+try {
+	doSomething();
+} catch (error) {
+	throw new AggregateError([error], "Something failed.");
+}
+
+try {
+	doSomething();
+} catch (cause) {
+	throw new Error("Something failed.");
+}
+
+try {
+	doSomething();
+} catch (error) {
+	throw new Error(
+		"Something failed.",
+	);
+}
+
+try {
+	doSomething();
+} catch (error) {
+	throw new Error("Something failed.", {
+		code: 1
+	});
+}
+
+try {
+	doSomething();
+} catch (error) {
+	throw (new Error(`Something failed: ${error.message}`));
+}
+
+try {
+	doSomething();
+} catch (error) {
+	if (retry) {
+		throw new Error("Something failed.");
+	}
+}
+
+// No fix: the cause isn't the caught error.
+try {
+	doSomething();
+} catch (error) {
+	throw new Error("Something failed.", { cause: otherError });
+}
+
+// No fix: the message is missing.
+try {
+	doSomething();
+} catch (error) {
+	throw new Error();
+}
+
+// No fix: the caught error is shadowed.
+try {
+	doSomething();
+} catch (error) {
+	{
+		const error = 1;
+		throw new Error("Something failed.");
+	}
+}
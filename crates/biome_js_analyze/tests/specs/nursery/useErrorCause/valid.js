@@ -0,0 +1,71 @@
+try {
+	doSomething();
+} catch (error) {
+	throw new Error("Something failed.", { cause: error });
+}
+
+try {
+	doSomething();
+} catch (error) {
+	throw error;
+}
+
+try {
+	doSomething();
+} catch (cause) {
+	throw new Error("Something failed.", { cause });
+}
+
+try {
+	doSomething();
+} catch (error) {
+	throw new Error("Something failed.", { cause: { error, code: 1 } });
+}
+
+try {
+	doSomething();
+} catch {
+	throw new Error("Something failed.");
+}
+
+try {
+	doSomething();
+} catch ({ message }) {
+	throw new Error(message);
+}
+
+try {
+	doSomething();
+} catch (error) {
+	throw new CustomError("Something failed.");
+}
+
+try {
+	doSomething();
+} catch (error) {
+	setTimeout(() => {
+		throw new Error("Something failed.");
+	});
+}
+
+try {
+	doSomething();
+} catch (error) {
+	throw new Error("Something failed.", options);
+}
+
+try {
+	doSomething();
+} catch (error) {
+	throw new Error(...args);
+}
+
+function f(Error) {
+	try {
+		doSomething();
+	} catch (error) {
+		throw new Error("Something failed.");
+	}
+}
+
+throw new Error("Something failed.");
@@ -0,0 +1,51 @@
+type Status = "idle" | "loading" | "done";
+
+function render(status: Status) {
+	switch (status) {
+		case "idle":
+			return "Idle";
+	}
+}
+
+function renderParenthesized(status: (Status)) {
+	switch ((status)) {
+		case 'loading':
+		case "done":
+			break;
+	}
+}
+
+type Code = 200 | -1 | 0x1f4;
+
+function describe(code: Code) {
+	switch (code) {
+		case 200:
+			return "OK";
+	}
+}
+
+const flag: true | false | null | undefined = true;
+switch (flag) {
+	case true:
+		break;
+}
+
+enum Direction {
+	Up,
+	Down,
+	"Top-Left",
+}
+
+function move(direction: Direction) {
+	switch (direction) {
+		case Direction.Up:
+			break;
+	}
+}
+
+type Nested = Status | "error";
+
+function nested(value: Nested) {
+	switch (value) {
+	}
+}
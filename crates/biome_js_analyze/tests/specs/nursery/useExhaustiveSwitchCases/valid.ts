@@ -0,0 +1,71 @@
+type Status = "idle" | "loading" | "done";
+
+function render(status: Status) {
+	switch (status) {
+		case "idle":
+			return "Idle";
+		case 'loading':
+			return "Loading";
+		case `done`:
+			return "Done";
+	}
+}
+
+function withDefault(code: 200 | 404 | 500) {
+	switch (code) {
+		case 200:
+			return "OK";
+		default:
+			return "Error";
+	}
+}
+
+function negative(code: 1 | -1 | 1.0) {
+	switch (code) {
+		case 1:
+		case -1:
+			break;
+	}
+}
+
+enum Direction {
+	Up,
+	Down,
+	"Top-Left",
+}
+
+function move(direction: Direction) {
+	switch (direction) {
+		case Direction.Up:
+		case Direction["Down"]:
+		case Direction["Top-Left"]:
+			break;
+	}
+}
+
+// Not a union of literal types
+function unknownType(value: string | "idle") {
+	switch (value) {
+		case "idle":
+			break;
+	}
+}
+
+function notAnnotated(value) {
+	switch (value) {
+		case "idle":
+			break;
+	}
+}
+
+function generic<T>(value: Array<T>) {
+	switch (value) {
+	}
+}
+
+type Loop = Loop | "idle";
+
+function recursive(value: Loop) {
+	switch (value) {
+	}
+}
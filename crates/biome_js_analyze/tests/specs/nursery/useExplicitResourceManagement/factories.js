@@ -0,0 +1,35 @@
+async function countUsers() {
+  const database = await openDatabase();
+  try {
+    return await database.count("users");
+  } finally {
+    await database.close();
+  }
+}
+
+function readRows() {
+  const client = pool.connect();
+  try {
+    client.query();
+  } finally {
+    client.release();
+  }
+}
+
+function readRowsWithOtherFactory() {
+  const client = connect();
+  try {
+    client.query();
+  } finally {
+    client.release();
+  }
+}
+
+function releaseWithArguments() {
+  const client = pool.connect();
+  try {
+    client.query();
+  } finally {
+    client.release(true);
+  }
+}
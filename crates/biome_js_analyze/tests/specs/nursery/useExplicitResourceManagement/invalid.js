@@ -0,0 +1,55 @@
+function writeLog(message) {
+  const file = openLogFile();
+  try {
+    file.write(message);
+  } finally {
+    file[Symbol.dispose]();
+  }
+}
+
+async function runQuery(sql) {
+  const connection = await connect();
+  try {
+    await connection.query(sql);
+  } finally {
+    await connection[Symbol.asyncDispose]();
+  }
+}
+
+function copyFiles() {
+  const lock = acquireLock();
+  try {
+    copy();
+  } finally {
+    lock[Symbol.dispose]();
+  }
+  notify();
+}
+
+function renderPage() {
+  const span = tracer.startSpan();
+  try {
+    // the span measures the rendering
+    render();
+  } finally {
+    span[Symbol.dispose]();
+  }
+}
+
+function writeFormatted(message) {
+  const line = format(message);
+  const file = openLogFile();
+  try {
+    const line = format(message, { verbose: true });
+    file.write(line);
+  } finally {
+    file[Symbol.dispose]();
+  }
+}
+
+const handle = openHandle();
+try {
+  handle.read();
+} finally {
+  handle[Symbol.dispose]();
+}
@@ -0,0 +1,80 @@
+function withCatch() {
+  const file = openLogFile();
+  try {
+    file.write("message");
+  } catch (error) {
+    console.error(error);
+  } finally {
+    file[Symbol.dispose]();
+  }
+}
+
+function withLet() {
+  let file = openLogFile();
+  try {
+    file.write("message");
+  } finally {
+    file[Symbol.dispose]();
+  }
+}
+
+function withOtherStatements() {
+  const file = openLogFile();
+  try {
+    file.write("message");
+  } finally {
+    file[Symbol.dispose]();
+    console.log("closed");
+  }
+}
+
+function withOtherResource(other) {
+  const file = openLogFile();
+  try {
+    file.write("message");
+  } finally {
+    other[Symbol.dispose]();
+  }
+}
+
+function withStatementBeforeTry() {
+  const file = openLogFile();
+  file.write("header");
+  try {
+    file.write("message");
+  } finally {
+    file[Symbol.dispose]();
+  }
+}
+
+function withUnknownMethod() {
+  const file = openLogFile();
+  try {
+    file.write("message");
+  } finally {
+    file.close();
+  }
+}
+
+function withAsyncDisposeNotAwaited() {
+  const connection = connect();
+  try {
+    connection.query("SELECT 1");
+  } finally {
+    connection[Symbol.asyncDispose]();
+  }
+}
+
+function withLocalSymbol(Symbol) {
+  const file = openLogFile();
+  try {
+    file.write("message");
+  } finally {
+    file[Symbol.dispose]();
+  }
+}
+
+function withUsing() {
+  using file = openLogFile();
+  file.write("message");
+}
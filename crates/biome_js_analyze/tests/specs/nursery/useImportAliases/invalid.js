@@ -0,0 +1,8 @@
+import { format } from "../../shared/format.js";
+import { parse } from '../../shared/utils/parse';
+export { Button } from "../../components/button";
+import config from "../../config/index.ts";
+import config2 from "../../config";
+const lazy = await import("../../pages/home.js");
+const legacy = require("../../shared/legacy");
+import "./../../styles/global.css";
@@ -0,0 +1,2 @@
+import { Layout } from "../layout.js";
+import { Form } from "./form.js";
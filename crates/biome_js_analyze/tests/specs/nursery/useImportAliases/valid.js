@@ -0,0 +1,6 @@
+import { format } from "@shared/format.js";
+import { Form } from "./form.js";
+import { Layout } from "../layout.js";
+import { ignored } from "../../../outside/module.js";
+import { dependency } from "dependency";
+import fs from "node:fs";
@@ -0,0 +1 @@
+import { format } from "../../shared/format.js";
@@ -0,0 +1,87 @@
+/**
+ * @param {number} a The first value.
+ * @param {number} c The second value.
+ * @returns {number} The sum.
+ */
+function renamed(a, b) {
+	return a + b;
+}
+
+/**
+ * @param {number} a The first value.
+ * @param {number} b The second value.
+ * @param {number} c The removed value.
+ * @returns {number} The sum.
+ */
+function removed(a, b) {
+	return a + b;
+}
+
+/**
+ * @param {number} a The first value.
+ * @param {number} a The first value, again.
+ * @param {number} b The second value.
+ */
+function duplicated(a, b) {
+	console.log(a, b);
+}
+
+/**
+ * @param {number} a The first value.
+ * @returns {number} The sum.
+ */
+function missingParam(a, b) {
+	return a + b;
+}
+
+/**
+ * Sums the values.
+ *
+ * @returns {number} The sum.
+ */
+function missingParams(a, b) {
+	return a + b;
+}
+
+/**
+ * @param {string} message The message.
+ * @returns {string} The message.
+ */
+function unexpectedReturns(message) {
+	console.log(message);
+	return;
+}
+
+/**
+ * @param {number} a The first value.
+ * @param {number} b The second value.
+ */
+const missingReturns = (a, b) => a + b;
+
+/**
+ * @param {number} a The first value.
+ * @param {number} b The second value.
+ */
+export const missingReturnsFunction = function (a, b) {
+	return a + b;
+};
+
+class Calculator {
+	/**
+	 * @param {number} value The initial value.
+	 * @returns {Calculator} The calculator.
+	 */
+	constructor(initial) {
+		this.value = initial;
+	}
+
+	/**
+	 * @param {number} value The value to add.
+	 */
+	add(value, times) {
+		this.value += value * times;
+	}
+}
+
+/** @param value The value. */
+function singleLine(input) {}
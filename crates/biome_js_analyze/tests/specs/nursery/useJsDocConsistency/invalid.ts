@@ -0,0 +1,23 @@
+/**
+ * @param value The value.
+ */
+function annotated(value: string): string {
+	throw new Error("Not implemented");
+}
+
+/**
+ * @param value The value.
+ * @returns The value.
+ */
+function voidAnnotated(value: string): void {}
+
+/**
+ * @param value The value.
+ * @returns Whether the value is a string.
+ */
+export declare function declared(input: unknown): input is string;
+
+/**
+ * @param options The options.
+ */
+export default function defaultExport(this: Window, options: object, extra: number) {}
@@ -0,0 +1,69 @@
+/**
+ * @param {number} a The first value.
+ * @param {number} b The second value.
+ * @returns {number} The sum.
+ */
+function sum(a, b) {
+	return a + b;
+}
+
+/** Logs the message. */
+function log(message) {
+	console.log(message);
+}
+
+/**
+ * Creates a user.
+ *
+ * @param {object} user The user.
+ * @param {string} user.name The name of the user.
+ * @param {...string} roles The roles.
+ */
+function createUser({ name }, ...roles) {}
+
+/**
+ * @param {number} value The value.
+ * @returns {void}
+ */
+function noop(value) {}
+
+/**
+ * @param {number[]} values The values.
+ */
+function* generate(values) {
+	yield* values;
+}
+
+/**
+ * @param {string} message The message.
+ * @returns {never}
+ */
+function fail(message) {
+	throw new Error(message);
+}
+
+/**
+ * @param {number[]} values The values.
+ */
+function forEach(values) {
+	values.forEach((value) => {
+		return value * 2;
+	});
+}
+
+/**
+ * @param {number} value The value.
+ */
+async function save(value) {
+	await store(value);
+}
+
+// A regular comment isn't checked
+function undocumented(a, b) {
+	return a + b;
+}
+
+/***
+ * @param banner Not a JSDoc comment.
+ */
+function banner(a) {}
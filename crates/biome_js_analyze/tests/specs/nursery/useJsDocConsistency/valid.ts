@@ -0,0 +1,25 @@
+/**
+ * @param event The event.
+ */
+function handle(this: Window, event: Event): void {}
+
+/**
+ * @param value The value.
+ * @returns The value.
+ */
+function identity<T>(value: T): T;
+function identity<T>(value: T): T {
+	return value;
+}
+
+class Service {
+	/**
+	 * @param repository The repository.
+	 */
+	constructor(private readonly repository: object) {}
+
+	/**
+	 * @param id The identifier.
+	 */
+	async remove(id: string): Promise<void> {}
+}
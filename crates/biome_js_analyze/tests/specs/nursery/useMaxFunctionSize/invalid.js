@@ -0,0 +1,50 @@
+function tooLong(values) {
+	let total = 0;
+	for (const value of values) {
+		total += value;
+	}
+	return total;
+}
+
+const arrow = (values) => {
+	const first = values[0];
+	const last = values.at(-1);
+	return first + last;
+};
+
+const template = () => `first line
+second line
+third line
+fourth line
+fifth line`;
+
+class Service {
+	constructor(options) {
+		this.options = options;
+		this.cache = new Map();
+		this.listeners = [];
+	}
+
+	get value() {
+		const value = this.compute();
+		this.cache.set("value", value);
+		return value;
+	}
+}
+
+const object = {
+	method() {
+		const a = 1;
+		const b = 2;
+		return a + b;
+	},
+};
+
+function outer() {
+	function inner() {
+		const a = 1;
+		const b = 2;
+		return a + b;
+	}
+	return inner;
+}
@@ -0,0 +1,17 @@
+function init() {
+	const config = loadConfig();
+	if (config.debug) {
+		enableDebug();
+		log("debug");
+	}
+	start(config);
+}
+
+function withNested() {
+	const handler = () => {
+		first();
+		second();
+		third();
+	};
+	return handler;
+}
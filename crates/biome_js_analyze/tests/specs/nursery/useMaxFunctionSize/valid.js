@@ -0,0 +1,14 @@
+function short(values) {
+	// The comments aren't counted
+
+	/*
+	 * Neither are the blank lines
+	 */
+
+	return values.length;
+}
+
+const arrow = (value) =>
+	value * 2;
+
+function oneLine() { const a = 1; const b = 2; const c = 3; const d = 4; return a + b + c + d; }
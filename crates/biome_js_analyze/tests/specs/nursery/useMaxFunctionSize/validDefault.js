@@ -0,0 +1,13 @@
+function defaultLimit() {
+	first();
+	second();
+	third();
+	fourth();
+	fifth();
+	sixth();
+	seventh();
+	eighth();
+	ninth();
+	tenth();
+	eleventh();
+}
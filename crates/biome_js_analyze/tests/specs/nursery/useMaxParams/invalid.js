@@ -0,0 +1,13 @@
+function declaration(a, b, c, d) {}
+const expression = function (a, b, c, d) {};
+const arrow = (a, b, c, d) => {};
+const withRest = (a, b, c, ...rest) => {};
+const withDefaults = (a = 1, b = 2, c = 3, d = 4) => {};
+class Example {
+	constructor(a, b, c, d) {}
+	method(a, b, c, d) {}
+	set value(value) {}
+}
+const object = {
+	method(a, b, c, d) {},
+};
@@ -0,0 +1,13 @@
+declare function declared(a: string, b: string, c: string, d: string): void;
+type Callback = (a: string, b: string, c: string, d: string) => void;
+interface Api {
+	method(a: string, b: string, c: string, d: string): void;
+}
+class Service {
+	constructor(
+		private readonly a: A,
+		private readonly b: B,
+		private readonly c: C,
+		private readonly d: D,
+	) {}
+}
@@ -0,0 +1,3 @@
+class Service {
+	constructor(a: A, b: B, c: C, d: D) {}
+}
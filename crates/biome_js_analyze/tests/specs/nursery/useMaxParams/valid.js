@@ -0,0 +1,8 @@
+function declaration(a, b, c) {}
+const arrow = (a, b, { c, d, e }) => {};
+const single = a => a;
+const withArray = (a, b, [c, d, e]) => {};
+class Example {
+	constructor(a, b, c) {}
+	method() {}
+}
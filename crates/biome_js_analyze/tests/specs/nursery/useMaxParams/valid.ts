@@ -0,0 +1,2 @@
+function withThis(this: Window, a: string, b: string, c: string) {}
+type Constructor = new (a: string, b: string, c: string) => object;
@@ -0,0 +1,8 @@
+class Service {
+	constructor(
+		private readonly a: A,
+		private readonly b: B,
+		c: C,
+		d: D,
+	) {}
+}
@@ -0,0 +1 @@
+function declaration(a, b, c, d, e) {}
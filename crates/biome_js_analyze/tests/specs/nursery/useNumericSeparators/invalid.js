@@ -0,0 +1,13 @@
+const million = 1000000;
+const tenThousand = 10000;
+const large = 123456789012;
+const hex = 0xFFFFFFFF;
+const upperHex = 0XABCDEF;
+const binary = 0b10101010;
+const octal = 0o7777777;
+const bigint = 9007199254740993n;
+const hexBigint = 0xFFFFFFFFFFn;
+const fraction = 0.0000001;
+const both = 123456.654321;
+const exponent = 1234567e10;
+const withTrivia = /* leading */ 1000000 /* trailing */;
@@ -0,0 +1,12 @@
+const million = 1_000_000;
+const irregular = 10_00000;
+const port = 8080;
+const small = 1234;
+const hex = 0xFFFF;
+const binary = 0b1010;
+const fraction = 0.1234;
+const exponent = 1e10000;
+const legacyOctal = 0777777;
+const leadingZero = 0999999;
+const zero = 0;
+const decimal = 1234.5678;
@@ -0,0 +1,2 @@
+const timeout = 300000;
+const hex = 0xFFFFFF;
@@ -0,0 +1,10 @@
+import path from "node:path";
+const config = __dirname + "/config/default.json";
+const output = `${process.cwd()}\\dist\\${name}`;
+const data = import.meta.dirname + "/data/" + file;
+const nested = __dirname + ("/a" + "/b");
+const mixed = __dirname + "/" + name + ".json";
+const trailing = `${__dirname}/`;
+const commented = __dirname + /* data */ "/data";
+const parent = process.cwd() + "/../shared";
+const assets = __dirname + '/assets';
@@ -0,0 +1,2 @@
+import path from "./utils/path.js";
+const config = __dirname + "/config/default.json";
@@ -0,0 +1,2 @@
+const path = require("path");
+const config = __dirname + "/config/default.json";
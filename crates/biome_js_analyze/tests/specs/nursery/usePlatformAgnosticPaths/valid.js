@@ -0,0 +1,9 @@
+import path from "node:path";
+const config = path.join(__dirname, "config", "default.json");
+const url = baseUrl + "/api/users";
+const tagged = sql`${__dirname}/data`;
+const root = process.cwd(workspace) + "/data";
+const message = "Loading " + __dirname;
+function load(__dirname) {
+	return __dirname + "/data";
+}
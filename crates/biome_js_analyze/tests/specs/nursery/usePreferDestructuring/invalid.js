@@ -0,0 +1,13 @@
+const name = user.name;
+let age = user.age;
+var city = (user.address).city;
+const id = object["id"];
+const first = array[0];
+const second = array[1];
+const nested = a.b.c.nested;
+const value = getObject().value;
+const commented = /* comment */ user.commented;
+let assigned;
+assigned = user.assigned;
+assigned = array[2];
+for (const item = items.item; ; ) {}
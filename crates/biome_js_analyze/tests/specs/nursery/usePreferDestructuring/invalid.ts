@@ -0,0 +1,2 @@
+const name: string = user.name;
+const value = (user as User).value;
@@ -0,0 +1,5 @@
+const fullName = user.name;
+const dynamic = object[key];
+const id = object["identifier"];
+let renamed;
+renamed = user.name;
@@ -0,0 +1,19 @@
+const { name } = user;
+const [first] = array;
+const fullName = user.name;
+const last = array[array.length - 1];
+const half = array[1.5];
+const dynamic = object[key];
+const optional = user?.optional;
+const chained = user?.address.chained;
+const computedOptional = array?.[0];
+let uninitialized;
+user.name = other.name;
+assigned += user.assigned;
+class Child extends Parent {
+	method() {
+		const method = super.method;
+		const field = this.#field;
+	}
+	#field;
+}
@@ -0,0 +1,2 @@
+const name = user.name;
+const id = object["id"];
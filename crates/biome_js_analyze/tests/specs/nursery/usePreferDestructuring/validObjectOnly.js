@@ -0,0 +1,3 @@
+const first = array[0];
+let second;
+second = array[1];
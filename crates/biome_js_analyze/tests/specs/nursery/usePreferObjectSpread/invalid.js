@@ -0,0 +1,8 @@
+const merged = Object.assign({}, defaults, options);
+const user = Object.assign({ role: "admin" }, profile);
+const copy = Object.assign({}, source);
+const empty = Object.assign({});
+const config = globalThis.Object.assign({}, options);
+const withDefaults = (options) => Object.assign({}, DEFAULTS, options);
+const commented = Object.assign({}, defaults, /* user options */ options);
+const withTargetComment = Object.assign(/* target */ {}, defaults);
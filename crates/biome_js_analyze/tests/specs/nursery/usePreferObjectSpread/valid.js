@@ -0,0 +1,11 @@
+const merged = { ...defaults, ...options };
+Object.assign(target, source);
+Object.assign(this.state, update);
+const spread = Object.assign({}, ...sources);
+const withAccessor = Object.assign({ set value(value) {} }, source);
+const optional = Object.assign?.({}, source);
+const assigned = Object.create({}, source);
+
+function withLocalObject(Object) {
+	return Object.assign({}, source);
+}
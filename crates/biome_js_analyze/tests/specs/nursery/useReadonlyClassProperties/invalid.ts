@@ -0,0 +1,58 @@
+class ConstructorAssigned {
+	private a: number;
+	#b: string;
+	private c = 0;
+
+	constructor() {
+		this.a = 1;
+		this.#b = "";
+		if (this.a > 0) {
+			this.c = 2;
+		}
+	}
+
+	method() {
+		return this.a + this.#b + this.c;
+	}
+}
+
+class NeverAssigned {
+	private a = new Map();
+	#b = [];
+	@decorator private c = 0;
+	private override d = 0;
+}
+
+class ParameterProperties {
+	constructor(
+		private a: number,
+		private b: string,
+	) {}
+
+	method() {
+		return this.a + this.b;
+	}
+}
+
+class OtherMemberAssigned {
+	private a = 0;
+	public b = 0;
+
+	method() {
+		this.b = 1;
+		other.a = 1;
+	}
+}
+
+class SameNameInOtherClass {
+	private a = 0;
+
+	method() {
+		return class {
+			#a = 0;
+			method() {
+				this.#a = 1;
+			}
+		};
+	}
+}
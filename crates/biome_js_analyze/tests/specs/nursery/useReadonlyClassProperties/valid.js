@@ -0,0 +1,3 @@
+class JavaScript {
+	#a = 0;
+}
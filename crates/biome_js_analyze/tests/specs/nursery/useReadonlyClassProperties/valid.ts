@@ -0,0 +1,85 @@
+class Readonly {
+	private readonly a = 0;
+	readonly #b = 0;
+	constructor(private readonly c: number) {}
+}
+
+class NotPrivate {
+	a = 0;
+	public b = 0;
+	protected c = 0;
+	constructor(public d: number, protected e: number) {}
+}
+
+class Static {
+	private static a = 0;
+	static #b = 0;
+}
+
+class Accessor {
+	accessor #a = 0;
+}
+
+class ModifiedInMethod {
+	private a = 0;
+	#b = 0;
+	private c = 0;
+	private d = 0;
+	private e = 0;
+
+	method() {
+		this.a = 1;
+		this.#b += 1;
+		this.c++;
+		[this.d] = [1];
+		({ e: this.e } = { e: 1 });
+	}
+}
+
+class ModifiedInNestedFunction {
+	private a = 0;
+	#b = 0;
+
+	constructor() {
+		const set = () => {
+			this.a = 1;
+		};
+		function set2(this: ModifiedInNestedFunction) {
+			this.#b = 1;
+		}
+	}
+}
+
+class ModifiedOnOtherInstance {
+	#a = 0;
+
+	constructor(other: ModifiedOnOtherInstance) {
+		other.#a = 1;
+	}
+}
+
+class ModifiedInInitializer {
+	private a = 0;
+	public b = (this.a = 1);
+	private c = 0;
+
+	static {
+		this.prototype.c = 1;
+	}
+}
+
+class ComputedModification {
+	private a = 0;
+
+	method() {
+		this["a"] = 1;
+	}
+}
+
+class ParameterModified {
+	constructor(private a: number) {}
+
+	method() {
+		this.a = 1;
+	}
+}
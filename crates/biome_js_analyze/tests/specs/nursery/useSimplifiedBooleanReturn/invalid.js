@@ -0,0 +1,82 @@
+function ifElse(a, b) {
+	if (a === b) {
+		return true;
+	} else {
+		return false;
+	}
+}
+
+function ifElseNegated(a, b) {
+	if (a === b) return false;
+	else return true;
+}
+
+function ifFollowedByReturn(value) {
+	if (value instanceof Date) {
+		return true;
+	}
+	return false;
+}
+
+function notBoolean(user) {
+	if (user.name) {
+		return true;
+	}
+	return false;
+}
+
+function notBooleanNegated(user) {
+	if (user.name) return false;
+	return true;
+}
+
+function binaryNegated(a, b) {
+	if (a + b) return false;
+	return true;
+}
+
+function logical(a, b) {
+	if (a > 0 && !b) return true;
+	return false;
+}
+
+function logicalNotBoolean(a, b) {
+	if (a && b) return true;
+	return false;
+}
+
+function elseIf(a) {
+	if (a > 1) {
+		return 1;
+	} else if (a < 0) {
+		return false;
+	} else {
+		return true;
+	}
+}
+
+function conditional(user) {
+	return user.name ? true : false;
+}
+
+function conditionalNegated(a, b) {
+	return a !== b ? false : true;
+}
+
+const arrow = (a, b) => {
+	return (a < b ? true : false);
+};
+
+function withoutSemicolons(a) {
+	if (a > 0) return true
+	return false
+}
+
+function withComments(a) {
+	// Check the value
+	if (a > 0) {
+		// positive
+		return true;
+	}
+	return false;
+}
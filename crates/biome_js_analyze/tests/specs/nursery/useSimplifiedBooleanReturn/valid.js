@@ -0,0 +1,38 @@
+function direct(a) {
+	return a > 0;
+}
+
+function sameValues(a) {
+	if (a) return true;
+	return true;
+}
+
+function otherStatements(value) {
+	if (value) {
+		log(value);
+		return true;
+	}
+	return false;
+}
+
+function noFollowingReturn(a) {
+	if (a) return true;
+	log(a);
+	return false;
+}
+
+function notLiterals(a) {
+	if (a) return 1;
+	return 0;
+}
+
+function conditionalNotLiterals(a) {
+	return a ? "yes" : false;
+}
+
+function nested(a, b) {
+	if (a) if (b) return true;
+	return false;
+}
+
+const value = a ? true : false;
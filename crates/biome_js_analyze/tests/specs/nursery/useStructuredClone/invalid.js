@@ -0,0 +1,5 @@
+const copy = JSON.parse(JSON.stringify(state));
+const nested = JSON.parse(JSON.stringify(store.getState().settings));
+const global = window.JSON.parse(globalThis.JSON.stringify(value));
+const parenthesized = JSON.parse((JSON.stringify(value)));
+const commented = JSON.parse(/* clone */ JSON.stringify(value));
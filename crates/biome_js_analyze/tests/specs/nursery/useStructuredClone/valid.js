@@ -0,0 +1,11 @@
+const copy = structuredClone(state);
+const withReviver = JSON.parse(JSON.stringify(state), reviver);
+const withReplacer = JSON.parse(JSON.stringify(state, replacer));
+const withIndentation = JSON.parse(JSON.stringify(state, null, 2));
+const parsed = JSON.parse(text);
+const serialized = JSON.stringify(JSON.parse(text));
+const spread = JSON.parse(JSON.stringify(...values));
+
+function withLocalJson(JSON) {
+	return JSON.parse(JSON.stringify(state));
+}
@@ -0,0 +1,50 @@
+const GET_USER = gql`
+	query GetUser($id: ID!) {
+		user(id: $id) {
+			login
+		}
+	}
+`;
+
+const GET_FRIENDS = gql`
+	query GetFriends($id: ID!) {
+		user(id: $id) {
+			friends(last: 10) {
+				name
+			}
+		}
+	}
+`;
+
+const GET_NAME = graphql`
+	query GetName {
+		user(id: $id) {
+			name
+		}
+	}
+`;
+
+const RENAME = gql`
+	mutation Rename($id: ID!) {
+		rename(id: $id, name: $name) {
+			name
+		}
+	}
+`;
+
+const USER_FIELDS = gql`
+	fragment UserFields on User {
+		name
+		age
+	}
+`;
+
+const NODE = gql`
+	{
+		user(id: "1") {
+			... on Node {
+				name
+			}
+		}
+	}
+`;
@@ -0,0 +1,7 @@
+const GET_USER = gql`
+	query GetUser($id: ID!) {
+		user(id: $id) {
+			name
+		}
+	}
+`;
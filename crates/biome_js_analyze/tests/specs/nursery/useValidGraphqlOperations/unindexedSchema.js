@@ -0,0 +1,8 @@
+/* should not generate diagnostics */
+const GET_USER = gql`
+	query GetUser($id: ID!) {
+		user(id: $id) {
+			login
+		}
+	}
+`;
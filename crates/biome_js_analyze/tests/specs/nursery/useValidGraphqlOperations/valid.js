@@ -0,0 +1,53 @@
+const GET_USER = gql`
+	query GetUser($id: ID!, $first: Int) {
+		user(id: $id) {
+			__typename
+			name
+			email
+			friends(first: $first) {
+				...UserFields
+			}
+		}
+	}
+	${USER_FIELDS}
+`;
+
+const GET_USERS = graphql`
+	query GetUsers($after: String) {
+		users(first: 10, after: $after) {
+			... on Node {
+				id
+			}
+			... on User {
+				name
+			}
+		}
+	}
+`;
+
+const RENAME = gql`
+	mutation Rename($id: ID!, $name: String!) {
+		rename(id: $id, name: $name) {
+			id
+		}
+	}
+`;
+
+const USER_FIELDS = gql`
+	fragment UserFields on User {
+		id
+		name
+	}
+`;
+
+// Not a GraphQL tag
+const NOT_GRAPHQL = css`
+	query GetUser {
+		unknown
+	}
+`;
+
+// Syntax errors are ignored
+const INVALID = gql`
+	query {
+`;
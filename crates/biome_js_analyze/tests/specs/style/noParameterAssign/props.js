@@ -0,0 +1,27 @@
+function modify(param) {
+	param.prop = 0;
+	param.a.b = 0;
+	param["prop"] += 1;
+	param.count++;
+	delete param.prop;
+	[param.first] = values;
+	({ value: param.value } = values);
+	for (param.key in object);
+}
+
+function read(param) {
+	param.method();
+	param.list.push(0);
+	other[param.key] = 0;
+	const copy = { ...param };
+	copy.prop = 0;
+}
+
+const counts = words.reduce((acc, word) => {
+	acc[word] = (acc[word] ?? 0) + 1;
+	return acc;
+}, {});
+
+function reset(acc) {
+	acc = {};
+}
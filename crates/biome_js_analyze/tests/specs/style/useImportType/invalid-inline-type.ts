@@ -0,0 +1,15 @@
+import { A, B } from "";
+export type { A, B };
+
+import type { C, D } from "";
+
+import type { type E, F } from "";
+
+import G, { H, I } from "";
+export type { G, H, I };
+
+import J, { type K } from "";
+export type { J, K };
+
+import L, { M, N } from "";
+export { L, type M, N };
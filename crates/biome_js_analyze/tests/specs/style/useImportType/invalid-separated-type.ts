@@ -0,0 +1,26 @@
+import { type A, B } from "";
+export type { A };
+export { B };
+
+import { C, D, E } from "";
+export type { C, E };
+export { D };
+
+import {
+	F,
+	G,
+	H,
+} from "";
+export type { G };
+export { F, H };
+
+import I, { J } from "";
+export { I, type J };
+
+import K, { type L, M, N } from "";
+export type { L, M };
+export { K, N };
+
+import O, { P, Q } from "";
+export type { O, P };
+export { Q };
@@ -0,0 +1,15 @@
+import { type A, type B } from "";
+export type { A, B };
+
+import { type C, D } from "";
+export type { C };
+export { D };
+
+import type E from "";
+export type { E };
+
+import type * as F from "";
+export type { F };
+
+import G, { type H } from "";
+export { G, type H };
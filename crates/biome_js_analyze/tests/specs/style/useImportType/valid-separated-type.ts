@@ -0,0 +1,12 @@
+import type { A } from "";
+import { B } from "";
+export type { A };
+export { B };
+
+import C, { D } from "";
+import type { E } from "";
+export type { E };
+export { C, D };
+
+import type F from "";
+export type { F };
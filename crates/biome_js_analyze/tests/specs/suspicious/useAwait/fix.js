@@ -0,0 +1,21 @@
+async function save() {
+	console.log("save");
+}
+save();
+
+// Loads the data
+async function load() {
+	return fetch("/data");
+}
+async function run() {
+	await load();
+	(load());
+}
+
+const arrow = async () => { console.log("arrow"); };
+arrow();
+
+const expression = async function () {
+	return 1;
+};
+await expression();
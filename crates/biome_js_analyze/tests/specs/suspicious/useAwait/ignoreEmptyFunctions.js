@@ -0,0 +1,7 @@
+async function noop() {}
+
+const arrow = async () => {};
+
+class Noop {
+	async run() {}
+}
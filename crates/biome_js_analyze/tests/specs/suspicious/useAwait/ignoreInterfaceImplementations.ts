@@ -0,0 +1,17 @@
+interface Store {
+	get(key: string): Promise<string>;
+}
+
+class MemoryStore implements Store {
+	values: Record<string, string> = {};
+
+	async get(key: string): Promise<string> {
+		return this.values[key];
+	}
+}
+
+class Cache {
+	async get(key: string): Promise<string> {
+		return key;
+	}
+}
@@ -0,0 +1,33 @@
+async function observed() {
+	return 1;
+}
+const value = observed();
+
+async function passed() {
+	return 1;
+}
+run(passed);
+
+export async function exported() {
+	return 1;
+}
+
+async function annotated(): Promise<number> {
+	return 1;
+}
+annotated();
+
+async function* generator() {
+	yield 1;
+}
+generator();
+
+let reassigned = async () => { return 1; };
+reassigned = async () => { return 2; };
+
+const object = {
+	async method() {
+		return 1;
+	},
+};
+object.method();
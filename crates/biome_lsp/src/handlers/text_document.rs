@@ -1,4 +1,4 @@
-use crate::utils::apply_document_changes;
+use crate::utils::{apply_document_changes, changed_range};
 use crate::{documents::Document, session::Session};
 use anyhow::Result;
 use biome_service::workspace::{
@@ -61,6 +61,11 @@ pub(crate) async fn did_change(
     tracing::trace!("old document: {:?}", old_text);
     tracing::trace!("content changes: {:?}", params.content_changes);
 
+    let changed_range = changed_range(
+        session.position_encoding(),
+        &old_text,
+        &params.content_changes,
+    );
     let text = apply_document_changes(
         session.position_encoding(),
         old_text,
@@ -75,6 +80,7 @@ pub(crate) async fn did_change(
         path: biome_path,
         version,
         content: text,
+        changed_range,
     })?;
 
     if let Err(err) = session.update_diagnostics(url).await {
@@ -621,6 +621,8 @@ impl ServerFactory {
 
         workspace_method!(builder, file_features);
         workspace_method!(builder, is_path_ignored);
+        workspace_method!(builder, is_path_indexed);
+        workspace_method!(builder, set_project_indexed);
         workspace_method!(builder, update_settings);
         workspace_method!(builder, register_project_folder);
         workspace_method!(builder, unregister_project_folder);
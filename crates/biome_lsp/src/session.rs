@@ -607,25 +607,30 @@ impl Session {
             .map(PathBuf::from)
             .or(self.base_path());
         if let Some(base_path) = base_path {
-            let result = self.fs.auto_search(&base_path, &["package.json"], false);
-            match result {
-                Ok(result) => {
-                    if let Some(result) = result {
-                        let biome_path = BiomePath::new(result.file_path);
-                        let result =
-                            self.workspace
-                                .set_manifest_for_project(SetManifestForProjectParams {
+            for file_names in [&["package.json"][..], &["tsconfig.json", "jsconfig.json"]] {
+                let result = self.fs.auto_search(&base_path, file_names, false);
+                match result {
+                    Ok(result) => {
+                        if let Some(result) = result {
+                            let biome_path = BiomePath::new(result.file_path);
+                            let result = self.workspace.set_manifest_for_project(
+                                SetManifestForProjectParams {
                                     manifest_path: biome_path.clone(),
                                     content: result.content,
                                     version: 0,
-                                });
-                        if let Err(err) = result {
-                            error!("{}", err);
+                                },
+                            );
+                            if let Err(err) = result {
+                                error!("{}", err);
+                            }
                         }
                     }
-                }
-                Err(err) => {
-                    error!("Couldn't load the package.json file, reason:\n {}", err);
+                    Err(err) => {
+                        error!(
+                            "Couldn't load the {} file, reason:\n {}",
+                            file_names[0], err
+                        );
+                    }
                 }
             }
         }
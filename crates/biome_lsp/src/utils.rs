@@ -377,11 +377,26 @@ pub(crate) fn apply_document_changes(
     text
 }
 
+/// Returns the range of `current_content` replaced by the content changes,
+/// when they consist of a single edit.
+pub(crate) fn changed_range(
+    position_encoding: PositionEncoding,
+    current_content: &str,
+    content_changes: &[lsp_types::TextDocumentContentChangeEvent],
+) -> Option<TextRange> {
+    let [change] = content_changes else {
+        return None;
+    };
+    let line_index = LineIndex::new(current_content);
+    from_proto::text_range(&line_index, change.range?, position_encoding).ok()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::apply_document_changes;
+    use super::{apply_document_changes, changed_range};
     use biome_lsp_converters::line_index::LineIndex;
     use biome_lsp_converters::{PositionEncoding, WideEncoding};
+    use biome_rowan::{TextRange, TextSize};
     use biome_text_edit::TextEdit;
     use tower_lsp::lsp_types as lsp;
     use tower_lsp::lsp_types::{Position, Range, TextDocumentContentChangeEvent};
@@ -513,4 +528,31 @@ line 7 new";
 
         assert_eq!(output, expected);
     }
+
+    #[test]
+    fn test_changed_range() {
+        let encoding = PositionEncoding::Wide(WideEncoding::Utf16);
+        let input = "a { color: red; }\nb { color: blue; }\n";
+        let change = TextDocumentContentChangeEvent {
+            range: Some(Range::new(Position::new(1, 11), Position::new(1, 15))),
+            range_length: Some(4),
+            text: String::from("green"),
+        };
+
+        assert_eq!(
+            changed_range(encoding, input, &[change.clone()]),
+            Some(TextRange::new(TextSize::from(29), TextSize::from(33)))
+        );
+        assert_eq!(
+            changed_range(encoding, input, &[change.clone(), change]),
+            None
+        );
+
+        let full_change = TextDocumentContentChangeEvent {
+            range: None,
+            range_length: None,
+            text: String::from("a {}"),
+        };
+        assert_eq!(changed_range(encoding, input, &[full_change]), None);
+    }
 }
@@ -2,10 +2,10 @@ use biome_rowan::SyntaxKind;
 use std::marker::PhantomData;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct TokenSet<K: SyntaxKind>([u128; 2], PhantomData<K>);
+pub struct TokenSet<K: SyntaxKind>([u128; 3], PhantomData<K>);
 
 impl<K: SyntaxKind> TokenSet<K> {
-    pub const EMPTY: TokenSet<K> = TokenSet([0; 2], PhantomData);
+    pub const EMPTY: TokenSet<K> = TokenSet([0; 3], PhantomData);
 
     pub fn singleton(kind: K) -> Self {
         unsafe { TokenSet::from_raw(kind.to_raw().0) }
@@ -13,18 +13,20 @@ impl<K: SyntaxKind> TokenSet<K> {
 
     pub const fn union(self, other: TokenSet<K>) -> Self {
         TokenSet(
-            [self.0[0] | other.0[0], self.0[1] | other.0[1]],
+            [
+                self.0[0] | other.0[0],
+                self.0[1] | other.0[1],
+                self.0[2] | other.0[2],
+            ],
             PhantomData,
         )
     }
 
     pub fn contains(&self, kind: K) -> bool {
         let kind = kind.to_raw().0;
-        let num = kind as usize;
-        match num {
-            0..=127 => self.0[0] & mask(kind)[0] != 0,
-            _ => self.0[1] & mask(kind)[1] != 0,
-        }
+        let index = kind as usize / u128::BITS as usize;
+
+        self.0[index] & mask(kind)[index] != 0
     }
 
     /// Constructs a token set for a single kind from a kind's raw `u16` representation.
@@ -39,12 +41,13 @@ impl<K: SyntaxKind> TokenSet<K> {
     }
 }
 
-const fn mask(kind: u16) -> [u128; 2] {
-    let num = kind as usize;
-    match num {
-        0..=127 => [1u128 << num, 0],
-        _ => [0, 1u128 << (num - 127)],
-    }
+const fn mask(kind: u16) -> [u128; 3] {
+    let index = kind as usize / u128::BITS as usize;
+    let shift = kind % u128::BITS as u16;
+
+    let mut bits = [0; 3];
+    bits[index] = 1 << shift;
+    bits
 }
 
 /// Utility macro for making a new token set
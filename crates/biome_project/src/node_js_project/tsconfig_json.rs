@@ -8,8 +8,17 @@ use rustc_hash::FxHashMap;
 #[derive(Debug, Default, Clone, Deserializable)]
 #[deserializable(unknown_fields = "allow")]
 pub struct TsConfigJson {
-    base_url: Option<String>,
-    paths: FxHashMap<String, Vec<String>>,
+    pub compiler_options: CompilerOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub struct CompilerOptions {
+    /// The directory of the non-relative module names, relative to the `tsconfig.json`
+    pub base_url: Option<String>,
+    /// The path mappings, e.g. `"@/*": ["./src/*"]`, relative to `base_url` if it's set,
+    /// or to the `tsconfig.json` otherwise
+    pub paths: FxHashMap<String, Vec<String>>,
 }
 
 impl Manifest for TsConfigJson {
@@ -24,7 +24,7 @@ use biome_analyze::{
 use biome_css_analyze::analyze;
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_formatter::format_node;
-use biome_css_parser::CssParserOptions;
+use biome_css_parser::{CssParse, CssParserOptions};
 use biome_css_syntax::{CssLanguage, CssRoot, CssSyntaxNode};
 use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{
@@ -45,6 +45,11 @@ pub struct CssFormatterSettings {
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
     pub quote_style: Option<QuoteStyle>,
+    pub collapse_single_declaration_rules: Option<bool>,
+    pub max_empty_lines: Option<u8>,
+    pub shorten_hex_colors: Option<bool>,
+    pub preserve_banner_comments: Option<bool>,
+    pub reflow_comments: Option<bool>,
     pub enabled: Option<bool>,
 }
 
@@ -59,6 +64,9 @@ pub struct CssLinterSettings {
 pub struct CssParserSettings {
     pub allow_wrong_line_comments: Option<bool>,
     pub css_modules: Option<bool>,
+    pub custom_media: Option<bool>,
+    pub tailwind_directives: Option<bool>,
+    pub raw_unknown_at_rules: Option<bool>,
 }
 
 impl ServiceLanguage for CssLanguage {
@@ -107,7 +115,27 @@ impl ServiceLanguage for CssLanguage {
         .with_indent_width(indent_width)
         .with_line_width(line_width)
         .with_line_ending(line_ending)
-        .with_quote_style(language.and_then(|l| l.quote_style).unwrap_or_default());
+        .with_quote_style(language.and_then(|l| l.quote_style).unwrap_or_default())
+        .with_collapse_single_declaration_rules(
+            language
+                .and_then(|l| l.collapse_single_declaration_rules)
+                .unwrap_or_default(),
+        )
+        .with_shorten_hex_colors(
+            language
+                .and_then(|l| l.shorten_hex_colors)
+                .unwrap_or_default(),
+        )
+        .with_preserve_banner_comments(
+            language
+                .and_then(|l| l.preserve_banner_comments)
+                .unwrap_or_default(),
+        )
+        .with_reflow_comments(language.and_then(|l| l.reflow_comments).unwrap_or_default());
+        let options = match language.and_then(|l| l.max_empty_lines) {
+            Some(max_empty_lines) => options.with_max_empty_lines(max_empty_lines),
+            None => options,
+        };
         if let Some(overrides) = overrides {
             overrides.to_override_css_format_options(path, options)
         } else {
@@ -164,7 +192,10 @@ pub(crate) struct CssFileHandler;
 impl ExtensionHandler for CssFileHandler {
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: Some(reparse),
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -187,13 +218,10 @@ impl ExtensionHandler for CssFileHandler {
     }
 }
 
-fn parse(
+pub(crate) fn parser_options(
     biome_path: &BiomePath,
-    _file_source: DocumentFileSource,
-    text: &str,
     settings: Option<&Settings>,
-    cache: &mut NodeCache,
-) -> ParseResult {
+) -> CssParserOptions {
     let mut options = CssParserOptions {
         allow_wrong_line_comments: settings
             .and_then(|s| s.languages.css.parser.allow_wrong_line_comments)
@@ -201,6 +229,15 @@ fn parse(
         css_modules: settings
             .and_then(|s| s.languages.css.parser.css_modules)
             .unwrap_or_default(),
+        custom_media: settings
+            .and_then(|s| s.languages.css.parser.custom_media)
+            .unwrap_or_default(),
+        tailwind_directives: settings
+            .and_then(|s| s.languages.css.parser.tailwind_directives)
+            .unwrap_or_default(),
+        raw_unknown_at_rules: settings
+            .and_then(|s| s.languages.css.parser.raw_unknown_at_rules)
+            .unwrap_or_default(),
         grit_metavariables: false,
     };
     if let Some(settings) = settings {
@@ -208,6 +245,17 @@ fn parse(
             .override_settings
             .to_override_css_parser_options(biome_path, options);
     }
+    options
+}
+
+fn parse(
+    biome_path: &BiomePath,
+    _file_source: DocumentFileSource,
+    text: &str,
+    settings: Option<&Settings>,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let options = parser_options(biome_path, settings);
     let parse = biome_css_parser::parse_css_with_cache(text, cache, options);
     ParseResult {
         any_parse: parse.into(),
@@ -215,7 +263,20 @@ fn parse(
     }
 }
 
-fn debug_syntax_tree(_rome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
+fn reparse(
+    biome_path: &BiomePath,
+    previous: &AnyParse,
+    text: &str,
+    changed_range: TextRange,
+    settings: Option<&Settings>,
+    cache: &mut NodeCache,
+) -> AnyParse {
+    let options = parser_options(biome_path, settings);
+    let previous = CssParse::new(previous.syntax(), previous.diagnostics().to_vec());
+    biome_css_parser::reparse_css_with_cache(&previous, text, changed_range, cache, options).into()
+}
+
+pub(crate) fn debug_syntax_tree(_rome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
     let syntax: CssSyntaxNode = parse.syntax();
     let tree: CssRoot = parse.tree();
     GetSyntaxTreeResult {
@@ -224,7 +285,7 @@ fn debug_syntax_tree(_rome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeRe
     }
 }
 
-fn debug_formatter_ir(
+pub(crate) fn debug_formatter_ir(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
@@ -240,7 +301,7 @@ fn debug_formatter_ir(
 }
 
 #[tracing::instrument(level = "debug", skip(parse))]
-fn format(
+pub(crate) fn format(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
@@ -259,7 +320,7 @@ fn format(
     }
 }
 
-fn format_range(
+pub(crate) fn format_range(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
@@ -273,7 +334,7 @@ fn format_range(
     Ok(printed)
 }
 
-fn format_on_type(
+pub(crate) fn format_on_type(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
@@ -356,51 +417,57 @@ fn lint(params: LintParams) -> LintResults {
                 .count();
 
             info!("Analyze file {}", params.path.display());
-            let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-                if let Some(mut diagnostic) = signal.diagnostic() {
-                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
-                    if ignores_suppression_comment
-                        && diagnostic.category() == Some(category!("suppressions/unused"))
-                    {
-                        return ControlFlow::<Never>::Continue(());
-                    }
+            let (_, analyze_diagnostics) = analyze(
+                &tree,
+                filter,
+                &analyzer_options,
+                params.css_project_index,
+                |signal| {
+                    if let Some(mut diagnostic) = signal.diagnostic() {
+                        // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+                        if ignores_suppression_comment
+                            && diagnostic.category() == Some(category!("suppressions/unused"))
+                        {
+                            return ControlFlow::<Never>::Continue(());
+                        }
 
-                    diagnostic_count += 1;
-
-                    // We do now check if the severity of the diagnostics should be changed.
-                    // The configuration allows to change the severity of the diagnostics emitted by rules.
-                    let severity = diagnostic
-                        .category()
-                        .filter(|category| category.name().starts_with("lint/"))
-                        .map_or_else(
-                            || diagnostic.severity(),
-                            |category| {
-                                rules
-                                    .as_ref()
-                                    .and_then(|rules| rules.get_severity_from_code(category))
-                                    .unwrap_or(Severity::Warning)
-                            },
-                        );
-
-                    if severity >= Severity::Error {
-                        errors += 1;
-                    }
+                        diagnostic_count += 1;
+
+                        // We do now check if the severity of the diagnostics should be changed.
+                        // The configuration allows to change the severity of the diagnostics emitted by rules.
+                        let severity = diagnostic
+                            .category()
+                            .filter(|category| category.name().starts_with("lint/"))
+                            .map_or_else(
+                                || diagnostic.severity(),
+                                |category| {
+                                    rules
+                                        .as_ref()
+                                        .and_then(|rules| rules.get_severity_from_code(category))
+                                        .unwrap_or(Severity::Warning)
+                                },
+                            );
 
-                    if diagnostic_count <= params.max_diagnostics {
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diagnostic = diagnostic.add_code_suggestion(action.into());
-                            }
+                        if severity >= Severity::Error {
+                            errors += 1;
                         }
 
-                        let error = diagnostic.with_severity(severity);
+                        if diagnostic_count <= params.max_diagnostics {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diagnostic = diagnostic.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            let error = diagnostic.with_severity(severity);
 
-                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                            diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                        }
                     }
-                }
 
-                ControlFlow::<Never>::Continue(())
-            });
+                    ControlFlow::<Never>::Continue(())
+                },
+            );
 
             diagnostics.extend(
                 analyze_diagnostics
@@ -433,6 +500,8 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        css_project_index,
+        js_project_index: _,
         language,
         only,
         skip,
@@ -471,19 +540,25 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
             info!("CSS runs the analyzer");
 
-            analyze(&tree, filter, &analyzer_options, |signal| {
-                actions.extend(signal.actions().into_code_action_iter().map(|item| {
-                    CodeAction {
-                        category: item.category.clone(),
-                        rule_name: item
-                            .rule_name
-                            .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                        suggestion: item.suggestion,
-                    }
-                }));
+            analyze(
+                &tree,
+                filter,
+                &analyzer_options,
+                css_project_index,
+                |signal| {
+                    actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                        CodeAction {
+                            category: item.category.clone(),
+                            rule_name: item
+                                .rule_name
+                                .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                            suggestion: item.suggestion,
+                        }
+                    }));
 
-                ControlFlow::<Never>::Continue(())
-            });
+                    ControlFlow::<Never>::Continue(())
+                },
+            );
 
             PullActionsResult { actions }
         })
@@ -529,48 +604,54 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         params.suppression_reason,
     );
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-            let current_diagnostic = signal.diagnostic();
-
-            if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules.as_deref()) {
-                    errors += 1;
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            params.css_project_index.clone(),
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                        errors += 1;
+                    }
                 }
-            }
 
-            for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
+                for action in signal.actions() {
+                    // suppression actions should not be part of the fixes (safe or suggested)
+                    if action.is_suppression() {
+                        continue;
+                    }
 
-                match params.fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
+                    match params.fix_file_mode {
+                        FixFileMode::SafeFixes => {
+                            if action.applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if action.applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                        if action.applicability == Applicability::Always {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if matches!(
+                                action.applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                    }
-                    FixFileMode::SafeAndUnsafeFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::ApplySuppressions => {
+                            // TODO: to implement
                         }
                     }
-                    FixFileMode::ApplySuppressions => {
-                        // TODO: to implement
-                    }
                 }
-            }
 
-            ControlFlow::Continue(())
-        });
+                ControlFlow::Continue(())
+            },
+        );
 
         match action {
             Some(action) => {
@@ -157,7 +157,10 @@ pub(crate) struct GraphqlFileHandler;
 impl ExtensionHandler for GraphqlFileHandler {
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -407,6 +410,8 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        css_project_index: _,
+        js_project_index: _,
         language,
         only,
         skip,
@@ -293,7 +293,10 @@ pub(crate) struct JsFileHandler;
 impl ExtensionHandler for JsFileHandler {
     fn capabilities(&self) -> super::Capabilities {
         super::Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: Some(debug_control_flow),
@@ -396,6 +399,7 @@ fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
         &options,
         JsFileSource::default(),
         None,
+        Default::default(),
         |_| ControlFlow::<Never>::Continue(()),
     );
 
@@ -475,6 +479,7 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 analyzer_options,
                 file_source,
                 params.manifest,
+                params.js_project_index,
                 |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         if ignores_suppression_comment
@@ -545,6 +550,8 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest,
+        css_project_index: _,
+        js_project_index,
         language,
         only,
         skip,
@@ -588,6 +595,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 &analyzer_options,
                 source_type,
                 manifest,
+                js_project_index,
                 |signal| {
                     actions.extend(signal.actions().into_code_action_iter().map(|item| {
                         CodeAction {
@@ -659,6 +667,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             &analyzer_options,
             file_source,
             params.manifest.clone(),
+            params.js_project_index.clone(),
             |signal| {
                 let current_diagnostic = signal.diagnostic();
 
@@ -888,6 +897,7 @@ pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult,
         &AnalyzerOptions::default(),
         JsFileSource::default(),
         None,
+        Default::default(),
         |signal| {
             for action in signal.actions() {
                 if action.is_suppression() {
@@ -156,7 +156,10 @@ pub(crate) struct JsonFileHandler;
 impl ExtensionHandler for JsonFileHandler {
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -454,6 +457,8 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        css_project_index: _,
+        js_project_index: _,
         language,
         skip,
         only,
@@ -1,5 +1,5 @@
 use self::{
-    css::CssFileHandler, javascript::JsFileHandler, json::JsonFileHandler,
+    css::CssFileHandler, javascript::JsFileHandler, json::JsonFileHandler, scss::ScssFileHandler,
     unknown::UnknownFileHandler,
 };
 use crate::diagnostics::{QueryDiagnostic, SearchError};
@@ -22,6 +22,7 @@ use biome_configuration::analyzer::RuleSelector;
 use biome_configuration::Rules;
 use biome_console::fmt::Formatter;
 use biome_console::markup;
+use biome_css_analyze::project::CssProjectIndex;
 use biome_css_syntax::{CssFileSource, CssLanguage};
 use biome_diagnostics::{Diagnostic, Severity};
 use biome_formatter::Printed;
@@ -30,6 +31,7 @@ use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
 use biome_grit_patterns::{GritQuery, GritQueryResult, GritTargetFile};
 use biome_grit_syntax::file_source::GritFileSource;
 use biome_html_syntax::HtmlFileSource;
+use biome_js_analyze::project::JsProjectIndex;
 use biome_js_parser::{parse, JsParserOptions};
 use biome_js_syntax::{
     EmbeddingKind, JsFileSource, JsLanguage, Language, LanguageVariant, TextRange, TextSize,
@@ -47,6 +49,7 @@ use rustc_hash::FxHashSet;
 use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::path::Path;
+use std::sync::Arc;
 use tracing::instrument;
 
 mod astro;
@@ -56,6 +59,7 @@ mod grit;
 mod html;
 mod javascript;
 mod json;
+mod scss;
 mod svelte;
 mod unknown;
 mod vue;
@@ -294,6 +298,10 @@ impl DocumentFileSource {
         matches!(self, DocumentFileSource::Css(_))
     }
 
+    pub const fn is_graphql_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Graphql(_))
+    }
+
     pub fn to_js_file_source(&self) -> Option<JsFileSource> {
         match self {
             DocumentFileSource::Js(file_source) => Some(*file_source),
@@ -379,7 +387,13 @@ impl biome_console::fmt::Display for DocumentFileSource {
                     fmt.write_markup(markup! { "JSON" })
                 }
             }
-            DocumentFileSource::Css(_) => fmt.write_markup(markup! { "CSS" }),
+            DocumentFileSource::Css(css) => {
+                if css.is_scss() {
+                    fmt.write_markup(markup! { "SCSS" })
+                } else {
+                    fmt.write_markup(markup! { "CSS" })
+                }
+            }
             DocumentFileSource::Graphql(_) => fmt.write_markup(markup! { "GraphQL" }),
             DocumentFileSource::Html(_) => fmt.write_markup(markup! { "HTML" }),
             DocumentFileSource::Grit(_) => fmt.write_markup(markup! { "Grit" }),
@@ -396,6 +410,8 @@ pub struct FixAllParams<'a> {
     pub(crate) should_format: bool,
     pub(crate) biome_path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) css_project_index: Arc<CssProjectIndex>,
+    pub(crate) js_project_index: Arc<JsProjectIndex>,
     pub(crate) document_file_source: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
@@ -422,10 +438,15 @@ pub struct ParseResult {
 type Parse =
     fn(&BiomePath, DocumentFileSource, &str, Option<&Settings>, &mut NodeCache) -> ParseResult;
 
+type Reparse =
+    fn(&BiomePath, &AnyParse, &str, TextRange, Option<&Settings>, &mut NodeCache) -> AnyParse;
+
 #[derive(Default)]
 pub struct ParserCapabilities {
     /// Parse a file
     pub(crate) parse: Option<Parse>,
+    /// Reparse the part of a file touched by an edit, reusing the previous syntax tree
+    pub(crate) reparse: Option<Reparse>,
 }
 
 type DebugSyntaxTree = fn(&BiomePath, AnyParse) -> GetSyntaxTreeResult;
@@ -458,6 +479,8 @@ pub(crate) struct LintParams<'a> {
     pub(crate) skip: Vec<RuleSelector>,
     pub(crate) categories: RuleCategories,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) css_project_index: Arc<CssProjectIndex>,
+    pub(crate) js_project_index: Arc<JsProjectIndex>,
     pub(crate) suppression_reason: Option<String>,
 }
 
@@ -473,6 +496,8 @@ pub(crate) struct CodeActionsParams<'a> {
     pub(crate) workspace: &'a WorkspaceSettingsHandle<'a>,
     pub(crate) path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) css_project_index: Arc<CssProjectIndex>,
+    pub(crate) js_project_index: Arc<JsProjectIndex>,
     pub(crate) language: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
@@ -558,6 +583,7 @@ pub(crate) struct Features {
     json: JsonFileHandler,
     #[allow(unused)]
     css: CssFileHandler,
+    scss: ScssFileHandler,
     astro: AstroFileHandler,
     vue: VueFileHandler,
     svelte: SvelteFileHandler,
@@ -573,6 +599,7 @@ impl Features {
             js: JsFileHandler {},
             json: JsonFileHandler {},
             css: CssFileHandler {},
+            scss: ScssFileHandler {},
             astro: AstroFileHandler {},
             vue: VueFileHandler {},
             svelte: SvelteFileHandler {},
@@ -597,7 +624,13 @@ impl Features {
                 EmbeddingKind::None => self.js.capabilities(),
             },
             DocumentFileSource::Json(_) => self.json.capabilities(),
-            DocumentFileSource::Css(_) => self.css.capabilities(),
+            DocumentFileSource::Css(source) => {
+                if source.is_scss() {
+                    self.scss.capabilities()
+                } else {
+                    self.css.capabilities()
+                }
+            }
             DocumentFileSource::Graphql(_) => self.graphql.capabilities(),
             DocumentFileSource::Html(_) => self.html.capabilities(),
             DocumentFileSource::Grit(_) => self.grit.capabilities(),
@@ -0,0 +1,130 @@
+use crate::file_handlers::{
+    css, AnalyzerCapabilities, Capabilities, DebugCapabilities, ExtensionHandler,
+    FormatterCapabilities, ParseResult, ParserCapabilities, SearchCapabilities,
+};
+use crate::settings::{Settings, WorkspaceSettingsHandle};
+use crate::workspace::DocumentFileSource;
+use crate::WorkspaceError;
+use biome_css_parser::CssParse;
+use biome_css_syntax::CssSyntaxNode;
+use biome_formatter::Printed;
+use biome_fs::BiomePath;
+use biome_parser::AnyParse;
+use biome_rowan::{NodeCache, SyntaxKind, TextRange, TextSize};
+use tracing::debug;
+
+/// Handles the SCSS files.
+///
+/// The CSS parser doesn't support the SCSS syntax yet. SCSS files are parsed as CSS,
+/// with line comments and raw unknown at-rules, e.g. `@mixin` and `@include`, and the parts
+/// that aren't valid CSS end up in bogus nodes. The errors of the parser aren't reported,
+/// and the files that contain bogus nodes are printed verbatim by the formatter.
+///
+/// The linter isn't supported, since the CSS rules don't understand the SCSS syntax.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct ScssFileHandler;
+
+impl ExtensionHandler for ScssFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: None,
+            },
+            debug: DebugCapabilities {
+                debug_syntax_tree: Some(css::debug_syntax_tree),
+                debug_control_flow: None,
+                debug_formatter_ir: Some(css::debug_formatter_ir),
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: None,
+                code_actions: None,
+                rename: None,
+                fix_all: None,
+                organize_imports: None,
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: Some(format_range),
+                format_on_type: Some(format_on_type),
+            },
+            search: SearchCapabilities { search: None },
+        }
+    }
+}
+
+fn parse(
+    biome_path: &BiomePath,
+    _file_source: DocumentFileSource,
+    text: &str,
+    settings: Option<&Settings>,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let options = css::parser_options(biome_path, settings)
+        .allow_wrong_line_comments()
+        .allow_raw_unknown_at_rules();
+    let parse = biome_css_parser::parse_css_with_cache(text, cache, options);
+    if parse.has_errors() {
+        debug!("The SCSS file {biome_path:?} uses the SCSS syntax, it's kept as written");
+    }
+    ParseResult {
+        any_parse: CssParse::new(parse.syntax(), Vec::new()).into(),
+        language: None,
+    }
+}
+
+/// Returns `true` if the stylesheet contains syntax that the CSS parser doesn't support.
+fn has_scss_syntax(tree: &CssSyntaxNode) -> bool {
+    tree.descendants().any(|node| node.kind().is_bogus())
+}
+
+/// Prints the code of `range`, or of the whole stylesheet, as written.
+fn print_verbatim(tree: &CssSyntaxNode, range: Option<TextRange>) -> Printed {
+    let code = match range {
+        Some(range) => tree.text().slice(range).to_string(),
+        None => tree.to_string(),
+    };
+    let verbatim_range = TextRange::up_to(TextSize::of(code.as_str()));
+    Printed::new(code, range, Vec::new(), vec![verbatim_range])
+}
+
+fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let tree: CssSyntaxNode = parse.syntax();
+    if has_scss_syntax(&tree) {
+        return Ok(print_verbatim(&tree, None));
+    }
+    css::format(biome_path, document_file_source, parse, settings)
+}
+
+fn format_range(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+    range: TextRange,
+) -> Result<Printed, WorkspaceError> {
+    let tree: CssSyntaxNode = parse.syntax();
+    if has_scss_syntax(&tree) {
+        return Ok(print_verbatim(&tree, Some(range)));
+    }
+    css::format_range(biome_path, document_file_source, parse, settings, range)
+}
+
+fn format_on_type(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+    offset: TextSize,
+) -> Result<Printed, WorkspaceError> {
+    let tree: CssSyntaxNode = parse.syntax();
+    if has_scss_syntax(&tree) {
+        return Ok(print_verbatim(&tree, Some(tree.text_range())));
+    }
+    css::format_on_type(biome_path, document_file_source, parse, settings, offset)
+}
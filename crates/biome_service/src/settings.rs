@@ -33,7 +33,7 @@ use biome_js_syntax::{JsFileSource, JsLanguage};
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
-use biome_project::{NodeJsProject, PackageJson};
+use biome_project::{NodeJsProject, PackageJson, TsConfigJson};
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use indexmap::IndexSet;
 use rustc_hash::FxHashMap;
@@ -124,11 +124,24 @@ impl WorkspaceSettings {
         })
     }
 
-    pub fn insert_manifest(&mut self, manifest: NodeJsProject) {
+    /// Stores the `package.json` of the current project, keeping its `tsconfig.json`.
+    pub fn insert_manifest(&mut self, mut manifest: NodeJsProject) {
         let project_data = self.get_current_project_data_mut();
+        if let Some(previous) = project_data.project.take() {
+            manifest.tsconfig = previous.tsconfig;
+        }
         let _ = project_data.project.insert(manifest);
     }
 
+    /// Stores the `tsconfig.json` of the current project, keeping its `package.json`.
+    pub fn insert_tsconfig(&mut self, tsconfig: TsConfigJson) {
+        let project_data = self.get_current_project_data_mut();
+        project_data
+            .project
+            .get_or_insert_with(Default::default)
+            .tsconfig = tsconfig;
+    }
+
     /// Remove a project using its folder.
     pub fn remove_project(&mut self, workspace_path: &Path) {
         let keys_to_remove = {
@@ -629,6 +642,9 @@ impl From<PartialCssConfiguration> for LanguageSettings<CssLanguage> {
         if let Some(parser) = css.parser {
             language_setting.parser.allow_wrong_line_comments = parser.allow_wrong_line_comments;
             language_setting.parser.css_modules = parser.css_modules;
+            language_setting.parser.custom_media = parser.custom_media;
+            language_setting.parser.tailwind_directives = parser.tailwind_directives;
+            language_setting.parser.raw_unknown_at_rules = parser.raw_unknown_at_rules;
         }
         if let Some(formatter) = css.formatter {
             language_setting.formatter.enabled = formatter.enabled;
@@ -637,6 +653,17 @@ impl From<PartialCssConfiguration> for LanguageSettings<CssLanguage> {
             language_setting.formatter.line_width = formatter.line_width;
             language_setting.formatter.line_ending = formatter.line_ending;
             language_setting.formatter.quote_style = formatter.quote_style;
+            language_setting.formatter.collapse_single_declaration_rules =
+                formatter.collapse_single_declaration_rules;
+            language_setting.formatter.max_empty_lines = formatter.max_empty_lines;
+            language_setting.formatter.shorten_hex_colors = formatter.shorten_hex_colors;
+            language_setting.formatter.preserve_banner_comments =
+                formatter.preserve_banner_comments;
+            language_setting.formatter.reflow_comments = formatter.reflow_comments;
+            language_setting.formatter.shorten_hex_colors = formatter.shorten_hex_colors;
+            language_setting.formatter.preserve_banner_comments =
+                formatter.preserve_banner_comments;
+            language_setting.formatter.reflow_comments = formatter.reflow_comments;
         }
         if let Some(linter) = css.linter {
             language_setting.linter.enabled = linter.enabled;
@@ -1312,6 +1339,23 @@ impl OverrideSettingPattern {
         if let Some(quote_style) = css_formatter.quote_style {
             options.set_quote_style(quote_style);
         }
+        if let Some(collapse_single_declaration_rules) =
+            css_formatter.collapse_single_declaration_rules
+        {
+            options.set_collapse_single_declaration_rules(collapse_single_declaration_rules);
+        }
+        if let Some(max_empty_lines) = css_formatter.max_empty_lines {
+            options.set_max_empty_lines(max_empty_lines);
+        }
+        if let Some(shorten_hex_colors) = css_formatter.shorten_hex_colors {
+            options.set_shorten_hex_colors(shorten_hex_colors);
+        }
+        if let Some(preserve_banner_comments) = css_formatter.preserve_banner_comments {
+            options.set_preserve_banner_comments(preserve_banner_comments);
+        }
+        if let Some(reflow_comments) = css_formatter.reflow_comments {
+            options.set_reflow_comments(reflow_comments);
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_css_format_options.write() {
             let options = options.clone();
@@ -1464,6 +1508,15 @@ impl OverrideSettingPattern {
         if let Some(css_modules) = css_parser.css_modules {
             options.css_modules = css_modules;
         }
+        if let Some(custom_media) = css_parser.custom_media {
+            options.custom_media = custom_media;
+        }
+        if let Some(tailwind_directives) = css_parser.tailwind_directives {
+            options.tailwind_directives = tailwind_directives;
+        }
+        if let Some(raw_unknown_at_rules) = css_parser.raw_unknown_at_rules {
+            options.raw_unknown_at_rules = raw_unknown_at_rules;
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_css_parser_options.write() {
             let options = *options;
@@ -1697,6 +1750,9 @@ fn to_css_language_settings(
     language_setting.formatter.indent_width = formatter.indent_width.map(Into::into);
     language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
     language_setting.formatter.quote_style = formatter.quote_style;
+    language_setting.formatter.collapse_single_declaration_rules =
+        formatter.collapse_single_declaration_rules;
+    language_setting.formatter.max_empty_lines = formatter.max_empty_lines;
 
     let parser = conf.parser.take().unwrap_or_default();
     let parent_parser = &parent_settings.parser;
@@ -1704,6 +1760,13 @@ fn to_css_language_settings(
         .allow_wrong_line_comments
         .or(parent_parser.allow_wrong_line_comments);
     language_setting.parser.css_modules = parser.css_modules.or(parent_parser.css_modules);
+    language_setting.parser.custom_media = parser.custom_media.or(parent_parser.custom_media);
+    language_setting.parser.tailwind_directives = parser
+        .tailwind_directives
+        .or(parent_parser.tailwind_directives);
+    language_setting.parser.raw_unknown_at_rules = parser
+        .raw_unknown_at_rules
+        .or(parent_parser.raw_unknown_at_rules);
 
     language_setting
 }
@@ -588,6 +588,9 @@ pub struct ChangeFileParams {
     pub path: BiomePath,
     pub content: String,
     pub version: i32,
+    /// The range of the previous content replaced by the new content, when the change is a single edit.
+    /// It lets the workspace reparse only the part of the file touched by the edit.
+    pub changed_range: Option<TextRange>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -852,6 +855,19 @@ pub struct IsPathIgnoredParams {
     pub features: FeatureName,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IsPathIndexedParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SetProjectIndexedParams {
+    /// Whether all the files of the project that the workspace indexes have been opened
+    pub indexed: bool,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
@@ -885,6 +901,18 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// If the file path matches, then `true` is returned, and it should be considered ignored.
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError>;
 
+    /// Checks if the file at the given path is indexed when it's opened,
+    /// because a rule that looks across files is enabled for it.
+    ///
+    /// Files that aren't indexed don't need to be opened ahead of their analysis.
+    fn is_path_indexed(&self, params: IsPathIndexedParams) -> Result<bool, WorkspaceError>;
+
+    /// Tells the workspace whether all the files of the project that it indexes have been opened.
+    ///
+    /// The rules that report what no file of the project uses, or a file that the project doesn't have,
+    /// don't report anything until then.
+    fn set_project_indexed(&self, params: SetProjectIndexedParams) -> Result<(), WorkspaceError>;
+
     /// Update the global settings for this workspace
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError>;
 
@@ -1039,6 +1067,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             path: self.path.clone(),
             version,
             content,
+            changed_range: None,
         })
     }
 
@@ -1,7 +1,8 @@
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, ProjectKey, RageParams, RageResult, RegisterProjectFolderParams,
-    ServerInfo, SetManifestForProjectParams, UnregisterProjectFolderParams,
+    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, IsPathIndexedParams,
+    OrganizeImportsParams, OrganizeImportsResult, ProjectKey, RageParams, RageResult,
+    RegisterProjectFolderParams, ServerInfo, SetManifestForProjectParams, SetProjectIndexedParams,
+    UnregisterProjectFolderParams,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -110,6 +111,12 @@ where
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError> {
         self.request("biome/is_path_ignored", params)
     }
+    fn is_path_indexed(&self, params: IsPathIndexedParams) -> Result<bool, WorkspaceError> {
+        self.request("biome/is_path_indexed", params)
+    }
+    fn set_project_indexed(&self, params: SetProjectIndexedParams) -> Result<(), WorkspaceError> {
+        self.request("biome/set_project_indexed", params)
+    }
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
         self.request("biome/update_settings", params)
     }
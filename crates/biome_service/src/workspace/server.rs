@@ -11,21 +11,30 @@ use crate::diagnostics::{InvalidPattern, SearchError};
 use crate::file_handlers::{
     Capabilities, CodeActionsParams, DocumentFileSource, FixAllParams, LintParams, ParseResult,
 };
-use crate::settings::{WorkspaceSettings, WorkspaceSettingsHandleMut};
+use crate::settings::{Settings, WorkspaceSettings, WorkspaceSettingsHandleMut};
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
+    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, IsPathIndexedParams,
+    OrganizeImportsParams, OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
+    SetProjectIndexedParams,
 };
 use crate::{
     file_handlers::Features, settings::WorkspaceSettingsHandle, Workspace, WorkspaceError,
 };
+use biome_analyze::RuleFilter;
 use biome_configuration::DEFAULT_FILE_SIZE_LIMIT;
+use biome_css_analyze::project::{
+    CssFileSummary, CssProjectIndex, PROJECT_RULES as CSS_PROJECT_RULES,
+};
 use biome_diagnostics::{
     serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
 };
 use biome_formatter::Printed;
 use biome_fs::{BiomePath, ConfigName};
 use biome_grit_patterns::GritQuery;
+use biome_js_analyze::graphql::GraphqlSchema;
+use biome_js_analyze::project::{
+    JsModuleSummary, JsProjectIndex, PROJECT_RULES as JS_PROJECT_RULES,
+};
 use biome_js_syntax::ModuleKind;
 use biome_json_parser::{parse_json_with_cache, JsonParserOptions};
 use biome_json_syntax::JsonFileSource;
@@ -38,6 +47,7 @@ use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::{panic::RefUnwindSafe, sync::RwLock};
 use tracing::{debug, info, info_span};
 
@@ -56,6 +66,12 @@ pub(super) struct WorkspaceServer {
     file_sources: RwLock<IndexSet<DocumentFileSource>>,
     /// Stores patterns to search for.
     patterns: DashMap<PatternId, GritQuery>,
+    /// Stores the summaries of the stylesheets opened in the workspace,
+    /// shared with the CSS analyzer
+    css_project_index: Arc<CssProjectIndex>,
+    /// Stores the summaries of the modules opened in the workspace,
+    /// shared with the JavaScript analyzer
+    js_project_index: Arc<JsProjectIndex>,
 }
 
 /// The `Workspace` object is long-lived, so we want it to be able to cross
@@ -91,6 +107,8 @@ impl WorkspaceServer {
             current_project_path: RwLock::default(),
             file_sources: RwLock::default(),
             patterns: Default::default(),
+            css_project_index: Default::default(),
+            js_project_index: Default::default(),
         }
     }
 
@@ -183,20 +201,41 @@ impl WorkspaceServer {
     }
 
     /// Updates the manifest for the current project. Given the manifest path, the function will try to parse the manifest and update the current project.
+    ///
+    /// The manifest is either a `package.json`, or a `tsconfig.json`/`jsconfig.json` whose path aliases
+    /// are shared with the JavaScript analyzer.
     fn register_manifest_for_project(&self, manifest_path: BiomePath) {
         let mut workspace = self.workspaces_mut();
         let workspace_mut = workspace.as_mut();
         let mut document = self.documents.get_mut(&manifest_path);
         if let Some(document) = document.as_deref_mut() {
+            let is_tsconfig = matches!(
+                manifest_path.file_name().and_then(OsStr::to_str),
+                Some("tsconfig.json" | "jsconfig.json")
+            );
+            let parser_options = if is_tsconfig {
+                JsonParserOptions::default()
+                    .with_allow_comments()
+                    .with_allow_trailing_commas()
+            } else {
+                JsonParserOptions::default()
+            };
             let parsed = parse_json_with_cache(
                 document.content.as_str(),
                 &mut document.node_cache,
-                JsonParserOptions::default(),
+                parser_options,
             );
 
             let mut node_js_project = NodeJsProject::default();
-            node_js_project.deserialize_manifest(&parsed.tree());
-            workspace_mut.insert_manifest(node_js_project);
+            if is_tsconfig {
+                node_js_project.deserialize_tsconfig(&parsed.tree());
+                self.js_project_index
+                    .set_tsconfig(&manifest_path, &node_js_project.tsconfig);
+                workspace_mut.insert_tsconfig(node_js_project.tsconfig);
+            } else {
+                node_js_project.deserialize_manifest(&parsed.tree());
+                workspace_mut.insert_manifest(node_js_project);
+            }
         }
     }
 
@@ -236,13 +275,7 @@ impl WorkspaceServer {
                     .parse
                     .ok_or_else(self.build_capability_error(biome_path))?;
 
-                let size_limit = {
-                    let workspace = self.workspace();
-                    let settings = workspace.settings();
-                    let limit =
-                        settings.map_or(DEFAULT_FILE_SIZE_LIMIT.get(), |s| s.files.max_size.get());
-                    usize::try_from(limit).unwrap_or(usize::MAX)
-                };
+                let size_limit = self.file_size_limit();
 
                 let document = &mut *document;
                 let size = document.content.as_bytes().len();
@@ -273,11 +306,78 @@ impl WorkspaceServer {
                 if let Some(language) = language {
                     document.file_source_index = self.set_source(language);
                 }
+                self.index_parse(
+                    biome_path,
+                    self.get_source(document.file_source_index),
+                    &any_parse,
+                    settings,
+                );
                 Ok(entry.insert(any_parse).clone())
             }
         }
     }
 
+    /// Returns the maximum size of the files that the workspace parses.
+    fn file_size_limit(&self) -> usize {
+        let workspace = self.workspace();
+        let settings = workspace.settings();
+        let limit = settings.map_or(DEFAULT_FILE_SIZE_LIMIT.get(), |s| s.files.max_size.get());
+        usize::try_from(limit).unwrap_or(usize::MAX)
+    }
+
+    /// Adds the summary of the parsed file at `path` to the project indexes that need it.
+    fn index_parse(
+        &self,
+        path: &BiomePath,
+        file_source: Option<DocumentFileSource>,
+        parse: &AnyParse,
+        settings: Option<&Settings>,
+    ) {
+        match file_source {
+            Some(DocumentFileSource::Css(_)) if is_css_project_index_needed(settings, path) => {
+                self.css_project_index
+                    .insert(path.to_path_buf(), CssFileSummary::from_root(&parse.tree()));
+            }
+            Some(DocumentFileSource::Js(_)) if is_js_project_index_needed(settings, path) => {
+                self.js_project_index
+                    .insert(path, JsModuleSummary::from_root(&parse.tree()));
+            }
+            Some(DocumentFileSource::Graphql(_)) if is_js_project_index_needed(settings, path) => {
+                self.js_project_index
+                    .insert_graphql_schema(path, GraphqlSchema::from_root(&parse.tree()));
+            }
+            _ => {}
+        }
+    }
+
+    /// Updates the summary of the file at `path` in the project indexes with its current content,
+    /// so the rules that look across files never see an outdated version of the file.
+    ///
+    /// The file is removed from the indexes when it can't be parsed anymore, e.g. because it's too large.
+    fn update_indexes(&self, path: &BiomePath) {
+        let source = self.get_file_source(path);
+        if self.is_indexed(path, &source) && self.get_parse(path.clone()).is_err() {
+            self.css_project_index.remove(path);
+            self.js_project_index.remove(path);
+        }
+    }
+
+    /// Returns `true` if the file at `path` is indexed as soon as it's opened.
+    ///
+    /// Files are indexed only when a rule that looks across files is enabled,
+    /// since parsing them ahead of time isn't free.
+    fn is_indexed(&self, path: &Path, source: &DocumentFileSource) -> bool {
+        let workspace = self.workspace();
+        let settings = workspace.settings();
+        match source {
+            DocumentFileSource::Css(_) => is_css_project_index_needed(settings, path),
+            DocumentFileSource::Js(_) | DocumentFileSource::Graphql(_) => {
+                is_js_project_index_needed(settings, path)
+            }
+            _ => false,
+        }
+    }
+
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
     /// or in the feature `ignore`/`include`
     fn is_ignored(&self, path: &Path, features: FeatureName) -> bool {
@@ -413,6 +513,15 @@ impl Workspace for WorkspaceServer {
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError> {
         Ok(self.is_ignored(params.biome_path.as_path(), params.features))
     }
+    fn is_path_indexed(&self, params: IsPathIndexedParams) -> Result<bool, WorkspaceError> {
+        let source = DocumentFileSource::from_path(&params.path);
+        Ok(self.is_indexed(&params.path, &source))
+    }
+    fn set_project_indexed(&self, params: SetProjectIndexedParams) -> Result<(), WorkspaceError> {
+        self.css_project_index.set_complete(params.indexed);
+        self.js_project_index.set_complete(params.indexed);
+        Ok(())
+    }
     /// Update the global settings for this workspace
     ///
     /// ## Panics
@@ -464,6 +573,14 @@ impl Workspace for WorkspaceServer {
             self.set_current_project(project_key);
         }
 
+        // Stylesheets, modules, and GraphQL schemas are indexed as soon as
+        // they're opened, so the rules that look across files know about them
+        // before they're analyzed.
+        if self.is_indexed(&params.path, &source) {
+            // Parsing errors are reported when the file is analyzed
+            let _ = self.get_parse(params.path);
+        }
+
         Ok(())
     }
     fn set_manifest_for_project(
@@ -587,6 +704,9 @@ impl Workspace for WorkspaceServer {
 
     /// Change the content of an open file
     fn change_file(&self, params: ChangeFileParams) -> Result<(), WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let previous = self.syntax.remove(&params.path).map(|(_, parse)| parse);
+
         {
             let mut document = self
                 .documents
@@ -596,14 +716,45 @@ impl Workspace for WorkspaceServer {
             debug_assert!(params.version > document.version);
             document.version = params.version;
             document.content = params.content;
+
+            // Reparse only the part of the file touched by the edit. Otherwise, the file
+            // is parsed from scratch the next time its syntax tree is requested.
+            if let (Some(reparse), Some(previous), Some(changed_range)) =
+                (capabilities.parser.reparse, previous, params.changed_range)
+            {
+                if document.content.len() < self.file_size_limit() {
+                    let workspace = self.workspace();
+                    let settings = workspace.settings();
+                    let document = &mut *document;
+                    let parse = reparse(
+                        &params.path,
+                        &previous,
+                        &document.content,
+                        changed_range,
+                        settings,
+                        &mut document.node_cache,
+                    );
+                    self.index_parse(
+                        &params.path,
+                        self.get_source(document.file_source_index),
+                        &parse,
+                        settings,
+                    );
+                    self.syntax.insert(params.path.clone(), parse);
+                }
+            }
         }
 
-        self.syntax.remove(&params.path);
+        self.update_indexes(&params.path);
         Ok(())
     }
 
     /// Remove a file from the workspace
+    ///
+    /// The summary of the file is kept in the project indexes,
+    /// because the rules that look across files still need it when they analyze the other files.
     fn close_file(&self, params: CloseFileParams) -> Result<(), WorkspaceError> {
+        self.update_indexes(&params.path);
         self.documents
             .remove(&params.path)
             .ok_or_else(WorkspaceError::not_found)?;
@@ -633,6 +784,8 @@ impl Workspace for WorkspaceServer {
                         language: self.get_file_source(&params.path),
                         categories: params.categories,
                         manifest,
+                        css_project_index: self.css_project_index.clone(),
+                        js_project_index: self.js_project_index.clone(),
                         suppression_reason: None,
                     });
 
@@ -686,6 +839,8 @@ impl Workspace for WorkspaceServer {
             workspace: &workspace,
             path: &params.path,
             manifest,
+            css_project_index: self.css_project_index.clone(),
+            js_project_index: self.js_project_index.clone(),
             language,
             only: params.only,
             skip: params.skip,
@@ -785,6 +940,8 @@ impl Workspace for WorkspaceServer {
             should_format: params.should_format,
             biome_path: &params.path,
             manifest,
+            css_project_index: self.css_project_index.clone(),
+            js_project_index: self.js_project_index.clone(),
             document_file_source: language,
             only: params.only,
             skip: params.skip,
@@ -899,3 +1056,24 @@ fn make_search_pattern_id() -> PatternId {
     let counter = COUNTER.fetch_add(1, Ordering::AcqRel);
     format!("p{counter}").into()
 }
+
+/// Returns `true` if a rule that queries the [CssProjectIndex] is enabled for the stylesheet at `path`.
+fn is_css_project_index_needed(settings: Option<&Settings>, path: &Path) -> bool {
+    is_any_rule_enabled(settings, path, CSS_PROJECT_RULES)
+}
+
+/// Returns `true` if a rule that queries the [JsProjectIndex] is enabled for the module at `path`.
+fn is_js_project_index_needed(settings: Option<&Settings>, path: &Path) -> bool {
+    is_any_rule_enabled(settings, path, JS_PROJECT_RULES)
+}
+
+/// Returns `true` if the linter is enabled for the file at `path`, with at least one of `rules`.
+fn is_any_rule_enabled(settings: Option<&Settings>, path: &Path, rules: &[RuleFilter]) -> bool {
+    settings.is_some_and(|settings| {
+        settings.linter().enabled
+            && settings.as_linter_rules(path).is_some_and(|linter_rules| {
+                let enabled_rules = linter_rules.as_enabled_rules();
+                rules.iter().any(|rule| enabled_rules.contains(rule))
+            })
+    })
+}
@@ -2,11 +2,15 @@
 mod test {
     use biome_analyze::RuleCategories;
     use biome_configuration::analyzer::{RuleGroup, RuleSelector};
+    use biome_configuration::PartialConfiguration;
+    use biome_deserialize::json::deserialize_from_json_str;
     use biome_fs::BiomePath;
     use biome_js_syntax::{JsFileSource, TextSize};
+    use biome_json_parser::JsonParserOptions;
     use biome_service::file_handlers::DocumentFileSource;
     use biome_service::workspace::{
-        server, FileGuard, OpenFileParams, RegisterProjectFolderParams,
+        server, FileGuard, OpenFileParams, RegisterProjectFolderParams, SetProjectIndexedParams,
+        UpdateSettingsParams,
     };
     use biome_service::Workspace;
     fn create_server() -> Box<dyn Workspace> {
@@ -272,4 +276,141 @@ type User {
 
         assert!(syntax.starts_with("GritRoot"))
     }
+
+    #[test]
+    fn updates_the_project_index_when_a_file_changes() {
+        let workspace = create_server();
+        let configuration = deserialize_from_json_str::<PartialConfiguration>(
+            r#"{
+  "css": { "linter": { "enabled": true } },
+  "linter": { "rules": { "nursery": { "noUnusedCustomProperties": "error" } } }
+}"#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                configuration,
+                vcs_base_path: None,
+                gitignore_matches: vec![],
+                workspace_directory: None,
+            })
+            .unwrap();
+
+        let theme_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("theme.css"),
+                content: ":root { --brand-color: rebeccapurple; }".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let button_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("button.css"),
+                content: ".button { color: var(--brand-color); }".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        workspace
+            .set_project_indexed(SetProjectIndexedParams { indexed: true })
+            .unwrap();
+        let pull_theme_diagnostics = || {
+            theme_file
+                .pull_diagnostics(
+                    RuleCategories::all(),
+                    10,
+                    vec![RuleSelector::Rule(
+                        RuleGroup::Nursery,
+                        "noUnusedCustomProperties",
+                    )],
+                    vec![],
+                )
+                .unwrap()
+                .diagnostics
+        };
+        assert_eq!(pull_theme_diagnostics().len(), 0);
+
+        button_file
+            .change_file(1, ".button { color: rebeccapurple; }".into())
+            .unwrap();
+        assert_eq!(pull_theme_diagnostics().len(), 1);
+
+        button_file
+            .change_file(2, ".button { color: var(--brand-color); }".into())
+            .unwrap();
+        assert_eq!(pull_theme_diagnostics().len(), 0);
+    }
+
+    #[test]
+    fn resolves_the_imports_again_when_a_module_changes() {
+        let workspace = create_server();
+        let configuration = deserialize_from_json_str::<PartialConfiguration>(
+            r#"{
+  "linter": { "rules": { "nursery": { "noUnusedExports": "error" } } }
+}"#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                configuration,
+                vcs_base_path: None,
+                gitignore_matches: vec![],
+                workspace_directory: None,
+            })
+            .unwrap();
+
+        let utils_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("utils.js"),
+                content: "export function sum(a, b) { return a + b; }".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let main_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("main.js"),
+                content: r#"import { sum } from "./utils.js"; sum(1, 2);"#.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let pull_utils_diagnostics = || {
+            utils_file
+                .pull_diagnostics(
+                    RuleCategories::all(),
+                    10,
+                    vec![RuleSelector::Rule(RuleGroup::Nursery, "noUnusedExports")],
+                    vec![],
+                )
+                .unwrap()
+                .diagnostics
+        };
+        assert_eq!(pull_utils_diagnostics().len(), 0);
+
+        main_file
+            .change_file(1, "console.log(1 + 2);".into())
+            .unwrap();
+        assert_eq!(pull_utils_diagnostics().len(), 1);
+
+        main_file
+            .change_file(2, r#"import { sum } from "./utils.js"; sum(1, 2);"#.into())
+            .unwrap();
+        assert_eq!(pull_utils_diagnostics().len(), 0);
+    }
 }
@@ -352,6 +352,10 @@ export interface PartialCssAssists {
  * Options that changes how the CSS formatter behaves
  */
 export interface PartialCssFormatter {
+	/**
+	 * Whether to print the rules and at-rules that contain a single declaration on a single line, such as `a { color: red; }`. Defaults to false.
+	 */
+	collapseSingleDeclarationRules?: boolean;
 	/**
 	 * Control the formatter for CSS (and its super languages) files.
 	 */
@@ -372,10 +376,26 @@ export interface PartialCssFormatter {
 	 * What's the max width of a line applied to CSS (and its super languages) files. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * The maximum number of consecutive empty lines preserved between rules, at-rules, and declarations. Defaults to 1.
+	 */
+	maxEmptyLines?: number;
+	/**
+	 * Whether to print the banner comments as written, without aligning or reflowing them. The banner comments are the comment at the start of the file, and the comments that start with `/*!`. Defaults to false.
+	 */
+	preserveBannerComments?: boolean;
 	/**
 	 * The type of quotes used in CSS code. Defaults to double.
 	 */
 	quoteStyle?: QuoteStyle;
+	/**
+	 * Whether to wrap the single-line block comments that don't fit in the line width over multiple lines. Defaults to false.
+	 */
+	reflowComments?: boolean;
+	/**
+	 * Whether to shorten the hex colors that have a short form, such as `#ffffff` to `#fff`. The hex colors are always printed in lowercase. Defaults to false.
+	 */
+	shortenHexColors?: boolean;
 }
 /**
  * Options that changes how the CSS linter behaves
@@ -398,6 +418,18 @@ export interface PartialCssParser {
 	 * Enables parsing of CSS Modules specific features.
 	 */
 	cssModules?: boolean;
+	/**
+	 * Enables parsing of the `@custom-media` at-rule.
+	 */
+	customMedia?: boolean;
+	/**
+	 * Keeps the block of unknown at-rules as written instead of parsing it as CSS.
+	 */
+	rawUnknownAtRules?: boolean;
+	/**
+	 * Enables parsing of the Tailwind CSS directives, such as `@tailwind` and `@apply`.
+	 */
+	tailwindDirectives?: boolean;
 }
 export type AttributePosition = "auto" | "multiline";
 export type BracketSpacing = boolean;
@@ -693,6 +725,10 @@ export interface Source {
 	 * Sorts the keys of a JSON object in natural order
 	 */
 	useSortedKeys?: RuleAssistConfiguration_for_Null;
+	/**
+	 * Sort the declarations of a block.
+	 */
+	useSortedProperties?: RuleAssistConfiguration_for_UseSortedPropertiesOptions;
 }
 export type QuoteStyle = "double" | "single";
 export type ArrowParentheses = "always" | "asNeeded";
@@ -1168,7 +1204,7 @@ export interface Correctness {
 	/**
 	 * Disallow unused private class members
 	 */
-	noUnusedPrivateClassMembers?: RuleFixConfiguration_for_Null;
+	noUnusedPrivateClassMembers?: RuleFixConfiguration_for_NoUnusedPrivateClassMembersOptions;
 	/**
 	 * Disallow unused variables.
 	 */
@@ -1226,10 +1262,50 @@ export interface Nursery {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * Enforce a maximum depth for the nested rules.
+	 */
+	maxNestingDepth?: RuleConfiguration_for_MaxNestingDepthOptions;
+	/**
+	 * Enforce that z-index values come from a scale.
+	 */
+	noArbitraryZIndex?: RuleConfiguration_for_NoArbitraryZIndexOptions;
+	/**
+	 * Disallow await inside of loops.
+	 */
+	noAwaitInLoop?: RuleConfiguration_for_NoAwaitInLoopOptions;
+	/**
+	 * Disallow the imports that form a cycle between modules.
+	 */
+	noCircularImports?: RuleConfiguration_for_NoCircularImportsOptions;
+	/**
+	 * Disallow commented-out code.
+	 */
+	noCommentedOutCode?: RuleConfiguration_for_NoCommentedOutCodeOptions;
 	/**
 	 * Disallow use of CommonJs module system in favor of ESM style imports.
 	 */
 	noCommonJs?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow calling expect conditionally.
+	 */
+	noConditionalExpect?: RuleConfiguration_for_NoConditionalExpectOptions;
+	/**
+	 * Disallow references to undeclared custom properties that look like typos.
+	 */
+	noCustomPropertyTypos?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the relative imports that go through too many parent directories.
+	 */
+	noDeepRelativeImports?: RuleConfiguration_for_NoDeepRelativeImportsOptions;
+	/**
+	 * Disallow the use of declarations marked as deprecated with the JSDoc tag @deprecated.
+	 */
+	noDeprecatedApis?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow deprecated and nonstandard properties and values.
+	 */
+	noDeprecatedProperties?: RuleFixConfiguration_for_Null;
 	/**
 	 * Disallow a lower specificity selector from coming after a higher specificity selector.
 	 */
@@ -1254,6 +1330,14 @@ export interface Nursery {
 	 * Disallow duplicate properties within declaration blocks.
 	 */
 	noDuplicateProperties?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow style rules whose selector is already used in another stylesheet.
+	 */
+	noDuplicateSelectorsAcrossFiles?: RuleConfiguration_for_NoDuplicateSelectorsAcrossFilesOptions;
+	/**
+	 * Disallow tests with the same title in the same `describe` block.
+	 */
+	noDuplicateTestTitles?: RuleConfiguration_for_Null;
 	/**
 	 * No duplicated fields in GraphQL operations.
 	 */
@@ -1270,10 +1354,22 @@ export interface Nursery {
 	 * Disallow exporting an imported variable.
 	 */
 	noExportedImports?: RuleConfiguration_for_Null;
+	/**
+	 * Require the promises created in a statement to be awaited or handled.
+	 */
+	noFloatingPromises?: RuleFixConfiguration_for_NoFloatingPromisesOptions;
 	/**
 	 * Disallow the use of __dirname and __filename in the global scope.
 	 */
 	noGlobalDirnameFilename?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow hardcoded colors in favor of the custom properties of a design system.
+	 */
+	noHardcodedColors?: RuleConfiguration_for_NoHardcodedColorsOptions;
+	/**
+	 * Disallow the strings displayed to the user that aren't passed to a translation function.
+	 */
+	noHardcodedUserFacingStrings?: RuleConfiguration_for_NoHardcodedUserFacingStringsOptions;
 	/**
 	 * Prevent usage of \<head> element in a Next.js project.
 	 */
@@ -1282,6 +1378,14 @@ export interface Nursery {
 	 * Prevent using the next/head module in pages/_document.js on Next.js projects.
 	 */
 	noHeadImportInDocument?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow selectors whose specificity is higher than a maximum.
+	 */
+	noHighSpecificitySelectors?: RuleConfiguration_for_NoHighSpecificitySelectorsOptions;
+	/**
+	 * Disallow ID selectors.
+	 */
+	noIdSelectors?: RuleConfiguration_for_NoIdSelectorsOptions;
 	/**
 	 * Prevent usage of \<img> element in a Next.js project.
 	 */
@@ -1290,14 +1394,34 @@ export interface Nursery {
 	 * Disallows the use of irregular whitespace characters.
 	 */
 	noIrregularWhitespace?: RuleConfiguration_for_Null;
+	/**
+	 * Require the event listeners added by an effect or by a subscription to be removed by its cleanup function.
+	 */
+	noLeakedEventListeners?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow text and background colors with an insufficient contrast.
+	 */
+	noLowContrastColorPairs?: RuleConfiguration_for_NoLowContrastColorPairsOptions;
+	/**
+	 * Disallow magic numbers.
+	 */
+	noMagicNumbers?: RuleConfiguration_for_NoMagicNumbersOptions;
 	/**
 	 * Disallow missing var function for css variables.
 	 */
 	noMissingVarFunction?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow promises where they aren't expected.
+	 */
+	noMisusedPromises?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the statements with side effects at the top level of a module.
+	 */
+	noModuleScopeSideEffects?: RuleConfiguration_for_NoModuleScopeSideEffectsOptions;
 	/**
 	 * Disallow nested ternary expressions.
 	 */
-	noNestedTernary?: RuleConfiguration_for_Null;
+	noNestedTernary?: RuleConfiguration_for_NoNestedTernaryOptions;
 	/**
 	 * Disallow octal escape sequences in string literals
 	 */
@@ -1305,7 +1429,7 @@ export interface Nursery {
 	/**
 	 * Disallow the use of process.env.
 	 */
-	noProcessEnv?: RuleConfiguration_for_Null;
+	noProcessEnv?: RuleConfiguration_for_NoProcessEnvOptions;
 	/**
 	 * Disallow the use of process global.
 	 */
@@ -1314,6 +1438,10 @@ export interface Nursery {
 	 * Disallow specified modules when loaded by import or require.
 	 */
 	noRestrictedImports?: RuleConfiguration_for_RestrictedImportsOptions;
+	/**
+	 * Disallow the code matched by the configured patterns.
+	 */
+	noRestrictedSyntax?: RuleConfiguration_for_NoRestrictedSyntaxOptions;
 	/**
 	 * Disallow user defined types.
 	 */
@@ -1322,6 +1450,10 @@ export interface Nursery {
 	 * Disallow usage of sensitive data such as API keys and tokens.
 	 */
 	noSecrets?: RuleConfiguration_for_NoSecretsOptions;
+	/**
+	 * Disallow building the queries passed to a database with template literals that interpolate values.
+	 */
+	noSqlInjection?: RuleConfiguration_for_NoSqlInjectionOptions;
 	/**
 	 * Enforce that static, visible elements (such as \<div>) that have click handlers use the valid role attribute.
 	 */
@@ -1330,14 +1462,38 @@ export interface Nursery {
 	 * Enforce the use of String.slice() over String.substr() and String.substring().
 	 */
 	noSubstr?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow the regular expressions that can take super-linear time to match some strings.
+	 */
+	noSuperLinearRegex?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the synchronous file system and child process functions in async functions and request handlers.
+	 */
+	noSyncFsInAsyncContexts?: RuleConfiguration_for_NoSyncFsInAsyncContextsOptions;
 	/**
 	 * Disallow template literal placeholder syntax in regular strings.
 	 */
 	noTemplateCurlyInString?: RuleConfiguration_for_Null;
+	/**
+	 * Require the TODO and FIXME comments to reference a ticket.
+	 */
+	noTodoWithoutTicket?: RuleConfiguration_for_NoTodoWithoutTicketOptions;
+	/**
+	 * Disallow transitions of all the properties.
+	 */
+	noTransitionAll?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow animation names that don't match any @keyframes.
+	 */
+	noUnknownAnimationName?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unknown at-rules.
 	 */
 	noUnknownAtRule?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow unknown size feature names in container queries.
+	 */
+	noUnknownContainerFeatureName?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unknown pseudo-class selectors.
 	 */
@@ -1350,6 +1506,34 @@ export interface Nursery {
 	 * Disallow unknown type selectors.
 	 */
 	noUnknownTypeSelector?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow conditions that are always truthy or always falsy according to the types.
+	 */
+	noUnnecessaryConditions?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow type assertions that bypass the type checking.
+	 */
+	noUnsafeTypeAssertions?: RuleConfiguration_for_NoUnsafeTypeAssertionsOptions;
+	/**
+	 * Disallow inserting HTML that isn't sanitized into the document.
+	 */
+	noUnsanitizedDomInsertion?: RuleConfiguration_for_NoUnsanitizedDomInsertionOptions;
+	/**
+	 * Disallow the object, array, and function literals as default values of the props of a component, when the props are dependencies of a hook.
+	 */
+	noUnstableDefaultProps?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow custom properties that are never read.
+	 */
+	noUnusedCustomProperties?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow exports that no module of the project imports.
+	 */
+	noUnusedExports?: RuleConfiguration_for_NoUnusedExportsOptions;
+	/**
+	 * Disallow @keyframes that are never used by an animation.
+	 */
+	noUnusedKeyframes?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unnecessary escape sequence in regular expression literals.
 	 */
@@ -1366,6 +1550,10 @@ export interface Nursery {
 	 * Disallow use of @value rule in css modules.
 	 */
 	noValueAtRule?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow vendor prefixes that aren't needed by the target browsers.
+	 */
+	noVendorPrefixes?: RuleFixConfiguration_for_NoVendorPrefixesOptions;
 	/**
 	 * It enables the recommended rules for this group
 	 */
@@ -1374,6 +1562,10 @@ export interface Nursery {
 	 * Disallow the use of overload signatures that are not next to each other.
 	 */
 	useAdjacentOverloadSignatures?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the units allowed for each property.
+	 */
+	useAllowedUnits?: RuleConfiguration_for_UseAllowedUnitsOptions;
 	/**
 	 * Enforce that ARIA properties are valid for the roles that are supported by the element.
 	 */
@@ -1382,6 +1574,14 @@ export interface Nursery {
 	 * Use at() instead of integer index access.
 	 */
 	useAtIndex?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow CSS features that aren't supported by the target browsers.
+	 */
+	useBrowserCompat?: RuleConfiguration_for_UseBrowserCompatOptions;
+	/**
+	 * Enforce a naming convention for the class selectors.
+	 */
+	useClassNamingConvention?: RuleConfiguration_for_UseClassNamingConventionOptions;
 	/**
 	 * Enforce using single if instead of nested if clauses.
 	 */
@@ -1390,6 +1590,10 @@ export interface Nursery {
 	 * Enforce declaring components only within modules that export React Components exclusively.
 	 */
 	useComponentExportOnlyModules?: RuleConfiguration_for_UseComponentExportOnlyModulesOptions;
+	/**
+	 * Enforce a consistent format for the colors.
+	 */
+	useConsistentColorFormat?: RuleFixConfiguration_for_UseConsistentColorFormatOptions;
 	/**
 	 * This rule enforces consistent use of curly braces inside JSX attributes and JSX children.
 	 */
@@ -1398,10 +1602,26 @@ export interface Nursery {
 	 * Require consistent accessibility modifiers on class properties and methods.
 	 */
 	useConsistentMemberAccessibility?: RuleConfiguration_for_ConsistentMemberAccessibilityOptions;
+	/**
+	 * Enforce a consistent convention for the titles of the tests.
+	 */
+	useConsistentTestNaming?: RuleFixConfiguration_for_UseConsistentTestNamingOptions;
 	/**
 	 * Require specifying the reason argument when using @deprecated directive
 	 */
 	useDeprecatedReason?: RuleConfiguration_for_Null;
+	/**
+	 * Require the caught error to be preserved as the cause of the error thrown in a catch clause.
+	 */
+	useErrorCause?: RuleFixConfiguration_for_Null;
+	/**
+	 * Require the switch statements over a union of literal types or over an enum to handle every case.
+	 */
+	useExhaustiveSwitchCases?: RuleFixConfiguration_for_Null;
+	/**
+	 * Prefer `using` declarations to disposing of resources in a `finally` clause.
+	 */
+	useExplicitResourceManagement?: RuleFixConfiguration_for_UseExplicitResourceManagementOptions;
 	/**
 	 * Require explicit return types on functions and class methods.
 	 */
@@ -1410,6 +1630,10 @@ export interface Nursery {
 	 * Require that all exports are declared after all non-export statements.
 	 */
 	useExportsLast?: RuleConfiguration_for_Null;
+	/**
+	 * Require font-family declarations to end with a generic font family.
+	 */
+	useFontFamilyFallbacks?: RuleConfiguration_for_UseFontFamilyFallbacksOptions;
 	/**
 	 * Enforces the use of a recommended display strategy with Google Fonts.
 	 */
@@ -1422,10 +1646,30 @@ export interface Nursery {
 	 * Require for-in loops to include an if statement.
 	 */
 	useGuardForIn?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the use of the path aliases of the project instead of deep relative imports.
+	 */
+	useImportAliases?: RuleFixConfiguration_for_UseImportAliasesOptions;
 	/**
 	 * Disallows package private imports.
 	 */
 	useImportRestrictions?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce that the JSDoc comments of the functions match their signatures.
+	 */
+	useJsDocConsistency?: RuleFixConfiguration_for_Null;
+	/**
+	 * Enforce the use of logical properties and values instead of physical ones.
+	 */
+	useLogicalProperties?: RuleFixConfiguration_for_UseLogicalPropertiesOptions;
+	/**
+	 * Enforce a maximum size of the functions.
+	 */
+	useMaxFunctionSize?: RuleConfiguration_for_UseMaxFunctionSizeOptions;
+	/**
+	 * Enforce a maximum number of parameters in the function signatures.
+	 */
+	useMaxParams?: RuleConfiguration_for_UseMaxParamsOptions;
 	/**
 	 * Enforce specifying the name of GraphQL operations.
 	 */
@@ -1434,10 +1678,38 @@ export interface Nursery {
 	 * Validates that all enum values are capitalized.
 	 */
 	useNamingConvention?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the use of numeric separators in long numeric literals.
+	 */
+	useNumericSeparators?: RuleFixConfiguration_for_UseNumericSeparatorsOptions;
 	/**
 	 * Enforce the consistent use of the radix argument when using parseInt().
 	 */
 	useParseIntRadix?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow building file paths with string concatenation and hardcoded separators.
+	 */
+	usePlatformAgnosticPaths?: RuleFixConfiguration_for_Null;
+	/**
+	 * Require destructuring from arrays and objects.
+	 */
+	usePreferDestructuring?: RuleFixConfiguration_for_UsePreferDestructuringOptions;
+	/**
+	 * Prefer object spread over `Object.assign()` when constructing a new object.
+	 */
+	usePreferObjectSpread?: RuleFixConfiguration_for_Null;
+	/**
+	 * Enforce marking private properties as readonly if they're never modified outside the constructor.
+	 */
+	useReadonlyClassProperties?: RuleFixConfiguration_for_Null;
+	/**
+	 * Enforce the use of shorthand properties instead of groups of longhand properties.
+	 */
+	useShorthand?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow returning boolean literals that depend on a condition, instead of the condition itself.
+	 */
+	useSimplifiedBooleanReturn?: RuleFixConfiguration_for_Null;
 	/**
 	 * Enforce the sorting of CSS utility classes.
 	 */
@@ -1446,6 +1718,10 @@ export interface Nursery {
 	 * Enforce the use of the directive "use strict" in script files.
 	 */
 	useStrictMode?: RuleFixConfiguration_for_Null;
+	/**
+	 * Prefer `structuredClone()` to deep clone a value with `JSON.parse()` and `JSON.stringify()`.
+	 */
+	useStructuredClone?: RuleFixConfiguration_for_Null;
 	/**
 	 * Enforce the use of String.trimStart() and String.trimEnd() over String.trimLeft() and String.trimRight().
 	 */
@@ -1454,6 +1730,14 @@ export interface Nursery {
 	 * Use valid values for the autocomplete attribute on input elements.
 	 */
 	useValidAutocomplete?: RuleConfiguration_for_UseValidAutocompleteOptions;
+	/**
+	 * Enforce that the GraphQL operations of the `gql` and `graphql` tagged templates are valid against the schema of the project.
+	 */
+	useValidGraphqlOperations?: RuleConfiguration_for_UseValidGraphqlOperationsOptions;
+	/**
+	 * Require a fallback value in the var() functions that reference custom properties declared outside the project.
+	 */
+	useVarFallbacks?: RuleConfiguration_for_UseVarFallbacksOptions;
 }
 /**
  * A list of rules that belong to this group
@@ -1564,7 +1848,7 @@ export interface Style {
 	/**
 	 * Disallow reassigning function parameters.
 	 */
-	noParameterAssign?: RuleConfiguration_for_Null;
+	noParameterAssign?: RuleConfiguration_for_NoParameterAssignOptions;
 	/**
 	 * Disallow the use of parameter properties in class constructors.
 	 */
@@ -1660,7 +1944,7 @@ export interface Style {
 	/**
 	 * Promotes the use of import type for types.
 	 */
-	useImportType?: RuleFixConfiguration_for_Null;
+	useImportType?: RuleFixConfiguration_for_ImportTypeOptions;
 	/**
 	 * Require all enum members to be literal values.
 	 */
@@ -1977,7 +2261,7 @@ export interface Suspicious {
 	/**
 	 * Ensure async functions utilize await.
 	 */
-	useAwait?: RuleConfiguration_for_Null;
+	useAwait?: RuleFixConfiguration_for_UseAwaitOptions;
 	/**
 	 * Enforce default clauses in switch statements to be last
 	 */
@@ -2064,6 +2348,9 @@ export type RuleAssistConfiguration_for_Options =
 export type RuleAssistConfiguration_for_Null =
 	| RuleAssistPlainConfiguration
 	| RuleAssistWithOptions_for_Null;
+export type RuleAssistConfiguration_for_UseSortedPropertiesOptions =
+	| RuleAssistPlainConfiguration
+	| RuleAssistWithOptions_for_UseSortedPropertiesOptions;
 export type RuleFixConfiguration_for_Null =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_Null;
@@ -2091,6 +2378,9 @@ export type RuleConfiguration_for_NoUndeclaredDependenciesOptions =
 export type RuleConfiguration_for_UndeclaredVariablesOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_UndeclaredVariablesOptions;
+export type RuleFixConfiguration_for_NoUnusedPrivateClassMembersOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_NoUnusedPrivateClassMembersOptions;
 export type RuleConfiguration_for_UseExhaustiveDependenciesOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_UseExhaustiveDependenciesOptions;
@@ -2100,27 +2390,153 @@ export type RuleConfiguration_for_DeprecatedHooksOptions =
 export type RuleFixConfiguration_for_UseImportExtensionsOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_UseImportExtensionsOptions;
+export type RuleConfiguration_for_MaxNestingDepthOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_MaxNestingDepthOptions;
+export type RuleConfiguration_for_NoArbitraryZIndexOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoArbitraryZIndexOptions;
+export type RuleConfiguration_for_NoAwaitInLoopOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoAwaitInLoopOptions;
+export type RuleConfiguration_for_NoCircularImportsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoCircularImportsOptions;
+export type RuleConfiguration_for_NoCommentedOutCodeOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoCommentedOutCodeOptions;
+export type RuleConfiguration_for_NoConditionalExpectOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoConditionalExpectOptions;
+export type RuleConfiguration_for_NoDeepRelativeImportsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoDeepRelativeImportsOptions;
+export type RuleConfiguration_for_NoDuplicateSelectorsAcrossFilesOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoDuplicateSelectorsAcrossFilesOptions;
+export type RuleFixConfiguration_for_NoFloatingPromisesOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_NoFloatingPromisesOptions;
+export type RuleConfiguration_for_NoHardcodedColorsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoHardcodedColorsOptions;
+export type RuleConfiguration_for_NoHardcodedUserFacingStringsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoHardcodedUserFacingStringsOptions;
+export type RuleConfiguration_for_NoHighSpecificitySelectorsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoHighSpecificitySelectorsOptions;
+export type RuleConfiguration_for_NoIdSelectorsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoIdSelectorsOptions;
+export type RuleConfiguration_for_NoLowContrastColorPairsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoLowContrastColorPairsOptions;
+export type RuleConfiguration_for_NoMagicNumbersOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoMagicNumbersOptions;
+export type RuleConfiguration_for_NoModuleScopeSideEffectsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoModuleScopeSideEffectsOptions;
+export type RuleConfiguration_for_NoNestedTernaryOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoNestedTernaryOptions;
+export type RuleConfiguration_for_NoProcessEnvOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoProcessEnvOptions;
 export type RuleConfiguration_for_RestrictedImportsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_RestrictedImportsOptions;
+export type RuleConfiguration_for_NoRestrictedSyntaxOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoRestrictedSyntaxOptions;
 export type RuleFixConfiguration_for_NoRestrictedTypesOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_NoRestrictedTypesOptions;
 export type RuleConfiguration_for_NoSecretsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_NoSecretsOptions;
+export type RuleConfiguration_for_NoSqlInjectionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoSqlInjectionOptions;
+export type RuleConfiguration_for_NoSyncFsInAsyncContextsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoSyncFsInAsyncContextsOptions;
+export type RuleConfiguration_for_NoTodoWithoutTicketOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoTodoWithoutTicketOptions;
+export type RuleConfiguration_for_NoUnsafeTypeAssertionsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoUnsafeTypeAssertionsOptions;
+export type RuleConfiguration_for_NoUnsanitizedDomInsertionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoUnsanitizedDomInsertionOptions;
+export type RuleConfiguration_for_NoUnusedExportsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoUnusedExportsOptions;
+export type RuleFixConfiguration_for_NoVendorPrefixesOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_NoVendorPrefixesOptions;
+export type RuleConfiguration_for_UseAllowedUnitsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseAllowedUnitsOptions;
+export type RuleConfiguration_for_UseBrowserCompatOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseBrowserCompatOptions;
+export type RuleConfiguration_for_UseClassNamingConventionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseClassNamingConventionOptions;
 export type RuleConfiguration_for_UseComponentExportOnlyModulesOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_UseComponentExportOnlyModulesOptions;
+export type RuleFixConfiguration_for_UseConsistentColorFormatOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseConsistentColorFormatOptions;
 export type RuleConfiguration_for_ConsistentMemberAccessibilityOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_ConsistentMemberAccessibilityOptions;
+export type RuleFixConfiguration_for_UseConsistentTestNamingOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseConsistentTestNamingOptions;
+export type RuleFixConfiguration_for_UseExplicitResourceManagementOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseExplicitResourceManagementOptions;
+export type RuleConfiguration_for_UseFontFamilyFallbacksOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseFontFamilyFallbacksOptions;
+export type RuleFixConfiguration_for_UseImportAliasesOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseImportAliasesOptions;
+export type RuleFixConfiguration_for_UseLogicalPropertiesOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseLogicalPropertiesOptions;
+export type RuleConfiguration_for_UseMaxFunctionSizeOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseMaxFunctionSizeOptions;
+export type RuleConfiguration_for_UseMaxParamsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseMaxParamsOptions;
+export type RuleFixConfiguration_for_UseNumericSeparatorsOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseNumericSeparatorsOptions;
+export type RuleFixConfiguration_for_UsePreferDestructuringOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UsePreferDestructuringOptions;
 export type RuleFixConfiguration_for_UtilityClassSortingOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_UtilityClassSortingOptions;
 export type RuleConfiguration_for_UseValidAutocompleteOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_UseValidAutocompleteOptions;
+export type RuleConfiguration_for_UseValidGraphqlOperationsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseValidGraphqlOperationsOptions;
+export type RuleConfiguration_for_UseVarFallbacksOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseVarFallbacksOptions;
+export type RuleConfiguration_for_NoParameterAssignOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoParameterAssignOptions;
 export type RuleConfiguration_for_RestrictedGlobalsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_RestrictedGlobalsOptions;
@@ -2130,6 +2546,9 @@ export type RuleFixConfiguration_for_ConsistentArrayTypeOptions =
 export type RuleConfiguration_for_FilenamingConventionOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_FilenamingConventionOptions;
+export type RuleFixConfiguration_for_ImportTypeOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_ImportTypeOptions;
 export type RuleFixConfiguration_for_NamingConventionOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_NamingConventionOptions;
@@ -2142,6 +2561,9 @@ export type RuleFixConfiguration_for_NoConsoleOptions =
 export type RuleFixConfiguration_for_NoDoubleEqualsOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_NoDoubleEqualsOptions;
+export type RuleFixConfiguration_for_UseAwaitOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseAwaitOptions;
 export type RuleAssistPlainConfiguration = "on" | "off";
 export interface RuleAssistWithOptions_for_Options {
 	/**
@@ -2163,6 +2585,16 @@ export interface RuleAssistWithOptions_for_Null {
 	 */
 	options: null;
 }
+export interface RuleAssistWithOptions_for_UseSortedPropertiesOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RuleAssistPlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseSortedPropertiesOptions;
+}
 export type RulePlainConfiguration = "warn" | "error" | "info" | "off";
 export interface RuleWithFixOptions_for_Null {
 	/**
@@ -2266,6 +2698,20 @@ export interface RuleWithOptions_for_UndeclaredVariablesOptions {
 	 */
 	options: UndeclaredVariablesOptions;
 }
+export interface RuleWithFixOptions_for_NoUnusedPrivateClassMembersOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoUnusedPrivateClassMembersOptions;
+}
 export interface RuleWithOptions_for_UseExhaustiveDependenciesOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
@@ -2300,7 +2746,7 @@ export interface RuleWithFixOptions_for_UseImportExtensionsOptions {
 	 */
 	options: UseImportExtensionsOptions;
 }
-export interface RuleWithOptions_for_RestrictedImportsOptions {
+export interface RuleWithOptions_for_MaxNestingDepthOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2308,13 +2754,9 @@ export interface RuleWithOptions_for_RestrictedImportsOptions {
 	/**
 	 * Rule's options
 	 */
-	options: RestrictedImportsOptions;
+	options: MaxNestingDepthOptions;
 }
-export interface RuleWithFixOptions_for_NoRestrictedTypesOptions {
-	/**
-	 * The kind of the code actions emitted by the rule
-	 */
-	fix?: FixKind;
+export interface RuleWithOptions_for_NoArbitraryZIndexOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2322,9 +2764,9 @@ export interface RuleWithFixOptions_for_NoRestrictedTypesOptions {
 	/**
 	 * Rule's options
 	 */
-	options: NoRestrictedTypesOptions;
+	options: NoArbitraryZIndexOptions;
 }
-export interface RuleWithOptions_for_NoSecretsOptions {
+export interface RuleWithOptions_for_NoAwaitInLoopOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2332,9 +2774,9 @@ export interface RuleWithOptions_for_NoSecretsOptions {
 	/**
 	 * Rule's options
 	 */
-	options: NoSecretsOptions;
+	options: NoAwaitInLoopOptions;
 }
-export interface RuleWithOptions_for_UseComponentExportOnlyModulesOptions {
+export interface RuleWithOptions_for_NoCircularImportsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2342,9 +2784,9 @@ export interface RuleWithOptions_for_UseComponentExportOnlyModulesOptions {
 	/**
 	 * Rule's options
 	 */
-	options: UseComponentExportOnlyModulesOptions;
+	options: NoCircularImportsOptions;
 }
-export interface RuleWithOptions_for_ConsistentMemberAccessibilityOptions {
+export interface RuleWithOptions_for_NoCommentedOutCodeOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2352,13 +2794,9 @@ export interface RuleWithOptions_for_ConsistentMemberAccessibilityOptions {
 	/**
 	 * Rule's options
 	 */
-	options: ConsistentMemberAccessibilityOptions;
+	options: NoCommentedOutCodeOptions;
 }
-export interface RuleWithFixOptions_for_UtilityClassSortingOptions {
-	/**
-	 * The kind of the code actions emitted by the rule
-	 */
-	fix?: FixKind;
+export interface RuleWithOptions_for_NoConditionalExpectOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2366,9 +2804,9 @@ export interface RuleWithFixOptions_for_UtilityClassSortingOptions {
 	/**
 	 * Rule's options
 	 */
-	options: UtilityClassSortingOptions;
+	options: NoConditionalExpectOptions;
 }
-export interface RuleWithOptions_for_UseValidAutocompleteOptions {
+export interface RuleWithOptions_for_NoDeepRelativeImportsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2376,9 +2814,9 @@ export interface RuleWithOptions_for_UseValidAutocompleteOptions {
 	/**
 	 * Rule's options
 	 */
-	options: UseValidAutocompleteOptions;
+	options: NoDeepRelativeImportsOptions;
 }
-export interface RuleWithOptions_for_RestrictedGlobalsOptions {
+export interface RuleWithOptions_for_NoDuplicateSelectorsAcrossFilesOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2386,9 +2824,9 @@ export interface RuleWithOptions_for_RestrictedGlobalsOptions {
 	/**
 	 * Rule's options
 	 */
-	options: RestrictedGlobalsOptions;
+	options: NoDuplicateSelectorsAcrossFilesOptions;
 }
-export interface RuleWithFixOptions_for_ConsistentArrayTypeOptions {
+export interface RuleWithFixOptions_for_NoFloatingPromisesOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
@@ -2400,9 +2838,9 @@ export interface RuleWithFixOptions_for_ConsistentArrayTypeOptions {
 	/**
 	 * Rule's options
 	 */
-	options: ConsistentArrayTypeOptions;
+	options: NoFloatingPromisesOptions;
 }
-export interface RuleWithOptions_for_FilenamingConventionOptions {
+export interface RuleWithOptions_for_NoHardcodedColorsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2410,13 +2848,9 @@ export interface RuleWithOptions_for_FilenamingConventionOptions {
 	/**
 	 * Rule's options
 	 */
-	options: FilenamingConventionOptions;
+	options: NoHardcodedColorsOptions;
 }
-export interface RuleWithFixOptions_for_NamingConventionOptions {
-	/**
-	 * The kind of the code actions emitted by the rule
-	 */
-	fix?: FixKind;
+export interface RuleWithOptions_for_NoHardcodedUserFacingStringsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2424,13 +2858,9 @@ export interface RuleWithFixOptions_for_NamingConventionOptions {
 	/**
 	 * Rule's options
 	 */
-	options: NamingConventionOptions;
+	options: NoHardcodedUserFacingStringsOptions;
 }
-export interface RuleWithFixOptions_for_UseSelfClosingElementsOptions {
-	/**
-	 * The kind of the code actions emitted by the rule
-	 */
-	fix?: FixKind;
+export interface RuleWithOptions_for_NoHighSpecificitySelectorsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2438,13 +2868,9 @@ export interface RuleWithFixOptions_for_UseSelfClosingElementsOptions {
 	/**
 	 * Rule's options
 	 */
-	options: UseSelfClosingElementsOptions;
+	options: NoHighSpecificitySelectorsOptions;
 }
-export interface RuleWithFixOptions_for_NoConsoleOptions {
-	/**
-	 * The kind of the code actions emitted by the rule
-	 */
-	fix?: FixKind;
+export interface RuleWithOptions_for_NoIdSelectorsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2452,136 +2878,1008 @@ export interface RuleWithFixOptions_for_NoConsoleOptions {
 	/**
 	 * Rule's options
 	 */
-	options: NoConsoleOptions;
+	options: NoIdSelectorsOptions;
 }
-export interface RuleWithFixOptions_for_NoDoubleEqualsOptions {
+export interface RuleWithOptions_for_NoLowContrastColorPairsOptions {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoLowContrastColorPairsOptions;
+}
+export interface RuleWithOptions_for_NoMagicNumbersOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoMagicNumbersOptions;
+}
+export interface RuleWithOptions_for_NoModuleScopeSideEffectsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoModuleScopeSideEffectsOptions;
+}
+export interface RuleWithOptions_for_NoNestedTernaryOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoNestedTernaryOptions;
+}
+export interface RuleWithOptions_for_NoProcessEnvOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoProcessEnvOptions;
+}
+export interface RuleWithOptions_for_RestrictedImportsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: RestrictedImportsOptions;
+}
+export interface RuleWithOptions_for_NoRestrictedSyntaxOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoRestrictedSyntaxOptions;
+}
+export interface RuleWithFixOptions_for_NoRestrictedTypesOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoRestrictedTypesOptions;
+}
+export interface RuleWithOptions_for_NoSecretsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoSecretsOptions;
+}
+export interface RuleWithOptions_for_NoSqlInjectionOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoSqlInjectionOptions;
+}
+export interface RuleWithOptions_for_NoSyncFsInAsyncContextsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoSyncFsInAsyncContextsOptions;
+}
+export interface RuleWithOptions_for_NoTodoWithoutTicketOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoTodoWithoutTicketOptions;
+}
+export interface RuleWithOptions_for_NoUnsafeTypeAssertionsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoUnsafeTypeAssertionsOptions;
+}
+export interface RuleWithOptions_for_NoUnsanitizedDomInsertionOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoUnsanitizedDomInsertionOptions;
+}
+export interface RuleWithOptions_for_NoUnusedExportsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoUnusedExportsOptions;
+}
+export interface RuleWithFixOptions_for_NoVendorPrefixesOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoVendorPrefixesOptions;
+}
+export interface RuleWithOptions_for_UseAllowedUnitsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseAllowedUnitsOptions;
+}
+export interface RuleWithOptions_for_UseBrowserCompatOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseBrowserCompatOptions;
+}
+export interface RuleWithOptions_for_UseClassNamingConventionOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseClassNamingConventionOptions;
+}
+export interface RuleWithOptions_for_UseComponentExportOnlyModulesOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseComponentExportOnlyModulesOptions;
+}
+export interface RuleWithFixOptions_for_UseConsistentColorFormatOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseConsistentColorFormatOptions;
+}
+export interface RuleWithOptions_for_ConsistentMemberAccessibilityOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ConsistentMemberAccessibilityOptions;
+}
+export interface RuleWithFixOptions_for_UseConsistentTestNamingOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseConsistentTestNamingOptions;
+}
+export interface RuleWithFixOptions_for_UseExplicitResourceManagementOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseExplicitResourceManagementOptions;
+}
+export interface RuleWithOptions_for_UseFontFamilyFallbacksOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseFontFamilyFallbacksOptions;
+}
+export interface RuleWithFixOptions_for_UseImportAliasesOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseImportAliasesOptions;
+}
+export interface RuleWithFixOptions_for_UseLogicalPropertiesOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseLogicalPropertiesOptions;
+}
+export interface RuleWithOptions_for_UseMaxFunctionSizeOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseMaxFunctionSizeOptions;
+}
+export interface RuleWithOptions_for_UseMaxParamsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseMaxParamsOptions;
+}
+export interface RuleWithFixOptions_for_UseNumericSeparatorsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseNumericSeparatorsOptions;
+}
+export interface RuleWithFixOptions_for_UsePreferDestructuringOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UsePreferDestructuringOptions;
+}
+export interface RuleWithFixOptions_for_UtilityClassSortingOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UtilityClassSortingOptions;
+}
+export interface RuleWithOptions_for_UseValidAutocompleteOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseValidAutocompleteOptions;
+}
+export interface RuleWithOptions_for_UseValidGraphqlOperationsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseValidGraphqlOperationsOptions;
+}
+export interface RuleWithOptions_for_UseVarFallbacksOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseVarFallbacksOptions;
+}
+export interface RuleWithOptions_for_NoParameterAssignOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoParameterAssignOptions;
+}
+export interface RuleWithOptions_for_RestrictedGlobalsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: RestrictedGlobalsOptions;
+}
+export interface RuleWithFixOptions_for_ConsistentArrayTypeOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ConsistentArrayTypeOptions;
+}
+export interface RuleWithOptions_for_FilenamingConventionOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: FilenamingConventionOptions;
+}
+export interface RuleWithFixOptions_for_ImportTypeOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ImportTypeOptions;
+}
+export interface RuleWithFixOptions_for_NamingConventionOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NamingConventionOptions;
+}
+export interface RuleWithFixOptions_for_UseSelfClosingElementsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseSelfClosingElementsOptions;
+}
+export interface RuleWithFixOptions_for_NoConsoleOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoConsoleOptions;
+}
+export interface RuleWithFixOptions_for_NoDoubleEqualsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoDoubleEqualsOptions;
+}
+export interface RuleWithFixOptions_for_UseAwaitOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseAwaitOptions;
+}
+export interface Options {
+	importGroups?: ImportGroup[];
+	legacy?: boolean;
+}
+export interface UseSortedPropertiesOptions {
+	/**
+	 * The order of the declarations.
+	 */
+	strategy?: SortStrategy;
+}
+/**
+ * Used to identify the kind of code action emitted by a rule
+ */
+export type FixKind = "none" | "safe" | "unsafe";
+export interface AllowDomainOptions {
+	/**
+	 * List of domains to allow `target="_blank"` without `rel="noreferrer"`
+	 */
+	allowDomains: string[];
+}
+export interface NoLabelWithoutControlOptions {
+	/**
+	 * Array of component names that should be considered the same as an `input` element.
+	 */
+	inputComponents?: string[];
+	/**
+	 * Array of attributes that should be treated as the `label` accessible text content.
+	 */
+	labelAttributes?: string[];
+	/**
+	 * Array of component names that should be considered the same as a `label` element.
+	 */
+	labelComponents?: string[];
+}
+export interface ValidAriaRoleOptions {
+	allowInvalidRoles?: string[];
+	ignoreNonDom?: boolean;
+}
+/**
+ * Options for the rule `noExcessiveCognitiveComplexity`.
+ */
+export interface ComplexityOptions {
+	/**
+	 * The maximum complexity score that we allow. Anything higher is considered excessive.
+	 */
+	maxAllowedComplexity?: number;
+}
+export interface NoForEachOptions {
+	/**
+	 * A list of variable names allowed for `forEach` calls.
+	 */
+	allowedIdentifiers: string[];
+}
+/**
+ * Rule's options
+ */
+export interface NoUndeclaredDependenciesOptions {
+	/**
+	 * If set to `false`, then the rule will show an error when `devDependencies` are imported. Defaults to `true`.
+	 */
+	devDependencies?: DependencyAvailability;
+	/**
+	 * If set to `false`, then the rule will show an error when `optionalDependencies` are imported. Defaults to `true`.
+	 */
+	optionalDependencies?: DependencyAvailability;
+	/**
+	 * If set to `false`, then the rule will show an error when `peerDependencies` are imported. Defaults to `true`.
+	 */
+	peerDependencies?: DependencyAvailability;
+}
+export interface UndeclaredVariablesOptions {
+	/**
+	 * Check undeclared types.
+	 */
+	checkTypes?: boolean;
+}
+/**
+ * Options for the rule `noUnusedPrivateClassMembers`.
+ */
+export interface NoUnusedPrivateClassMembersOptions {
+	/**
+	 * Ignore the members declared with the TypeScript `private` modifier.
+	 */
+	ignoreTsPrivateMembers?: boolean;
+}
+/**
+ * Options for the rule `useExhaustiveDependencies`
+ */
+export interface UseExhaustiveDependenciesOptions {
+	/**
+	 * List of hooks of which the dependencies should be validated.
+	 */
+	hooks?: Hook[];
+	/**
+	 * Whether to report an error when a hook has no dependencies array.
+	 */
+	reportMissingDependenciesArray?: boolean;
+	/**
+	 * Whether to report an error when a dependency is listed in the dependencies array but isn't used. Defaults to true.
+	 */
+	reportUnnecessaryDependencies?: boolean;
+}
+/**
+ * Options for the `useHookAtTopLevel` rule have been deprecated, since we now use the React hook naming convention to determine whether a function is a hook.
+ */
+export interface DeprecatedHooksOptions {}
+export interface UseImportExtensionsOptions {
+	/**
+	 * A map of custom import extension mappings, where the key is the inspected file extension, and the value is a pair of `module` extension and `component` import extension
+	 */
+	suggestedExtensions?: {};
+}
+/**
+ * Options for the rule `maxNestingDepth`.
+ */
+export interface MaxNestingDepthOptions {
+	/**
+	 * The maximum number of at-rules that can contain an at-rule.
+	 */
+	maxAtRuleDepth?: number;
+	/**
+	 * The maximum number of style rules that can contain a style rule.
+	 */
+	maxDepth?: number;
+}
+/**
+ * Options for the rule `noArbitraryZIndex`.
+ */
+export interface NoArbitraryZIndexOptions {
+	/**
+	 * The custom properties of the `z-index` scale. All the custom properties are allowed when it's empty.
+	 */
+	allowedCustomProperties: Regex[];
+	/**
+	 * The integers of the `z-index` scale.
+	 */
+	allowedValues: number[];
+}
+/**
+ * Options for the rule `noAwaitInLoop`.
+ */
+export interface NoAwaitInLoopOptions {
+	/**
+	 * The text of the comment that marks a loop as explicitly sequential.
+	 */
+	sequentialComment?: string;
+}
+/**
+ * Options for the rule `noCircularImports`.
+ */
+export interface NoCircularImportsOptions {
+	/**
+	 * Whether to ignore the imports that are erased at compile time.
+	 */
+	ignoreTypeOnlyImports?: boolean;
+	/**
+	 * The maximum number of modules of a reported cycle. All the cycles are reported if it's unset.
+	 */
+	maxCycleLength?: number;
+}
+/**
+ * Options for the rule `noCommentedOutCode`.
+ */
+export interface NoCommentedOutCodeOptions {
+	/**
+	 * The comments that start with one of these markers are allowed.
+	 */
+	allowedMarkers: string[];
+	/**
+	 * The minimum number of lines of a reported comment, or of the consecutive line comments.
+	 */
+	minimumLines?: number;
+}
+/**
+ * Options for the rule `noConditionalExpect`.
+ */
+export interface NoConditionalExpectOptions {
+	/**
+	 * The names of the functions that declare a test.
+	 */
+	testFunctionNames?: string[];
+}
+/**
+ * Options for the rule `noDeepRelativeImports`.
+ */
+export interface NoDeepRelativeImportsOptions {
+	/**
+	 * The number of parent directories that a relative import can go through without being reported.
+	 */
+	maxDepth?: number;
+	/**
+	 * The maximum depths that apply to the modules matched by globs, e.g. the test files.
+	 */
+	overrides?: ImportDepthOverride[];
+}
+/**
+ * Options for the rule `noDuplicateSelectorsAcrossFiles`.
+ */
+export interface NoDuplicateSelectorsAcrossFilesOptions {
+	/**
+	 * The stylesheets to ignore.
+	 */
+	ignore: Regex[];
+}
+/**
+ * Options for the rule `noFloatingPromises`.
+ */
+export interface NoFloatingPromisesOptions {
+	/**
+	 * The names of the functions that return a promise, such as `fetch` or `api.get`.
+	 */
+	functions: string[];
+}
+/**
+ * Options for the rule `noHardcodedColors`.
+ */
+export interface NoHardcodedColorsOptions {
+	/**
+	 * The properties whose values can contain hardcoded colors.
+	 */
+	allowedProperties: string[];
+	/**
+	 * The colors that can be hardcoded.
+	 */
+	allowedValues: string[];
+}
+/**
+ * Options for the rule `noHardcodedUserFacingStrings`.
+ */
+export interface NoHardcodedUserFacingStringsOptions {
+	/**
+	 * The attributes whose value is displayed to the user, e.g. `title`.
+	 */
+	attributes?: string[];
+	/**
+	 * The functions that translate a string, e.g. `t`.
+	 */
+	translationFunctions?: string[];
+}
+/**
+ * Options for the rule `noHighSpecificitySelectors`.
+ */
+export interface NoHighSpecificitySelectorsOptions {
+	/**
+	 * The highest specificity allowed, written as `<ids>,<classes>,<types>`.
+	 */
+	maxSpecificity?: MaxSpecificity;
+}
+/**
+ * Options for the rule `noIdSelectors`.
+ */
+export interface NoIdSelectorsOptions {
+	/**
+	 * Allow the ID selectors in the arguments of `:not()`.
 	 */
-	fix?: FixKind;
+	allowInNot?: boolean;
 	/**
-	 * The severity of the emitted diagnostics by the rule
+	 * The IDs that can be selected.
 	 */
-	level: RulePlainConfiguration;
+	allowedIds: Regex[];
+}
+/**
+ * Options for the rule `noLowContrastColorPairs`.
+ */
+export interface NoLowContrastColorPairsOptions {
 	/**
-	 * Rule's options
+	 * The WCAG conformance level whose minimum contrast ratio is required.
 	 */
-	options: NoDoubleEqualsOptions;
+	level?: ContrastLevel;
 }
-export interface Options {
-	importGroups?: ImportGroup[];
-	legacy?: boolean;
+/**
+ * Options for the rule `noMagicNumbers`.
+ */
+export interface NoMagicNumbersOptions {
+	/**
+	 * Require the numbers declared in variables to be declared with `const`.
+	 */
+	enforceConst?: boolean;
+	/**
+	 * The numbers that are allowed everywhere.
+	 */
+	ignore: number[];
+	/**
+	 * Allow the numbers used as array indexes.
+	 */
+	ignoreArrayIndexes?: boolean;
+	/**
+	 * Allow the numbers used as default values.
+	 */
+	ignoreDefaultValues?: boolean;
 }
 /**
- * Used to identify the kind of code action emitted by a rule
+ * Options for the rule `noModuleScopeSideEffects`.
  */
-export type FixKind = "none" | "safe" | "unsafe";
-export interface AllowDomainOptions {
+export interface NoModuleScopeSideEffectsOptions {
 	/**
-	 * List of domains to allow `target="_blank"` without `rel="noreferrer"`
+	 * Globs of the modules whose top-level side effects are allowed. The globs prefixed with `!` are exceptions.
 	 */
-	allowDomains: string[];
+	allowedFiles: Regex[];
 }
-export interface NoLabelWithoutControlOptions {
+/**
+ * Options for the rule `noNestedTernary`.
+ */
+export interface NoNestedTernaryOptions {
 	/**
-	 * Array of component names that should be considered the same as an `input` element.
+	 * Whether to allow the nested ternary expressions whose branches are all JSX elements, JSX fragments, or `null`.
 	 */
-	inputComponents?: string[];
+	allowJsxConditionalRendering?: boolean;
 	/**
-	 * Array of attributes that should be treated as the `label` accessible text content.
+	 * The maximum depth of the ternary expressions.
 	 */
-	labelAttributes?: string[];
+	maxDepth?: number;
+}
+/**
+ * Options for the rule `noProcessEnv`.
+ */
+export interface NoProcessEnvOptions {
 	/**
-	 * Array of component names that should be considered the same as a `label` element.
+	 * Globs of the files where `process.env` can be used. The globs prefixed with `!` are exceptions.
 	 */
-	labelComponents?: string[];
+	allowedFiles: Regex[];
+	/**
+	 * Environment variables that can be read anywhere.
+	 */
+	allowedVariables: string[];
 }
-export interface ValidAriaRoleOptions {
-	allowInvalidRoles?: string[];
-	ignoreNonDom?: boolean;
+/**
+ * Options for the rule `noRestrictedImports`.
+ */
+export interface RestrictedImportsOptions {
+	/**
+	 * A list of import paths that should trigger the rule.
+	 */
+	paths: {};
+	/**
+	 * A list of glob patterns of import paths that should trigger the rule.
+	 */
+	patterns: RestrictedImportPattern[];
 }
 /**
- * Options for the rule `noExcessiveCognitiveComplexity`.
+ * Options for the rule `noRestrictedSyntax`.
  */
-export interface ComplexityOptions {
+export interface NoRestrictedSyntaxOptions {
 	/**
-	 * The maximum complexity score that we allow. Anything higher is considered excessive.
+	 * The restricted patterns.
 	 */
-	maxAllowedComplexity?: number;
+	patterns: RestrictedPattern[];
 }
-export interface NoForEachOptions {
+export interface NoRestrictedTypesOptions {
+	types?: {};
+}
+export interface NoSecretsOptions {
 	/**
-	 * A list of variable names allowed for `forEach` calls.
+	 * Regular expressions that match additional secrets.
 	 */
-	allowedIdentifiers: string[];
+	additionalPatterns: Regex[];
+	/**
+	 * Regular expressions that match the strings that are never reported.
+	 */
+	allowedPatterns: Regex[];
+	/**
+	 * Set entropy threshold (default is 41).
+	 */
+	entropyThreshold?: number;
 }
 /**
- * Rule's options
+ * Options for the rule `noSqlInjection`.
  */
-export interface NoUndeclaredDependenciesOptions {
+export interface NoSqlInjectionOptions {
 	/**
-	 * If set to `false`, then the rule will show an error when `devDependencies` are imported. Defaults to `true`.
+	 * The functions that execute a query, written as the end of the callee, e.g. `db.query`.
 	 */
-	devDependencies?: DependencyAvailability;
+	sinks?: string[];
+}
+/**
+ * Options for the rule `noSyncFsInAsyncContexts`.
+ */
+export interface NoSyncFsInAsyncContextsOptions {
 	/**
-	 * If set to `false`, then the rule will show an error when `optionalDependencies` are imported. Defaults to `true`.
+	 * Globs of the files where the synchronous functions can be used. The globs prefixed with `!` are exceptions.
 	 */
-	optionalDependencies?: DependencyAvailability;
+	allowedFiles: Regex[];
 	/**
-	 * If set to `false`, then the rule will show an error when `peerDependencies` are imported. Defaults to `true`.
+	 * The functions that register a request handler, e.g. `app.get`.
 	 */
-	peerDependencies?: DependencyAvailability;
+	requestHandlers?: string[];
 }
-export interface UndeclaredVariablesOptions {
+/**
+ * Options for the rule `noTodoWithoutTicket`.
+ */
+export interface NoTodoWithoutTicketOptions {
 	/**
-	 * Check undeclared types.
+	 * The keywords of the comments that must reference a ticket.
 	 */
-	checkTypes?: boolean;
+	keywords?: string[];
+	/**
+	 * A regular expression that matches a ticket reference.
+	 */
+	ticketPattern?: Regex;
 }
 /**
- * Options for the rule `useExhaustiveDependencies`
+ * Options for the rule `noUnsafeTypeAssertions`.
  */
-export interface UseExhaustiveDependenciesOptions {
+export interface NoUnsafeTypeAssertionsOptions {
 	/**
-	 * List of hooks of which the dependencies should be validated.
+	 * Allow all the assertions in test files.
 	 */
-	hooks?: Hook[];
+	allowInTestFiles?: boolean;
 	/**
-	 * Whether to report an error when a hook has no dependencies array.
+	 * Report the assertions to `any`.
 	 */
-	reportMissingDependenciesArray?: boolean;
+	anyAssertions?: boolean;
 	/**
-	 * Whether to report an error when a dependency is listed in the dependencies array but isn't used. Defaults to true.
+	 * Report the double assertions, such as `value as unknown as T`.
 	 */
-	reportUnnecessaryDependencies?: boolean;
+	doubleAssertions?: boolean;
+	/**
+	 * Report the non-null assertions.
+	 */
+	nonNullAssertions?: boolean;
 }
 /**
- * Options for the `useHookAtTopLevel` rule have been deprecated, since we now use the React hook naming convention to determine whether a function is a hook.
+ * Options for the rule `noUnsanitizedDomInsertion`.
  */
-export interface DeprecatedHooksOptions {}
-export interface UseImportExtensionsOptions {
+export interface NoUnsanitizedDomInsertionOptions {
 	/**
-	 * A map of custom import extension mappings, where the key is the inspected file extension, and the value is a pair of `module` extension and `component` import extension
+	 * The functions whose return values are safe to insert as HTML, e.g. `DOMPurify.sanitize`.
 	 */
-	suggestedExtensions?: {};
+	sanitizers?: string[];
 }
 /**
- * Options for the rule `noRestrictedImports`.
+ * Options for the rule `noUnusedExports`.
  */
-export interface RestrictedImportsOptions {
+export interface NoUnusedExportsOptions {
 	/**
-	 * A list of import paths that should trigger the rule.
+	 * Globs of the modules whose exports aren't reported. The globs prefixed with `!` are exceptions.
 	 */
-	paths: {};
+	entryPoints: Regex[];
 }
-export interface NoRestrictedTypesOptions {
-	types?: {};
+/**
+ * Options for the rule `noVendorPrefixes`.
+ */
+export interface NoVendorPrefixesOptions {
+	/**
+	 * A browserslist query of the target browsers, e.g. `"chrome >= 110, safari >= 16.4"`.
+	 */
+	browserslist?: string;
 }
-export interface NoSecretsOptions {
+/**
+ * Options for the rule `useAllowedUnits`.
+ */
+export interface UseAllowedUnitsOptions {
 	/**
-	 * Set entropy threshold (default is 41).
+	 * A map from property patterns to the units they accept.
 	 */
-	entropyThreshold?: number;
+	properties: {};
+}
+/**
+ * Options for the rule `useBrowserCompat`.
+ */
+export interface UseBrowserCompatOptions {
+	/**
+	 * Only allow the features that became Baseline during or before this year.
+	 */
+	baseline?: number;
+	/**
+	 * A browserslist query of the target browsers, e.g. `"chrome >= 110, safari >= 16.4"`.
+	 */
+	browserslist?: string;
+}
+/**
+ * Options for the rule `useClassNamingConvention`.
+ */
+export interface UseClassNamingConventionOptions {
+	/**
+	 * The naming convention of the class names.
+	 */
+	convention?: ClassNamingConvention;
+	/**
+	 * A regular expression that the class names must match. It replaces the convention.
+	 */
+	match?: Regex;
 }
 export interface UseComponentExportOnlyModulesOptions {
 	/**
@@ -2593,9 +3891,123 @@ export interface UseComponentExportOnlyModulesOptions {
 	 */
 	allowExportNames: string[];
 }
+/**
+ * Options for the rule `useConsistentColorFormat`.
+ */
+export interface UseConsistentColorFormatOptions {
+	/**
+	 * The format of the colors.
+	 */
+	format?: ColorFormat;
+}
 export interface ConsistentMemberAccessibilityOptions {
 	accessibility?: Accessibility;
 }
+/**
+ * Options for the rule `useConsistentTestNaming`.
+ */
+export interface UseConsistentTestNamingOptions {
+	/**
+	 * Whether the titles may end with a period.
+	 */
+	allowTrailingPeriod?: boolean;
+	/**
+	 * The case of the first letter of the titles.
+	 */
+	firstLetter?: TestTitleFirstLetter;
+	/**
+	 * A regular expression that the whole titles must match.
+	 */
+	pattern?: Regex;
+}
+/**
+ * Options for the rule `useExplicitResourceManagement`.
+ */
+export interface UseExplicitResourceManagementOptions {
+	/**
+	 * The functions that create a disposable resource, e.g. `pool.connect`.
+	 */
+	factories?: string[];
+}
+/**
+ * Options for the rule `useFontFamilyFallbacks`.
+ */
+export interface UseFontFamilyFallbacksOptions {
+	/**
+	 * Ignore the declarations whose value uses a custom property.
+	 */
+	ignoreCustomProperties?: boolean;
+}
+/**
+ * Options for the rule `useImportAliases`.
+ */
+export interface UseImportAliasesOptions {
+	/**
+	 * The number of parent directories that a relative import can go through without being reported.
+	 */
+	maxRelativeDepth?: number;
+}
+/**
+ * Options for the rule `useLogicalProperties`.
+ */
+export interface UseLogicalPropertiesOptions {
+	/**
+	 * The physical properties to report. All the physical properties are reported when it's empty.
+	 */
+	properties: string[];
+}
+/**
+ * Options for the rule `useMaxFunctionSize`.
+ */
+export interface UseMaxFunctionSizeOptions {
+	/**
+	 * The maximum number of lines of a function, without the blank lines and the comments.
+	 */
+	maxLines?: number;
+	/**
+	 * The maximum number of statements of a function.
+	 */
+	maxStatements?: number;
+}
+/**
+ * Options for the rule `useMaxParams`.
+ */
+export interface UseMaxParamsOptions {
+	/**
+	 * Whether to ignore the constructors that declare parameter properties.
+	 */
+	ignoreParameterProperties?: boolean;
+	/**
+	 * The maximum number of parameters of a function.
+	 */
+	maxParameters?: number;
+}
+/**
+ * Options for the rule `useNumericSeparators`.
+ */
+export interface UseNumericSeparatorsOptions {
+	/**
+	 * The minimum number of digits of the integer part or of the fractional part of a reported literal.
+	 */
+	minimumDigits?: number;
+}
+/**
+ * Options for the rule `usePreferDestructuring`.
+ */
+export interface UsePreferDestructuringOptions {
+	/**
+	 * Whether to report the accesses of an array element by an integer index.
+	 */
+	array?: boolean;
+	/**
+	 * Whether to also report the accesses of an object property whose name differs from the name of the variable.
+	 */
+	enforceForRenamedProperties?: boolean;
+	/**
+	 * Whether to report the accesses of an object property.
+	 */
+	object?: boolean;
+}
 export interface UtilityClassSortingOptions {
 	/**
 	 * Additional attributes that will be sorted.
@@ -2612,6 +4024,37 @@ export interface UseValidAutocompleteOptions {
 	 */
 	inputComponents?: string[];
 }
+/**
+ * Options for the rule `useValidGraphqlOperations`.
+ */
+export interface UseValidGraphqlOperationsOptions {
+	/**
+	 * The path of the GraphQL file that declares the schema.
+	 */
+	schema?: string;
+}
+/**
+ * Options for the rule `useVarFallbacks`.
+ */
+export interface UseVarFallbacksOptions {
+	/**
+	 * The prefixes of the custom properties that require a fallback value. All the custom properties require a fallback value when it's empty.
+	 */
+	prefixes: string[];
+}
+/**
+ * Options for the rule `noParameterAssign`.
+ */
+export interface NoParameterAssignOptions {
+	/**
+	 * The names of the parameters whose properties can be modified when `props` is enabled.
+	 */
+	ignorePropertyModificationsFor?: string[];
+	/**
+	 * Whether to report the modifications of a property of a parameter.
+	 */
+	props?: boolean;
+}
 /**
  * Options for the rule `noRestrictedGlobals`.
  */
@@ -2645,6 +4088,15 @@ export interface FilenamingConventionOptions {
 	 */
 	strictCase: boolean;
 }
+/**
+ * Options for the rule `useImportType`.
+ */
+export interface ImportTypeOptions {
+	/**
+	 * The style of the type imports.
+	 */
+	style?: ImportTypeStyle;
+}
 /**
  * Rule's options.
  */
@@ -2689,7 +4141,21 @@ If `false`, no such exception will be made.
 	 */
 	ignoreNull: boolean;
 }
+/**
+ * Options for the rule `useAwait`.
+ */
+export interface UseAwaitOptions {
+	/**
+	 * Ignore the `async` functions whose body is empty.
+	 */
+	ignoreEmptyFunctions?: boolean;
+	/**
+	 * Ignore the `async` methods of the classes that implement an interface.
+	 */
+	ignoreInterfaceImplementations?: boolean;
+}
 export type ImportGroup = PredefinedImportGroup | Regex;
+export type SortStrategy = "alphabetical" | "concentric" | "recess" | "smacss";
 export type DependencyAvailability = boolean | string[];
 export interface Hook {
 	/**
@@ -2717,10 +4183,63 @@ For example, for React's `useRef()` hook the value would be `true`, while for `u
 	 */
 	stableResult?: StableHookResult;
 }
+export type Regex = string;
+/**
+ * A maximum depth that applies to the modules matched by globs.
+ */
+export interface ImportDepthOverride {
+	/**
+	 * Globs of the importing modules. The globs prefixed with `!` are exceptions.
+	 */
+	include?: Regex[];
+	/**
+	 * The number of parent directories that a relative import of these modules can go through.
+	 */
+	maxDepth?: number;
+}
+export type MaxSpecificity = string;
+export type ContrastLevel = "AA" | "AAA";
+/**
+ * A group of import paths selected by globs, and its restriction.
+ */
+export interface RestrictedImportPattern {
+	/**
+	 * Names of the exported members that are allowed to be used.
+	 */
+	allowImportNames: string[];
+	/**
+	 * The globs of the import paths. The globs prefixed with `!` are exceptions.
+	 */
+	group?: Regex[];
+	/**
+	 * Names of the exported members that should not be used.
+	 */
+	importNames: string[];
+	/**
+	 * The message to display when this module is imported.
+	 */
+	message: string;
+}
+/**
+ * A pattern of the option `patterns`.
+ */
+export interface RestrictedPattern {
+	/**
+	 * The message of the diagnostic.
+	 */
+	message?: string;
+	/**
+	 * The GritQL query of the restricted code.
+	 */
+	pattern?: GritPattern;
+}
+export type ClassNamingConvention = "kebab-case" | "bem";
+export type ColorFormat = "hex" | "rgb" | "hsl" | "oklch";
 export type Accessibility = "noPublic" | "explicit" | "none";
+export type TestTitleFirstLetter = "lowercase" | "uppercase" | "any";
 export type ConsistentArrayType = "shorthand" | "generic";
 export type FilenameCases = FilenameCase[];
-export type Regex = string;
+export type ImportTypeStyle = "auto" | "inlineType" | "separatedType";
 export interface Convention {
 	/**
 	 * String cases to enforce
@@ -2749,6 +4268,7 @@ export type PredefinedImportGroup =
 	| ":node:"
 	| ":types:";
 export type StableHookResult = boolean | number[];
+export type GritPattern = string;
 /**
  * Supported cases for file names.
  */
@@ -2888,9 +4408,9 @@ export type LanguageVersion = "ES2022" | "ESNext";
 /**
 	* The style of CSS contained in the file.
 
-Currently, Biome only supports plain CSS, and aims to be compatible with the latest Recommendation level standards. 
+Currently, Biome only parses plain CSS, and aims to be compatible with the latest Recommendation level standards. SCSS files are recognized, but the ones that use the SCSS syntax are kept as written. 
 	 */
-export type CssVariant = "Standard";
+export type CssVariant = "Standard" | "Scss";
 /**
  * The style of GraphQL contained in the file.
  */
@@ -2898,6 +4418,10 @@ export type GraphqlVariant = "Standard";
 export type HtmlVariant = "Standard" | "Astro";
 export type GritVariant = "Standard";
 export interface ChangeFileParams {
+	/**
+	 * The range of the previous content replaced by the new content, when the change is a single edit. It lets the workspace reparse only the part of the file touched by the edit.
+	 */
+	changed_range?: TextRange;
 	content: string;
 	path: BiomePath;
 	version: number;
@@ -3085,9 +4609,19 @@ export type Category =
 	| "lint/correctness/useValidForDirection"
 	| "lint/correctness/useYield"
 	| "lint/nursery/colorNoInvalidHex"
+	| "lint/nursery/maxNestingDepth"
+	| "lint/nursery/noArbitraryZIndex"
+	| "lint/nursery/noAwaitInLoop"
+	| "lint/nursery/noCircularImports"
 	| "lint/nursery/noColorInvalidHex"
+	| "lint/nursery/noCommentedOutCode"
 	| "lint/nursery/noCommonJs"
+	| "lint/nursery/noConditionalExpect"
 	| "lint/nursery/noConsole"
+	| "lint/nursery/noCustomPropertyTypos"
+	| "lint/nursery/noDeepRelativeImports"
+	| "lint/nursery/noDeprecatedApis"
+	| "lint/nursery/noDeprecatedProperties"
 	| "lint/nursery/noDescendingSpecificity"
 	| "lint/nursery/noDocumentCookie"
 	| "lint/nursery/noDocumentImportInPage"
@@ -3096,35 +4630,55 @@ export type Category =
 	| "lint/nursery/noDuplicateCustomProperties"
 	| "lint/nursery/noDuplicateElseIf"
 	| "lint/nursery/noDuplicateProperties"
+	| "lint/nursery/noDuplicateSelectorsAcrossFiles"
+	| "lint/nursery/noDuplicateTestTitles"
 	| "lint/nursery/noDuplicatedFields"
 	| "lint/nursery/noDynamicNamespaceImportAccess"
 	| "lint/nursery/noEnum"
 	| "lint/nursery/noExportedImports"
+	| "lint/nursery/noFloatingPromises"
 	| "lint/nursery/noGlobalDirnameFilename"
+	| "lint/nursery/noHardcodedColors"
+	| "lint/nursery/noHardcodedUserFacingStrings"
 	| "lint/nursery/noHeadElement"
 	| "lint/nursery/noHeadImportInDocument"
+	| "lint/nursery/noHighSpecificitySelectors"
+	| "lint/nursery/noIdSelectors"
 	| "lint/nursery/noImgElement"
 	| "lint/nursery/noImportantInKeyframe"
 	| "lint/nursery/noInvalidDirectionInLinearGradient"
 	| "lint/nursery/noInvalidGridAreas"
 	| "lint/nursery/noInvalidPositionAtImportRule"
 	| "lint/nursery/noIrregularWhitespace"
+	| "lint/nursery/noLeakedEventListeners"
+	| "lint/nursery/noLowContrastColorPairs"
+	| "lint/nursery/noMagicNumbers"
 	| "lint/nursery/noMissingGenericFamilyKeyword"
 	| "lint/nursery/noMissingVarFunction"
+	| "lint/nursery/noMisusedPromises"
+	| "lint/nursery/noModuleScopeSideEffects"
 	| "lint/nursery/noNestedTernary"
 	| "lint/nursery/noOctalEscape"
 	| "lint/nursery/noProcessEnv"
 	| "lint/nursery/noProcessGlobal"
 	| "lint/nursery/noReactSpecificProps"
 	| "lint/nursery/noRestrictedImports"
+	| "lint/nursery/noRestrictedSyntax"
 	| "lint/nursery/noRestrictedTypes"
 	| "lint/nursery/noSecrets"
 	| "lint/nursery/noShorthandPropertyOverrides"
+	| "lint/nursery/noSqlInjection"
 	| "lint/nursery/noStaticElementInteractions"
 	| "lint/nursery/noSubstr"
+	| "lint/nursery/noSuperLinearRegex"
+	| "lint/nursery/noSyncFsInAsyncContexts"
 	| "lint/nursery/noTemplateCurlyInString"
+	| "lint/nursery/noTodoWithoutTicket"
+	| "lint/nursery/noTransitionAll"
 	| "lint/nursery/noUndeclaredDependencies"
+	| "lint/nursery/noUnknownAnimationName"
 	| "lint/nursery/noUnknownAtRule"
+	| "lint/nursery/noUnknownContainerFeatureName"
 	| "lint/nursery/noUnknownFunction"
 	| "lint/nursery/noUnknownMediaFeatureName"
 	| "lint/nursery/noUnknownProperty"
@@ -3135,35 +4689,67 @@ export type Category =
 	| "lint/nursery/noUnknownTypeSelector"
 	| "lint/nursery/noUnknownUnit"
 	| "lint/nursery/noUnmatchableAnbSelector"
+	| "lint/nursery/noUnnecessaryConditions"
+	| "lint/nursery/noUnsafeTypeAssertions"
+	| "lint/nursery/noUnsanitizedDomInsertion"
+	| "lint/nursery/noUnstableDefaultProps"
+	| "lint/nursery/noUnusedCustomProperties"
+	| "lint/nursery/noUnusedExports"
 	| "lint/nursery/noUnusedFunctionParameters"
+	| "lint/nursery/noUnusedKeyframes"
 	| "lint/nursery/noUselessEscapeInRegex"
 	| "lint/nursery/noUselessStringRaw"
 	| "lint/nursery/noUselessUndefined"
 	| "lint/nursery/noValueAtRule"
+	| "lint/nursery/noVendorPrefixes"
 	| "lint/nursery/useAdjacentOverloadSignatures"
+	| "lint/nursery/useAllowedUnits"
 	| "lint/nursery/useAriaPropsSupportedByRole"
 	| "lint/nursery/useAtIndex"
 	| "lint/nursery/useBiomeSuppressionComment"
+	| "lint/nursery/useBrowserCompat"
+	| "lint/nursery/useClassNamingConvention"
 	| "lint/nursery/useCollapsedIf"
 	| "lint/nursery/useComponentExportOnlyModules"
+	| "lint/nursery/useConsistentColorFormat"
 	| "lint/nursery/useConsistentCurlyBraces"
 	| "lint/nursery/useConsistentMemberAccessibility"
+	| "lint/nursery/useConsistentTestNaming"
 	| "lint/nursery/useDeprecatedReason"
+	| "lint/nursery/useErrorCause"
+	| "lint/nursery/useExhaustiveSwitchCases"
 	| "lint/nursery/useExplicitFunctionReturnType"
+	| "lint/nursery/useExplicitResourceManagement"
 	| "lint/nursery/useExplicitType"
 	| "lint/nursery/useExportsLast"
+	| "lint/nursery/useFontFamilyFallbacks"
 	| "lint/nursery/useGoogleFontDisplay"
 	| "lint/nursery/useGoogleFontPreconnect"
 	| "lint/nursery/useGuardForIn"
+	| "lint/nursery/useImportAliases"
 	| "lint/nursery/useImportRestrictions"
+	| "lint/nursery/useJsDocConsistency"
 	| "lint/nursery/useJsxCurlyBraceConvention"
+	| "lint/nursery/useLogicalProperties"
+	| "lint/nursery/useMaxFunctionSize"
+	| "lint/nursery/useMaxParams"
 	| "lint/nursery/useNamedOperation"
 	| "lint/nursery/useNamingConvention"
+	| "lint/nursery/useNumericSeparators"
 	| "lint/nursery/useParseIntRadix"
+	| "lint/nursery/usePlatformAgnosticPaths"
+	| "lint/nursery/usePreferDestructuring"
+	| "lint/nursery/usePreferObjectSpread"
+	| "lint/nursery/useReadonlyClassProperties"
+	| "lint/nursery/useShorthand"
+	| "lint/nursery/useSimplifiedBooleanReturn"
 	| "lint/nursery/useSortedClasses"
 	| "lint/nursery/useStrictMode"
+	| "lint/nursery/useStructuredClone"
 	| "lint/nursery/useTrimStartEnd"
 	| "lint/nursery/useValidAutocomplete"
+	| "lint/nursery/useValidGraphqlOperations"
+	| "lint/nursery/useVarFallbacks"
 	| "lint/performance/noAccumulatingSpread"
 	| "lint/performance/noBarrelFile"
 	| "lint/performance/noDelete"
@@ -3290,6 +4876,7 @@ export type Category =
 	| "lint/suspicious/useNumberToFixedDigitsArgument"
 	| "lint/suspicious/useValidTypeof"
 	| "assists/source/useSortedKeys"
+	| "assists/source/useSortedProperties"
 	| "syntax/correctness/noTypeOnlyImportAttributes"
 	| "syntax/correctness/noSuperWithoutExtends"
 	| "syntax/correctness/noInitializerWithDefinite"
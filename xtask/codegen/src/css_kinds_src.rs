@@ -116,6 +116,29 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "ridge",
         "inset",
         "outset",
+        "anchor",
+        "anchor-size",
+        "position-try",
+        "view-transition",
+        "system",
+        "symbols",
+        "additive-symbols",
+        "range",
+        "fixed",
+        "extends",
+        "infinite",
+        "custom-media",
+        "font-tech",
+        "font-format",
+        "color-mix",
+        "hue",
+        "slotted",
+        "tailwind",
+        "apply",
+        "theme",
+        "config",
+        "variants",
+        "scroll-state",
         // HERE: Add new regular keywords _above_ here. Be sure to also add them
         // to `consume_identifier` in `biome_css_parser/src/lexer/mod.rs` as well.
         // CSS-wide keywords
@@ -263,6 +286,7 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_PERCENTAGE_VALUE",
         "CSS_UNICODE_CODEPOINT_LITERAL",
         "CSS_UNICODE_RANGE_WILDCARD_LITERAL",
+        "CSS_TAILWIND_UTILITY_LITERAL",
     ],
     tokens: &[
         "ERROR_TOKEN",
@@ -334,6 +358,8 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_PSEUDO_ELEMENT_IDENTIFIER",
         "CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR",
         "CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER",
+        "CSS_PSEUDO_ELEMENT_FUNCTION_CUSTOM_IDENTIFIER_LIST",
+        "CSS_PSEUDO_ELEMENT_FUNCTION_COMPOUND_SELECTOR",
         "CSS_PSEUDO_CLASS_IDENTIFIER",
         "CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER",
         "CSS_PSEUDO_CLASS_FUNCTION_SELECTOR",
@@ -373,12 +399,23 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_UNICODE_CODEPOINT",
         "CSS_UNICODE_RANGE_WILDCARD",
         "CSS_UNICODE_RANGE_INTERVAL",
+        "CSS_ANCHOR_FUNCTION",
+        "CSS_ANCHOR_SIZE_FUNCTION",
+        "CSS_ANCHOR_FALLBACK",
+        "CSS_COLOR_MIX_FUNCTION",
+        "CSS_COLOR_INTERPOLATION_METHOD",
+        "CSS_COLOR_HUE_INTERPOLATION_METHOD",
+        "CSS_COLOR_MIX_COLOR_LIST",
+        "CSS_COLOR_MIX_COLOR",
+        "CSS_RELATIVE_COLOR_FUNCTION",
+        "CSS_RELATIVE_COLOR_ALPHA",
         // At rule nodes
         "CSS_AT_RULE",
         "CSS_CHARSET_AT_RULE",
         "CSS_COLOR_PROFILE_AT_RULE",
         "CSS_COUNTER_STYLE_AT_RULE",
         "CSS_PROPERTY_AT_RULE",
+        "CSS_PROPERTY_AT_RULE_BLOCK",
         "CSS_CONTAINER_AT_RULE",
         "CSS_CONTAINER_NOT_QUERY",
         "CSS_CONTAINER_AND_QUERY",
@@ -390,6 +427,13 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_CONTAINER_STYLE_AND_QUERY",
         "CSS_CONTAINER_STYLE_OR_QUERY",
         "CSS_CONTAINER_STYLE_IN_PARENS",
+        "CSS_CONTAINER_STYLE_RANGE",
+        "CSS_CONTAINER_STYLE_RANGE_INTERVAL",
+        "CSS_CONTAINER_SCROLL_STATE_QUERY_IN_PARENS",
+        "CSS_CONTAINER_SCROLL_STATE_NOT_QUERY",
+        "CSS_CONTAINER_SCROLL_STATE_AND_QUERY",
+        "CSS_CONTAINER_SCROLL_STATE_OR_QUERY",
+        "CSS_CONTAINER_SCROLL_STATE_IN_PARENS",
         "CSS_FONT_FACE_AT_RULE",
         "CSS_FONT_FAMILY_NAME",
         "CSS_FONT_FAMILY_NAME_LIST",
@@ -455,6 +499,8 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_SUPPORTS_CONDITION_IN_PARENS",
         "CSS_SUPPORTS_FEATURE_DECLARATION",
         "CSS_SUPPORTS_FEATURE_SELECTOR",
+        "CSS_SUPPORTS_FEATURE_FONT_TECH",
+        "CSS_SUPPORTS_FEATURE_FONT_FORMAT",
         "CSS_SCOPE_AT_RULE",
         "CSS_SCOPE_RANGE_START",
         "CSS_SCOPE_RANGE_END",
@@ -478,9 +524,34 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_VALUE_AT_RULE_DECLARATION_CLAUSE",
         "CSS_VALUE_AT_RULE_GENERIC_PROPERTY",
         "CSS_VALUE_AT_RULE_GENERIC_VALUE",
+        "CSS_POSITION_TRY_AT_RULE",
+        "CSS_VIEW_TRANSITION_AT_RULE",
+        "CSS_CUSTOM_MEDIA_AT_RULE",
+        "CSS_COUNTER_STYLE_SYSTEM_PROPERTY",
+        "CSS_COUNTER_STYLE_FIXED_SYSTEM",
+        "CSS_COUNTER_STYLE_EXTENDS_SYSTEM",
+        "CSS_COUNTER_STYLE_SYMBOLS_PROPERTY",
+        "CSS_COUNTER_STYLE_SYMBOL_LIST",
+        "CSS_COUNTER_STYLE_ADDITIVE_SYMBOLS_PROPERTY",
+        "CSS_COUNTER_STYLE_ADDITIVE_SYMBOL_LIST",
+        "CSS_COUNTER_STYLE_ADDITIVE_SYMBOL",
+        "CSS_COUNTER_STYLE_RANGE_PROPERTY",
+        "CSS_COUNTER_STYLE_RANGE_VALUE",
+        "CSS_COUNTER_STYLE_RANGE_LIST",
+        "CSS_COUNTER_STYLE_RANGE",
+        "CSS_TAILWIND_AT_RULE",
+        "CSS_TAILWIND_APPLY_AT_RULE",
+        "CSS_TAILWIND_UTILITY_LIST",
+        "CSS_TAILWIND_UTILITY",
+        "CSS_TAILWIND_THEME_AT_RULE",
+        "CSS_TAILWIND_THEME_MODIFIER_LIST",
+        "CSS_TAILWIND_CONFIG_AT_RULE",
+        "CSS_TAILWIND_VARIANTS_AT_RULE",
+        "CSS_TAILWIND_VARIANT_LIST",
         "CSS_UNKNOWN_BLOCK_AT_RULE",
         "CSS_UNKNOWN_VALUE_AT_RULE",
         "CSS_UNKNOWN_AT_RULE_COMPONENT_LIST",
+        "CSS_UNKNOWN_AT_RULE_RAW_BLOCK",
         // Bogus nodes
         "CSS_BOGUS",
         "CSS_BOGUS_BLOCK",
@@ -499,6 +570,7 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_BOGUS_PROPERTY",
         "CSS_BOGUS_PROPERTY_VALUE",
         "CSS_BOGUS_MEDIA_QUERY",
+        "CSS_BOGUS_CONTAINER_QUERY",
         "CSS_BOGUS_SCOPE_RANGE",
         "CSS_BOGUS_URL_MODIFIER",
         "CSS_BOGUS_DOCUMENT_MATCHER",
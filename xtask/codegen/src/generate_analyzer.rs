@@ -47,8 +47,12 @@ fn generate_css_analyzer() -> Result<()> {
     let base_path = project_root().join("crates/biome_css_analyze/src");
     let mut analyzers = BTreeMap::new();
     generate_category("lint", &mut analyzers, &base_path)?;
+
+    let mut assists = BTreeMap::new();
+    generate_category("assists", &mut assists, &base_path)?;
+
     generate_options(&base_path)?;
-    update_css_registry_builder(analyzers)
+    update_css_registry_builder(analyzers, assists)
 }
 
 fn generate_graphql_analyzer() -> Result<()> {
@@ -312,10 +316,16 @@ fn update_json_registry_builder(
     Ok(())
 }
 
-fn update_css_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+fn update_css_registry_builder(
+    analyzers: BTreeMap<&'static str, TokenStream>,
+    assists: BTreeMap<&'static str, TokenStream>,
+) -> Result<()> {
     let path = project_root().join("crates/biome_css_analyze/src/registry.rs");
 
-    let categories = analyzers.into_values();
+    let categories = analyzers
+        .into_iter()
+        .chain(assists)
+        .map(|(_, tokens)| tokens);
 
     let tokens = xtask::reformat(quote! {
         use biome_analyze::RegistryVisitor;
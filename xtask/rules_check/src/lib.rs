@@ -9,12 +9,15 @@ use biome_analyze::{
 };
 use biome_configuration::PartialConfiguration;
 use biome_console::{markup, Console};
+use biome_css_analyze::project::CssProjectIndex;
 use biome_css_parser::CssParserOptions;
 use biome_css_syntax::CssLanguage;
 use biome_deserialize::json::deserialize_from_json_ast;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic};
 use biome_fs::BiomePath;
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_js_analyze::graphql::GraphqlSchema;
+use biome_js_analyze::project::JsProjectIndex;
 use biome_js_parser::JsParserOptions;
 use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage, TextSize};
 use biome_json_factory::make;
@@ -26,9 +29,10 @@ use biome_service::workspace::DocumentFileSource;
 use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd};
 use std::collections::BTreeMap;
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::slice;
 use std::str::FromStr;
+use std::sync::Arc;
 
 pub fn check_rules() -> anyhow::Result<()> {
     #[derive(Default)]
@@ -327,6 +331,7 @@ fn assert_lint(
     test: &CodeBlockTest,
     code: &str,
     config: &Option<PartialConfiguration>,
+    graphql_schema: Option<&str>,
 ) -> anyhow::Result<()> {
     let file_path = format!("code-block.{}", test.tag);
 
@@ -395,34 +400,54 @@ fn assert_lint(
                     o
                 };
 
-                biome_js_analyze::analyze(&root, filter, &options, file_source, None, |signal| {
-                    if let Some(mut diag) = signal.diagnostic() {
-                        let category = diag.category().expect("linter diagnostic has no code");
-                        let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
+                // The code block is the whole project of the example, with the schema
+                // of the last GraphQL code block as the file `schema.graphql`
+                let project = JsProjectIndex::default();
+                if let Some(schema) = graphql_schema {
+                    let parse = biome_graphql_parser::parse_graphql(schema);
+                    project.insert_graphql_schema(
+                        Path::new("schema.graphql"),
+                        GraphqlSchema::from_root(&parse.tree()),
+                    );
+                }
+                project.set_complete(true);
+
+                biome_js_analyze::analyze(
+                    &root,
+                    filter,
+                    &options,
+                    file_source,
+                    None,
+                    Arc::new(project),
+                    |signal| {
+                        if let Some(mut diag) = signal.diagnostic() {
+                            let category = diag.category().expect("linter diagnostic has no code");
+                            let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
                                 "If you see this error, it means you need to run cargo codegen-configuration",
                             );
 
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diag = diag.add_code_suggestion(action.into());
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diag = diag.add_code_suggestion(action.into());
+                                }
                             }
-                        }
 
-                        let error = diag
-                            .with_severity(severity)
-                            .with_file_path(&file_path)
-                            .with_file_source_code(code);
-                        let res = diagnostics.write_diagnostic(error);
+                            let error = diag
+                                .with_severity(severity)
+                                .with_file_path(&file_path)
+                                .with_file_source_code(code);
+                            let res = diagnostics.write_diagnostic(error);
 
-                        // Abort the analysis on error
-                        if let Err(err) = res {
-                            eprintln!("Error: {err}");
-                            return ControlFlow::Break(err);
+                            // Abort the analysis on error
+                            if let Err(err) = res {
+                                eprintln!("Error: {err}");
+                                return ControlFlow::Break(err);
+                            }
                         }
-                    }
 
-                    ControlFlow::Continue(())
-                });
+                        ControlFlow::Continue(())
+                    },
+                );
             }
         }
         DocumentFileSource::Json(file_source) => {
@@ -493,7 +518,11 @@ fn assert_lint(
 
                 let options = create_analyzer_options::<JsonLanguage>(&settings, &file_path, test);
 
-                biome_css_analyze::analyze(&root, filter, &options, |signal| {
+                // The code block is the whole project of the example
+                let project = CssProjectIndex::default();
+                project.set_complete(true);
+
+                biome_css_analyze::analyze(&root, filter, &options, Arc::new(project), |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
                         let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
@@ -781,6 +810,8 @@ fn parse_documentation(
 
     // Track the last configuration options block that was encountered
     let mut last_options: Option<PartialConfiguration> = None;
+    // Track the last GraphQL code block, which declares the schema of the examples
+    let mut last_graphql_schema: Option<String> = None;
 
     // Tracks the content of the current code block if it's using a
     // language supported for analysis
@@ -798,7 +829,17 @@ fn parse_documentation(
                     if test.options != OptionsParsingMode::NoOptions {
                         last_options = parse_rule_options(group, rule, &test, &block)?;
                     } else {
-                        assert_lint(group, rule, &test, &block, &last_options)?;
+                        assert_lint(
+                            group,
+                            rule,
+                            &test,
+                            &block,
+                            &last_options,
+                            last_graphql_schema.as_deref(),
+                        )?;
+                        if test.tag == "graphql" {
+                            last_graphql_schema = Some(block);
+                        }
                     }
                 }
             }